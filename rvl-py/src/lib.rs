@@ -0,0 +1,73 @@
+//! Python bindings for [`rvl::compare`].
+//!
+//! `rvl.compare(old_path, new_path, key=..., tolerance=...)` runs the same
+//! pipeline as the CLI and returns the `rvl.v0` report as a dict, exactly as
+//! `--json` would print it. Refusals are returned like any other verdict
+//! (`outcome == "REFUSAL"`); only process-level failures raise `RvlError`.
+
+use std::path::PathBuf;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::prelude::*;
+use rvl::cli::args::{DEFAULT_THRESHOLD, DEFAULT_TOLERANCE};
+use rvl::cli::delimiter::parse_delimiter_arg;
+use rvl::{CompareInput, CompareOptions};
+
+create_exception!(rvl, RvlError, PyException, "A comparison could not run.");
+
+/// Compare two CSV files and return the `rvl.v0` report as a dict.
+#[pyfunction]
+#[pyo3(signature = (
+    old_path,
+    new_path,
+    *,
+    key = None,
+    threshold = DEFAULT_THRESHOLD,
+    tolerance = DEFAULT_TOLERANCE,
+    delimiter = None,
+))]
+fn compare(
+    py: Python<'_>,
+    old_path: PathBuf,
+    new_path: PathBuf,
+    key: Option<String>,
+    threshold: f64,
+    tolerance: f64,
+    delimiter: Option<&str>,
+) -> PyResult<PyObject> {
+    if !threshold.is_finite() || threshold <= 0.0 || threshold > 1.0 {
+        return Err(PyValueError::new_err("threshold must be 0 < x <= 1"));
+    }
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        return Err(PyValueError::new_err("tolerance must be >= 0"));
+    }
+    let delimiter = delimiter
+        .map(parse_delimiter_arg)
+        .transpose()
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let options = CompareOptions {
+        key,
+        threshold,
+        tolerance,
+        delimiter,
+        ..CompareOptions::new(CompareInput::path(old_path), CompareInput::path(new_path))
+    };
+
+    let result = py
+        .allow_threads(|| rvl::compare(options))
+        .map_err(|err| RvlError::new_err(err.to_string()))?;
+    let report =
+        serde_json::to_string(result.report()).map_err(|err| RvlError::new_err(err.to_string()))?;
+    let report = py.import("json")?.call_method1("loads", (report,))?;
+    Ok(report.unbind())
+}
+
+#[pymodule]
+#[pyo3(name = "rvl")]
+fn rvl_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(compare, m)?)?;
+    m.add("RvlError", m.py().get_type::<RvlError>())?;
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    Ok(())
+}
@@ -0,0 +1,225 @@
+//! Agent manifest (`rvl agent-manifest`).
+//!
+//! One document that agent registries can ingest: what to run, what it
+//! accepts, what it returns, and how it exits. Every part is derived from the
+//! binary itself: the input schema from the clap definition of the comparison
+//! arguments, the output schema from `rvl schema`, exit codes from
+//! [`Outcome`], and refusal codes from [`RefusalCode::ALL`]. A new flag or
+//! refusal code therefore shows up in the manifest without a separate edit.
+
+use clap::{Arg, ArgAction, CommandFactory};
+use serde_json::{Map, Value, json};
+
+use crate::cli::args::{AgentManifestArgs, Args};
+use crate::cli::exit::{Outcome, exit_code};
+use crate::output::json::OutputVersion;
+use crate::refusal::codes::RefusalCode;
+use crate::refusal::process::PipelineError;
+
+const MANIFEST_VERSION: &str = "rvl.agent-manifest.v0";
+
+/// Arguments that select a display mode instead of feeding a comparison.
+const DISPLAY_ARGS: [&str; 5] = ["help", "version", "describe", "schema", "robot_triage"];
+
+/// Run `rvl agent-manifest`. Always exits 0.
+pub fn run(args: &AgentManifestArgs) -> Result<u8, PipelineError> {
+    let manifest = build_manifest();
+    match args.format.as_str() {
+        "yaml" => print!("{}", render_yaml(&manifest)),
+        _ => println!("{}", serde_json::to_string_pretty(&manifest)?),
+    }
+    Ok(0)
+}
+
+pub fn build_manifest() -> Value {
+    let exit_codes: Map<String, Value> =
+        [Outcome::NoRealChange, Outcome::RealChange, Outcome::Refusal]
+            .into_iter()
+            .map(|outcome| (exit_code(outcome).to_string(), json!(outcome.as_str())))
+            .collect();
+    let refusal_codes: Vec<Value> = RefusalCode::ALL
+        .iter()
+        .map(|code| json!({ "code": code.as_str(), "reason": code.reason() }))
+        .collect();
+    json!({
+        "manifest_version": MANIFEST_VERSION,
+        "name": "rvl",
+        "version": env!("CARGO_PKG_VERSION"),
+        "description": env!("CARGO_PKG_DESCRIPTION"),
+        "repository": env!("CARGO_PKG_REPOSITORY"),
+        "invocation": {
+            "binary": "rvl",
+            "usage": "rvl <old.csv> <new.csv> --json [OPTIONS]",
+            "output": "stdout",
+        },
+        "input_schema": input_schema(),
+        "output_schema": crate::output_schema(OutputVersion::V0),
+        "exit_codes": exit_codes,
+        "refusal_codes": refusal_codes,
+    })
+}
+
+/// JSON Schema for the comparison arguments, one property per argument id.
+/// Values are the strings passed on the command line, so every non-boolean
+/// property is a string (or a list of strings for repeatable flags).
+fn input_schema() -> Value {
+    // Positional indices are only assigned once the command is built.
+    let mut command = Args::command();
+    command.build();
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for arg in command.get_arguments() {
+        let id = arg.get_id().as_str();
+        if DISPLAY_ARGS.contains(&id) {
+            continue;
+        }
+        if arg.get_index().is_some() {
+            required.push(json!(id));
+        }
+        properties.insert(id.to_string(), arg_schema(arg));
+    }
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "rvl comparison arguments",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn arg_schema(arg: &Arg) -> Value {
+    let mut schema = Map::new();
+    let string_values = || {
+        let mut values = json!({ "type": "string" });
+        let choices: Vec<Value> = arg
+            .get_possible_values()
+            .iter()
+            .map(|value| json!(value.get_name()))
+            .collect();
+        if !choices.is_empty() {
+            values["enum"] = json!(choices);
+        }
+        values
+    };
+    match arg.get_action() {
+        ArgAction::SetTrue => {
+            schema.insert("type".to_string(), json!("boolean"));
+        }
+        ArgAction::Append => {
+            schema.insert("type".to_string(), json!("array"));
+            schema.insert("items".to_string(), string_values());
+        }
+        _ => {
+            if let Value::Object(values) = string_values() {
+                schema.extend(values);
+            }
+        }
+    }
+    if let Some(help) = arg.get_help() {
+        schema.insert("description".to_string(), json!(help.to_string()));
+    }
+    if let Some(default) = arg.get_default_values().first() {
+        schema.insert("default".to_string(), json!(default.to_string_lossy()));
+    }
+    match (arg.get_long(), arg.get_index()) {
+        (Some(long), _) => {
+            schema.insert("x-flag".to_string(), json!(format!("--{long}")));
+        }
+        (None, Some(index)) => {
+            schema.insert("x-position".to_string(), json!(index - 1));
+        }
+        (None, None) => {}
+    }
+    if let Some(env) = arg.get_env() {
+        schema.insert("x-env".to_string(), json!(env.to_string_lossy()));
+    }
+    Value::Object(schema)
+}
+
+/// Block-style YAML for a JSON value. Strings are written as JSON string
+/// literals, which YAML reads as double-quoted scalars, so nothing needs
+/// YAML-specific escaping.
+pub fn render_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(value, 0, &mut out);
+    out
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                out.push_str(&pad);
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_yaml_child(child, indent, out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                out.push_str(&pad);
+                out.push('-');
+                write_yaml_child(item, indent, out);
+            }
+        }
+        scalar => {
+            out.push_str(&pad);
+            out.push_str(&scalar.to_string());
+            out.push('\n');
+        }
+    }
+}
+
+/// Write a mapping value or sequence item: scalars and empty collections
+/// inline, non-empty collections as an indented block.
+fn write_yaml_child(value: &Value, indent: usize, out: &mut String) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            out.push('\n');
+            write_yaml(value, indent + 2, out);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            out.push('\n');
+            write_yaml(value, indent + 2, out);
+        }
+        scalar => {
+            out.push(' ');
+            out.push_str(&scalar.to_string());
+            out.push('\n');
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_input_schema_tracks_the_cli() {
+        let manifest = build_manifest();
+        let input = &manifest["input_schema"];
+        assert_eq!(input["required"], json!(["old", "new"]));
+        assert_eq!(input["properties"]["json"]["type"], "boolean");
+        assert_eq!(input["properties"]["key"]["x-flag"], "--key");
+        assert_eq!(input["properties"]["key"]["x-env"], "RVL_KEY");
+        assert_eq!(input["properties"]["threshold"]["default"], "0.95");
+        assert_eq!(input["properties"]["column_map"]["type"], "array");
+        assert!(input["properties"].get("describe").is_none());
+        assert_eq!(manifest["exit_codes"]["1"], "REAL_CHANGE");
+        assert_eq!(
+            manifest["output_schema"]["properties"]["version"]["const"],
+            "rvl.v0"
+        );
+    }
+
+    #[test]
+    fn render_yaml_writes_block_style() {
+        let value = json!({ "args": { "key": "id" }, "codes": [0, 1], "empty": [], "name": "rvl" });
+        assert_eq!(
+            render_yaml(&value),
+            "\"args\":\n  \"key\": \"id\"\n\"codes\":\n  - 0\n  - 1\n\"empty\": []\n\"name\": \"rvl\"\n"
+        );
+    }
+}
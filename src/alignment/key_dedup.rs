@@ -0,0 +1,193 @@
+//! Duplicate-key handling before the key join (`--dedup`).
+//!
+//! The default `refuse` leaves duplicates to [`build_key_map`], which refuses
+//! with `E_KEY_DUP`. `first` keeps the first record per key; `sum` collapses
+//! each key's records into one, summing every column whose non-missing values
+//! all parse as numbers and keeping the first record's value elsewhere.
+//!
+//! [`build_key_map`]: crate::alignment::key_join::build_key_map
+
+use std::collections::HashMap;
+
+use crate::alignment::key_normalize::{KeyNormalizeStep, normalize_key};
+use crate::csv::records::OwnedRecord;
+use crate::numeric::parse::{DecimalSeparator, NumberFormat};
+
+/// How records sharing a key are handled (`--dedup`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DedupMode {
+    /// Refuse with `E_KEY_DUP`.
+    #[default]
+    Refuse,
+    /// Sum numeric columns per key.
+    Sum,
+    /// Keep the first record per key.
+    First,
+}
+
+impl DedupMode {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "refuse" => Some(DedupMode::Refuse),
+            "sum" => Some(DedupMode::Sum),
+            "first" => Some(DedupMode::First),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DedupMode::Refuse => "refuse",
+            DedupMode::Sum => "sum",
+            DedupMode::First => "first",
+        }
+    }
+}
+
+/// Records folded into an earlier record with the same key, per file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyDedup {
+    pub mode: DedupMode,
+    pub collapsed_old: u64,
+    pub collapsed_new: u64,
+}
+
+/// Collapse records sharing a key under `mode`, numbering the survivors by
+/// their first record (1-based). Returns the records and how many were
+/// folded away. Blank records and empty keys pass through untouched so the
+/// key map still skips or refuses them.
+pub fn collapse_duplicate_keys(
+    records: Vec<OwnedRecord>,
+    key_index: usize,
+    normalize: &[KeyNormalizeStep],
+    mode: DedupMode,
+    format: NumberFormat<'_>,
+) -> (Vec<(u64, OwnedRecord)>, u64) {
+    let numbered = records
+        .into_iter()
+        .enumerate()
+        .map(|(idx, record)| ((idx + 1) as u64, record));
+    if mode == DedupMode::Refuse {
+        return (numbered.collect(), 0);
+    }
+
+    let mut groups: Vec<Vec<(u64, OwnedRecord)>> = Vec::new();
+    let mut by_key: HashMap<Vec<u8>, usize> = HashMap::new();
+    for (record_number, record) in numbered {
+        let key = normalize_key(record.field(key_index), normalize);
+        let key = key.as_ref();
+        if key.is_empty() {
+            groups.push(vec![(record_number, record)]);
+            continue;
+        }
+        match by_key.get(key) {
+            Some(&group) => groups[group].push((record_number, record)),
+            None => {
+                by_key.insert(key.to_vec(), groups.len());
+                groups.push(vec![(record_number, record)]);
+            }
+        }
+    }
+
+    let mut collapsed = 0u64;
+    let records = groups
+        .into_iter()
+        .map(|mut group| {
+            collapsed += (group.len() - 1) as u64;
+            if mode == DedupMode::Sum && group.len() > 1 {
+                let summed = sum_records(&group, key_index, format);
+                group[0].1 = summed;
+            }
+            group.swap_remove(0)
+        })
+        .collect();
+    (records, collapsed)
+}
+
+fn sum_records(
+    group: &[(u64, OwnedRecord)],
+    key_index: usize,
+    format: NumberFormat<'_>,
+) -> OwnedRecord {
+    let mut summed = group[0].1.clone();
+    for column in (0..summed.len()).filter(|&column| column != key_index) {
+        let mut total = 0.0;
+        let mut numeric = false;
+        let values = group
+            .iter()
+            .map(|(_, record)| record.field(column))
+            .filter(|raw| !format.is_missing(raw));
+        for raw in values {
+            match format.parse(raw) {
+                Some(value) => {
+                    total += value;
+                    numeric = true;
+                }
+                None => {
+                    numeric = false;
+                    break;
+                }
+            }
+        }
+        if numeric {
+            summed.set(column, render_sum(total, format.decimal).as_bytes());
+        }
+    }
+    summed
+}
+
+/// A summed value as a plain token the same number format reads back.
+fn render_sum(value: f64, decimal: DecimalSeparator) -> String {
+    let plain = value.to_string();
+    match decimal {
+        DecimalSeparator::Dot => plain,
+        DecimalSeparator::Comma => plain.replace('.', ","),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(rows: &[&[&str]]) -> Vec<OwnedRecord> {
+        rows.iter()
+            .map(|row| row.iter().map(|field| field.as_bytes()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn sum_folds_numeric_columns_and_keeps_first_text() {
+        let input = records(&[
+            &["A", "1,000", "x"],
+            &["B", "5", "y"],
+            &["A", "250.5", "z"],
+            &["A", "", "w"],
+        ]);
+        let (kept, collapsed) =
+            collapse_duplicate_keys(input, 0, &[], DedupMode::Sum, NumberFormat::default());
+        assert_eq!(collapsed, 2);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0].0, 1);
+        assert_eq!(kept[0].1, records(&[&["A", "1250.5", "x"]])[0]);
+        assert_eq!(kept[1].0, 2);
+    }
+
+    #[test]
+    fn first_keeps_the_earliest_record() {
+        let input = records(&[&["A", "1"], &["A", "2"], &["B", "3"]]);
+        let (kept, collapsed) =
+            collapse_duplicate_keys(input, 0, &[], DedupMode::First, NumberFormat::default());
+        assert_eq!(collapsed, 1);
+        assert_eq!(kept[0].1, records(&[&["A", "1"]])[0]);
+        assert_eq!(kept[1].0, 3);
+    }
+
+    #[test]
+    fn refuse_leaves_records_untouched() {
+        let input = records(&[&["A", "1"], &["A", "2"]]);
+        let (kept, collapsed) =
+            collapse_duplicate_keys(input, 0, &[], DedupMode::Refuse, NumberFormat::default());
+        assert_eq!(collapsed, 0);
+        assert_eq!(kept.len(), 2);
+    }
+}
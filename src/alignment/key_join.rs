@@ -4,6 +4,7 @@
 //! - Build a key map from normalized records.
 //! - Detect empty keys and duplicates.
 //! - Compare key sets and surface mismatches.
+//! - Join on the key intersection when missing keys are allowed.
 //!
 //! Memory note (v0):
 //! - Key mode materializes one full side into a `HashMap<key, row>` before join.
@@ -12,9 +13,12 @@
 
 use std::collections::HashMap;
 
+use crate::alignment::key_normalize::{
+    KeyNormalizeHint, KeyNormalizeStep, normalize_key, suggest_key_normalize,
+};
 use crate::normalize::trim::ascii_trim;
 
-pub type OwnedRecord = Vec<Vec<u8>>;
+pub use crate::csv::records::OwnedRecord;
 
 #[derive(Debug, Clone)]
 pub struct KeyEntry {
@@ -34,6 +38,46 @@ pub struct KeyAlignedRow {
     pub new: KeyEntry,
 }
 
+/// A row whose key appears in only one file.
+#[derive(Debug, Clone)]
+pub struct UnmatchedRow {
+    pub key: Vec<u8>,
+    pub entry: KeyEntry,
+}
+
+/// Join result over the common keys (`--allow-missing-keys`).
+#[derive(Debug, Clone, Default)]
+pub struct KeyIntersection {
+    pub aligned: Vec<KeyAlignedRow>,
+    /// Rows only in the old file, sorted by key.
+    pub removed: Vec<UnmatchedRow>,
+    /// Rows only in the new file, sorted by key.
+    pub added: Vec<UnmatchedRow>,
+}
+
+/// Rows outside the key intersection, with per-column numeric totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyChangeReport {
+    pub rows_added: u64,
+    pub rows_removed: u64,
+    /// Per numeric column; `None` when values are redacted.
+    pub totals: Option<Vec<KeyChangeTotal>>,
+    /// Whether the rows' cells were ranked as contributors (`--include-entity-changes`).
+    pub ranked: bool,
+    /// Row-order rows past the common prefix (`--allow-trailing-rows`)
+    /// rather than unmatched keys.
+    pub trailing: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyChangeTotal {
+    pub column: Vec<u8>,
+    /// Sum of the column over added rows.
+    pub added: f64,
+    /// Sum of the column over removed rows.
+    pub removed: f64,
+}
+
 /// Errors encountered in key-mode alignment.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum KeyJoinError {
@@ -50,6 +94,8 @@ pub enum KeyJoinError {
         extra_count: usize,
         missing_samples: Vec<Vec<u8>>,
         extra_samples: Vec<Vec<u8>>,
+        /// Set when a `--key-normalize` step would pair missing and extra keys.
+        normalize_hint: Option<KeyNormalizeHint>,
     },
 }
 
@@ -58,6 +104,18 @@ pub enum KeyJoinError {
 /// `records` should be data records only (header excluded) and already
 /// normalized to header width.
 pub fn build_key_map<I>(records: I, key_index: usize) -> Result<KeyMap, KeyJoinError>
+where
+    I: IntoIterator<Item = (u64, OwnedRecord)>,
+{
+    build_key_map_with(records, key_index, &[])
+}
+
+/// [`build_key_map`] with `--key-normalize` steps applied to every key.
+pub fn build_key_map_with<I>(
+    records: I,
+    key_index: usize,
+    normalize: &[KeyNormalizeStep],
+) -> Result<KeyMap, KeyJoinError>
 where
     I: IntoIterator<Item = (u64, OwnedRecord)>,
 {
@@ -66,8 +124,8 @@ where
         if is_blank_owned_record(&record) {
             continue;
         }
-        let raw_key = record.get(key_index).map(|v| v.as_slice()).unwrap_or(b"");
-        let key = ascii_trim(raw_key);
+        let key = normalize_key(record.field(key_index), normalize);
+        let key = key.as_ref();
         if key.is_empty() {
             return Err(KeyJoinError::EmptyKey { record_number });
         }
@@ -119,6 +177,50 @@ pub fn join_key_maps(old: KeyMap, new: KeyMap) -> Result<Vec<KeyAlignedRow>, Key
     Ok(aligned)
 }
 
+/// Join two key maps on their common keys; rows on one side only are
+/// returned as removed (old) or added (new) instead of refusing.
+pub fn join_key_intersection(old: KeyMap, new: KeyMap) -> KeyIntersection {
+    let mut old_entries = old.entries;
+    let mut new_entries = new.entries;
+
+    let mut common: Vec<Vec<u8>> = old_entries
+        .keys()
+        .filter(|key| new_entries.contains_key(*key))
+        .cloned()
+        .collect();
+    common.sort();
+
+    let mut aligned = Vec::with_capacity(common.len());
+    for key in common {
+        let old_entry = old_entries
+            .remove(&key)
+            .expect("key should exist in old map");
+        let new_entry = new_entries
+            .remove(&key)
+            .expect("key should exist in new map");
+        aligned.push(KeyAlignedRow {
+            key,
+            old: old_entry,
+            new: new_entry,
+        });
+    }
+
+    KeyIntersection {
+        aligned,
+        removed: unmatched_rows(old_entries),
+        added: unmatched_rows(new_entries),
+    }
+}
+
+fn unmatched_rows(entries: HashMap<Vec<u8>, KeyEntry>) -> Vec<UnmatchedRow> {
+    let mut rows: Vec<UnmatchedRow> = entries
+        .into_iter()
+        .map(|(key, entry)| UnmatchedRow { key, entry })
+        .collect();
+    rows.sort_by(|left, right| left.key.cmp(&right.key));
+    rows
+}
+
 fn compare_key_sets(
     old_entries: &HashMap<Vec<u8>, KeyEntry>,
     new_entries: &HashMap<Vec<u8>, KeyEntry>,
@@ -141,6 +243,7 @@ fn compare_key_sets(
         return None;
     }
 
+    let normalize_hint = suggest_key_normalize(&missing, &extra);
     missing.sort();
     extra.sort();
     let missing_count = missing.len();
@@ -153,6 +256,7 @@ fn compare_key_sets(
         extra_count,
         missing_samples: missing,
         extra_samples: extra,
+        normalize_hint,
     })
 }
 
@@ -163,7 +267,7 @@ fn truncate_samples(samples: &mut Vec<Vec<u8>>) {
     }
 }
 
-fn is_blank_owned_record(record: &[Vec<u8>]) -> bool {
+fn is_blank_owned_record(record: &OwnedRecord) -> bool {
     record.iter().all(|field| ascii_trim(field).is_empty())
 }
 
@@ -172,7 +276,7 @@ mod tests {
     use super::*;
 
     fn record(fields: &[&[u8]]) -> OwnedRecord {
-        fields.iter().map(|field| field.to_vec()).collect()
+        fields.iter().copied().collect()
     }
 
     #[test]
@@ -216,6 +320,7 @@ mod tests {
                 extra_count: 1,
                 missing_samples: vec![b"B".to_vec()],
                 extra_samples: vec![b"C".to_vec()],
+                normalize_hint: None,
             }
         );
     }
@@ -229,4 +334,34 @@ mod tests {
         assert_eq!(joined[0].key, b"a".to_vec());
         assert_eq!(joined[1].key, b"b".to_vec());
     }
+
+    #[test]
+    fn join_key_intersection_splits_unmatched_rows() {
+        let old = build_key_map(
+            vec![
+                (1, record(&[b"A"])),
+                (2, record(&[b"B"])),
+                (3, record(&[b"D"])),
+            ],
+            0,
+        )
+        .unwrap();
+        let new = build_key_map(
+            vec![
+                (1, record(&[b"C"])),
+                (2, record(&[b"A"])),
+                (3, record(&[b"E"])),
+            ],
+            0,
+        )
+        .unwrap();
+        let joined = join_key_intersection(old, new);
+        let keys =
+            |rows: &[UnmatchedRow]| rows.iter().map(|row| row.key.clone()).collect::<Vec<_>>();
+        assert_eq!(joined.aligned.len(), 1);
+        assert_eq!(joined.aligned[0].key, b"A".to_vec());
+        assert_eq!(keys(&joined.removed), vec![b"B".to_vec(), b"D".to_vec()]);
+        assert_eq!(keys(&joined.added), vec![b"C".to_vec(), b"E".to_vec()]);
+        assert_eq!(joined.added[0].entry.record_number, 1);
+    }
 }
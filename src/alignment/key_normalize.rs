@@ -0,0 +1,184 @@
+//! Opt-in key normalization before the key join (`--key-normalize`).
+//!
+//! Keys are always ASCII-trimmed. The steps here go further, in the order
+//! given, on both sides: `trim` also strips Unicode whitespace (NBSP, thin
+//! space, ...), `lower` case-folds, and `strip-leading-zeros` turns `0001234`
+//! into `1234` (an all-zero key keeps one `0`). Rows report the normalized key.
+//!
+//! When key sets still differ, [`suggest_key_normalize`] checks whether one
+//! more step would pair the missing keys with the extra ones, so the
+//! `E_KEY_MISMATCH` refusal can name the flag to try.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use crate::normalize::trim::ascii_trim;
+
+/// One key normalization step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyNormalizeStep {
+    Trim,
+    Lower,
+    StripLeadingZeros,
+}
+
+impl KeyNormalizeStep {
+    pub const ALL: [KeyNormalizeStep; 3] = [
+        KeyNormalizeStep::Trim,
+        KeyNormalizeStep::Lower,
+        KeyNormalizeStep::StripLeadingZeros,
+    ];
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "trim" => Some(KeyNormalizeStep::Trim),
+            "lower" => Some(KeyNormalizeStep::Lower),
+            "strip-leading-zeros" => Some(KeyNormalizeStep::StripLeadingZeros),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyNormalizeStep::Trim => "trim",
+            KeyNormalizeStep::Lower => "lower",
+            KeyNormalizeStep::StripLeadingZeros => "strip-leading-zeros",
+        }
+    }
+
+    /// How keys compare under this step, for refusal text.
+    pub fn describe(self) -> &'static str {
+        match self {
+            KeyNormalizeStep::Trim => "after trimming Unicode whitespace",
+            KeyNormalizeStep::Lower => "case-insensitively",
+            KeyNormalizeStep::StripLeadingZeros => "ignoring leading zeros",
+        }
+    }
+}
+
+/// Missing keys that would pair with an extra key under one more step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyNormalizeHint {
+    pub step: KeyNormalizeStep,
+    /// Missing keys whose normalized form matches a normalized extra key.
+    pub matched: usize,
+    pub missing: usize,
+}
+
+/// The single step that pairs the most missing keys (old only) with extra
+/// keys (new only), or `None` when no step pairs any. Ties go to the first
+/// step in [`KeyNormalizeStep::ALL`]. Steps already applied pair nothing
+/// new, since every step is idempotent.
+pub fn suggest_key_normalize(missing: &[Vec<u8>], extra: &[Vec<u8>]) -> Option<KeyNormalizeHint> {
+    let mut best: Option<KeyNormalizeHint> = None;
+    for step in KeyNormalizeStep::ALL {
+        let extras: HashSet<Vec<u8>> = extra
+            .iter()
+            .map(|key| normalize_key(key, &[step]).into_owned())
+            .collect();
+        let matched = missing
+            .iter()
+            .filter(|key| extras.contains(normalize_key(key, &[step]).as_ref()))
+            .count();
+        if matched > best.map_or(0, |hint| hint.matched) {
+            best = Some(KeyNormalizeHint {
+                step,
+                matched,
+                missing: missing.len(),
+            });
+        }
+    }
+    best
+}
+
+/// The join key for a raw key cell: ASCII-trimmed, then each step in order.
+pub fn normalize_key<'a>(raw: &'a [u8], steps: &[KeyNormalizeStep]) -> Cow<'a, [u8]> {
+    let trimmed = ascii_trim(raw);
+    if steps.is_empty() {
+        return Cow::Borrowed(trimmed);
+    }
+    let mut key = trimmed.to_vec();
+    for step in steps {
+        key = match step {
+            KeyNormalizeStep::Trim => unicode_trim(&key).to_vec(),
+            KeyNormalizeStep::Lower => lowercase(&key),
+            KeyNormalizeStep::StripLeadingZeros => strip_leading_zeros(&key).to_vec(),
+        };
+    }
+    Cow::Owned(key)
+}
+
+fn unicode_trim(key: &[u8]) -> &[u8] {
+    match std::str::from_utf8(key) {
+        Ok(text) => text.trim().as_bytes(),
+        Err(_) => key,
+    }
+}
+
+fn lowercase(key: &[u8]) -> Vec<u8> {
+    match std::str::from_utf8(key) {
+        Ok(text) => text.to_lowercase().into_bytes(),
+        Err(_) => key.to_ascii_lowercase(),
+    }
+}
+
+fn strip_leading_zeros(key: &[u8]) -> &[u8] {
+    let zeros = key.iter().take_while(|byte| **byte == b'0').count();
+    &key[zeros.min(key.len().saturating_sub(1))..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_apply_in_order_after_ascii_trim() {
+        let all = [
+            KeyNormalizeStep::Trim,
+            KeyNormalizeStep::Lower,
+            KeyNormalizeStep::StripLeadingZeros,
+        ];
+        assert_eq!(normalize_key(b" ABC ", &[]).as_ref(), b"ABC");
+        assert_eq!(
+            normalize_key(" \u{a0}Abc\u{a0}".as_bytes(), &all).as_ref(),
+            b"abc"
+        );
+        assert_eq!(normalize_key(b"0001234", &all).as_ref(), b"1234");
+        assert_eq!(normalize_key(b"000", &all).as_ref(), b"0");
+        assert_eq!(normalize_key(b"0001234", &[]).as_ref(), b"0001234");
+    }
+
+    #[test]
+    fn suggests_the_step_that_pairs_most_missing_keys() {
+        let keys = |raw: &[&str]| {
+            raw.iter()
+                .map(|key| key.as_bytes().to_vec())
+                .collect::<Vec<_>>()
+        };
+        let hint =
+            suggest_key_normalize(&keys(&["abc", "def", "0042"]), &keys(&["ABC", "DEF", "42"]))
+                .expect("hint");
+        assert_eq!(
+            hint,
+            KeyNormalizeHint {
+                step: KeyNormalizeStep::Lower,
+                matched: 2,
+                missing: 3,
+            }
+        );
+        assert_eq!(suggest_key_normalize(&keys(&["a"]), &keys(&["b"])), None);
+    }
+
+    #[test]
+    fn parses_step_names() {
+        assert_eq!(
+            KeyNormalizeStep::parse("strip-leading-zeros"),
+            Some(KeyNormalizeStep::StripLeadingZeros)
+        );
+        assert_eq!(
+            KeyNormalizeStep::parse("LOWER"),
+            Some(KeyNormalizeStep::Lower)
+        );
+        assert_eq!(KeyNormalizeStep::parse("upper"), None);
+    }
+}
@@ -0,0 +1,153 @@
+//! Explicit old→new key translations for `--key-map`.
+//!
+//! A map file is a CSV whose first two columns are `old_key,new_key` after a
+//! header row. Translations rewrite the old file's key values before the join,
+//! so known ID migrations (system cutovers, renumbering) still align by key.
+//! Several old keys may translate to the same new key; one old key may not
+//! translate to two different new keys.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+
+use crate::alignment::key_join::OwnedRecord;
+use crate::csv::blank::is_blank_record;
+use crate::csv::parser::{EscapeMode, build_reader};
+use crate::normalize::trim::ascii_trim;
+
+#[derive(Debug, Clone)]
+pub struct KeyTranslation {
+    pub file: String,
+    map: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl KeyTranslation {
+    /// Load a map file, returning a short reason on failure.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let bytes = fs::read(path).map_err(|err| format!("cannot read file ({err})"))?;
+        Ok(Self {
+            file: path.to_string_lossy().to_string(),
+            map: parse_key_map(&bytes)?,
+        })
+    }
+
+    /// Number of old→new entries in the map file.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Rewrite the key field of every record whose key has a translation.
+    ///
+    /// Returns how many records were translated.
+    pub fn apply(&self, records: &mut [OwnedRecord], key_index: usize) -> u64 {
+        let mut translated = 0;
+        for record in records {
+            let Some(field) = record.get(key_index) else {
+                continue;
+            };
+            if let Some(new_key) = self.map.get(ascii_trim(field)) {
+                record.set(key_index, new_key);
+                translated += 1;
+            }
+        }
+        translated
+    }
+}
+
+fn parse_key_map(bytes: &[u8]) -> Result<HashMap<Vec<u8>, Vec<u8>>, String> {
+    let mut reader = build_reader(Cursor::new(bytes), b',', EscapeMode::None);
+    let mut map: HashMap<Vec<u8>, Vec<u8>> = HashMap::new();
+    let mut seen_header = false;
+    for record in reader.byte_records() {
+        let record = record.map_err(|err| match err.position() {
+            Some(pos) => format!("CSV parse failure near line {}", pos.line()),
+            None => "CSV parse failure".to_string(),
+        })?;
+        let line = record.position().map_or(0, |pos| pos.line());
+        if is_blank_record(&record) {
+            continue;
+        }
+        if !seen_header {
+            seen_header = true;
+            continue;
+        }
+        let (Some(old_key), Some(new_key)) = (record.get(0), record.get(1)) else {
+            return Err(format!("line {line} needs old_key,new_key"));
+        };
+        let (old_key, new_key) = (ascii_trim(old_key), ascii_trim(new_key));
+        if old_key.is_empty() || new_key.is_empty() {
+            return Err(format!("line {line} has an empty key"));
+        }
+        match map.get(old_key) {
+            Some(existing) if existing.as_slice() != new_key => {
+                return Err(format!(
+                    "old key \"{}\" maps to more than one new key",
+                    String::from_utf8_lossy(old_key)
+                ));
+            }
+            Some(_) => {}
+            None => {
+                map.insert(old_key.to_vec(), new_key.to_vec());
+            }
+        }
+    }
+    if map.is_empty() {
+        return Err("no old_key,new_key rows".to_string());
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(text: &str) -> KeyTranslation {
+        KeyTranslation {
+            file: "map.csv".to_string(),
+            map: parse_key_map(text.as_bytes()).unwrap(),
+        }
+    }
+
+    fn record(fields: &[&str]) -> OwnedRecord {
+        fields.iter().map(|field| field.as_bytes()).collect()
+    }
+
+    #[test]
+    fn translates_mapped_keys_and_leaves_others() {
+        let map = translation("old_id,new_id\nL-1,LN-001\nL-2,LN-002\n");
+        let mut records = vec![record(&["L-1", "10"]), record(&["LN-003", "30"])];
+        assert_eq!(map.apply(&mut records, 0), 1);
+        assert_eq!(records[0].field(0), b"LN-001");
+        assert_eq!(records[1].field(0), b"LN-003");
+    }
+
+    #[test]
+    fn allows_many_to_one_but_not_one_to_many() {
+        assert_eq!(translation("old,new\nA,X\nB,X\nA,X\n").len(), 2);
+        assert_eq!(
+            parse_key_map(b"old,new\nA,X\nA,Y\n").unwrap_err(),
+            "old key \"A\" maps to more than one new key"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_rows() {
+        assert_eq!(
+            parse_key_map(b"old,new\nA\n").unwrap_err(),
+            "line 2 needs old_key,new_key"
+        );
+        assert_eq!(
+            parse_key_map(b"old,new\nA, \n").unwrap_err(),
+            "line 2 has an empty key"
+        );
+        assert_eq!(
+            parse_key_map(b"old,new\n").unwrap_err(),
+            "no old_key,new_key rows"
+        );
+    }
+}
@@ -1,5 +1,10 @@
+pub mod key_dedup;
 pub mod key_discovery;
 pub mod key_join;
+pub mod key_normalize;
 pub mod key_parse;
+pub mod key_translation;
+pub mod row_filter;
 pub mod row_order;
+pub mod sample;
 pub mod shuffle;
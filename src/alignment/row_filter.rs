@@ -0,0 +1,132 @@
+//! Row exclusion by predicate (`--where`).
+//!
+//! `--where "status != 'Sold'"` keeps only rows whose `status` cell is not
+//! `Sold`. Filters apply after alignment: an aligned pair is dropped when
+//! either side fails, an unmatched row when its own side does. Repeated
+//! `--where` flags must all hold. Cells and values are compared as
+//! ASCII-trimmed bytes; a missing cell reads as empty.
+
+use crate::csv::records::OwnedRecord;
+use crate::normalize::trim::ascii_trim;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhereOp {
+    Eq,
+    Ne,
+}
+
+impl WhereOp {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WhereOp::Eq => "=",
+            WhereOp::Ne => "!=",
+        }
+    }
+}
+
+/// One `--where` predicate: `COLUMN = VALUE` or `COLUMN != VALUE`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowPredicate {
+    pub column: Vec<u8>,
+    pub op: WhereOp,
+    pub value: Vec<u8>,
+}
+
+impl RowPredicate {
+    /// Parse `COLUMN = VALUE` / `COLUMN != VALUE` (`==` also accepted).
+    /// The split is at the first operator; the value may be wrapped in
+    /// single or double quotes.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let (column, op, value) = match raw.find(['!', '=']) {
+            Some(at) if raw[at..].starts_with("!=") => (&raw[..at], WhereOp::Ne, &raw[at + 2..]),
+            Some(at) if raw[at..].starts_with("==") => (&raw[..at], WhereOp::Eq, &raw[at + 2..]),
+            Some(at) if raw[at..].starts_with('=') => (&raw[..at], WhereOp::Eq, &raw[at + 1..]),
+            _ => return Err("must be column = value or column != value".to_string()),
+        };
+        let column = unquote(ascii_trim(column.as_bytes()));
+        if column.is_empty() {
+            return Err("column name must be non-empty".to_string());
+        }
+        Ok(Self {
+            column: column.to_vec(),
+            op,
+            value: ascii_trim(unquote(ascii_trim(value.as_bytes()))).to_vec(),
+        })
+    }
+
+    pub fn matches(&self, cell: &[u8]) -> bool {
+        let equal = ascii_trim(cell) == self.value.as_slice();
+        match self.op {
+            WhereOp::Eq => equal,
+            WhereOp::Ne => !equal,
+        }
+    }
+
+    /// The flag value that reproduces this predicate.
+    pub fn as_arg(&self) -> String {
+        format!(
+            "{} {} '{}'",
+            String::from_utf8_lossy(&self.column),
+            self.op.as_str(),
+            String::from_utf8_lossy(&self.value)
+        )
+    }
+}
+
+/// Whether `record` passes every predicate; `indices[i]` is predicate `i`'s
+/// column in this record's file.
+pub fn row_passes(predicates: &[RowPredicate], indices: &[usize], record: &OwnedRecord) -> bool {
+    predicates
+        .iter()
+        .zip(indices)
+        .all(|(predicate, &index)| predicate.matches(record.field(index)))
+}
+
+fn unquote(value: &[u8]) -> &[u8] {
+    match value {
+        [b'\'', inner @ .., b'\''] | [b'"', inner @ .., b'"'] => inner,
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_matches_predicates() {
+        let sold = RowPredicate::parse(" status != 'Sold' ").unwrap();
+        assert_eq!(sold.column, b"status".to_vec());
+        assert_eq!(sold.op, WhereOp::Ne);
+        assert!(!sold.matches(b" Sold"));
+        assert!(sold.matches(b"Active"));
+        assert_eq!(sold.as_arg(), "status != 'Sold'");
+
+        let region = RowPredicate::parse("\"region\"==\"EU\"").unwrap();
+        assert_eq!(region.column, b"region".to_vec());
+        assert!(region.matches(b"EU"));
+        assert!(!region.matches(b""));
+
+        assert_eq!(
+            RowPredicate::parse("desk=").unwrap().value,
+            Vec::<u8>::new()
+        );
+        assert!(RowPredicate::parse("status").is_err());
+        assert!(RowPredicate::parse("= x").is_err());
+    }
+
+    #[test]
+    fn a_record_must_pass_every_predicate() {
+        let predicates = [
+            RowPredicate::parse("status != Sold").unwrap(),
+            RowPredicate::parse("desk = rates").unwrap(),
+        ];
+        let record: OwnedRecord = ["A", "rates", "Active"]
+            .iter()
+            .map(|cell| cell.as_bytes())
+            .collect();
+        assert!(row_passes(&predicates, &[2, 1], &record));
+        assert!(row_passes(&predicates, &[1, 1], &record));
+        assert!(!row_passes(&predicates, &[2, 0], &record));
+    }
+}
@@ -0,0 +1,384 @@
+//! Verdict assertions (`rvl assert`).
+//!
+//! `rvl assert old.csv new.csv --expect NO_REAL_CHANGE` runs the comparison
+//! and checks its verdict, so a data pipeline can encode "this transform must
+//! be value-preserving" as a test. `--expect` names an outcome
+//! (`NO_REAL_CHANGE`, `REAL_CHANGE`, `REFUSAL`) or a refusal code (`E_*`).
+//! `--expect-json` takes a saved `rvl --json` result and checks every leaf of
+//! its verdict fields (outcome, refusal code, counts, metrics, contributors);
+//! file paths and receipts are not compared, so the result can be replayed
+//! against copies of the inputs elsewhere. Numbers match to within one part
+//! in 10^12, so a saved result always re-reads equal.
+//!
+//! Exit codes: 0 = the verdict matches, 1 = it does not (the differences are
+//! reported), 2 = the assertion could not run. Assertions record no witness
+//! entries.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::args::Args;
+use crate::cli::exit::Outcome;
+use crate::orchestrator;
+use crate::refusal::codes::RefusalCode;
+use crate::refusal::process::{PipelineError, ProcessError};
+
+const ASSERT_SCHEMA_VERSION: &str = "rvl.assert.v0";
+
+/// JSON pointers of the `rvl.v0` fields that make up a verdict, with the
+/// path they are reported under.
+const VERDICT_FIELDS: [(&str, &str); 5] = [
+    ("/outcome", "outcome"),
+    ("/refusal/code", "refusal.code"),
+    ("/counts", "counts"),
+    ("/metrics", "metrics"),
+    ("/contributors", "contributors"),
+];
+
+const NUMBER_TOLERANCE: f64 = 1e-12;
+
+/// Differences listed in human output; `--json` lists all of them.
+const MAX_HUMAN_DIFFERENCES: usize = 20;
+
+/// The verdict named by `--expect`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedVerdict {
+    Outcome(Outcome),
+    Refusal(RefusalCode),
+}
+
+impl ExpectedVerdict {
+    /// Parse an outcome or refusal code; case-insensitive, `-` for `_`.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let normalized = raw.trim().to_ascii_uppercase().replace('-', "_");
+        match normalized.as_str() {
+            "NO_REAL_CHANGE" => Ok(Self::Outcome(Outcome::NoRealChange)),
+            "REAL_CHANGE" => Ok(Self::Outcome(Outcome::RealChange)),
+            "REFUSAL" => Ok(Self::Outcome(Outcome::Refusal)),
+            code => code.parse().map(Self::Refusal).map_err(|_| {
+                "verdict must be NO_REAL_CHANGE, REAL_CHANGE, REFUSAL, or an E_* code".to_string()
+            }),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Outcome(outcome) => outcome.as_str(),
+            Self::Refusal(code) => code.as_str(),
+        }
+    }
+
+    fn differences(self, outcome: Outcome, refusal: Option<RefusalCode>) -> Vec<Difference> {
+        let mut differences = Vec::new();
+        let expected_outcome = match self {
+            Self::Outcome(outcome) => outcome,
+            Self::Refusal(_) => Outcome::Refusal,
+        };
+        if expected_outcome != outcome {
+            differences.push(Difference {
+                path: "outcome".to_string(),
+                expected: Value::from(expected_outcome.as_str()),
+                actual: Value::from(outcome.as_str()),
+            });
+        }
+        if let Self::Refusal(code) = self
+            && outcome == Outcome::Refusal
+            && refusal != Some(code)
+        {
+            differences.push(Difference {
+                path: "refusal.code".to_string(),
+                expected: Value::from(code.as_str()),
+                actual: refusal.map_or(Value::Null, |actual| Value::from(actual.as_str())),
+            });
+        }
+        differences
+    }
+}
+
+/// One verdict leaf that differs; `null` stands for an absent value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Difference {
+    pub path: String,
+    pub expected: Value,
+    pub actual: Value,
+}
+
+enum Expectation {
+    Verdict(ExpectedVerdict),
+    /// A saved `rvl.v0` result (`--expect-json`).
+    Result(Value),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssertReport {
+    pub version: &'static str,
+    pub passed: bool,
+    /// Expected outcome, or refusal code when a refusal is expected.
+    pub expected: String,
+    /// The `--expect-json` file the expectation was read from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_file: Option<String>,
+    /// Actual outcome, or refusal code when the comparison refused.
+    pub actual: String,
+    pub differences: Vec<Difference>,
+    /// The comparison's full `rvl.v0` result.
+    pub result: Value,
+}
+
+/// Run `rvl assert` and print its report.
+pub fn run(args: &Args) -> Result<u8, PipelineError> {
+    let report = evaluate(args)?;
+    if args.json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(if report.passed { 0 } else { 1 })
+}
+
+/// Run the comparison and check it against `--expect` or `--expect-json`.
+pub fn evaluate(args: &Args) -> Result<AssertReport, PipelineError> {
+    let expectation = match (args.expect_json.as_deref(), args.expect) {
+        (Some(path), _) => Expectation::Result(load_expected(path)?),
+        (None, Some(verdict)) => Expectation::Verdict(verdict),
+        (None, None) => {
+            return Err(ProcessError::new(
+                "rvl assert needs --expect <VERDICT> or --expect-json <RESULT.json>",
+            )
+            .into());
+        }
+    };
+
+    let mut run_args = args.clone();
+    run_args.json = true;
+    run_args.format = None;
+    let result = orchestrator::run(&run_args)?;
+    let actual: Value = serde_json::from_str(&result.output)?;
+
+    let (expected, differences) = match &expectation {
+        Expectation::Verdict(verdict) => (
+            verdict.as_str().to_string(),
+            verdict.differences(result.outcome, result.refusal),
+        ),
+        Expectation::Result(expected) => (
+            verdict_label(expected),
+            verdict_differences(expected, &actual),
+        ),
+    };
+    Ok(AssertReport {
+        version: ASSERT_SCHEMA_VERSION,
+        passed: differences.is_empty(),
+        expected,
+        expected_file: args
+            .expect_json
+            .as_deref()
+            .map(|path| path.to_string_lossy().to_string()),
+        actual: verdict_label(&actual),
+        differences,
+        result: actual,
+    })
+}
+
+fn load_expected(path: &Path) -> Result<Value, PipelineError> {
+    let text = fs::read_to_string(path).map_err(|err| {
+        ProcessError::new(format!(
+            "cannot read --expect-json {}: {err}",
+            path.display()
+        ))
+    })?;
+    let value: Value = serde_json::from_str(&text).map_err(|_| {
+        ProcessError::new(format!(
+            "--expect-json {} is not a JSON document",
+            path.display()
+        ))
+    })?;
+    if value.get("version").and_then(Value::as_str) != Some("rvl.v0")
+        || value.get("outcome").and_then(Value::as_str).is_none()
+    {
+        return Err(ProcessError::new(format!(
+            "--expect-json {} is not an rvl.v0 JSON result",
+            path.display()
+        ))
+        .into());
+    }
+    Ok(value)
+}
+
+/// The outcome of an `rvl.v0` result, or its refusal code when it refused.
+fn verdict_label(result: &Value) -> String {
+    result
+        .pointer("/refusal/code")
+        .and_then(Value::as_str)
+        .or_else(|| result.get("outcome").and_then(Value::as_str))
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Compare the verdict fields of two `rvl.v0` results leaf by leaf.
+pub fn verdict_differences(expected: &Value, actual: &Value) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    for (pointer, path) in VERDICT_FIELDS {
+        diff_values(
+            path.to_string(),
+            expected.pointer(pointer),
+            actual.pointer(pointer),
+            &mut differences,
+        );
+    }
+    differences
+}
+
+fn diff_values(
+    path: String,
+    expected: Option<&Value>,
+    actual: Option<&Value>,
+    differences: &mut Vec<Difference>,
+) {
+    match (expected, actual) {
+        (Some(Value::Object(expected)), Some(Value::Object(actual))) => {
+            let keys: BTreeSet<&String> = expected.keys().chain(actual.keys()).collect();
+            for key in keys {
+                diff_values(
+                    format!("{path}.{key}"),
+                    expected.get(key),
+                    actual.get(key),
+                    differences,
+                );
+            }
+        }
+        (Some(Value::Array(expected)), Some(Value::Array(actual))) => {
+            for idx in 0..expected.len().max(actual.len()) {
+                diff_values(
+                    format!("{path}[{idx}]"),
+                    expected.get(idx),
+                    actual.get(idx),
+                    differences,
+                );
+            }
+        }
+        _ if leaves_match(expected, actual) => {}
+        _ => differences.push(Difference {
+            path,
+            expected: expected.cloned().unwrap_or(Value::Null),
+            actual: actual.cloned().unwrap_or(Value::Null),
+        }),
+    }
+}
+
+fn leaves_match(expected: Option<&Value>, actual: Option<&Value>) -> bool {
+    match (expected, actual) {
+        (Some(Value::Number(expected)), Some(Value::Number(actual))) => {
+            match (expected.as_f64(), actual.as_f64()) {
+                (Some(expected), Some(actual)) => {
+                    (expected - actual).abs() <= NUMBER_TOLERANCE * expected.abs().max(actual.abs())
+                }
+                _ => expected == actual,
+            }
+        }
+        (None | Some(Value::Null), None | Some(Value::Null)) => true,
+        _ => expected == actual,
+    }
+}
+
+fn render_human(report: &AssertReport) -> String {
+    let status = if report.passed { "PASS" } else { "FAIL" };
+    let expected = match report.expected_file.as_deref() {
+        Some(file) => format!("{} (from {file})", report.expected),
+        None => report.expected.clone(),
+    };
+    let mut lines = vec![format!(
+        "RVL ASSERT {status}: expected {expected}, got {}",
+        report.actual
+    )];
+    if report.differences.is_empty() {
+        return lines.join("\n");
+    }
+    lines.push(String::new());
+    for difference in report.differences.iter().take(MAX_HUMAN_DIFFERENCES) {
+        lines.push(format!(
+            "  {}: expected {}, got {}",
+            difference.path,
+            render_value(&difference.expected),
+            render_value(&difference.actual)
+        ));
+    }
+    if report.differences.len() > MAX_HUMAN_DIFFERENCES {
+        lines.push(format!(
+            "  ... and {} more (--json lists all)",
+            report.differences.len() - MAX_HUMAN_DIFFERENCES
+        ));
+    }
+    lines.join("\n")
+}
+
+fn render_value(value: &Value) -> String {
+    match value {
+        Value::Null => "(absent)".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn expected_verdicts_parse_outcomes_and_codes() {
+        assert_eq!(
+            ExpectedVerdict::parse("no-real-change"),
+            Ok(ExpectedVerdict::Outcome(Outcome::NoRealChange))
+        );
+        assert_eq!(
+            ExpectedVerdict::parse("E_KEY_DUP"),
+            Ok(ExpectedVerdict::Refusal(RefusalCode::KeyDup))
+        );
+        assert!(ExpectedVerdict::parse("SAME").is_err());
+    }
+
+    #[test]
+    fn refusal_code_expectation_checks_the_code() {
+        let expect = ExpectedVerdict::Refusal(RefusalCode::KeyDup);
+        assert!(
+            expect
+                .differences(Outcome::Refusal, Some(RefusalCode::KeyDup))
+                .is_empty()
+        );
+        let differences = expect.differences(Outcome::Refusal, Some(RefusalCode::NoKey));
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].path, "refusal.code");
+        let differences = expect.differences(Outcome::NoRealChange, None);
+        assert_eq!(differences[0].path, "outcome");
+    }
+
+    #[test]
+    fn verdict_leaves_are_compared_and_paths_ignored() {
+        let expected = json!({
+            "version": "rvl.v0",
+            "outcome": "REAL_CHANGE",
+            "files": { "old": "a/old.csv", "new": "a/new.csv" },
+            "counts": { "rows_old": 2, "rows_new": 2 },
+            "metrics": { "total_change": 30.0 },
+            "contributors": [{ "row_id": "B", "delta": -30.0 }]
+        });
+        let mut actual = expected.clone();
+        actual["files"]["old"] = json!("b/old.csv");
+        actual["metrics"]["total_change"] = json!(30.000000000000004);
+        assert!(verdict_differences(&expected, &actual).is_empty());
+
+        actual["contributors"][0]["delta"] = json!(-20.0);
+        actual["counts"]["comment_lines_old"] = json!(1);
+        let paths: Vec<String> = verdict_differences(&expected, &actual)
+            .into_iter()
+            .map(|difference| difference.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["counts.comment_lines_old", "contributors[0].delta"]
+        );
+    }
+}
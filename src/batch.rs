@@ -0,0 +1,204 @@
+//! Directory-of-pairs comparison (`rvl batch`).
+//!
+//! Pairs are discovered exactly as for `rvl calibrate`: every immediate
+//! subdirectory holding an `old.csv` and a `new.csv`. Each pair runs through
+//! the regular comparison pipeline with the given settings. The batch verdict
+//! is the most severe pair verdict: any refusal, then any real change,
+//! otherwise no real change.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::calibrate::{CalibrationPair, discover_pairs};
+use crate::cli::args::{Args, BatchArgs};
+use crate::cli::exit::{Outcome, exit_code};
+use crate::refusal::process::PipelineError;
+
+const BATCH_SCHEMA_VERSION: &str = "rvl.batch.v0";
+
+/// Verdict for one pair.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchPair {
+    pub name: String,
+    pub outcome: &'static str,
+    pub refusal_code: Option<String>,
+    /// The pair's full `rvl.v0` report.
+    pub result: Value,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BatchCounts {
+    pub real_change: usize,
+    pub no_real_change: usize,
+    pub refusal: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReport {
+    pub version: &'static str,
+    pub outcome: &'static str,
+    pub directory: String,
+    pub counts: BatchCounts,
+    pub pairs: Vec<BatchPair>,
+    #[serde(skip)]
+    pub verdict: Outcome,
+}
+
+/// Run `rvl batch`. Exit codes follow the batch verdict (0/1/2); an
+/// unreadable directory or one without pairs also exits 2.
+pub fn run(args: &BatchArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let pairs = match discover_pairs(&args.dir) {
+        Ok(pairs) => pairs,
+        Err(err) => {
+            eprintln!("rvl: cannot read {}: {err}", args.dir.display());
+            return Ok(2);
+        }
+    };
+    if pairs.is_empty() {
+        eprintln!(
+            "rvl: no pairs in {} (expected <pair>/old.csv and <pair>/new.csv)",
+            args.dir.display()
+        );
+        return Ok(2);
+    }
+
+    let report = compare(args, &pairs)?;
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(exit_code(report.verdict))
+}
+
+/// Compare every pair and fold the verdicts.
+pub fn compare(args: &BatchArgs, pairs: &[CalibrationPair]) -> Result<BatchReport, PipelineError> {
+    let mut results = Vec::with_capacity(pairs.len());
+    let mut counts = BatchCounts::default();
+    let mut verdict = Outcome::NoRealChange;
+    for pair in pairs {
+        let compare_args = Args::new(
+            pair.old.clone(),
+            pair.new.clone(),
+            args.key.clone(),
+            args.threshold,
+            args.tolerance,
+            args.delimiter,
+            true,
+        );
+        let result = crate::orchestrator::run(&compare_args)?;
+        let outcome = result.outcome;
+        let report = result.into_report()?;
+        match outcome {
+            Outcome::RealChange => counts.real_change += 1,
+            Outcome::NoRealChange => counts.no_real_change += 1,
+            Outcome::Refusal => counts.refusal += 1,
+        }
+        if exit_code(outcome) > exit_code(verdict) {
+            verdict = outcome;
+        }
+        results.push(BatchPair {
+            name: pair.name.clone(),
+            outcome: outcome.as_str(),
+            refusal_code: report.refusal.as_ref().map(|refusal| refusal.code.clone()),
+            result: serde_json::to_value(&report)?,
+        });
+    }
+
+    Ok(BatchReport {
+        version: BATCH_SCHEMA_VERSION,
+        outcome: verdict.as_str(),
+        directory: args.dir.to_string_lossy().to_string(),
+        counts,
+        pairs: results,
+        verdict,
+    })
+}
+
+fn render_human(report: &BatchReport) -> String {
+    let mut lines = vec![
+        "RVL BATCH".to_string(),
+        String::new(),
+        format!("Directory: {}", report.directory),
+        format!(
+            "Pairs: {} ({} real change, {} no real change, {} refused)",
+            report.pairs.len(),
+            report.counts.real_change,
+            report.counts.no_real_change,
+            report.counts.refusal
+        ),
+        format!("Verdict: {}", report.outcome.replace('_', " ")),
+        String::new(),
+    ];
+    for pair in &report.pairs {
+        match pair.refusal_code.as_deref() {
+            Some(code) => lines.push(format!("{}  REFUSAL ({code})", pair.name)),
+            None => lines.push(format!("{}  {}", pair.name, pair.outcome.replace('_', " "))),
+        }
+    }
+    if report.counts.refusal > 0 {
+        lines.push(String::new());
+        lines.push(
+            "Next: rerun a refused pair as `rvl <pair>/old.csv <pair>/new.csv` for its refusal detail."
+                .to_string(),
+        );
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("rvl_batch_{label}_{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_pair(dir: &Path, name: &str, old: &str, new: &str) {
+        let pair = dir.join(name);
+        fs::create_dir_all(&pair).unwrap();
+        fs::write(pair.join("old.csv"), old).unwrap();
+        fs::write(pair.join("new.csv"), new).unwrap();
+    }
+
+    fn batch_args(dir: &Path) -> BatchArgs {
+        BatchArgs {
+            dir: dir.to_path_buf(),
+            key: Some("id".to_string()),
+            threshold: 0.95,
+            tolerance: 1e-9,
+            delimiter: None,
+            json: true,
+        }
+    }
+
+    #[test]
+    fn batch_verdict_is_the_most_severe_pair_verdict() {
+        let dir = temp_dir("severity");
+        write_pair(&dir, "a", "id,v\n1,1\n", "id,v\n1,1\n");
+        write_pair(&dir, "b", "id,v\n1,1\n", "id,v\n1,5\n");
+
+        let args = batch_args(&dir);
+        let report = compare(&args, &discover_pairs(&dir).unwrap()).unwrap();
+        assert_eq!(report.pairs[0].outcome, "NO_REAL_CHANGE");
+        assert_eq!(report.pairs[1].outcome, "REAL_CHANGE");
+        assert_eq!(report.outcome, "REAL_CHANGE");
+        assert_eq!(report.counts.real_change, 1);
+
+        write_pair(&dir, "c", "id,v\n1,1\n", "id,v\n1,x\n2,2\n");
+        let report = compare(&args, &discover_pairs(&dir).unwrap()).unwrap();
+        assert_eq!(report.outcome, "REFUSAL");
+        assert!(report.pairs[2].refusal_code.is_some());
+        assert_eq!(exit_code(report.verdict), 2);
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,411 @@
+//! Pipeline benchmark over real inputs (`rvl bench`).
+//!
+//! Unlike the parser-only bakeoff harness, every timed iteration runs the full
+//! comparison pipeline exactly as the CLI does (minus witness recording), so
+//! rows/sec and MB/sec reflect what users see. Per-stage timings come from the
+//! pipeline itself. Peak RSS is read from `/proc/self/status` (Linux only) and
+//! reset between pairs where the kernel allows it.
+//!
+//! With `--against <rvl>`, each pair is also run once through this binary and
+//! the other one with `--json`, and the exit code and stdout must match byte
+//! for byte. That is the release check for "faster, and still the same
+//! answers".
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+use crate::calibrate::{CalibrationPair, discover_pairs};
+use crate::cli::args::{Args, BenchArgs, DEFAULT_THRESHOLD, DEFAULT_TOLERANCE};
+use crate::orchestrator::StageTimings;
+use crate::refusal::process::{PipelineError, ProcessError};
+
+const BENCH_SCHEMA_VERSION: &str = "rvl.bench.v0";
+
+/// Mean per-stage wall-clock milliseconds across the timed iterations.
+#[derive(Debug, Clone, Serialize)]
+pub struct StageMeans {
+    pub setup_ms: f64,
+    pub parse_ms: f64,
+    pub diff_ms: f64,
+}
+
+/// Output identity between this binary and the `--against` binary.
+#[derive(Debug, Clone, Serialize)]
+pub struct Identity {
+    pub identical: bool,
+    pub exit_code: Option<i32>,
+    pub against_exit_code: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PairBench {
+    pub name: String,
+    pub old: String,
+    pub new: String,
+    pub outcome: String,
+    /// Input bytes on disk, old + new.
+    pub bytes: u64,
+    /// Data rows parsed, old + new (zero when the run refuses before parsing).
+    pub rows: u64,
+    pub mean_ms: f64,
+    pub min_ms: f64,
+    pub rows_per_sec: f64,
+    pub mb_per_sec: f64,
+    pub peak_rss_bytes: Option<u64>,
+    pub stages: StageMeans,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity: Option<Identity>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub version: &'static str,
+    pub iterations: u64,
+    pub warmup: u64,
+    pub against: Option<String>,
+    pub pairs: Vec<PairBench>,
+}
+
+impl BenchReport {
+    fn exit_code(&self) -> u8 {
+        let differs = self
+            .pairs
+            .iter()
+            .any(|pair| pair.identity.as_ref().is_some_and(|id| !id.identical));
+        if differs { 1 } else { 0 }
+    }
+}
+
+/// Run `rvl bench`. Exit codes: 0 = benchmark complete (and identical output
+/// when `--against` is given), 1 = output differs from the `--against`
+/// binary, 2 = unreadable inputs or an `--against` binary that cannot run.
+pub fn run(args: &BenchArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let pairs = match bench_pairs(&args.inputs) {
+        Ok(pairs) => pairs,
+        Err(err) => {
+            eprintln!("rvl: {err}");
+            return Ok(2);
+        }
+    };
+
+    let report = match bench(args, &pairs) {
+        Ok(report) => report,
+        Err(BenchError::Pipeline(err)) => return Err(err),
+        Err(BenchError::Against(err)) => {
+            eprintln!("rvl: {err}");
+            return Ok(2);
+        }
+    };
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(report.exit_code())
+}
+
+/// Two paths are one pair; a single directory holds calibration-style pairs.
+fn bench_pairs(inputs: &[PathBuf]) -> Result<Vec<CalibrationPair>, String> {
+    match inputs {
+        [old, new] => {
+            for path in [old, new] {
+                if !path.is_file() {
+                    return Err(format!("cannot read {}: not a file", path.display()));
+                }
+            }
+            Ok(vec![CalibrationPair {
+                name: format!("{} -> {}", file_name(old), file_name(new)),
+                old: old.clone(),
+                new: new.clone(),
+            }])
+        }
+        [dir] => {
+            let pairs = discover_pairs(dir)
+                .map_err(|err| format!("cannot read {}: {err}", dir.display()))?;
+            if pairs.is_empty() {
+                return Err(format!(
+                    "no bench pairs in {} (expected <pair>/old.csv and <pair>/new.csv)",
+                    dir.display()
+                ));
+            }
+            Ok(pairs)
+        }
+        _ => Err("bench takes <old.csv> <new.csv> or one directory of pairs".to_string()),
+    }
+}
+
+#[derive(Debug)]
+enum BenchError {
+    Pipeline(PipelineError),
+    Against(String),
+}
+
+impl From<PipelineError> for BenchError {
+    fn from(err: PipelineError) -> Self {
+        BenchError::Pipeline(err)
+    }
+}
+
+fn bench(args: &BenchArgs, pairs: &[CalibrationPair]) -> Result<BenchReport, BenchError> {
+    let mut results = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let mut result = bench_pair(args, pair)?;
+        if let Some(against) = args.against.as_deref() {
+            result.identity = Some(check_identity(args, pair, against)?);
+        }
+        results.push(result);
+    }
+    Ok(BenchReport {
+        version: BENCH_SCHEMA_VERSION,
+        iterations: args.iterations,
+        warmup: args.warmup,
+        against: args
+            .against
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
+        pairs: results,
+    })
+}
+
+fn bench_pair(args: &BenchArgs, pair: &CalibrationPair) -> Result<PairBench, PipelineError> {
+    let compare_args = Args::new(
+        pair.old.clone(),
+        pair.new.clone(),
+        args.key.clone(),
+        DEFAULT_THRESHOLD,
+        DEFAULT_TOLERANCE,
+        args.delimiter,
+        true,
+    );
+
+    reset_peak_rss();
+    for _ in 0..args.warmup {
+        crate::orchestrator::run(&compare_args)?;
+    }
+    let mut totals = StageTimings::default();
+    let mut min_ms = f64::INFINITY;
+    let mut last = None;
+    for _ in 0..args.iterations {
+        let result = crate::orchestrator::run(&compare_args)?;
+        totals.setup_ms += result.timings.setup_ms;
+        totals.parse_ms += result.timings.parse_ms;
+        totals.diff_ms += result.timings.diff_ms;
+        min_ms = min_ms.min(result.timings.total_ms());
+        last = Some(result);
+    }
+    let peak_rss_bytes = peak_rss_bytes();
+
+    let runs = args.iterations as f64;
+    let mean_ms = totals.total_ms() / runs;
+    let report = last
+        .ok_or_else(|| ProcessError::new("--iterations must be at least 1"))?
+        .into_report()?;
+    let rows = report.counts.rows_old.unwrap_or(0) + report.counts.rows_new.unwrap_or(0);
+    let bytes = file_len(&pair.old) + file_len(&pair.new);
+    let per_sec = |amount: f64| {
+        if mean_ms > 0.0 {
+            amount / (mean_ms / 1000.0)
+        } else {
+            0.0
+        }
+    };
+
+    Ok(PairBench {
+        name: pair.name.clone(),
+        old: pair.old.to_string_lossy().to_string(),
+        new: pair.new.to_string_lossy().to_string(),
+        outcome: report.outcome.as_str().to_string(),
+        bytes,
+        rows,
+        mean_ms,
+        min_ms,
+        rows_per_sec: per_sec(rows as f64),
+        mb_per_sec: per_sec(bytes as f64 / 1_000_000.0),
+        peak_rss_bytes,
+        stages: StageMeans {
+            setup_ms: totals.setup_ms / runs,
+            parse_ms: totals.parse_ms / runs,
+            diff_ms: totals.diff_ms / runs,
+        },
+        identity: None,
+    })
+}
+
+/// Run the pair through this binary and `against` with `--json` and compare
+/// exit codes and stdout bytes.
+fn check_identity(
+    args: &BenchArgs,
+    pair: &CalibrationPair,
+    against: &Path,
+) -> Result<Identity, BenchError> {
+    let this = std::env::current_exe()
+        .map_err(|err| BenchError::Against(format!("cannot locate rvl binary: {err}")))?;
+    let ours = run_binary(&this, args, pair)?;
+    let theirs = run_binary(against, args, pair)?;
+    Ok(Identity {
+        identical: ours == theirs,
+        exit_code: ours.0,
+        against_exit_code: theirs.0,
+    })
+}
+
+fn run_binary(
+    binary: &Path,
+    args: &BenchArgs,
+    pair: &CalibrationPair,
+) -> Result<(Option<i32>, Vec<u8>), BenchError> {
+    let mut command = Command::new(binary);
+    command
+        .arg(&pair.old)
+        .arg(&pair.new)
+        .args(["--json", "--no-witness"]);
+    if let Some(key) = args.key.as_deref() {
+        command.args(["--key", key]);
+    }
+    if let Some(delimiter) = args.delimiter {
+        command.arg("--delimiter").arg(format!("0x{delimiter:02x}"));
+    }
+    let output = command
+        .output()
+        .map_err(|err| BenchError::Against(format!("cannot run {}: {err}", binary.display())))?;
+    Ok((output.status.code(), output.stdout))
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn file_len(path: &Path) -> u64 {
+    fs::metadata(path).map(|meta| meta.len()).unwrap_or(0)
+}
+
+/// Peak resident set size of this process (`VmHWM`), when the OS reports it.
+fn peak_rss_bytes() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+/// Reset the peak RSS mark so each pair reports its own peak. Best effort: on
+/// kernels or platforms without `clear_refs` the mark stays process-wide.
+fn reset_peak_rss() {
+    let _ = fs::write("/proc/self/clear_refs", "5");
+}
+
+fn render_human(report: &BenchReport) -> String {
+    let mut lines = vec![
+        "RVL BENCH".to_string(),
+        String::new(),
+        format!(
+            "Iterations: {} (warmup {})",
+            report.iterations, report.warmup
+        ),
+    ];
+    if let Some(against) = report.against.as_deref() {
+        lines.push(format!("Against: {against}"));
+    }
+    for pair in &report.pairs {
+        lines.push(String::new());
+        lines.push(format!("{}  {}", pair.name, pair.outcome.replace('_', " ")));
+        lines.push(format!(
+            "  {:.3} ms mean, {:.3} ms min  |  {} rows, {:.0} rows/sec  |  {:.2} MB/sec",
+            pair.mean_ms, pair.min_ms, pair.rows, pair.rows_per_sec, pair.mb_per_sec
+        ));
+        lines.push(format!(
+            "  stages: setup {:.3} ms, parse {:.3} ms, diff {:.3} ms",
+            pair.stages.setup_ms, pair.stages.parse_ms, pair.stages.diff_ms
+        ));
+        match pair.peak_rss_bytes {
+            Some(bytes) => lines.push(format!("  peak RSS: {:.1} MB", bytes as f64 / 1_000_000.0)),
+            None => lines.push("  peak RSS: unavailable on this platform".to_string()),
+        }
+        if let Some(identity) = &pair.identity {
+            if identity.identical {
+                lines.push("  output: identical".to_string());
+            } else {
+                lines.push(format!(
+                    "  output: DIFFERS (exit {} vs {})",
+                    exit_label(identity.exit_code),
+                    exit_label(identity.against_exit_code)
+                ));
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+fn exit_label(code: Option<i32>) -> String {
+    code.map_or_else(|| "signal".to_string(), |code| code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("rvl_bench_{label}_{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn bench_args(inputs: Vec<PathBuf>) -> BenchArgs {
+        BenchArgs {
+            inputs,
+            key: Some("id".to_string()),
+            delimiter: None,
+            iterations: 2,
+            warmup: 0,
+            against: None,
+            json: true,
+        }
+    }
+
+    #[test]
+    fn benches_a_file_pair_through_the_full_pipeline() {
+        let dir = temp_dir("pair");
+        let old = dir.join("old.csv");
+        let new = dir.join("new.csv");
+        fs::write(&old, "id,v\nA,1\nB,2\n").unwrap();
+        fs::write(&new, "id,v\nA,1\nB,5\n").unwrap();
+
+        let args = bench_args(vec![old, new]);
+        let pairs = bench_pairs(&args.inputs).unwrap();
+        let report = bench(&args, &pairs).unwrap();
+        let pair = &report.pairs[0];
+        assert_eq!(pair.name, "old.csv -> new.csv");
+        assert_eq!(pair.outcome, "REAL_CHANGE");
+        assert_eq!(pair.rows, 4);
+        assert_eq!(pair.bytes, 26);
+        assert!(pair.min_ms <= pair.mean_ms);
+        assert!(pair.stages.parse_ms > 0.0);
+        assert_eq!(report.exit_code(), 0);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn rejects_a_missing_input() {
+        let err = bench_pairs(&[
+            PathBuf::from("missing_old.csv"),
+            PathBuf::from("missing_new.csv"),
+        ])
+        .unwrap_err();
+        assert!(err.contains("missing_old.csv"), "{err}");
+    }
+}
@@ -0,0 +1,279 @@
+//! Tolerance calibration over accepted file pairs (`rvl calibrate`).
+//!
+//! Each immediate subdirectory of the calibration directory that contains an
+//! `old.csv` and a `new.csv` is one historical pair that was accepted as
+//! "no real change" (capsule directories written by `--capsule-out` already
+//! follow this layout). Every pair is compared with a zero tolerance so the
+//! raw `max_abs_delta` is observed, and the recommended tolerance is the
+//! largest of those deltas: the smallest noise floor that would have
+//! classified every pair as NO REAL CHANGE.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::cli::args::{Args, CalibrateArgs};
+use crate::format::numbers::format_float_shortest;
+use crate::refusal::process::PipelineError;
+
+const CALIBRATE_SCHEMA_VERSION: &str = "rvl.calibrate.v0";
+const PAIR_OLD_NAME: &str = "old.csv";
+const PAIR_NEW_NAME: &str = "new.csv";
+
+/// One accepted old/new pair discovered in the calibration directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationPair {
+    pub name: String,
+    pub old: PathBuf,
+    pub new: PathBuf,
+}
+
+/// Comparison result for a single pair at zero tolerance.
+#[derive(Debug, Clone, Serialize)]
+pub struct PairResult {
+    pub name: String,
+    pub outcome: String,
+    pub refusal_code: Option<String>,
+    pub max_abs_delta: Option<f64>,
+}
+
+impl PairResult {
+    fn calibrated(&self) -> bool {
+        self.max_abs_delta.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationReport {
+    pub version: &'static str,
+    pub directory: String,
+    pub pairs_total: usize,
+    pub pairs_calibrated: usize,
+    pub pairs_refused: usize,
+    pub recommended_tolerance: Option<f64>,
+    pub recommended_threshold: f64,
+    pub pairs: Vec<PairResult>,
+}
+
+impl CalibrationReport {
+    fn exit_code(&self) -> u8 {
+        if self.pairs_refused == 0 { 0 } else { 1 }
+    }
+}
+
+/// Run `rvl calibrate`. Exit codes: 0 = every pair calibrated, 1 = some pairs
+/// refused, 2 = unreadable directory or no pairs found.
+pub fn run(args: &CalibrateArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let pairs = match discover_pairs(&args.dir) {
+        Ok(pairs) => pairs,
+        Err(err) => {
+            eprintln!("rvl: cannot read {}: {err}", args.dir.display());
+            return Ok(2);
+        }
+    };
+    if pairs.is_empty() {
+        eprintln!(
+            "rvl: no calibration pairs in {} (expected <pair>/{PAIR_OLD_NAME} and <pair>/{PAIR_NEW_NAME})",
+            args.dir.display()
+        );
+        return Ok(2);
+    }
+
+    let report = calibrate(args, &pairs)?;
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(report.exit_code())
+}
+
+/// Discover `<dir>/<pair>/old.csv` + `<dir>/<pair>/new.csv` pairs, sorted by name.
+pub fn discover_pairs(dir: &Path) -> io::Result<Vec<CalibrationPair>> {
+    let mut pairs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let old = path.join(PAIR_OLD_NAME);
+        let new = path.join(PAIR_NEW_NAME);
+        if !old.is_file() || !new.is_file() {
+            continue;
+        }
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        pairs.push(CalibrationPair { name, old, new });
+    }
+    pairs.sort_by(|left, right| left.name.cmp(&right.name));
+    Ok(pairs)
+}
+
+/// Compare every pair at zero tolerance and derive the recommended settings.
+pub fn calibrate(
+    args: &CalibrateArgs,
+    pairs: &[CalibrationPair],
+) -> Result<CalibrationReport, PipelineError> {
+    let mut results = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        let compare_args = Args::new(
+            pair.old.clone(),
+            pair.new.clone(),
+            args.key.clone(),
+            args.threshold,
+            0.0,
+            args.delimiter,
+            true,
+        );
+        let result = crate::orchestrator::run(&compare_args)?;
+        let report = result.into_report()?;
+        results.push(PairResult {
+            name: pair.name.clone(),
+            outcome: report.outcome.as_str().to_string(),
+            refusal_code: report.refusal.map(|refusal| refusal.code),
+            max_abs_delta: report.metrics.max_abs_delta,
+        });
+    }
+
+    let pairs_calibrated = results.iter().filter(|pair| pair.calibrated()).count();
+    let recommended_tolerance = results
+        .iter()
+        .filter_map(|pair| pair.max_abs_delta)
+        .reduce(f64::max);
+
+    Ok(CalibrationReport {
+        version: CALIBRATE_SCHEMA_VERSION,
+        directory: args.dir.to_string_lossy().to_string(),
+        pairs_total: results.len(),
+        pairs_calibrated,
+        pairs_refused: results.len() - pairs_calibrated,
+        recommended_tolerance,
+        recommended_threshold: args.threshold,
+        pairs: results,
+    })
+}
+
+fn render_human(report: &CalibrationReport) -> String {
+    let mut lines = vec![
+        "RVL CALIBRATE".to_string(),
+        String::new(),
+        format!("Directory: {}", report.directory),
+        format!(
+            "Pairs: {} ({} calibrated, {} refused)",
+            report.pairs_total, report.pairs_calibrated, report.pairs_refused
+        ),
+        String::new(),
+    ];
+    for pair in &report.pairs {
+        match (pair.max_abs_delta, pair.refusal_code.as_deref()) {
+            (Some(delta), _) => lines.push(format!(
+                "{}  max abs delta {}",
+                pair.name,
+                format_float_shortest(delta)
+            )),
+            (None, Some(code)) => lines.push(format!("{}  refused ({code})", pair.name)),
+            (None, None) => lines.push(format!("{}  {}", pair.name, pair.outcome)),
+        }
+    }
+    lines.push(String::new());
+    match report.recommended_tolerance {
+        Some(tolerance) => {
+            lines.push(format!(
+                "Recommended: --tolerance {} --threshold {}",
+                format_float_shortest(tolerance),
+                format_float_shortest(report.recommended_threshold)
+            ));
+            lines.push(
+                "Every calibrated pair is NO REAL CHANGE at this tolerance; threshold only applies above it."
+                    .to_string(),
+            );
+        }
+        None => lines.push("Recommended: none (no pair could be calibrated).".to_string()),
+    }
+    if report.pairs_refused > 0 {
+        lines.push(
+            "Next: fix or remove the refused pairs (or pass --key <column>), then rerun rvl calibrate."
+                .to_string(),
+        );
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("rvl_calibrate_{label}_{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_pair(dir: &Path, name: &str, old: &str, new: &str) {
+        let pair = dir.join(name);
+        fs::create_dir_all(&pair).unwrap();
+        fs::write(pair.join(PAIR_OLD_NAME), old).unwrap();
+        fs::write(pair.join(PAIR_NEW_NAME), new).unwrap();
+    }
+
+    fn calibrate_args(dir: &Path) -> CalibrateArgs {
+        CalibrateArgs {
+            dir: dir.to_path_buf(),
+            key: None,
+            threshold: 0.95,
+            delimiter: None,
+            json: true,
+        }
+    }
+
+    #[test]
+    fn discovers_pairs_sorted_and_skips_incomplete_directories() {
+        let dir = temp_dir("discover");
+        write_pair(&dir, "b", "id,v\n1,1\n", "id,v\n1,1\n");
+        write_pair(&dir, "a", "id,v\n1,1\n", "id,v\n1,1\n");
+        fs::create_dir_all(dir.join("incomplete")).unwrap();
+        fs::write(dir.join("incomplete").join(PAIR_OLD_NAME), "id,v\n").unwrap();
+
+        let pairs = discover_pairs(&dir).unwrap();
+        let names: Vec<&str> = pairs.iter().map(|pair| pair.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn recommends_largest_observed_delta() {
+        let dir = temp_dir("recommend");
+        write_pair(&dir, "a", "id,v\n1,1.0\n2,2.0\n", "id,v\n1,1.0005\n2,2.0\n");
+        write_pair(&dir, "b", "id,v\n1,5\n", "id,v\n1,5.002\n");
+
+        let args = calibrate_args(&dir);
+        let report = calibrate(&args, &discover_pairs(&dir).unwrap()).unwrap();
+        assert_eq!(report.pairs_calibrated, 2);
+        assert_eq!(report.pairs_refused, 0);
+        let tolerance = report.recommended_tolerance.expect("tolerance");
+        assert!((tolerance - 0.002).abs() < 1e-9);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn refused_pairs_are_reported_without_tolerance() {
+        let dir = temp_dir("refused");
+        write_pair(&dir, "a", "id,v\n1,1\n", "id,v\n1,1\n2,2\n");
+
+        let args = calibrate_args(&dir);
+        let report = calibrate(&args, &discover_pairs(&dir).unwrap()).unwrap();
+        assert_eq!(report.pairs_refused, 1);
+        assert_eq!(report.pairs[0].refusal_code.as_deref(), Some("E_ROWCOUNT"));
+        assert!(report.recommended_tolerance.is_none());
+        assert_eq!(report.exit_code(), 1);
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,19 +1,52 @@
-use std::path::PathBuf;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 
-use clap::{Parser, Subcommand};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command, CommandFactory, FromArgMatches, Parser, Subcommand};
+use serde_json::Value;
 
+use super::config::{ConfigReceipt, ConfigSetting, ConfigValue, config_path, parse_config};
 use super::delimiter::parse_delimiter_arg;
+use super::exit::StreamPolicy;
+use crate::alignment::key_dedup::DedupMode;
+use crate::alignment::key_normalize::KeyNormalizeStep;
+use crate::alignment::row_filter::RowPredicate;
+use crate::assertion::ExpectedVerdict;
+use crate::csv::comment::parse_comment_prefix;
+use crate::csv::encoding::EncodingMode;
+use crate::csv::footer::FooterSkip;
+use crate::csv::input::SkipRows;
+use crate::csv::source::{InlineInputs, InputSource};
+use crate::demo::DemoScenario;
+use crate::diff::coverage::{CoverageMode, DiffusePolicy};
+use crate::diff::heap::MAX_CONTRIBUTORS;
+use crate::diff::rank::{RankMode, Ranking};
+use crate::diff::tolerance::{ColumnTolerance, DEFAULT_SWEEP_LEVELS, SweepLevels};
+use crate::normalize::column_map::ColumnMapping;
+use crate::normalize::headers::HeaderMatch;
+use crate::numeric::columns::MixedTypesPolicy;
+use crate::numeric::drift::DriftMetric;
+use crate::numeric::missing::MissingPolicy;
+use crate::numeric::parse::{DecimalSeparator, NumberFormat};
+use crate::numeric::transform::ColumnTransform;
+use crate::output::ReportFormat;
+use crate::output::events::EventTarget;
+use crate::output::json::OutputVersion;
 
-const DEFAULT_THRESHOLD: f64 = 0.95;
-const DEFAULT_TOLERANCE: f64 = 1e-9;
+pub const DEFAULT_THRESHOLD: f64 = 0.95;
+pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+pub const DEFAULT_MIN_HEADER_OVERLAP: f64 = 0.5;
 const DEFAULT_MAX_AUDIT_CHANGES: u64 = 10_000;
+/// Upper bound for `--top`; larger ledgers belong to `--exhaustive`.
+pub const MAX_TOP: usize = 1_000;
 
 /// CLI argument parsing & validation (bd-l7j).
 #[derive(Debug, Clone, Parser)]
 #[command(
     name = "rvl",
     about = "Reveal the smallest set of numeric changes that explain what actually changed.",
-    override_usage = "rvl <old.csv> <new.csv> [OPTIONS]\n       rvl --robot-triage\n       rvl capabilities --json\n       rvl robot-docs guide\n       rvl witness <query|last|count> [OPTIONS]\n       rvl doctor <health|capabilities|robot-docs> [OPTIONS]",
+    override_usage = "rvl [compare] <old.csv> <new.csv> [OPTIONS]\n       rvl watch <old.csv> <new.csv> [OPTIONS]\n       rvl assert <old.csv> <new.csv> --expect <VERDICT> [OPTIONS]\n       rvl --base <base.csv> <ours.csv> <theirs.csv> [OPTIONS]\n       rvl batch <DIR> [OPTIONS]\n       rvl inspect <file.csv> [OPTIONS]\n       rvl keys <old.csv> <new.csv> [OPTIONS]\n       rvl snapshot <file.csv> --out <file.rvlsnap>\n       rvl verify <file.csv> <file.rvlsnap>\n       rvl schema [--output json] [--output-version v0]\n       rvl agent-manifest [--format json|yaml]\n       rvl --robot-triage\n       rvl capabilities --json\n       rvl robot-docs guide\n       rvl witness <query|last|count> [OPTIONS]\n       rvl calibrate <DIR> [OPTIONS]\n       rvl xlsx <old.xlsx> <new.xlsx> [OPTIONS]\n       rvl bench <old.csv> <new.csv> | <DIR> [OPTIONS]\n       rvl demo [real-change|no-real-change|refusal] [--json]\n       rvl doctor <health|capabilities|robot-docs> [OPTIONS]",
     subcommand_negates_reqs = true
 )]
 pub struct Args {
@@ -25,16 +58,83 @@ pub struct Args {
     #[arg(value_name = "NEW_CSV")]
     pub new: Option<PathBuf>,
 
+    /// Three-way mode: compare OLD_CSV (ours) and NEW_CSV (theirs) against this common base.
+    #[arg(long, value_name = "BASE_CSV")]
+    pub base: Option<PathBuf>,
+
     /// Align rows by this key column (otherwise align by row order).
-    #[arg(long, value_name = "COLUMN")]
+    #[arg(long, value_name = "COLUMN", env = "RVL_KEY")]
     pub key: Option<String>,
 
+    /// Translate old-file keys via this old_key,new_key CSV before the key join.
+    #[arg(long, value_name = "MAP.csv")]
+    pub key_map: Option<PathBuf>,
+
+    /// Normalize join keys on both sides: trim, lower, strip-leading-zeros (comma-separated, applied in order).
+    #[arg(
+        long,
+        value_name = "STEPS",
+        value_delimiter = ',',
+        value_parser = parse_key_normalize_step
+    )]
+    pub key_normalize: Vec<KeyNormalizeStep>,
+
+    /// Compare old-file column OLD as new-file column NEW (repeatable).
+    #[arg(long = "map", value_name = "OLD=NEW", value_parser = parse_column_mapping)]
+    pub column_map: Vec<ColumnMapping>,
+
+    /// Compare only these columns (comma-separated, repeatable; narrows a profile's scope).
+    #[arg(
+        long,
+        value_name = "NAMES",
+        value_delimiter = ',',
+        value_parser = parse_column_name
+    )]
+    pub columns: Vec<String>,
+
+    /// Header name matching: exact (default) or loose (ignore case, whitespace, BOM remnants).
+    #[arg(long, value_name = "MODE", default_value = "exact", value_parser = parse_header_match)]
+    pub header_match: HeaderMatch,
+
+    /// In key mode, compare the common keys and report added/removed rows instead of refusing.
+    #[arg(long)]
+    pub allow_missing_keys: bool,
+
+    /// Rank added/removed rows as contributors alongside changed cells (implies --allow-missing-keys).
+    #[arg(long)]
+    pub include_entity_changes: bool,
+
+    /// In row-order mode, compare the common prefix when row counts differ by at most N and report the trailing rows.
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub allow_trailing_rows: u64,
+
+    /// Keep only aligned rows where COLUMN = VALUE or COLUMN != VALUE holds on both sides (repeatable; all must hold).
+    #[arg(long = "where", value_name = "PREDICATE", value_parser = parse_row_predicate)]
+    pub where_filters: Vec<RowPredicate>,
+
+    /// In key mode, compare only N aligned rows chosen by key hash; the verdict is marked SAMPLED.
+    #[arg(long, value_name = "N", value_parser = parse_sample_size)]
+    pub sample: Option<u64>,
+
+    /// Seed for --sample; the same seed picks the same keys on every run.
+    #[arg(long, value_name = "N", default_value_t = 0, requires = "sample")]
+    pub seed: u64,
+
+    /// In key mode, handle repeated keys: refuse (default), sum (numeric columns per key), or first.
+    #[arg(long, value_name = "MODE", default_value = "refuse", value_parser = parse_dedup_mode)]
+    pub dedup: DedupMode,
+
+    /// Sum numeric columns within each group of this column on both sides and compare group totals.
+    #[arg(long, value_name = "COLUMN", conflicts_with_all = ["key", "dedup"])]
+    pub group_by: Option<String>,
+
     /// Coverage target: 0 < x <= 1 (default: 0.95).
     #[arg(
         long,
         value_name = "FLOAT",
         default_value_t = DEFAULT_THRESHOLD,
-        value_parser = parse_threshold
+        value_parser = parse_threshold,
+        env = "RVL_THRESHOLD"
     )]
     pub threshold: f64,
 
@@ -43,14 +143,103 @@ pub struct Args {
         long,
         value_name = "FLOAT",
         default_value_t = DEFAULT_TOLERANCE,
-        value_parser = parse_tolerance
+        value_parser = parse_tolerance,
+        env = "RVL_TOLERANCE"
     )]
     pub tolerance: f64,
 
+    /// Infer a semantic type per numeric column (currency, percent, ...) and apply its default tolerance.
+    #[arg(long)]
+    pub smart_tolerance: bool,
+
+    /// Per-column noise floor overriding --tolerance for COLUMN (repeatable).
+    #[arg(
+        long = "column-tolerance",
+        value_name = "COLUMN=FLOAT",
+        value_parser = parse_column_tolerance
+    )]
+    pub column_tolerances: Vec<ColumnTolerance>,
+
+    /// Rewrite COLUMN's values before diffing: abs, round:N, or scale:F; prefix old: or new: for one side (repeatable).
+    #[arg(
+        long = "transform",
+        value_name = "COLUMN=TRANSFORM",
+        value_parser = parse_column_transform
+    )]
+    pub transforms: Vec<ColumnTransform>,
+
+    /// Also report cells above, and the verdict at, each tolerance in LEVELS (default: 1e-9,1e-6,1e-3,0.01,1).
+    #[arg(
+        long,
+        value_name = "LEVELS",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = DEFAULT_SWEEP_LEVELS,
+        value_parser = parse_tolerance_sweep
+    )]
+    pub tolerance_sweep: Option<SweepLevels>,
+
+    /// Refuse when fewer than this fraction of the narrower file's columns match by name: 0 <= x <= 1 (default: 0.5).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_MIN_HEADER_OVERLAP,
+        value_parser = parse_min_header_overlap
+    )]
+    pub min_header_overlap: f64,
+
+    /// Contributors to keep and report: 1 <= N <= 1000 (default: 25).
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = MAX_CONTRIBUTORS,
+        value_parser = parse_top
+    )]
+    pub top: usize,
+
+    /// Contribution ranking: abs (|delta|, default) or relative (|delta| / max(|old|, |new|)).
+    #[arg(
+        long,
+        value_name = "MODE",
+        default_value = "abs",
+        value_parser = parse_rank_mode,
+        conflicts_with = "weight_col"
+    )]
+    pub rank: RankMode,
+
+    /// Rank contributions by |delta| x |COLUMN| (a per-row weight such as market value).
+    #[arg(long, value_name = "COLUMN")]
+    pub weight_col: Option<String>,
+
     /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
-    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    #[arg(
+        long,
+        value_name = "DELIM",
+        value_parser = parse_delimiter,
+        env = "RVL_DELIMITER"
+    )]
     pub delimiter: Option<u8>,
 
+    /// Drop banner lines before the header row: N for both files, or OLD:NEW.
+    #[arg(long, value_name = "N|OLD:NEW", default_value = "0", value_parser = parse_skip_rows)]
+    pub skip_rows: SkipRows,
+
+    /// Drop trailing total rows: N or OLD:NEW data rows, or `auto` for a last row whose key is empty or "Total".
+    #[arg(long, value_name = "auto|N|OLD:NEW", default_value = "0", value_parser = parse_skip_footer)]
+    pub skip_footer: FooterSkip,
+
+    /// Input encoding: utf8 (default; refuse others), auto (UTF-16 with a BOM, else Windows-1252 when not UTF-8), utf16, or windows-1252.
+    #[arg(long, value_name = "ENCODING", default_value = "utf8", value_parser = parse_encoding)]
+    pub encoding: EncodingMode,
+
+    /// Skip lines starting with this prefix (e.g. '#') anywhere in either file.
+    #[arg(long, value_name = "PREFIX", value_parser = parse_comment)]
+    pub comment: Option<String>,
+
+    /// Refuse (E_DIALECT) instead of warning when old and new are read with different delimiters.
+    #[arg(long)]
+    pub strict_dialect: bool,
+
     /// Emit every changed numeric cell above tolerance instead of the smallest explanation prefix.
     #[arg(long)]
     pub exhaustive: bool,
@@ -64,70 +253,617 @@ pub struct Args {
         long,
         value_name = "N",
         default_value_t = DEFAULT_MAX_AUDIT_CHANGES,
-        value_parser = parse_max_audit_changes
+        value_parser = parse_max_audit_changes,
+        env = "RVL_MAX_AUDIT_CHANGES"
     )]
     pub max_audit_changes: u64,
 
+    /// Refuse up front (E_RESOURCE) when the estimated peak memory exceeds SIZE (e.g. 512M, 4G).
+    #[arg(
+        long,
+        value_name = "SIZE",
+        value_parser = parse_max_memory,
+        env = "RVL_MAX_MEMORY"
+    )]
+    pub max_memory: Option<u64>,
+
+    /// In NO REAL CHANGE output, list the N largest deltas that fell within tolerance.
+    #[arg(long, value_name = "N")]
+    pub show_subtolerance: Option<usize>,
+
+    /// Drop data rows that exactly repeat an earlier row in the same file before aligning.
+    #[arg(long)]
+    pub dedupe_rows: bool,
+
+    /// Type each (row, column) cell independently instead of whole columns (pivoted grids).
+    #[arg(long)]
+    pub cross_tab: bool,
+
+    /// Parse `N%` tokens as N/100 so percent-formatted columns compare as numbers.
+    #[arg(long)]
+    pub parse_percent: bool,
+
+    /// Decimal separator in numeric cells: dot (1,234.56) or comma (1.234,56).
+    #[arg(long, value_name = "SEP", default_value = "dot", value_parser = parse_decimal_separator)]
+    pub decimal: DecimalSeparator,
+
+    /// Extra currency symbols to strip from numbers, beyond $ € £ ¥ (comma-separated, repeatable).
+    #[arg(
+        long,
+        value_name = "SYMBOLS",
+        value_delimiter = ',',
+        value_parser = parse_currency_symbol
+    )]
+    pub currency_symbols: Vec<String>,
+
+    /// Accept Unicode minus (U+2212) and non-breaking/thin-space digit grouping in numbers.
+    #[arg(long)]
+    pub lenient_numbers: bool,
+
+    /// Extra placeholders to treat as missing, beyond the built-in tokens (comma-separated, repeatable).
+    #[arg(
+        long,
+        value_name = "TOKENS",
+        value_delimiter = ',',
+        value_parser = parse_na_value
+    )]
+    pub na_values: Vec<String>,
+
+    /// Handle a cell missing on one side and numeric on the other: refuse, skip, or zero.
+    #[arg(
+        long,
+        value_name = "POLICY",
+        default_value = "refuse",
+        value_parser = parse_missing_policy
+    )]
+    pub on_missing: MissingPolicy,
+
+    /// Handle a column mixing numbers and text: refuse, or exclude it from the numeric set.
+    #[arg(
+        long,
+        value_name = "POLICY",
+        default_value = "refuse",
+        value_parser = parse_mixed_types_policy
+    )]
+    pub on_mixed_types: MixedTypesPolicy,
+
+    /// On refusal, keep analyzing where possible and report every blocking issue, not just the first.
+    #[arg(long)]
+    pub collect_refusals: bool,
+
+    /// Handle change too diffuse for the top contributors: refuse, or expand to a per-column rollup.
+    #[arg(
+        long,
+        value_name = "POLICY",
+        default_value = "refuse",
+        value_parser = parse_diffuse_policy
+    )]
+    pub on_diffuse: DiffusePolicy,
+
+    /// Coverage: total (one threshold over all change) or per-column (every changed column must reach it).
+    #[arg(
+        long,
+        value_name = "MODE",
+        default_value = "total",
+        value_parser = parse_coverage_mode
+    )]
+    pub coverage: CoverageMode,
+
+    /// Report per-column distribution drift (psi or ks) alongside the verdict.
+    #[arg(long, value_name = "METRIC", value_parser = parse_drift_metric)]
+    pub drift: Option<DriftMetric>,
+
+    /// Summarize changed text cells per column alongside the verdict.
+    #[arg(long)]
+    pub include_text: bool,
+
+    /// Compare schema fingerprints (ordered headers + inferred types) and stop before diffing cells.
+    #[arg(long)]
+    pub schema_only: bool,
+
+    /// Mark contributors already present in this approved `rvl --json` result.
+    #[arg(long, value_name = "RESULT.json")]
+    pub expected: Option<PathBuf>,
+
+    /// With `rvl assert`: the verdict to require (NO_REAL_CHANGE, REAL_CHANGE, REFUSAL, or an E_* code).
+    #[arg(long, value_name = "VERDICT", value_parser = parse_expected_verdict)]
+    pub expect: Option<ExpectedVerdict>,
+
+    /// With `rvl assert`: require the verdict of this saved `rvl --json` result.
+    #[arg(long, value_name = "RESULT.json", conflicts_with = "expect")]
+    pub expect_json: Option<PathBuf>,
+
     /// Use profile YAML at this path for key derivation and column scoping.
-    #[arg(long, value_name = "PATH")]
+    #[arg(long, value_name = "PATH", env = "RVL_PROFILE")]
     pub profile: Option<PathBuf>,
 
     /// Resolve profile by ID from ~/.cmdrvl/config/profile/profiles/*.yaml (or from a direct path).
-    #[arg(long = "profile-id", value_name = "ID")]
+    #[arg(long = "profile-id", value_name = "ID", env = "RVL_PROFILE_ID")]
     pub profile_id: Option<String>,
 
+    /// Read flag defaults from this TOML file (default: ./.rvl.toml when present).
+    #[arg(long, value_name = "PATH", conflicts_with = "no_config")]
+    pub config: Option<PathBuf>,
+
+    /// Ignore ./.rvl.toml.
+    #[arg(long)]
+    pub no_config: bool,
+
     /// Write deterministic repro capsule artifacts to this directory (default: disabled).
-    #[arg(long, value_name = "DIR")]
+    #[arg(long, value_name = "DIR", env = "RVL_CAPSULE_OUT")]
     pub capsule_out: Option<PathBuf>,
 
-    /// Emit JSON output (single object).
+    /// Write a self-contained HTML explorer over the change ledger to this path.
+    #[arg(long, value_name = "PATH")]
+    pub explore: Option<PathBuf>,
+
+    /// Write the verdict summary and changed-cells ledger to this .xlsx workbook.
+    #[arg(long, value_name = "PATH")]
+    pub report_xlsx: Option<PathBuf>,
+
+    /// Stream every changed numeric cell (row, column; values with --explicit) to this CSV.
+    #[arg(long, value_name = "PATH")]
+    pub report_csv: Option<PathBuf>,
+
+    /// Write NDJSON lifecycle events (parse_started, dialect_detected, refusal, verdict) to fd:N or a file.
+    #[arg(long, value_name = "TARGET", value_parser = EventTarget::parse)]
+    pub events: Option<EventTarget>,
+
+    /// Report wall-clock time per phase (read, parse, align, type, diff, render) in JSON metrics and a human footer line.
     #[arg(long)]
+    pub timings: bool,
+
+    /// Stream for rvl's output: stdout, stderr, or auto (refusals to stderr in human mode).
+    #[arg(
+        long,
+        value_name = "STREAM",
+        default_value = "auto",
+        value_parser = parse_output_stream,
+        env = "RVL_OUTPUT_STREAM"
+    )]
+    pub output_stream: StreamPolicy,
+
+    /// Emit JSON output (single object).
+    #[arg(long, env = "RVL_JSON")]
     pub json: bool,
 
+    /// Render the report as a document (html, sarif) instead of human text.
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        value_parser = parse_report_format,
+        conflicts_with = "json"
+    )]
+    pub format: Option<ReportFormat>,
+
+    /// Pin the JSON output schema version (v0); an unknown version is a CLI error.
+    #[arg(
+        long,
+        value_name = "VERSION",
+        default_value = "v0",
+        value_parser = parse_output_version,
+        env = "RVL_OUTPUT_VERSION"
+    )]
+    pub output_version: OutputVersion,
+
     /// Suppress witness ledger recording.
-    #[arg(long)]
+    #[arg(long, env = "RVL_NO_WITNESS")]
     pub no_witness: bool,
 
-    /// Show raw data values in output (default: redacted for zero-retention safety).
-    #[arg(long)]
-    pub explicit: bool,
+    /// Show raw data values in output (default: redacted for zero-retention safety).
+    #[arg(long)]
+    pub explicit: bool,
+
+    /// Worker threads for the cell scan (default: 0 = one per core on large inputs).
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub threads: usize,
+
+    /// Print compiled operator.json and exit 0.
+    #[arg(long)]
+    pub describe: bool,
+
+    /// Print JSON Schema for rvl.v0 output format and exit 0.
+    #[arg(long)]
+    pub schema: bool,
+
+    /// Print version and exit 0.
+    #[arg(long)]
+    pub version: bool,
+
+    /// Emit one-call machine triage for headless agents.
+    #[arg(long = "robot-triage")]
+    pub robot_triage: bool,
+
+    #[command(subcommand)]
+    pub command: Option<RvlCommand>,
+
+    /// Re-run whenever either input changes; set by `rvl watch`, not a flag.
+    #[arg(skip)]
+    pub watch: bool,
+
+    /// Check the verdict against `--expect`; set by `rvl assert`, not a flag.
+    #[arg(skip)]
+    pub assert: bool,
+
+    /// Where each configurable parameter came from (cli/env/config/default).
+    #[arg(skip)]
+    pub param_sources: ParamSources,
+
+    /// Settings read from the config file, when one was used.
+    #[arg(skip)]
+    pub config_receipt: Option<ConfigReceipt>,
+
+    /// In-memory bytes for `old`/`new`, read instead of the paths.
+    #[arg(skip)]
+    pub inline: InlineInputs,
+}
+
+/// Origin of a parameter value, recorded in the witness receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamSource {
+    Cli,
+    Env,
+    Config,
+    Default,
+}
+
+impl ParamSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ParamSource::Cli => "cli",
+            ParamSource::Env => "env",
+            ParamSource::Config => "config",
+            ParamSource::Default => "default",
+        }
+    }
+}
+
+/// Parameters that accept an `RVL_*` environment default, keyed by arg id.
+const ENV_PARAMS: &[&str] = &[
+    "key",
+    "threshold",
+    "tolerance",
+    "delimiter",
+    "max_audit_changes",
+    "max_memory",
+    "profile",
+    "profile_id",
+    "capsule_out",
+    "output_stream",
+    "json",
+    "output_version",
+    "no_witness",
+];
+
+/// Provenance of the `RVL_*`-configurable parameters for one invocation.
+///
+/// Empty when `Args` is built directly rather than parsed from argv.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParamSources {
+    entries: Vec<(&'static str, ParamSource)>,
+}
+
+impl ParamSources {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        let entries = ENV_PARAMS
+            .iter()
+            .filter_map(|id| {
+                let source = match matches.value_source(id)? {
+                    ValueSource::CommandLine => ParamSource::Cli,
+                    ValueSource::EnvVariable => ParamSource::Env,
+                    _ => ParamSource::Default,
+                };
+                Some((*id, source))
+            })
+            .collect();
+        Self { entries }
+    }
+
+    fn mark_config(&mut self, id: &str) {
+        for (name, source) in &mut self.entries {
+            if *name == id {
+                *source = ParamSource::Config;
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<ParamSource> {
+        self.entries
+            .iter()
+            .find(|(name, _)| *name == id)
+            .map(|(_, source)| *source)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, ParamSource)> + '_ {
+        self.entries.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum RvlCommand {
+    /// Query the witness ledger.
+    Witness {
+        #[command(subcommand)]
+        action: WitnessAction,
+    },
+    /// Print the machine-readable rvl capability contract.
+    Capabilities(TopLevelCapabilitiesArgs),
+    /// Print paste-ready operating notes for agents.
+    RobotDocs {
+        #[command(subcommand)]
+        action: Option<RobotDocsAction>,
+    },
+    /// Recommend tolerance/threshold settings from accepted historical pairs.
+    Calibrate(CalibrateArgs),
+    /// Compare two Excel workbooks sheet by sheet.
+    Xlsx(XlsxArgs),
+    /// Time the full comparison pipeline and check output identity.
+    Bench(BenchArgs),
+    /// Run built-in example comparisons for each outcome.
+    Demo(DemoArgs),
+    /// Inspect rvl's read-only diagnostic surface.
+    Doctor(DoctorArgs),
+    /// Compare every `<pair>/old.csv` + `<pair>/new.csv` in a directory.
+    Batch(BatchArgs),
+    /// Print the JSON Schema for `--json` output (same as --schema).
+    Schema(SchemaArgs),
+    /// Profile one CSV: dialect, headers, column types, row count, key candidates.
+    Inspect(InspectArgs),
+    /// Score every shared column as a `--key`: uniqueness, empties, key-set overlap.
+    Keys(KeysArgs),
+    /// Record a CSV's column stats and digests to a snapshot file.
+    Snapshot(SnapshotArgs),
+    /// Check a CSV against a snapshot without the original file.
+    Verify(VerifyArgs),
+    /// Print the agent manifest: input/output schemas, exit codes, refusal codes.
+    AgentManifest(AgentManifestArgs),
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct CalibrateArgs {
+    /// Directory of accepted pairs (`<pair>/old.csv` + `<pair>/new.csv`).
+    #[arg(value_name = "DIR")]
+    pub dir: PathBuf,
+
+    /// Align rows by this key column (otherwise align by row order).
+    #[arg(long, value_name = "COLUMN")]
+    pub key: Option<String>,
+
+    /// Coverage target to recommend alongside the tolerance (default: 0.95).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_THRESHOLD,
+        value_parser = parse_threshold
+    )]
+    pub threshold: f64,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct InspectArgs {
+    /// CSV file to profile.
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct KeysArgs {
+    /// Old CSV file.
+    #[arg(value_name = "OLD_CSV")]
+    pub old: PathBuf,
+
+    /// New CSV file.
+    #[arg(value_name = "NEW_CSV")]
+    pub new: PathBuf,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct SnapshotArgs {
+    /// CSV file to record.
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Snapshot file to write (conventionally `*.rvlsnap`).
+    #[arg(long, value_name = "PATH")]
+    pub out: PathBuf,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct VerifyArgs {
+    /// CSV file to check.
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Snapshot written by `rvl snapshot`.
+    #[arg(value_name = "SNAPSHOT")]
+    pub snapshot: PathBuf,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct BatchArgs {
+    /// Directory of pairs (`<pair>/old.csv` + `<pair>/new.csv`).
+    #[arg(value_name = "DIR")]
+    pub dir: PathBuf,
+
+    /// Align rows by this key column (otherwise align by row order).
+    #[arg(long, value_name = "COLUMN")]
+    pub key: Option<String>,
+
+    /// Coverage target: 0 < x <= 1 (default: 0.95).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_THRESHOLD,
+        value_parser = parse_threshold
+    )]
+    pub threshold: f64,
+
+    /// Per-cell noise floor: x >= 0 (default: 1e-9).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_TOLERANCE,
+        value_parser = parse_tolerance
+    )]
+    pub tolerance: f64,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct XlsxArgs {
+    /// Old workbook path.
+    #[arg(value_name = "OLD_XLSX")]
+    pub old: PathBuf,
+
+    /// New workbook path.
+    #[arg(value_name = "NEW_XLSX")]
+    pub new: PathBuf,
+
+    /// Compare every sheet paired by name (otherwise only the first sheet of each).
+    #[arg(long)]
+    pub all_sheets: bool,
+
+    /// Align rows by this key column (otherwise align by row order).
+    #[arg(long, value_name = "COLUMN")]
+    pub key: Option<String>,
+
+    /// Coverage target: 0 < x <= 1 (default: 0.95).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_THRESHOLD,
+        value_parser = parse_threshold
+    )]
+    pub threshold: f64,
+
+    /// Per-cell noise floor: x >= 0 (default: 1e-9).
+    #[arg(
+        long,
+        value_name = "FLOAT",
+        default_value_t = DEFAULT_TOLERANCE,
+        value_parser = parse_tolerance
+    )]
+    pub tolerance: f64,
+
+    /// Emit JSON output.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct BenchArgs {
+    /// An old/new CSV pair, or one directory of `<pair>/old.csv` + `<pair>/new.csv`.
+    #[arg(value_name = "INPUT", required = true, num_args = 1..=2)]
+    pub inputs: Vec<PathBuf>,
+
+    /// Align rows by this key column (otherwise align by row order).
+    #[arg(long, value_name = "COLUMN")]
+    pub key: Option<String>,
+
+    /// Force a CSV delimiter (comma/tab/semicolon/pipe/caret, 0xNN, or single ASCII byte).
+    #[arg(long, value_name = "DELIM", value_parser = parse_delimiter)]
+    pub delimiter: Option<u8>,
+
+    /// Timed runs per pair (default: 5).
+    #[arg(long, value_name = "N", default_value_t = 5, value_parser = parse_iterations)]
+    pub iterations: u64,
+
+    /// Untimed runs per pair before timing starts (default: 1).
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    pub warmup: u64,
 
-    /// Print compiled operator.json and exit 0.
-    #[arg(long)]
-    pub describe: bool,
+    /// Another rvl binary whose `--json` output must match this one byte for byte.
+    #[arg(long, value_name = "RVL")]
+    pub against: Option<PathBuf>,
 
-    /// Print JSON Schema for rvl.v0 output format and exit 0.
+    /// Emit JSON output.
     #[arg(long)]
-    pub schema: bool,
+    pub json: bool,
+}
 
-    /// Print version and exit 0.
+#[derive(Debug, Clone, clap::Args)]
+pub struct DemoArgs {
+    /// Run one scenario: real-change, no-real-change, or refusal (default: all three).
+    #[arg(value_name = "SCENARIO", value_parser = parse_demo_scenario)]
+    pub scenario: Option<DemoScenario>,
+
+    /// Emit JSON output.
     #[arg(long)]
-    pub version: bool,
+    pub json: bool,
+}
 
-    /// Emit one-call machine triage for headless agents.
-    #[arg(long = "robot-triage")]
-    pub robot_triage: bool,
+#[derive(Debug, Clone, clap::Args)]
+pub struct SchemaArgs {
+    /// Schema document format.
+    #[arg(long, value_name = "FORMAT", default_value = "json", value_parser = ["json"])]
+    pub output: String,
 
-    #[command(subcommand)]
-    pub command: Option<RvlCommand>,
+    /// Output version to describe (v0).
+    #[arg(
+        long,
+        value_name = "VERSION",
+        default_value = "v0",
+        value_parser = parse_output_version
+    )]
+    pub output_version: OutputVersion,
 }
 
-#[derive(Debug, Clone, Subcommand)]
-pub enum RvlCommand {
-    /// Query the witness ledger.
-    Witness {
-        #[command(subcommand)]
-        action: WitnessAction,
-    },
-    /// Print the machine-readable rvl capability contract.
-    Capabilities(TopLevelCapabilitiesArgs),
-    /// Print paste-ready operating notes for agents.
-    RobotDocs {
-        #[command(subcommand)]
-        action: Option<RobotDocsAction>,
-    },
-    /// Inspect rvl's read-only diagnostic surface.
-    Doctor(DoctorArgs),
+#[derive(Debug, Clone, clap::Args)]
+pub struct AgentManifestArgs {
+    /// Manifest document format.
+    #[arg(long, value_name = "FORMAT", default_value = "json", value_parser = ["json", "yaml"])]
+    pub format: String,
 }
 
 #[derive(Debug, Clone, clap::Args)]
@@ -231,15 +967,53 @@ pub struct WitnessLastArgs {
 
 impl Args {
     pub fn parse() -> Result<Self, clap::Error> {
-        Self::try_parse()
+        Self::parse_from(std::env::args_os())
     }
 
+    /// Parse argv, falling back to `RVL_*` environment variables and then
+    /// the config file for unset flags (flags win), and record where each
+    /// parameter came from. `rvl compare ...` is the same as `rvl ...`, and
+    /// `rvl watch ...` and `rvl assert ...` are the same with `watch` or
+    /// `assert` set.
     pub fn parse_from<I, T>(itr: I) -> Result<Self, clap::Error>
     where
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
     {
-        Self::try_parse_from(itr)
+        let mut command = Self::command();
+        let mut argv: Vec<OsString> = itr.into_iter().map(Into::into).collect();
+        let watch = argv.get(1).is_some_and(|arg| arg == "watch");
+        let assert = argv.get(1).is_some_and(|arg| arg == "assert");
+        if watch || assert || argv.get(1).is_some_and(|arg| arg == "compare") {
+            argv.remove(1);
+        }
+        let mut matches = command.try_get_matches_from_mut(argv.clone())?;
+
+        let mut config = None;
+        if let Some(path) = run_config_path(&matches) {
+            let from_file = config_flags(&command, &matches, &path)
+                .map_err(|msg| command.error(ErrorKind::InvalidValue, msg))?;
+            if !from_file.flags.is_empty() {
+                let mut with_config = Vec::with_capacity(argv.len() + from_file.flags.len());
+                with_config.extend(argv.first().cloned());
+                with_config.extend(from_file.flags.iter().map(OsString::from));
+                with_config.extend(argv.iter().skip(1).cloned());
+                matches = command.try_get_matches_from_mut(with_config)?;
+            }
+            config = Some(from_file);
+        }
+
+        let mut args = Self::from_arg_matches(&matches).map_err(|err| err.format(&mut command))?;
+        args.watch = watch;
+        args.assert = assert;
+        args.param_sources = ParamSources::from_matches(&matches);
+        if let Some(from_file) = config {
+            for id in &from_file.ids {
+                args.param_sources.mark_config(id);
+            }
+            args.config_receipt = Some(from_file.receipt);
+        }
+        Ok(args)
     }
 
     /// Create Args directly (for API/library use).
@@ -255,24 +1029,87 @@ impl Args {
         Self {
             old: Some(old),
             new: Some(new),
+            base: None,
             key,
+            key_map: None,
+            key_normalize: Vec::new(),
+            column_map: Vec::new(),
+            columns: Vec::new(),
+            header_match: HeaderMatch::Exact,
+            allow_missing_keys: false,
+            include_entity_changes: false,
+            allow_trailing_rows: 0,
+            where_filters: Vec::new(),
+            sample: None,
+            seed: 0,
+            dedup: DedupMode::Refuse,
+            group_by: None,
             threshold,
             tolerance,
+            smart_tolerance: false,
+            column_tolerances: Vec::new(),
+            transforms: Vec::new(),
+            tolerance_sweep: None,
+            min_header_overlap: DEFAULT_MIN_HEADER_OVERLAP,
+            top: MAX_CONTRIBUTORS,
+            rank: RankMode::Abs,
+            weight_col: None,
             delimiter,
+            skip_rows: SkipRows::default(),
+            skip_footer: FooterSkip::default(),
+            encoding: EncodingMode::default(),
+            comment: None,
+            strict_dialect: false,
             exhaustive: false,
             audit_fields: false,
             max_audit_changes: DEFAULT_MAX_AUDIT_CHANGES,
+            max_memory: None,
+            show_subtolerance: None,
+            dedupe_rows: false,
+            cross_tab: false,
+            parse_percent: false,
+            decimal: DecimalSeparator::Dot,
+            currency_symbols: Vec::new(),
+            lenient_numbers: false,
+            na_values: Vec::new(),
+            on_missing: MissingPolicy::Refuse,
+            on_mixed_types: MixedTypesPolicy::Refuse,
+            collect_refusals: false,
+            on_diffuse: DiffusePolicy::Refuse,
+            coverage: CoverageMode::Total,
+            drift: None,
+            include_text: false,
+            schema_only: false,
+            expected: None,
+            expect: None,
+            expect_json: None,
             profile: None,
             profile_id: None,
+            config: None,
+            no_config: false,
             capsule_out: None,
+            explore: None,
+            report_xlsx: None,
+            report_csv: None,
+            events: None,
+            timings: false,
+            output_stream: StreamPolicy::Auto,
             json,
+            format: None,
+            output_version: OutputVersion::V0,
             no_witness: false,
             explicit: false,
+            threads: 0,
             describe: false,
             schema: false,
             version: false,
             robot_triage: false,
             command: None,
+            watch: false,
+            assert: false,
+            param_sources: ParamSources::default(),
+            config_receipt: None,
+            inline: InlineInputs::default(),
         }
     }
 
@@ -285,6 +1122,174 @@ impl Args {
     pub fn new_path(&self) -> &PathBuf {
         self.new.as_ref().expect("new path required for comparison")
     }
+
+    /// Where to read the old input: its in-memory bytes, or its path.
+    pub fn old_source(&self) -> InputSource<'_> {
+        InputSource::new(self.old_path(), self.inline.old.as_ref())
+    }
+
+    /// Where to read the new input: its in-memory bytes, or its path.
+    pub fn new_source(&self) -> InputSource<'_> {
+        InputSource::new(self.new_path(), self.inline.new.as_ref())
+    }
+
+    /// Whether key mode joins on the common keys instead of refusing on a mismatch.
+    pub fn allows_missing_keys(&self) -> bool {
+        self.allow_missing_keys || self.include_entity_changes
+    }
+
+    /// Alignment column: `--key`, or the `--group-by` column.
+    pub fn key_column(&self) -> Option<&str> {
+        self.key.as_deref().or(self.group_by.as_deref())
+    }
+
+    /// Duplicate-key handling; `--group-by` always sums within groups.
+    pub fn dedup_mode(&self) -> DedupMode {
+        if self.group_by.is_some() {
+            DedupMode::Sum
+        } else {
+            self.dedup
+        }
+    }
+
+    /// Contribution ranking selected by `--rank` / `--weight-col`.
+    pub fn ranking(&self) -> Ranking {
+        Ranking::new(self.rank, self.weight_col.as_deref())
+    }
+
+    /// Opt-in numeric token forms selected by the flags.
+    pub fn number_format(&self) -> NumberFormat<'_> {
+        NumberFormat {
+            percent: self.parse_percent,
+            decimal: self.decimal,
+            lenient: self.lenient_numbers,
+            currency_symbols: &self.currency_symbols,
+            na_values: &self.na_values,
+        }
+    }
+}
+
+/// Flags a config file may not set: they select what rvl does rather than
+/// how a comparison runs.
+const CONFIG_EXCLUDED: &[&str] = &[
+    "config",
+    "no-config",
+    "describe",
+    "schema",
+    "version",
+    "robot-triage",
+    "help",
+];
+
+/// The config file for a comparison run: `--config`, else `./.rvl.toml`
+/// unless `--no-config`. Subcommands and info flags never read one.
+fn run_config_path(matches: &ArgMatches) -> Option<PathBuf> {
+    if matches.subcommand().is_some() {
+        return None;
+    }
+    let discover = !matches.get_flag("no_config") && matches.contains_id("old");
+    config_path(matches.get_one::<PathBuf>("config"), discover)
+}
+
+/// A config file translated for one parse.
+struct ConfigArgs {
+    /// `--flag=value` arguments for settings the command line left unset.
+    flags: Vec<String>,
+    receipt: ConfigReceipt,
+    /// Arg ids the file supplied.
+    ids: Vec<String>,
+}
+
+/// Read a config file and translate it into `--flag=value` arguments for
+/// every setting not already given on the command line or via `RVL_*`.
+fn config_flags(
+    command: &Command,
+    matches: &ArgMatches,
+    path: &Path,
+) -> Result<ConfigArgs, String> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read config {}: {err}", path.display()))?;
+    let file = parse_config(&raw).map_err(|msg| format!("config {}: {msg}", path.display()))?;
+    let mut receipt = ConfigReceipt::new(path);
+    let mut flags = Vec::new();
+    let mut ids = Vec::new();
+
+    let mut settings = file.settings;
+    if !file.tolerances.is_empty() {
+        let overrides = file
+            .tolerances
+            .iter()
+            .map(|(column, tolerance)| ConfigValue::String(format!("{column}={tolerance}")))
+            .collect();
+        settings.push(ConfigSetting {
+            name: "column-tolerance".to_string(),
+            value: ConfigValue::Array(overrides),
+        });
+    }
+
+    for setting in settings {
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(setting.name.as_str()))
+            .filter(|_| !CONFIG_EXCLUDED.contains(&setting.name.as_str()))
+            .ok_or_else(|| {
+                format!(
+                    "config {}: unknown setting `{}`",
+                    path.display(),
+                    setting.name
+                )
+            })?;
+        let id = arg.get_id().as_str();
+        let receipt_name = if setting.name == "column-tolerance" {
+            "tolerances".to_string()
+        } else {
+            setting.name.replace('-', "_")
+        };
+        if matches!(
+            matches.value_source(id),
+            Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+        ) {
+            receipt.overridden.push(receipt_name);
+            continue;
+        }
+
+        let long = setting.name.as_str();
+        if arg.get_action().takes_values() {
+            let values = setting
+                .value
+                .flag_values()
+                .map_err(|msg| format!("config {}: `{long}`: {msg}", path.display()))?;
+            flags.extend(values.into_iter().map(|value| format!("--{long}={value}")));
+        } else {
+            match setting.value {
+                ConfigValue::Bool(true) => flags.push(format!("--{long}")),
+                ConfigValue::Bool(false) => {}
+                _ => {
+                    return Err(format!(
+                        "config {}: `{long}` must be true or false",
+                        path.display()
+                    ));
+                }
+            }
+        }
+        let value = if setting.name == "column-tolerance" {
+            Value::Object(
+                file.tolerances
+                    .iter()
+                    .map(|(column, tolerance)| (column.clone(), Value::from(*tolerance)))
+                    .collect(),
+            )
+        } else {
+            setting.value.to_json()
+        };
+        receipt.settings.insert(receipt_name, value);
+        ids.push(id.to_string());
+    }
+    Ok(ConfigArgs {
+        flags,
+        receipt,
+        ids,
+    })
 }
 
 fn parse_threshold(raw: &str) -> Result<f64, String> {
@@ -295,6 +1300,14 @@ fn parse_threshold(raw: &str) -> Result<f64, String> {
     Ok(value)
 }
 
+fn parse_min_header_overlap(raw: &str) -> Result<f64, String> {
+    let value = parse_finite(raw, "min-header-overlap")?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err("min-header-overlap must be 0 <= x <= 1".to_string());
+    }
+    Ok(value)
+}
+
 fn parse_tolerance(raw: &str) -> Result<f64, String> {
     let value = parse_finite(raw, "tolerance")?;
     if value < 0.0 {
@@ -317,6 +1330,36 @@ fn parse_delimiter(raw: &str) -> Result<u8, String> {
     parse_delimiter_arg(raw).map_err(|err| err.to_string())
 }
 
+fn parse_top(raw: &str) -> Result<usize, String> {
+    let value = raw
+        .parse::<usize>()
+        .map_err(|_| "top must be a positive integer".to_string())?;
+    if value == 0 || value > MAX_TOP {
+        return Err(format!("top must be 1 <= N <= {MAX_TOP}"));
+    }
+    Ok(value)
+}
+
+fn parse_iterations(raw: &str) -> Result<u64, String> {
+    let value = raw
+        .parse::<u64>()
+        .map_err(|_| "iterations must be a positive integer".to_string())?;
+    if value == 0 {
+        return Err("iterations must be greater than zero".to_string());
+    }
+    Ok(value)
+}
+
+fn parse_sample_size(raw: &str) -> Result<u64, String> {
+    let value = raw
+        .parse::<u64>()
+        .map_err(|_| "sample must be a positive integer".to_string())?;
+    if value == 0 {
+        return Err("sample must be greater than zero".to_string());
+    }
+    Ok(value)
+}
+
 fn parse_max_audit_changes(raw: &str) -> Result<u64, String> {
     let value = raw
         .parse::<u64>()
@@ -327,9 +1370,187 @@ fn parse_max_audit_changes(raw: &str) -> Result<u64, String> {
     Ok(value)
 }
 
+/// Byte count with an optional binary suffix: `K`, `M`, `G` or `T`, optionally
+/// followed by `B` or `iB` (`512M`, `4GiB`, `1073741824`).
+fn parse_max_memory(raw: &str) -> Result<u64, String> {
+    const INVALID: &str = "max memory must be a byte count such as 1073741824, 512M or 4G";
+    let lower = raw.trim().to_ascii_lowercase();
+    let (number, binary) = match lower.strip_suffix("ib") {
+        Some(number) => (number, true),
+        None => (lower.strip_suffix('b').unwrap_or(&lower), false),
+    };
+    let (digits, shift) = match number.as_bytes().last() {
+        Some(b'k') => (&number[..number.len() - 1], 10),
+        Some(b'm') => (&number[..number.len() - 1], 20),
+        Some(b'g') => (&number[..number.len() - 1], 30),
+        Some(b't') => (&number[..number.len() - 1], 40),
+        _ if binary => return Err(INVALID.to_string()),
+        _ => (number, 0),
+    };
+    let value = digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|value| value.checked_mul(1 << shift))
+        .ok_or_else(|| INVALID.to_string())?;
+    if value == 0 {
+        return Err("max memory must be greater than zero".to_string());
+    }
+    Ok(value)
+}
+
+fn parse_output_stream(raw: &str) -> Result<StreamPolicy, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "auto" => Ok(StreamPolicy::Auto),
+        "stdout" => Ok(StreamPolicy::Stdout),
+        "stderr" => Ok(StreamPolicy::Stderr),
+        _ => Err("output stream must be one of: stdout, stderr, auto".to_string()),
+    }
+}
+
+fn parse_demo_scenario(raw: &str) -> Result<DemoScenario, String> {
+    DemoScenario::parse(raw)
+        .ok_or_else(|| "scenario must be one of: real-change, no-real-change, refusal".to_string())
+}
+
+fn parse_skip_rows(raw: &str) -> Result<SkipRows, String> {
+    SkipRows::parse(raw)
+}
+
+fn parse_encoding(raw: &str) -> Result<EncodingMode, String> {
+    EncodingMode::parse(raw)
+}
+
+fn parse_comment(raw: &str) -> Result<String, String> {
+    parse_comment_prefix(raw)
+}
+
+fn parse_expected_verdict(raw: &str) -> Result<ExpectedVerdict, String> {
+    ExpectedVerdict::parse(raw)
+}
+
+fn parse_skip_footer(raw: &str) -> Result<FooterSkip, String> {
+    FooterSkip::parse(raw)
+}
+
+fn parse_column_mapping(raw: &str) -> Result<ColumnMapping, String> {
+    ColumnMapping::parse(raw)
+}
+
+fn parse_header_match(raw: &str) -> Result<HeaderMatch, String> {
+    HeaderMatch::parse(raw).ok_or_else(|| "header match must be one of: exact, loose".to_string())
+}
+
+fn parse_decimal_separator(raw: &str) -> Result<DecimalSeparator, String> {
+    DecimalSeparator::parse(raw)
+        .ok_or_else(|| "decimal separator must be one of: dot, comma".to_string())
+}
+
+fn parse_currency_symbol(raw: &str) -> Result<String, String> {
+    let symbol = raw.trim();
+    if symbol.is_empty() {
+        return Err("currency symbol must be non-empty".to_string());
+    }
+    if symbol
+        .chars()
+        .any(|ch| ch.is_ascii_digit() || ch.is_whitespace() || "+-.,()%".contains(ch))
+    {
+        return Err(format!(
+            "currency symbol '{symbol}' must not contain digits, spaces, signs, or separators"
+        ));
+    }
+    Ok(symbol.to_string())
+}
+
+fn parse_na_value(raw: &str) -> Result<String, String> {
+    let token = raw.trim();
+    if token.is_empty() {
+        return Err("missing-value token must be non-empty".to_string());
+    }
+    Ok(token.to_string())
+}
+
+fn parse_key_normalize_step(raw: &str) -> Result<KeyNormalizeStep, String> {
+    KeyNormalizeStep::parse(raw).ok_or_else(|| {
+        "key normalization must be one of: trim, lower, strip-leading-zeros".to_string()
+    })
+}
+
+fn parse_column_name(raw: &str) -> Result<String, String> {
+    let name = raw.trim();
+    if name.is_empty() {
+        return Err("column name must be non-empty".to_string());
+    }
+    Ok(name.to_string())
+}
+
+fn parse_row_predicate(raw: &str) -> Result<RowPredicate, String> {
+    RowPredicate::parse(raw)
+}
+
+fn parse_column_tolerance(raw: &str) -> Result<ColumnTolerance, String> {
+    ColumnTolerance::parse(raw)
+}
+
+fn parse_column_transform(raw: &str) -> Result<ColumnTransform, String> {
+    ColumnTransform::parse(raw)
+}
+
+fn parse_tolerance_sweep(raw: &str) -> Result<SweepLevels, String> {
+    SweepLevels::parse(raw)
+}
+
+fn parse_missing_policy(raw: &str) -> Result<MissingPolicy, String> {
+    MissingPolicy::parse(raw)
+        .ok_or_else(|| "missing policy must be one of: refuse, skip, zero".to_string())
+}
+
+fn parse_mixed_types_policy(raw: &str) -> Result<MixedTypesPolicy, String> {
+    MixedTypesPolicy::parse(raw)
+        .ok_or_else(|| "mixed-types policy must be one of: refuse, exclude".to_string())
+}
+
+fn parse_output_version(raw: &str) -> Result<OutputVersion, String> {
+    OutputVersion::parse(raw).ok_or_else(|| "output version must be one of: v0".to_string())
+}
+
+fn parse_report_format(raw: &str) -> Result<ReportFormat, String> {
+    ReportFormat::parse(raw).ok_or_else(|| "format must be one of: html, sarif".to_string())
+}
+
+fn parse_dedup_mode(raw: &str) -> Result<DedupMode, String> {
+    DedupMode::parse(raw).ok_or_else(|| "dedup must be one of: refuse, sum, first".to_string())
+}
+
+fn parse_diffuse_policy(raw: &str) -> Result<DiffusePolicy, String> {
+    DiffusePolicy::parse(raw)
+        .ok_or_else(|| "diffuse policy must be one of: refuse, expand".to_string())
+}
+
+fn parse_coverage_mode(raw: &str) -> Result<CoverageMode, String> {
+    CoverageMode::parse(raw).ok_or_else(|| "coverage must be one of: total, per-column".to_string())
+}
+
+fn parse_rank_mode(raw: &str) -> Result<RankMode, String> {
+    RankMode::parse(raw).ok_or_else(|| "rank must be one of: abs, relative".to_string())
+}
+
+fn parse_drift_metric(raw: &str) -> Result<DriftMetric, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "psi" => Ok(DriftMetric::Psi),
+        "ks" => Ok(DriftMetric::Ks),
+        _ => Err("drift metric must be one of: psi, ks".to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Args;
+    use std::path::Path;
+
+    use super::{
+        Args, DedupMode, ExpectedVerdict, OutputVersion, ParamSource, RankMode, Ranking,
+        RvlCommand, SchemaArgs,
+    };
+    use crate::cli::exit::Outcome;
 
     #[test]
     fn parse_accepts_profile_flags_without_clap_conflict() {
@@ -352,4 +1573,240 @@ mod tests {
         );
         assert_eq!(args.profile_id.as_deref(), Some("csv.demo.v0"));
     }
+
+    #[test]
+    fn parse_records_cli_and_default_sources() {
+        let args = Args::parse_from(["rvl", "old.csv", "new.csv", "--tolerance", "0.01"])
+            .expect("args should parse");
+
+        assert_eq!(args.param_sources.get("tolerance"), Some(ParamSource::Cli));
+        assert_eq!(
+            args.param_sources.get("threshold"),
+            Some(ParamSource::Default)
+        );
+    }
+
+    #[test]
+    fn parse_splits_and_validates_currency_symbols() {
+        let args = Args::parse_from([
+            "rvl",
+            "old.csv",
+            "new.csv",
+            "--currency-symbols",
+            "CHF,R$",
+            "--currency-symbols",
+            "kr",
+        ])
+        .expect("args should parse");
+        assert_eq!(args.currency_symbols, vec!["CHF", "R$", "kr"]);
+
+        assert!(
+            Args::parse_from(["rvl", "old.csv", "new.csv", "--currency-symbols", "1x"]).is_err()
+        );
+    }
+
+    #[test]
+    fn parse_splits_na_values() {
+        let args = Args::parse_from(["rvl", "old.csv", "new.csv", "--na-values", "TBD, --,#N/A"])
+            .expect("args should parse");
+        assert_eq!(args.na_values, vec!["TBD", "--", "#N/A"]);
+
+        assert!(Args::parse_from(["rvl", "old.csv", "new.csv", "--na-values", "TBD,,x"]).is_err());
+    }
+
+    #[test]
+    fn parse_reads_column_scope_and_tolerances() {
+        let args = Args::parse_from([
+            "rvl",
+            "old.csv",
+            "new.csv",
+            "--columns",
+            "balance, rate",
+            "--column-tolerance",
+            "balance=0.5",
+        ])
+        .expect("args should parse");
+        assert_eq!(args.columns, vec!["balance", "rate"]);
+        assert_eq!(args.column_tolerances[0].column, b"balance".to_vec());
+        assert_eq!(args.column_tolerances[0].tolerance, 0.5);
+
+        assert!(
+            Args::parse_from(["rvl", "old.csv", "new.csv", "--column-tolerance", "balance"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_reads_tolerance_sweep_levels() {
+        let args = Args::parse_from(["rvl", "--tolerance-sweep", "old.csv", "new.csv"])
+            .expect("args should parse");
+        assert_eq!(args.old_path().to_str(), Some("old.csv"));
+        assert_eq!(
+            args.tolerance_sweep.map(|sweep| sweep.levels),
+            Some(vec![1e-9, 1e-6, 1e-3, 0.01, 1.0])
+        );
+
+        let args = Args::parse_from(["rvl", "old.csv", "new.csv", "--tolerance-sweep=0.5,0.01"])
+            .expect("args should parse");
+        assert_eq!(
+            args.tolerance_sweep.map(|sweep| sweep.levels),
+            Some(vec![0.01, 0.5])
+        );
+    }
+
+    #[test]
+    fn parse_reads_max_memory_sizes() {
+        let parse = |raw: &str| {
+            Args::parse_from(["rvl", "old.csv", "new.csv", "--max-memory", raw])
+                .map(|args| args.max_memory)
+        };
+        assert_eq!(parse("1048576").unwrap(), Some(1 << 20));
+        assert_eq!(parse("512M").unwrap(), Some(512 << 20));
+        assert_eq!(parse("4GiB").unwrap(), Some(4 << 30));
+        assert_eq!(parse("2kb").unwrap(), Some(2048));
+        assert!(parse("0").is_err());
+        assert!(parse("12iB").is_err());
+        assert!(parse("lots").is_err());
+    }
+
+    #[test]
+    fn parse_reads_rank_mode_exclusive_of_weight_col() {
+        let parse = |extra: &[&str]| {
+            let mut argv = vec!["rvl", "old.csv", "new.csv"];
+            argv.extend_from_slice(extra);
+            Args::parse_from(argv)
+        };
+        assert_eq!(parse(&[]).unwrap().rank, RankMode::Abs);
+        assert_eq!(
+            parse(&["--rank", "relative"]).unwrap().ranking(),
+            Ranking::Relative
+        );
+        assert!(parse(&["--rank", "pct"]).is_err());
+        assert!(parse(&["--rank", "relative", "--weight-col", "mv"]).is_err());
+        assert_eq!(
+            parse(&["--weight-col", "mv"]).unwrap().ranking().as_str(),
+            "weighted"
+        );
+    }
+
+    #[test]
+    fn parse_reads_group_by_exclusive_of_key_and_dedup() {
+        let parse = |extra: &[&str]| {
+            let mut argv = vec!["rvl", "old.csv", "new.csv"];
+            argv.extend_from_slice(extra);
+            Args::parse_from(argv)
+        };
+        let args = parse(&["--group-by", "account"]).unwrap();
+        assert_eq!(args.key_column(), Some("account"));
+        assert_eq!(args.dedup_mode(), DedupMode::Sum);
+        assert_eq!(parse(&[]).unwrap().dedup_mode(), DedupMode::Refuse);
+        assert!(parse(&["--group-by", "account", "--key", "id"]).is_err());
+        assert!(parse(&["--group-by", "account", "--dedup", "first"]).is_err());
+    }
+
+    #[test]
+    fn parse_reads_output_version_pins() {
+        let parse = |extra: &[&str]| {
+            let mut argv = vec!["rvl", "old.csv", "new.csv"];
+            argv.extend_from_slice(extra);
+            Args::parse_from(argv)
+        };
+        assert_eq!(
+            parse(&["--output-version", "rvl.v0"])
+                .unwrap()
+                .output_version,
+            OutputVersion::V0
+        );
+        assert!(parse(&["--output-version", "v1"]).is_err());
+
+        let args = Args::parse_from([
+            "rvl",
+            "schema",
+            "--output",
+            "json",
+            "--output-version",
+            "v0",
+        ])
+        .expect("schema should parse");
+        assert!(matches!(
+            args.command,
+            Some(RvlCommand::Schema(SchemaArgs {
+                output_version: OutputVersion::V0,
+                ..
+            }))
+        ));
+        assert!(Args::parse_from(["rvl", "schema", "--output", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn compare_subcommand_is_an_alias_for_the_default_comparison() {
+        let args = Args::parse_from(["rvl", "compare", "old.csv", "new.csv", "--key", "id"])
+            .expect("compare should parse");
+        assert!(args.command.is_none());
+        assert_eq!(args.old_path().to_str(), Some("old.csv"));
+        assert_eq!(args.key.as_deref(), Some("id"));
+    }
+
+    #[test]
+    fn watch_subcommand_takes_comparison_flags() {
+        let args = Args::parse_from([
+            "rvl", "watch", "old.csv", "new.csv", "--key", "id", "--json",
+        ])
+        .expect("watch should parse");
+        assert!(args.watch);
+        assert!(args.command.is_none());
+        assert_eq!(args.new.as_deref().and_then(Path::to_str), Some("new.csv"));
+        assert!(args.json);
+        assert!(
+            !Args::parse_from(["rvl", "old.csv", "new.csv"])
+                .unwrap()
+                .watch
+        );
+    }
+
+    #[test]
+    fn assert_subcommand_takes_an_expected_verdict() {
+        let args = Args::parse_from([
+            "rvl",
+            "assert",
+            "old.csv",
+            "new.csv",
+            "--key",
+            "id",
+            "--expect",
+            "no-real-change",
+        ])
+        .expect("assert should parse");
+        assert!(args.assert);
+        assert!(!args.watch);
+        assert_eq!(
+            args.expect,
+            Some(ExpectedVerdict::Outcome(Outcome::NoRealChange))
+        );
+        assert!(
+            Args::parse_from([
+                "rvl",
+                "assert",
+                "old.csv",
+                "new.csv",
+                "--expect",
+                "E_KEY_DUP",
+                "--expect-json",
+                "ok.json",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn sample_takes_a_positive_size_and_an_optional_seed() {
+        let args = Args::parse_from([
+            "rvl", "old.csv", "new.csv", "--key", "id", "--sample", "1000", "--seed", "42",
+        ])
+        .expect("sample should parse");
+        assert_eq!(args.sample, Some(1000));
+        assert_eq!(args.seed, 42);
+        assert!(Args::parse_from(["rvl", "old.csv", "new.csv", "--sample", "0"]).is_err());
+        assert!(Args::parse_from(["rvl", "old.csv", "new.csv", "--seed", "42"]).is_err());
+    }
 }
@@ -0,0 +1,373 @@
+//! Config file support (`--config rvl.toml`, or `.rvl.toml` in the working
+//! directory).
+//!
+//! A config file holds flag defaults for a project so long invocations stay
+//! short. Top-level keys are long flag names, with `-` or `_` (`key`,
+//! `tolerance`, `na-values`, `columns`, `delimiter`, ...); a `[tolerances]`
+//! table sets per-column tolerances (`--column-tolerance`). Flags given on
+//! the command line or through `RVL_*` variables win over the file, and the
+//! file wins over built-in defaults.
+//!
+//! Only the TOML subset these settings need is read: `#` comments,
+//! `key = value` lines with basic or literal strings, integers, floats,
+//! booleans, and single-line arrays, plus the `[tolerances]` table.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Config file picked up from the working directory when `--config` is not given.
+pub const DISCOVERED_CONFIG: &str = ".rvl.toml";
+
+const TOLERANCES_TABLE: &str = "tolerances";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<ConfigValue>),
+}
+
+impl ConfigValue {
+    /// Flag values for this setting: one per array item, else one.
+    pub fn flag_values(&self) -> Result<Vec<String>, String> {
+        match self {
+            ConfigValue::Array(items) => items
+                .iter()
+                .map(|item| match item {
+                    ConfigValue::Array(_) => Err("nested arrays are not supported".to_string()),
+                    scalar => Ok(scalar.scalar_text()),
+                })
+                .collect(),
+            scalar => Ok(vec![scalar.scalar_text()]),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            ConfigValue::Bool(value) => Value::Bool(*value),
+            ConfigValue::Integer(value) => Value::from(*value),
+            ConfigValue::Float(value) => Value::from(*value),
+            ConfigValue::String(value) => Value::String(value.clone()),
+            ConfigValue::Array(items) => Value::Array(items.iter().map(Self::to_json).collect()),
+        }
+    }
+
+    fn scalar_text(&self) -> String {
+        match self {
+            ConfigValue::Bool(value) => value.to_string(),
+            ConfigValue::Integer(value) => value.to_string(),
+            ConfigValue::Float(value) => value.to_string(),
+            ConfigValue::String(value) => value.clone(),
+            ConfigValue::Array(_) => unreachable!("arrays are expanded by flag_values"),
+        }
+    }
+}
+
+/// One top-level `name = value` line; `name` is folded to flag form (`na-values`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigSetting {
+    pub name: String,
+    pub value: ConfigValue,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigFile {
+    pub settings: Vec<ConfigSetting>,
+    /// `[tolerances]` entries as (column, tolerance), in file order.
+    pub tolerances: Vec<(String, f64)>,
+}
+
+/// What a config file contributed to one run, echoed in the JSON receipt.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigReceipt {
+    pub path: String,
+    /// Settings taken from the file, by flag name (`na_values`).
+    pub settings: Map<String, Value>,
+    /// Settings in the file that a CLI flag or `RVL_*` variable overrode.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub overridden: Vec<String>,
+}
+
+impl ConfigReceipt {
+    pub fn new(path: &std::path::Path) -> Self {
+        Self {
+            path: path.to_string_lossy().to_string(),
+            settings: Map::new(),
+            overridden: Vec::new(),
+        }
+    }
+}
+
+/// The config to read for this run: `--config`, else a discovered
+/// `.rvl.toml`. `None` when neither applies.
+pub fn config_path(explicit: Option<&PathBuf>, discover: bool) -> Option<PathBuf> {
+    if let Some(path) = explicit {
+        return Some(path.clone());
+    }
+    let discovered = PathBuf::from(DISCOVERED_CONFIG);
+    (discover && discovered.is_file()).then_some(discovered)
+}
+
+/// Parse config text. Errors name the 1-based line.
+pub fn parse_config(raw: &str) -> Result<ConfigFile, String> {
+    let mut config = ConfigFile::default();
+    let mut table: Option<String> = None;
+    for (idx, line) in raw.lines().enumerate() {
+        let line_no = idx + 1;
+        let at_line = |msg: String| format!("line {line_no}: {msg}");
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(rest) = trimmed.strip_prefix('[') {
+            let name = rest
+                .split_once(']')
+                .filter(|(_, tail)| is_blank_or_comment(tail))
+                .map(|(name, _)| name.trim())
+                .ok_or_else(|| at_line("malformed table header".to_string()))?;
+            if name != TOLERANCES_TABLE {
+                return Err(at_line(format!(
+                    "unsupported table [{name}] (only [{TOLERANCES_TABLE}])"
+                )));
+            }
+            table = Some(name.to_string());
+            continue;
+        }
+
+        let (key, rest) = parse_key(trimmed).map_err(at_line)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix('=')
+            .ok_or_else(|| at_line(format!("expected `=` after `{key}`")))?;
+        let (value, tail) = parse_value(rest.trim_start()).map_err(at_line)?;
+        if !is_blank_or_comment(tail) {
+            return Err(at_line(format!(
+                "unexpected text after value: {}",
+                tail.trim()
+            )));
+        }
+
+        if table.is_some() {
+            let tolerance = match value {
+                ConfigValue::Integer(value) => value as f64,
+                ConfigValue::Float(value) => value,
+                _ => return Err(at_line(format!("tolerance for `{key}` must be a number"))),
+            };
+            if config.tolerances.iter().any(|(column, _)| *column == key) {
+                return Err(at_line(format!("duplicate tolerance for `{key}`")));
+            }
+            config.tolerances.push((key, tolerance));
+        } else {
+            let name = key.replace('_', "-");
+            if config.settings.iter().any(|setting| setting.name == name) {
+                return Err(at_line(format!("duplicate setting `{key}`")));
+            }
+            config.settings.push(ConfigSetting { name, value });
+        }
+    }
+    Ok(config)
+}
+
+fn is_blank_or_comment(raw: &str) -> bool {
+    let trimmed = raw.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+fn parse_key(raw: &str) -> Result<(String, &str), String> {
+    if raw.starts_with('"') || raw.starts_with('\'') {
+        let (value, rest) = parse_string(raw)?;
+        if value.is_empty() {
+            return Err("empty key".to_string());
+        }
+        return Ok((value, rest));
+    }
+    let end = raw
+        .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'))
+        .unwrap_or(raw.len());
+    if end == 0 {
+        return Err("expected a key".to_string());
+    }
+    Ok((raw[..end].to_string(), &raw[end..]))
+}
+
+fn parse_value(raw: &str) -> Result<(ConfigValue, &str), String> {
+    if raw.starts_with('"') || raw.starts_with('\'') {
+        let (value, rest) = parse_string(raw)?;
+        return Ok((ConfigValue::String(value), rest));
+    }
+    if let Some(mut rest) = raw.strip_prefix('[') {
+        let mut items = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(tail) = rest.strip_prefix(']') {
+                return Ok((ConfigValue::Array(items), tail));
+            }
+            if rest.is_empty() {
+                return Err("unterminated array (arrays must fit on one line)".to_string());
+            }
+            let (item, tail) = parse_value(rest)?;
+            items.push(item);
+            rest = tail.trim_start();
+            if let Some(tail) = rest.strip_prefix(',') {
+                rest = tail;
+            } else if !rest.starts_with(']') {
+                return Err("expected `,` or `]` in array".to_string());
+            }
+        }
+    }
+
+    let end = raw
+        .find(|ch: char| ch.is_whitespace() || matches!(ch, ',' | ']' | '#'))
+        .unwrap_or(raw.len());
+    let (token, rest) = raw.split_at(end);
+    let value = match token {
+        "true" => ConfigValue::Bool(true),
+        "false" => ConfigValue::Bool(false),
+        _ => parse_number(token).ok_or_else(|| format!("invalid value `{token}`"))?,
+    };
+    Ok((value, rest))
+}
+
+fn parse_number(token: &str) -> Option<ConfigValue> {
+    let digits = token.replace('_', "");
+    let unsigned = digits.trim_start_matches(['+', '-']);
+    if !unsigned.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return None;
+    }
+    if !unsigned.contains(['.', 'e', 'E']) {
+        return digits.parse().ok().map(ConfigValue::Integer);
+    }
+    digits
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(ConfigValue::Float)
+}
+
+fn parse_string(raw: &str) -> Result<(String, &str), String> {
+    let quote = raw.chars().next().unwrap_or('"');
+    let body = &raw[1..];
+    if quote == '\'' {
+        let end = body
+            .find('\'')
+            .ok_or_else(|| "unterminated string".to_string())?;
+        return Ok((body[..end].to_string(), &body[end + 1..]));
+    }
+
+    let mut value = String::new();
+    let mut chars = body.char_indices();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' => return Ok((value, &body[idx + 1..])),
+            '\\' => {
+                let escaped = match chars.next().map(|(_, ch)| ch) {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some(other) => return Err(format!("unsupported escape `\\{other}`")),
+                    None => break,
+                };
+                value.push(escaped);
+            }
+            _ => value.push(ch),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(name: &str, value: ConfigValue) -> ConfigSetting {
+        ConfigSetting {
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn parses_settings_and_tolerances() {
+        let config = parse_config(
+            r#"
+# project defaults
+key = "loan_id"
+tolerance = 0.01   # cents
+top = 10
+na_values = ["TBD", '#N/A', "-"]
+explicit = true
+
+[tolerances]
+balance = 0.5
+"net income" = 1
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.settings,
+            vec![
+                setting("key", ConfigValue::String("loan_id".to_string())),
+                setting("tolerance", ConfigValue::Float(0.01)),
+                setting("top", ConfigValue::Integer(10)),
+                setting(
+                    "na-values",
+                    ConfigValue::Array(vec![
+                        ConfigValue::String("TBD".to_string()),
+                        ConfigValue::String("#N/A".to_string()),
+                        ConfigValue::String("-".to_string()),
+                    ])
+                ),
+                setting("explicit", ConfigValue::Bool(true)),
+            ]
+        );
+        assert_eq!(
+            config.tolerances,
+            vec![
+                ("balance".to_string(), 0.5),
+                ("net income".to_string(), 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines_with_line_numbers() {
+        assert_eq!(
+            parse_config("key = \"id\"\nkey = \"other\"").unwrap_err(),
+            "line 2: duplicate setting `key`"
+        );
+        assert_eq!(
+            parse_config("tolerance 0.1").unwrap_err(),
+            "line 1: expected `=` after `tolerance`"
+        );
+        assert_eq!(
+            parse_config("[output]").unwrap_err(),
+            "line 1: unsupported table [output] (only [tolerances])"
+        );
+        assert_eq!(
+            parse_config("[tolerances]\nbalance = \"x\"").unwrap_err(),
+            "line 2: tolerance for `balance` must be a number"
+        );
+        assert!(parse_config("na_values = [\"a\",").is_err());
+        assert!(parse_config("key = id").is_err());
+        assert!(parse_config("key = \"id\" extra").is_err());
+    }
+
+    #[test]
+    fn expands_flag_values() {
+        let value = ConfigValue::Array(vec![
+            ConfigValue::String("a".to_string()),
+            ConfigValue::Integer(2),
+        ]);
+        assert_eq!(value.flag_values().unwrap(), vec!["a", "2"]);
+        assert_eq!(
+            ConfigValue::Float(1e-9).flag_values().unwrap(),
+            vec!["0.000000001"]
+        );
+    }
+}
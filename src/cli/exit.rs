@@ -8,6 +8,17 @@ pub enum Outcome {
     Refusal,
 }
 
+impl Outcome {
+    /// The `outcome` string used in JSON output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::NoRealChange => "NO_REAL_CHANGE",
+            Outcome::RealChange => "REAL_CHANGE",
+            Outcome::Refusal => "REFUSAL",
+        }
+    }
+}
+
 /// Output mode chosen by the CLI.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputMode {
@@ -22,6 +33,37 @@ pub enum OutputStream {
     Stderr,
 }
 
+/// Stream selection requested with `--output-stream`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamPolicy {
+    /// Route by outcome and mode (see [`output_stream`]).
+    #[default]
+    Auto,
+    /// Every outcome goes to stdout.
+    Stdout,
+    /// Every outcome goes to stderr.
+    Stderr,
+}
+
+impl StreamPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StreamPolicy::Auto => "auto",
+            StreamPolicy::Stdout => "stdout",
+            StreamPolicy::Stderr => "stderr",
+        }
+    }
+
+    /// Resolve the target stream, deferring to outcome routing under `Auto`.
+    pub fn resolve(self, outcome: Outcome, mode: OutputMode) -> OutputStream {
+        match self {
+            StreamPolicy::Auto => output_stream(outcome, mode),
+            StreamPolicy::Stdout => OutputStream::Stdout,
+            StreamPolicy::Stderr => OutputStream::Stderr,
+        }
+    }
+}
+
 /// Exit code for a given outcome (domain-level only).
 pub fn exit_code(outcome: Outcome) -> u8 {
     match outcome {
@@ -85,4 +127,20 @@ mod tests {
             OutputStream::Stderr
         );
     }
+
+    #[test]
+    fn stream_policy_overrides_outcome_routing() {
+        assert_eq!(
+            StreamPolicy::Auto.resolve(Outcome::Refusal, OutputMode::Human),
+            OutputStream::Stderr
+        );
+        assert_eq!(
+            StreamPolicy::Stdout.resolve(Outcome::Refusal, OutputMode::Human),
+            OutputStream::Stdout
+        );
+        assert_eq!(
+            StreamPolicy::Stderr.resolve(Outcome::RealChange, OutputMode::Json),
+            OutputStream::Stderr
+        );
+    }
 }
@@ -1,3 +1,4 @@
 pub mod args;
+pub mod config;
 pub mod delimiter;
 pub mod exit;
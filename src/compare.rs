@@ -0,0 +1,266 @@
+//! Library entry point: compare two inputs without building CLI [`Args`].
+//!
+//! Inputs may be paths, byte buffers, or readers. Byte inputs ride on
+//! [`Args`] as in-memory sources, so they run through exactly the same
+//! pipeline as files without touching disk, and their name is what appears in
+//! the report's `files` block. Results are typed: the verdict's data is read
+//! from the structured report, never re-parsed from JSON text.
+
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::cli::args::{Args, DEFAULT_MIN_HEADER_OVERLAP, DEFAULT_THRESHOLD, DEFAULT_TOLERANCE};
+use crate::cli::exit::{Outcome, exit_code};
+use crate::output::json::{Contributor, Counts, JsonOutput, Metrics};
+use crate::refusal::codes::RefusalCode;
+use crate::refusal::process::PipelineError;
+
+/// One side of a comparison.
+#[derive(Debug, Clone)]
+pub enum CompareInput {
+    Path(PathBuf),
+    Bytes { name: String, bytes: Vec<u8> },
+}
+
+impl CompareInput {
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        CompareInput::Path(path.into())
+    }
+
+    /// In-memory CSV; `name` labels it in the report (e.g. `old.csv`).
+    pub fn bytes(name: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        CompareInput::Bytes {
+            name: name.into(),
+            bytes: bytes.into(),
+        }
+    }
+
+    /// Drain `reader` into an in-memory input.
+    pub fn reader(name: impl Into<String>, mut reader: impl Read) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self::bytes(name, bytes))
+    }
+}
+
+/// Comparison settings; defaults match the CLI.
+#[derive(Debug, Clone)]
+pub struct CompareOptions {
+    pub old: CompareInput,
+    pub new: CompareInput,
+    /// Align rows by this key column (otherwise align by row order).
+    pub key: Option<String>,
+    pub threshold: f64,
+    pub tolerance: f64,
+    pub smart_tolerance: bool,
+    pub min_header_overlap: f64,
+    /// Force a delimiter byte instead of sniffing the dialect.
+    pub delimiter: Option<u8>,
+}
+
+impl CompareOptions {
+    pub fn new(old: CompareInput, new: CompareInput) -> Self {
+        Self {
+            old,
+            new,
+            key: None,
+            threshold: DEFAULT_THRESHOLD,
+            tolerance: DEFAULT_TOLERANCE,
+            smart_tolerance: false,
+            min_header_overlap: DEFAULT_MIN_HEADER_OVERLAP,
+            delimiter: None,
+        }
+    }
+}
+
+/// Typed verdict. Every variant keeps the full `rvl.v0` report for the
+/// remaining fields (files, dialect, warnings, ...).
+#[derive(Debug, Clone)]
+pub enum CompareResult {
+    RealChange {
+        contributors: Vec<Contributor>,
+        metrics: Metrics,
+        counts: Counts,
+        report: Box<JsonOutput>,
+    },
+    NoRealChange {
+        metrics: Metrics,
+        counts: Counts,
+        report: Box<JsonOutput>,
+    },
+    Refusal {
+        code: RefusalCode,
+        detail: Value,
+        report: Box<JsonOutput>,
+    },
+}
+
+impl CompareResult {
+    fn from_report(outcome: Outcome, report: Box<JsonOutput>) -> Self {
+        match outcome {
+            Outcome::Refusal => {
+                let refusal = report
+                    .refusal
+                    .as_ref()
+                    .expect("refusals carry a refusal block");
+                CompareResult::Refusal {
+                    code: RefusalCode::from_str(&refusal.code)
+                        .expect("pipeline emits known refusal codes"),
+                    detail: refusal.detail.clone(),
+                    report,
+                }
+            }
+            Outcome::RealChange => CompareResult::RealChange {
+                contributors: report.contributors.clone(),
+                metrics: report.metrics.clone(),
+                counts: report.counts.clone(),
+                report,
+            },
+            Outcome::NoRealChange => CompareResult::NoRealChange {
+                metrics: report.metrics.clone(),
+                counts: report.counts.clone(),
+                report,
+            },
+        }
+    }
+
+    pub fn outcome(&self) -> Outcome {
+        match self {
+            CompareResult::RealChange { .. } => Outcome::RealChange,
+            CompareResult::NoRealChange { .. } => Outcome::NoRealChange,
+            CompareResult::Refusal { .. } => Outcome::Refusal,
+        }
+    }
+
+    /// CLI exit code for the verdict (0, 1, or 2).
+    pub fn exit_code(&self) -> u8 {
+        exit_code(self.outcome())
+    }
+
+    /// The full `rvl.v0` report; serialize it to get `--json` output.
+    pub fn report(&self) -> &JsonOutput {
+        match self {
+            CompareResult::RealChange { report, .. }
+            | CompareResult::NoRealChange { report, .. }
+            | CompareResult::Refusal { report, .. } => report,
+        }
+    }
+}
+
+/// Compare two inputs. Domain refusals are an `Ok` result with
+/// [`CompareResult::Refusal`]; `Err` is reserved for process-level failures, as
+/// with [`crate::orchestrator::run`]. No witness record is written.
+pub fn compare(options: CompareOptions) -> Result<CompareResult, PipelineError> {
+    let same_name = matches!(
+        (&options.old, &options.new),
+        (CompareInput::Bytes { name: old, .. }, CompareInput::Bytes { name: new, .. }) if old == new
+    );
+    let (old, old_bytes) = resolve(options.old, same_name.then_some("old"));
+    let (new, new_bytes) = resolve(options.new, same_name.then_some("new"));
+
+    let mut args = Args::new(
+        old,
+        new,
+        options.key,
+        options.threshold,
+        options.tolerance,
+        options.delimiter,
+        true,
+    );
+    args.smart_tolerance = options.smart_tolerance;
+    args.min_header_overlap = options.min_header_overlap;
+    args.inline.old = old_bytes;
+    args.inline.new = new_bytes;
+    let result = crate::orchestrator::run(&args)?;
+    let outcome = result.outcome;
+    Ok(CompareResult::from_report(outcome, result.into_report()?))
+}
+
+/// Path to hand the pipeline, and the bytes to read in its place for a byte
+/// input. Two byte inputs with the same name are told apart by an
+/// `old/`/`new/` prefix.
+fn resolve(input: CompareInput, side: Option<&str>) -> (PathBuf, Option<Arc<[u8]>>) {
+    match input {
+        CompareInput::Path(path) => (path, None),
+        CompareInput::Bytes { name, bytes } => {
+            let path = match side {
+                Some(side) => Path::new(side).join(name),
+                None => PathBuf::from(name),
+            };
+            (path, Some(bytes.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_byte_inputs_without_touching_disk() {
+        let options = CompareOptions {
+            key: Some("id".to_string()),
+            ..CompareOptions::new(
+                CompareInput::bytes("unit_old.csv", "id,v\nA,1\nB,2\n"),
+                CompareInput::bytes("unit_new.csv", "id,v\nA,1\nB,7\n"),
+            )
+        };
+        let result = compare(options).unwrap();
+        assert_eq!(result.exit_code(), 1);
+        assert_eq!(result.report().files.old, "unit_old.csv");
+        let CompareResult::RealChange {
+            contributors,
+            metrics,
+            counts,
+            ..
+        } = result
+        else {
+            panic!("expected REAL_CHANGE");
+        };
+        assert_eq!(contributors[0].row_id, "u8:B");
+        assert_eq!(metrics.total_change, Some(5.0));
+        assert_eq!(counts.rows_aligned, Some(2));
+    }
+
+    #[test]
+    fn byte_inputs_never_read_a_file_with_the_same_name() {
+        let result = compare(CompareOptions::new(
+            CompareInput::bytes("Cargo.toml", "id,v\n1,1\n"),
+            CompareInput::path("Cargo.toml"),
+        ))
+        .unwrap();
+        assert_eq!(result.outcome(), Outcome::Refusal);
+        assert_eq!(result.report().files.old, "Cargo.toml");
+    }
+
+    #[test]
+    fn same_named_byte_inputs_stay_distinct() {
+        let result = compare(CompareOptions::new(
+            CompareInput::bytes("unit_same.csv", "id,v\n1,1\n"),
+            CompareInput::reader("unit_same.csv", &b"id,v\n1,1\n"[..]).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(result.outcome(), Outcome::NoRealChange);
+        assert_eq!(result.report().files.old, "old/unit_same.csv");
+        assert_eq!(result.report().files.new, "new/unit_same.csv");
+    }
+
+    #[test]
+    fn refusals_carry_a_typed_code() {
+        let result = compare(CompareOptions::new(
+            CompareInput::bytes("unit_rows_old.csv", "id,v\n1,1\n"),
+            CompareInput::bytes("unit_rows_new.csv", "id,v\n1,1\n2,2\n"),
+        ))
+        .unwrap();
+        let CompareResult::Refusal { code, detail, .. } = result else {
+            panic!("expected REFUSAL");
+        };
+        assert_eq!(code, RefusalCode::RowCount);
+        assert_eq!(detail["rows_new"], 2);
+    }
+}
@@ -0,0 +1,112 @@
+//! Comment-line exclusion (`--comment`).
+//!
+//! Tools that append `# generated by ...` lines mid-file break width
+//! normalization: a comment with commas in it reads as a record with extra
+//! fields. `--comment '#'` blanks every line that starts with the prefix
+//! (after leading ASCII spaces/tabs) before dialect detection, so the
+//! existing blank-line handling drops it and reported line numbers still
+//! match the file. Lines inside a quoted field are never treated as comments.
+
+use std::borrow::Cow;
+
+use crate::normalize::trim::ascii_trim;
+
+/// Blank the comment lines of `input`, keeping their line endings.
+/// Returns the rewritten input and the number of lines blanked; input with
+/// no comment lines is borrowed unchanged.
+pub fn blank_comment_lines<'a>(input: &'a [u8], prefix: &[u8]) -> (Cow<'a, [u8]>, u64) {
+    let mut output: Option<Vec<u8>> = None;
+    let mut count = 0;
+    let mut in_quotes = false;
+    let mut start = 0;
+    while start < input.len() {
+        let end = input[start..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map_or(input.len(), |at| start + at);
+        let line = &input[start..end];
+        if !in_quotes && is_comment(line, prefix) {
+            let output = output.get_or_insert_with(|| input[..start].to_vec());
+            count += 1;
+            if end < input.len() {
+                output.push(b'\n');
+            }
+        } else {
+            // An odd number of quotes leaves a quoted field open; `""`
+            // escapes toggle twice and cancel out.
+            let quotes = line.iter().filter(|byte| **byte == b'"').count();
+            in_quotes ^= quotes % 2 == 1;
+            if let Some(output) = output.as_mut() {
+                output.extend_from_slice(&input[start..(end + 1).min(input.len())]);
+            }
+        }
+        start = end + 1;
+    }
+    match output {
+        Some(output) => (Cow::Owned(output), count),
+        None => (Cow::Borrowed(input), 0),
+    }
+}
+
+fn is_comment(line: &[u8], prefix: &[u8]) -> bool {
+    let leading = line
+        .iter()
+        .take_while(|byte| matches!(byte, b' ' | b'\t'))
+        .count();
+    line[leading..].starts_with(prefix)
+}
+
+/// Validate a `--comment` prefix: non-empty after trimming, no quotes or
+/// line breaks.
+pub fn parse_comment_prefix(raw: &str) -> Result<String, String> {
+    let prefix = ascii_trim(raw.as_bytes());
+    if prefix.is_empty() {
+        return Err("comment prefix must be non-empty".to_string());
+    }
+    if prefix
+        .iter()
+        .any(|byte| matches!(byte, b'"' | b'\n' | b'\r'))
+    {
+        return Err("comment prefix must not contain quotes or line breaks".to_string());
+    }
+    Ok(String::from_utf8_lossy(prefix).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comment_lines_are_blanked_in_place() {
+        let input = b"# export v2\nid,note\nA,1\n  # generated by tool, x\nB,2\n#end";
+        let (output, count) = blank_comment_lines(input, b"#");
+        assert_eq!(count, 3);
+        assert_eq!(output.as_ref(), b"\nid,note\nA,1\n\nB,2\n");
+
+        let (output, count) = blank_comment_lines(b"id\nA\n", b"#");
+        assert_eq!(count, 0);
+        assert!(matches!(output, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn quoted_lines_are_not_comments() {
+        let input = b"id,note\nA,\"first\n# still the note\"\n// gone\nB,\"\"\"x\"\n";
+        let (output, count) = blank_comment_lines(input, b"#");
+        assert_eq!(count, 0);
+        assert_eq!(output.as_ref(), input.as_slice());
+
+        let (output, count) = blank_comment_lines(input, b"//");
+        assert_eq!(count, 1);
+        assert_eq!(
+            output.as_ref(),
+            b"id,note\nA,\"first\n# still the note\"\n\nB,\"\"\"x\"\n".as_slice()
+        );
+    }
+
+    #[test]
+    fn prefixes_are_validated() {
+        assert_eq!(parse_comment_prefix(" # "), Ok("#".to_string()));
+        assert!(parse_comment_prefix("  ").is_err());
+        assert!(parse_comment_prefix("\"").is_err());
+    }
+}
@@ -0,0 +1,118 @@
+//! Transparent decompression of gzip/zstd inputs, detected by magic bytes
+//! before the encoding guard so `.csv.gz` exports diff like plain CSV.
+
+use std::borrow::Cow;
+use std::io::{self, Read};
+
+use serde::Serialize;
+
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Detect a compressed stream from its leading magic bytes.
+    pub fn detect(input: &[u8]) -> Option<Self> {
+        if input.starts_with(&GZIP_MAGIC) {
+            Some(Compression::Gzip)
+        } else if input.starts_with(&ZSTD_MAGIC) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Zstd => "zstd",
+        }
+    }
+}
+
+/// Decompress `input` if it starts with gzip or zstd magic bytes; plain input
+/// is borrowed unchanged. Errors carry a short reason for the refusal.
+pub fn decompress(input: &[u8]) -> Result<(Cow<'_, [u8]>, Option<Compression>), String> {
+    let Some(compression) = Compression::detect(input) else {
+        return Ok((Cow::Borrowed(input), None));
+    };
+    let mut output = Vec::new();
+    let result = match compression {
+        // MultiGzDecoder also reads concatenated members (`cat a.gz b.gz`).
+        Compression::Gzip => flate2::read::MultiGzDecoder::new(input)
+            .read_to_end(&mut output)
+            .map_err(|err| err.to_string()),
+        Compression::Zstd => ruzstd::decoding::StreamingDecoder::new(input)
+            .map_err(|err| err.to_string())
+            .and_then(|mut decoder| {
+                decoder
+                    .read_to_end(&mut output)
+                    .map_err(|err| err.to_string())
+            }),
+    };
+    result.map_err(|err| format!("cannot decompress {} input ({err})", compression.as_str()))?;
+    Ok((Cow::Owned(output), Some(compression)))
+}
+
+/// Stream-decode `reader`, which must start at the compression magic bytes.
+pub fn decode_reader<'a>(
+    compression: Compression,
+    reader: impl Read + 'a,
+) -> io::Result<Box<dyn Read + 'a>> {
+    match compression {
+        Compression::Gzip => Ok(Box::new(flate2::read::MultiGzDecoder::new(reader))),
+        Compression::Zstd => ruzstd::decoding::StreamingDecoder::new(reader)
+            .map(|decoder| Box::new(decoder) as Box<dyn Read + 'a>)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn plain_input_is_borrowed() {
+        let (bytes, compression) = decompress(b"a,b\n1,2\n").unwrap();
+        assert!(matches!(bytes, Cow::Borrowed(_)));
+        assert_eq!(compression, None);
+    }
+
+    #[test]
+    fn gzip_input_is_decompressed() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"a,b\n1,2\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let (bytes, compression) = decompress(&compressed).unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n");
+        assert_eq!(compression, Some(Compression::Gzip));
+    }
+
+    #[test]
+    fn zstd_input_is_decompressed() {
+        let compressed = ruzstd::encoding::compress_to_vec(
+            &b"a,b\n1,2\n"[..],
+            ruzstd::encoding::CompressionLevel::Fastest,
+        );
+
+        let (bytes, compression) = decompress(&compressed).unwrap();
+        assert_eq!(bytes.as_ref(), b"a,b\n1,2\n");
+        assert_eq!(compression, Some(Compression::Zstd));
+    }
+
+    #[test]
+    fn truncated_stream_is_an_error() {
+        let err = decompress(&[0x1F, 0x8B, 0x08]).unwrap_err();
+        assert!(err.starts_with("cannot decompress gzip input"), "{err}");
+        let err = decompress(&[0x28, 0xB5, 0x2F, 0xFD, 0x00]).unwrap_err();
+        assert!(err.starts_with("cannot decompress zstd input"), "{err}");
+    }
+}
@@ -25,6 +25,14 @@ pub struct DialectScore {
 }
 
 impl DialectScore {
+    /// Share of sampled records (header included) with the modal width.
+    fn consistency(self) -> f64 {
+        if self.records_parsed == 0 {
+            return 0.0;
+        }
+        self.mode_count as f64 / self.records_parsed as f64
+    }
+
     fn cmp_tuple(self, other: Self) -> Ordering {
         (self.records_parsed, self.mode_count, self.mode_fields).cmp(&(
             other.records_parsed,
@@ -42,6 +50,9 @@ pub struct Dialect {
     pub escape: EscapeMode,
     pub header_fields: usize,
     pub score: DialectScore,
+    /// How much more consistently the chosen delimiter splits the sample
+    /// than the best multi-column alternative, in `0.0..=1.0`.
+    pub confidence: f64,
 }
 
 /// Auto-detection failures.
@@ -127,12 +138,24 @@ pub fn auto_detect(input: &[u8]) -> Result<Dialect, DialectError> {
         });
     }
 
+    // Single-column parses and identical samples are not real contenders.
+    let runner_up = candidates
+        .iter()
+        .filter(|candidate| {
+            candidate.delimiter != chosen.delimiter
+                && candidate.score.mode_fields > 1
+                && candidate.records != chosen.records
+        })
+        .map(|candidate| candidate.score.consistency())
+        .fold(0.0, f64::max);
+
     Ok(Dialect {
         delimiter: chosen.delimiter,
         quote: b'"',
         escape: chosen.escape,
         header_fields: chosen.header_fields,
         score: chosen.score,
+        confidence: (chosen.score.consistency() - runner_up).max(0.0),
     })
 }
 
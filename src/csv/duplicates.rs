@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::csv::records::OwnedRecord;
+
+/// Sample duplicates kept for warnings (first occurrences in record order).
+pub const MAX_DUPLICATE_SAMPLES: usize = 5;
+
+/// One repeated data record and the earlier record it copies (1-based).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateSample {
+    pub record: u64,
+    pub duplicate_of: u64,
+}
+
+/// Fully duplicated data records within one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateRows {
+    /// Records that exactly repeat an earlier record (first copies not counted).
+    pub rows: u64,
+    pub samples: Vec<DuplicateSample>,
+}
+
+/// Find data records whose normalized fields exactly match an earlier record.
+pub fn find_duplicate_rows(records: &[OwnedRecord]) -> Option<DuplicateRows> {
+    let mut first_seen: HashMap<&OwnedRecord, u64> = HashMap::with_capacity(records.len());
+    let mut rows = 0u64;
+    let mut samples = Vec::new();
+    for (idx, record) in records.iter().enumerate() {
+        let record_number = (idx + 1) as u64;
+        if let Some(&first) = first_seen.get(record) {
+            rows += 1;
+            if samples.len() < MAX_DUPLICATE_SAMPLES {
+                samples.push(DuplicateSample {
+                    record: record_number,
+                    duplicate_of: first,
+                });
+            }
+        } else {
+            first_seen.insert(record, record_number);
+        }
+    }
+    (rows > 0).then_some(DuplicateRows { rows, samples })
+}
+
+/// Drop every record that repeats an earlier one, keeping first occurrences in
+/// order. Each record's source line is kept or dropped with it.
+pub fn dedupe_rows(records: Vec<OwnedRecord>, lines: Vec<u64>) -> (Vec<OwnedRecord>, Vec<u64>) {
+    let keep: Vec<bool> = {
+        let mut seen = HashSet::with_capacity(records.len());
+        records.iter().map(|record| seen.insert(record)).collect()
+    };
+    records
+        .into_iter()
+        .zip(lines)
+        .zip(keep)
+        .filter_map(|(pair, keep)| keep.then_some(pair))
+        .unzip()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(rows: &[&[&str]]) -> Vec<OwnedRecord> {
+        rows.iter()
+            .map(|row| row.iter().map(|field| field.as_bytes()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn counts_repeats_and_samples_the_first_copy() {
+        let input = records(&[
+            &["A", "1"],
+            &["B", "2"],
+            &["A", "1"],
+            &["A", "1"],
+            &["B", "3"],
+        ]);
+        let found = find_duplicate_rows(&input).unwrap();
+        assert_eq!(found.rows, 2);
+        assert_eq!(
+            found.samples,
+            vec![
+                DuplicateSample {
+                    record: 3,
+                    duplicate_of: 1
+                },
+                DuplicateSample {
+                    record: 4,
+                    duplicate_of: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn distinct_records_have_no_duplicates() {
+        let input = records(&[&["A", "1"], &["A", "2"]]);
+        assert_eq!(find_duplicate_rows(&input), None);
+    }
+
+    #[test]
+    fn dedupe_keeps_first_occurrences_in_order() {
+        let input = records(&[&["B", "2"], &["A", "1"], &["B", "2"], &["C", "3"]]);
+        let (kept, lines) = dedupe_rows(input, vec![2, 3, 4, 6]);
+        assert_eq!(kept, records(&[&["B", "2"], &["A", "1"], &["C", "3"]]));
+        assert_eq!(lines, vec![2, 3, 6]);
+    }
+}
@@ -0,0 +1,202 @@
+//! Transcoding of UTF-16 and Windows-1252 inputs to UTF-8 (`--encoding`).
+//!
+//! By default rvl reads UTF-8 only and refuses UTF-16/UTF-32 BOMs with
+//! `E_ENCODING`. `--encoding auto` transcodes UTF-16 that starts with a BOM
+//! (Excel's "Unicode Text" export) and reads input that is not valid UTF-8 as
+//! Windows-1252; `utf16` and `windows-1252` force one decoder. Transcoding
+//! runs after decompression and before the encoding guard, so UTF-32 input
+//! is still refused.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use super::input::{UTF8_BOM, UTF16_BE_BOM, UTF16_LE_BOM, UTF32_BE_BOM, UTF32_LE_BOM};
+
+/// `--encoding` setting for both files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EncodingMode {
+    /// Read UTF-8 and refuse anything else.
+    #[default]
+    Utf8,
+    /// Transcode BOM-marked UTF-16; read invalid UTF-8 as Windows-1252.
+    Auto,
+    /// UTF-16, little-endian unless a big-endian BOM says otherwise.
+    Utf16,
+    Windows1252,
+}
+
+impl EncodingMode {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "utf8" | "utf-8" => Ok(Self::Utf8),
+            "auto" => Ok(Self::Auto),
+            "utf16" | "utf-16" => Ok(Self::Utf16),
+            "windows-1252" | "cp1252" => Ok(Self::Windows1252),
+            _ => Err("encoding must be utf8, auto, utf16, or windows-1252".to_string()),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf8",
+            Self::Auto => "auto",
+            Self::Utf16 => "utf16",
+            Self::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+/// Encoding an input was transcoded from; UTF-8 input has none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SourceEncoding {
+    #[serde(rename = "utf-16le")]
+    Utf16Le,
+    #[serde(rename = "utf-16be")]
+    Utf16Be,
+    #[serde(rename = "windows-1252")]
+    Windows1252,
+}
+
+impl SourceEncoding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+            Self::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+/// UTF-16 input with an odd byte count or an unpaired surrogate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidUtf16;
+
+/// Transcode `input` to UTF-8 under `mode`; input that needs no decoding is
+/// borrowed unchanged.
+pub fn transcode(
+    input: &[u8],
+    mode: EncodingMode,
+) -> Result<(Cow<'_, [u8]>, Option<SourceEncoding>), InvalidUtf16> {
+    let utf32 = input.starts_with(&UTF32_BE_BOM) || input.starts_with(&UTF32_LE_BOM);
+    let source = match mode {
+        EncodingMode::Utf8 => None,
+        _ if utf32 => None,
+        EncodingMode::Auto => {
+            if input.starts_with(&UTF16_LE_BOM) {
+                Some(SourceEncoding::Utf16Le)
+            } else if input.starts_with(&UTF16_BE_BOM) {
+                Some(SourceEncoding::Utf16Be)
+            } else if input.starts_with(&UTF8_BOM) || std::str::from_utf8(input).is_ok() {
+                None
+            } else {
+                Some(SourceEncoding::Windows1252)
+            }
+        }
+        EncodingMode::Utf16 if input.starts_with(&UTF16_BE_BOM) => Some(SourceEncoding::Utf16Be),
+        EncodingMode::Utf16 => Some(SourceEncoding::Utf16Le),
+        EncodingMode::Windows1252 => Some(SourceEncoding::Windows1252),
+    };
+    let Some(source) = source else {
+        return Ok((Cow::Borrowed(input), None));
+    };
+    let decoded = match source {
+        SourceEncoding::Utf16Le => decode_utf16(input, &UTF16_LE_BOM, u16::from_le_bytes)?,
+        SourceEncoding::Utf16Be => decode_utf16(input, &UTF16_BE_BOM, u16::from_be_bytes)?,
+        SourceEncoding::Windows1252 => input.iter().map(|&byte| windows_1252_char(byte)).collect(),
+    };
+    Ok((Cow::Owned(decoded.into_bytes()), Some(source)))
+}
+
+fn decode_utf16(
+    input: &[u8],
+    bom: &[u8],
+    unit: fn([u8; 2]) -> u16,
+) -> Result<String, InvalidUtf16> {
+    let body = input.strip_prefix(bom).unwrap_or(input);
+    if !body.len().is_multiple_of(2) {
+        return Err(InvalidUtf16);
+    }
+    let units = body.chunks_exact(2).map(|pair| unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| InvalidUtf16)
+}
+
+/// Windows-1252 differs from Latin-1 only in 0x80..=0x9F; its five unassigned
+/// bytes there map to the matching C1 controls, as browsers decode them.
+fn windows_1252_char(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}',
+        '\u{017D}', '\u{008F}', '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}',
+        '\u{2022}', '\u{2013}', '\u{2014}', '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}',
+        '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+    ];
+    match byte {
+        0x80..=0x9F => HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        let mut bytes = UTF16_LE_BOM.to_vec();
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    #[test]
+    fn auto_transcodes_utf16_with_a_bom() {
+        let input = utf16le("id,amount\nA,1\u{20AC}\n");
+        let (decoded, source) = transcode(&input, EncodingMode::Auto).unwrap();
+        assert_eq!(source, Some(SourceEncoding::Utf16Le));
+        assert_eq!(decoded.as_ref(), "id,amount\nA,1\u{20AC}\n".as_bytes());
+
+        let mut input = UTF16_BE_BOM.to_vec();
+        input.extend("a,b".encode_utf16().flat_map(u16::to_be_bytes));
+        let (decoded, source) = transcode(&input, EncodingMode::Utf16).unwrap();
+        assert_eq!(source, Some(SourceEncoding::Utf16Be));
+        assert_eq!(decoded.as_ref(), b"a,b");
+    }
+
+    #[test]
+    fn auto_reads_invalid_utf8_as_windows_1252() {
+        let (decoded, source) = transcode(b"name\nCaf\xE9 \x80\n", EncodingMode::Auto).unwrap();
+        assert_eq!(source, Some(SourceEncoding::Windows1252));
+        assert_eq!(decoded.as_ref(), "name\nCaf\u{e9} \u{20AC}\n".as_bytes());
+
+        let utf8 = "name\nCaf\u{e9}\n".as_bytes();
+        let (decoded, source) = transcode(utf8, EncodingMode::Auto).unwrap();
+        assert_eq!(source, None);
+        assert!(matches!(decoded, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn utf8_mode_and_utf32_input_pass_through() {
+        let input = utf16le("a,b");
+        let (decoded, source) = transcode(&input, EncodingMode::Utf8).unwrap();
+        assert_eq!((decoded.as_ref(), source), (input.as_slice(), None));
+
+        let input = [UTF32_LE_BOM.as_slice(), b"a\0\0\0"].concat();
+        let (_, source) = transcode(&input, EncodingMode::Auto).unwrap();
+        assert_eq!(source, None);
+    }
+
+    #[test]
+    fn malformed_utf16_is_an_error() {
+        assert_eq!(
+            transcode(&[0xFF, 0xFE, b'a'], EncodingMode::Utf16),
+            Err(InvalidUtf16)
+        );
+        assert_eq!(
+            transcode(&[0xFF, 0xFE, 0x00, 0xD8], EncodingMode::Auto),
+            Err(InvalidUtf16)
+        );
+        assert_eq!(EncodingMode::parse("CP1252"), Ok(EncodingMode::Windows1252));
+        assert!(EncodingMode::parse("latin1").is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Footer/total-row exclusion (`--skip-footer`).
+//!
+//! Exports often end with a "Grand Total" row that repeats or blanks the key
+//! column. `--skip-footer N|OLD:NEW` drops a fixed number of trailing data
+//! records per file; `--skip-footer auto` drops the last record only when its
+//! key cell (the first column in row-order mode) is empty or a total label.
+
+use crate::csv::input::SkipRows;
+use crate::csv::records::OwnedRecord;
+use crate::normalize::trim::ascii_trim;
+
+/// `--skip-footer` setting for both files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FooterSkip {
+    /// Drop this many trailing data records, per file.
+    Rows(SkipRows),
+    /// Drop the last record when it looks like a total row.
+    Auto,
+}
+
+impl Default for FooterSkip {
+    fn default() -> Self {
+        Self::Rows(SkipRows::default())
+    }
+}
+
+/// `--skip-footer` setting for one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FooterRule {
+    Rows(usize),
+    Auto,
+}
+
+impl FooterSkip {
+    /// Parse `auto`, `N` (both files), or `OLD:NEW`.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        if raw.trim().eq_ignore_ascii_case("auto") {
+            return Ok(Self::Auto);
+        }
+        SkipRows::parse(raw)
+            .map(Self::Rows)
+            .map_err(|_| "skip-footer must be auto, N, or OLD:NEW (non-negative integers)".into())
+    }
+
+    pub fn is_set(self) -> bool {
+        match self {
+            Self::Rows(rows) => rows.is_set(),
+            Self::Auto => true,
+        }
+    }
+
+    /// The flag value that reproduces this setting.
+    pub fn as_arg(self) -> String {
+        match self {
+            Self::Rows(rows) => rows.as_arg(),
+            Self::Auto => "auto".to_string(),
+        }
+    }
+
+    pub fn old_rule(self) -> FooterRule {
+        match self {
+            Self::Rows(rows) => FooterRule::Rows(rows.old),
+            Self::Auto => FooterRule::Auto,
+        }
+    }
+
+    pub fn new_rule(self) -> FooterRule {
+        match self {
+            Self::Rows(rows) => FooterRule::Rows(rows.new),
+            Self::Auto => FooterRule::Auto,
+        }
+    }
+}
+
+/// Number of trailing records `rule` excludes; `key_index` is the column
+/// `auto` inspects.
+pub fn footer_rows(records: &[OwnedRecord], rule: FooterRule, key_index: usize) -> usize {
+    match rule {
+        FooterRule::Rows(count) => count.min(records.len()),
+        FooterRule::Auto => records
+            .last()
+            .filter(|record| is_total_label(record.get(key_index).unwrap_or_default()))
+            .map_or(0, |_| 1),
+    }
+}
+
+/// Empty, or starting with `total`, `subtotal`, or `grand total` as a word
+/// (ASCII case-insensitive).
+pub fn is_total_label(value: &[u8]) -> bool {
+    let value = ascii_trim(value).to_ascii_lowercase();
+    if value.is_empty() {
+        return true;
+    }
+    ["total", "subtotal", "grand total"].iter().any(|label| {
+        value.strip_prefix(label.as_bytes()).is_some_and(|rest| {
+            rest.first()
+                .is_none_or(|byte| !byte.is_ascii_alphanumeric())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_labels_are_recognized() {
+        assert!(is_total_label(b""));
+        assert!(is_total_label(b"  Grand Total "));
+        assert!(is_total_label(b"TOTAL:"));
+        assert!(is_total_label(b"Subtotal (EUR)"));
+        assert!(!is_total_label(b"Totalizer"));
+        assert!(!is_total_label(b"A-100"));
+    }
+
+    #[test]
+    fn auto_drops_only_a_trailing_total_row() {
+        let records: Vec<OwnedRecord> = [["A", "1"], ["B", "2"], ["Total", "3"]]
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.as_bytes()).collect())
+            .collect();
+        assert_eq!(footer_rows(&records, FooterRule::Auto, 0), 1);
+        assert_eq!(footer_rows(&records[..2], FooterRule::Auto, 0), 0);
+        assert_eq!(footer_rows(&records, FooterRule::Rows(5), 0), 3);
+        assert_eq!(FooterSkip::parse("auto"), Ok(FooterSkip::Auto));
+        assert_eq!(FooterSkip::parse("1:0").unwrap().as_arg(), "1:0");
+        assert!(FooterSkip::parse("last").is_err());
+    }
+}
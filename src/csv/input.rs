@@ -69,6 +69,64 @@ pub fn guard_input_bytes(input: &[u8]) -> Result<&[u8], EncodingIssue> {
     Ok(stripped)
 }
 
+/// Banner lines to drop before the header row, per file (`--skip-rows`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SkipRows {
+    pub old: usize,
+    pub new: usize,
+}
+
+impl SkipRows {
+    /// Parse `N` (both files) or `OLD:NEW`.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let count = |part: &str| {
+            part.trim()
+                .parse::<usize>()
+                .map_err(|_| "skip-rows must be N or OLD:NEW (non-negative integers)".to_string())
+        };
+        match raw.split_once(':') {
+            Some((old, new)) => Ok(Self {
+                old: count(old)?,
+                new: count(new)?,
+            }),
+            None => {
+                let both = count(raw)?;
+                Ok(Self {
+                    old: both,
+                    new: both,
+                })
+            }
+        }
+    }
+
+    pub fn is_set(self) -> bool {
+        self.old > 0 || self.new > 0
+    }
+
+    /// The flag value that reproduces this setting.
+    pub fn as_arg(self) -> String {
+        if self.old == self.new {
+            self.old.to_string()
+        } else {
+            format!("{}:{}", self.old, self.new)
+        }
+    }
+}
+
+/// Drop the first `count` physical lines (through each `\n`). Quotes are not
+/// interpreted: banner lines precede the CSV proper. Fewer lines than
+/// `count` leaves nothing.
+pub fn skip_lines(input: &[u8], count: usize) -> &[u8] {
+    let mut rest = input;
+    for _ in 0..count {
+        match rest.iter().position(|byte| *byte == b'\n') {
+            Some(end) => rest = &rest[end + 1..],
+            None => return &[],
+        }
+    }
+    rest
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +152,23 @@ mod tests {
         assert_eq!(guard_input_bytes(&input), Err(EncodingIssue::Utf16Or32Bom));
     }
 
+    #[test]
+    fn skip_lines_drops_banner_lines() {
+        let input = b"Report generated 2024-05-01\r\n\r\nid,amount\nA,1\n";
+        assert_eq!(skip_lines(input, 0), input.as_slice());
+        assert_eq!(skip_lines(input, 2), b"id,amount\nA,1\n".as_slice());
+        assert_eq!(skip_lines(input, 9), b"".as_slice());
+    }
+
+    #[test]
+    fn skip_rows_parses_both_or_per_file() {
+        assert_eq!(SkipRows::parse("3"), Ok(SkipRows { old: 3, new: 3 }));
+        assert_eq!(SkipRows::parse("3:0"), Ok(SkipRows { old: 3, new: 0 }));
+        assert_eq!(SkipRows::parse("3:0").unwrap().as_arg(), "3:0");
+        assert!(SkipRows::parse("-1").is_err());
+        assert!(SkipRows::parse("2:x").is_err());
+    }
+
     #[test]
     fn nul_in_first_8k_refused() {
         let input = b"ab\0cd";
@@ -1,6 +1,12 @@
 pub mod blank;
+pub mod comment;
+pub mod compression;
 pub mod dialect;
+pub mod duplicates;
+pub mod encoding;
+pub mod footer;
 pub mod input;
 pub mod parser;
 pub mod records;
 pub mod sep;
+pub mod source;
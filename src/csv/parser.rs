@@ -1,8 +1,14 @@
 //! CSV parsing mode: RFC4180 + backslash fallback (bd-5ez).
 //!
 //! Prefer RFC4180 quoting; if parsing hard-fails, retry with backslash escape.
+//!
+//! With the `simd` feature, the data pass reads quote-free RFC4180 input
+//! through `simd-csv`. Anything the fast path cannot handle (backslash
+//! escapes, quotes, bare `\r` line endings, or a `simd-csv` error) goes
+//! through the `csv` crate, so records and line numbers are identical either
+//! way.
 
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom};
 
 use csv::{ByteRecord, Reader};
 
@@ -66,6 +72,244 @@ pub fn build_reader<R: Read>(reader: R, delimiter: u8, escape: EscapeMode) -> Re
         .from_reader(reader)
 }
 
+/// Data-pass reader yielding each record with the line it starts on.
+pub struct RecordReader<'a> {
+    #[cfg(feature = "simd")]
+    input: &'a [u8],
+    #[cfg(feature = "simd")]
+    delimiter: u8,
+    #[cfg(feature = "simd")]
+    escape: EscapeMode,
+    /// Records handed out so far; a fallback replays past them.
+    emitted: u64,
+    lines: LineCounter<'a>,
+    inner: Inner<'a>,
+}
+
+enum Inner<'a> {
+    Csv(Reader<Cursor<&'a [u8]>>),
+    #[cfg(feature = "simd")]
+    Simd {
+        reader: simd_csv::Reader<Cursor<&'a [u8]>>,
+        record: simd_csv::ByteRecord,
+        lines: NonEmptyLines<'a>,
+    },
+}
+
+impl<'a> RecordReader<'a> {
+    pub fn new(input: &'a [u8], delimiter: u8, escape: EscapeMode) -> Self {
+        #[cfg(feature = "simd")]
+        if simd_eligible(input, escape) {
+            let reader = simd_csv::ReaderBuilder::new()
+                .delimiter(delimiter)
+                .quote(b'"')
+                .flexible(true)
+                .has_headers(false)
+                .from_reader(Cursor::new(input));
+            return Self {
+                input,
+                delimiter,
+                escape,
+                emitted: 0,
+                lines: LineCounter::new(input),
+                inner: Inner::Simd {
+                    reader,
+                    record: simd_csv::ByteRecord::new(),
+                    lines: NonEmptyLines::new(input),
+                },
+            };
+        }
+        Self {
+            #[cfg(feature = "simd")]
+            input,
+            #[cfg(feature = "simd")]
+            delimiter,
+            #[cfg(feature = "simd")]
+            escape,
+            emitted: 0,
+            lines: LineCounter::new(input),
+            inner: Inner::Csv(build_reader(Cursor::new(input), delimiter, escape)),
+        }
+    }
+
+    /// True while records come from the `simd-csv` fast path.
+    pub fn is_fast_path(&self) -> bool {
+        match self.inner {
+            Inner::Csv(_) => false,
+            #[cfg(feature = "simd")]
+            Inner::Simd { .. } => true,
+        }
+    }
+
+    /// Read the next record into `out`, returning its 1-based start line,
+    /// or `None` at end of input.
+    pub fn read(&mut self, out: &mut ByteRecord) -> Result<Option<u64>, csv::Error> {
+        #[cfg(feature = "simd")]
+        if let Some(line) = self.read_simd(out) {
+            return Ok(line);
+        }
+        #[cfg(feature = "simd")]
+        let Inner::Csv(reader) = &mut self.inner else {
+            unreachable!("fast path falls back to the csv reader")
+        };
+        #[cfg(not(feature = "simd"))]
+        let Inner::Csv(reader) = &mut self.inner;
+        if !reader.read_byte_record(out)? {
+            return Ok(None);
+        }
+        self.emitted += 1;
+        let start = out.position().map_or(0, |pos| pos.byte());
+        Ok(Some(self.lines.line_at(start)))
+    }
+
+    /// `Some` when the fast path produced an answer; `None` once it has
+    /// handed over to the `csv` crate.
+    #[cfg(feature = "simd")]
+    fn read_simd(&mut self, out: &mut ByteRecord) -> Option<Option<u64>> {
+        let Inner::Simd {
+            reader,
+            record,
+            lines,
+        } = &mut self.inner
+        else {
+            return None;
+        };
+        loop {
+            match reader.read_byte_record(record) {
+                Ok(true) => {
+                    // Without quotes a lone empty field can only be an empty
+                    // line, which the csv crate skips.
+                    if record.len() == 1 && record.iter().all(|field| field.is_empty()) {
+                        continue;
+                    }
+                    let Some(line) = lines.next() else {
+                        self.fall_back();
+                        return None;
+                    };
+                    out.clear();
+                    for field in record.iter() {
+                        out.push_field(field);
+                    }
+                    self.emitted += 1;
+                    return Some(Some(line));
+                }
+                Ok(false) => return Some(None),
+                Err(_) => {
+                    self.fall_back();
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// Switch to the `csv` crate, skipping the records already emitted.
+    #[cfg(feature = "simd")]
+    fn fall_back(&mut self) {
+        let mut reader = build_reader(Cursor::new(self.input), self.delimiter, self.escape);
+        let mut skipped = ByteRecord::new();
+        for _ in 0..self.emitted {
+            if !matches!(reader.read_byte_record(&mut skipped), Ok(true)) {
+                break;
+            }
+        }
+        self.inner = Inner::Csv(reader);
+    }
+}
+
+/// Physical line numbers for the `csv` crate path. A record's position can
+/// point at the terminator or blank lines before it, so the record starts at
+/// the first byte past them; lines end at `\n`, `\r\n`, or a bare `\r`.
+struct LineCounter<'a> {
+    input: &'a [u8],
+    offset: usize,
+    line: u64,
+}
+
+impl<'a> LineCounter<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            offset: 0,
+            line: 1,
+        }
+    }
+
+    /// Line of the record whose read began at byte `start`. Calls must not
+    /// go backwards.
+    fn line_at(&mut self, start: u64) -> u64 {
+        let input = self.input;
+        let mut start = usize::try_from(start).map_or(input.len(), |start| start.min(input.len()));
+        while start < input.len() && matches!(input[start], b'\r' | b'\n') {
+            start += 1;
+        }
+        if start > self.offset {
+            self.line += input[self.offset..start]
+                .iter()
+                .enumerate()
+                .filter(|&(idx, &byte)| {
+                    byte == b'\n'
+                        || (byte == b'\r' && input.get(self.offset + idx + 1) != Some(&b'\n'))
+                })
+                .count() as u64;
+            self.offset = start;
+        }
+        self.line
+    }
+}
+
+/// The fast path maps each record to one physical line, so it only takes
+/// RFC4180 input with no quotes and no bare `\r` terminators.
+#[cfg(feature = "simd")]
+fn simd_eligible(input: &[u8], escape: EscapeMode) -> bool {
+    escape == EscapeMode::None
+        && !input.contains(&b'"')
+        && input
+            .iter()
+            .enumerate()
+            .all(|(idx, &byte)| byte != b'\r' || input.get(idx + 1) == Some(&b'\n'))
+}
+
+/// 1-based numbers of the lines that hold at least one byte besides `\r\n`.
+#[cfg(feature = "simd")]
+struct NonEmptyLines<'a> {
+    rest: &'a [u8],
+    line: u64,
+}
+
+#[cfg(feature = "simd")]
+impl<'a> NonEmptyLines<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self {
+            rest: input,
+            line: 0,
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Iterator for NonEmptyLines<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while !self.rest.is_empty() {
+            let end = self
+                .rest
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(self.rest.len(), |idx| idx + 1);
+            let (line, rest) = self.rest.split_at(end);
+            self.rest = rest;
+            self.line += 1;
+            let body = line.strip_suffix(b"\n").unwrap_or(line);
+            let body = body.strip_suffix(b"\r").unwrap_or(body);
+            if !body.is_empty() {
+                return Some(self.line);
+            }
+        }
+        None
+    }
+}
+
 /// Detect which escape mode parses the input without hard errors.
 pub fn detect_escape_mode<R: Read + Seek>(
     reader: &mut R,
@@ -179,6 +423,40 @@ mod tests {
         assert_eq!(mode, EscapeMode::Backslash);
     }
 
+    fn read_all(input: &[u8], escape: EscapeMode) -> Vec<(u64, Vec<Vec<u8>>)> {
+        let mut reader = RecordReader::new(input, b',', escape);
+        let mut record = ByteRecord::new();
+        let mut rows = Vec::new();
+        while let Some(line) = reader.read(&mut record).expect("read") {
+            rows.push((line, record.iter().map(|field| field.to_vec()).collect()));
+        }
+        rows
+    }
+
+    #[test]
+    fn record_reader_matches_csv_crate_lines() {
+        let plain = b"id,v\r\n\r\nA,1\n\n\nB,\n";
+        let rows = read_all(plain, EscapeMode::None);
+        assert_eq!(
+            rows,
+            vec![
+                (1, vec![b"id".to_vec(), b"v".to_vec()]),
+                (3, vec![b"A".to_vec(), b"1".to_vec()]),
+                (6, vec![b"B".to_vec(), b"".to_vec()]),
+            ]
+        );
+        assert_eq!(
+            RecordReader::new(plain, b',', EscapeMode::None).is_fast_path(),
+            cfg!(feature = "simd")
+        );
+
+        let quoted = b"id,v\n\"A\nB\",1\nC,2\n";
+        let rows = read_all(quoted, EscapeMode::None);
+        assert_eq!(rows[1].1[0], b"A\nB");
+        assert_eq!(rows[2].0, 4);
+        assert!(!RecordReader::new(quoted, b',', EscapeMode::None).is_fast_path());
+    }
+
     #[test]
     fn errors_when_both_fail() {
         let data = b"col\n\"unterminated";
@@ -5,6 +5,10 @@
 //!   treated as empty string.
 //! - If a row has more fields than the header, extra trailing fields must be
 //!   empty after ASCII-trim; otherwise refuse with E_HEADERS.
+//!
+//! Loaded rows are kept as [`OwnedRecord`]: one byte buffer per record plus
+//! field end offsets, so holding a row costs two allocations instead of one
+//! per field, and a row's cells sit next to each other for the diff loops.
 
 use csv::ByteRecord;
 
@@ -42,6 +46,95 @@ impl<'a> NormalizedRecord<'a> {
     pub fn is_empty(&self) -> bool {
         self.header_len == 0
     }
+
+    /// Copy the normalized fields into an owned record.
+    pub fn to_owned_record(&self) -> OwnedRecord {
+        let mut record = OwnedRecord::with_capacity(self.len(), self.record.as_slice().len());
+        for idx in 0..self.len() {
+            record.push_field(self.field(idx));
+        }
+        record
+    }
+}
+
+/// A loaded row: every field in one buffer, addressed by end offsets.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OwnedRecord {
+    bytes: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl OwnedRecord {
+    /// An empty record with room for `fields` fields totalling `bytes` bytes.
+    pub fn with_capacity(fields: usize, bytes: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(bytes),
+            ends: Vec::with_capacity(fields),
+        }
+    }
+
+    /// Append a field after the current last one.
+    pub fn push_field(&mut self, field: &[u8]) {
+        self.bytes.extend_from_slice(field);
+        self.ends.push(self.bytes.len());
+    }
+
+    /// Returns the field at `index`, or `None` past the last field.
+    pub fn get(&self, index: usize) -> Option<&[u8]> {
+        let end = *self.ends.get(index)?;
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1],
+        };
+        Some(&self.bytes[start..end])
+    }
+
+    /// Returns the field at `index`, or empty string if missing.
+    pub fn field(&self, index: usize) -> &[u8] {
+        self.get(index).unwrap_or(b"")
+    }
+
+    /// Replace the field at `index`; returns false if there is no such field.
+    pub fn set(&mut self, index: usize, value: &[u8]) -> bool {
+        let Some(&end) = self.ends.get(index) else {
+            return false;
+        };
+        let start = match index {
+            0 => 0,
+            _ => self.ends[index - 1],
+        };
+        self.bytes.splice(start..end, value.iter().copied());
+        let shift = value.len() as isize - (end - start) as isize;
+        for end in &mut self.ends[index..] {
+            *end = end.wrapping_add_signed(shift);
+        }
+        true
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns true if the record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Iterate the fields in order.
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        (0..self.len()).map(|idx| self.field(idx))
+    }
+}
+
+impl<'a> FromIterator<&'a [u8]> for OwnedRecord {
+    fn from_iter<I: IntoIterator<Item = &'a [u8]>>(fields: I) -> Self {
+        let mut record = OwnedRecord::default();
+        for field in fields {
+            record.push_field(field);
+        }
+        record
+    }
 }
 
 /// Normalize a record to the header width, validating extra trailing fields.
@@ -102,6 +195,24 @@ mod tests {
         assert_eq!(normalized.field(1), b"b");
     }
 
+    #[test]
+    fn owned_record_keeps_fields_in_one_buffer() {
+        let rec = record(&[b"id", b"", b"12.5"]);
+        let mut owned = normalize_record(&rec, 4, 1).unwrap().to_owned_record();
+        assert_eq!(owned.len(), 4);
+        assert_eq!(owned.get(0), Some(&b"id"[..]));
+        assert_eq!(owned.get(1), Some(&b""[..]));
+        assert_eq!(owned.field(2), b"12.5");
+        assert_eq!(owned.get(4), None);
+        assert_eq!(owned.field(4), b"");
+
+        assert!(owned.set(0, b"longer-id"));
+        assert!(owned.set(2, b"7"));
+        assert!(!owned.set(9, b"x"));
+        let fields: Vec<&[u8]> = owned.iter().collect();
+        assert_eq!(fields, vec![&b"longer-id"[..], b"", b"7", b""]);
+    }
+
     #[test]
     fn rejects_extra_non_empty_fields() {
         let rec = record(&[b"a", b"b", b"extra"]);
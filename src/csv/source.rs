@@ -0,0 +1,175 @@
+//! Input sources: regular files, `-` (stdin), and streams such as process
+//! substitution (`<(cmd)` arrives as `/dev/fd/63`).
+//!
+//! A stream can be read only once, but the capsule and witness writers re-read
+//! the inputs after the verdict, so stream contents are kept for the life of
+//! the process and every later read returns the same bytes. Inputs already in
+//! memory, such as worksheets extracted by `rvl xlsx` or byte inputs passed to
+//! [`crate::compare`], travel on [`Args`](crate::cli::args::Args) as
+//! [`InlineInputs`] and are read through [`InputSource`], never from disk.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Path argument that reads the input from stdin.
+pub const STDIN_PATH: &str = "-";
+
+pub fn is_stdin(path: &Path) -> bool {
+    path.as_os_str() == STDIN_PATH
+}
+
+/// Streams drained so far.
+static STREAMS: OnceLock<Mutex<HashMap<PathBuf, Vec<u8>>>> = OnceLock::new();
+
+/// Bytes supplied in memory for either side of a comparison. The side's path
+/// still labels the input in reports, but is never opened.
+#[derive(Debug, Clone, Default)]
+pub struct InlineInputs {
+    pub old: Option<Arc<[u8]>>,
+    pub new: Option<Arc<[u8]>>,
+}
+
+/// Where one input's bytes come from.
+#[derive(Debug, Clone, Copy)]
+pub enum InputSource<'a> {
+    /// A file, `-`, or a stream, read with [`read_source`].
+    Path(&'a Path),
+    /// Bytes already in memory; `label` names them in reports.
+    Bytes { label: &'a Path, bytes: &'a [u8] },
+}
+
+impl<'a> InputSource<'a> {
+    pub fn new(path: &'a Path, inline: Option<&'a Arc<[u8]>>) -> Self {
+        match inline {
+            Some(bytes) => InputSource::Bytes { label: path, bytes },
+            None => InputSource::Path(path),
+        }
+    }
+
+    /// The path the input is reported under.
+    pub fn path(self) -> &'a Path {
+        match self {
+            InputSource::Path(path) | InputSource::Bytes { label: path, .. } => path,
+        }
+    }
+
+    /// The input's bytes; in-memory inputs are borrowed, not copied.
+    pub fn read(self) -> io::Result<Cow<'a, [u8]>> {
+        match self {
+            InputSource::Path(path) => read_source(path).map(Cow::Owned),
+            InputSource::Bytes { bytes, .. } => Ok(Cow::Borrowed(bytes)),
+        }
+    }
+
+    /// True when the input is read from stdin.
+    pub fn is_stdin(self) -> bool {
+        matches!(self, InputSource::Path(path) if is_stdin(path))
+    }
+
+    /// True when there is no regular file to re-read or link to.
+    pub fn is_stream(self) -> bool {
+        match self {
+            InputSource::Path(path) => is_stream(path),
+            InputSource::Bytes { .. } => true,
+        }
+    }
+
+    /// Path to print in a suggested rerun command; see [`rerun_path`].
+    pub fn rerun_path(self, placeholder: &str) -> String {
+        match self {
+            InputSource::Path(path) => rerun_path(path, placeholder),
+            InputSource::Bytes { .. } => placeholder.to_string(),
+        }
+    }
+}
+
+/// True for stdin and paths that are not regular files (pipes, FIFOs).
+pub fn is_stream(path: &Path) -> bool {
+    is_stdin(path) || fs::metadata(path).is_ok_and(|meta| !meta.is_file())
+}
+
+/// Read an input's bytes, draining a stream at most once per process.
+pub fn read_source(path: &Path) -> io::Result<Vec<u8>> {
+    if !is_stream(path) {
+        return fs::read(path);
+    }
+    let mut streams = STREAMS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(bytes) = streams.get(path) {
+        return Ok(bytes.clone());
+    }
+    let bytes = if is_stdin(path) {
+        let mut bytes = Vec::new();
+        io::stdin().lock().read_to_end(&mut bytes)?;
+        bytes
+    } else {
+        fs::read(path)?
+    };
+    streams.insert(path.to_path_buf(), bytes.clone());
+    Ok(bytes)
+}
+
+/// Short label for human output: the file name, `stdin`, or a stream's full path.
+pub fn source_label(path: &Path) -> String {
+    if is_stdin(path) {
+        return "stdin".to_string();
+    }
+    if is_stream(path) {
+        return path.to_string_lossy().to_string();
+    }
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+}
+
+/// Path to print in a suggested rerun command; a stream is already drained,
+/// so it is shown as a placeholder for a saved copy.
+pub fn rerun_path(path: &Path, placeholder: &str) -> String {
+    if is_stream(path) {
+        placeholder.to_string()
+    } else {
+        path.to_string_lossy().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_is_stdin_and_files_are_not_streams() {
+        assert!(is_stdin(Path::new("-")));
+        assert!(is_stream(Path::new("-")));
+        assert!(!is_stdin(Path::new("./-")));
+        assert!(!is_stream(Path::new("Cargo.toml")));
+        assert_eq!(source_label(Path::new("-")), "stdin");
+        assert_eq!(source_label(Path::new("data/old.csv")), "old.csv");
+        assert_eq!(rerun_path(Path::new("-"), "OLD_CSV"), "OLD_CSV");
+        assert_eq!(
+            rerun_path(Path::new("data/old.csv"), "OLD_CSV"),
+            "data/old.csv"
+        );
+    }
+
+    #[test]
+    fn inline_sources_read_from_memory_even_over_a_real_file() {
+        let bytes: Arc<[u8]> = Arc::from(&b"id,v\n1,2\n"[..]);
+        let source = InputSource::new(Path::new("Cargo.toml"), Some(&bytes));
+        assert!(matches!(
+            source.read().unwrap(),
+            Cow::Borrowed(b"id,v\n1,2\n")
+        ));
+        assert!(source.is_stream());
+        assert_eq!(source.path(), Path::new("Cargo.toml"));
+        assert_eq!(source.rerun_path("OLD_CSV"), "OLD_CSV");
+        let file = InputSource::new(Path::new("Cargo.toml"), None);
+        assert!(!file.is_stream());
+        assert_eq!(file.rerun_path("OLD_CSV"), "Cargo.toml");
+    }
+}
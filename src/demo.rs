@@ -0,0 +1,276 @@
+//! Built-in example comparisons (`rvl demo`).
+//!
+//! Three small loan-portfolio snapshots ship inside the binary, one per
+//! outcome. Each pair is passed as in-memory inputs labelled
+//! `demo/<scenario>/{old,new}.csv` and compared by the regular pipeline, so
+//! the output is exactly what `rvl old.csv new.csv` prints for the same
+//! files. That makes `rvl demo` a cheap installation check: it exits 0 only
+//! when every scenario reaches its expected outcome.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::args::{Args, DEFAULT_THRESHOLD, DEFAULT_TOLERANCE, DemoArgs};
+use crate::cli::exit::{Outcome, exit_code};
+use crate::refusal::process::PipelineError;
+
+const DEMO_SCHEMA_VERSION: &str = "rvl.demo.v0";
+
+/// One built-in example pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemoScenario {
+    /// A payoff and a paydown explain the change; a rate reset and a
+    /// scheduled payment stay in the tail.
+    RealChange,
+    /// Only number formatting changed.
+    NoRealChange,
+    /// Rows were reordered and no key was given (`E_NEED_KEY`).
+    Refusal,
+}
+
+impl DemoScenario {
+    pub const ALL: [DemoScenario; 3] = [
+        DemoScenario::RealChange,
+        DemoScenario::NoRealChange,
+        DemoScenario::Refusal,
+    ];
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|scenario| scenario.name() == raw.to_ascii_lowercase())
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DemoScenario::RealChange => "real-change",
+            DemoScenario::NoRealChange => "no-real-change",
+            DemoScenario::Refusal => "refusal",
+        }
+    }
+
+    pub fn expected(self) -> Outcome {
+        match self {
+            DemoScenario::RealChange => Outcome::RealChange,
+            DemoScenario::NoRealChange => Outcome::NoRealChange,
+            DemoScenario::Refusal => Outcome::Refusal,
+        }
+    }
+
+    fn key(self) -> Option<&'static str> {
+        match self {
+            DemoScenario::RealChange | DemoScenario::NoRealChange => Some("loan_id"),
+            DemoScenario::Refusal => None,
+        }
+    }
+
+    fn data(self) -> (&'static str, &'static str) {
+        match self {
+            DemoScenario::RealChange => (
+                "loan_id,balance,rate\n\
+                 LN-2208,250000.00,5.25\n\
+                 LN-3091,98000.00,4.10\n\
+                 LN-4117,412500.00,6.00\n",
+                "loan_id,balance,rate\n\
+                 LN-2208,40000.00,5.75\n\
+                 LN-3091,97850.00,4.10\n\
+                 LN-4117,0.00,6.00\n",
+            ),
+            DemoScenario::NoRealChange => (
+                "loan_id,balance,rate\n\
+                 LN-2208,250000,5.25\n\
+                 LN-3091,98000,4.1\n\
+                 LN-4117,412500,6\n",
+                "loan_id,balance,rate\n\
+                 LN-2208,\"250,000.00\",5.25\n\
+                 LN-3091,\"98,000.00\",4.10\n\
+                 LN-4117,\"412,500.00\",6.00\n",
+            ),
+            DemoScenario::Refusal => (
+                "loan_id,balance,rate\n\
+                 LN-2208,250000.00,5.25\n\
+                 LN-3091,98000.00,4.10\n\
+                 LN-4117,412500.00,6.00\n",
+                "loan_id,balance,rate\n\
+                 LN-4117,412500.00,6.00\n\
+                 LN-2208,250000.00,5.25\n\
+                 LN-3091,98000.00,4.10\n",
+            ),
+        }
+    }
+
+    fn path(self, side: &str) -> PathBuf {
+        PathBuf::from(format!("demo/{}/{side}.csv", self.name()))
+    }
+
+    /// The equivalent command line for the same files on disk.
+    fn command(self) -> String {
+        let mut command = format!(
+            "rvl {} {}",
+            self.path("old").display(),
+            self.path("new").display()
+        );
+        if let Some(key) = self.key() {
+            command.push_str(&format!(" --key {key}"));
+        }
+        command
+    }
+}
+
+/// One scenario's run.
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoRun {
+    pub name: &'static str,
+    pub command: String,
+    pub expected: &'static str,
+    pub outcome: &'static str,
+    /// The scenario's full `rvl.v0` report.
+    pub result: Value,
+    #[serde(skip)]
+    pub human: String,
+    #[serde(skip)]
+    pub verdict: Outcome,
+}
+
+impl DemoRun {
+    fn as_expected(&self) -> bool {
+        self.outcome == self.expected
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DemoReport {
+    pub version: &'static str,
+    pub ok: bool,
+    pub demos: Vec<DemoRun>,
+}
+
+/// Run `rvl demo`. With a scenario, print its comparison and exit with its
+/// verdict code (0/1/2) like a regular run. Without one, run all three and
+/// exit 0 when each reached its expected outcome, 1 otherwise.
+pub fn run(args: &DemoArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let json = args.json || json_output;
+    if let Some(scenario) = args.scenario {
+        let demo = run_scenario(scenario)?;
+        if json {
+            println!("{}", serde_json::to_string(&demo.result)?);
+        } else {
+            println!("$ {}\n\n{}", demo.command, demo.human.trim_end());
+        }
+        return Ok(exit_code(demo.verdict));
+    }
+
+    let demos = DemoScenario::ALL
+        .into_iter()
+        .map(run_scenario)
+        .collect::<Result<Vec<_>, _>>()?;
+    let report = DemoReport {
+        version: DEMO_SCHEMA_VERSION,
+        ok: demos.iter().all(DemoRun::as_expected),
+        demos,
+    };
+    if json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(if report.ok { 0 } else { 1 })
+}
+
+/// Compare one built-in pair through the regular pipeline.
+pub fn run_scenario(scenario: DemoScenario) -> Result<DemoRun, PipelineError> {
+    let (old_csv, new_csv) = scenario.data();
+    let run = |json: bool| {
+        let mut args = Args::new(
+            scenario.path("old"),
+            scenario.path("new"),
+            scenario.key().map(str::to_string),
+            DEFAULT_THRESHOLD,
+            DEFAULT_TOLERANCE,
+            None,
+            json,
+        );
+        args.inline.old = Some(old_csv.as_bytes().into());
+        args.inline.new = Some(new_csv.as_bytes().into());
+        crate::orchestrator::run(&args)
+    };
+    let human = run(false)?;
+    let json = run(true)?;
+
+    let verdict = json.outcome;
+    let report = json.into_report()?;
+    Ok(DemoRun {
+        name: scenario.name(),
+        command: scenario.command(),
+        expected: scenario.expected().as_str(),
+        outcome: verdict.as_str(),
+        result: serde_json::to_value(&report)?,
+        human: human.output,
+        verdict,
+    })
+}
+
+fn render_human(report: &DemoReport) -> String {
+    let mut lines = vec![
+        "RVL DEMO".to_string(),
+        String::new(),
+        "Three built-in loan-portfolio snapshots, compared by the regular pipeline.".to_string(),
+        String::new(),
+    ];
+    for demo in &report.demos {
+        let mark = if demo.as_expected() {
+            "ok"
+        } else {
+            "UNEXPECTED"
+        };
+        lines.push(format!(
+            "{}  {}  {mark}",
+            demo.name,
+            demo.outcome.replace('_', " ")
+        ));
+    }
+    for demo in &report.demos {
+        lines.push(String::new());
+        lines.push(format!("== Demo: {} ==", demo.name));
+        lines.push(format!("$ {}", demo.command));
+        lines.push(String::new());
+        lines.push(demo.human.trim_end().to_string());
+    }
+    lines.push(String::new());
+    lines.push(
+        "Run one scenario with `rvl demo <real-change|no-real-change|refusal>`; add --json for the report."
+            .to_string(),
+    );
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_scenario_reaches_its_expected_outcome() {
+        for scenario in DemoScenario::ALL {
+            let demo = run_scenario(scenario).unwrap();
+            assert!(demo.as_expected(), "{}: {}", demo.name, demo.outcome);
+        }
+    }
+
+    #[test]
+    fn refusal_scenario_suggests_the_key() {
+        let demo = run_scenario(DemoScenario::Refusal).unwrap();
+        assert_eq!(demo.result["refusal"]["code"], "E_NEED_KEY");
+        assert!(demo.human.contains("loan_id"), "{}", demo.human);
+    }
+
+    #[test]
+    fn parses_scenario_names() {
+        assert_eq!(
+            DemoScenario::parse("No-Real-Change"),
+            Some(DemoScenario::NoRealChange)
+        );
+        assert_eq!(DemoScenario::parse("refused"), None);
+    }
+}
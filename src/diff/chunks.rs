@@ -0,0 +1,111 @@
+//! Row chunks for the cell scan, optionally spread over worker threads.
+//!
+//! Chunk boundaries depend only on the row count, never on the number of
+//! workers, and chunk results are merged in chunk order. A run therefore
+//! produces the same output on one thread or many.
+
+use std::ops::Range;
+use std::thread;
+
+/// Rows per scan chunk.
+pub const CHUNK_ROWS: usize = 4096;
+
+/// With `--threads 0` (auto), inputs below this many cells stay on the
+/// calling thread.
+pub const PARALLEL_MIN_CELLS: usize = 1 << 20;
+
+/// Workers for a scan over `rows` × `columns` cells. `requested` is
+/// `--threads`: 0 picks one per available core for large inputs and 1 for
+/// small ones. Never more than there are chunks.
+pub fn worker_count(requested: usize, rows: usize, columns: usize) -> usize {
+    let workers = match requested {
+        0 if rows.saturating_mul(columns) < PARALLEL_MIN_CELLS => 1,
+        0 => thread::available_parallelism().map_or(1, |cores| cores.get()),
+        requested => requested,
+    };
+    workers.clamp(1, rows.div_ceil(CHUNK_ROWS).max(1))
+}
+
+/// Scan every chunk of `0..rows` and hand each result to `merge` in chunk
+/// order. With several workers, up to `workers` chunks are scanned at once,
+/// so at most that many unmerged results are held in memory.
+pub fn scan_chunks<R, E>(
+    rows: usize,
+    workers: usize,
+    scan: impl Fn(Range<usize>) -> R + Sync,
+    mut merge: impl FnMut(R) -> Result<(), E>,
+) -> Result<(), E>
+where
+    R: Send,
+{
+    let ranges: Vec<Range<usize>> = (0..rows)
+        .step_by(CHUNK_ROWS)
+        .map(|start| start..(start + CHUNK_ROWS).min(rows))
+        .collect();
+    if workers <= 1 {
+        for range in ranges {
+            merge(scan(range))?;
+        }
+        return Ok(());
+    }
+
+    let scan = &scan;
+    for wave in ranges.chunks(workers) {
+        let results: Vec<R> = thread::scope(|scope| {
+            let handles: Vec<_> = wave
+                .iter()
+                .cloned()
+                .map(|range| scope.spawn(move || scan(range)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        });
+        for result in results {
+            merge(result)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_inputs_stay_single_threaded() {
+        assert_eq!(worker_count(0, 10, 10), 1);
+        assert_eq!(worker_count(8, CHUNK_ROWS * 2, 1), 2);
+        assert_eq!(worker_count(1, CHUNK_ROWS * 100, 1_000), 1);
+        assert_eq!(worker_count(3, 0, 10), 1);
+    }
+
+    #[test]
+    fn merges_chunks_in_order_for_any_worker_count() {
+        let rows = CHUNK_ROWS * 5 + 17;
+        let run = |workers: usize| {
+            let mut merged = Vec::new();
+            scan_chunks(
+                rows,
+                workers,
+                |range| range,
+                |range| {
+                    merged.push(range);
+                    Ok::<(), ()>(())
+                },
+            )
+            .unwrap();
+            merged
+        };
+        let serial = run(1);
+        assert_eq!(serial.len(), 6);
+        assert_eq!(serial[0], 0..CHUNK_ROWS);
+        assert_eq!(serial[5], CHUNK_ROWS * 5..rows);
+        assert_eq!(run(4), serial);
+    }
+}
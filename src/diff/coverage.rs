@@ -2,6 +2,61 @@
 //!
 //! Contributions must be ordered by contribution descending (top-K).
 
+/// What to do when the top-K contributors cannot reach the threshold
+/// (`--on-diffuse`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiffusePolicy {
+    /// Refuse the run with `E_DIFFUSE`.
+    #[default]
+    Refuse,
+    /// Report REAL_CHANGE with the per-column rollup of total change.
+    Expand,
+}
+
+impl DiffusePolicy {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "refuse" => Some(DiffusePolicy::Refuse),
+            "expand" => Some(DiffusePolicy::Expand),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DiffusePolicy::Refuse => "refuse",
+            DiffusePolicy::Expand => "expand",
+        }
+    }
+}
+
+/// How coverage is evaluated (`--coverage`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CoverageMode {
+    /// One threshold over total change.
+    #[default]
+    Total,
+    /// Every changed column must reach the threshold of its own change.
+    PerColumn,
+}
+
+impl CoverageMode {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "total" => Some(CoverageMode::Total),
+            "per-column" => Some(CoverageMode::PerColumn),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CoverageMode::Total => "total",
+            CoverageMode::PerColumn => "per-column",
+        }
+    }
+}
+
 /// Coverage decision derived from total_change and top-K contributions.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CoverageDecision {
@@ -51,6 +106,81 @@ pub fn evaluate_coverage(
     }
 }
 
+/// Cumulative coverage after the first 1..=K contributors, for
+/// `metrics.coverage_curve`. Empty when there is no change.
+pub fn coverage_curve(contributions_desc: &[f64], total_change: f64) -> Vec<f64> {
+    if !total_change.is_finite() || total_change <= 0.0 {
+        return Vec::new();
+    }
+    let mut cumulative = 0.0;
+    contributions_desc
+        .iter()
+        .map(|contribution| {
+            cumulative += contribution;
+            cumulative / total_change
+        })
+        .collect()
+}
+
+/// Coverage that fell short of the threshold, reported instead of
+/// `E_DIFFUSE` under `--on-diffuse expand`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffuseShortfall {
+    pub top_k_coverage: f64,
+    pub threshold: f64,
+    /// The least-explained column under `--coverage per-column`.
+    pub column: Option<Vec<u8>>,
+}
+
+/// Per-column coverage decision (`--coverage per-column`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnCoverageDecision {
+    /// No column changed.
+    NoChange,
+    /// The least-explained changed column cannot reach the threshold with
+    /// its own top-K.
+    Diffuse { column: usize, top_k_coverage: f64 },
+    /// Per column, the smallest prefix of its top-K reaching the threshold
+    /// of its own change (0 for unchanged columns).
+    Explainable { cutoffs: Vec<usize> },
+}
+
+/// Evaluate coverage column by column, so a small column's change is not
+/// carried by one large column reaching the threshold alone.
+///
+/// - `columns[i]` is column `i`'s total change and its top-K contributions,
+///   sorted descending.
+/// - Every column with change above tolerance must reach `threshold`.
+pub fn evaluate_column_coverage(
+    columns: &[(f64, Vec<f64>)],
+    threshold: f64,
+) -> ColumnCoverageDecision {
+    let mut cutoffs = Vec::with_capacity(columns.len());
+    let mut worst: Option<(usize, f64)> = None;
+    for (idx, (total_change, contributions_desc)) in columns.iter().enumerate() {
+        match evaluate_coverage(contributions_desc, *total_change, threshold) {
+            CoverageDecision::NoChange => cutoffs.push(0),
+            CoverageDecision::Diffuse { top_k_coverage } => {
+                if worst.is_none_or(|(_, coverage)| top_k_coverage < coverage) {
+                    worst = Some((idx, top_k_coverage));
+                }
+                cutoffs.push(0);
+            }
+            CoverageDecision::Explainable { cutoff, .. } => cutoffs.push(cutoff),
+        }
+    }
+    if let Some((column, top_k_coverage)) = worst {
+        return ColumnCoverageDecision::Diffuse {
+            column,
+            top_k_coverage,
+        };
+    }
+    if cutoffs.iter().all(|cutoff| *cutoff == 0) {
+        return ColumnCoverageDecision::NoChange;
+    }
+    ColumnCoverageDecision::Explainable { cutoffs }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +225,39 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn per_column_requires_every_changed_column() {
+        // Column 0 alone would carry 95% of total change; column 1 is only
+        // half explained by its top-K.
+        let columns = vec![(100.0, vec![100.0]), (4.0, vec![2.0]), (0.0, vec![])];
+        assert_eq!(
+            evaluate_column_coverage(&columns, 0.95),
+            ColumnCoverageDecision::Diffuse {
+                column: 1,
+                top_k_coverage: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn per_column_explains_each_column_with_its_own_prefix() {
+        let columns = vec![(10.0, vec![6.0, 3.0, 1.0]), (0.0, vec![]), (1.0, vec![1.0])];
+        assert_eq!(
+            evaluate_column_coverage(&columns, 0.9),
+            ColumnCoverageDecision::Explainable {
+                cutoffs: vec![2, 0, 1]
+            }
+        );
+        assert_eq!(
+            evaluate_column_coverage(&[(0.0, vec![])], 0.9),
+            ColumnCoverageDecision::NoChange
+        );
+    }
+
+    #[test]
+    fn coverage_curve_accumulates_shares() {
+        assert_eq!(coverage_curve(&[6.0, 3.0, 1.0], 20.0), vec![0.3, 0.45, 0.5]);
+        assert!(coverage_curve(&[1.0], 0.0).is_empty());
+    }
 }
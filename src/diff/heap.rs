@@ -80,6 +80,15 @@ impl<T> DiffAccumulator<T> {
             ));
         }
     }
+
+    /// Fold in an accumulator that observed a later, disjoint set of cells.
+    pub fn merge(&mut self, other: DiffAccumulator<T>) {
+        if other.max_abs_delta > self.max_abs_delta {
+            self.max_abs_delta = other.max_abs_delta;
+        }
+        self.total_change += other.total_change;
+        self.top.merge(other.top);
+    }
 }
 
 #[derive(Debug)]
@@ -125,6 +134,14 @@ impl<T> TopContributors<T> {
         }
     }
 
+    /// Keep the top entries of both sets. Ties still break on `tie_break`,
+    /// so the result matches pushing every entry into one heap.
+    pub fn merge(&mut self, other: TopContributors<T>) {
+        for entry in other.heap {
+            self.push(entry.0.contributor);
+        }
+    }
+
     pub fn into_vec(self) -> Vec<Contributor<T>> {
         self.heap
             .into_iter()
@@ -215,4 +232,34 @@ mod tests {
         assert_eq!(acc.max_abs_delta, 3.0);
         assert_eq!(acc.top.len(), 2);
     }
+
+    #[test]
+    fn merge_matches_a_single_accumulator() {
+        let cells = [
+            ("a", 2.0, 1),
+            ("b", 5.0, 2),
+            ("c", 2.0, 3),
+            ("d", 4.0, 4),
+            ("e", 2.0, 5),
+        ];
+        let mut single = DiffAccumulator::new(3);
+        let mut first = DiffAccumulator::new(3);
+        let mut second = DiffAccumulator::new(3);
+        for (idx, (id, value, tie_break)) in cells.into_iter().enumerate() {
+            single.observe(id, 0.0, value, value, value, tie_break);
+            let part = if idx < 2 { &mut first } else { &mut second };
+            part.observe(id, 0.0, value, value, value, tie_break);
+        }
+        first.merge(second);
+
+        let ids = |acc: DiffAccumulator<&'static str>| {
+            let mut ids: Vec<_> = acc.top.into_vec().into_iter().map(|c| c.id).collect();
+            ids.sort_unstable();
+            ids
+        };
+        assert_eq!(first.total_change, single.total_change);
+        assert_eq!(first.max_abs_delta, single.max_abs_delta);
+        assert_eq!(ids(first), vec!["a", "b", "d"]);
+        assert_eq!(ids(single), vec!["a", "b", "d"]);
+    }
 }
@@ -0,0 +1,51 @@
+// Byte-string interning for column names and key values in cell identifiers.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Shared, immutable byte string. Cloning bumps a reference count instead of
+/// copying the bytes, so per-cell `CellId`s stay allocation-free.
+pub type Symbol = Arc<[u8]>;
+
+/// Deduplicates byte strings so every occurrence of the same header or key
+/// shares one allocation.
+#[derive(Debug, Default)]
+pub struct Interner {
+    symbols: HashSet<Symbol>,
+}
+
+impl Interner {
+    pub fn intern(&mut self, bytes: &[u8]) -> Symbol {
+        if let Some(existing) = self.symbols.get(bytes) {
+            return Arc::clone(existing);
+        }
+        let symbol: Symbol = Arc::from(bytes);
+        self.symbols.insert(Arc::clone(&symbol));
+        symbol
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning_same_bytes_shares_allocation() {
+        let mut interner = Interner::default();
+        let first = interner.intern(b"amount");
+        let second = interner.intern(b"amount");
+        let other = interner.intern(b"balance");
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(&*first, b"amount");
+        assert_eq!(interner.len(), 2);
+    }
+}
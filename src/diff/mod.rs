@@ -1,4 +1,10 @@
+pub mod chunks;
 pub mod coverage;
 pub mod heap;
+pub mod intern;
 pub mod order;
+pub mod rank;
+pub mod rescale;
+pub mod rollup;
+pub mod text;
 pub mod tolerance;
@@ -1,12 +1,13 @@
 use std::cmp::Ordering;
 
 use super::heap::Contributor;
+use super::intern::Symbol;
 
 /// Row identifier used for deterministic ordering.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum RowId {
     RowIndex(usize),
-    Key(Vec<u8>),
+    Key(Symbol),
 }
 
 impl RowId {
@@ -14,7 +15,7 @@ impl RowId {
         Self::RowIndex(index)
     }
 
-    pub fn key(bytes: Vec<u8>) -> Self {
+    pub fn key(bytes: Symbol) -> Self {
         Self::Key(bytes)
     }
 }
@@ -36,25 +37,58 @@ impl PartialOrd for RowId {
     }
 }
 
+/// How a numeric cell came to differ.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CellKind {
+    /// Present in both files with different values.
+    #[default]
+    Changed,
+    /// Only in the new file (`--include-entity-changes`); the old value is 0.
+    RowAdded,
+    /// Only in the old file (`--include-entity-changes`); the new value is 0.
+    RowRemoved,
+}
+
+impl CellKind {
+    /// JSON label for whole-row changes; `None` for ordinary cell changes.
+    pub fn entity_label(self) -> Option<&'static str> {
+        match self {
+            CellKind::Changed => None,
+            CellKind::RowAdded => Some("row_added"),
+            CellKind::RowRemoved => Some("row_removed"),
+        }
+    }
+}
+
 /// Deterministic identifier for a single numeric cell.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CellId {
     pub row_id: RowId,
-    pub column: Vec<u8>,
+    pub column: Symbol,
+    pub kind: CellKind,
 }
 
 impl CellId {
-    pub fn new(row_id: RowId, column: Vec<u8>) -> Self {
-        Self { row_id, column }
+    pub fn new(row_id: RowId, column: Symbol) -> Self {
+        Self {
+            row_id,
+            column,
+            kind: CellKind::Changed,
+        }
+    }
+
+    pub fn with_kind(mut self, kind: CellKind) -> Self {
+        self.kind = kind;
+        self
     }
 }
 
 impl Ord for CellId {
     fn cmp(&self, other: &Self) -> Ordering {
-        match self.row_id.cmp(&other.row_id) {
-            Ordering::Equal => self.column.cmp(&other.column),
-            ord => ord,
-        }
+        self.row_id
+            .cmp(&other.row_id)
+            .then_with(|| self.column.cmp(&other.column))
+            .then_with(|| self.kind.cmp(&other.kind))
     }
 }
 
@@ -71,6 +105,11 @@ pub struct TieBreaker {
 }
 
 impl TieBreaker {
+    /// Start at `first`, for a scan chunk whose cells follow earlier chunks.
+    pub fn starting_at(first: u64) -> Self {
+        Self { next: first }
+    }
+
     pub fn next_value(&mut self) -> u64 {
         let current = self.next;
         self.next = self.next.wrapping_add(1);
@@ -108,7 +147,7 @@ mod tests {
 
     fn contributor(row: usize, column: &str, contribution: f64) -> Contributor<CellId> {
         Contributor::new(
-            CellId::new(RowId::row_index(row), column.as_bytes().to_vec()),
+            CellId::new(RowId::row_index(row), column.as_bytes().into()),
             0.0,
             0.0,
             0.0,
@@ -126,9 +165,9 @@ mod tests {
         ];
         sort_contributors(&mut items);
         assert_eq!(items[0].id.row_id, RowId::row_index(1));
-        assert_eq!(items[0].id.column, b"a");
+        assert_eq!(&*items[0].id.column, b"a");
         assert_eq!(items[1].id.row_id, RowId::row_index(1));
-        assert_eq!(items[1].id.column, b"b");
+        assert_eq!(&*items[1].id.column, b"b");
         assert_eq!(items[2].id.row_id, RowId::row_index(2));
     }
 
@@ -0,0 +1,129 @@
+//! Contribution ranking modes.
+//!
+//! By default a changed cell contributes its |delta| (once above tolerance).
+//! `--rank relative` scores it as |delta| / max(|old|, |new|, epsilon), so a
+//! 10% move on a small line outranks a rounding-sized move on a large one.
+//! `--weight-col` instead scales every contribution in a row by the absolute
+//! value of that row's weight (market value, notional, ...). Either way total
+//! change, coverage, the top-K, and the rollups are all in the ranking's
+//! units; tolerance is still applied to the raw delta.
+
+/// Floor for the relative-delta denominator, so cells at zero on both
+/// sides of a tiny move stay finite.
+pub const RELATIVE_EPSILON: f64 = 1e-9;
+
+/// How a changed cell's delta is scored (`--rank`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankMode {
+    /// |delta|.
+    #[default]
+    Abs,
+    /// |delta| / max(|old|, |new|, epsilon).
+    Relative,
+}
+
+impl RankMode {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "abs" => Some(RankMode::Abs),
+            "relative" => Some(RankMode::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RankMode::Abs => "abs",
+            RankMode::Relative => "relative",
+        }
+    }
+
+    /// Score one cell; `abs_delta` is its |delta| after tolerance (zero
+    /// within tolerance, which stays zero).
+    pub fn score(self, old: f64, new: f64, abs_delta: f64) -> f64 {
+        match self {
+            RankMode::Abs => abs_delta,
+            RankMode::Relative => abs_delta / old.abs().max(new.abs()).max(RELATIVE_EPSILON),
+        }
+    }
+}
+
+/// How changed cells are ranked; declared in the `Ranking:` receipt line.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Ranking {
+    /// |delta|, unscaled.
+    #[default]
+    AbsDelta,
+    /// |delta| relative to the larger side (`--rank relative`).
+    Relative,
+    /// |delta| x |weight| of the row's `column`.
+    Weighted { column: Vec<u8> },
+}
+
+impl Ranking {
+    /// The ranking for `--rank` and `--weight-col`, which the CLI keeps
+    /// exclusive; a weight column wins.
+    pub fn new(mode: RankMode, weight_col: Option<&str>) -> Self {
+        match (mode, weight_col) {
+            (_, Some(column)) => Ranking::Weighted {
+                column: column.as_bytes().to_vec(),
+            },
+            (RankMode::Abs, None) => Ranking::AbsDelta,
+            (RankMode::Relative, None) => Ranking::Relative,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ranking::AbsDelta => "abs_delta",
+            Ranking::Relative => "relative",
+            Ranking::Weighted { .. } => "weighted",
+        }
+    }
+
+    pub fn weight_column(&self) -> Option<&[u8]> {
+        match self {
+            Ranking::AbsDelta | Ranking::Relative => None,
+            Ranking::Weighted { column } => Some(column),
+        }
+    }
+}
+
+/// Multiplier for a row's contributions: the absolute weight, or 1 when the
+/// row has no usable (finite, non-zero) weight so its changes still rank.
+pub fn row_weight(value: Option<f64>) -> f64 {
+    match value.map(f64::abs) {
+        Some(weight) if weight > 0.0 && weight.is_finite() => weight,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_weight_uses_magnitude_and_falls_back_to_one() {
+        assert_eq!(row_weight(Some(250.0)), 250.0);
+        assert_eq!(row_weight(Some(-4.0)), 4.0);
+        assert_eq!(row_weight(Some(0.0)), 1.0);
+        assert_eq!(row_weight(None), 1.0);
+    }
+
+    #[test]
+    fn relative_score_divides_by_the_larger_side() {
+        assert_eq!(RankMode::Abs.score(100.0, 110.0, 10.0), 10.0);
+        assert_eq!(RankMode::Relative.score(100.0, 110.0, 10.0), 10.0 / 110.0);
+        assert_eq!(RankMode::Relative.score(-50.0, 0.0, 50.0), 1.0);
+        assert_eq!(RankMode::Relative.score(0.0, 0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn ranking_from_flags() {
+        assert_eq!(Ranking::new(RankMode::Abs, None), Ranking::AbsDelta);
+        assert_eq!(Ranking::new(RankMode::Relative, None), Ranking::Relative);
+        let weighted = Ranking::new(RankMode::Abs, Some("market_value"));
+        assert_eq!(weighted.as_str(), "weighted");
+        assert_eq!(weighted.weight_column(), Some(&b"market_value"[..]));
+    }
+}
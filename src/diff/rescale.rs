@@ -0,0 +1,190 @@
+//! Unit-rescale detection.
+//!
+//! An export that switches a column from dollars to thousands changes every
+//! cell of that column by the same factor. When all of a column's compared
+//! cell pairs share one `new / old` ratio within 1% and that ratio is a
+//! power of ten other than 1, the column is reported as rescaled so the
+//! verdict can name the cause instead of leaving it to a wall of
+//! contributors.
+
+/// Pairs with a non-zero value needed before a column can look rescaled.
+const MIN_RESCALE_CELLS: u64 = 3;
+/// Largest relative spread of the `new / old` ratios still read as constant.
+const RATIO_SPREAD: f64 = 0.01;
+/// Largest power of ten (either way) reported as a rescale.
+const MAX_EXPONENT: i32 = 12;
+
+/// A column whose new values look like its old values times `factor`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RescaledColumn {
+    pub column: Vec<u8>,
+    /// `new / old`, a power of ten.
+    pub factor: f64,
+    /// Compared pairs with a non-zero value.
+    pub cells: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ColumnRatios {
+    cells: u64,
+    min: f64,
+    max: f64,
+    /// False once a pair rules out a constant positive ratio.
+    consistent: bool,
+}
+
+impl Default for ColumnRatios {
+    fn default() -> Self {
+        Self {
+            cells: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            consistent: true,
+        }
+    }
+}
+
+/// Collects per-column `new / old` ratio bounds during the diff pass.
+#[derive(Debug, Clone)]
+pub struct RescaleTracker {
+    columns: Vec<ColumnRatios>,
+}
+
+impl RescaleTracker {
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns: vec![ColumnRatios::default(); columns],
+        }
+    }
+
+    /// Record one aligned cell pair. Pairs that are zero on both sides fit
+    /// any factor and are ignored; a zero opposite a non-zero value, or a
+    /// sign flip, rules the column out.
+    #[inline]
+    pub fn observe(&mut self, column: usize, old: f64, new: f64) {
+        let entry = &mut self.columns[column];
+        if !entry.consistent || (old == 0.0 && new == 0.0) {
+            return;
+        }
+        let ratio = new / old;
+        if !ratio.is_finite() || ratio <= 0.0 {
+            entry.consistent = false;
+            return;
+        }
+        entry.cells += 1;
+        entry.min = entry.min.min(ratio);
+        entry.max = entry.max.max(ratio);
+    }
+
+    /// Fold in the bounds of a later scan chunk.
+    pub fn merge(&mut self, other: RescaleTracker) {
+        for (entry, next) in self.columns.iter_mut().zip(other.columns) {
+            entry.cells += next.cells;
+            entry.min = entry.min.min(next.min);
+            entry.max = entry.max.max(next.max);
+            entry.consistent &= next.consistent;
+        }
+    }
+
+    /// Rescaled columns in column order; `names` is indexed like `observe`.
+    pub fn finish<N: AsRef<[u8]>>(&self, names: &[N]) -> Vec<RescaledColumn> {
+        self.columns
+            .iter()
+            .zip(names)
+            .filter_map(|(entry, name)| {
+                Some(RescaledColumn {
+                    column: name.as_ref().to_vec(),
+                    factor: entry.factor()?,
+                    cells: entry.cells,
+                })
+            })
+            .collect()
+    }
+}
+
+impl ColumnRatios {
+    fn factor(&self) -> Option<f64> {
+        if !self.consistent || self.cells < MIN_RESCALE_CELLS {
+            return None;
+        }
+        if self.max > self.min * (1.0 + RATIO_SPREAD) {
+            return None;
+        }
+        let exponent = (self.min * self.max).sqrt().log10().round() as i32;
+        if exponent == 0 || exponent.abs() > MAX_EXPONENT {
+            return None;
+        }
+        let factor = if exponent > 0 {
+            10f64.powi(exponent)
+        } else {
+            1.0 / 10f64.powi(-exponent)
+        };
+        let within = |ratio: f64| (ratio / factor - 1.0).abs() <= RATIO_SPREAD;
+        (within(self.min) && within(self.max)).then_some(factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(pairs: &[(f64, f64)]) -> Option<f64> {
+        let mut tracker = RescaleTracker::new(1);
+        for &(old, new) in pairs {
+            tracker.observe(0, old, new);
+        }
+        tracker
+            .finish(&[b"value"])
+            .first()
+            .map(|column| column.factor)
+    }
+
+    #[test]
+    fn detects_power_of_ten_rescales() {
+        assert_eq!(
+            detect(&[
+                (125_000.0, 125.0),
+                (40_000.0, 40.1),
+                (0.0, 0.0),
+                (-9_000.0, -9.0)
+            ]),
+            Some(0.001)
+        );
+        assert_eq!(
+            detect(&[(1.5, 150.0), (2.0, 200.0), (3.0, 301.0)]),
+            Some(100.0)
+        );
+    }
+
+    #[test]
+    fn ignores_other_changes() {
+        // Too few cells, a 5% uplift, a mixed ratio, a zero, and a sign flip.
+        assert_eq!(detect(&[(1.0, 1000.0), (2.0, 2000.0)]), None);
+        assert_eq!(
+            detect(&[(100.0, 105.0), (200.0, 210.0), (300.0, 315.0)]),
+            None
+        );
+        assert_eq!(detect(&[(1.0, 1000.0), (2.0, 2000.0), (3.0, 3.0)]), None);
+        assert_eq!(detect(&[(1.0, 1000.0), (2.0, 2000.0), (3.0, 0.0)]), None);
+        assert_eq!(
+            detect(&[(1.0, 1000.0), (2.0, 2000.0), (3.0, -3000.0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn merged_chunks_match_a_single_pass() {
+        let mut first = RescaleTracker::new(1);
+        first.observe(0, 1.0, 1000.0);
+        first.observe(0, 2.0, 2000.0);
+        let mut second = RescaleTracker::new(1);
+        second.observe(0, 3.0, 3000.0);
+        first.merge(second);
+        assert_eq!(first.finish(&[b"value"])[0].factor, 1000.0);
+
+        let mut broken = RescaleTracker::new(1);
+        broken.observe(0, 4.0, 0.0);
+        first.merge(broken);
+        assert!(first.finish(&[b"value"]).is_empty());
+    }
+}
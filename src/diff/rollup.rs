@@ -0,0 +1,399 @@
+//! Per-column and per-row rollups of numeric change.
+//!
+//! Cell-level top-K explains concentrated change. The rollups answer the
+//! coarser questions of which column moved and which rows (units, accounts)
+//! explain the change: contributions (|delta| above tolerance) summed per
+//! numeric column or per aligned row, with the number of changed cells and
+//! each group's share of total change. For a NO REAL CHANGE verdict,
+//! per-column statistics of every compared |delta| (tolerated ones included)
+//! tell exact equality apart from drift just below tolerance.
+
+use std::cmp::Ordering;
+
+use super::heap::{Contributor, TopContributors};
+use super::order::RowId;
+
+/// Change attributed to one numeric column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnContribution {
+    pub column: Vec<u8>,
+    /// Sum of cell contributions in this column.
+    pub contribution: f64,
+    pub cells_changed: u64,
+    /// `contribution / total_change`.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnRollup {
+    /// Columns with at least one changed cell, by descending contribution,
+    /// then by column order.
+    pub columns: Vec<ColumnContribution>,
+}
+
+/// Collects per-column contribution totals during the diff pass.
+#[derive(Debug, Clone)]
+pub struct ColumnTotals {
+    contribution: Vec<f64>,
+    cells_changed: Vec<u64>,
+}
+
+impl ColumnTotals {
+    pub fn new(columns: usize) -> Self {
+        Self {
+            contribution: vec![0.0; columns],
+            cells_changed: vec![0; columns],
+        }
+    }
+
+    /// Record one cell; zero contributions (within tolerance) are ignored.
+    pub fn observe(&mut self, column: usize, contribution: f64) {
+        if contribution > 0.0 {
+            self.contribution[column] += contribution;
+            self.cells_changed[column] += 1;
+        }
+    }
+
+    /// Fold in the totals of a later scan chunk.
+    pub fn merge(&mut self, other: ColumnTotals) {
+        for (total, next) in self.contribution.iter_mut().zip(other.contribution) {
+            *total += next;
+        }
+        for (total, next) in self.cells_changed.iter_mut().zip(other.cells_changed) {
+            *total += next;
+        }
+    }
+
+    /// Summed contribution of one column.
+    pub fn contribution(&self, column: usize) -> f64 {
+        self.contribution[column]
+    }
+
+    /// Build the rollup; `names` is indexed like `observe`.
+    pub fn finish<N: AsRef<[u8]>>(&self, names: &[N], total_change: f64) -> ColumnRollup {
+        let mut columns = self
+            .contribution
+            .iter()
+            .zip(&self.cells_changed)
+            .zip(names)
+            .filter(|((_, cells), _)| **cells > 0)
+            .map(|((contribution, cells_changed), name)| ColumnContribution {
+                column: name.as_ref().to_vec(),
+                contribution: *contribution,
+                cells_changed: *cells_changed,
+                share: if total_change > 0.0 {
+                    contribution / total_change
+                } else {
+                    0.0
+                },
+            })
+            .collect::<Vec<_>>();
+        // Stable sort keeps column order among equal contributions.
+        columns.sort_by(|a, b| b.contribution.total_cmp(&a.contribution));
+        ColumnRollup { columns }
+    }
+}
+
+/// |delta| statistics for one numeric column over the cells compared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDeltaStats {
+    pub column: Vec<u8>,
+    /// Cells compared (both sides numeric, or zeroed by `--on-missing zero`).
+    pub cells: u64,
+    /// Cells whose delta is not exactly zero.
+    pub cells_differing: u64,
+    /// `None` when no cell was compared.
+    pub min_abs_delta: Option<f64>,
+    pub max_abs_delta: Option<f64>,
+    pub mean_abs_delta: Option<f64>,
+}
+
+/// Collects per-column |delta| statistics during the diff pass.
+#[derive(Debug, Clone)]
+pub struct ColumnDeltas {
+    cells: Vec<u64>,
+    cells_differing: Vec<u64>,
+    min: Vec<f64>,
+    max: Vec<f64>,
+    sum: Vec<f64>,
+}
+
+impl ColumnDeltas {
+    pub fn new(columns: usize) -> Self {
+        Self {
+            cells: vec![0; columns],
+            cells_differing: vec![0; columns],
+            min: vec![f64::INFINITY; columns],
+            max: vec![0.0; columns],
+            sum: vec![0.0; columns],
+        }
+    }
+
+    /// Record one compared cell's delta.
+    pub fn observe(&mut self, column: usize, delta: f64) {
+        let abs = delta.abs();
+        self.cells[column] += 1;
+        if abs != 0.0 {
+            self.cells_differing[column] += 1;
+        }
+        self.min[column] = self.min[column].min(abs);
+        self.max[column] = self.max[column].max(abs);
+        self.sum[column] += abs;
+    }
+
+    /// Fold in the statistics of a later scan chunk.
+    pub fn merge(&mut self, other: ColumnDeltas) {
+        for column in 0..self.cells.len() {
+            self.cells[column] += other.cells[column];
+            self.cells_differing[column] += other.cells_differing[column];
+            self.min[column] = self.min[column].min(other.min[column]);
+            self.max[column] = self.max[column].max(other.max[column]);
+            self.sum[column] += other.sum[column];
+        }
+    }
+
+    /// One entry per column, in column order; `names` is indexed like `observe`.
+    pub fn finish<N: AsRef<[u8]>>(&self, names: &[N]) -> Vec<ColumnDeltaStats> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(column, name)| {
+                let cells = self.cells[column];
+                let compared = cells > 0;
+                ColumnDeltaStats {
+                    column: name.as_ref().to_vec(),
+                    cells,
+                    cells_differing: self.cells_differing[column],
+                    min_abs_delta: compared.then_some(self.min[column]),
+                    max_abs_delta: compared.then_some(self.max[column]),
+                    mean_abs_delta: compared.then(|| self.sum[column] / cells as f64),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Change attributed to one aligned row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowContribution {
+    pub row_id: RowId,
+    /// Sum of cell contributions across the row's numeric columns.
+    pub contribution: f64,
+    pub cells_changed: u64,
+    /// `contribution / total_change`.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowRollup {
+    /// The top rows by descending contribution, then by row id.
+    pub rows: Vec<RowContribution>,
+}
+
+/// Keeps the top rows by summed contribution during the diff pass.
+///
+/// A row's cells are observed one after another and a row never spans scan
+/// chunks, so only the row in progress is held open; it is ranked once the
+/// next row starts (or the chunk ends).
+#[derive(Debug)]
+pub struct RowTotals {
+    current: Option<PendingRow>,
+    /// Ranked rows; the id carries the changed-cell count, and the value
+    /// fields of the heap entry are unused.
+    top: TopContributors<(RowId, u64)>,
+}
+
+#[derive(Debug)]
+struct PendingRow {
+    row_id: RowId,
+    contribution: f64,
+    cells_changed: u64,
+    /// Tie-break of the row's first changed cell.
+    tie_break: u64,
+}
+
+impl RowTotals {
+    pub fn new(max: usize) -> Self {
+        Self {
+            current: None,
+            top: TopContributors::new(max),
+        }
+    }
+
+    /// Record one cell; zero contributions (within tolerance) are ignored.
+    pub fn observe(&mut self, row_id: &RowId, contribution: f64, tie_break: u64) {
+        if contribution <= 0.0 {
+            return;
+        }
+        match self.current.as_mut() {
+            Some(pending) if pending.row_id == *row_id => {
+                pending.contribution += contribution;
+                pending.cells_changed += 1;
+            }
+            _ => {
+                self.flush();
+                self.current = Some(PendingRow {
+                    row_id: row_id.clone(),
+                    contribution,
+                    cells_changed: 1,
+                    tie_break,
+                });
+            }
+        }
+    }
+
+    /// Fold in the rows of a later scan chunk.
+    pub fn merge(&mut self, mut other: RowTotals) {
+        self.flush();
+        other.flush();
+        self.top.merge(other.top);
+    }
+
+    pub fn finish(mut self, total_change: f64) -> RowRollup {
+        self.flush();
+        let mut rows = self
+            .top
+            .into_vec()
+            .into_iter()
+            .map(|entry| {
+                let (row_id, cells_changed) = entry.id;
+                RowContribution {
+                    row_id,
+                    contribution: entry.contribution,
+                    cells_changed,
+                    share: if total_change > 0.0 {
+                        entry.contribution / total_change
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by(
+            |left, right| match right.contribution.total_cmp(&left.contribution) {
+                Ordering::Equal => left.row_id.cmp(&right.row_id),
+                ord => ord,
+            },
+        );
+        RowRollup { rows }
+    }
+
+    fn flush(&mut self) {
+        if let Some(pending) = self.current.take() {
+            self.top.push(Contributor::new(
+                (pending.row_id, pending.cells_changed),
+                0.0,
+                0.0,
+                0.0,
+                pending.contribution,
+                pending.tie_break,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rolls_up_contributions_by_column() {
+        let mut totals = ColumnTotals::new(3);
+        totals.observe(0, 1.0);
+        totals.observe(1, 4.0);
+        totals.observe(1, 2.0);
+        totals.observe(2, 0.0);
+        totals.observe(0, 3.0);
+
+        let rollup = totals.finish(&["a", "b", "c"], 10.0);
+        assert_eq!(
+            rollup.columns,
+            vec![
+                ColumnContribution {
+                    column: b"b".to_vec(),
+                    contribution: 6.0,
+                    cells_changed: 2,
+                    share: 0.6,
+                },
+                ColumnContribution {
+                    column: b"a".to_vec(),
+                    contribution: 4.0,
+                    cells_changed: 2,
+                    share: 0.4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ranks_rows_by_summed_contribution() {
+        let mut totals = RowTotals::new(2);
+        totals.observe(&RowId::row_index(1), 1.0, 0);
+        totals.observe(&RowId::row_index(1), 1.0, 1);
+        totals.observe(&RowId::row_index(2), 5.0, 2);
+        totals.observe(&RowId::row_index(3), 0.0, 4);
+        totals.observe(&RowId::row_index(4), 1.5, 6);
+
+        let rollup = totals.finish(10.0);
+        let rows: Vec<(RowId, f64, u64)> = rollup
+            .rows
+            .iter()
+            .map(|row| (row.row_id.clone(), row.contribution, row.cells_changed))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![(RowId::row_index(2), 5.0, 1), (RowId::row_index(1), 2.0, 2)]
+        );
+        assert_eq!(rollup.rows[0].share, 0.5);
+    }
+
+    #[test]
+    fn row_merge_matches_a_single_pass() {
+        let mut first = RowTotals::new(3);
+        first.observe(&RowId::row_index(1), 2.0, 0);
+        let mut second = RowTotals::new(3);
+        second.observe(&RowId::row_index(2), 1.0, 2);
+        second.observe(&RowId::row_index(2), 3.0, 3);
+        first.merge(second);
+
+        let rollup = first.finish(6.0);
+        assert_eq!(rollup.rows[0].row_id, RowId::row_index(2));
+        assert_eq!(rollup.rows[0].cells_changed, 2);
+        assert_eq!(rollup.rows[1].contribution, 2.0);
+    }
+
+    #[test]
+    fn merge_matches_a_single_pass() {
+        let mut first = ColumnTotals::new(2);
+        first.observe(0, 1.5);
+        let mut second = ColumnTotals::new(2);
+        second.observe(0, 0.5);
+        second.observe(1, 2.0);
+        first.merge(second);
+
+        let rollup = first.finish(&["x", "y"], 4.0);
+        assert_eq!(rollup.columns[0].contribution, 2.0);
+        assert_eq!(rollup.columns[0].cells_changed, 2);
+        assert_eq!(rollup.columns[1].column, b"y".to_vec());
+        assert_eq!(rollup.columns[1].cells_changed, 1);
+    }
+
+    #[test]
+    fn delta_stats_cover_tolerated_cells_and_merge() {
+        let mut first = ColumnDeltas::new(2);
+        first.observe(0, 0.0);
+        first.observe(0, -1e-10);
+        let mut second = ColumnDeltas::new(2);
+        second.observe(0, 3e-10);
+        first.merge(second);
+
+        let stats = first.finish(&["x", "y"]);
+        assert_eq!(stats[0].cells, 3);
+        assert_eq!(stats[0].cells_differing, 2);
+        assert_eq!(stats[0].min_abs_delta, Some(0.0));
+        assert_eq!(stats[0].max_abs_delta, Some(3e-10));
+        assert!((stats[0].mean_abs_delta.unwrap() - 4e-10 / 3.0).abs() < 1e-24);
+        assert_eq!(stats[1].cells, 0);
+        assert_eq!(stats[1].mean_abs_delta, None);
+    }
+}
@@ -0,0 +1,89 @@
+//! Text change summary (`--include-text`).
+//!
+//! Numeric verdicts ignore text columns, but a status flip (`Active` →
+//! `Sold`) often explains why the numbers moved. This summary counts the
+//! changed text cells per column and keeps the first few as samples. It is
+//! reported alongside the verdict and never changes it.
+
+use crate::normalize::trim::ascii_trim;
+
+/// Samples kept per column.
+pub const TEXT_SAMPLES_PER_COLUMN: usize = 3;
+
+/// Changed text cells in the aligned rows, one entry per compared column.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextChangeReport {
+    pub columns: Vec<TextColumnChanges>,
+}
+
+impl TextChangeReport {
+    pub fn cells_changed(&self) -> u64 {
+        self.columns.iter().map(|column| column.changed).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextColumnChanges {
+    pub column: Vec<u8>,
+    pub changed: u64,
+    /// First changed cells in alignment order.
+    pub samples: Vec<TextSample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextSample {
+    pub row_id: Vec<u8>,
+    /// Old/new cell text; `None` unless values may be shown (`--explicit`).
+    pub values: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+impl TextColumnChanges {
+    pub fn new(column: Vec<u8>) -> Self {
+        Self {
+            column,
+            changed: 0,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Count one aligned cell pair when its ASCII-trimmed text differs.
+    pub fn observe(&mut self, row_id: &[u8], old: &[u8], new: &[u8], explicit: bool) {
+        let old = ascii_trim(old);
+        let new = ascii_trim(new);
+        if old == new {
+            return;
+        }
+        self.changed = self.changed.saturating_add(1);
+        if self.samples.len() < TEXT_SAMPLES_PER_COLUMN {
+            self.samples.push(TextSample {
+                row_id: row_id.to_vec(),
+                values: explicit.then(|| (old.to_vec(), new.to_vec())),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_trimmed_changes_and_caps_samples() {
+        let mut column = TextColumnChanges::new(b"status".to_vec());
+        column.observe(b"A", b"Active", b" Active ", true);
+        for row in [b"B", b"C", b"D", b"E"] {
+            column.observe(row, b"Active", b"Sold", true);
+        }
+        assert_eq!(column.changed, 4);
+        assert_eq!(column.samples.len(), TEXT_SAMPLES_PER_COLUMN);
+        assert_eq!(column.samples[0].row_id, b"B".to_vec());
+        assert_eq!(
+            column.samples[0].values,
+            Some((b"Active".to_vec(), b"Sold".to_vec()))
+        );
+
+        let mut redacted = TextColumnChanges::new(b"status".to_vec());
+        redacted.observe(b"B", b"Active", b"Sold", false);
+        assert_eq!(redacted.samples[0].values, None);
+    }
+}
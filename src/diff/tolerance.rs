@@ -1,5 +1,8 @@
 // Tolerance application & max_abs_delta tracking (bd-tvf)
 
+use crate::format::numbers::format_float_shortest;
+use crate::normalize::trim::ascii_trim;
+
 /// Tracks tolerance and the maximum absolute delta observed (pre-zeroing).
 #[derive(Debug, Clone, Copy)]
 pub struct ToleranceTracker {
@@ -18,12 +21,18 @@ impl ToleranceTracker {
     /// Returns (delta, contribution). Contribution is zeroed when within tolerance.
     #[inline]
     pub fn apply(&mut self, old: f64, new: f64) -> (f64, f64) {
+        self.apply_with(old, new, self.tolerance)
+    }
+
+    /// Like `apply`, with a per-column tolerance (`--smart-tolerance`).
+    #[inline]
+    pub fn apply_with(&mut self, old: f64, new: f64, tolerance: f64) -> (f64, f64) {
         let delta = new - old;
         let abs = delta.abs();
         if abs > self.max_abs_delta {
             self.max_abs_delta = abs;
         }
-        let contribution = if abs <= self.tolerance { 0.0 } else { abs };
+        let contribution = if abs <= tolerance { 0.0 } else { abs };
         (delta, contribution)
     }
 
@@ -33,9 +42,122 @@ impl ToleranceTracker {
     }
 }
 
+/// One `--column-tolerance COLUMN=FLOAT` override of the run tolerance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnTolerance {
+    pub column: Vec<u8>,
+    pub tolerance: f64,
+}
+
+impl ColumnTolerance {
+    /// Parse `COLUMN=FLOAT`. The split is at the last `=`, so column names
+    /// may contain `=`; the name is ASCII-trimmed like headers.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let (column, tolerance) = raw
+            .rsplit_once('=')
+            .ok_or_else(|| "must be column=tolerance".to_string())?;
+        let column = ascii_trim(column.as_bytes());
+        if column.is_empty() {
+            return Err("column name must be non-empty".to_string());
+        }
+        let tolerance: f64 = tolerance
+            .trim()
+            .parse()
+            .map_err(|_| "tolerance must be a number".to_string())?;
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err("tolerance must be x >= 0".to_string());
+        }
+        Ok(Self {
+            column: column.to_vec(),
+            tolerance,
+        })
+    }
+}
+
+/// Per-column tolerance for `column`: the last matching override, else `base`.
+pub fn column_tolerance(overrides: &[ColumnTolerance], column: &[u8], base: f64) -> f64 {
+    overrides
+        .iter()
+        .rev()
+        .find(|entry| entry.column == column)
+        .map_or(base, |entry| entry.tolerance)
+}
+
+/// `--tolerance-sweep` levels used when the flag is given without a list.
+pub const DEFAULT_SWEEP_LEVELS: &str = "1e-9,1e-6,1e-3,0.01,1";
+
+/// `--tolerance-sweep[=LEVELS]`: tolerances to re-check every cell against,
+/// ascending and deduplicated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepLevels {
+    pub levels: Vec<f64>,
+}
+
+impl SweepLevels {
+    /// Parse a comma-separated list of tolerances (each x >= 0).
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let mut levels = Vec::new();
+        for part in raw.split(',') {
+            let level: f64 = part.trim().parse().map_err(|_| {
+                "tolerance sweep must be a comma-separated list of numbers".to_string()
+            })?;
+            if !level.is_finite() || level < 0.0 {
+                return Err("tolerance sweep levels must be x >= 0".to_string());
+            }
+            levels.push(level);
+        }
+        levels.sort_by(f64::total_cmp);
+        levels.dedup();
+        Ok(Self { levels })
+    }
+
+    /// The flag value that reproduces these levels.
+    pub fn as_arg(&self) -> String {
+        self.levels
+            .iter()
+            .map(|level| format_float_shortest(*level))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Cells whose absolute delta exceeds each sweep level, counted in the same
+/// scan as the verdict. A delta equal to a level is within it, as in
+/// [`ToleranceTracker::apply`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToleranceSweep {
+    pub levels: Vec<f64>,
+    pub cells_above: Vec<u64>,
+}
+
+impl ToleranceSweep {
+    pub fn new(levels: &[f64]) -> Self {
+        Self {
+            levels: levels.to_vec(),
+            cells_above: vec![0; levels.len()],
+        }
+    }
+
+    #[inline]
+    pub fn observe(&mut self, delta: f64) {
+        let abs = delta.abs();
+        for (level, count) in self.levels.iter().zip(self.cells_above.iter_mut()) {
+            if abs > *level {
+                *count += 1;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: ToleranceSweep) {
+        for (count, next) in self.cells_above.iter_mut().zip(other.cells_above) {
+            *count += next;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::ToleranceTracker;
+    use super::{ColumnTolerance, SweepLevels, ToleranceSweep, ToleranceTracker, column_tolerance};
 
     #[test]
     fn zeros_within_tolerance() {
@@ -52,6 +174,14 @@ mod tests {
         assert!((contrib - 0.01).abs() < 1e-12);
     }
 
+    #[test]
+    fn applies_a_per_column_tolerance() {
+        let mut tracker = ToleranceTracker::new(1e-9);
+        let (_delta, contrib) = tracker.apply_with(100.0, 100.004, 0.005);
+        assert_eq!(contrib, 0.0);
+        assert!((tracker.max_abs_delta() - 0.004).abs() < 1e-9);
+    }
+
     #[test]
     fn tracks_max_abs_delta_pre_zeroing() {
         let mut tracker = ToleranceTracker::new(1.0);
@@ -59,4 +189,37 @@ mod tests {
         tracker.apply(10.0, 8.0);
         assert!((tracker.max_abs_delta() - 2.0).abs() < 1e-12);
     }
+
+    #[test]
+    fn parses_column_tolerance_overrides() {
+        let entry = ColumnTolerance::parse(" a=b = 0.5").unwrap();
+        assert_eq!(entry.column, b"a=b".to_vec());
+        assert_eq!(entry.tolerance, 0.5);
+        assert!(ColumnTolerance::parse("balance").is_err());
+        assert!(ColumnTolerance::parse("=0.5").is_err());
+        assert!(ColumnTolerance::parse("balance=-1").is_err());
+
+        let overrides = [
+            ColumnTolerance::parse("balance=0.5").unwrap(),
+            ColumnTolerance::parse("balance=2").unwrap(),
+        ];
+        assert_eq!(column_tolerance(&overrides, b"balance", 1e-9), 2.0);
+        assert_eq!(column_tolerance(&overrides, b"rate", 1e-9), 1e-9);
+    }
+
+    #[test]
+    fn sweep_counts_cells_above_each_level() {
+        let levels = SweepLevels::parse("1, 1e-3,0.01,1e-3").unwrap();
+        assert_eq!(levels.levels, vec![1e-3, 0.01, 1.0]);
+        assert_eq!(levels.as_arg(), "1e-3,0.01,1");
+        let mut sweep = ToleranceSweep::new(&levels.levels);
+        sweep.observe(0.005);
+        let mut next = ToleranceSweep::new(&levels.levels);
+        next.observe(-2.0);
+        next.observe(0.01);
+        sweep.merge(next);
+        assert_eq!(sweep.cells_above, vec![3, 1, 1]);
+        assert!(SweepLevels::parse("0.1,-1").is_err());
+        assert!(SweepLevels::parse("").is_err());
+    }
 }
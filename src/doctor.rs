@@ -4,11 +4,12 @@ use serde::Serialize;
 use serde_json::json;
 
 use crate::cli::args::{DoctorAction, DoctorArgs, RobotDocsAction};
+use crate::refusal::process::PipelineError;
 
 const DOCTOR_SCHEMA_VERSION: &str = "rvl.doctor.v1";
 const DOCTOR_CONTRACT_VERSION: &str = "cmdrvl.read_only_doctor.v1";
 
-pub fn run(args: &DoctorArgs, json_output: bool) -> Result<u8, Box<dyn std::error::Error>> {
+pub fn run(args: &DoctorArgs, json_output: bool) -> Result<u8, PipelineError> {
     if args.fix {
         return fix_unavailable();
     }
@@ -28,7 +29,7 @@ pub fn run(args: &DoctorArgs, json_output: bool) -> Result<u8, Box<dyn std::erro
     }
 }
 
-fn health(json: bool) -> Result<u8, Box<dyn std::error::Error>> {
+fn health(json: bool) -> Result<u8, PipelineError> {
     let report = build_report();
     if json {
         println!("{}", serde_json::to_string_pretty(&report)?);
@@ -43,7 +44,7 @@ fn health(json: bool) -> Result<u8, Box<dyn std::error::Error>> {
     Ok(report.exit_code)
 }
 
-fn human_triage() -> Result<u8, Box<dyn std::error::Error>> {
+fn human_triage() -> Result<u8, PipelineError> {
     let report = build_report();
     println!("RVL DOCTOR");
     println!();
@@ -62,7 +63,7 @@ fn human_triage() -> Result<u8, Box<dyn std::error::Error>> {
     Ok(report.exit_code)
 }
 
-pub fn emit_capabilities(json: bool) -> Result<u8, Box<dyn std::error::Error>> {
+pub fn emit_capabilities(json: bool) -> Result<u8, PipelineError> {
     let payload = build_capabilities();
     if json {
         println!("{}", serde_json::to_string_pretty(&payload)?);
@@ -76,7 +77,7 @@ pub fn emit_capabilities(json: bool) -> Result<u8, Box<dyn std::error::Error>> {
     Ok(0)
 }
 
-pub fn emit_robot_docs(action: Option<&RobotDocsAction>) -> Result<u8, Box<dyn std::error::Error>> {
+pub fn emit_robot_docs(action: Option<&RobotDocsAction>) -> Result<u8, PipelineError> {
     match action {
         Some(RobotDocsAction::Guide) | None => {}
     }
@@ -121,13 +122,13 @@ pub fn emit_robot_docs(action: Option<&RobotDocsAction>) -> Result<u8, Box<dyn s
     Ok(0)
 }
 
-pub fn emit_robot_triage() -> Result<u8, Box<dyn std::error::Error>> {
+pub fn emit_robot_triage() -> Result<u8, PipelineError> {
     let report = build_report();
     println!("{}", serde_json::to_string_pretty(&report)?);
     Ok(report.exit_code)
 }
 
-fn fix_unavailable() -> Result<u8, Box<dyn std::error::Error>> {
+fn fix_unavailable() -> Result<u8, PipelineError> {
     use std::io::Write;
 
     let mut stderr = std::io::stderr();
@@ -491,6 +492,10 @@ fn build_capabilities() -> DoctorCapabilities {
                 name: "EPISTEMIC_WITNESS",
                 description: "Overrides the witness ledger path for comparison runs; doctor commands do not write it.",
             },
+            EnvVarCapability {
+                name: "RVL_*",
+                description: "Environment defaults for comparison flags (RVL_KEY, RVL_THRESHOLD, RVL_TOLERANCE, ...); explicit flags win.",
+            },
             EnvVarCapability {
                 name: "HOME",
                 description: "Used to resolve ~/.cmdrvl default state and config paths; doctor commands do not write them.",
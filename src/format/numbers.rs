@@ -61,6 +61,21 @@ pub fn format_percent_one_decimal(value: f64) -> String {
     format!("{:.1}%", value * 100.0)
 }
 
+/// Format a byte count with a binary unit and one decimal place (`1.5 GiB`).
+pub fn format_bytes(value: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if value < 1024 {
+        return format!("{value} B");
+    }
+    let mut scaled = value as f64 / 1024.0;
+    let mut unit = 0;
+    while scaled >= 1024.0 && unit + 1 < UNITS.len() {
+        scaled /= 1024.0;
+        unit += 1;
+    }
+    format!("{scaled:.1} {}", UNITS[unit])
+}
+
 fn format_scientific_short(value: f64) -> String {
     let raw = format!("{:e}", value);
     let (mantissa, exponent) = match raw.split_once('e') {
@@ -133,4 +148,11 @@ mod tests {
         assert_eq!(format_percent_one_decimal(0.95), "95.0%");
         assert_eq!(format_percent_one_decimal(0.001), "0.1%");
     }
+
+    #[test]
+    fn formats_bytes_in_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(6 * 1024 * 1024 * 1024), "6.0 GiB");
+    }
 }
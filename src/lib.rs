@@ -1,8 +1,17 @@
 #![forbid(unsafe_code)]
+// The output schema (`output_schema`) is one large `json!` literal.
+#![recursion_limit = "256"]
 
+pub mod agent_manifest;
 pub mod alignment;
+pub mod assertion;
+pub mod batch;
+pub mod bench;
+pub mod calibrate;
 pub mod cli;
+pub mod compare;
 pub mod csv;
+pub mod demo;
 pub mod diff;
 pub mod doctor;
 pub mod format;
@@ -14,12 +23,23 @@ pub(crate) mod paths;
 pub mod profile;
 pub mod refusal;
 pub mod repro;
+pub mod three_way;
+pub(crate) mod trace;
+pub mod watch;
 pub mod witness;
+pub mod workbook;
+pub mod xlsx;
+
+pub use crate::compare::{CompareInput, CompareOptions, CompareResult, compare};
+use crate::refusal::process::PipelineError;
 
 pub(crate) const OPERATOR_JSON: &str = include_str!("../operator.json");
 
 /// Run the rvl pipeline. Returns exit code (0, 1, or 2).
-pub fn run() -> Result<u8, Box<dyn std::error::Error>> {
+///
+/// Domain refusals are reported through the exit code and output; `Err` is
+/// reserved for process-level failures (I/O, invalid `--key` identifiers).
+pub fn run() -> Result<u8, PipelineError> {
     if let Some(display_mode) = detect_display_mode(std::env::args_os()) {
         return handle_display_mode(display_mode);
     }
@@ -45,7 +65,7 @@ pub fn run() -> Result<u8, Box<dyn std::error::Error>> {
     }
 
     if args.schema {
-        return handle_display_mode(DisplayMode::Schema);
+        return handle_display_mode(DisplayMode::Schema(args.output_version));
     }
 
     if args.robot_triage {
@@ -58,25 +78,64 @@ pub fn run() -> Result<u8, Box<dyn std::error::Error>> {
 
     if args.old.is_none() || args.new.is_none() {
         eprintln!(
-            "error: the following required arguments were not provided:\n  <OLD_CSV>\n  <NEW_CSV>\n\nUsage: rvl <OLD_CSV> <NEW_CSV> [OPTIONS]\n       rvl --robot-triage\n       rvl capabilities --json\n       rvl robot-docs guide\n       rvl witness <query|last|count> [OPTIONS]\n       rvl doctor <health|capabilities|robot-docs> [OPTIONS]\n\nFor more information, try '--help'."
+            "error: the following required arguments were not provided:\n  <OLD_CSV>\n  <NEW_CSV>\n\nUsage: rvl [compare] <OLD_CSV> <NEW_CSV> [OPTIONS]\n       rvl watch <OLD_CSV> <NEW_CSV> [OPTIONS]\n       rvl assert <OLD_CSV> <NEW_CSV> --expect <VERDICT> [OPTIONS]\n       rvl --base <BASE_CSV> <OURS_CSV> <THEIRS_CSV> [OPTIONS]\n       rvl batch <DIR> [OPTIONS]\n       rvl inspect <FILE> [OPTIONS]\n       rvl keys <OLD_CSV> <NEW_CSV> [OPTIONS]\n       rvl snapshot <FILE> --out <PATH>\n       rvl verify <FILE> <SNAPSHOT>\n       rvl schema [--output json] [--output-version v0]\n       rvl agent-manifest [--format json|yaml]\n       rvl --robot-triage\n       rvl capabilities --json\n       rvl robot-docs guide\n       rvl witness <query|last|count> [OPTIONS]\n       rvl calibrate <DIR> [OPTIONS]\n       rvl xlsx <OLD_XLSX> <NEW_XLSX> [OPTIONS]\n       rvl bench <OLD_CSV> <NEW_CSV> | <DIR> [OPTIONS]\n       rvl demo [real-change|no-real-change|refusal] [--json]\n       rvl doctor <health|capabilities|robot-docs> [OPTIONS]\n\nFor more information, try '--help'."
         );
         return Ok(2);
     }
 
+    if !args.assert && (args.expect.is_some() || args.expect_json.is_some()) {
+        eprintln!("rvl: --expect and --expect-json only apply to `rvl assert`");
+        return Ok(2);
+    }
+
+    if args.assert {
+        if args.base.is_some() {
+            eprintln!("rvl: assert does not support three-way --base comparisons");
+            return Ok(2);
+        }
+        return assertion::run(&args);
+    }
+
+    if args.watch {
+        if args.base.is_some() {
+            eprintln!("rvl: watch does not support three-way --base comparisons");
+            return Ok(2);
+        }
+        return watch::run(&args);
+    }
+
+    if args.base.is_some() {
+        return three_way::run(&args);
+    }
+
     run_comparison(args)
 }
 
-fn run_command(
-    cmd: &cli::args::RvlCommand,
-    json_output: bool,
-) -> Result<u8, Box<dyn std::error::Error>> {
+fn run_command(cmd: &cli::args::RvlCommand, json_output: bool) -> Result<u8, PipelineError> {
     match cmd {
         cli::args::RvlCommand::Witness { action } => run_witness(action),
         cli::args::RvlCommand::Capabilities(args) => {
             doctor::emit_capabilities(args.json || json_output)
         }
         cli::args::RvlCommand::RobotDocs { action } => doctor::emit_robot_docs(action.as_ref()),
+        cli::args::RvlCommand::Calibrate(args) => calibrate::run(args, json_output),
+        cli::args::RvlCommand::Xlsx(args) => workbook::run(args, json_output),
+        cli::args::RvlCommand::Bench(args) => bench::run(args, json_output),
+        cli::args::RvlCommand::Demo(args) => demo::run(args, json_output),
         cli::args::RvlCommand::Doctor(args) => doctor::run(args, json_output),
+        cli::args::RvlCommand::Batch(args) => batch::run(args, json_output),
+        cli::args::RvlCommand::Schema(args) => {
+            handle_display_mode(DisplayMode::Schema(args.output_version))
+        }
+        cli::args::RvlCommand::Inspect(args) => orchestrator::inspect::run(args, json_output),
+        cli::args::RvlCommand::Keys(args) => orchestrator::keys::run(args, json_output),
+        cli::args::RvlCommand::Snapshot(args) => {
+            orchestrator::snapshot::run_snapshot(args, json_output)
+        }
+        cli::args::RvlCommand::Verify(args) => {
+            orchestrator::snapshot::run_verify(args, json_output)
+        }
+        cli::args::RvlCommand::AgentManifest(args) => agent_manifest::run(args),
     }
 }
 
@@ -84,7 +143,8 @@ fn run_command(
 enum DisplayMode {
     Version,
     Describe,
-    Schema,
+    /// JSON Schema for one output version.
+    Schema(output::json::OutputVersion),
 }
 
 fn detect_display_mode<I, T>(args: I) -> Option<DisplayMode>
@@ -102,13 +162,13 @@ where
     } else if args.iter().skip(1).any(|arg| arg == "--describe") {
         Some(DisplayMode::Describe)
     } else if args.iter().skip(1).any(|arg| arg == "--schema") {
-        Some(DisplayMode::Schema)
+        Some(DisplayMode::Schema(output::json::OutputVersion::V0))
     } else {
         None
     }
 }
 
-fn handle_display_mode(mode: DisplayMode) -> Result<u8, Box<dyn std::error::Error>> {
+fn handle_display_mode(mode: DisplayMode) -> Result<u8, PipelineError> {
     match mode {
         DisplayMode::Version => {
             println!("rvl {}", env!("CARGO_PKG_VERSION"));
@@ -118,121 +178,582 @@ fn handle_display_mode(mode: DisplayMode) -> Result<u8, Box<dyn std::error::Erro
             println!("{OPERATOR_JSON}");
             Ok(0)
         }
-        DisplayMode::Schema => {
-            let schema = serde_json::json!({
-                "$schema": "https://json-schema.org/draft/2020-12/schema",
-                "$id": "https://rvl.v0/schema.json",
-                "title": "rvl Output Schema",
-                "description": "JSON schema for rvl.v0 output format",
+        DisplayMode::Schema(version) => {
+            let schema = output_schema(version);
+            println!("{}", serde_json::to_string_pretty(&schema)?);
+            Ok(0)
+        }
+    }
+}
+
+/// JSON Schema for one `--json` output version (`--schema`, `rvl schema`).
+pub(crate) fn output_schema(version: output::json::OutputVersion) -> serde_json::Value {
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": format!("https://{}/schema.json", version.as_str()),
+        "title": "rvl Output Schema",
+        "description": format!("JSON schema for {} output format", version.as_str()),
+        "type": "object",
+        "required": ["version", "outcome", "files", "alignment", "dialect", "threshold", "tolerance", "counts", "metrics", "limits", "contributors"],
+        "properties": {
+            "version": { "type": "string", "const": version.as_str() },
+            "outcome": { "type": "string", "enum": ["REAL_CHANGE", "NO_REAL_CHANGE", "REFUSAL"] },
+            "sampled": { "type": "boolean", "const": true },
+            "mode": { "type": "string", "enum": ["exhaustive_numeric", "schema_only"] },
+            "profile_id": { "type": ["string", "null"] },
+            "profile_sha256": { "type": ["string", "null"] },
+            "files": {
                 "type": "object",
-                "required": ["version", "outcome", "files", "alignment", "dialect", "threshold", "tolerance", "counts", "metrics", "limits", "contributors"],
                 "properties": {
-                    "version": { "type": "string", "const": "rvl.v0" },
-                    "outcome": { "type": "string", "enum": ["REAL_CHANGE", "NO_REAL_CHANGE", "REFUSAL"] },
-                    "mode": { "type": "string", "enum": ["exhaustive_numeric"] },
-                    "profile_id": { "type": ["string", "null"] },
-                    "profile_sha256": { "type": ["string", "null"] },
-                    "files": {
+                    "old": { "type": "string" },
+                    "new": { "type": "string" }
+                },
+                "required": ["old", "new"]
+            },
+            "alignment": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["key", "group_by", "row_order"] },
+                    "key_column": { "type": ["string", "null"] },
+                    "key_map": {
                         "type": "object",
                         "properties": {
-                            "old": { "type": "string" },
-                            "new": { "type": "string" }
+                            "file": { "type": "string" },
+                            "entries": { "type": "integer" }
                         },
-                        "required": ["old", "new"]
+                        "required": ["file", "entries"]
+                    },
+                    "key_normalize": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": ["trim", "lower", "strip-leading-zeros"] }
                     },
-                    "alignment": {
+                    "dedup": {
                         "type": "object",
                         "properties": {
-                            "mode": { "type": "string", "enum": ["key", "row_order"] },
-                            "key_column": { "type": ["string", "null"] }
+                            "mode": { "type": "string", "enum": ["sum", "first"] },
+                            "rows_collapsed_old": { "type": "integer" },
+                            "rows_collapsed_new": { "type": "integer" }
                         },
-                        "required": ["mode"]
+                        "required": ["mode", "rows_collapsed_old", "rows_collapsed_new"]
                     },
-                    "dialect": {
+                    "sample": {
                         "type": "object",
                         "properties": {
-                            "old": { "type": ["object", "null"] },
-                            "new": { "type": ["object", "null"] }
-                        }
+                            "method": { "type": "string", "enum": ["key_hash"] },
+                            "size": { "type": "integer" },
+                            "seed": { "type": "integer" },
+                            "rows_eligible": { "type": "integer" },
+                            "rows_sampled": { "type": "integer" }
+                        },
+                        "required": ["method", "size", "seed", "rows_eligible", "rows_sampled"]
+                    }
+                },
+                "required": ["mode"]
+            },
+            "column_map": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "old": { "type": "string" },
+                        "new": { "type": "string" }
                     },
-                    "threshold": { "type": "number" },
-                    "tolerance": { "type": "number" },
-                    "counts": { "type": "object" },
-                    "metrics": { "type": "object" },
-                    "audit": {
-                        "type": "object",
+                    "required": ["old", "new"]
+                }
+            },
+            "suggested_column_maps": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "old": { "type": "string" },
+                        "new": { "type": "string" }
+                    },
+                    "required": ["old", "new"]
+                }
+            },
+            "dialect": {
+                "type": "object",
+                "properties": {
+                    "old": {
+                        "type": ["object", "null"],
+                        "properties": {
+                            "delimiter": { "type": "string" },
+                            "quote": { "type": "string" },
+                            "escape": { "type": ["string", "null"] },
+                            "compression": { "type": "string", "enum": ["gzip", "zstd"] },
+                            "encoding": { "type": "string", "enum": ["utf-16le", "utf-16be", "windows-1252"] },
+                            "decimal": { "type": "string", "enum": ["comma"] },
+                            "confidence": { "type": "number" }
+                        },
+                        "required": ["delimiter", "quote", "escape"]
+                    },
+                    "new": {
+                        "type": ["object", "null"],
                         "properties": {
-                            "numeric_changes_emitted": { "type": "integer" },
-                            "field_changes_emitted": { "type": "integer" },
-                            "truncated": { "type": "boolean" }
+                            "delimiter": { "type": "string" },
+                            "quote": { "type": "string" },
+                            "escape": { "type": ["string", "null"] },
+                            "compression": { "type": "string", "enum": ["gzip", "zstd"] },
+                            "encoding": { "type": "string", "enum": ["utf-16le", "utf-16be", "windows-1252"] },
+                            "decimal": { "type": "string", "enum": ["comma"] },
+                            "confidence": { "type": "number" }
+                        },
+                        "required": ["delimiter", "quote", "escape"]
+                    }
+                }
+            },
+            "warnings": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "code": { "type": "string", "enum": ["W_DUPLICATE_ROWS", "W_DIALECT_MISMATCH"] },
+                        "file": { "type": "string", "enum": ["old", "new"] },
+                        "rows": { "type": "integer" },
+                        "samples": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "record": { "type": "integer" },
+                                    "duplicate_of": { "type": "integer" }
+                                },
+                                "required": ["record", "duplicate_of"]
+                            }
                         },
-                        "required": ["numeric_changes_emitted", "field_changes_emitted", "truncated"]
+                        "deduped": { "type": "boolean" },
+                        "old_delimiter": { "type": "string" },
+                        "new_delimiter": { "type": "string" }
+                    },
+                    "required": ["code"]
+                }
+            },
+            "schema_change": {
+                "type": "object",
+                "properties": {
+                    "old_fingerprint": { "type": "string" },
+                    "new_fingerprint": { "type": "string" },
+                    "columns_old": { "type": "integer" },
+                    "columns_new": { "type": "integer" },
+                    "added": { "type": "array", "items": { "type": "string" } },
+                    "removed": { "type": "array", "items": { "type": "string" } },
+                    "retyped": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "column": { "type": "string" },
+                                "old": { "type": "string", "enum": ["numeric", "text", "empty"] },
+                                "new": { "type": "string", "enum": ["numeric", "text", "empty"] }
+                            },
+                            "required": ["column", "old", "new"]
+                        }
+                    },
+                    "reordered": { "type": "boolean" }
+                },
+                "required": ["old_fingerprint", "new_fingerprint", "columns_old", "columns_new", "added", "removed", "retyped", "reordered"]
+            },
+            "threshold": { "type": "number" },
+            "tolerance": { "type": "number" },
+            "ranking": {
+                "type": "object",
+                "properties": {
+                    "mode": { "type": "string", "enum": ["abs_delta", "relative", "weighted"] },
+                    "weight_column": { "type": "string" }
+                },
+                "required": ["mode"]
+            },
+            "missing_policy": { "type": "string", "enum": ["skip", "zero"] },
+            "config": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string" },
+                    "settings": { "type": "object" },
+                    "overridden": { "type": "array", "items": { "type": "string" } }
+                },
+                "required": ["path", "settings"]
+            },
+            "column_types": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "type": { "type": "string", "enum": ["currency", "percent", "ratio", "date", "count", "number"] },
+                        "tolerance": { "type": "number" }
+                    },
+                    "required": ["column", "type", "tolerance"]
+                }
+            },
+            "percent_columns": {
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "transforms": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "side": { "type": "string", "enum": ["both", "old", "new"] },
+                        "transform": { "type": "string" }
                     },
-                    "limits": {
+                    "required": ["column", "side", "transform"]
+                }
+            },
+            "columns_excluded_mixed": {
+                "type": "array",
+                "items": { "type": "string" }
+            },
+            "column_normalizations": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "currency_symbols": { "type": "array", "items": { "type": "string" } }
+                    },
+                    "required": ["column", "currency_symbols"]
+                }
+            },
+            "counts": {
+                "type": "object",
+                "properties": {
+                    "rows_old": { "type": ["integer", "null"] },
+                    "rows_new": { "type": ["integer", "null"] },
+                    "rows_aligned": { "type": ["integer", "null"] },
+                    "columns_old": { "type": ["integer", "null"] },
+                    "columns_new": { "type": ["integer", "null"] },
+                    "columns_common": { "type": ["integer", "null"] },
+                    "columns_old_only": { "type": ["integer", "null"] },
+                    "columns_new_only": { "type": ["integer", "null"] },
+                    "numeric_columns": { "type": ["integer", "null"] },
+                    "numeric_cells_checked": { "type": ["integer", "null"] },
+                    "numeric_cells_changed": { "type": ["integer", "null"] },
+                    "format_only_changes": { "type": "integer" },
+                    "missing_skipped": { "type": "integer" },
+                    "missing_zeroed": { "type": "integer" },
+                    "missing_both": { "type": "integer" },
+                    "missing_row": { "type": "integer" },
+                    "unparsable": { "type": "integer" },
+                    "footer_rows_old": { "type": "integer" },
+                    "footer_rows_new": { "type": "integer" },
+                    "comment_lines_old": { "type": "integer" },
+                    "comment_lines_new": { "type": "integer" },
+                    "rows_filtered_old": { "type": "integer" },
+                    "rows_filtered_new": { "type": "integer" }
+                },
+                "required": ["rows_old", "rows_new", "rows_aligned", "columns_old", "columns_new", "columns_common", "columns_old_only", "columns_new_only", "numeric_columns", "numeric_cells_checked", "numeric_cells_changed"]
+            },
+            "metrics": {
+                "type": "object",
+                "properties": {
+                    "total_change": { "type": ["number", "null"] },
+                    "max_abs_delta": { "type": ["number", "null"] },
+                    "top_k_coverage": { "type": ["number", "null"] },
+                    "coverage_curve": { "type": "array", "items": { "type": "number" } },
+                    "timings": {
                         "type": "object",
                         "properties": {
-                            "max_contributors": { "type": "integer" }
+                            "setup_ms": { "type": "number" },
+                            "read_ms": { "type": "number" },
+                            "parse_ms": { "type": "number" },
+                            "align_ms": { "type": "number" },
+                            "type_ms": { "type": "number" },
+                            "diff_ms": { "type": "number" },
+                            "render_ms": { "type": "number" },
+                            "total_ms": { "type": "number" }
                         },
-                        "required": ["max_contributors"]
+                        "required": ["setup_ms", "read_ms", "parse_ms", "align_ms", "type_ms", "diff_ms", "render_ms", "total_ms"]
+                    }
+                },
+                "required": ["total_change", "max_abs_delta", "top_k_coverage"]
+            },
+            "diffuse": {
+                "type": "object",
+                "properties": {
+                    "top_k_coverage": { "type": "number" },
+                    "threshold": { "type": "number" },
+                    "column": { "type": "string" }
+                },
+                "required": ["top_k_coverage", "threshold"]
+            },
+            "audit": {
+                "type": "object",
+                "properties": {
+                    "numeric_changes_emitted": { "type": "integer" },
+                    "field_changes_emitted": { "type": "integer" },
+                    "truncated": { "type": "boolean" }
+                },
+                "required": ["numeric_changes_emitted", "field_changes_emitted", "truncated"]
+            },
+            "limits": {
+                "type": "object",
+                "properties": {
+                    "max_contributors": { "type": "integer" }
+                },
+                "required": ["max_contributors"]
+            },
+            "memory": {
+                "type": "object",
+                "properties": {
+                    "estimated_peak_bytes": { "type": "integer" },
+                    "max_memory": { "type": "integer" }
+                },
+                "required": ["estimated_peak_bytes", "max_memory"]
+            },
+            "contributors": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "row_id": { "type": "string" },
+                        "column": { "type": "string" },
+                        "old": { "type": "number" },
+                        "new": { "type": "number" },
+                        "delta": { "type": "number" },
+                        "contribution": { "type": "number" },
+                        "share": { "type": "number" },
+                        "cumulative_share": { "type": "number" },
+                        "kind": { "type": "string", "enum": ["row_added", "row_removed"] },
+                        "baseline": { "type": "string", "enum": ["approved", "new"] }
+                    },
+                    "required": ["row_id", "column", "share", "cumulative_share"]
+                }
+            },
+            "by_column": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "contribution": { "type": "number" },
+                        "cells_changed": { "type": "integer" },
+                        "share": { "type": "number" }
+                    },
+                    "required": ["column", "contribution", "cells_changed", "share"]
+                }
+            },
+            "by_row": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "row_id": { "type": "string" },
+                        "contribution": { "type": "number" },
+                        "cells_changed": { "type": "integer" },
+                        "share": { "type": "number" }
                     },
-                    "contributors": {
+                    "required": ["row_id", "contribution", "cells_changed", "share"]
+                }
+            },
+            "field_changes": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "row_id": { "type": "string" },
+                        "column": { "type": "string" },
+                        "old": { "type": "string" },
+                        "new": { "type": "string" }
+                    },
+                    "required": ["row_id", "column"]
+                }
+            },
+            "subtolerance": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "row_id": { "type": "string" },
+                        "column": { "type": "string" },
+                        "old": { "type": "number" },
+                        "new": { "type": "number" },
+                        "delta": { "type": "number" }
+                    },
+                    "required": ["row_id", "column"]
+                }
+            },
+            "column_deltas": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "cells": { "type": "integer" },
+                        "cells_differing": { "type": "integer" },
+                        "min_abs_delta": { "type": ["number", "null"] },
+                        "max_abs_delta": { "type": ["number", "null"] },
+                        "mean_abs_delta": { "type": ["number", "null"] }
+                    },
+                    "required": ["column", "cells", "cells_differing", "min_abs_delta", "max_abs_delta", "mean_abs_delta"]
+                }
+            },
+            "identical_files": { "type": "boolean" },
+            "rescaled_columns": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "column": { "type": "string" },
+                        "factor": { "type": "number" },
+                        "cells": { "type": "integer" }
+                    },
+                    "required": ["column", "factor", "cells"]
+                }
+            },
+            "drift": {
+                "type": "object",
+                "properties": {
+                    "metric": { "type": "string", "enum": ["psi", "ks"] },
+                    "columns": {
                         "type": "array",
                         "items": {
                             "type": "object",
                             "properties": {
-                                "row_id": { "type": "string" },
                                 "column": { "type": "string" },
-                                "old": { "type": "number" },
-                                "new": { "type": "number" },
-                                "delta": { "type": "number" },
-                                "contribution": { "type": "number" },
-                                "share": { "type": "number" },
-                                "cumulative_share": { "type": "number" }
+                                "value": { "type": "number" },
+                                "pairs": { "type": "integer" }
                             },
-                            "required": ["row_id", "column", "old", "new", "delta", "contribution", "share", "cumulative_share"]
+                            "required": ["column", "value", "pairs"]
                         }
+                    }
+                },
+                "required": ["metric", "columns"]
+            },
+            "tolerance_sweep": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "tolerance": { "type": "number" },
+                        "cells_above": { "type": "integer" },
+                        "verdict": { "type": "string", "enum": ["REAL_CHANGE", "NO_REAL_CHANGE"] }
                     },
-                    "field_changes": {
+                    "required": ["tolerance", "cells_above", "verdict"]
+                }
+            },
+            "key_changes": {
+                "type": "object",
+                "properties": {
+                    "rows_added": { "type": "integer" },
+                    "rows_removed": { "type": "integer" },
+                    "totals": {
                         "type": "array",
                         "items": {
                             "type": "object",
                             "properties": {
+                                "column": { "type": "string" },
+                                "added": { "type": "number" },
+                                "removed": { "type": "number" }
+                            },
+                            "required": ["column", "added", "removed"]
+                        }
+                    }
+                },
+                "required": ["rows_added", "rows_removed"]
+            },
+            "date_changes": {
+                "type": "object",
+                "properties": {
+                    "columns": { "type": "array", "items": { "type": "string" } },
+                    "cells_changed": { "type": "integer" },
+                    "changes": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "kind": { "type": "string", "const": "date_change" },
                                 "row_id": { "type": "string" },
                                 "column": { "type": "string" },
+                                "direction": { "type": "string", "enum": ["later", "earlier"] },
                                 "old": { "type": "string" },
-                                "new": { "type": "string" }
+                                "new": { "type": "string" },
+                                "shift_days": { "type": "integer" }
                             },
-                            "required": ["row_id", "column"]
+                            "required": ["kind", "row_id", "column", "direction"]
+                        }
+                    }
+                },
+                "required": ["columns", "cells_changed", "changes"]
+            },
+            "text_changes": {
+                "type": "object",
+                "properties": {
+                    "cells_changed": { "type": "integer" },
+                    "columns": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "column": { "type": "string" },
+                                "changed": { "type": "integer" },
+                                "samples": {
+                                    "type": "array",
+                                    "items": {
+                                        "type": "object",
+                                        "properties": {
+                                            "row_id": { "type": "string" },
+                                            "old": { "type": "string" },
+                                            "new": { "type": "string" }
+                                        },
+                                        "required": ["row_id"]
+                                    }
+                                }
+                            },
+                            "required": ["column", "changed", "samples"]
                         }
-                    },
-                    "refusal": {
-                        "type": ["object", "null"],
-                        "properties": {
-                            "code": { "type": "string" },
-                            "message": { "type": "string" },
-                            "detail": {}
-                        },
-                        "required": ["code", "message", "detail"]
                     }
+                },
+                "required": ["cells_changed", "columns"]
+            },
+            "expected": {
+                "type": "object",
+                "properties": {
+                    "file": { "type": "string" },
+                    "approved": { "type": "integer" },
+                    "new": { "type": "integer" },
+                    "verdict": { "type": "string", "enum": ["NEW_CHANGE", "NO_NEW_CHANGE"] }
+                },
+                "required": ["file", "approved", "new", "verdict"]
+            },
+            "refusal": {
+                "type": ["object", "null"],
+                "properties": {
+                    "code": { "type": "string" },
+                    "message": { "type": "string" },
+                    "detail": {}
+                },
+                "required": ["code", "message", "detail"]
+            },
+            "refusals": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "code": { "type": "string" },
+                        "message": { "type": "string" },
+                        "detail": {}
+                    },
+                    "required": ["code", "message", "detail"]
                 }
-            });
-            println!("{}", serde_json::to_string_pretty(&schema)?);
-            Ok(0)
+            }
         }
-    }
+    })
 }
 
 /// Run the CSV comparison pipeline (the default mode).
-fn run_comparison(args: cli::args::Args) -> Result<u8, Box<dyn std::error::Error>> {
+fn run_comparison(args: cli::args::Args) -> Result<u8, PipelineError> {
     use std::io::{self, Write};
 
     let result = orchestrator::run(&args)?;
-    let mode = if args.json {
+    let mode = if args.json || args.format.is_some() {
         cli::exit::OutputMode::Json
     } else {
         cli::exit::OutputMode::Human
     };
-    let stream = cli::exit::output_stream(result.outcome, mode);
+    let stream = args.output_stream.resolve(result.outcome, mode);
 
     match stream {
         cli::exit::OutputStream::Stdout => {
@@ -256,7 +777,7 @@ fn run_comparison(args: cli::args::Args) -> Result<u8, Box<dyn std::error::Error
 
 /// Run witness subcommand (query/last/count).
 /// Exit codes: 0 = success, 1 = no record for `last`, 2 = error.
-fn run_witness(action: &cli::args::WitnessAction) -> Result<u8, Box<dyn std::error::Error>> {
+fn run_witness(action: &cli::args::WitnessAction) -> Result<u8, PipelineError> {
     use std::io::{self, Write};
 
     let reader = witness::reader::LedgerReader::open()?;
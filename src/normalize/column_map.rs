@@ -0,0 +1,253 @@
+//! Old→new column renames for `--map old=new`.
+//!
+//! A mapping renames one old-file header to its new-file name before the
+//! header intersection, so a column a vendor renamed between exports is still
+//! compared instead of dropping out as old-only/new-only. Renames apply after
+//! header normalization (and registry aliases); a rename that lands on another
+//! old-file header is reported as a duplicate header.
+//!
+//! [`suggest_column_maps`] pairs leftover old-only/new-only columns whose
+//! names look alike, so a run can print the `--map` it probably needs.
+
+use std::collections::HashSet;
+
+use crate::normalize::headers::{DuplicateHeader, find_duplicate_header};
+use crate::normalize::trim::ascii_trim;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    pub old: Vec<u8>,
+    pub new: Vec<u8>,
+}
+
+impl ColumnMapping {
+    /// Parse one `old=new` flag value. The split is at the first `=`, and
+    /// both names are ASCII-trimmed like headers.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let (old, new) = raw
+            .split_once('=')
+            .ok_or_else(|| "must be old_name=new_name".to_string())?;
+        let old = ascii_trim(old.as_bytes());
+        let new = ascii_trim(new.as_bytes());
+        if old.is_empty() || new.is_empty() {
+            return Err("column names must be non-empty".to_string());
+        }
+        Ok(Self {
+            old: old.to_vec(),
+            new: new.to_vec(),
+        })
+    }
+}
+
+/// Rename matching headers in place. Returns the mappings that matched a
+/// header, in flag order; when two mappings name the same old column, the
+/// first wins. Renames match original names only, so `a=b` with `b=c`
+/// renames both columns rather than taking `a` to `c`.
+pub fn apply_column_map(
+    headers: &mut [Vec<u8>],
+    map: &[ColumnMapping],
+) -> Result<Vec<ColumnMapping>, DuplicateHeader> {
+    let mut renames = Vec::new();
+    for (idx, mapping) in map.iter().enumerate() {
+        if map[..idx].iter().any(|earlier| earlier.old == mapping.old) {
+            continue;
+        }
+        if let Some(position) = headers.iter().position(|header| *header == mapping.old) {
+            renames.push((position, mapping));
+        }
+    }
+    for (position, mapping) in &renames {
+        headers[*position].clone_from(&mapping.new);
+    }
+    let applied: Vec<ColumnMapping> = renames
+        .into_iter()
+        .map(|(_, mapping)| mapping.clone())
+        .collect();
+
+    if !applied.is_empty()
+        && let Some(duplicate) = find_duplicate_header(headers)
+    {
+        return Err(duplicate);
+    }
+    Ok(applied)
+}
+
+/// Most suggestions reported per run.
+pub const MAX_SUGGESTED_MAPS: usize = 10;
+
+/// Pair old-only with new-only columns whose names look alike: equal once
+/// case, spaces, and `_`/`-`/`.` are ignored; one name is the other's word
+/// initials (`net_operating_income` / `noi`); or a small edit distance
+/// (at most one edit per four characters). Closest pairs win, each column is
+/// used once, and ties break by name so the list is deterministic.
+pub fn suggest_column_maps(old_only: &[Vec<u8>], new_only: &[Vec<u8>]) -> Vec<ColumnMapping> {
+    let mut candidates = Vec::new();
+    for old in old_only {
+        let old_folded = fold_name(old);
+        for new in new_only {
+            let new_folded = fold_name(new);
+            if let Some(distance) = name_distance(old, &old_folded, new, &new_folded) {
+                candidates.push((distance, old, new));
+            }
+        }
+    }
+    candidates.sort_by(|left, right| {
+        left.0
+            .cmp(&right.0)
+            .then_with(|| left.1.cmp(right.1))
+            .then_with(|| left.2.cmp(right.2))
+    });
+
+    let mut used_old = HashSet::new();
+    let mut used_new = HashSet::new();
+    let mut suggestions = Vec::new();
+    for (_, old, new) in candidates {
+        if suggestions.len() == MAX_SUGGESTED_MAPS {
+            break;
+        }
+        if used_old.contains(old) || used_new.contains(new) {
+            continue;
+        }
+        used_old.insert(old);
+        used_new.insert(new);
+        suggestions.push(ColumnMapping {
+            old: old.clone(),
+            new: new.clone(),
+        });
+    }
+    suggestions
+}
+
+/// Lowercase and drop separators (space, tab, `_`, `-`, `.`).
+fn fold_name(name: &[u8]) -> Vec<u8> {
+    name.iter()
+        .filter(|byte| !is_separator(**byte))
+        .map(u8::to_ascii_lowercase)
+        .collect()
+}
+
+fn is_separator(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'_' | b'-' | b'.')
+}
+
+/// First letter of each separator-delimited word, lowercased.
+fn initials(name: &[u8]) -> Vec<u8> {
+    name.split(|byte| is_separator(*byte))
+        .filter_map(|word| word.first())
+        .map(u8::to_ascii_lowercase)
+        .collect()
+}
+
+fn name_distance(old: &[u8], old_folded: &[u8], new: &[u8], new_folded: &[u8]) -> Option<usize> {
+    if old_folded.is_empty() || new_folded.is_empty() {
+        return None;
+    }
+    if old_folded == new_folded {
+        return Some(0);
+    }
+    let is_acronym = |name: &[u8], folded: &[u8]| {
+        let letters = initials(name);
+        letters.len() > 1 && letters == folded
+    };
+    if is_acronym(old, new_folded) || is_acronym(new, old_folded) {
+        return Some(1);
+    }
+    let distance = edit_distance(old_folded, new_folded);
+    let budget = old_folded.len().min(new_folded.len()) / 4;
+    (distance <= budget.max(1) && distance < old_folded.len().min(new_folded.len()))
+        .then_some(distance)
+}
+
+/// Levenshtein distance over bytes.
+fn edit_distance(left: &[u8], right: &[u8]) -> usize {
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+    for (i, &l) in left.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &r) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(l != r);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(raw: &str) -> ColumnMapping {
+        ColumnMapping::parse(raw).unwrap()
+    }
+
+    #[test]
+    fn parses_old_equals_new() {
+        assert_eq!(
+            mapping(" net_operating_income = noi"),
+            ColumnMapping {
+                old: b"net_operating_income".to_vec(),
+                new: b"noi".to_vec(),
+            }
+        );
+        assert_eq!(mapping("a=b=c").new, b"b=c".to_vec());
+        assert!(ColumnMapping::parse("noi").is_err());
+        assert!(ColumnMapping::parse("=noi").is_err());
+    }
+
+    #[test]
+    fn renames_matching_headers_and_reports_applied() {
+        let mut headers = vec![b"id".to_vec(), b"net_operating_income".to_vec()];
+        let applied = apply_column_map(
+            &mut headers,
+            &[mapping("net_operating_income=noi"), mapping("absent=x")],
+        )
+        .unwrap();
+        assert_eq!(headers, vec![b"id".to_vec(), b"noi".to_vec()]);
+        assert_eq!(applied, vec![mapping("net_operating_income=noi")]);
+    }
+
+    #[test]
+    fn rename_onto_existing_header_is_duplicate() {
+        let mut headers = vec![b"noi".to_vec(), b"net_operating_income".to_vec()];
+        let err =
+            apply_column_map(&mut headers, &[mapping("net_operating_income=noi")]).unwrap_err();
+        assert_eq!(err.name, b"noi".to_vec());
+        assert_eq!((err.first_index, err.second_index), (1, 2));
+    }
+
+    fn names(raw: &[&str]) -> Vec<Vec<u8>> {
+        raw.iter().map(|name| name.as_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn suggests_loose_acronym_and_typo_matches() {
+        let old_only = names(&[
+            "Unit Count",
+            "net_operating_income",
+            "occupancy_pct",
+            "notes",
+        ]);
+        let new_only = names(&["unit_count", "noi", "occupancy_pc", "tenant"]);
+        let suggestions = suggest_column_maps(&old_only, &new_only);
+        assert_eq!(
+            suggestions,
+            vec![
+                mapping("Unit Count=unit_count"),
+                mapping("net_operating_income=noi"),
+                mapping("occupancy_pct=occupancy_pc"),
+            ]
+        );
+    }
+
+    #[test]
+    fn each_column_is_suggested_once() {
+        let old_only = names(&["amount", "amount_"]);
+        let new_only = names(&["Amount"]);
+        assert_eq!(
+            suggest_column_maps(&old_only, &new_only),
+            vec![mapping("amount=Amount")]
+        );
+        assert!(suggest_column_maps(&names(&["rent"]), &names(&["fee"])).is_empty());
+    }
+}
@@ -4,6 +4,8 @@
 //! - ASCII-trim header bytes (spaces + tabs only).
 //! - Empty headers become `__rvl_col_<1-based index>`.
 //! - Normalized headers must be unique (byte-for-byte); duplicates are errors.
+//! - `--header-match loose` additionally folds names (see [`fold_header_loose`])
+//!   so Excel round-trips like `Unit Count` → `unit count ` still match.
 
 use std::collections::HashMap;
 
@@ -17,6 +19,92 @@ pub struct DuplicateHeader {
     pub second_index: usize,
 }
 
+/// How header names are compared across files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeaderMatch {
+    /// Byte-for-byte after trimming (the default).
+    #[default]
+    Exact,
+    /// Case-, whitespace-, and BOM-insensitive.
+    Loose,
+}
+
+impl HeaderMatch {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "exact" => Some(HeaderMatch::Exact),
+            "loose" => Some(HeaderMatch::Loose),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HeaderMatch::Exact => "exact",
+            HeaderMatch::Loose => "loose",
+        }
+    }
+
+    /// Fold every header under this mode, then re-check uniqueness.
+    pub fn apply(self, headers: &mut [Vec<u8>]) -> Result<(), DuplicateHeader> {
+        if self == HeaderMatch::Exact {
+            return Ok(());
+        }
+        for name in headers.iter_mut() {
+            *name = fold_header_loose(name);
+        }
+        match find_duplicate_header(headers) {
+            Some(duplicate) => Err(duplicate),
+            None => Ok(()),
+        }
+    }
+}
+
+/// UTF-8 BOM, and the same bytes mis-decoded as Latin-1 and re-encoded (`ï»¿`).
+const BOM_REMNANTS: [&[u8]; 2] = [b"\xEF\xBB\xBF", "\u{ef}\u{bb}\u{bf}".as_bytes()];
+
+/// Loose header form: strip leading BOM remnants, trim, collapse internal
+/// whitespace runs to one space, and lowercase (Unicode-aware for UTF-8).
+pub fn fold_header_loose(name: &[u8]) -> Vec<u8> {
+    let mut rest = name;
+    while let Some(stripped) = BOM_REMNANTS
+        .iter()
+        .find_map(|bom| ascii_trim(rest).strip_prefix(*bom))
+    {
+        rest = stripped;
+    }
+    let mut collapsed = Vec::with_capacity(rest.len());
+    for word in rest
+        .split(|byte| byte.is_ascii_whitespace())
+        .filter(|word| !word.is_empty())
+    {
+        if !collapsed.is_empty() {
+            collapsed.push(b' ');
+        }
+        collapsed.extend_from_slice(word);
+    }
+    match std::str::from_utf8(&collapsed) {
+        Ok(text) => text.to_lowercase().into_bytes(),
+        Err(_) => collapsed.to_ascii_lowercase(),
+    }
+}
+
+/// First repeated name, with 1-based positions of both occurrences.
+pub fn find_duplicate_header(headers: &[Vec<u8>]) -> Option<DuplicateHeader> {
+    let mut seen: HashMap<&[u8], usize> = HashMap::new();
+    for (idx, name) in headers.iter().enumerate() {
+        if let Some(first) = seen.get(name.as_slice()) {
+            return Some(DuplicateHeader {
+                name: name.clone(),
+                first_index: *first,
+                second_index: idx + 1,
+            });
+        }
+        seen.insert(name, idx + 1);
+    }
+    None
+}
+
 /// Normalize headers according to v0 rules.
 pub fn normalize_headers<'a, I>(headers: I) -> Result<Vec<Vec<u8>>, DuplicateHeader>
 where
@@ -116,4 +204,29 @@ mod tests {
         assert_eq!(err.first_index, 1);
         assert_eq!(err.second_index, 2);
     }
+
+    #[test]
+    fn loose_fold_ignores_case_whitespace_and_bom() {
+        assert_eq!(fold_header_loose(b"Unit  Count "), b"unit count".to_vec());
+        assert_eq!(
+            fold_header_loose(b"\xEF\xBB\xBFLoan ID"),
+            b"loan id".to_vec()
+        );
+        assert_eq!(
+            fold_header_loose("\u{ef}\u{bb}\u{bf}Balance".as_bytes()),
+            b"balance".to_vec()
+        );
+        assert_eq!(fold_header_loose("ÉCART".as_bytes()), "écart".as_bytes());
+    }
+
+    #[test]
+    fn loose_match_rechecks_uniqueness() {
+        let mut headers = vec![b"Amount".to_vec(), b"amount".to_vec()];
+        assert_eq!(HeaderMatch::Exact.apply(&mut headers), Ok(()));
+        let err = HeaderMatch::Loose
+            .apply(&mut headers)
+            .expect_err("duplicate");
+        assert_eq!(err.name, b"amount".to_vec());
+        assert_eq!((err.first_index, err.second_index), (1, 2));
+    }
 }
@@ -1,2 +1,3 @@
+pub mod column_map;
 pub mod headers;
 pub mod trim;
@@ -5,9 +5,11 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::csv::records::NormalizedRecord;
-use crate::numeric::missing::is_missing_token;
-use crate::numeric::parse::parse_numeric;
+use crate::csv::records::{NormalizedRecord, OwnedRecord};
+use crate::normalize::trim::ascii_trim;
+use crate::numeric::dates::parse_date;
+use crate::numeric::missing::MissingPolicy;
+use crate::numeric::parse::{NumberFormat, has_percent_suffix};
 
 /// Column present in both files (after header normalization).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -25,6 +27,25 @@ pub struct ColumnIntersection {
     pub new_only: Vec<Vec<u8>>,
 }
 
+impl ColumnIntersection {
+    /// Shared columns as a fraction of the narrower file's columns, or `None`
+    /// when either side has no comparable columns. A file that only adds
+    /// columns keeps an overlap of 1.0.
+    pub fn overlap(&self) -> Option<f64> {
+        let common = self.common.len();
+        let narrower = common + self.old_only.len().min(self.new_only.len());
+        (narrower > 0).then(|| common as f64 / narrower as f64)
+    }
+}
+
+/// How a numeric column's cells were written beyond plain digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnNormalization {
+    pub column: Vec<u8>,
+    /// Currency symbols stripped from the column's cells, sorted.
+    pub currency_symbols: Vec<String>,
+}
+
 /// File side for error reporting.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Side {
@@ -41,6 +62,49 @@ pub struct MixedTypesError<RowId> {
     pub value: Vec<u8>,
 }
 
+/// Maximum non-numeric sample values carried in an `E_MIXED_TYPES` refusal.
+pub const MAX_MIXED_TYPE_SAMPLES: usize = 3;
+
+/// Non-missing cells of one column by type, across both files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeHistogram {
+    pub numeric: u64,
+    pub currency: u64,
+    pub percent: u64,
+    pub text: u64,
+    pub dates: u64,
+}
+
+/// A non-numeric cell in a mixed column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedTypeSample {
+    pub side: Side,
+    /// 1-based data record number within `side`'s file.
+    pub record: u64,
+    pub value: Vec<u8>,
+}
+
+/// How mixed a refused column is: whether it holds one stray cell or is
+/// mixed throughout.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MixedColumnProfile {
+    pub histogram: TypeHistogram,
+    /// The first [`MAX_MIXED_TYPE_SAMPLES`] non-numeric cells in row order.
+    pub samples: Vec<MixedTypeSample>,
+}
+
+/// Share of common columns that must be mixed (strictly more than this) before
+/// an `E_MIXED_TYPES` refusal calls the table a likely cross-tab.
+pub const CROSS_TAB_MIXED_SHARE: f64 = 0.6;
+
+/// Why a mixed-types refusal looks like a pivoted grid: most common columns
+/// are mixed and the row labels read like field names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrossTabSignal {
+    pub mixed_columns: u64,
+    pub columns: u64,
+}
+
 /// Error returned when one side is missing and the other is numeric.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MissingnessError<RowId> {
@@ -57,6 +121,52 @@ pub enum ColumnTypingError<RowId> {
     Missingness(MissingnessError<RowId>),
 }
 
+impl<RowId> ColumnTypingError<RowId> {
+    /// The column the error names.
+    pub fn column(&self) -> &[u8] {
+        match self {
+            ColumnTypingError::MixedTypes(detail) => &detail.column,
+            ColumnTypingError::Missingness(detail) => &detail.column,
+        }
+    }
+}
+
+/// What to do with a column holding both numeric and non-numeric values
+/// (`--on-mixed-types`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MixedTypesPolicy {
+    /// Refuse the run with `E_MIXED_TYPES`.
+    #[default]
+    Refuse,
+    /// Drop the column from the numeric set and report it.
+    Exclude,
+}
+
+impl MixedTypesPolicy {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "refuse" => Some(MixedTypesPolicy::Refuse),
+            "exclude" => Some(MixedTypesPolicy::Exclude),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MixedTypesPolicy::Refuse => "refuse",
+            MixedTypesPolicy::Exclude => "exclude",
+        }
+    }
+}
+
+/// Column typing result: the numeric columns, plus the columns dropped for
+/// mixed types under [`MixedTypesPolicy::Exclude`], both in column order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnTyping {
+    pub numeric: Vec<CommonColumn>,
+    pub excluded_mixed: Vec<Vec<u8>>,
+}
+
 /// Minimal access to CSV fields for column typing.
 pub trait FieldAccess {
     fn field(&self, index: usize) -> &[u8];
@@ -68,6 +178,18 @@ impl<'a> FieldAccess for NormalizedRecord<'a> {
     }
 }
 
+impl FieldAccess for OwnedRecord {
+    fn field(&self, index: usize) -> &[u8] {
+        OwnedRecord::field(self, index)
+    }
+}
+
+impl FieldAccess for &OwnedRecord {
+    fn field(&self, index: usize) -> &[u8] {
+        OwnedRecord::field(self, index)
+    }
+}
+
 impl FieldAccess for Vec<Vec<u8>> {
     fn field(&self, index: usize) -> &[u8] {
         self.get(index).map(|v| v.as_slice()).unwrap_or(b"")
@@ -132,11 +254,15 @@ pub fn intersect_headers(
     }
 }
 
-/// Determine numeric columns and refuse mixed/missingness cases.
+/// Determine numeric columns and refuse mixed types, and missingness
+/// mismatches under [`MissingPolicy::Refuse`].
 pub fn detect_numeric_columns<RowId, Old, New, I>(
     columns: &[CommonColumn],
     rows: I,
-) -> Result<Vec<CommonColumn>, ColumnTypingError<RowId>>
+    format: NumberFormat<'_>,
+    missing: MissingPolicy,
+    mixed: MixedTypesPolicy,
+) -> Result<ColumnTyping, ColumnTypingError<RowId>>
 where
     RowId: Clone,
     Old: FieldAccess,
@@ -151,106 +277,267 @@ where
 
     for (row_id, old, new) in rows {
         for state in &mut states {
+            if state.excluded {
+                continue;
+            }
             let old_raw = old.field(state.column.old_index);
             let new_raw = new.field(state.column.new_index);
+            match state.observe(&row_id, old_raw, new_raw, format, missing) {
+                Err(ColumnTypingError::MixedTypes(_)) if mixed == MixedTypesPolicy::Exclude => {
+                    state.excluded = true;
+                }
+                result => result?,
+            }
+        }
+    }
+
+    let mut typing = ColumnTyping::default();
+    for state in states {
+        if state.excluded {
+            typing.excluded_mixed.push(state.column.name);
+        } else if state.saw_numeric {
+            typing.numeric.push(state.column);
+        }
+    }
+    Ok(typing)
+}
+
+/// Cross-tab typing (`--cross-tab`): each (row, column) cell is typed on its own.
+///
+/// Pivoted grids (rent rolls, comp grids) mix numeric and text rows within one
+/// value column, so whole-column typing would refuse with `E_MIXED_TYPES`.
+/// Here a cell that is text on both sides is skipped, and a column is numeric
+/// when at least one cell is numeric on both sides. A single cell that changes
+/// type between files still refuses, as does one missing opposite a number
+/// under [`MissingPolicy::Refuse`].
+pub fn detect_cross_tab_columns<RowId, Old, New, I>(
+    columns: &[CommonColumn],
+    rows: I,
+    format: NumberFormat<'_>,
+    missing: MissingPolicy,
+    mixed: MixedTypesPolicy,
+) -> Result<ColumnTyping, ColumnTypingError<RowId>>
+where
+    RowId: Clone,
+    Old: FieldAccess,
+    New: FieldAccess,
+    I: IntoIterator<Item = (RowId, Old, New)>,
+{
+    let mut saw_numeric = vec![false; columns.len()];
+    let mut excluded = vec![false; columns.len()];
 
-            let old_missing = is_missing_token(old_raw);
-            let new_missing = is_missing_token(new_raw);
+    for (row_id, old, new) in rows {
+        for ((column, saw_numeric), excluded) in columns
+            .iter()
+            .zip(saw_numeric.iter_mut())
+            .zip(excluded.iter_mut())
+        {
+            if *excluded {
+                continue;
+            }
+            let old_raw = old.field(column.old_index);
+            let new_raw = new.field(column.new_index);
 
+            let old_missing = format.is_missing(old_raw);
+            let new_missing = format.is_missing(new_raw);
             if old_missing && new_missing {
                 continue;
             }
 
             if old_missing || new_missing {
-                let (present_raw, present_side, missing_side) = if old_missing {
-                    (new_raw, Side::New, Side::Old)
+                let (present_raw, missing_side) = if old_missing {
+                    (new_raw, Side::Old)
                 } else {
-                    (old_raw, Side::Old, Side::New)
+                    (old_raw, Side::New)
                 };
-
-                if parse_numeric(present_raw).is_some() {
-                    return Err(ColumnTypingError::Missingness(MissingnessError {
-                        row_id: row_id.clone(),
-                        column: state.column.name.clone(),
-                        missing_side,
-                        present_value: present_raw.to_vec(),
-                    }));
+                if format.parse(present_raw).is_some() {
+                    if missing == MissingPolicy::Refuse {
+                        return Err(ColumnTypingError::Missingness(MissingnessError {
+                            row_id,
+                            column: column.name.clone(),
+                            missing_side,
+                            present_value: present_raw.to_vec(),
+                        }));
+                    }
+                    *saw_numeric = true;
                 }
+                continue;
+            }
 
-                if state.saw_numeric {
-                    return Err(ColumnTypingError::MixedTypes(MixedTypesError {
-                        row_id: row_id.clone(),
-                        column: state.column.name.clone(),
-                        side: present_side,
-                        value: present_raw.to_vec(),
-                    }));
+            let (side, value) = match (
+                format.parse(old_raw).is_some(),
+                format.parse(new_raw).is_some(),
+            ) {
+                (true, true) => {
+                    *saw_numeric = true;
+                    continue;
                 }
-
-                state.record_non_numeric(row_id.clone(), present_side, present_raw);
+                (true, false) => (Side::New, new_raw),
+                (false, true) => (Side::Old, old_raw),
+                (false, false) => continue,
+            };
+            if mixed == MixedTypesPolicy::Exclude {
+                *excluded = true;
                 continue;
             }
+            return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                row_id,
+                column: column.name.clone(),
+                side,
+                value: value.to_vec(),
+            }));
+        }
+    }
 
-            let old_num = parse_numeric(old_raw);
-            let new_num = parse_numeric(new_raw);
+    let mut typing = ColumnTyping::default();
+    for ((column, saw_numeric), excluded) in columns.iter().zip(saw_numeric).zip(excluded) {
+        if excluded {
+            typing.excluded_mixed.push(column.name.clone());
+        } else if saw_numeric {
+            typing.numeric.push(column.clone());
+        }
+    }
+    Ok(typing)
+}
 
-            match (old_num.is_some(), new_num.is_some()) {
-                (true, true) => {
-                    if let Some(non_numeric) = state.first_non_numeric.take() {
-                        return Err(ColumnTypingError::MixedTypes(MixedTypesError {
-                            row_id: non_numeric.row_id,
-                            column: state.column.name.clone(),
-                            side: non_numeric.side,
-                            value: non_numeric.value,
-                        }));
-                    }
-                    state.saw_numeric = true;
+/// Type every non-missing cell of `column` over the aligned rows, each
+/// tagged with its (old, new) data record numbers.
+pub fn profile_mixed_column<Old, New, I>(
+    column: &CommonColumn,
+    rows: I,
+    format: NumberFormat<'_>,
+) -> MixedColumnProfile
+where
+    Old: FieldAccess,
+    New: FieldAccess,
+    I: IntoIterator<Item = ((u64, u64), Old, New)>,
+{
+    let mut profile = MixedColumnProfile::default();
+    // Percent cells are counted whether or not `--parse-percent` is on, so
+    // the histogram can point at it.
+    let percent_format = NumberFormat {
+        percent: true,
+        ..format
+    };
+    for ((old_record, new_record), old, new) in rows {
+        let cells = [
+            (Side::Old, old_record, old.field(column.old_index)),
+            (Side::New, new_record, new.field(column.new_index)),
+        ];
+        for (side, record, raw) in cells {
+            if format.is_missing(raw) {
+                continue;
+            }
+            let histogram = &mut profile.histogram;
+            if has_percent_suffix(raw) && percent_format.parse(raw).is_some() {
+                histogram.percent += 1;
+                continue;
+            }
+            if format.parse(raw).is_some() {
+                if format.currency_symbol(raw).is_some() {
+                    histogram.currency += 1;
+                } else {
+                    histogram.numeric += 1;
                 }
-                (true, false) | (false, true) => {
-                    let (non_numeric_raw, non_numeric_side) = if old_num.is_some() {
-                        (new_raw, Side::New)
-                    } else {
-                        (old_raw, Side::Old)
-                    };
-
-                    if state.saw_numeric {
-                        return Err(ColumnTypingError::MixedTypes(MixedTypesError {
-                            row_id: row_id.clone(),
-                            column: state.column.name.clone(),
-                            side: non_numeric_side,
-                            value: non_numeric_raw.to_vec(),
-                        }));
-                    }
+                continue;
+            }
+            if parse_date(raw).is_some() {
+                histogram.dates += 1;
+            } else {
+                histogram.text += 1;
+            }
+            if profile.samples.len() < MAX_MIXED_TYPE_SAMPLES {
+                profile.samples.push(MixedTypeSample {
+                    side,
+                    record,
+                    value: raw.to_vec(),
+                });
+            }
+        }
+    }
+    profile
+}
 
-                    state.record_non_numeric(row_id.clone(), non_numeric_side, non_numeric_raw);
+/// Detect a pivoted grid behind a mixed-types refusal: more than
+/// [`CROSS_TAB_MIXED_SHARE`] of `columns` hold both numeric and non-numeric
+/// cells, and `labels` (the key column, or the first column in row-order
+/// mode) look like field names.
+pub fn detect_cross_tab_shape<'l, Old, New, I, L>(
+    columns: &[CommonColumn],
+    rows: I,
+    labels: L,
+    format: NumberFormat<'_>,
+) -> Option<CrossTabSignal>
+where
+    Old: FieldAccess,
+    New: FieldAccess,
+    I: IntoIterator<Item = (Old, New)>,
+    L: IntoIterator<Item = &'l [u8]>,
+{
+    if columns.is_empty() {
+        return None;
+    }
+    // (saw numeric, saw non-numeric) per column.
+    let mut seen = vec![(false, false); columns.len()];
+    for (old, new) in rows {
+        for (column, (numeric, text)) in columns.iter().zip(seen.iter_mut()) {
+            for raw in [old.field(column.old_index), new.field(column.new_index)] {
+                if format.is_missing(raw) {
+                    continue;
                 }
-                (false, false) => {
-                    if state.saw_numeric {
-                        return Err(ColumnTypingError::MixedTypes(MixedTypesError {
-                            row_id: row_id.clone(),
-                            column: state.column.name.clone(),
-                            side: Side::Old,
-                            value: old_raw.to_vec(),
-                        }));
-                    }
-                    state.record_non_numeric(row_id.clone(), Side::Old, old_raw);
+                if format.parse(raw).is_some() {
+                    *numeric = true;
+                } else {
+                    *text = true;
                 }
             }
         }
     }
+    let mixed_columns = seen
+        .iter()
+        .filter(|(numeric, text)| *numeric && *text)
+        .count();
+    if mixed_columns as f64 <= CROSS_TAB_MIXED_SHARE * columns.len() as f64 {
+        return None;
+    }
+    looks_like_field_labels(labels, format).then_some(CrossTabSignal {
+        mixed_columns: mixed_columns as u64,
+        columns: columns.len() as u64,
+    })
+}
 
-    let numeric = states
-        .into_iter()
-        .filter(|state| state.saw_numeric)
-        .map(|state| state.column)
-        .collect();
-
-    Ok(numeric)
+/// Row labels read like field names (`Rent`, `Unit Type`) rather than
+/// identifiers (`A-1001`, `2024-05-01`): none is a number or date, and letters
+/// make up at least half of each label's non-space characters.
+fn looks_like_field_labels<'l>(
+    labels: impl IntoIterator<Item = &'l [u8]>,
+    format: NumberFormat<'_>,
+) -> bool {
+    let mut any = false;
+    for label in labels {
+        let label = ascii_trim(label);
+        if format.is_missing(label) {
+            continue;
+        }
+        if format.parse(label).is_some() || parse_date(label).is_some() {
+            return false;
+        }
+        let visible = label.iter().filter(|b| !b.is_ascii_whitespace()).count();
+        let letters = label.iter().filter(|b| b.is_ascii_alphabetic()).count();
+        if letters * 2 < visible {
+            return false;
+        }
+        any = true;
+    }
+    any
 }
 
 #[derive(Debug)]
 struct ColumnState<RowId> {
     column: CommonColumn,
     saw_numeric: bool,
+    /// Dropped for mixed types under [`MixedTypesPolicy::Exclude`].
+    excluded: bool,
     first_non_numeric: Option<NonNumeric<RowId>>,
 }
 
@@ -259,10 +546,119 @@ impl<RowId> ColumnState<RowId> {
         Self {
             column,
             saw_numeric: false,
+            excluded: false,
             first_non_numeric: None,
         }
     }
 
+    /// Type one aligned cell pair of this column.
+    fn observe(
+        &mut self,
+        row_id: &RowId,
+        old_raw: &[u8],
+        new_raw: &[u8],
+        format: NumberFormat<'_>,
+        missing: MissingPolicy,
+    ) -> Result<(), ColumnTypingError<RowId>>
+    where
+        RowId: Clone,
+    {
+        let old_missing = format.is_missing(old_raw);
+        let new_missing = format.is_missing(new_raw);
+
+        if old_missing && new_missing {
+            return Ok(());
+        }
+
+        if old_missing || new_missing {
+            let (present_raw, present_side, missing_side) = if old_missing {
+                (new_raw, Side::New, Side::Old)
+            } else {
+                (old_raw, Side::Old, Side::New)
+            };
+
+            if format.parse(present_raw).is_some() {
+                if missing == MissingPolicy::Refuse {
+                    return Err(ColumnTypingError::Missingness(MissingnessError {
+                        row_id: row_id.clone(),
+                        column: self.column.name.clone(),
+                        missing_side,
+                        present_value: present_raw.to_vec(),
+                    }));
+                }
+                if let Some(non_numeric) = self.first_non_numeric.take() {
+                    return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                        row_id: non_numeric.row_id,
+                        column: self.column.name.clone(),
+                        side: non_numeric.side,
+                        value: non_numeric.value,
+                    }));
+                }
+                self.saw_numeric = true;
+                return Ok(());
+            }
+
+            if self.saw_numeric {
+                return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                    row_id: row_id.clone(),
+                    column: self.column.name.clone(),
+                    side: present_side,
+                    value: present_raw.to_vec(),
+                }));
+            }
+
+            self.record_non_numeric(row_id.clone(), present_side, present_raw);
+            return Ok(());
+        }
+
+        let old_num = format.parse(old_raw);
+        let new_num = format.parse(new_raw);
+
+        match (old_num.is_some(), new_num.is_some()) {
+            (true, true) => {
+                if let Some(non_numeric) = self.first_non_numeric.take() {
+                    return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                        row_id: non_numeric.row_id,
+                        column: self.column.name.clone(),
+                        side: non_numeric.side,
+                        value: non_numeric.value,
+                    }));
+                }
+                self.saw_numeric = true;
+            }
+            (true, false) | (false, true) => {
+                let (non_numeric_raw, non_numeric_side) = if old_num.is_some() {
+                    (new_raw, Side::New)
+                } else {
+                    (old_raw, Side::Old)
+                };
+
+                if self.saw_numeric {
+                    return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                        row_id: row_id.clone(),
+                        column: self.column.name.clone(),
+                        side: non_numeric_side,
+                        value: non_numeric_raw.to_vec(),
+                    }));
+                }
+
+                self.record_non_numeric(row_id.clone(), non_numeric_side, non_numeric_raw);
+            }
+            (false, false) => {
+                if self.saw_numeric {
+                    return Err(ColumnTypingError::MixedTypes(MixedTypesError {
+                        row_id: row_id.clone(),
+                        column: self.column.name.clone(),
+                        side: Side::Old,
+                        value: old_raw.to_vec(),
+                    }));
+                }
+                self.record_non_numeric(row_id.clone(), Side::Old, old_raw);
+            }
+        }
+        Ok(())
+    }
+
     fn record_non_numeric(&mut self, row_id: RowId, side: Side, value: &[u8]) {
         if self.first_non_numeric.is_none() {
             self.first_non_numeric = Some(NonNumeric {
@@ -314,6 +710,26 @@ mod tests {
         assert_eq!(intersection.new_only, vec![b"c".to_vec()]);
     }
 
+    #[test]
+    fn overlap_is_relative_to_the_narrower_file() {
+        let headers = |names: &[&str]| -> Vec<Vec<u8>> {
+            names.iter().map(|name| name.as_bytes().to_vec()).collect()
+        };
+        let widened =
+            intersect_headers(&headers(&["a", "b"]), &headers(&["a", "b", "c", "d"]), None);
+        assert_eq!(widened.overlap(), Some(1.0));
+
+        let renamed = intersect_headers(
+            &headers(&["id", "a", "b", "c", "d"]),
+            &headers(&["id", "a", "x", "y", "z"]),
+            Some(b"id"),
+        );
+        assert_eq!(renamed.overlap(), Some(0.25));
+
+        let key_only = intersect_headers(&headers(&["id"]), &headers(&["id"]), Some(b"id"));
+        assert_eq!(key_only.overlap(), None);
+    }
+
     #[test]
     fn numeric_column_detected() {
         let columns = vec![column("a", 0, 0)];
@@ -321,7 +737,15 @@ mod tests {
             (1u64, record(&[b"1"]), record(&[b"2"])),
             (2u64, record(&[b""]), record(&[b""])),
         ];
-        let numeric = detect_numeric_columns(&columns, rows).expect("numeric");
+        let numeric = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .expect("numeric")
+        .numeric;
         assert_eq!(numeric.len(), 1);
         assert_eq!(numeric[0].name, b"a".to_vec());
     }
@@ -330,7 +754,15 @@ mod tests {
     fn non_numeric_column_is_ignored() {
         let columns = vec![column("a", 0, 0)];
         let rows = vec![(1u64, record(&[b"foo"]), record(&[b"bar"]))];
-        let numeric = detect_numeric_columns(&columns, rows).expect("ok");
+        let numeric = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .expect("ok")
+        .numeric;
         assert!(numeric.is_empty());
     }
 
@@ -341,7 +773,14 @@ mod tests {
             (1u64, record(&[b"1"]), record(&[b"2"])),
             (2u64, record(&[b"foo"]), record(&[b"bar"])),
         ];
-        let err = detect_numeric_columns(&columns, rows).unwrap_err();
+        let err = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
         match err {
             ColumnTypingError::MixedTypes(detail) => {
                 assert_eq!(detail.row_id, 2);
@@ -359,7 +798,14 @@ mod tests {
             (1u64, record(&[b"foo"]), record(&[b"bar"])),
             (2u64, record(&[b"1"]), record(&[b"2"])),
         ];
-        let err = detect_numeric_columns(&columns, rows).unwrap_err();
+        let err = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
         match err {
             ColumnTypingError::MixedTypes(detail) => {
                 assert_eq!(detail.row_id, 1);
@@ -374,7 +820,14 @@ mod tests {
     fn missingness_is_refused() {
         let columns = vec![column("a", 0, 0)];
         let rows = vec![(7u64, record(&[b""]), record(&[b"9"]))];
-        let err = detect_numeric_columns(&columns, rows).unwrap_err();
+        let err = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
         match err {
             ColumnTypingError::Missingness(detail) => {
                 assert_eq!(detail.row_id, 7);
@@ -385,6 +838,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn missing_vs_numeric_is_numeric_under_skip_or_zero() {
+        let columns = vec![column("a", 0, 0)];
+        for policy in [MissingPolicy::Skip, MissingPolicy::Zero] {
+            let rows = vec![(7u64, record(&[b""]), record(&[b"9"]))];
+            let numeric = detect_numeric_columns(
+                &columns,
+                rows,
+                NumberFormat::default(),
+                policy,
+                MixedTypesPolicy::Refuse,
+            )
+            .expect("numeric")
+            .numeric;
+            assert_eq!(numeric, columns);
+        }
+    }
+
     #[test]
     fn missing_vs_text_without_numeric_is_ignored() {
         let columns = vec![column("a", 0, 0)];
@@ -392,7 +863,15 @@ mod tests {
             (1u64, record(&[b""]), record(&[b"foo"])),
             (2u64, record(&[b""]), record(&[b"bar"])),
         ];
-        let numeric = detect_numeric_columns(&columns, rows).expect("ok");
+        let numeric = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .expect("ok")
+        .numeric;
         assert!(numeric.is_empty());
     }
 
@@ -403,7 +882,14 @@ mod tests {
             (1u64, record(&[b""]), record(&[b"foo"])),
             (2u64, record(&[b"1"]), record(&[b"2"])),
         ];
-        let err = detect_numeric_columns(&columns, rows).unwrap_err();
+        let err = detect_numeric_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
         match err {
             ColumnTypingError::MixedTypes(detail) => {
                 assert_eq!(detail.row_id, 1);
@@ -413,4 +899,144 @@ mod tests {
             _ => panic!("expected mixed types"),
         }
     }
+
+    #[test]
+    fn cross_tab_types_each_cell_independently() {
+        let columns = vec![column("a", 0, 0), column("label", 1, 1)];
+        let rows = vec![
+            (1u64, record(&[b"1", b"x"]), record(&[b"2", b"y"])),
+            (
+                2u64,
+                record(&[b"Class A", b"z"]),
+                record(&[b"Class B", b"z"]),
+            ),
+            (3u64, record(&[b"", b"q"]), record(&[b"n/a text", b"q"])),
+        ];
+        let numeric = detect_cross_tab_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .expect("cross-tab")
+        .numeric;
+        assert_eq!(numeric, vec![column("a", 0, 0)]);
+    }
+
+    #[test]
+    fn cross_tab_refuses_a_cell_that_changes_type() {
+        let columns = vec![column("a", 0, 0)];
+        let rows = vec![
+            (1u64, record(&[b"Class A"]), record(&[b"Class A"])),
+            (2u64, record(&[b"1"]), record(&[b"Class B"])),
+        ];
+        let err = detect_cross_tab_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
+        match err {
+            ColumnTypingError::MixedTypes(detail) => {
+                assert_eq!(detail.row_id, 2);
+                assert_eq!(detail.side, Side::New);
+                assert_eq!(detail.value, b"Class B".to_vec());
+            }
+            _ => panic!("expected mixed types"),
+        }
+    }
+
+    #[test]
+    fn cross_tab_keeps_missingness_refusal() {
+        let columns = vec![column("a", 0, 0)];
+        let rows = vec![(4u64, record(&[b"5"]), record(&[b"NA"]))];
+        let err = detect_cross_tab_columns(
+            &columns,
+            rows,
+            NumberFormat::default(),
+            MissingPolicy::Refuse,
+            MixedTypesPolicy::Refuse,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ColumnTypingError::Missingness(MissingnessError {
+                row_id: 4,
+                missing_side: Side::New,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn mixed_column_profile_counts_types_and_samples_text() {
+        let columns = [column("amount", 0, 0)];
+        let rows = vec![
+            ((1u64, 1u64), record(&[b"100"]), record(&[b"$120"])),
+            ((2, 2), record(&[b"12%"]), record(&[b"n/a"])),
+            ((3, 3), record(&[b"2024-05-01"]), record(&[b"TBD"])),
+            ((4, 4), record(&[b"pending"]), record(&[b"later"])),
+        ];
+        let profile = profile_mixed_column(&columns[0], rows, NumberFormat::default());
+        assert_eq!(
+            profile.histogram,
+            TypeHistogram {
+                numeric: 1,
+                currency: 1,
+                percent: 1,
+                text: 3,
+                dates: 1,
+            }
+        );
+        let samples: Vec<(Side, u64, &[u8])> = profile
+            .samples
+            .iter()
+            .map(|sample| (sample.side, sample.record, sample.value.as_slice()))
+            .collect();
+        assert_eq!(
+            samples,
+            vec![
+                (Side::Old, 3, b"2024-05-01".as_slice()),
+                (Side::New, 3, b"TBD".as_slice()),
+                (Side::Old, 4, b"pending".as_slice()),
+            ]
+        );
+    }
+
+    #[test]
+    fn cross_tab_shape_needs_mixed_columns_and_label_rows() {
+        let columns = [column("comp_1", 0, 0), column("comp_2", 1, 1)];
+        let grid = [
+            record(&[b"1200", b"1350"]),
+            record(&[b"Office", b"Retail"]),
+            record(&[b"6.5", b"7"]),
+        ];
+        let rows = || grid.iter().map(|row| (row.as_slice(), row.as_slice()));
+        let labels: [&[u8]; 3] = [b"Rent", b"Property Type", b"Cap Rate %"];
+        assert_eq!(
+            detect_cross_tab_shape(&columns, rows(), labels, NumberFormat::default()),
+            Some(CrossTabSignal {
+                mixed_columns: 2,
+                columns: 2,
+            })
+        );
+
+        let ids: [&[u8]; 3] = [b"A-1001", b"A-1002", b"A-1003"];
+        assert_eq!(
+            detect_cross_tab_shape(&columns, rows(), ids, NumberFormat::default()),
+            None
+        );
+
+        let one_bad_cell = [record(&[b"1200", b"1350"]), record(&[b"TBD", b"7"])];
+        let rows = one_bad_cell
+            .iter()
+            .map(|row| (row.as_slice(), row.as_slice()));
+        assert_eq!(
+            detect_cross_tab_shape(&columns, rows, labels, NumberFormat::default()),
+            None
+        );
+    }
 }
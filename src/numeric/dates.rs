@@ -0,0 +1,170 @@
+//! Date columns: cells that are calendar dates rather than numbers.
+//!
+//! A common column whose aligned cells all parse as dates (ignoring missing
+//! tokens) is a date column. Its cells are compared as days since
+//! 1970-01-01, so a shifted lease start is reported as a number of days
+//! instead of dropping out as non-numeric.
+//!
+//! Accepted formats (ASCII-trimmed):
+//! - ISO `YYYY-MM-DD`
+//! - US `M/D/YYYY` and `MM/DD/YYYY`
+
+use crate::normalize::trim::ascii_trim;
+
+/// Changed date cells in the aligned rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateChangeReport {
+    /// Columns detected as dates, in common-column order.
+    pub columns: Vec<Vec<u8>>,
+    /// Cells whose date differs on both sides.
+    pub cells_changed: u64,
+    /// Largest shifts first, capped at `--top`.
+    pub changes: Vec<DateChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateChange {
+    pub row_id: Vec<u8>,
+    pub column: Vec<u8>,
+    /// True when the new date is after the old one.
+    pub later: bool,
+    /// Old/new dates; `None` unless values may be shown (`--explicit`).
+    pub values: Option<DateValues>,
+}
+
+/// Old and new dates of one changed cell, as days since 1970-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateValues {
+    pub old: i64,
+    pub new: i64,
+}
+
+impl DateValues {
+    pub fn shift_days(self) -> i64 {
+        self.new - self.old
+    }
+}
+
+/// Parse one cell as a date, returning days since 1970-01-01.
+pub fn parse_date(input: &[u8]) -> Option<i64> {
+    let trimmed = ascii_trim(input);
+    let (year, month, day) = parse_iso(trimmed).or_else(|| parse_us(trimmed))?;
+    if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Render days since 1970-01-01 as `YYYY-MM-DD`.
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+fn parse_iso(input: &[u8]) -> Option<(i64, u32, u32)> {
+    if input.len() != 10 || input[4] != b'-' || input[7] != b'-' {
+        return None;
+    }
+    let year = digits(&input[..4])?;
+    let month = digits(&input[5..7])?;
+    let day = digits(&input[8..])?;
+    Some((i64::from(year), month, day))
+}
+
+fn parse_us(input: &[u8]) -> Option<(i64, u32, u32)> {
+    let mut parts = input.split(|byte| *byte == b'/');
+    let month = parts.next().filter(|part| (1..=2).contains(&part.len()))?;
+    let day = parts.next().filter(|part| (1..=2).contains(&part.len()))?;
+    let year = parts.next().filter(|part| part.len() == 4)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((i64::from(digits(year)?), digits(month)?, digits(day)?))
+}
+
+fn digits(input: &[u8]) -> Option<u32> {
+    if input.is_empty() || !input.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(
+        input
+            .iter()
+            .fold(0, |acc, byte| acc * 10 + u32::from(byte - b'0')),
+    )
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Proleptic Gregorian conversions (Howard Hinnant's civil algorithms).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_iso_and_us_dates() {
+        assert_eq!(parse_date(b"1970-01-01"), Some(0));
+        assert_eq!(parse_date(b" 2024-01-31 "), Some(19_753));
+        assert_eq!(parse_date(b"1/31/2024"), Some(19_753));
+        assert_eq!(parse_date(b"01/31/2024"), Some(19_753));
+        assert_eq!(parse_date(b"1969-12-31"), Some(-1));
+    }
+
+    #[test]
+    fn rejects_invalid_dates_and_other_tokens() {
+        assert_eq!(parse_date(b"2023-02-29"), None);
+        assert_eq!(parse_date(b"2024-13-01"), None);
+        assert_eq!(parse_date(b"31/01/2024"), None);
+        assert_eq!(parse_date(b"1/31/24"), None);
+        assert_eq!(parse_date(b"2024-1-31"), None);
+        assert_eq!(parse_date(b"20240131"), None);
+        assert_eq!(parse_date(b"n/a"), None);
+    }
+
+    #[test]
+    fn formats_round_trip() {
+        for raw in ["2024-02-29", "1970-01-01", "1900-03-01", "2000-12-31"] {
+            assert_eq!(format_date(parse_date(raw.as_bytes()).unwrap()), raw);
+        }
+        assert_eq!(format_date(-1), "1969-12-31");
+    }
+}
@@ -0,0 +1,233 @@
+//! Per-column distribution drift between old and new numeric values.
+//!
+//! Cell-level ranking explains concentrated change; a systematic shift that
+//! touches every row (everything +2%) is diffuse by construction and is
+//! better summarized by a distribution statistic per column.
+
+/// Fraction floor for empty PSI bins so the log term stays finite.
+const PSI_EPSILON: f64 = 1e-4;
+/// Number of quantile bins used for PSI.
+const PSI_BINS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftMetric {
+    /// Population Stability Index over pooled-quantile bins.
+    Psi,
+    /// Two-sample Kolmogorov-Smirnov statistic (max CDF distance).
+    Ks,
+}
+
+impl DriftMetric {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DriftMetric::Psi => "psi",
+            DriftMetric::Ks => "ks",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DriftMetric::Psi => "PSI",
+            DriftMetric::Ks => "KS",
+        }
+    }
+}
+
+/// Drift statistic for one numeric column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnDrift {
+    pub column: Vec<u8>,
+    pub value: f64,
+    /// Aligned value pairs the statistic was computed over.
+    pub pairs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriftReport {
+    pub metric: DriftMetric,
+    /// Columns ordered by descending drift, then by column order.
+    pub columns: Vec<ColumnDrift>,
+}
+
+/// Collects aligned numeric values per column during the diff pass.
+#[derive(Debug)]
+pub struct DriftSamples {
+    metric: DriftMetric,
+    old: Vec<Vec<f64>>,
+    new: Vec<Vec<f64>>,
+}
+
+impl DriftSamples {
+    pub fn new(metric: DriftMetric, columns: usize) -> Self {
+        Self {
+            metric,
+            old: vec![Vec::new(); columns],
+            new: vec![Vec::new(); columns],
+        }
+    }
+
+    pub fn observe(&mut self, column: usize, old: f64, new: f64) {
+        self.old[column].push(old);
+        self.new[column].push(new);
+    }
+
+    /// Append samples observed after this set's (a later scan chunk).
+    pub fn append(&mut self, other: DriftSamples) {
+        for (column, values) in self.old.iter_mut().zip(other.old) {
+            column.extend(values);
+        }
+        for (column, values) in self.new.iter_mut().zip(other.new) {
+            column.extend(values);
+        }
+    }
+
+    /// Compute the statistic per column; `names` is indexed like `observe`.
+    pub fn finish<N: AsRef<[u8]>>(self, names: &[N]) -> DriftReport {
+        let metric = self.metric;
+        let mut columns = self
+            .old
+            .into_iter()
+            .zip(self.new)
+            .zip(names)
+            .map(|((mut old, mut new), name)| {
+                let pairs = old.len() as u64;
+                let value = match metric {
+                    DriftMetric::Psi => psi(&mut old, &mut new),
+                    DriftMetric::Ks => ks_statistic(&mut old, &mut new),
+                };
+                ColumnDrift {
+                    column: name.as_ref().to_vec(),
+                    value,
+                    pairs,
+                }
+            })
+            .collect::<Vec<_>>();
+        // Stable sort keeps column order among equal statistics.
+        columns.sort_by(|a, b| b.value.total_cmp(&a.value));
+        DriftReport { metric, columns }
+    }
+}
+
+/// Population Stability Index of `new` against `old`.
+///
+/// Bin edges are deciles of the pooled sample, so a constant old column
+/// still yields distinct bins when the new values move away from it.
+pub fn psi(old: &mut [f64], new: &mut [f64]) -> f64 {
+    if old.is_empty() || new.is_empty() {
+        return 0.0;
+    }
+    let mut pooled = old.iter().chain(new.iter()).copied().collect::<Vec<_>>();
+    pooled.sort_by(f64::total_cmp);
+    let mut edges = (1..PSI_BINS)
+        .map(|bin| pooled[(bin * pooled.len() / PSI_BINS).min(pooled.len() - 1)])
+        .collect::<Vec<_>>();
+    edges.dedup();
+
+    let old_fractions = bin_fractions(old, &edges);
+    let new_fractions = bin_fractions(new, &edges);
+    old_fractions
+        .iter()
+        .zip(&new_fractions)
+        .map(|(&expected, &actual)| {
+            let expected = expected.max(PSI_EPSILON);
+            let actual = actual.max(PSI_EPSILON);
+            (actual - expected) * (actual / expected).ln()
+        })
+        .sum()
+}
+
+/// Two-sample Kolmogorov-Smirnov statistic: the largest gap between the
+/// empirical CDFs of `old` and `new`, in `[0, 1]`.
+pub fn ks_statistic(old: &mut [f64], new: &mut [f64]) -> f64 {
+    if old.is_empty() || new.is_empty() {
+        return 0.0;
+    }
+    old.sort_by(f64::total_cmp);
+    new.sort_by(f64::total_cmp);
+    let (n_old, n_new) = (old.len() as f64, new.len() as f64);
+    let (mut i, mut j) = (0usize, 0usize);
+    let mut max_gap = 0.0f64;
+    while i < old.len() && j < new.len() {
+        // Advance past every copy of the smaller value on both sides so ties
+        // move the two CDFs together.
+        let value = if old[i] <= new[j] { old[i] } else { new[j] };
+        while i < old.len() && old[i] <= value {
+            i += 1;
+        }
+        while j < new.len() && new[j] <= value {
+            j += 1;
+        }
+        max_gap = max_gap.max((i as f64 / n_old - j as f64 / n_new).abs());
+    }
+    max_gap
+}
+
+fn bin_fractions(values: &[f64], edges: &[f64]) -> Vec<f64> {
+    let mut counts = vec![0u64; edges.len() + 1];
+    for value in values {
+        counts[edges.partition_point(|edge| edge < value)] += 1;
+    }
+    let total = values.len() as f64;
+    counts
+        .into_iter()
+        .map(|count| count as f64 / total)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_distributions_have_no_drift() {
+        let values = (1..=50).map(f64::from).collect::<Vec<_>>();
+        assert_eq!(ks_statistic(&mut values.clone(), &mut values.clone()), 0.0);
+        assert!(psi(&mut values.clone(), &mut values.clone()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ks_is_one_for_disjoint_samples() {
+        let mut old = vec![1.0, 2.0, 3.0];
+        let mut new = vec![10.0, 11.0, 12.0];
+        assert_eq!(ks_statistic(&mut old, &mut new), 1.0);
+    }
+
+    #[test]
+    fn ks_measures_partial_overlap() {
+        let mut old = vec![1.0, 2.0, 3.0, 4.0];
+        let mut new = vec![3.0, 4.0, 5.0, 6.0];
+        assert_eq!(ks_statistic(&mut old, &mut new), 0.5);
+    }
+
+    #[test]
+    fn psi_flags_a_systematic_shift_from_a_constant_column() {
+        let mut old = vec![100.0; 20];
+        let mut new = vec![98.0; 20];
+        assert!(psi(&mut old, &mut new) > 1.0);
+    }
+
+    #[test]
+    fn uniform_shift_drifts_more_than_a_single_outlier() {
+        let old = (1..=100).map(f64::from).collect::<Vec<_>>();
+        let shifted = old.iter().map(|v| v * 1.5).collect::<Vec<_>>();
+        let mut outlier = old.clone();
+        outlier[0] = 1000.0;
+        assert!(psi(&mut old.clone(), &mut shifted.clone()) > psi(&mut old.clone(), &mut outlier));
+    }
+
+    #[test]
+    fn finish_orders_columns_by_descending_drift() {
+        let mut samples = DriftSamples::new(DriftMetric::Ks, 2);
+        for idx in 0..4 {
+            let value = f64::from(idx);
+            samples.observe(0, value, value);
+            samples.observe(1, value, value + 10.0);
+        }
+        let report = samples.finish(&[b"flat".to_vec(), b"moved".to_vec()]);
+        assert_eq!(report.metric, DriftMetric::Ks);
+        assert_eq!(report.columns[0].column, b"moved".to_vec());
+        assert_eq!(report.columns[0].value, 1.0);
+        assert_eq!(report.columns[0].pairs, 4);
+        assert_eq!(report.columns[1].value, 0.0);
+    }
+}
@@ -1,4 +1,71 @@
+use serde::Serialize;
+
 use crate::normalize::trim::ascii_trim;
+use crate::numeric::parse::NumberFormat;
+
+/// What to do with a cell that is missing on one side and numeric on the
+/// other (`--on-missing`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MissingPolicy {
+    /// Refuse the run with `E_MISSINGNESS`.
+    #[default]
+    Refuse,
+    /// Leave the cell out of the comparison and count it.
+    Skip,
+    /// Read the missing side as 0 and include the delta.
+    Zero,
+}
+
+impl MissingPolicy {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "refuse" => Some(MissingPolicy::Refuse),
+            "skip" => Some(MissingPolicy::Skip),
+            "zero" => Some(MissingPolicy::Zero),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MissingPolicy::Refuse => "refuse",
+            MissingPolicy::Skip => "skip",
+            MissingPolicy::Zero => "zero",
+        }
+    }
+
+    /// Resolve an aligned cell pair of a numeric column under this policy.
+    pub fn pair(self, format: NumberFormat<'_>, old: &[u8], new: &[u8]) -> CellPair {
+        match (format.parse(old), format.parse(new)) {
+            (Some(old), Some(new)) => CellPair::Values(old, new),
+            (Some(value), None) if format.is_missing(new) => self.one_sided(value, 0.0),
+            (None, Some(value)) if format.is_missing(old) => self.one_sided(0.0, value),
+            _ => CellPair::NotCompared,
+        }
+    }
+
+    fn one_sided(self, old: f64, new: f64) -> CellPair {
+        match self {
+            MissingPolicy::Refuse => CellPair::NotCompared,
+            MissingPolicy::Skip => CellPair::Skipped,
+            MissingPolicy::Zero => CellPair::Zeroed(old, new),
+        }
+    }
+}
+
+/// An aligned numeric cell pair after [`MissingPolicy::pair`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellPair {
+    /// Both sides parsed.
+    Values(f64, f64),
+    /// One side missing, read as 0 (`zero`).
+    Zeroed(f64, f64),
+    /// One side missing, left out (`skip`).
+    Skipped,
+    /// Missing or text on both sides, or refused during column typing.
+    NotCompared,
+}
 
 /// Returns true if the input is a missing token after ASCII-trimming.
 ///
@@ -29,7 +96,8 @@ fn ascii_eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::is_missing_token;
+    use super::{CellPair, MissingPolicy, is_missing_token};
+    use crate::numeric::parse::NumberFormat;
 
     #[test]
     fn missing_empty_or_blanks() {
@@ -74,6 +142,31 @@ mod tests {
         assert!(!is_missing_token(b"NULLS"));
     }
 
+    #[test]
+    fn policy_resolves_one_sided_missing_cells() {
+        let format = NumberFormat::default();
+        assert_eq!(
+            MissingPolicy::Skip.pair(format, b"1", b"2"),
+            CellPair::Values(1.0, 2.0)
+        );
+        assert_eq!(
+            MissingPolicy::Skip.pair(format, b"", b"2"),
+            CellPair::Skipped
+        );
+        assert_eq!(
+            MissingPolicy::Zero.pair(format, b"5", b"NA"),
+            CellPair::Zeroed(5.0, 0.0)
+        );
+        assert_eq!(
+            MissingPolicy::Refuse.pair(format, b"5", b""),
+            CellPair::NotCompared
+        );
+        assert_eq!(
+            MissingPolicy::Zero.pair(format, b"5", b"text"),
+            CellPair::NotCompared
+        );
+    }
+
     #[test]
     fn non_ascii_trim_not_applied() {
         assert!(!is_missing_token(b"\r"));
@@ -1,5 +1,10 @@
 pub mod columns;
+pub mod dates;
+pub mod drift;
 pub mod missing;
 pub mod missingness;
 pub mod no_numeric;
 pub mod parse;
+pub mod schema;
+pub mod semantic;
+pub mod transform;
@@ -3,16 +3,208 @@
 //! Supported (per PLAN_RVL.md):
 //! - Plain numbers with optional sign and exponent.
 //! - US thousands separators (commas in 3-digit groups).
-//! - Currency prefix `$` (with sign before or after `$`).
+//! - Currency symbol `$`, `€`, `£`, or `¥` before the number (with sign before
+//!   or after it) or after it (`12,50 €`); more via `--currency-symbols`.
 //! - Accounting parentheses to force negative (e.g., `(123.45)` or `($1,234.56)`).
+//!
+//! Opt-in forms are selected by [`NumberFormat`]: a trailing `%` (`--parse-percent`)
+//! comma decimals with dot thousands separators (`--decimal comma`), and
+//! Unicode minus and space-grouped digits (`--lenient-numbers`).
+
+use serde::Serialize;
 
 use crate::normalize::trim::ascii_trim;
+use crate::numeric::missing::is_missing_token;
+
+/// Currency symbols accepted in every mode.
+pub const BUILTIN_CURRENCY_SYMBOLS: [&str; 4] = ["$", "€", "£", "¥"];
+
+/// Opt-in numeric token forms beyond the v0 rules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NumberFormat<'a> {
+    /// Accept a trailing `%` and scale by 1/100 (`91%` -> `0.91`).
+    pub percent: bool,
+    pub decimal: DecimalSeparator,
+    /// Read U+2212 as `-`, drop non-breaking/thin spaces, and accept them
+    /// (or plain spaces) as thousands separators between digits.
+    pub lenient: bool,
+    /// Symbols accepted in addition to [`BUILTIN_CURRENCY_SYMBOLS`].
+    pub currency_symbols: &'a [String],
+    /// Placeholders treated as missing in addition to the built-in tokens
+    /// (`--na-values`), matched like them: ASCII-trimmed, case-insensitive.
+    pub na_values: &'a [String],
+}
+
+/// Decimal separator; the other of `.`/`,` is the thousands separator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalSeparator {
+    /// `1,234.56`
+    #[default]
+    Dot,
+    /// `1.234,56` (most European locales).
+    Comma,
+}
+
+impl DecimalSeparator {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "dot" => Some(DecimalSeparator::Dot),
+            "comma" => Some(DecimalSeparator::Comma),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DecimalSeparator::Dot => "dot",
+            DecimalSeparator::Comma => "comma",
+        }
+    }
+}
+
+impl<'a> NumberFormat<'a> {
+    /// Parse a numeric token under these options; see [`parse_numeric`].
+    pub fn parse(self, input: &[u8]) -> Option<f64> {
+        parse_with(input, self)
+    }
+
+    /// Whether a cell is a missing token: a built-in one (see
+    /// [`is_missing_token`]) or one of `na_values`.
+    pub fn is_missing(self, input: &[u8]) -> bool {
+        if is_missing_token(input) {
+            return true;
+        }
+        let trimmed = ascii_trim(input);
+        self.na_values
+            .iter()
+            .any(|token| trimmed.eq_ignore_ascii_case(ascii_trim(token.as_bytes())))
+    }
+
+    /// The currency symbol written in a token, if any (longest match wins,
+    /// so `R$` is reported rather than `$`).
+    pub fn currency_symbol(self, input: &[u8]) -> Option<&'a str> {
+        self.symbols()
+            .filter(|symbol| contains_bytes(input, symbol.as_bytes()))
+            .max_by_key(|symbol| symbol.len())
+    }
+
+    fn symbols(self) -> impl Iterator<Item = &'a str> {
+        let builtin: [&'a str; 4] = BUILTIN_CURRENCY_SYMBOLS;
+        builtin
+            .into_iter()
+            .chain(self.currency_symbols.iter().map(String::as_str))
+    }
+
+    /// Length of the longest symbol that `token` starts with.
+    fn leading_symbol(self, token: &[u8]) -> Option<usize> {
+        self.symbols()
+            .map(str::as_bytes)
+            .filter(|symbol| token.starts_with(symbol))
+            .map(<[u8]>::len)
+            .max()
+    }
+
+    /// Length of the longest symbol that `token` ends with.
+    fn trailing_symbol(self, token: &[u8]) -> Option<usize> {
+        self.symbols()
+            .map(str::as_bytes)
+            .filter(|symbol| token.ends_with(symbol))
+            .map(<[u8]>::len)
+            .max()
+    }
+
+    fn contains_symbol(self, token: &[u8]) -> bool {
+        // Every built-in symbol is `$` or starts with a non-ASCII byte.
+        if self.currency_symbols.is_empty() && token.iter().all(|b| b.is_ascii() && *b != b'$') {
+            return false;
+        }
+        self.symbols()
+            .any(|symbol| contains_bytes(token, symbol.as_bytes()))
+    }
+}
+
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
 
 /// Parse a numeric token according to v0 rules.
 ///
 /// Returns `Some(f64)` if the value is valid and finite; otherwise `None`.
 pub fn parse_numeric(input: &[u8]) -> Option<f64> {
+    parse_with(input, NumberFormat::default())
+}
+
+/// True when a token carries a percent sign (`91%`, `(5%)`).
+pub fn has_percent_suffix(input: &[u8]) -> bool {
+    let token = ascii_trim(input);
+    token.ends_with(b"%") || token.ends_with(b"%)")
+}
+
+fn parse_with(input: &[u8], format: NumberFormat<'_>) -> Option<f64> {
     let trimmed = ascii_trim(input);
+    if format.lenient && trimmed.iter().any(|b| !b.is_ascii() || *b == b' ') {
+        return parse_trimmed(&normalize_lenient(trimmed, format.decimal), format);
+    }
+    parse_trimmed(trimmed, format)
+}
+
+/// UTF-8 spaces that exports use for padding and digit grouping: NBSP,
+/// thin space, and narrow NBSP.
+const UNICODE_SPACES: [&[u8]; 3] = [
+    "\u{00A0}".as_bytes(),
+    "\u{2009}".as_bytes(),
+    "\u{202F}".as_bytes(),
+];
+const UNICODE_MINUS: &[u8] = "\u{2212}".as_bytes();
+
+/// Rewrite a lenient token into the strict grammar: U+2212 becomes `-`, and a
+/// space (Unicode or ASCII) becomes the thousands separator between two
+/// digits and is dropped anywhere else.
+fn normalize_lenient(token: &[u8], decimal: DecimalSeparator) -> Vec<u8> {
+    let thousands = match decimal {
+        DecimalSeparator::Dot => b',',
+        DecimalSeparator::Comma => b'.',
+    };
+    let mut out = Vec::with_capacity(token.len());
+    let mut idx = 0;
+    while idx < token.len() {
+        let rest = &token[idx..];
+        if rest.starts_with(UNICODE_MINUS) {
+            out.push(b'-');
+            idx += UNICODE_MINUS.len();
+            continue;
+        }
+        let space_len = if rest[0] == b' ' {
+            Some(1)
+        } else {
+            UNICODE_SPACES
+                .iter()
+                .find(|space| rest.starts_with(space))
+                .map(|space| space.len())
+        };
+        match space_len {
+            Some(len) => {
+                let before_digit = out.last().is_some_and(u8::is_ascii_digit);
+                let after_digit = token.get(idx + len).is_some_and(u8::is_ascii_digit);
+                if before_digit && after_digit {
+                    out.push(thousands);
+                }
+                idx += len;
+            }
+            None => {
+                out.push(rest[0]);
+                idx += 1;
+            }
+        }
+    }
+    out
+}
+
+fn parse_trimmed(trimmed: &[u8], format: NumberFormat<'_>) -> Option<f64> {
     if trimmed.is_empty() {
         return None;
     }
@@ -24,12 +216,29 @@ pub fn parse_numeric(input: &[u8]) -> Option<f64> {
         token = &token[1..token.len() - 1];
     }
 
+    let mut divisor = 1.0;
+    if format.percent && token.len() >= 2 && token[token.len() - 1] == b'%' {
+        divisor = 100.0;
+        token = &token[..token.len() - 1];
+    }
+
+    let mut seen_symbol = false;
+    if let Some(len) = format.trailing_symbol(token) {
+        seen_symbol = true;
+        token = ascii_trim(&token[..token.len() - len]);
+    }
+
     if token.is_empty() {
         return None;
     }
 
-    let (sign, rest) = parse_prefix(token)?;
-    let mut value = parse_number_core(rest)?;
+    let (sign, rest) = parse_prefix(token, format, seen_symbol)?;
+    let magnitude = match format.decimal {
+        DecimalSeparator::Dot => parse_number_core(rest)?,
+        // Swapping `.` and `,` maps `1.234,56` onto the US grammar.
+        DecimalSeparator::Comma => parse_number_core(&swap_separators(rest))?,
+    };
+    let mut value = magnitude / divisor;
     value *= sign;
     if force_negative {
         value = -value.abs();
@@ -37,10 +246,19 @@ pub fn parse_numeric(input: &[u8]) -> Option<f64> {
     Some(value)
 }
 
-fn parse_prefix(token: &[u8]) -> Option<(f64, &[u8])> {
+/// True when two parsed cells hold the same value but were written differently
+/// (e.g. `1000` vs `1,000.00`). Surrounding ASCII whitespace is not a change.
+pub fn is_format_only_change(old_raw: &[u8], new_raw: &[u8], old: f64, new: f64) -> bool {
+    old == new && ascii_trim(old_raw) != ascii_trim(new_raw)
+}
+
+fn parse_prefix<'t>(
+    token: &'t [u8],
+    format: NumberFormat<'_>,
+    mut seen_symbol: bool,
+) -> Option<(f64, &'t [u8])> {
     let mut sign = 1.0;
     let mut seen_sign = false;
-    let mut seen_dollar = false;
     let mut idx = 0;
 
     while idx < token.len() {
@@ -50,10 +268,13 @@ fn parse_prefix(token: &[u8]) -> Option<(f64, &[u8])> {
                 seen_sign = true;
                 idx += 1;
             }
-            b'$' if !seen_dollar => {
-                seen_dollar = true;
-                idx += 1;
-            }
+            _ if !seen_symbol => match format.leading_symbol(&token[idx..]) {
+                Some(len) => {
+                    seen_symbol = true;
+                    idx += len;
+                }
+                None => break,
+            },
             _ => break,
         }
     }
@@ -65,7 +286,7 @@ fn parse_prefix(token: &[u8]) -> Option<(f64, &[u8])> {
     if matches!(rest[0], b'+' | b'-') {
         return None;
     }
-    if rest.contains(&b'$') {
+    if format.contains_symbol(rest) {
         return None;
     }
     Some((sign, rest))
@@ -165,6 +386,17 @@ fn validate_commas(mantissa: &[u8]) -> bool {
     true
 }
 
+fn swap_separators(token: &[u8]) -> Vec<u8> {
+    token
+        .iter()
+        .map(|byte| match byte {
+            b'.' => b',',
+            b',' => b'.',
+            other => *other,
+        })
+        .collect()
+}
+
 #[inline]
 fn all_digits(slice: &[u8]) -> bool {
     slice.iter().all(|b| b.is_ascii_digit())
@@ -179,6 +411,14 @@ fn has_digit(slice: &[u8]) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn detects_format_only_changes() {
+        assert!(is_format_only_change(b"1000", b"1,000.00", 1000.0, 1000.0));
+        assert!(is_format_only_change(b"-5", b"(5)", -5.0, -5.0));
+        assert!(!is_format_only_change(b"1000", b" 1000 ", 1000.0, 1000.0));
+        assert!(!is_format_only_change(b"1000", b"1001", 1000.0, 1001.0));
+    }
+
     #[test]
     fn parses_plain_numbers() {
         assert_eq!(parse_numeric(b"123"), Some(123.0));
@@ -240,6 +480,108 @@ mod tests {
         assert_eq!(parse_numeric(b"-inf"), None);
     }
 
+    #[test]
+    fn parses_percent_only_when_enabled() {
+        let percent = NumberFormat {
+            percent: true,
+            ..NumberFormat::default()
+        };
+        assert_eq!(parse_numeric(b"91%"), None);
+        assert_eq!(percent.parse(b"91%"), Some(0.91));
+        assert_eq!(percent.parse(b"-5.5%"), Some(-0.055));
+        assert_eq!(percent.parse(b"(12%)"), Some(-0.12));
+        assert_eq!(percent.parse(b"1,250%"), Some(12.5));
+        assert_eq!(percent.parse(b"42"), Some(42.0));
+        assert_eq!(percent.parse(b"%"), None);
+        assert_eq!(percent.parse(b"5%%"), None);
+        assert!(has_percent_suffix(b" 91% "));
+        assert!(!has_percent_suffix(b"91"));
+    }
+
+    #[test]
+    fn parses_comma_decimals_when_enabled() {
+        let comma = NumberFormat {
+            decimal: DecimalSeparator::Comma,
+            ..NumberFormat::default()
+        };
+        assert_eq!(comma.parse(b"1.234,56"), Some(1234.56));
+        assert_eq!(comma.parse(b"-1.234.567,8"), Some(-1234567.8));
+        assert_eq!(comma.parse(b"(12,5)"), Some(-12.5));
+        assert_eq!(comma.parse(b"$0,75"), Some(0.75));
+        assert_eq!(comma.parse(b"1,5E3"), Some(1500.0));
+        assert_eq!(comma.parse(b"1.23,4"), None);
+        assert_eq!(comma.parse(b"1,2,3"), None);
+        assert_eq!(parse_numeric(b"1.234,56"), None);
+    }
+
+    #[test]
+    fn parses_builtin_and_configured_currency_symbols() {
+        assert_eq!(parse_numeric("€1,250.50".as_bytes()), Some(1250.5));
+        assert_eq!(parse_numeric("-£12".as_bytes()), Some(-12.0));
+        assert_eq!(parse_numeric("(¥300)".as_bytes()), Some(-300.0));
+        assert_eq!(parse_numeric("12.50 €".as_bytes()), Some(12.5));
+        assert_eq!(parse_numeric("€12€".as_bytes()), None);
+        assert_eq!(parse_numeric("$€12".as_bytes()), None);
+        assert_eq!(parse_numeric(b"CHF 12"), None);
+
+        let extra = ["CHF".to_string(), "R$".to_string()];
+        let format = NumberFormat {
+            currency_symbols: &extra,
+            ..NumberFormat::default()
+        };
+        assert_eq!(format.parse(b"CHF12"), Some(12.0));
+        assert_eq!(format.parse(b"12 CHF"), Some(12.0));
+        assert_eq!(format.parse(b"-R$5"), Some(-5.0));
+        assert_eq!(format.currency_symbol(b"R$5"), Some("R$"));
+        assert_eq!(format.currency_symbol("€5".as_bytes()), Some("€"));
+        assert_eq!(format.currency_symbol(b"5"), None);
+    }
+
+    #[test]
+    fn parses_unicode_minus_and_spaces_when_lenient() {
+        let lenient = NumberFormat {
+            lenient: true,
+            ..NumberFormat::default()
+        };
+        assert_eq!(parse_numeric("\u{2212}12.5".as_bytes()), None);
+        assert_eq!(lenient.parse("\u{2212}12.5".as_bytes()), Some(-12.5));
+        assert_eq!(
+            lenient.parse("\u{00A0}1234\u{00A0}".as_bytes()),
+            Some(1234.0)
+        );
+        assert_eq!(
+            lenient.parse("1\u{2009}234\u{2009}567".as_bytes()),
+            Some(1234567.0)
+        );
+        assert_eq!(lenient.parse(b"1 234.5"), Some(1234.5));
+        assert_eq!(lenient.parse("12\u{202F}€".as_bytes()), Some(12.0));
+        assert_eq!(lenient.parse(b"12 34"), None);
+
+        let comma = NumberFormat {
+            lenient: true,
+            decimal: DecimalSeparator::Comma,
+            ..NumberFormat::default()
+        };
+        assert_eq!(
+            comma.parse("\u{2212}1\u{202F}234,56".as_bytes()),
+            Some(-1234.56)
+        );
+    }
+
+    #[test]
+    fn na_values_extend_missing_tokens() {
+        let na_values = vec!["TBD".to_string(), "--".to_string()];
+        let format = NumberFormat {
+            na_values: &na_values,
+            ..NumberFormat::default()
+        };
+        assert!(format.is_missing(b" tbd "));
+        assert!(format.is_missing(b"--"));
+        assert!(format.is_missing(b"N/A"));
+        assert!(!format.is_missing(b"TBA"));
+        assert!(!NumberFormat::default().is_missing(b"TBD"));
+    }
+
     #[test]
     fn trims_ascii_whitespace() {
         assert_eq!(parse_numeric(b"  123  "), Some(123.0));
@@ -0,0 +1,258 @@
+//! Schema fingerprints: ordered headers plus an inferred type per column.
+//!
+//! Two files with the same fingerprint have the same columns, in the same
+//! order, holding the same kind of values. When fingerprints differ the
+//! orchestrator reports the structural change before any cell diffing.
+
+use std::collections::HashMap;
+
+use crate::numeric::columns::FieldAccess;
+use crate::numeric::parse::NumberFormat;
+
+/// Inferred type of a single column in one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// Every non-missing value parses as a number.
+    Numeric,
+    /// At least one non-missing value is not a number.
+    Text,
+    /// Every value is a missing token (or the file has no rows).
+    Empty,
+}
+
+impl ColumnKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ColumnKind::Numeric => "numeric",
+            ColumnKind::Text => "text",
+            ColumnKind::Empty => "empty",
+        }
+    }
+
+    fn tag(self) -> u8 {
+        match self {
+            ColumnKind::Numeric => b'n',
+            ColumnKind::Text => b't',
+            ColumnKind::Empty => b'e',
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaColumn {
+    pub name: Vec<u8>,
+    pub kind: ColumnKind,
+}
+
+/// Ordered columns of one file and the BLAKE3 digest over them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaFingerprint {
+    pub columns: Vec<SchemaColumn>,
+    /// `blake3:`-prefixed hex digest of the ordered (name, kind) pairs.
+    pub digest: String,
+}
+
+impl SchemaFingerprint {
+    /// Fingerprint normalized headers and the records parsed under them.
+    pub fn compute<R: FieldAccess>(
+        headers: &[Vec<u8>],
+        records: &[R],
+        format: NumberFormat<'_>,
+    ) -> Self {
+        let columns = headers
+            .iter()
+            .enumerate()
+            .map(|(index, name)| SchemaColumn {
+                name: name.clone(),
+                kind: infer_kind(records, index, format),
+            })
+            .collect::<Vec<_>>();
+
+        let mut hasher = blake3::Hasher::new();
+        for column in &columns {
+            // Length-prefix names so ("ab","c") and ("a","bc") cannot collide.
+            hasher.update(&(column.name.len() as u64).to_le_bytes());
+            hasher.update(&column.name);
+            hasher.update(&[column.kind.tag()]);
+        }
+        let digest = format!("blake3:{}", hasher.finalize().to_hex());
+
+        Self { columns, digest }
+    }
+}
+
+/// A column present in both files whose inferred type changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetypedColumn {
+    pub name: Vec<u8>,
+    pub old: ColumnKind,
+    pub new: ColumnKind,
+}
+
+/// Structural differences between two schema fingerprints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub old_digest: String,
+    pub new_digest: String,
+    pub columns_old: usize,
+    pub columns_new: usize,
+    /// Columns only in the new file, in new-file order.
+    pub added: Vec<Vec<u8>>,
+    /// Columns only in the old file, in old-file order.
+    pub removed: Vec<Vec<u8>>,
+    /// Common columns whose inferred type differs, in old-file order.
+    pub retyped: Vec<RetypedColumn>,
+    /// True when the common columns appear in a different relative order.
+    pub reordered: bool,
+}
+
+/// Compare two fingerprints. Returns `None` when the digests match.
+pub fn compare_schemas(old: &SchemaFingerprint, new: &SchemaFingerprint) -> Option<SchemaChange> {
+    if old.digest == new.digest {
+        return None;
+    }
+
+    let new_by_name: HashMap<&[u8], &SchemaColumn> = new
+        .columns
+        .iter()
+        .map(|column| (column.name.as_slice(), column))
+        .collect();
+    let old_by_name: HashMap<&[u8], &SchemaColumn> = old
+        .columns
+        .iter()
+        .map(|column| (column.name.as_slice(), column))
+        .collect();
+
+    let mut removed = Vec::new();
+    let mut retyped = Vec::new();
+    let mut common_old_order = Vec::new();
+    for column in &old.columns {
+        match new_by_name.get(column.name.as_slice()) {
+            Some(other) => {
+                common_old_order.push(column.name.as_slice());
+                if other.kind != column.kind {
+                    retyped.push(RetypedColumn {
+                        name: column.name.clone(),
+                        old: column.kind,
+                        new: other.kind,
+                    });
+                }
+            }
+            None => removed.push(column.name.clone()),
+        }
+    }
+
+    let mut added = Vec::new();
+    let mut common_new_order = Vec::new();
+    for column in &new.columns {
+        if old_by_name.contains_key(column.name.as_slice()) {
+            common_new_order.push(column.name.as_slice());
+        } else {
+            added.push(column.name.clone());
+        }
+    }
+
+    Some(SchemaChange {
+        old_digest: old.digest.clone(),
+        new_digest: new.digest.clone(),
+        columns_old: old.columns.len(),
+        columns_new: new.columns.len(),
+        added,
+        removed,
+        retyped,
+        reordered: common_old_order != common_new_order,
+    })
+}
+
+fn infer_kind<R: FieldAccess>(records: &[R], index: usize, format: NumberFormat<'_>) -> ColumnKind {
+    let mut kind = ColumnKind::Empty;
+    for record in records {
+        let raw = record.field(index);
+        if format.is_missing(raw) {
+            continue;
+        }
+        if format.parse(raw).is_none() {
+            return ColumnKind::Text;
+        }
+        kind = ColumnKind::Numeric;
+    }
+    kind
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fingerprint(headers: &[Vec<u8>], records: &[Vec<Vec<u8>>]) -> SchemaFingerprint {
+        SchemaFingerprint::compute(headers, records, NumberFormat::default())
+    }
+
+    fn headers(names: &[&str]) -> Vec<Vec<u8>> {
+        names.iter().map(|name| name.as_bytes().to_vec()).collect()
+    }
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<Vec<u8>>> {
+        rows.iter()
+            .map(|row| row.iter().map(|field| field.as_bytes().to_vec()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn identical_schemas_share_a_digest() {
+        let old = fingerprint(&headers(&["id", "v"]), &rows(&[&["A", "1"]]));
+        let new = fingerprint(&headers(&["id", "v"]), &rows(&[&["B", "2.5"]]));
+        assert_eq!(old.digest, new.digest);
+        assert!(old.digest.starts_with("blake3:"));
+        assert_eq!(compare_schemas(&old, &new), None);
+    }
+
+    #[test]
+    fn infers_numeric_text_and_empty_columns() {
+        let fingerprint = fingerprint(
+            &headers(&["id", "v", "note"]),
+            &rows(&[&["A", "1", ""], &["B", "NA", "-"]]),
+        );
+        let kinds = fingerprint
+            .columns
+            .iter()
+            .map(|column| column.kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![ColumnKind::Text, ColumnKind::Numeric, ColumnKind::Empty]
+        );
+    }
+
+    #[test]
+    fn reports_added_removed_retyped_and_reordered_columns() {
+        let old = fingerprint(
+            &headers(&["id", "a", "b", "gone"]),
+            &rows(&[&["A", "1", "2", "3"]]),
+        );
+        let new = fingerprint(
+            &headers(&["id", "b", "a", "fresh"]),
+            &rows(&[&["A", "2", "x", "3"]]),
+        );
+        let change = compare_schemas(&old, &new).expect("schemas differ");
+        assert_eq!(change.added, headers(&["fresh"]));
+        assert_eq!(change.removed, headers(&["gone"]));
+        assert_eq!(
+            change.retyped,
+            vec![RetypedColumn {
+                name: b"a".to_vec(),
+                old: ColumnKind::Numeric,
+                new: ColumnKind::Text,
+            }]
+        );
+        assert!(change.reordered);
+    }
+
+    #[test]
+    fn digest_is_order_sensitive() {
+        let old = fingerprint(&headers(&["a", "b"]), &rows(&[&["1", "2"]]));
+        let new = fingerprint(&headers(&["b", "a"]), &rows(&[&["2", "1"]]));
+        let change = compare_schemas(&old, &new).expect("order differs");
+        assert!(change.reordered);
+        assert!(change.added.is_empty() && change.removed.is_empty());
+    }
+}
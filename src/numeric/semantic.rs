@@ -0,0 +1,250 @@
+//! Semantic types for numeric columns (`--smart-tolerance`).
+//!
+//! One absolute tolerance rarely fits every column: half a cent is noise on a
+//! balance but a real move on a rate. Each numeric column is classified by a
+//! table of matchers over its header words and value evidence; the first match
+//! wins and picks that type's default tolerance.
+
+use crate::normalize::trim::ascii_trim;
+use crate::numeric::parse::NumberFormat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticType {
+    Currency,
+    Percent,
+    Ratio,
+    Date,
+    Count,
+    /// No matcher applied; the global `--tolerance` is used.
+    Number,
+}
+
+impl SemanticType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SemanticType::Currency => "currency",
+            SemanticType::Percent => "percent",
+            SemanticType::Ratio => "ratio",
+            SemanticType::Date => "date",
+            SemanticType::Count => "count",
+            SemanticType::Number => "number",
+        }
+    }
+
+    /// Default absolute tolerance for the type, or `None` to use `--tolerance`.
+    pub fn default_tolerance(self) -> Option<f64> {
+        match self {
+            // Sub-cent rounding.
+            SemanticType::Currency => Some(0.005),
+            // Rounding at the fourth decimal of a percentage.
+            SemanticType::Percent => Some(0.00005),
+            SemanticType::Ratio => Some(0.000005),
+            // Integral values: any nonzero delta is real.
+            SemanticType::Date | SemanticType::Count => Some(0.0),
+            SemanticType::Number => None,
+        }
+    }
+}
+
+/// Value evidence gathered from both sides of one numeric column.
+#[derive(Debug, Clone, Copy)]
+pub struct ColumnEvidence {
+    pub values: u64,
+    pub currency_symbol: bool,
+    pub all_integral: bool,
+    pub all_non_negative: bool,
+    pub all_unit_interval: bool,
+    pub all_yyyymmdd: bool,
+}
+
+impl Default for ColumnEvidence {
+    fn default() -> Self {
+        Self {
+            values: 0,
+            currency_symbol: false,
+            all_integral: true,
+            all_non_negative: true,
+            all_unit_interval: true,
+            all_yyyymmdd: true,
+        }
+    }
+}
+
+impl ColumnEvidence {
+    pub fn observe(&mut self, raw: &[u8], value: f64) {
+        let token = ascii_trim(raw);
+        self.values += 1;
+        self.currency_symbol |= NumberFormat::default().currency_symbol(token).is_some();
+        self.all_integral &= value.fract() == 0.0 && !token.contains(&b'.');
+        self.all_non_negative &= value >= 0.0;
+        self.all_unit_interval &= (-1.0..=1.0).contains(&value);
+        self.all_yyyymmdd &= is_yyyymmdd(token);
+    }
+}
+
+type Matcher = fn(&[String], &ColumnEvidence) -> bool;
+
+/// Matchers in priority order; the first that accepts a column wins.
+const MATCHERS: &[(SemanticType, Matcher)] = &[
+    (SemanticType::Date, |words, evidence| {
+        has_word(words, &["date", "dt"]) && evidence.all_yyyymmdd
+    }),
+    (SemanticType::Currency, |words, evidence| {
+        evidence.currency_symbol
+            || has_word(
+                words,
+                &[
+                    "amount",
+                    "amt",
+                    "balance",
+                    "bal",
+                    "price",
+                    "cost",
+                    "rent",
+                    "revenue",
+                    "income",
+                    "expense",
+                    "fee",
+                    "payment",
+                    "principal",
+                    "usd",
+                ],
+            )
+    }),
+    (SemanticType::Percent, |words, _| {
+        has_word(words, &["pct", "percent", "rate", "yield", "margin", "apr"])
+    }),
+    (SemanticType::Ratio, |words, evidence| {
+        has_word(words, &["ratio", "dscr", "ltv", "multiple", "factor"])
+            || (evidence.all_unit_interval && !evidence.all_integral)
+    }),
+    (SemanticType::Count, |_, evidence| {
+        evidence.all_integral && evidence.all_non_negative
+    }),
+];
+
+/// Classify a numeric column from its header and value evidence.
+pub fn classify(name: &[u8], evidence: &ColumnEvidence) -> SemanticType {
+    if evidence.values == 0 {
+        return SemanticType::Number;
+    }
+    let words = header_words(name);
+    MATCHERS
+        .iter()
+        .find(|(_, matches)| matches(&words, evidence))
+        .map(|(semantic, _)| *semantic)
+        .unwrap_or(SemanticType::Number)
+}
+
+/// Semantic type and effective tolerance for one numeric column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSemantic {
+    pub column: Vec<u8>,
+    pub semantic: SemanticType,
+    pub tolerance: f64,
+}
+
+/// Classify every column; a type default never tightens below `base_tolerance`.
+pub fn classify_columns(
+    names: &[&[u8]],
+    evidence: &[ColumnEvidence],
+    base_tolerance: f64,
+) -> Vec<ColumnSemantic> {
+    names
+        .iter()
+        .zip(evidence)
+        .map(|(name, evidence)| {
+            let semantic = classify(name, evidence);
+            let tolerance = semantic
+                .default_tolerance()
+                .map_or(base_tolerance, |default| default.max(base_tolerance));
+            ColumnSemantic {
+                column: name.to_vec(),
+                semantic,
+                tolerance,
+            }
+        })
+        .collect()
+}
+
+fn header_words(name: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(name)
+        .to_ascii_lowercase()
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn has_word(words: &[String], candidates: &[&str]) -> bool {
+    words.iter().any(|word| candidates.contains(&word.as_str()))
+}
+
+fn is_yyyymmdd(token: &[u8]) -> bool {
+    if token.len() != 8 || !token.iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    let number = |range: std::ops::Range<usize>| {
+        token[range]
+            .iter()
+            .fold(0u32, |acc, digit| acc * 10 + u32::from(digit - b'0'))
+    };
+    let (year, month, day) = (number(0..4), number(4..6), number(6..8));
+    (1900..=2199).contains(&year) && (1..=12).contains(&month) && (1..=31).contains(&day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evidence(tokens: &[&str]) -> ColumnEvidence {
+        let mut evidence = ColumnEvidence::default();
+        for token in tokens {
+            let value = crate::numeric::parse::parse_numeric(token.as_bytes()).unwrap();
+            evidence.observe(token.as_bytes(), value);
+        }
+        evidence
+    }
+
+    #[test]
+    fn classifies_by_header_words_and_values() {
+        assert_eq!(
+            classify(b"loan_balance", &evidence(&["100.25", "99.10"])),
+            SemanticType::Currency
+        );
+        assert_eq!(
+            classify(b"value", &evidence(&["$1,200", "$900"])),
+            SemanticType::Currency
+        );
+        assert_eq!(
+            classify(b"interest_rate", &evidence(&["5.25", "5.5"])),
+            SemanticType::Percent
+        );
+        assert_eq!(
+            classify(b"weight", &evidence(&["0.25", "0.75"])),
+            SemanticType::Ratio
+        );
+        assert_eq!(
+            classify(b"close_date", &evidence(&["20240131", "20240229"])),
+            SemanticType::Date
+        );
+        assert_eq!(
+            classify(b"units", &evidence(&["12", "40"])),
+            SemanticType::Count
+        );
+        assert_eq!(
+            classify(b"score", &evidence(&["-3.5", "12.25"])),
+            SemanticType::Number
+        );
+    }
+
+    #[test]
+    fn type_defaults_never_tighten_the_global_tolerance() {
+        let names: [&[u8]; 2] = [b"amount", b"score"];
+        let columns = classify_columns(&names, &[evidence(&["1.50"]), evidence(&["-2.5"])], 0.01);
+        assert_eq!(columns[0].semantic, SemanticType::Currency);
+        assert_eq!(columns[0].tolerance, 0.01);
+        assert_eq!(columns[1].semantic, SemanticType::Number);
+        assert_eq!(columns[1].tolerance, 0.01);
+    }
+}
@@ -0,0 +1,214 @@
+//! Per-column value transforms (`--transform`).
+//!
+//! `--transform rent=abs`, `area=round:0`, or `value=scale:0.001` rewrites
+//! parsed values of a numeric column before they are diffed. Prefix the
+//! column with `old:` or `new:` to transform one side only, e.g.
+//! `new:value=scale:1000` when the new export is in thousands. Transforms on
+//! the same column apply in flag order.
+
+use crate::normalize::trim::ascii_trim;
+
+/// Largest `round:N` accepted; finer rounding is below f64 precision for
+/// most values.
+const MAX_ROUND_DIGITS: u32 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformOp {
+    Abs,
+    /// Round half away from zero to this many decimal places.
+    Round(u32),
+    Scale(f64),
+}
+
+impl TransformOp {
+    fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        let (name, arg) = match raw.split_once(':') {
+            Some((name, arg)) => (name.trim(), Some(arg.trim())),
+            None => (raw, None),
+        };
+        match (name.to_ascii_lowercase().as_str(), arg) {
+            ("abs", None) => Ok(Self::Abs),
+            ("round", Some(arg)) => arg
+                .parse()
+                .ok()
+                .filter(|digits| *digits <= MAX_ROUND_DIGITS)
+                .map(Self::Round)
+                .ok_or_else(|| format!("round:N needs 0 <= N <= {MAX_ROUND_DIGITS}")),
+            ("scale", Some(arg)) => arg
+                .parse::<f64>()
+                .ok()
+                .filter(|factor| factor.is_finite() && *factor != 0.0)
+                .map(Self::Scale)
+                .ok_or_else(|| "scale:F needs a finite, non-zero factor".to_string()),
+            _ => Err("transform must be abs, round:N, or scale:F".to_string()),
+        }
+    }
+
+    pub fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Abs => value.abs(),
+            Self::Round(digits) => {
+                let factor = 10f64.powi(digits as i32);
+                (value * factor).round() / factor
+            }
+            Self::Scale(factor) => value * factor,
+        }
+    }
+
+    pub fn as_arg(self) -> String {
+        match self {
+            Self::Abs => "abs".to_string(),
+            Self::Round(digits) => format!("round:{digits}"),
+            Self::Scale(factor) => format!("scale:{factor}"),
+        }
+    }
+}
+
+/// Which file's values a transform rewrites.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransformSide {
+    Both,
+    Old,
+    New,
+}
+
+impl TransformSide {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Both => "both",
+            Self::Old => "old",
+            Self::New => "new",
+        }
+    }
+}
+
+/// One `--transform [old:|new:]COLUMN=TRANSFORM` entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnTransform {
+    pub column: Vec<u8>,
+    pub side: TransformSide,
+    pub op: TransformOp,
+}
+
+impl ColumnTransform {
+    /// Parse `[old:|new:]COLUMN=TRANSFORM`. The split is at the last `=`,
+    /// so column names may contain `=`; the name is ASCII-trimmed like
+    /// headers.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let (column, op) = raw
+            .rsplit_once('=')
+            .ok_or_else(|| "must be column=transform".to_string())?;
+        let column = column.trim_start();
+        let (side, column) = if let Some(column) = column.strip_prefix("old:") {
+            (TransformSide::Old, column)
+        } else if let Some(column) = column.strip_prefix("new:") {
+            (TransformSide::New, column)
+        } else {
+            (TransformSide::Both, column)
+        };
+        let column = ascii_trim(column.as_bytes());
+        if column.is_empty() {
+            return Err("column name must be non-empty".to_string());
+        }
+        Ok(Self {
+            column: column.to_vec(),
+            side,
+            op: TransformOp::parse(op)?,
+        })
+    }
+
+    /// The flag value that reproduces this transform.
+    pub fn as_arg(&self) -> String {
+        let side = match self.side {
+            TransformSide::Both => "",
+            TransformSide::Old => "old:",
+            TransformSide::New => "new:",
+        };
+        format!(
+            "{side}{}={}",
+            String::from_utf8_lossy(&self.column),
+            self.op.as_arg()
+        )
+    }
+}
+
+/// The transforms for one column, split by side, in flag order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueTransforms {
+    old: Vec<TransformOp>,
+    new: Vec<TransformOp>,
+}
+
+impl ValueTransforms {
+    pub fn for_column(transforms: &[ColumnTransform], column: &[u8]) -> Self {
+        let mut resolved = Self::default();
+        for entry in transforms.iter().filter(|entry| entry.column == column) {
+            if entry.side != TransformSide::New {
+                resolved.old.push(entry.op);
+            }
+            if entry.side != TransformSide::Old {
+                resolved.new.push(entry.op);
+            }
+        }
+        resolved
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.old.is_empty() && self.new.is_empty()
+    }
+
+    #[inline]
+    pub fn apply_old(&self, value: f64) -> f64 {
+        self.old.iter().fold(value, |value, op| op.apply(value))
+    }
+
+    #[inline]
+    pub fn apply_new(&self, value: f64) -> f64 {
+        self.new.iter().fold(value, |value, op| op.apply(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_column_transforms() {
+        let scale = ColumnTransform::parse("new: value = scale:1e3").unwrap();
+        assert_eq!(scale.column, b"value".to_vec());
+        assert_eq!(scale.side, TransformSide::New);
+        assert_eq!(scale.op, TransformOp::Scale(1000.0));
+        assert_eq!(scale.as_arg(), "new:value=scale:1000");
+
+        let round = ColumnTransform::parse("area=ROUND:0").unwrap();
+        assert_eq!(round.side, TransformSide::Both);
+        assert_eq!(round.op, TransformOp::Round(0));
+        assert_eq!(ColumnTransform::parse("a=b=abs").unwrap().column, b"a=b");
+
+        assert!(ColumnTransform::parse("rent").is_err());
+        assert!(ColumnTransform::parse("=abs").is_err());
+        assert!(ColumnTransform::parse("rent=abs:1").is_err());
+        assert!(ColumnTransform::parse("rent=round:-1").is_err());
+        assert!(ColumnTransform::parse("rent=scale:0").is_err());
+        assert!(ColumnTransform::parse("rent=negate").is_err());
+    }
+
+    #[test]
+    fn applies_transforms_per_side_in_order() {
+        let transforms = [
+            ColumnTransform::parse("new:value=scale:1000").unwrap(),
+            ColumnTransform::parse("value=round:0").unwrap(),
+            ColumnTransform::parse("rent=abs").unwrap(),
+        ];
+        let value = ValueTransforms::for_column(&transforms, b"value");
+        assert_eq!(value.apply_old(1234.4), 1234.0);
+        assert_eq!(value.apply_new(1.2346), 1235.0);
+        assert_eq!(
+            ValueTransforms::for_column(&transforms, b"rent").apply_old(-5.0),
+            5.0
+        );
+        assert!(ValueTransforms::for_column(&transforms, b"area").is_empty());
+        assert_eq!(TransformOp::Round(1).apply(-2.25), -2.3);
+    }
+}
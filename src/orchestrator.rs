@@ -1,59 +1,122 @@
 //! Pipeline orchestration: parse → align → diff → output (bd-22s)
 
 mod capsule;
-
-use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::fs;
+mod expected;
+mod explore;
+mod identical;
+pub mod inspect;
+pub mod keys;
+mod memory;
+mod report;
+mod scan;
+pub mod snapshot;
+
+use std::borrow::Cow;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::io::Cursor;
-use std::path::Path;
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use csv::ByteRecord;
+use serde::Serialize;
 use serde_json::{Value, json};
 
+use crate::alignment::key_dedup::{DedupMode, KeyDedup, collapse_duplicate_keys};
 use crate::alignment::key_discovery::{KeyRow, discover_key_candidates};
 use crate::alignment::key_join::{
-    KeyAlignedRow, KeyJoinError, OwnedRecord, build_key_map, join_key_maps,
+    KeyAlignedRow, KeyChangeReport, KeyChangeTotal, KeyIntersection, KeyJoinError, OwnedRecord,
+    UnmatchedRow, build_key_map_with, join_key_intersection, join_key_maps,
 };
 use crate::alignment::key_parse::parse_key_identifier;
+use crate::alignment::key_translation::KeyTranslation;
+use crate::alignment::row_filter::{RowPredicate, row_passes};
+use crate::alignment::sample::{RowSample, sample_mask};
 use crate::alignment::shuffle::detect_shuffle;
 use crate::cli::args::Args;
-use crate::cli::exit::Outcome;
+use crate::cli::exit::{Outcome, exit_code};
 use crate::csv::blank::is_blank_record;
+use crate::csv::comment::blank_comment_lines;
+use crate::csv::compression::{Compression, decompress};
 use crate::csv::dialect::{DialectError, auto_detect};
+use crate::csv::duplicates::{DuplicateRows, dedupe_rows, find_duplicate_rows};
+use crate::csv::encoding::{EncodingMode, SourceEncoding, transcode};
+use crate::csv::footer::{FooterRule, footer_rows};
 use crate::csv::input::{
-    EncodingIssue as InputEncodingIssue, UTF32_BE_BOM, UTF32_LE_BOM, guard_input_bytes,
+    EncodingIssue as InputEncodingIssue, UTF32_BE_BOM, UTF32_LE_BOM, guard_input_bytes, skip_lines,
 };
-use crate::csv::parser::{EscapeMode, build_reader, detect_escape_mode};
+use crate::csv::parser::{EscapeMode, RecordReader, detect_escape_mode};
 use crate::csv::records::normalize_record;
 use crate::csv::sep::{SepScan, scan_first_non_blank_line};
-use crate::diff::coverage::{CoverageDecision, evaluate_coverage};
-use crate::diff::heap::DiffAccumulator;
-use crate::diff::order::{CellId, RowId, TieBreaker, sort_contributors};
-use crate::diff::tolerance::ToleranceTracker;
+use crate::csv::source::{InputSource, source_label};
+use crate::diff::chunks::{scan_chunks, worker_count};
+use crate::diff::coverage::{
+    ColumnCoverageDecision, CoverageDecision, CoverageMode, DiffusePolicy, DiffuseShortfall,
+    coverage_curve, evaluate_column_coverage, evaluate_coverage,
+};
+use crate::diff::heap::TopContributors;
+use crate::diff::intern::{Interner, Symbol};
+use crate::diff::order::{CellId, CellKind, RowId, sort_contributors};
+use crate::diff::rescale::RescaledColumn;
+use crate::diff::rollup::ColumnTotals;
+use crate::diff::text::{TextChangeReport, TextColumnChanges};
+use crate::diff::tolerance::{ToleranceSweep, column_tolerance};
 use crate::format::ident_human::render_identifier_human;
 use crate::format::ident_json::encode_identifier_json;
-use crate::normalize::headers::normalize_headers_with_aliases;
+use crate::normalize::column_map::{ColumnMapping, apply_column_map, suggest_column_maps};
+use crate::normalize::headers::{
+    DuplicateHeader, HeaderMatch, fold_header_loose, normalize_headers_with_aliases,
+};
 use crate::numeric::columns::{
-    ColumnIntersection, ColumnTypingError, Side as ColumnSide, detect_numeric_columns,
-    intersect_headers,
+    ColumnIntersection, ColumnNormalization, ColumnTyping, ColumnTypingError, CommonColumn,
+    CrossTabSignal, FieldAccess, MixedColumnProfile, Side as ColumnSide, detect_cross_tab_columns,
+    detect_cross_tab_shape, detect_numeric_columns, intersect_headers, profile_mixed_column,
 };
-use crate::numeric::missing::is_missing_token;
-use crate::numeric::parse::parse_numeric;
+use crate::numeric::dates::{DateChange, DateChangeReport, DateValues, parse_date};
+use crate::numeric::drift::DriftReport;
+use crate::numeric::missing::MissingPolicy;
+use crate::numeric::parse::{DecimalSeparator, NumberFormat, has_percent_suffix};
+use crate::numeric::schema::{SchemaChange, SchemaFingerprint, compare_schemas};
+use crate::numeric::semantic::{ColumnEvidence, ColumnSemantic, classify_columns};
+use crate::numeric::transform::{ColumnTransform, ValueTransforms};
+use crate::output::changes_csv::ChangesCsvWriter;
+use crate::output::events::{Event, EventStream};
+use crate::output::human::column_types::render_column_type_lines;
+use crate::output::human::date_changes::render_date_change_lines;
+use crate::output::human::drift::render_drift_lines;
+use crate::output::human::expected::render_expected_lines;
 use crate::output::human::header::{
     Alignment as HumanAlignment, CheckedCounts, ColumnCounts, DialectReceipt, HumanHeader,
-    Profile as HumanProfile, RefusalHeader, Settings as HumanSettings, render_real_no_real_header,
-    render_refusal_header,
+    Profile as HumanProfile, RefusalHeader, Settings as HumanSettings, map_flags,
+    render_real_no_real_header, render_refusal_header,
+};
+use crate::output::human::key_changes::render_key_change_lines;
+use crate::output::human::no_real::{
+    NoRealBody, SubtoleranceDelta, render_comment_lines_line, render_filtered_rows_line,
+    render_footer_rows_line, render_format_only_line, render_identical_files_line,
+    render_missing_cells_line, render_no_real_body, render_sampled_rows_line,
+    render_subtolerance_lines, render_uncompared_cells_line,
 };
-use crate::output::human::no_real::{NoRealBody, render_no_real_body};
 use crate::output::human::real_change::{
     RealChangeBody, RealChangeContributor, RealChangeFieldChange, render_real_change_body,
 };
 use crate::output::human::refusal::{RefusalBody, render_refusal_body};
+use crate::output::human::rescale::render_rescaled_lines;
+use crate::output::human::rollup::{
+    render_by_column_lines, render_by_row_lines, render_coverage_curve_line, render_diffuse_line,
+};
+use crate::output::human::schema::render_schema_change_lines;
+use crate::output::human::text_changes::render_text_change_lines;
+use crate::output::human::timings::render_timings_line;
+use crate::output::human::tolerance_sweep::render_tolerance_sweep_lines;
+use crate::output::human::warnings::render_warning_lines;
 use crate::output::json::{
-    Alignment as JsonAlignment, Audit as JsonAudit, Counts, Dialect, DialectSide,
-    FieldChange as JsonFieldChange, Files, JsonContext, JsonOutput, Metrics,
-    OutputMode as JsonOutputMode, Refusal as JsonRefusal,
+    Alignment as JsonAlignment, AlignmentMode as JsonAlignmentMode, Audit as JsonAudit, Counts,
+    Dialect, DialectSide, Expected as JsonExpected, FieldChange as JsonFieldChange, Files,
+    JsonContext, JsonOutput, KeyDedup as JsonKeyDedup, KeyMap as JsonKeyMap, Limits as JsonLimits,
+    Memory as JsonMemory, Metrics, OutputMode as JsonOutputMode, PhaseTimings,
+    Refusal as JsonRefusal, Sample as JsonSample, Subtolerance as JsonSubtolerance,
+    Warning as JsonWarning,
 };
 use crate::profile::{
     ColumnRegistryRunInfo, ResolveError, ResolvedProfile, load_profile_from_path,
@@ -64,12 +127,153 @@ use crate::refusal::details::{
     DelimiterHint, DialectSuggestion, EncodingIssue, FileSide, HeadersIssue, NamedDelimiter,
     RefusalDetail, RefusalKind, RerunPaths,
 };
+use crate::refusal::process::{PipelineError, ProcessError};
+use crate::trace::{Parent, phase_span};
 use capsule::{CapsuleContributor, CapsuleContributorSummary, CapsuleRunSummary};
+use expected::ExpectedBaseline;
+use identical::{identical_digest, per_side_rules};
+use memory::{InputFootprint, MemoryEstimate};
+use scan::{CellScan, RowLabel, ScanSettings};
 
 pub struct PipelineResult {
     pub outcome: Outcome,
+    /// Rendered output: the human report, or `report` serialized in JSON mode.
     pub output: String,
     pub profile: ProfileRunInfo,
+    /// The structured `rvl.v0` report (JSON mode only).
+    pub report: Option<Box<JsonOutput>>,
+    /// The refusal code when `outcome` is REFUSAL.
+    pub refusal: Option<RefusalCode>,
+    pub timings: StageTimings,
+}
+
+/// Wall-clock milliseconds spent in each pipeline stage. A run that refuses
+/// before a stage starts reports zero for it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct StageTimings {
+    /// Profile, key map, and expected-baseline loading.
+    pub setup_ms: f64,
+    /// Reading, dialect detection, and parsing of both inputs.
+    pub parse_ms: f64,
+    /// Alignment, column typing, diffing, and rendering.
+    pub diff_ms: f64,
+}
+
+impl StageTimings {
+    pub fn total_ms(&self) -> f64 {
+        self.setup_ms + self.parse_ms + self.diff_ms
+    }
+}
+
+/// Stage boundaries observed during one run.
+struct StageClock {
+    started: Instant,
+    parse_started: Option<Instant>,
+    /// The slower file's read, decompress, and transcode time.
+    read: Duration,
+    diff_started: Option<Instant>,
+    marks: PhaseMarks,
+}
+
+impl StageClock {
+    fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            parse_started: None,
+            read: Duration::ZERO,
+            diff_started: None,
+            marks: PhaseMarks::default(),
+        }
+    }
+
+    fn finish(self) -> (StageTimings, PhaseTimings) {
+        let ended = Instant::now();
+        let ms = |from: Instant, to: Instant| (to - from).as_secs_f64() * 1000.0;
+        let parse_started = self.parse_started.unwrap_or(ended);
+        let diff_started = self.diff_started.unwrap_or(ended);
+        let stages = StageTimings {
+            setup_ms: ms(self.started, parse_started),
+            parse_ms: ms(parse_started, diff_started),
+            diff_ms: ms(diff_started, ended),
+        };
+        // An unmarked phase never finished: the run stopped inside it.
+        let mark = |slot: &OnceLock<Instant>| slot.get().copied().unwrap_or(ended);
+        let (aligned, typed, diffed) = (
+            mark(&self.marks.aligned),
+            mark(&self.marks.typed),
+            mark(&self.marks.diffed),
+        );
+        let read_ms = (self.read.as_secs_f64() * 1000.0).min(stages.parse_ms);
+        let phases = PhaseTimings {
+            setup_ms: stages.setup_ms,
+            read_ms,
+            parse_ms: stages.parse_ms - read_ms,
+            align_ms: ms(diff_started, aligned),
+            type_ms: ms(aligned, typed),
+            diff_ms: ms(typed, diffed),
+            render_ms: ms(diffed, ended),
+            total_ms: ms(self.started, ended),
+        };
+        (stages, phases)
+    }
+}
+
+/// Ends of the phases after parsing, each marked once as the run passes it.
+#[derive(Default)]
+struct PhaseMarks {
+    aligned: OnceLock<Instant>,
+    typed: OnceLock<Instant>,
+    diffed: OnceLock<Instant>,
+}
+
+#[derive(Clone, Copy)]
+enum Mark {
+    Aligned,
+    Typed,
+    Diffed,
+}
+
+impl PhaseMarks {
+    fn mark(&self, mark: Mark) {
+        let slot = match mark {
+            Mark::Aligned => &self.aligned,
+            Mark::Typed => &self.typed,
+            Mark::Diffed => &self.diffed,
+        };
+        // A later set would be a second pass through the same phase; keep the first.
+        let _ = slot.set(Instant::now());
+    }
+}
+
+impl PipelineResult {
+    /// The structured report; only JSON-mode runs carry one.
+    pub fn into_report(self) -> Result<Box<JsonOutput>, PipelineError> {
+        self.report.ok_or_else(|| {
+            ProcessError::new("pipeline ran without --json and has no structured report").into()
+        })
+    }
+
+    fn human(outcome: Outcome, lines: &[String], profile: ProfileRunInfo) -> Self {
+        Self {
+            outcome,
+            output: lines.join("\n"),
+            profile,
+            report: None,
+            refusal: None,
+            timings: StageTimings::default(),
+        }
+    }
+
+    fn json(outcome: Outcome, report: JsonOutput, profile: ProfileRunInfo) -> Self {
+        Self {
+            outcome,
+            output: report.to_string().unwrap_or_else(|_| "{}".to_string()),
+            profile,
+            report: Some(Box::new(report)),
+            refusal: None,
+            timings: StageTimings::default(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -90,16 +294,54 @@ struct ActiveProfile {
     info: ProfileRunInfo,
 }
 
+/// How one file is decoded, where its rows start and end, and how its header
+/// is renamed before the intersection: `--encoding` transcoding, `--skip-rows`
+/// banner lines before the header, `--comment` lines anywhere, `--skip-footer`
+/// total rows after the data, then registry aliases, `--map` renames (old file
+/// only), and `--header-match` folding.
+#[derive(Clone, Copy)]
+struct FileRules<'a> {
+    encoding: EncodingMode,
+    comment: Option<&'a [u8]>,
+    skip_rows: usize,
+    footer: FooterRule,
+    /// Column `--skip-footer auto` inspects; the first column when unset.
+    key: Option<&'a [u8]>,
+    aliases: Option<&'a HashMap<Vec<u8>, Vec<u8>>>,
+    column_map: &'a [ColumnMapping],
+    matching: HeaderMatch,
+}
+
+#[derive(Clone)]
 struct ParsedCsv {
+    compression: Option<Compression>,
+    encoding: Option<SourceEncoding>,
+    decimal: DecimalSeparator,
     delimiter: u8,
+    /// Auto-detection confidence; `None` for `--delimiter` or `sep=`.
+    confidence: Option<f64>,
     escape: EscapeMode,
     headers: Vec<Vec<u8>>,
     records: Vec<OwnedRecord>,
+    /// 1-based source line where each record starts (for `--explore` links).
+    lines: Vec<u64>,
+    schema: SchemaFingerprint,
+    duplicates: Option<DuplicateRows>,
+    /// `--map` renames that matched an old-file header.
+    column_map: Vec<ColumnMapping>,
+    /// Trailing records dropped by `--skip-footer`.
+    footer_rows: u64,
+    /// Lines blanked by `--comment`.
+    comment_lines: u64,
+    /// Time spent reading, decompressing, and transcoding the file.
+    read_time: Duration,
 }
 
 struct RefusalPayload {
     code: RefusalCode,
     detail: RefusalDetail,
+    /// Further blocking issues found under `--collect-refusals`.
+    also: Vec<RefusalPayload>,
 }
 
 struct RefusalContext<'a> {
@@ -110,6 +352,21 @@ struct RefusalContext<'a> {
     profile: ProfileRunInfo,
     counts: Counts,
     metrics: Metrics,
+    schema_change: Option<SchemaChange>,
+    warnings: &'a [JsonWarning],
+    column_map: &'a [ColumnMapping],
+    suggested_maps: &'a [ColumnMapping],
+    drift: Option<DriftReport>,
+    rescaled_columns: Vec<RescaledColumn>,
+    tolerance_sweep: Option<ToleranceSweep>,
+    key_changes: Option<KeyChangeReport>,
+    date_changes: Option<DateChangeReport>,
+    text_changes: Option<TextChangeReport>,
+    column_types: Option<Vec<ColumnSemantic>>,
+    percent_columns: Vec<Vec<u8>>,
+    transforms: Vec<ColumnTransform>,
+    columns_excluded_mixed: Vec<Vec<u8>>,
+    column_normalizations: Vec<ColumnNormalization>,
 }
 
 #[derive(Clone, Copy)]
@@ -119,6 +376,62 @@ struct RunContext<'a> {
     dialect_new: Option<DialectReceipt>,
     rerun_paths: RerunPaths<'a>,
     active_profile: &'a ActiveProfile,
+    schema_change: Option<&'a SchemaChange>,
+    warnings: &'a [JsonWarning],
+    column_map: &'a [ColumnMapping],
+    suggested_maps: &'a [ColumnMapping],
+    key_map: Option<&'a KeyTranslation>,
+    /// Set in key mode under `--dedup sum|first`.
+    key_dedup: Option<KeyDedup>,
+    /// Set in key mode under `--sample`.
+    sample: Option<RowSample>,
+    expected: Option<&'a ExpectedBaseline>,
+    source_lines: &'a SourceLines,
+    /// Rows dropped by `--skip-footer` (old, new); set only under that flag.
+    footer_rows: Option<(u64, u64)>,
+    /// Lines skipped by `--comment` (old, new); set only under that flag.
+    comment_lines: Option<(u64, u64)>,
+    marks: &'a PhaseMarks,
+}
+
+/// Add a closing line to human output, after a blank line.
+fn append_footer_line(output: &mut String, line: &str) {
+    let trailing_newline = output.ends_with('\n');
+    output.truncate(output.trim_end_matches('\n').len());
+    output.push_str("\n\n");
+    output.push_str(line);
+    if trailing_newline {
+        output.push('\n');
+    }
+}
+
+/// Source line of every data record, by side.
+struct SourceLines {
+    old: Vec<u64>,
+    new: Vec<u64>,
+}
+
+impl SourceLines {
+    /// Lines for a pair of 1-based record numbers.
+    fn locate(&self, old_record: u64, new_record: u64) -> Option<RowLines> {
+        let line = |lines: &[u64], record: u64| {
+            lines
+                .get(usize::try_from(record).ok()?.checked_sub(1)?)
+                .copied()
+                .filter(|line| *line > 0)
+        };
+        Some(RowLines {
+            old: line(&self.old, old_record)?,
+            new: line(&self.new, new_record)?,
+        })
+    }
+}
+
+/// Where a changed row starts in each input (1-based lines).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RowLines {
+    old: u64,
+    new: u64,
 }
 
 #[derive(Clone, Debug)]
@@ -139,18 +452,98 @@ impl RowRef {
 
 impl KeyRow for OwnedRecord {
     fn field(&self, index: usize) -> &[u8] {
-        self.get(index).map(|v| v.as_slice()).unwrap_or(b"")
+        OwnedRecord::field(self, index)
+    }
+}
+
+pub fn run(args: &Args) -> Result<PipelineResult, PipelineError> {
+    if let Some(format) = args.format {
+        // Documents render from the structured report, so run in JSON mode.
+        let mut json_args = args.clone();
+        json_args.json = true;
+        json_args.format = None;
+        let mut result = run(&json_args)?;
+        if let Some(report) = result.report.as_deref() {
+            result.output = format.render(report);
+        }
+        return Ok(result);
     }
+    let mut events = match args.events.as_ref() {
+        Some(target) => EventStream::open(target)
+            .map_err(|err| ProcessError::new(format!("cannot open --events target: {err}")))?,
+        None => EventStream::disabled(),
+    };
+    let phase = phase_span!("compare", outcome);
+    let mut clock = StageClock::start();
+    let mut memory = None;
+    let mut result = run_stages(args, &mut clock, &mut memory, &mut events)?;
+    let (stages, phases) = clock.finish();
+    result.timings = stages;
+    let phases = args.timings.then_some(phases);
+    match result.report.as_deref_mut() {
+        Some(report) => {
+            if memory.is_some() || phases.is_some() {
+                report.memory = memory;
+                report.metrics.timings = phases;
+                result.output = report.to_string().unwrap_or_else(|_| "{}".to_string());
+            }
+        }
+        None => {
+            if let Some(phases) = phases {
+                append_footer_line(&mut result.output, &render_timings_line(&phases));
+            }
+        }
+    }
+    phase.label("outcome", result.outcome.as_str());
+    match result.refusal {
+        Some(code) => events.emit(&Event::Refusal {
+            code: code.as_str(),
+            message: code.reason(),
+        }),
+        None => events.emit(&Event::Verdict {
+            outcome: result.outcome.as_str(),
+            exit_code: exit_code(result.outcome),
+        }),
+    }
+    Ok(result)
 }
 
-pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
-    let old_path = args.old_path().to_string_lossy().to_string();
-    let new_path = args.new_path().to_string_lossy().to_string();
+/// `memory` receives the `--max-memory` receipt once the inputs are measured;
+/// `events` receives the in-flight lifecycle events (the terminal one is
+/// emitted by [`run`]).
+fn run_stages(
+    args: &Args,
+    clock: &mut StageClock,
+    memory: &mut Option<JsonMemory>,
+    events: &mut EventStream,
+) -> Result<PipelineResult, PipelineError> {
+    let old_path = args.old_source().rerun_path("OLD_CSV");
+    let new_path = args.new_source().rerun_path("NEW_CSV");
     let rerun_paths = RerunPaths {
         old: &old_path,
         new: &new_path,
     };
 
+    if args.old_source().is_stdin() && args.new_source().is_stdin() {
+        let refusal = RefusalPayload::with_default_next(
+            RefusalCode::Io,
+            RefusalKind::Io {
+                file: FileSide::New,
+                error: "stdin (-) can supply only one of the two inputs".to_string(),
+            },
+            rerun_paths,
+        );
+        return Ok(render_refusal(
+            refusal,
+            args,
+            None,
+            None,
+            None,
+            &ProfileRunInfo::default(),
+            None,
+        ));
+    }
+
     if args.audit_fields && !args.exhaustive {
         let refusal = RefusalPayload::with_default_next(
             RefusalCode::AuditFieldsRequiresExhaustive,
@@ -164,6 +557,7 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
             None,
             None,
             &ProfileRunInfo::default(),
+            None,
         ));
     }
 
@@ -177,6 +571,7 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
                 None,
                 None,
                 &ProfileRunInfo::default(),
+                None,
             ));
         }
     };
@@ -194,10 +589,11 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
             None,
             None,
             &active_profile.info,
+            None,
         ));
     }
 
-    let cli_key = match args.key.as_deref() {
+    let cli_key = match args.key_column() {
         Some(key) => Some(parse_key_identifier(key)?),
         None => None,
     };
@@ -205,7 +601,7 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
         let refusal = RefusalPayload::with_default_next(
             RefusalCode::KeyConflict,
             RefusalKind::KeyConflict {
-                key_flag: args.key.clone().unwrap_or_default(),
+                key_flag: args.key_column().unwrap_or_default().to_string(),
                 profile_key: active_profile.key_labels.clone(),
             },
             rerun_paths,
@@ -217,38 +613,222 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
             None,
             None,
             &active_profile.info,
+            None,
         ));
     }
-    let key_bytes = cli_key.or_else(|| active_profile.key.clone());
+    let key_bytes =
+        cli_key
+            .or_else(|| active_profile.key.clone())
+            .map(|key| match args.header_match {
+                HeaderMatch::Exact => key,
+                HeaderMatch::Loose => fold_header_loose(&key),
+            });
+    if args.sample.is_some() && key_bytes.is_none() {
+        return Err(ProcessError::new(
+            "--sample needs key mode (--key or a profile key): rows are sampled by key hash",
+        )
+        .into());
+    }
 
-    let old = match parse_csv(
-        args.old_path(),
-        FileSide::Old,
-        args.delimiter,
-        rerun_paths,
-        active_profile.header_aliases.as_ref(),
-    ) {
-        Ok(parsed) => parsed,
+    let key_map = match args.key_map.as_deref() {
+        Some(path) => {
+            let loaded = if key_bytes.is_some() {
+                KeyTranslation::load(path)
+            } else {
+                Err("requires key mode (--key or a profile key)".to_string())
+            };
+            match loaded {
+                Ok(map) => Some(map),
+                Err(reason) => {
+                    let refusal = RefusalPayload::with_default_next(
+                        RefusalCode::KeyMap,
+                        RefusalKind::KeyMap {
+                            file: path.to_string_lossy().to_string(),
+                            reason,
+                        },
+                        rerun_paths,
+                    );
+                    return Ok(render_refusal(
+                        refusal,
+                        args,
+                        key_bytes.as_deref(),
+                        None,
+                        None,
+                        &active_profile.info,
+                        None,
+                    ));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let expected = match args.expected.as_deref().map(ExpectedBaseline::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(reason)) => {
+            let refusal = RefusalPayload::with_default_next(
+                RefusalCode::Expected,
+                RefusalKind::Expected {
+                    file: args
+                        .expected
+                        .as_deref()
+                        .map(|path| path.to_string_lossy().to_string())
+                        .unwrap_or_default(),
+                    reason,
+                },
+                rerun_paths,
+            );
+            return Ok(render_refusal(
+                refusal,
+                args,
+                key_bytes.as_deref(),
+                None,
+                None,
+                &active_profile.info,
+                None,
+            ));
+        }
+        None => None,
+    };
+
+    if let Some(max_memory) = args.max_memory {
+        // An unreadable input is left for the parse to refuse with E_IO.
+        if let (Ok(old), Ok(new)) = (
+            InputFootprint::measure(args.old_source()),
+            InputFootprint::measure(args.new_source()),
+        ) {
+            let estimate = MemoryEstimate::new(old, new, key_bytes.is_some());
+            *memory = Some(JsonMemory {
+                estimated_peak_bytes: estimate.peak_bytes,
+                max_memory,
+            });
+            if estimate.peak_bytes > max_memory {
+                let refusal = RefusalPayload::with_default_next(
+                    RefusalCode::Resource,
+                    RefusalKind::Resource {
+                        estimated_bytes: estimate.peak_bytes,
+                        max_memory,
+                        row_order_bytes: estimate.row_order_bytes,
+                    },
+                    rerun_paths,
+                );
+                return Ok(render_refusal(
+                    refusal,
+                    args,
+                    key_bytes.as_deref(),
+                    None,
+                    None,
+                    &active_profile.info,
+                    None,
+                ));
+            }
+        }
+    }
+
+    // The two inputs are independent until alignment, so parse them concurrently.
+    // Refusal precedence stays deterministic: an old-file refusal wins over a
+    // new-file refusal regardless of which thread finishes first.
+    let new_file_rules = FileRules {
+        encoding: args.encoding,
+        comment: args.comment.as_deref().map(str::as_bytes),
+        skip_rows: args.skip_rows.new,
+        footer: args.skip_footer.new_rule(),
+        key: key_bytes.as_deref(),
+        aliases: active_profile.header_aliases.as_ref(),
+        column_map: &[],
+        matching: args.header_match,
+    };
+    let old_file_rules = FileRules {
+        skip_rows: args.skip_rows.old,
+        footer: args.skip_footer.old_rule(),
+        column_map: &args.column_map,
+        ..new_file_rules
+    };
+    clock.parse_started = Some(Instant::now());
+    events.emit(&Event::ParseStarted {
+        old: &args.old_path().to_string_lossy(),
+        new: &args.new_path().to_string_lossy(),
+    });
+    let parent = Parent::current();
+    let (old_raw, new_raw) = thread::scope(|scope| {
+        let new_handle = scope.spawn(|| {
+            parent.in_scope(|| read_input(args.new_source(), FileSide::New, rerun_paths))
+        });
+        let old_raw = read_input(args.old_source(), FileSide::Old, rerun_paths);
+        let new_raw = new_handle
+            .join()
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload));
+        (old_raw, new_raw)
+    });
+    // Compared on the bytes the parse reads anyway, so a pair that differs
+    // costs only an in-memory comparison, never an extra read.
+    let identical = match (&old_raw, &new_raw) {
+        (Ok(old), Ok(new)) if !per_side_rules(args) => identical_digest(&old.bytes, &new.bytes),
+        _ => None,
+    };
+    let parse = |raw: Result<RawInput<'_>, Box<RefusalPayload>>, file_side, file_rules| {
+        raw.and_then(|raw| {
+            parse_raw(
+                &raw,
+                file_side,
+                args.delimiter,
+                rerun_paths,
+                file_rules,
+                args.number_format(),
+                args.dedupe_rows,
+            )
+        })
+    };
+    let (old_parsed, new_parsed) = if identical.is_some() {
+        // Identical bytes under identical rules parse identically, so one parse
+        // serves both sides and every refusal and count matches a full run.
+        let old_parsed = parse(old_raw, FileSide::Old, old_file_rules);
+        let new_parsed = match &old_parsed {
+            Ok(parsed) => Ok(parsed.clone()),
+            Err(_) => parse(new_raw, FileSide::New, new_file_rules),
+        };
+        (old_parsed, new_parsed)
+    } else {
+        thread::scope(|scope| {
+            let new_handle =
+                scope.spawn(|| parent.in_scope(|| parse(new_raw, FileSide::New, new_file_rules)));
+            let old_parsed = parse(old_raw, FileSide::Old, old_file_rules);
+            let new_parsed = new_handle
+                .join()
+                .unwrap_or_else(|payload| std::panic::resume_unwind(payload));
+            (old_parsed, new_parsed)
+        })
+    };
+
+    let mut old = match old_parsed {
+        Ok(parsed) => {
+            emit_dialect(events, FileSide::Old, &parsed);
+            parsed
+        }
         Err(refusal) => {
+            let mut refusal = *refusal;
+            if args.collect_refusals
+                && let Err(new_refusal) = new_parsed
+            {
+                refusal.also.push(*new_refusal);
+            }
             return Ok(render_refusal(
-                *refusal,
+                refusal,
                 args,
                 key_bytes.as_deref(),
                 None,
                 None,
                 &active_profile.info,
+                None,
             ));
         }
     };
 
-    let new = match parse_csv(
-        args.new_path(),
-        FileSide::New,
-        args.delimiter,
-        rerun_paths,
-        active_profile.header_aliases.as_ref(),
-    ) {
-        Ok(parsed) => parsed,
+    let mut new = match new_parsed {
+        Ok(parsed) => {
+            emit_dialect(events, FileSide::New, &parsed);
+            parsed
+        }
         Err(refusal) => {
             return Ok(render_refusal(
                 *refusal,
@@ -257,30 +837,222 @@ pub fn run(args: &Args) -> Result<PipelineResult, Box<dyn Error>> {
                 Some(dialect_receipt(&old)),
                 None,
                 &active_profile.info,
+                None,
             ));
         }
     };
 
+    // Differently detected delimiters usually mean one side was misread.
+    let dialect_mismatch = old.delimiter != new.delimiter;
+    if dialect_mismatch && args.strict_dialect {
+        let refusal = RefusalPayload::with_default_next(
+            RefusalCode::Dialect,
+            RefusalKind::DialectMismatch {
+                old_delimiter: old.delimiter,
+                new_delimiter: new.delimiter,
+            },
+            rerun_paths,
+        );
+        return Ok(render_refusal(
+            refusal,
+            args,
+            key_bytes.as_deref(),
+            Some(dialect_receipt(&old)),
+            Some(dialect_receipt(&new)),
+            &active_profile.info,
+            None,
+        ));
+    }
+
+    clock.read = old.read_time.max(new.read_time);
+    clock.diff_started = Some(Instant::now());
     let dialect_old = Some(dialect_receipt(&old));
     let dialect_new = Some(dialect_receipt(&new));
+    let schema_change = compare_schemas(&old.schema, &new.schema);
+    let mut warnings = duplicate_row_warnings(&old, &new, args.dedupe_rows);
+    if dialect_mismatch {
+        warnings.insert(
+            0,
+            JsonWarning::dialect_mismatch(old.delimiter, new.delimiter),
+        );
+    }
+    let source_lines = SourceLines {
+        old: std::mem::take(&mut old.lines),
+        new: std::mem::take(&mut new.lines),
+    };
+    let column_map = std::mem::take(&mut old.column_map);
+    let leftover = scope_intersection(
+        intersect_headers(&old.headers, &new.headers, key_bytes.as_deref()),
+        active_profile.include_scope.as_ref(),
+    );
+    let suggested_maps = suggest_column_maps(&leftover.old_only, &leftover.new_only);
     let context = RunContext {
         args,
         dialect_old,
         dialect_new,
         rerun_paths,
         active_profile: &active_profile,
+        schema_change: schema_change.as_ref(),
+        warnings: &warnings,
+        column_map: &column_map,
+        suggested_maps: &suggested_maps,
+        key_map: key_map.as_ref(),
+        key_dedup: None,
+        sample: None,
+        expected: expected.as_ref(),
+        source_lines: &source_lines,
+        footer_rows: args
+            .skip_footer
+            .is_set()
+            .then_some((old.footer_rows, new.footer_rows)),
+        comment_lines: args
+            .comment
+            .is_some()
+            .then_some((old.comment_lines, new.comment_lines)),
+        marks: &clock.marks,
     };
 
-    if let Some(key) = key_bytes.as_deref() {
+    if args.schema_only {
+        return Ok(render_schema_only(
+            key_bytes.as_deref(),
+            &old,
+            &new,
+            context,
+        ));
+    }
+
+    if let Some(result) = header_overlap_refusal(&old, &new, key_bytes.as_deref(), context) {
+        return Ok(result);
+    }
+
+    let result = if let Some(key) = key_bytes.as_deref() {
         run_key_mode(key, old, new, context)
     } else {
         run_row_order(old, new, context)
+    }?;
+    Ok(match identical {
+        Some(digest) => mark_identical_files(result, &digest),
+        None => result,
+    })
+}
+
+/// Refuse when so few header names match that comparing the intersection
+/// would silently ignore most of both files.
+fn header_overlap_refusal(
+    old: &ParsedCsv,
+    new: &ParsedCsv,
+    key: Option<&[u8]>,
+    context: RunContext<'_>,
+) -> Option<PipelineResult> {
+    let args = context.args;
+    let include_scope = context.active_profile.include_scope.as_ref();
+    let intersection = scope_intersection(
+        intersect_headers(&old.headers, &new.headers, key),
+        include_scope,
+    );
+    let overlap = intersection.overlap()?;
+    if overlap >= args.min_header_overlap {
+        return None;
     }
+
+    let counts = Counts {
+        rows_old: Some(old.records.len() as u64),
+        rows_new: Some(new.records.len() as u64),
+        columns_old: Some(count_columns(&old.headers, key, include_scope)),
+        columns_new: Some(count_columns(&new.headers, key, include_scope)),
+        columns_common: Some(intersection.common.len() as u64),
+        columns_old_only: Some(intersection.old_only.len() as u64),
+        columns_new_only: Some(intersection.new_only.len() as u64),
+        footer_rows_old: context.footer_rows.map(|rows| rows.0),
+        footer_rows_new: context.footer_rows.map(|rows| rows.1),
+        comment_lines_old: context.comment_lines.map(|lines| lines.0),
+        comment_lines_new: context.comment_lines.map(|lines| lines.1),
+        ..Counts::default()
+    };
+    let refusal = RefusalPayload::with_default_next(
+        RefusalCode::HeaderOverlap,
+        RefusalKind::HeaderOverlap {
+            columns_old: counts.columns_old.unwrap_or(0),
+            columns_new: counts.columns_new.unwrap_or(0),
+            columns_common: intersection.common.len() as u64,
+            overlap,
+            min_overlap: args.min_header_overlap,
+            map_flags: map_flags(context.suggested_maps),
+        },
+        context.rerun_paths,
+    );
+    let alignment = match key {
+        Some(key) => key_alignment(
+            context.args,
+            key,
+            context.key_map,
+            context.key_dedup,
+            context.sample,
+        ),
+        None => JsonAlignment::row_order(),
+    };
+    let context = RefusalContext {
+        key,
+        dialect_old: context.dialect_old,
+        dialect_new: context.dialect_new,
+        alignment,
+        profile: context.active_profile.info.clone(),
+        counts,
+        metrics: Metrics::default(),
+        schema_change: context.schema_change.cloned(),
+        warnings: context.warnings,
+        column_map: context.column_map,
+        suggested_maps: context.suggested_maps,
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
+    };
+    Some(render_refusal_with_context(refusal, args, context))
 }
 
+fn duplicate_row_warnings(old: &ParsedCsv, new: &ParsedCsv, deduped: bool) -> Vec<JsonWarning> {
+    [("old", old), ("new", new)]
+        .into_iter()
+        .filter_map(|(file, parsed)| {
+            parsed
+                .duplicates
+                .as_ref()
+                .map(|duplicates| JsonWarning::duplicate_rows(file, duplicates, deduped))
+        })
+        .collect()
+}
+
+/// The profile (if any) with `--columns` narrowing its column scope.
 fn resolve_active_profile(
     args: &Args,
     rerun_paths: RerunPaths<'_>,
+) -> Result<ActiveProfile, Box<RefusalPayload>> {
+    let mut active = resolve_profile(args, rerun_paths)?;
+    if !args.columns.is_empty() {
+        let selected: HashSet<Vec<u8>> = args
+            .columns
+            .iter()
+            .map(|column| column.as_bytes().to_vec())
+            .collect();
+        active.include_scope = Some(match active.include_scope.take() {
+            Some(scope) => scope.intersection(&selected).cloned().collect(),
+            None => selected,
+        });
+    }
+    Ok(active)
+}
+
+fn resolve_profile(
+    args: &Args,
+    rerun_paths: RerunPaths<'_>,
 ) -> Result<ActiveProfile, Box<RefusalPayload>> {
     if let (Some(profile_path), Some(profile_id)) =
         (args.profile.as_ref(), args.profile_id.as_ref())
@@ -389,13 +1161,15 @@ fn run_key_mode(
     key: &[u8],
     old: ParsedCsv,
     new: ParsedCsv,
-    context: RunContext<'_>,
-) -> Result<PipelineResult, Box<dyn Error>> {
+    mut context: RunContext<'_>,
+) -> Result<PipelineResult, PipelineError> {
     let args = context.args;
     let dialect_old = context.dialect_old;
     let dialect_new = context.dialect_new;
     let rerun_paths = context.rerun_paths;
     let active_profile = context.active_profile;
+    let phase = phase_span!("align", mode, rows_aligned, rows_removed, rows_added);
+    phase.label("mode", "key");
 
     let old_key_index = match find_key_index(&old.headers, key) {
         Some(index) => index,
@@ -414,6 +1188,7 @@ fn run_key_mode(
                 dialect_old,
                 dialect_new,
                 &active_profile.info,
+                Some(context),
             ));
         }
     };
@@ -435,6 +1210,7 @@ fn run_key_mode(
                 dialect_old,
                 dialect_new,
                 &active_profile.info,
+                Some(context),
             ));
         }
     };
@@ -442,16 +1218,46 @@ fn run_key_mode(
     let rows_old = old.records.len() as u64;
     let rows_new = new.records.len() as u64;
 
-    let old_map = match build_key_map(
-        old.records
-            .into_iter()
-            .enumerate()
-            .map(|(idx, record)| ((idx + 1) as u64, record)),
+    let mut old_records = old.records;
+    if let Some(key_map) = context.key_map {
+        key_map.apply(&mut old_records, old_key_index);
+    }
+    let number_format = args.number_format();
+    let dedup = args.dedup_mode();
+    let (old_records, collapsed_old) = collapse_duplicate_keys(
+        old_records,
         old_key_index,
-    ) {
+        &args.key_normalize,
+        dedup,
+        number_format,
+    );
+    let (new_records, collapsed_new) = collapse_duplicate_keys(
+        new.records,
+        new_key_index,
+        &args.key_normalize,
+        dedup,
+        number_format,
+    );
+    if dedup != DedupMode::Refuse {
+        context.key_dedup = Some(KeyDedup {
+            mode: dedup,
+            collapsed_old,
+            collapsed_new,
+        });
+    }
+
+    let old_map = match build_key_map_with(old_records, old_key_index, &args.key_normalize) {
         Ok(map) => map,
         Err(err) => {
-            let refusal = map_key_join_error(err, FileSide::Old, key, rerun_paths);
+            let mut refusal = map_key_join_error(err, FileSide::Old, key, rerun_paths);
+            if args.collect_refusals
+                && let Err(err) =
+                    build_key_map_with(new_records, new_key_index, &args.key_normalize)
+            {
+                refusal
+                    .also
+                    .push(map_key_join_error(err, FileSide::New, key, rerun_paths));
+            }
             return Ok(render_refusal(
                 refusal,
                 args,
@@ -459,17 +1265,12 @@ fn run_key_mode(
                 dialect_old,
                 dialect_new,
                 &active_profile.info,
+                Some(context),
             ));
         }
     };
 
-    let new_map = match build_key_map(
-        new.records
-            .into_iter()
-            .enumerate()
-            .map(|(idx, record)| ((idx + 1) as u64, record)),
-        new_key_index,
-    ) {
+    let new_map = match build_key_map_with(new_records, new_key_index, &args.key_normalize) {
         Ok(map) => map,
         Err(err) => {
             let refusal = map_key_join_error(err, FileSide::New, key, rerun_paths);
@@ -480,12 +1281,21 @@ fn run_key_mode(
                 dialect_old,
                 dialect_new,
                 &active_profile.info,
+                Some(context),
             ));
         }
     };
 
-    let aligned = match join_key_maps(old_map, new_map) {
-        Ok(rows) => rows,
+    let joined = if args.allows_missing_keys() {
+        Ok(join_key_intersection(old_map, new_map))
+    } else {
+        join_key_maps(old_map, new_map).map(|aligned| KeyIntersection {
+            aligned,
+            ..KeyIntersection::default()
+        })
+    };
+    let joined = match joined {
+        Ok(joined) => joined,
         Err(err) => {
             let refusal = map_key_join_error(err, FileSide::New, key, rerun_paths);
             return Ok(render_refusal(
@@ -495,16 +1305,25 @@ fn run_key_mode(
                 dialect_old,
                 dialect_new,
                 &active_profile.info,
+                Some(context),
             ));
         }
     };
 
+    phase.count("rows_aligned", joined.aligned.len() as u64);
+    phase.count("rows_removed", joined.removed.len() as u64);
+    phase.count("rows_added", joined.added.len() as u64);
+    context.marks.mark(Mark::Aligned);
+    drop(phase);
+
     run_diff(
         AlignmentContext::Key {
             key: key.to_vec(),
             rows_old,
             rows_new,
-            key_rows: aligned,
+            key_rows: joined.aligned,
+            removed: joined.removed,
+            added: joined.added,
         },
         old.headers,
         new.headers,
@@ -516,14 +1335,17 @@ fn run_row_order(
     old: ParsedCsv,
     new: ParsedCsv,
     context: RunContext<'_>,
-) -> Result<PipelineResult, Box<dyn Error>> {
+) -> Result<PipelineResult, PipelineError> {
     let args = context.args;
     let dialect_old = context.dialect_old;
     let dialect_new = context.dialect_new;
     let rerun_paths = context.rerun_paths;
     let active_profile = context.active_profile;
+    let phase = phase_span!("align", mode, rows_aligned);
+    phase.label("mode", "row_order");
 
-    if old.records.len() != new.records.len() {
+    let trailing = old.records.len().abs_diff(new.records.len()) as u64;
+    if trailing > args.allow_trailing_rows {
         let suggested_keys = discover_key_candidates(
             &old.headers,
             &new.headers,
@@ -565,6 +1387,10 @@ fn run_row_order(
             columns_common: Some(intersection.common.len() as u64),
             columns_old_only: Some(intersection.old_only.len() as u64),
             columns_new_only: Some(intersection.new_only.len() as u64),
+            footer_rows_old: context.footer_rows.map(|rows| rows.0),
+            footer_rows_new: context.footer_rows.map(|rows| rows.1),
+            comment_lines_old: context.comment_lines.map(|lines| lines.0),
+            comment_lines_new: context.comment_lines.map(|lines| lines.1),
             ..Counts::default()
         };
         let context = RefusalContext {
@@ -575,10 +1401,32 @@ fn run_row_order(
             profile: active_profile.info.clone(),
             counts,
             metrics: Metrics::default(),
+            schema_change: context.schema_change.cloned(),
+            warnings: context.warnings,
+            column_map: context.column_map,
+            suggested_maps: context.suggested_maps,
+            drift: None,
+            rescaled_columns: Vec::new(),
+            tolerance_sweep: None,
+            key_changes: None,
+            date_changes: None,
+            text_changes: None,
+            column_types: None,
+            percent_columns: Vec::new(),
+            transforms: Vec::new(),
+            columns_excluded_mixed: Vec::new(),
+            column_normalizations: Vec::new(),
         };
         return Ok(render_refusal_with_context(refusal, args, context));
     }
 
+    phase.count(
+        "rows_aligned",
+        old.records.len().min(new.records.len()) as u64,
+    );
+    context.marks.mark(Mark::Aligned);
+    drop(phase);
+
     run_diff(
         AlignmentContext::RowOrder {
             old_rows: old.records,
@@ -596,35 +1444,370 @@ enum AlignmentContext {
         rows_old: u64,
         rows_new: u64,
         key_rows: Vec<KeyAlignedRow>,
+        /// Old-only rows; empty unless `--allow-missing-keys`.
+        removed: Vec<UnmatchedRow>,
+        /// New-only rows; empty unless `--allow-missing-keys`.
+        added: Vec<UnmatchedRow>,
     },
+    /// Rows pair up by position; rows past the shorter file are trailing
+    /// rows (unequal lengths only under `--allow-trailing-rows`).
     RowOrder {
         old_rows: Vec<OwnedRecord>,
         new_rows: Vec<OwnedRecord>,
     },
 }
 
+/// Drop rows failing `--where` (`columns[i]` locates predicate `i` in both
+/// files): aligned pairs when either side fails, unmatched and trailing rows
+/// on their own side. Returns the rows dropped from each file.
+fn filter_alignment(
+    alignment: AlignmentContext,
+    predicates: &[RowPredicate],
+    columns: &[CommonColumn],
+) -> (AlignmentContext, (u64, u64)) {
+    let old_indices: Vec<usize> = columns.iter().map(|column| column.old_index).collect();
+    let new_indices: Vec<usize> = columns.iter().map(|column| column.new_index).collect();
+    let keep_old = |record: &OwnedRecord| row_passes(predicates, &old_indices, record);
+    let keep_new = |record: &OwnedRecord| row_passes(predicates, &new_indices, record);
+    match alignment {
+        AlignmentContext::Key {
+            key,
+            rows_old,
+            rows_new,
+            mut key_rows,
+            mut removed,
+            mut added,
+        } => {
+            let before = (removed.len() + key_rows.len(), added.len() + key_rows.len());
+            key_rows.retain(|row| keep_old(&row.old.fields) && keep_new(&row.new.fields));
+            removed.retain(|row| keep_old(&row.entry.fields));
+            added.retain(|row| keep_new(&row.entry.fields));
+            let dropped = (
+                (before.0 - removed.len() - key_rows.len()) as u64,
+                (before.1 - added.len() - key_rows.len()) as u64,
+            );
+            let alignment = AlignmentContext::Key {
+                key,
+                rows_old,
+                rows_new,
+                key_rows,
+                removed,
+                added,
+            };
+            (alignment, dropped)
+        }
+        AlignmentContext::RowOrder {
+            mut old_rows,
+            mut new_rows,
+        } => {
+            let before = (old_rows.len(), new_rows.len());
+            let aligned = old_rows.len().min(new_rows.len());
+            let mut old_trailing = old_rows.split_off(aligned);
+            let mut new_trailing = new_rows.split_off(aligned);
+            let (mut old_rows, mut new_rows): (Vec<OwnedRecord>, Vec<OwnedRecord>) = old_rows
+                .into_iter()
+                .zip(new_rows)
+                .filter(|(old, new)| keep_old(old) && keep_new(new))
+                .unzip();
+            old_trailing.retain(|row| keep_old(row));
+            new_trailing.retain(|row| keep_new(row));
+            old_rows.append(&mut old_trailing);
+            new_rows.append(&mut new_trailing);
+            let dropped = (
+                (before.0 - old_rows.len()) as u64,
+                (before.1 - new_rows.len()) as u64,
+            );
+            (AlignmentContext::RowOrder { old_rows, new_rows }, dropped)
+        }
+    }
+}
+
+/// Keep the `--sample` rows among the aligned key rows, in their original
+/// order. `--sample` without a key is rejected before parsing, so row-order
+/// alignments pass through.
+fn sample_alignment(
+    alignment: AlignmentContext,
+    size: u64,
+    seed: u64,
+) -> (AlignmentContext, Option<RowSample>) {
+    match alignment {
+        AlignmentContext::Key {
+            key,
+            rows_old,
+            rows_new,
+            key_rows,
+            removed,
+            added,
+        } => {
+            let rows_eligible = key_rows.len() as u64;
+            let mask = sample_mask(key_rows.iter().map(|row| row.key.as_slice()), size, seed);
+            let key_rows: Vec<KeyAlignedRow> = key_rows
+                .into_iter()
+                .zip(mask)
+                .filter_map(|(row, keep)| keep.then_some(row))
+                .collect();
+            let sample = RowSample {
+                size,
+                seed,
+                rows_eligible,
+                rows_sampled: key_rows.len() as u64,
+            };
+            let alignment = AlignmentContext::Key {
+                key,
+                rows_old,
+                rows_new,
+                key_rows,
+                removed,
+                added,
+            };
+            (alignment, Some(sample))
+        }
+        row_order @ AlignmentContext::RowOrder { .. } => (row_order, None),
+    }
+}
+
+/// Added/removed rows under `--allow-missing-keys`, or trailing rows under
+/// `--allow-trailing-rows`, with per-column totals when values may be shown.
+fn key_change_report(
+    alignment: &AlignmentContext,
+    numeric_columns: &[CommonColumn],
+    transforms: &[ValueTransforms],
+    args: &Args,
+) -> Option<KeyChangeReport> {
+    let (removed, added, trailing): (Vec<&OwnedRecord>, Vec<&OwnedRecord>, bool) = match alignment {
+        AlignmentContext::Key { removed, added, .. } if args.allows_missing_keys() => (
+            removed.iter().map(|row| &row.entry.fields).collect(),
+            added.iter().map(|row| &row.entry.fields).collect(),
+            false,
+        ),
+        AlignmentContext::RowOrder { old_rows, new_rows } if old_rows.len() != new_rows.len() => {
+            let aligned = old_rows.len().min(new_rows.len());
+            (
+                old_rows[aligned..].iter().collect(),
+                new_rows[aligned..].iter().collect(),
+                true,
+            )
+        }
+        _ => return None,
+    };
+    let sum = |rows: &[&OwnedRecord], index: usize, transform: &dyn Fn(f64) -> f64| {
+        rows.iter()
+            .filter_map(|row| row.get(index))
+            .filter(|raw| !args.number_format().is_missing(raw))
+            .filter_map(|raw| args.number_format().parse(raw))
+            .map(transform)
+            .sum::<f64>()
+    };
+    let totals = args.explicit.then(|| {
+        numeric_columns
+            .iter()
+            .zip(transforms)
+            .map(|(column, transforms)| KeyChangeTotal {
+                column: column.name.clone(),
+                added: sum(&added, column.new_index, &|value| {
+                    transforms.apply_new(value)
+                }),
+                removed: sum(&removed, column.old_index, &|value| {
+                    transforms.apply_old(value)
+                }),
+            })
+            .collect()
+    });
+    Some(KeyChangeReport {
+        rows_added: added.len() as u64,
+        rows_removed: removed.len() as u64,
+        totals,
+        ranked: !trailing && args.include_entity_changes,
+        trailing,
+    })
+}
+
+/// Common non-numeric columns whose aligned cells are all dates (missing
+/// tokens aside), with their changed cells, largest shifts first and capped
+/// at `--top`. `None` when no such column exists.
+fn date_change_report(
+    alignment: &AlignmentContext,
+    candidates: &[CommonColumn],
+    args: &Args,
+) -> Option<DateChangeReport> {
+    // `Some(seen_a_date)` while every cell so far is a date; `None` after one is not.
+    let mut dates = vec![Some(false); candidates.len()];
+    for_each_aligned_row(alignment, |old_row, new_row| {
+        for (column, seen) in candidates.iter().zip(dates.iter_mut()) {
+            let sides = [old_row.get(column.old_index), new_row.get(column.new_index)];
+            for raw in sides.into_iter().flatten() {
+                if seen.is_none() || args.number_format().is_missing(raw) {
+                    continue;
+                }
+                *seen = parse_date(raw).map(|_| true);
+            }
+        }
+    });
+    let columns: Vec<&CommonColumn> = candidates
+        .iter()
+        .zip(&dates)
+        .filter(|(_, seen)| **seen == Some(true))
+        .map(|(column, _)| column)
+        .collect();
+    if columns.is_empty() {
+        return None;
+    }
+
+    let mut changes = Vec::new();
+    let visit = |row_id: &[u8], old_row: &OwnedRecord, new_row: &OwnedRecord| {
+        for column in &columns {
+            let old = old_row.get(column.old_index).and_then(parse_date);
+            let new = new_row.get(column.new_index).and_then(parse_date);
+            if let (Some(old), Some(new)) = (old, new)
+                && old != new
+            {
+                changes.push(DateChange {
+                    row_id: row_id.to_vec(),
+                    column: column.name.clone(),
+                    later: new > old,
+                    values: Some(DateValues { old, new }),
+                });
+            }
+        }
+    };
+    for_each_labelled_row(alignment, visit);
+
+    let cells_changed = changes.len() as u64;
+    changes.sort_by_key(|change| {
+        std::cmp::Reverse(change.values.map_or(0, |values| values.shift_days().abs()))
+    });
+    changes.truncate(args.top);
+    if !args.explicit {
+        for change in &mut changes {
+            change.values = None;
+        }
+    }
+    Some(DateChangeReport {
+        columns: columns.iter().map(|column| column.name.clone()).collect(),
+        cells_changed,
+        changes,
+    })
+}
+
 fn run_diff(
     alignment: AlignmentContext,
     old_headers: Vec<Vec<u8>>,
     new_headers: Vec<Vec<u8>>,
-    context: RunContext<'_>,
-) -> Result<PipelineResult, Box<dyn Error>> {
+    mut context: RunContext<'_>,
+) -> Result<PipelineResult, PipelineError> {
     let args = context.args;
     let dialect_old = context.dialect_old;
     let dialect_new = context.dialect_new;
     let rerun_paths = context.rerun_paths;
     let active_profile = context.active_profile;
+    let number_format = args.number_format();
+    let missing = args.on_missing;
 
     let key_bytes = match &alignment {
-        AlignmentContext::Key { key, .. } => Some(key.as_slice()),
+        AlignmentContext::Key { key, .. } => Some(key.clone()),
         AlignmentContext::RowOrder { .. } => None,
     };
 
     let intersection = scope_intersection(
-        intersect_headers(&old_headers, &new_headers, key_bytes),
+        intersect_headers(&old_headers, &new_headers, key_bytes.as_deref()),
         active_profile.include_scope.as_ref(),
     );
 
+    let ranking = args.ranking();
+    let weight_column = match ranking.weight_column() {
+        Some(name) => match find_named_column(name, &old_headers, &new_headers) {
+            Ok(column) => Some(column),
+            Err(file) => {
+                let refusal = RefusalPayload::with_default_next(
+                    RefusalCode::Headers,
+                    RefusalKind::Headers {
+                        file,
+                        issue: HeadersIssue::MissingColumn {
+                            name: name.to_vec(),
+                        },
+                    },
+                    rerun_paths,
+                );
+                return Ok(render_refusal(
+                    refusal,
+                    args,
+                    key_bytes.as_deref(),
+                    dialect_old,
+                    dialect_new,
+                    &active_profile.info,
+                    Some(context),
+                ));
+            }
+        },
+        None => None,
+    };
+
+    let mut where_columns = Vec::with_capacity(args.where_filters.len());
+    for predicate in &args.where_filters {
+        match find_named_column(&predicate.column, &old_headers, &new_headers) {
+            Ok(column) => where_columns.push(column),
+            Err(file) => {
+                let refusal = RefusalPayload::with_default_next(
+                    RefusalCode::Headers,
+                    RefusalKind::Headers {
+                        file,
+                        issue: HeadersIssue::MissingColumn {
+                            name: predicate.column.clone(),
+                        },
+                    },
+                    rerun_paths,
+                );
+                return Ok(render_refusal(
+                    refusal,
+                    args,
+                    key_bytes.as_deref(),
+                    dialect_old,
+                    dialect_new,
+                    &active_profile.info,
+                    Some(context),
+                ));
+            }
+        }
+    }
+    for transform in &args.transforms {
+        if let Err(file) = find_named_column(&transform.column, &old_headers, &new_headers) {
+            let refusal = RefusalPayload::with_default_next(
+                RefusalCode::Headers,
+                RefusalKind::Headers {
+                    file,
+                    issue: HeadersIssue::MissingColumn {
+                        name: transform.column.clone(),
+                    },
+                },
+                rerun_paths,
+            );
+            return Ok(render_refusal(
+                refusal,
+                args,
+                key_bytes.as_deref(),
+                dialect_old,
+                dialect_new,
+                &active_profile.info,
+                Some(context),
+            ));
+        }
+    }
+    let (alignment, rows_filtered) = if args.where_filters.is_empty() {
+        (alignment, None)
+    } else {
+        let (alignment, dropped) = filter_alignment(alignment, &args.where_filters, &where_columns);
+        (alignment, Some(dropped))
+    };
+    let (filtered_old, filtered_new) = rows_filtered.unwrap_or_default();
+    let alignment = match args.sample {
+        Some(size) => {
+            let (alignment, sample) = sample_alignment(alignment, size, args.seed);
+            context.sample = sample;
+            alignment
+        }
+        None => alignment,
+    };
+
     let (rows_old, rows_new, rows_aligned) = match &alignment {
         AlignmentContext::Key {
             rows_old,
@@ -634,70 +1817,103 @@ fn run_diff(
         } => (*rows_old, *rows_new, key_rows.len() as u64),
         AlignmentContext::RowOrder { old_rows, new_rows } => {
             let rows_old = old_rows.len() as u64;
-            let rows_new = new_rows.len() as u64;
-            (rows_old, rows_new, rows_old.min(rows_new))
-        }
-    };
-
-    let numeric_columns = match &alignment {
-        AlignmentContext::Key { key_rows, .. } => {
-            let rows = key_rows.iter().map(|row| {
-                (
-                    RowRef {
-                        old_record: row.old.record_number,
-                        new_record: row.new.record_number,
-                        key: Some(row.key.clone()),
-                    },
-                    row.old.fields.as_slice(),
-                    row.new.fields.as_slice(),
-                )
-            });
-            match detect_numeric_columns(&intersection.common, rows) {
-                Ok(columns) => columns,
-                Err(err) => {
-                    let refusal = map_column_error(err, rerun_paths);
-                    return Ok(render_refusal(
-                        refusal,
-                        args,
-                        key_bytes,
-                        dialect_old,
-                        dialect_new,
-                        &active_profile.info,
-                    ));
-                }
-            }
+            let rows_new = new_rows.len() as u64;
+            (
+                rows_old + filtered_old,
+                rows_new + filtered_new,
+                rows_old.min(rows_new),
+            )
         }
-        AlignmentContext::RowOrder { old_rows, new_rows } => {
-            let rows = old_rows.iter().zip(new_rows.iter()).enumerate().map(
-                |(idx, (old_row, new_row))| {
-                    let record = (idx + 1) as u64;
-                    (
-                        RowRef {
-                            old_record: record,
-                            new_record: record,
-                            key: None,
+    };
+
+    let typing_phase = phase_span!("typing", columns_common, columns_numeric, columns_mixed);
+    typing_phase.count("columns_common", intersection.common.len() as u64);
+    let typed =
+        match &alignment {
+            AlignmentContext::Key { key_rows, .. } => type_common_columns(
+                &intersection.common,
+                || {
+                    key_rows.iter().map(|row| {
+                        (
+                            RowRef {
+                                old_record: row.old.record_number,
+                                new_record: row.new.record_number,
+                                key: Some(row.key.clone()),
+                            },
+                            &row.old.fields,
+                            &row.new.fields,
+                        )
+                    })
+                },
+                || key_rows.iter().map(|row| row.key.as_slice()),
+                args,
+                rerun_paths,
+            ),
+            AlignmentContext::RowOrder { old_rows, new_rows } => type_common_columns(
+                &intersection.common,
+                || {
+                    old_rows.iter().zip(new_rows.iter()).enumerate().map(
+                        |(idx, (old_row, new_row))| {
+                            let record = (idx + 1) as u64;
+                            (
+                                RowRef {
+                                    old_record: record,
+                                    new_record: record,
+                                    key: None,
+                                },
+                                old_row,
+                                new_row,
+                            )
                         },
-                        old_row.as_slice(),
-                        new_row.as_slice(),
                     )
                 },
-            );
-            match detect_numeric_columns(&intersection.common, rows) {
-                Ok(columns) => columns,
-                Err(err) => {
-                    let refusal = map_column_error(err, rerun_paths);
-                    return Ok(render_refusal(
-                        refusal,
-                        args,
-                        key_bytes,
-                        dialect_old,
-                        dialect_new,
-                        &active_profile.info,
-                    ));
-                }
-            }
+                || old_rows.iter().map(|row| row.field(0)),
+                args,
+                rerun_paths,
+            ),
+        };
+    let ColumnTyping {
+        numeric: numeric_columns,
+        excluded_mixed,
+    } = match typed {
+        Ok(typing) => typing,
+        Err(refusal) => {
+            return Ok(render_refusal(
+                *refusal,
+                args,
+                key_bytes.as_deref(),
+                dialect_old,
+                dialect_new,
+                &active_profile.info,
+                Some(context),
+            ));
         }
     };
+    typing_phase.count("columns_numeric", numeric_columns.len() as u64);
+    typing_phase.count("columns_mixed", excluded_mixed.len() as u64);
+    context.marks.mark(Mark::Typed);
+    drop(typing_phase);
+
+    let percent_columns = if args.parse_percent {
+        percent_columns(&alignment, &numeric_columns)
+    } else {
+        Vec::new()
+    };
+    let normalizations = column_normalizations(&alignment, &numeric_columns, number_format);
+    let value_transforms: Vec<ValueTransforms> = numeric_columns
+        .iter()
+        .map(|column| ValueTransforms::for_column(&args.transforms, &column.name))
+        .collect();
+    let transforms: Vec<ColumnTransform> = args
+        .transforms
+        .iter()
+        .filter(|entry| {
+            numeric_columns
+                .iter()
+                .any(|column| column.name == entry.column)
+        })
+        .cloned()
+        .collect();
 
     let field_columns = if args.audit_fields {
         non_numeric_columns(&intersection.common, &numeric_columns)
@@ -712,7 +1928,13 @@ fn run_diff(
             rerun_paths,
         );
         let alignment_mode = match &alignment {
-            AlignmentContext::Key { key, .. } => JsonAlignment::key(encode_identifier_json(key)),
+            AlignmentContext::Key { key, .. } => key_alignment(
+                args,
+                key,
+                context.key_map,
+                context.key_dedup,
+                context.sample,
+            ),
             AlignmentContext::RowOrder { .. } => JsonAlignment::row_order(),
         };
         let counts = Counts {
@@ -721,12 +1943,12 @@ fn run_diff(
             rows_aligned: Some(rows_aligned),
             columns_old: Some(count_columns(
                 &old_headers,
-                key_bytes,
+                key_bytes.as_deref(),
                 active_profile.include_scope.as_ref(),
             )),
             columns_new: Some(count_columns(
                 &new_headers,
-                key_bytes,
+                key_bytes.as_deref(),
                 active_profile.include_scope.as_ref(),
             )),
             columns_common: Some(intersection.common.len() as u64),
@@ -735,124 +1957,261 @@ fn run_diff(
             numeric_columns: Some(0),
             numeric_cells_checked: Some(0),
             numeric_cells_changed: Some(0),
+            format_only_changes: None,
+            missing_skipped: None,
+            missing_zeroed: None,
+            missing_both: None,
+            missing_row: None,
+            unparsable: None,
+            footer_rows_old: context.footer_rows.map(|rows| rows.0),
+            footer_rows_new: context.footer_rows.map(|rows| rows.1),
+            comment_lines_old: context.comment_lines.map(|lines| lines.0),
+            comment_lines_new: context.comment_lines.map(|lines| lines.1),
+            rows_filtered_old: rows_filtered.map(|rows| rows.0),
+            rows_filtered_new: rows_filtered.map(|rows| rows.1),
         };
         let context = RefusalContext {
-            key: key_bytes,
+            key: key_bytes.as_deref(),
             dialect_old,
             dialect_new,
             alignment: alignment_mode,
             profile: active_profile.info.clone(),
             counts,
             metrics: Metrics::default(),
+            schema_change: context.schema_change.cloned(),
+            warnings: context.warnings,
+            column_map: context.column_map,
+            suggested_maps: context.suggested_maps,
+            drift: None,
+            rescaled_columns: Vec::new(),
+            tolerance_sweep: None,
+            key_changes: None,
+            date_changes: None,
+            text_changes: None,
+            column_types: None,
+            percent_columns: Vec::new(),
+            transforms: Vec::new(),
+            columns_excluded_mixed: Vec::new(),
+            column_normalizations: Vec::new(),
         };
         return Ok(render_refusal_with_context(refusal, args, context));
     }
 
-    let mut accumulator = DiffAccumulator::with_default_max();
-    let mut tie_breaker = TieBreaker::default();
-    let mut tolerance = ToleranceTracker::new(args.tolerance);
-    let mut numeric_cells_changed = 0u64;
-    let mut exhaustive_details = Vec::new();
+    let mut interner = Interner::default();
+    let numeric_names = intern_column_names(&mut interner, &numeric_columns);
+    let mut column_types = args.smart_tolerance.then(|| {
+        semantic_column_types(&alignment, &numeric_columns, args.tolerance, number_format)
+    });
+    if let Some(columns) = column_types.as_mut() {
+        for column in columns.iter_mut() {
+            column.tolerance =
+                column_tolerance(&args.column_tolerances, &column.column, column.tolerance);
+        }
+    }
+    let tolerances: Vec<f64> = match column_types.as_ref() {
+        Some(columns) => columns.iter().map(|column| column.tolerance).collect(),
+        None => numeric_columns
+            .iter()
+            .map(|column| column_tolerance(&args.column_tolerances, &column.name, args.tolerance))
+            .collect(),
+    };
+    let collect_ledger = args.exhaustive || args.explore.is_some() || args.report_xlsx.is_some();
+    let mut changes_csv = args
+        .report_csv
+        .as_deref()
+        .map(|path| ChangesCsvWriter::create(path, args.explicit))
+        .transpose()?;
+    let settings = ScanSettings {
+        columns: &numeric_columns,
+        names: &numeric_names,
+        tolerances: &tolerances,
+        transforms: &value_transforms,
+        base_tolerance: args.tolerance,
+        sweep: args
+            .tolerance_sweep
+            .as_ref()
+            .map(|sweep| sweep.levels.as_slice()),
+        number_format,
+        missing,
+        top: args.top,
+        rank: args.rank,
+        weight: weight_column.as_ref(),
+        subtolerance: args.show_subtolerance,
+        column_top: args.coverage == CoverageMode::PerColumn,
+        drift: args.drift,
+        collect_ledger,
+        max_audit_changes: args.max_audit_changes,
+        report_csv: changes_csv.is_some(),
+        source_lines: context.source_lines,
+    };
+    let diff_phase = phase_span!("diff", rows, columns, workers, cells_changed);
+    diff_phase.count("rows", rows_aligned);
+    diff_phase.count("columns", numeric_columns.len() as u64);
+    let mut scan = CellScan::new(&settings, 0);
+    let mut merge_chunk = |mut chunk: CellScan| -> std::io::Result<()> {
+        chunk.flush_csv(changes_csv.as_mut())?;
+        scan.merge(chunk, args.max_audit_changes);
+        Ok(())
+    };
+    let workers = worker_count(args.threads, rows_aligned as usize, numeric_columns.len());
+    diff_phase.count("workers", workers as u64);
 
     match &alignment {
-        AlignmentContext::Key { key_rows, .. } => {
-            for row in key_rows.iter() {
-                let row_id = RowId::key(row.key.clone());
-                for column in &numeric_columns {
-                    let old_raw = row
-                        .old
-                        .fields
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = row
-                        .new
-                        .fields
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    if is_missing_token(old_raw) && is_missing_token(new_raw) {
-                        continue;
+        AlignmentContext::Key {
+            key_rows,
+            removed,
+            added,
+            ..
+        } => {
+            // The interner is single-threaded, so row ids are resolved up front.
+            let row_ids: Vec<RowId> = key_rows
+                .iter()
+                .map(|row| RowId::key(interner.intern(&row.key)))
+                .collect();
+            scan_chunks(
+                key_rows.len(),
+                workers,
+                |range| {
+                    let mut chunk = CellScan::new(&settings, settings.first_tie_break(range.start));
+                    for idx in range {
+                        let row = &key_rows[idx];
+                        chunk.scan_row(
+                            &settings,
+                            &row_ids[idx],
+                            &row.old.fields,
+                            &row.new.fields,
+                            (row.old.record_number, row.new.record_number),
+                            RowLabel::Key(&row.key),
+                        );
                     }
-                    let (old_val, new_val) = match (parse_numeric(old_raw), parse_numeric(new_raw))
-                    {
-                        (Some(old_val), Some(new_val)) => (old_val, new_val),
-                        _ => continue,
+                    chunk
+                },
+                &mut merge_chunk,
+            )?;
+            // Whole-row changes rank as cells against an absent (zero) side.
+            if args.include_entity_changes {
+                let mut entities =
+                    CellScan::new(&settings, settings.first_tie_break(key_rows.len()));
+                let entity_rows = removed
+                    .iter()
+                    .map(|row| (row, CellKind::RowRemoved))
+                    .chain(added.iter().map(|row| (row, CellKind::RowAdded)));
+                for (row, kind) in entity_rows {
+                    let row_id = RowId::key(interner.intern(&row.key));
+                    let weight = match kind {
+                        CellKind::RowRemoved => settings.row_weight(Some(&row.entry.fields), None),
+                        _ => settings.row_weight(None, Some(&row.entry.fields)),
                     };
-                    let (delta, contribution) = tolerance.apply(old_val, new_val);
-                    let cell_id = CellId::new(row_id.clone(), column.name.clone());
-                    if contribution > 0.0 {
-                        numeric_cells_changed += 1;
-                        if args.exhaustive && numeric_cells_changed <= args.max_audit_changes {
-                            exhaustive_details.push(ContributionDetail {
-                                id: cell_id.clone(),
-                                old: old_val,
-                                new: new_val,
-                                delta,
-                                contribution,
-                            });
+                    for (column_idx, (column, column_name)) in
+                        numeric_columns.iter().zip(&numeric_names).enumerate()
+                    {
+                        let index = match kind {
+                            CellKind::RowRemoved => column.old_index,
+                            _ => column.new_index,
+                        };
+                        let raw = row.entry.fields.field(index);
+                        if number_format.is_missing(raw) {
+                            entities.cells_missing_row += 1;
+                            continue;
                         }
+                        let Some(value) = number_format.parse(raw) else {
+                            entities.cells_unparsable += 1;
+                            continue;
+                        };
+                        let transforms = &value_transforms[column_idx];
+                        let (old_val, new_val) = match kind {
+                            CellKind::RowRemoved => (transforms.apply_old(value), 0.0),
+                            _ => (0.0, transforms.apply_new(value)),
+                        };
+                        let cell_id =
+                            CellId::new(row_id.clone(), Arc::clone(column_name)).with_kind(kind);
+                        entities.observe(
+                            &settings,
+                            cell_id,
+                            column_idx,
+                            old_val,
+                            new_val,
+                            weight,
+                            || None,
+                            RowLabel::Key(&row.key),
+                            false,
+                        );
                     }
-                    accumulator.observe(
-                        cell_id,
-                        old_val,
-                        new_val,
-                        delta,
-                        contribution,
-                        tie_breaker.next_value(),
-                    );
                 }
+                merge_chunk(entities)?;
             }
         }
         AlignmentContext::RowOrder { old_rows, new_rows } => {
-            for (idx, (old_row, new_row)) in old_rows.iter().zip(new_rows.iter()).enumerate() {
-                let row_id = RowId::row_index(idx + 1);
-                for column in &numeric_columns {
-                    let old_raw = old_row
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = new_row
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    if is_missing_token(old_raw) && is_missing_token(new_raw) {
-                        continue;
+            scan_chunks(
+                old_rows.len().min(new_rows.len()),
+                workers,
+                |range| {
+                    let mut chunk = CellScan::new(&settings, settings.first_tie_break(range.start));
+                    for idx in range {
+                        let record = (idx + 1) as u64;
+                        chunk.scan_row(
+                            &settings,
+                            &RowId::row_index(idx + 1),
+                            &old_rows[idx],
+                            &new_rows[idx],
+                            (record, record),
+                            RowLabel::Record(record),
+                        );
                     }
-                    let (old_val, new_val) = match (parse_numeric(old_raw), parse_numeric(new_raw))
-                    {
-                        (Some(old_val), Some(new_val)) => (old_val, new_val),
-                        _ => continue,
-                    };
-                    let (delta, contribution) = tolerance.apply(old_val, new_val);
-                    let cell_id = CellId::new(row_id.clone(), column.name.clone());
-                    if contribution > 0.0 {
-                        numeric_cells_changed += 1;
-                        if args.exhaustive && numeric_cells_changed <= args.max_audit_changes {
-                            exhaustive_details.push(ContributionDetail {
-                                id: cell_id.clone(),
-                                old: old_val,
-                                new: new_val,
-                                delta,
-                                contribution,
-                            });
-                        }
-                    }
-                    accumulator.observe(
-                        cell_id,
-                        old_val,
-                        new_val,
-                        delta,
-                        contribution,
-                        tie_breaker.next_value(),
-                    );
-                }
-            }
+                    chunk
+                },
+                &mut merge_chunk,
+            )?;
         }
     }
+    let CellScan {
+        accumulator,
+        subtolerance,
+        column_top,
+        drift,
+        sweep,
+        column_totals,
+        column_deltas,
+        rescale,
+        row_totals,
+        cells_changed: numeric_cells_changed,
+        cells_checked: numeric_cells_checked,
+        cells_missing_both,
+        cells_missing_row,
+        cells_unparsable,
+        details: mut exhaustive_details,
+        format_only_changes,
+        missing_cells,
+        ..
+    } = scan;
+    diff_phase.count("cells_changed", numeric_cells_changed);
+    drop(diff_phase);
+
+    let drift = drift.map(|samples| samples.finish(&numeric_names));
+    let rescaled_columns = rescale.finish(&numeric_names);
+    let key_changes = key_change_report(&alignment, &numeric_columns, &value_transforms, args);
+    let other_columns = non_numeric_columns(&intersection.common, &numeric_columns);
+    let date_changes = date_change_report(&alignment, &other_columns, args);
+    let text_changes = args.include_text.then(|| {
+        let text_columns: Vec<CommonColumn> = other_columns
+            .iter()
+            .filter(|column| {
+                date_changes
+                    .as_ref()
+                    .is_none_or(|report| !report.columns.contains(&column.name))
+            })
+            .cloned()
+            .collect();
+        text_change_report(&alignment, &text_columns, args)
+    });
 
     let mut field_audit = if args.audit_fields {
-        collect_field_changes(&alignment, &field_columns, args.max_audit_changes)
+        collect_field_changes(
+            &alignment,
+            &field_columns,
+            args.max_audit_changes,
+            &mut interner,
+        )
     } else {
         FieldChangeAudit::default()
     };
@@ -873,7 +2232,13 @@ fn run_diff(
     };
 
     let alignment_mode = match &alignment {
-        AlignmentContext::Key { key, .. } => JsonAlignment::key(encode_identifier_json(key)),
+        AlignmentContext::Key { key, .. } => key_alignment(
+            args,
+            key,
+            context.key_map,
+            context.key_dedup,
+            context.sample,
+        ),
         AlignmentContext::RowOrder { .. } => JsonAlignment::row_order(),
     };
 
@@ -883,32 +2248,53 @@ fn run_diff(
         rows_aligned: Some(rows_aligned),
         columns_old: Some(count_columns(
             &old_headers,
-            key_bytes,
+            key_bytes.as_deref(),
             active_profile.include_scope.as_ref(),
         )),
         columns_new: Some(count_columns(
             &new_headers,
-            key_bytes,
+            key_bytes.as_deref(),
             active_profile.include_scope.as_ref(),
         )),
         columns_common: Some(intersection.common.len() as u64),
         columns_old_only: Some(intersection.old_only.len() as u64),
         columns_new_only: Some(intersection.new_only.len() as u64),
         numeric_columns: Some(numeric_columns.len() as u64),
-        numeric_cells_checked: Some(rows_aligned * numeric_columns.len() as u64),
+        numeric_cells_checked: Some(numeric_cells_checked),
         numeric_cells_changed: Some(numeric_cells_changed),
+        format_only_changes: (format_only_changes > 0).then_some(format_only_changes),
+        missing_skipped: (missing == MissingPolicy::Skip).then_some(missing_cells),
+        missing_zeroed: (missing == MissingPolicy::Zero).then_some(missing_cells),
+        missing_both: (cells_missing_both > 0).then_some(cells_missing_both),
+        missing_row: (cells_missing_row > 0).then_some(cells_missing_row),
+        unparsable: (cells_unparsable > 0).then_some(cells_unparsable),
+        footer_rows_old: context.footer_rows.map(|rows| rows.0),
+        footer_rows_new: context.footer_rows.map(|rows| rows.1),
+        comment_lines_old: context.comment_lines.map(|lines| lines.0),
+        comment_lines_new: context.comment_lines.map(|lines| lines.1),
+        rows_filtered_old: rows_filtered.map(|rows| rows.0),
+        rows_filtered_new: rows_filtered.map(|rows| rows.1),
     };
 
     let mut metrics = Metrics {
         total_change: Some(accumulator.total_change),
         max_abs_delta: Some(accumulator.max_abs_delta),
         top_k_coverage,
+        coverage_curve: (accumulator.total_change > 0.0)
+            .then(|| coverage_curve(&contributions, accumulator.total_change)),
+        timings: None,
     };
 
     if let AlignmentContext::RowOrder { old_rows, new_rows } = &alignment
         && accumulator.total_change > 0.0
     {
-        let detection = detect_shuffle(&old_headers, &new_headers, old_rows, new_rows);
+        let aligned = rows_aligned as usize;
+        let detection = detect_shuffle(
+            &old_headers,
+            &new_headers,
+            &old_rows[..aligned],
+            &new_rows[..aligned],
+        );
         if detection.reordered {
             let refusal = RefusalPayload::with_default_next(
                 RefusalCode::NeedKey,
@@ -920,21 +2306,47 @@ fn run_diff(
             let mut counts = counts.clone();
             counts.numeric_cells_checked = None;
             counts.numeric_cells_changed = None;
+            counts.format_only_changes = None;
+            counts.missing_skipped = None;
+            counts.missing_zeroed = None;
+            counts.missing_both = None;
+            counts.missing_row = None;
+            counts.unparsable = None;
             metrics = Metrics::default();
             let context = RefusalContext {
-                key: key_bytes,
+                key: key_bytes.as_deref(),
                 dialect_old,
                 dialect_new,
                 alignment: alignment_mode,
                 profile: active_profile.info.clone(),
                 counts,
                 metrics,
+                schema_change: context.schema_change.cloned(),
+                warnings: context.warnings,
+                column_map: context.column_map,
+                suggested_maps: context.suggested_maps,
+                drift: drift.clone(),
+                rescaled_columns: rescaled_columns.clone(),
+                tolerance_sweep: sweep.clone(),
+                key_changes: key_changes.clone(),
+                date_changes: date_changes.clone(),
+                text_changes: text_changes.clone(),
+                column_types: column_types.clone(),
+                percent_columns: percent_columns.clone(),
+                transforms: transforms.clone(),
+                columns_excluded_mixed: excluded_mixed.clone(),
+                column_normalizations: normalizations.clone(),
             };
             return Ok(render_refusal_with_context(refusal, args, context));
         }
     }
 
-    let alignment_label = key_bytes.map(render_identifier_human);
+    let alignment_label = key_bytes.as_deref().map(render_identifier_human);
+    let subtolerance_details = subtolerance.map(|top| {
+        let mut top = top.into_vec();
+        sort_contributors(&mut top);
+        collect_details(&top)
+    });
 
     if args.exhaustive && audit_changes > args.max_audit_changes {
         let refusal = RefusalPayload::with_default_next(
@@ -948,17 +2360,46 @@ fn run_diff(
         let mut counts = counts.clone();
         counts.numeric_cells_changed = None;
         let context = RefusalContext {
-            key: key_bytes,
+            key: key_bytes.as_deref(),
             dialect_old,
             dialect_new,
             alignment: alignment_mode,
             profile: active_profile.info.clone(),
             counts,
             metrics,
+            schema_change: context.schema_change.cloned(),
+            warnings: context.warnings,
+            column_map: context.column_map,
+            suggested_maps: context.suggested_maps,
+            drift: drift.clone(),
+            rescaled_columns: rescaled_columns.clone(),
+            tolerance_sweep: sweep.clone(),
+            key_changes: key_changes.clone(),
+            date_changes: date_changes.clone(),
+            text_changes: text_changes.clone(),
+            column_types: column_types.clone(),
+            percent_columns: percent_columns.clone(),
+            transforms: transforms.clone(),
+            columns_excluded_mixed: excluded_mixed.clone(),
+            column_normalizations: normalizations.clone(),
         };
         return Ok(render_refusal_with_context(refusal, args, context));
     }
 
+    sort_contribution_details(&mut exhaustive_details);
+    let by_column = column_totals.finish(&numeric_names, accumulator.total_change);
+    let by_row = row_totals.finish(accumulator.total_change);
+    let column_deltas = column_deltas.finish(&numeric_names);
+    context.marks.mark(Mark::Diffed);
+    let mut ledger_summary = LedgerSummary {
+        alignment_label: alignment_label.as_deref(),
+        total_change: accumulator.total_change,
+        max_abs_delta: accumulator.max_abs_delta,
+        cells_changed: numeric_cells_changed,
+        refusal: None,
+        ledger: &exhaustive_details,
+    };
+
     if args.exhaustive {
         sort_field_change_details(&mut field_audit.details);
         let field_changes = if args.audit_fields {
@@ -969,7 +2410,7 @@ fn run_diff(
         } else {
             None
         };
-        let ctx = json_context_with_field_changes(
+        let mut ctx = json_context_with_field_changes(
             args,
             alignment_mode,
             dialect_old,
@@ -980,25 +2421,100 @@ fn run_diff(
             field_audit.changed,
             field_changes,
         );
-        if accumulator.total_change == 0.0 && field_audit.changed == 0 {
-            return Ok(render_no_real_change(args, ctx, alignment_label.as_deref()));
-        }
-        sort_contribution_details(&mut exhaustive_details);
-        return Ok(render_real_change(
-            args,
-            ctx,
-            &exhaustive_details,
-            1.0,
-            alignment_label.as_deref(),
-            &field_audit.details,
-        ));
+        ctx.schema_change = context.schema_change.cloned();
+        ctx.warnings = context.warnings.to_vec();
+        ctx.column_map = context.column_map.to_vec();
+        ctx.suggested_maps = context.suggested_maps.to_vec();
+        ctx.drift = drift.clone();
+        ctx.rescaled_columns = rescaled_columns.clone();
+        ctx.tolerance_sweep = sweep.clone();
+        ctx.key_changes = key_changes.clone();
+        ctx.date_changes = date_changes.clone();
+        ctx.text_changes = text_changes.clone();
+        ctx.column_types = column_types.clone();
+        ctx.percent_columns = percent_columns.clone();
+        ctx.transforms = transforms.clone();
+        ctx.columns_excluded_mixed = excluded_mixed.clone();
+        ctx.column_normalizations = normalizations.clone();
+        let result = if accumulator.total_change == 0.0 && field_audit.changed == 0 {
+            ctx.column_deltas = Some(column_deltas);
+            render_no_real_change(
+                args,
+                ctx,
+                alignment_label.as_deref(),
+                subtolerance_details.as_deref(),
+            )
+        } else {
+            ctx.by_column = Some(by_column);
+            ctx.by_row = Some(by_row);
+            render_real_change(
+                args,
+                ctx,
+                &exhaustive_details,
+                1.0,
+                alignment_label.as_deref(),
+                &field_audit.details,
+                context.expected,
+            )
+        };
+        write_ledger_artifacts(args, &result, &ledger_summary, changes_csv)?;
+        return Ok(result);
     }
 
-    let coverage = evaluate_coverage(&contributions, accumulator.total_change, args.threshold);
+    let (top, coverage, diffuse_column) = match column_top {
+        Some(column_top) => per_column_coverage(
+            column_top,
+            &column_totals,
+            &numeric_columns,
+            accumulator.total_change,
+            args,
+        ),
+        None => {
+            let coverage =
+                evaluate_coverage(&contributions, accumulator.total_change, args.threshold);
+            (top, coverage, None)
+        }
+    };
 
-    match coverage {
+    let result = match coverage {
         CoverageDecision::NoChange => {
-            let ctx = json_context(
+            let mut ctx = json_context(
+                args,
+                alignment_mode,
+                dialect_old,
+                dialect_new,
+                &active_profile.info,
+                counts,
+                metrics,
+            );
+            ctx.schema_change = context.schema_change.cloned();
+            ctx.warnings = context.warnings.to_vec();
+            ctx.column_map = context.column_map.to_vec();
+            ctx.suggested_maps = context.suggested_maps.to_vec();
+            ctx.drift = drift.clone();
+            ctx.rescaled_columns = rescaled_columns.clone();
+            ctx.tolerance_sweep = sweep.clone();
+            ctx.key_changes = key_changes.clone();
+            ctx.date_changes = date_changes.clone();
+            ctx.text_changes = text_changes.clone();
+            ctx.column_types = column_types.clone();
+            ctx.percent_columns = percent_columns.clone();
+            ctx.transforms = transforms.clone();
+            ctx.columns_excluded_mixed = excluded_mixed.clone();
+            ctx.column_normalizations = normalizations.clone();
+            ctx.column_deltas = Some(column_deltas);
+            render_no_real_change(
+                args,
+                ctx,
+                alignment_label.as_deref(),
+                subtolerance_details.as_deref(),
+            )
+        }
+        CoverageDecision::Diffuse { top_k_coverage }
+            if args.on_diffuse == DiffusePolicy::Expand =>
+        {
+            let details = collect_details(&top);
+            let mut ctx = json_context(
                 args,
                 alignment_mode,
                 dialect_old,
@@ -1007,7 +2523,37 @@ fn run_diff(
                 counts,
                 metrics,
             );
-            Ok(render_no_real_change(args, ctx, alignment_label.as_deref()))
+            ctx.schema_change = context.schema_change.cloned();
+            ctx.warnings = context.warnings.to_vec();
+            ctx.column_map = context.column_map.to_vec();
+            ctx.suggested_maps = context.suggested_maps.to_vec();
+            ctx.drift = drift.clone();
+            ctx.rescaled_columns = rescaled_columns.clone();
+            ctx.tolerance_sweep = sweep.clone();
+            ctx.key_changes = key_changes.clone();
+            ctx.date_changes = date_changes.clone();
+            ctx.text_changes = text_changes.clone();
+            ctx.column_types = column_types.clone();
+            ctx.percent_columns = percent_columns.clone();
+            ctx.transforms = transforms.clone();
+            ctx.columns_excluded_mixed = excluded_mixed.clone();
+            ctx.column_normalizations = normalizations.clone();
+            ctx.diffuse = Some(DiffuseShortfall {
+                top_k_coverage,
+                threshold: args.threshold,
+                column: diffuse_column,
+            });
+            ctx.by_column = Some(by_column);
+            ctx.by_row = Some(by_row);
+            render_real_change(
+                args,
+                ctx,
+                &details,
+                top_k_coverage,
+                alignment_label.as_deref(),
+                &[],
+                context.expected,
+            )
         }
         CoverageDecision::Diffuse { top_k_coverage } => {
             let refusal = RefusalPayload::with_default_next(
@@ -1015,24 +2561,39 @@ fn run_diff(
                 RefusalKind::Diffuse {
                     top_k_coverage,
                     threshold: args.threshold,
+                    column: diffuse_column,
                 },
                 rerun_paths,
             );
             let context = RefusalContext {
-                key: key_bytes,
+                key: key_bytes.as_deref(),
                 dialect_old,
                 dialect_new,
                 alignment: alignment_mode,
                 profile: active_profile.info.clone(),
                 counts,
                 metrics,
+                schema_change: context.schema_change.cloned(),
+                warnings: context.warnings,
+                column_map: context.column_map,
+                suggested_maps: context.suggested_maps,
+                drift: drift.clone(),
+                rescaled_columns: rescaled_columns.clone(),
+                tolerance_sweep: sweep.clone(),
+                key_changes: key_changes.clone(),
+                date_changes: date_changes.clone(),
+                text_changes: text_changes.clone(),
+                column_types: column_types.clone(),
+                percent_columns: percent_columns.clone(),
+                transforms: transforms.clone(),
+                columns_excluded_mixed: excluded_mixed.clone(),
+                column_normalizations: normalizations.clone(),
             };
-            Ok(render_refusal_with_context(refusal, args, context))
+            render_refusal_with_context(refusal, args, context)
         }
         CoverageDecision::Explainable { cutoff, coverage } => {
-            let details =
-                collect_details(&alignment, &numeric_columns, &top[..cutoff], args.tolerance);
-            let ctx = json_context(
+            let details = collect_details(&top[..cutoff]);
+            let mut ctx = json_context(
                 args,
                 alignment_mode,
                 dialect_old,
@@ -1041,36 +2602,219 @@ fn run_diff(
                 counts,
                 metrics,
             );
-            Ok(render_real_change(
+            ctx.schema_change = context.schema_change.cloned();
+            ctx.warnings = context.warnings.to_vec();
+            ctx.column_map = context.column_map.to_vec();
+            ctx.suggested_maps = context.suggested_maps.to_vec();
+            ctx.drift = drift.clone();
+            ctx.rescaled_columns = rescaled_columns.clone();
+            ctx.tolerance_sweep = sweep.clone();
+            ctx.key_changes = key_changes.clone();
+            ctx.date_changes = date_changes.clone();
+            ctx.text_changes = text_changes.clone();
+            ctx.column_types = column_types.clone();
+            ctx.percent_columns = percent_columns.clone();
+            ctx.transforms = transforms.clone();
+            ctx.columns_excluded_mixed = excluded_mixed.clone();
+            ctx.column_normalizations = normalizations.clone();
+            ctx.by_column = Some(by_column);
+            ctx.by_row = Some(by_row);
+            render_real_change(
                 args,
                 ctx,
                 &details,
                 coverage,
                 alignment_label.as_deref(),
                 &[],
-            ))
+                context.expected,
+            )
+        }
+    };
+    if let CoverageDecision::Diffuse { .. } = coverage
+        && args.on_diffuse == DiffusePolicy::Refuse
+    {
+        ledger_summary.refusal = Some(RefusalCode::Diffuse);
+    }
+    write_ledger_artifacts(args, &result, &ledger_summary, changes_csv)?;
+    Ok(result)
+}
+
+/// `--coverage per-column`: the contributors each changed column needs to
+/// reach the threshold of its own change, merged in contributor order.
+/// Diffuse runs keep the overall top-K for `--on-diffuse expand` and name the
+/// least-explained column.
+fn per_column_coverage(
+    column_top: Vec<TopContributors<CellId>>,
+    column_totals: &ColumnTotals,
+    numeric_columns: &[CommonColumn],
+    total_change: f64,
+    args: &Args,
+) -> (
+    Vec<crate::diff::heap::Contributor<CellId>>,
+    CoverageDecision,
+    Option<Vec<u8>>,
+) {
+    let column_top: Vec<Vec<crate::diff::heap::Contributor<CellId>>> = column_top
+        .into_iter()
+        .map(|top| {
+            let mut top = top.into_vec();
+            sort_contributors(&mut top);
+            top
+        })
+        .collect();
+    let columns: Vec<(f64, Vec<f64>)> = column_top
+        .iter()
+        .enumerate()
+        .map(|(idx, top)| {
+            (
+                column_totals.contribution(idx),
+                top.iter().map(|c| c.contribution).collect(),
+            )
+        })
+        .collect();
+    match evaluate_column_coverage(&columns, args.threshold) {
+        ColumnCoverageDecision::NoChange => (Vec::new(), CoverageDecision::NoChange, None),
+        ColumnCoverageDecision::Diffuse {
+            column,
+            top_k_coverage,
+        } => {
+            let mut top: Vec<_> = column_top.into_iter().flatten().collect();
+            sort_contributors(&mut top);
+            top.truncate(args.top);
+            (
+                top,
+                CoverageDecision::Diffuse { top_k_coverage },
+                Some(numeric_columns[column].name.clone()),
+            )
+        }
+        ColumnCoverageDecision::Explainable { cutoffs } => {
+            let mut top: Vec<_> = column_top
+                .into_iter()
+                .zip(cutoffs)
+                .flat_map(|(top, cutoff)| top.into_iter().take(cutoff))
+                .collect();
+            sort_contributors(&mut top);
+            let coverage = top.iter().map(|c| c.contribution).sum::<f64>() / total_change;
+            let cutoff = top.len();
+            (
+                top,
+                CoverageDecision::Explainable { cutoff, coverage },
+                None,
+            )
         }
     }
 }
 
+/// Changed-cell ledger plus run totals shared by `--explore` and `--report-xlsx`.
+struct LedgerSummary<'a> {
+    alignment_label: Option<&'a str>,
+    total_change: f64,
+    max_abs_delta: f64,
+    cells_changed: u64,
+    refusal: Option<RefusalCode>,
+    /// Changed cells sorted by contribution desc (capped at `--max-audit-changes`).
+    ledger: &'a [ContributionDetail],
+}
+
+fn write_ledger_artifacts(
+    args: &Args,
+    result: &PipelineResult,
+    summary: &LedgerSummary<'_>,
+    changes_csv: Option<ChangesCsvWriter>,
+) -> Result<(), PipelineError> {
+    explore::write_explorer(args, result, summary)?;
+    report::write_xlsx_report(args, result, summary)?;
+    if let Some(writer) = changes_csv {
+        writer.finish()?;
+    }
+    Ok(())
+}
+
+/// An input's raw bytes, as read from disk or a stream, or borrowed from memory.
+struct RawInput<'a> {
+    bytes: Cow<'a, [u8]>,
+    read_time: Duration,
+}
+
+fn read_input<'a>(
+    source: InputSource<'a>,
+    file_side: FileSide,
+    rerun_paths: RerunPaths<'_>,
+) -> Result<RawInput<'a>, Box<RefusalPayload>> {
+    let read_started = Instant::now();
+    let bytes = source.read().map_err(|err| {
+        Box::new(RefusalPayload::with_default_next(
+            RefusalCode::Io,
+            RefusalKind::Io {
+                file: file_side,
+                error: err.to_string(),
+            },
+            rerun_paths,
+        ))
+    })?;
+    Ok(RawInput {
+        bytes,
+        read_time: read_started.elapsed(),
+    })
+}
+
+/// Read and parse one input.
 fn parse_csv(
-    path: &Path,
+    source: InputSource<'_>,
+    file_side: FileSide,
+    forced_delimiter: Option<u8>,
+    rerun_paths: RerunPaths<'_>,
+    file_rules: FileRules<'_>,
+    number_format: NumberFormat<'_>,
+    dedupe: bool,
+) -> Result<ParsedCsv, Box<RefusalPayload>> {
+    let raw = read_input(source, file_side, rerun_paths)?;
+    parse_raw(
+        &raw,
+        file_side,
+        forced_delimiter,
+        rerun_paths,
+        file_rules,
+        number_format,
+        dedupe,
+    )
+}
+
+fn parse_raw(
+    raw: &RawInput<'_>,
     file_side: FileSide,
     forced_delimiter: Option<u8>,
     rerun_paths: RerunPaths<'_>,
-    header_aliases: Option<&HashMap<Vec<u8>, Vec<u8>>>,
+    file_rules: FileRules<'_>,
+    number_format: NumberFormat<'_>,
+    dedupe: bool,
 ) -> Result<ParsedCsv, Box<RefusalPayload>> {
-    let bytes = fs::read(path).map_err(|err| {
+    let phase = phase_span!("parse", file, bytes, rows, columns);
+    phase.label("file", file_side.as_str());
+    let read_started = Instant::now();
+    let (bytes, compression) = decompress(&raw.bytes).map_err(|error| {
         Box::new(RefusalPayload::with_default_next(
             RefusalCode::Io,
             RefusalKind::Io {
                 file: file_side,
-                error: err.to_string(),
+                error,
             },
             rerun_paths,
         ))
     })?;
 
+    let (bytes, encoding) = transcode(&bytes, file_rules.encoding).map_err(|_| {
+        Box::new(RefusalPayload::with_default_next(
+            RefusalCode::Encoding,
+            RefusalKind::Encoding {
+                file: file_side,
+                issue: EncodingIssue::InvalidUtf16,
+            },
+            rerun_paths,
+        ))
+    })?;
+    let read_time = raw.read_time + read_started.elapsed();
+    phase.count("bytes", bytes.len() as u64);
     let guarded = guard_input_bytes(&bytes).map_err(|issue| {
         Box::new(RefusalPayload::with_default_next(
             RefusalCode::Encoding,
@@ -1081,6 +2825,15 @@ fn parse_csv(
             rerun_paths,
         ))
     })?;
+    // Reported line numbers count the skipped banner lines.
+    let guarded = skip_lines(guarded, file_rules.skip_rows);
+    let line_offset = file_rules.skip_rows as u64;
+    // Comment lines are blanked in place, so line numbers still match the file.
+    let (uncommented, comment_lines) = match file_rules.comment {
+        Some(prefix) => blank_comment_lines(guarded, prefix),
+        None => (Cow::Borrowed(guarded), 0),
+    };
+    let guarded: &[u8] = &uncommented;
 
     let mut skip_sep = false;
     let mut sep_delimiter = None;
@@ -1092,20 +2845,21 @@ fn parse_csv(
         SepScan::FirstNonBlank { .. } | SepScan::NoLines => {}
     }
 
-    let (delimiter, escape) = if let Some(forced) = forced_delimiter {
+    let dialect_phase = phase_span!("dialect", source, delimiter);
+    let (delimiter, escape, confidence) = if let Some(forced) = forced_delimiter {
         let mut cursor = Cursor::new(guarded);
         let escape = detect_escape_mode(&mut cursor, forced).map_err(|err| {
             Box::new(RefusalPayload::with_default_next(
                 RefusalCode::CsvParse,
                 RefusalKind::CsvParse {
                     file: file_side,
-                    line: err.line,
+                    line: err.line.map(|line| line + line_offset),
                     column: None,
                 },
                 rerun_paths,
             ))
         })?;
-        (forced, escape)
+        (forced, escape, None)
     } else if let Some(sep) = sep_delimiter {
         let mut cursor = Cursor::new(guarded);
         let escape = detect_escape_mode(&mut cursor, sep).map_err(|err| {
@@ -1113,30 +2867,43 @@ fn parse_csv(
                 RefusalCode::CsvParse,
                 RefusalKind::CsvParse {
                     file: file_side,
-                    line: err.line,
+                    line: err.line.map(|line| line + line_offset),
                     column: None,
                 },
                 rerun_paths,
             ))
         })?;
-        (sep, escape)
+        (sep, escape, None)
     } else {
         match auto_detect(guarded) {
-            Ok(dialect) => (dialect.delimiter, dialect.escape),
+            Ok(dialect) => (dialect.delimiter, dialect.escape, Some(dialect.confidence)),
             Err(err) => return Err(Box::new(map_dialect_error(err, file_side, rerun_paths))),
         }
     };
+    let source = match (forced_delimiter, sep_delimiter) {
+        (Some(_), _) => "forced",
+        (None, Some(_)) => "sep",
+        (None, None) => "detected",
+    };
+    dialect_phase.label("source", source);
+    dialect_phase.label(
+        "delimiter",
+        std::str::from_utf8(&[delimiter]).unwrap_or("?"),
+    );
+    drop(dialect_phase);
 
-    let mut reader = build_reader(Cursor::new(guarded), delimiter, escape);
+    let mut reader = RecordReader::new(guarded, delimiter, escape);
     let mut record = ByteRecord::new();
     let mut header: Option<Vec<Vec<u8>>> = None;
+    let mut applied_map = Vec::new();
     let mut records = Vec::new();
+    let mut lines = Vec::new();
     let mut data_index: u64 = 0;
     let mut skipped_sep = !skip_sep;
 
     loop {
-        match reader.read_byte_record(&mut record) {
-            Ok(true) => {
+        match reader.read(&mut record) {
+            Ok(Some(line)) => {
                 if header.is_none() {
                     if is_blank_record(&record) && record.len() == 1 {
                         continue;
@@ -1145,17 +2912,25 @@ fn parse_csv(
                         skipped_sep = true;
                         continue;
                     }
-                    let normalized = normalize_headers_with_aliases(record.iter(), header_aliases)
-                        .map_err(|err| {
-                            Box::new(RefusalPayload::with_default_next(
-                                RefusalCode::Headers,
-                                RefusalKind::Headers {
-                                    file: file_side,
-                                    issue: HeadersIssue::Duplicate { name: err.name },
-                                },
-                                rerun_paths,
-                            ))
-                        })?;
+                    let duplicate = |err: DuplicateHeader| {
+                        Box::new(RefusalPayload::with_default_next(
+                            RefusalCode::Headers,
+                            RefusalKind::Headers {
+                                file: file_side,
+                                issue: HeadersIssue::Duplicate { name: err.name },
+                            },
+                            rerun_paths,
+                        ))
+                    };
+                    let mut normalized =
+                        normalize_headers_with_aliases(record.iter(), file_rules.aliases)
+                            .map_err(duplicate)?;
+                    applied_map = apply_column_map(&mut normalized, file_rules.column_map)
+                        .map_err(duplicate)?;
+                    file_rules
+                        .matching
+                        .apply(&mut normalized)
+                        .map_err(duplicate)?;
                     header = Some(normalized);
                     continue;
                 }
@@ -1179,15 +2954,16 @@ fn parse_csv(
                             rerun_paths,
                         ))
                     })?;
-                records.push(owned_record(normalized));
+                records.push(normalized.to_owned_record());
+                lines.push(line + line_offset);
             }
-            Ok(false) => break,
+            Ok(None) => break,
             Err(err) => {
                 return Err(Box::new(RefusalPayload::with_default_next(
                     RefusalCode::CsvParse,
                     RefusalKind::CsvParse {
                         file: file_side,
-                        line: err.position().map(|pos| pos.line()),
+                        line: err.position().map(|pos| pos.line() + line_offset),
                         column: None,
                     },
                     rerun_paths,
@@ -1210,22 +2986,44 @@ fn parse_csv(
         }
     };
 
+    let key_index = file_rules
+        .key
+        .and_then(|key| headers.iter().position(|name| name == key))
+        .unwrap_or(0);
+    let footer = footer_rows(&records, file_rules.footer, key_index);
+    records.truncate(records.len() - footer);
+    lines.truncate(records.len());
+
+    // Fingerprinting and duplicate detection here keep the extra passes on the
+    // per-file parse thread.
+    let schema = SchemaFingerprint::compute(&headers, &records, number_format);
+    let duplicates = find_duplicate_rows(&records);
+    let (records, lines) = if dedupe && duplicates.is_some() {
+        dedupe_rows(records, lines)
+    } else {
+        (records, lines)
+    };
+    phase.count("rows", records.len() as u64);
+    phase.count("columns", headers.len() as u64);
     Ok(ParsedCsv {
+        compression,
+        encoding,
+        decimal: number_format.decimal,
         delimiter,
+        confidence,
         escape,
         headers,
         records,
+        lines,
+        schema,
+        duplicates,
+        column_map: applied_map,
+        footer_rows: footer as u64,
+        comment_lines,
+        read_time,
     })
 }
 
-fn owned_record(normalized: crate::csv::records::NormalizedRecord<'_>) -> OwnedRecord {
-    let mut fields = Vec::with_capacity(normalized.len());
-    for idx in 0..normalized.len() {
-        fields.push(normalized.field(idx).to_vec());
-    }
-    fields
-}
-
 fn map_dialect_error(
     err: DialectError,
     file_side: FileSide,
@@ -1285,6 +3083,22 @@ fn find_key_index(headers: &[Vec<u8>], key: &[u8]) -> Option<usize> {
     headers.iter().position(|name| name.as_slice() == key)
 }
 
+/// Locate a flag-named column (`--weight-col`) in both files; `Err` names
+/// the first file without it.
+fn find_named_column(
+    name: &[u8],
+    old_headers: &[Vec<u8>],
+    new_headers: &[Vec<u8>],
+) -> Result<CommonColumn, FileSide> {
+    let old_index = find_key_index(old_headers, name).ok_or(FileSide::Old)?;
+    let new_index = find_key_index(new_headers, name).ok_or(FileSide::New)?;
+    Ok(CommonColumn {
+        name: name.to_vec(),
+        old_index,
+        new_index,
+    })
+}
+
 fn map_key_join_error(
     err: KeyJoinError,
     file: FileSide,
@@ -1319,6 +3133,7 @@ fn map_key_join_error(
             extra_count,
             missing_samples,
             extra_samples,
+            normalize_hint,
         } => RefusalPayload::with_default_next(
             RefusalCode::KeyMismatch,
             RefusalKind::KeyMismatch {
@@ -1326,13 +3141,104 @@ fn map_key_join_error(
                 extra_in_new: extra_count,
                 missing_samples,
                 extra_samples,
+                normalize_hint,
             },
             paths,
         ),
     }
 }
 
-fn map_column_error(err: ColumnTypingError<RowRef>, paths: RerunPaths<'_>) -> RefusalPayload {
+/// Type the common columns (`--cross-tab` aware). A refusal carries the
+/// refused column's type profile and, for mixed types, any cross-tab shape;
+/// under `--collect-refusals` every other column is typed on its own and its
+/// refusal attached.
+fn type_common_columns<'l, Old, New, I, L>(
+    columns: &[CommonColumn],
+    rows: impl Fn() -> I,
+    labels: impl FnOnce() -> L,
+    args: &Args,
+    paths: RerunPaths<'_>,
+) -> Result<ColumnTyping, Box<RefusalPayload>>
+where
+    Old: FieldAccess,
+    New: FieldAccess,
+    I: Iterator<Item = (RowRef, Old, New)>,
+    L: IntoIterator<Item = &'l [u8]>,
+{
+    let number_format = args.number_format();
+    let detect = |columns: &[CommonColumn]| {
+        if args.cross_tab {
+            detect_cross_tab_columns(
+                columns,
+                rows(),
+                number_format,
+                args.on_missing,
+                args.on_mixed_types,
+            )
+        } else {
+            detect_numeric_columns(
+                columns,
+                rows(),
+                number_format,
+                args.on_missing,
+                args.on_mixed_types,
+            )
+        }
+    };
+    let profile = |err: &ColumnTypingError<RowRef>| {
+        mixed_column(err, columns).map(|column| {
+            let rows = rows().map(|(row, old, new)| ((row.old_record, row.new_record), old, new));
+            profile_mixed_column(column, rows, number_format)
+        })
+    };
+
+    let err = match detect(columns) {
+        Ok(typing) => return Ok(typing),
+        Err(err) => err,
+    };
+    let refused = err.column().to_vec();
+    let mixed_profile = profile(&err);
+    let cross_tab = if mixed_profile.is_some() && !args.cross_tab {
+        detect_cross_tab_shape(
+            columns,
+            rows().map(|(_, old, new)| (old, new)),
+            labels(),
+            number_format,
+        )
+    } else {
+        None
+    };
+    let mut refusal = map_column_error(err, mixed_profile, cross_tab, paths);
+    if args.collect_refusals {
+        for column in columns.iter().filter(|column| column.name != refused) {
+            if let Err(err) = detect(std::slice::from_ref(column)) {
+                let mixed_profile = profile(&err);
+                refusal
+                    .also
+                    .push(map_column_error(err, mixed_profile, None, paths));
+            }
+        }
+    }
+    Err(Box::new(refusal))
+}
+
+/// The common column a mixed-types refusal names, for profiling.
+fn mixed_column<'a>(
+    err: &ColumnTypingError<RowRef>,
+    columns: &'a [CommonColumn],
+) -> Option<&'a CommonColumn> {
+    let ColumnTypingError::MixedTypes(detail) = err else {
+        return None;
+    };
+    columns.iter().find(|column| column.name == detail.column)
+}
+
+fn map_column_error(
+    err: ColumnTypingError<RowRef>,
+    profile: Option<MixedColumnProfile>,
+    cross_tab: Option<CrossTabSignal>,
+    paths: RerunPaths<'_>,
+) -> RefusalPayload {
     match err {
         ColumnTypingError::MixedTypes(detail) => {
             let file = match detail.side {
@@ -1352,6 +3258,8 @@ fn map_column_error(err: ColumnTypingError<RowRef>, paths: RerunPaths<'_>) -> Re
                     column: detail.column,
                     value: detail.value,
                     key_value,
+                    profile: profile.unwrap_or_default(),
+                    cross_tab,
                 },
                 paths,
             )
@@ -1392,9 +3300,16 @@ fn render_refusal(
     dialect_old: Option<DialectReceipt>,
     dialect_new: Option<DialectReceipt>,
     profile: &ProfileRunInfo,
+    run: Option<RunContext<'_>>,
 ) -> PipelineResult {
     let alignment_mode = match key {
-        Some(key) => JsonAlignment::key(encode_identifier_json(key)),
+        Some(key) => key_alignment(
+            args,
+            key,
+            run.and_then(|run| run.key_map),
+            run.and_then(|run| run.key_dedup),
+            run.and_then(|run| run.sample),
+        ),
         None => JsonAlignment::row_order(),
     };
 
@@ -1406,21 +3321,61 @@ fn render_refusal(
         profile: profile.clone(),
         counts: Counts::default(),
         metrics: Metrics::default(),
+        schema_change: run.and_then(|run| run.schema_change).cloned(),
+        warnings: run.map_or(&[], |run| run.warnings),
+        column_map: run.map_or(&[], |run| run.column_map),
+        suggested_maps: run.map_or(&[], |run| run.suggested_maps),
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
     };
 
     render_refusal_with_context(refusal, args, context)
 }
 
+fn key_alignment(
+    args: &Args,
+    key: &[u8],
+    key_map: Option<&KeyTranslation>,
+    key_dedup: Option<KeyDedup>,
+    sample: Option<RowSample>,
+) -> JsonAlignment {
+    let mut alignment = JsonAlignment::key(encode_identifier_json(key));
+    if args.group_by.is_some() {
+        alignment.mode = JsonAlignmentMode::GroupBy;
+    }
+    alignment.key_normalize = args
+        .key_normalize
+        .iter()
+        .map(|step| step.as_str())
+        .collect();
+    alignment.key_map = key_map.map(|map| JsonKeyMap {
+        file: map.file.clone(),
+        entries: map.len() as u64,
+    });
+    alignment.dedup = key_dedup.map(JsonKeyDedup::from_dedup);
+    alignment.sample = sample.map(JsonSample::from_sample);
+    alignment
+}
+
 fn render_refusal_with_context(
     refusal: RefusalPayload,
     args: &Args,
     context: RefusalContext<'_>,
 ) -> PipelineResult {
-    let old_display = display_name(args.old_path());
-    let new_display = display_name(args.new_path());
+    let old_display = source_label(args.old_path());
+    let new_display = source_label(args.new_path());
 
-    let result = if args.json {
-        let ctx = json_context(
+    let mut result = if args.json {
+        let mut ctx = json_context(
             args,
             context.alignment,
             context.dialect_old,
@@ -1429,29 +3384,49 @@ fn render_refusal_with_context(
             context.counts,
             context.metrics,
         );
-        let detail = refusal_detail_json(&refusal.detail);
-        let refusal_json = JsonRefusal::new(refusal.code, refusal.code.reason(), detail);
-        let output = JsonOutput::refusal(ctx, refusal_json)
-            .to_string()
-            .unwrap_or_else(|_| "{}".to_string());
-        PipelineResult {
-            outcome: Outcome::Refusal,
-            output,
-            profile: context.profile.clone(),
+        ctx.schema_change = context.schema_change.clone();
+        ctx.warnings = context.warnings.to_vec();
+        ctx.column_map = context.column_map.to_vec();
+        ctx.suggested_maps = context.suggested_maps.to_vec();
+        ctx.drift = context.drift.clone();
+        ctx.rescaled_columns = context.rescaled_columns.clone();
+        ctx.tolerance_sweep = context.tolerance_sweep.clone();
+        ctx.key_changes = context.key_changes.clone();
+        ctx.date_changes = context.date_changes.clone();
+        ctx.text_changes = context.text_changes.clone();
+        ctx.column_types = context.column_types.clone();
+        ctx.percent_columns = context.percent_columns.clone();
+        ctx.transforms = context.transforms.clone();
+        ctx.columns_excluded_mixed = context.columns_excluded_mixed.clone();
+        ctx.column_normalizations = context.column_normalizations.clone();
+        let mut output = JsonOutput::refusal(ctx, json_refusal(&refusal));
+        if args.collect_refusals {
+            output.refusals = Some(
+                std::iter::once(&refusal)
+                    .chain(&refusal.also)
+                    .map(json_refusal)
+                    .collect(),
+            );
         }
+        PipelineResult::json(Outcome::Refusal, output, context.profile.clone())
     } else {
         let mut lines = Vec::new();
-        lines.push(format!("RVL ERROR ({})", refusal.code));
+        lines.push(sampled_verdict(
+            &format!("RVL ERROR ({})", refusal.code),
+            &context.alignment,
+        ));
         lines.push(String::new());
         let alignment_label = context.key.map(render_identifier_human);
         let header = RefusalHeader {
             old_name: &old_display,
             new_name: &new_display,
-            alignment: match alignment_label.as_deref() {
-                Some(label) => HumanAlignment::Key { column: label },
-                None => HumanAlignment::RowOrder,
-            },
+            alignment: human_alignment(alignment_label.as_deref(), &context.alignment),
+            column_map: context.column_map,
+            suggested_maps: context.suggested_maps,
             profile: to_human_profile(&context.profile),
+            percent_columns: &context.percent_columns,
+            transforms: &context.transforms,
+            columns_excluded_mixed: &context.columns_excluded_mixed,
             dialect_old: context.dialect_old,
             dialect_new: context.dialect_new,
             settings: HumanSettings {
@@ -1461,6 +3436,34 @@ fn render_refusal_with_context(
         };
         lines.extend(render_refusal_header(&header));
         lines.push(String::new());
+        if !context.warnings.is_empty() {
+            lines.extend(render_warning_lines(
+                context.warnings,
+                &old_display,
+                &new_display,
+            ));
+            lines.push(String::new());
+        }
+        if let Some(change) = context.schema_change.as_ref() {
+            lines.extend(render_schema_change_lines(change));
+            lines.push(String::new());
+        }
+        if let Some(report) = context.key_changes.as_ref() {
+            lines.extend(render_key_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = context
+            .date_changes
+            .as_ref()
+            .filter(|report| report.cells_changed > 0)
+        {
+            lines.extend(render_date_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = context.text_changes.as_ref() {
+            lines.extend(render_text_change_lines(report));
+            lines.push(String::new());
+        }
         let body = RefusalBody {
             code: refusal.code,
             detail: &refusal.detail,
@@ -1468,12 +3471,45 @@ fn render_refusal_with_context(
             new_name: &new_display,
         };
         lines.extend(render_refusal_body(&body));
-        PipelineResult {
-            outcome: Outcome::Refusal,
-            output: lines.join("\n"),
-            profile: context.profile.clone(),
+        for also in &refusal.also {
+            let body = RefusalBody {
+                code: also.code,
+                detail: &also.detail,
+                old_name: &old_display,
+                new_name: &new_display,
+            };
+            lines.push(String::new());
+            lines.extend(render_refusal_body(&body).into_iter().skip(1));
+        }
+        if refusal.code == RefusalCode::Diffuse
+            && let Some(line) = context
+                .metrics
+                .coverage_curve
+                .as_deref()
+                .and_then(render_coverage_curve_line)
+        {
+            lines.push(String::new());
+            lines.push(line);
+        }
+        if !context.rescaled_columns.is_empty() {
+            lines.push(String::new());
+            lines.extend(render_rescaled_lines(&context.rescaled_columns));
+        }
+        if let Some(report) = context.drift.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_drift_lines(report));
+        }
+        if let Some(sweep) = context.tolerance_sweep.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_tolerance_sweep_lines(sweep));
         }
+        if let Some(columns) = context.column_types.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_column_type_lines(columns));
+        }
+        PipelineResult::human(Outcome::Refusal, &lines, context.profile.clone())
     };
+    result.refusal = Some(refusal.code);
 
     capsule::write_capsule(
         args,
@@ -1483,28 +3519,121 @@ fn render_refusal_with_context(
     result
 }
 
+/// `--schema-only`: report the fingerprint comparison and stop before alignment.
+/// A changed schema is a REAL CHANGE; matching fingerprints are NO REAL CHANGE.
+fn render_schema_only(
+    key: Option<&[u8]>,
+    old: &ParsedCsv,
+    new: &ParsedCsv,
+    context: RunContext<'_>,
+) -> PipelineResult {
+    let args = context.args;
+    let profile = &context.active_profile.info;
+    let outcome = if context.schema_change.is_some() {
+        Outcome::RealChange
+    } else {
+        Outcome::NoRealChange
+    };
+
+    let result = if args.json {
+        let alignment = match key {
+            Some(key) => JsonAlignment::key(encode_identifier_json(key)),
+            None => JsonAlignment::row_order(),
+        };
+        let counts = Counts {
+            rows_old: Some(old.records.len() as u64),
+            rows_new: Some(new.records.len() as u64),
+            columns_old: Some(old.headers.len() as u64),
+            columns_new: Some(new.headers.len() as u64),
+            footer_rows_old: context.footer_rows.map(|rows| rows.0),
+            footer_rows_new: context.footer_rows.map(|rows| rows.1),
+            comment_lines_old: context.comment_lines.map(|lines| lines.0),
+            comment_lines_new: context.comment_lines.map(|lines| lines.1),
+            ..Counts::default()
+        };
+        let mut ctx = json_context(
+            args,
+            alignment,
+            context.dialect_old,
+            context.dialect_new,
+            profile,
+            counts,
+            Metrics::default(),
+        );
+        ctx.mode = Some(JsonOutputMode::SchemaOnly);
+        ctx.schema_change = context.schema_change.cloned();
+        ctx.warnings = context.warnings.to_vec();
+        ctx.column_map = context.column_map.to_vec();
+        ctx.suggested_maps = context.suggested_maps.to_vec();
+        let output = match outcome {
+            Outcome::RealChange => JsonOutput::real_change(ctx, Vec::new()),
+            _ => JsonOutput::no_real_change(ctx),
+        };
+        PipelineResult::json(outcome, output, profile.clone())
+    } else {
+        let mut lines = vec![
+            "RVL".to_string(),
+            String::new(),
+            match outcome {
+                Outcome::RealChange => "SCHEMA CHANGE".to_string(),
+                _ => "SCHEMA UNCHANGED".to_string(),
+            },
+            String::new(),
+            format!(
+                "Compared: {} -> {}",
+                source_label(args.old_path()),
+                source_label(args.new_path())
+            ),
+            format!(
+                "Schema(old): {} ({} columns)",
+                old.schema.digest,
+                old.headers.len()
+            ),
+            format!(
+                "Schema(new): {} ({} columns)",
+                new.schema.digest,
+                new.headers.len()
+            ),
+            String::new(),
+        ];
+        if !context.warnings.is_empty() {
+            lines.extend(render_warning_lines(
+                context.warnings,
+                &source_label(args.old_path()),
+                &source_label(args.new_path()),
+            ));
+            lines.push(String::new());
+        }
+        match context.schema_change {
+            Some(change) => lines.extend(render_schema_change_lines(change)),
+            None => lines.push("Headers and inferred column types match.".to_string()),
+        }
+        PipelineResult::human(outcome, &lines, profile.clone())
+    };
+
+    capsule::write_capsule(args, &result, &CapsuleRunSummary::no_real_change());
+    result
+}
+
 fn render_no_real_change(
     args: &Args,
     ctx: JsonContext,
     alignment_label: Option<&str>,
+    subtolerance: Option<&[ContributionDetail]>,
 ) -> PipelineResult {
     let run_profile = profile_from_json_context(&ctx);
     let result = if args.json {
-        let output = JsonOutput::no_real_change(ctx)
-            .to_string()
-            .unwrap_or_else(|_| "{}".to_string());
-        PipelineResult {
-            outcome: Outcome::NoRealChange,
-            output,
-            profile: run_profile.clone(),
-        }
+        let mut output = JsonOutput::no_real_change(ctx);
+        output.subtolerance =
+            subtolerance.map(|details| build_json_subtolerance(details, args.explicit));
+        PipelineResult::json(Outcome::NoRealChange, output, run_profile.clone())
     } else {
-        let old_display = display_name(args.old_path());
-        let new_display = display_name(args.new_path());
+        let old_display = source_label(args.old_path());
+        let new_display = source_label(args.new_path());
         let mut lines = vec![
             "RVL".to_string(),
             String::new(),
-            "NO REAL CHANGE".to_string(),
+            sampled_verdict("NO REAL CHANGE", &ctx.alignment),
             String::new(),
         ];
         lines.extend(render_human_header_lines(
@@ -1515,22 +3644,110 @@ fn render_no_real_change(
             &new_display,
         ));
         lines.push(String::new());
+        if !ctx.warnings.is_empty() {
+            lines.extend(render_warning_lines(
+                &ctx.warnings,
+                &old_display,
+                &new_display,
+            ));
+            lines.push(String::new());
+        }
+        if let Some(change) = ctx.schema_change.as_ref() {
+            lines.extend(render_schema_change_lines(change));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx.key_changes.as_ref() {
+            lines.extend(render_key_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx
+            .date_changes
+            .as_ref()
+            .filter(|report| report.cells_changed > 0)
+        {
+            lines.extend(render_date_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx.text_changes.as_ref() {
+            lines.extend(render_text_change_lines(report));
+            lines.push(String::new());
+        }
         let body = NoRealBody {
             max_abs_delta: ctx.metrics.max_abs_delta.unwrap_or(0.0),
             tolerance: args.tolerance,
         };
         lines.extend(render_no_real_body(&body));
-        PipelineResult {
-            outcome: Outcome::NoRealChange,
-            output: lines.join("\n"),
-            profile: run_profile.clone(),
+        if let Some(count) = ctx.counts.format_only_changes {
+            lines.push(render_format_only_line(count));
+        }
+        if let Some(count) = ctx.counts.missing_skipped {
+            lines.push(render_missing_cells_line(count, MissingPolicy::Skip));
         }
+        if let Some(count) = ctx.counts.missing_zeroed {
+            lines.push(render_missing_cells_line(count, MissingPolicy::Zero));
+        }
+        if let Some(line) = render_uncompared_cells(&ctx.counts) {
+            lines.push(line);
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.footer_rows_old, ctx.counts.footer_rows_new) {
+            lines.push(render_footer_rows_line(old, new));
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.comment_lines_old, ctx.counts.comment_lines_new)
+        {
+            lines.push(render_comment_lines_line(old, new));
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.rows_filtered_old, ctx.counts.rows_filtered_new)
+        {
+            lines.push(render_filtered_rows_line(old, new));
+        }
+        if let Some(sample) = ctx.alignment.sample.as_ref() {
+            lines.push(render_sampled_rows_line(
+                sample.rows_sampled,
+                sample.rows_eligible,
+                sample.seed,
+            ));
+        }
+        if let Some(details) = subtolerance
+            && !details.is_empty()
+        {
+            let deltas = build_human_subtolerance(details);
+            lines.push(String::new());
+            lines.extend(render_subtolerance_lines(&deltas, args.explicit));
+        }
+        if let Some(report) = ctx.drift.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_drift_lines(report));
+        }
+        if let Some(sweep) = ctx.tolerance_sweep.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_tolerance_sweep_lines(sweep));
+        }
+        if let Some(columns) = ctx.column_types.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_column_type_lines(columns));
+        }
+        PipelineResult::human(Outcome::NoRealChange, &lines, run_profile.clone())
     };
 
     capsule::write_capsule(args, &result, &CapsuleRunSummary::no_real_change());
     result
 }
 
+/// Receipt for byte-identical inputs that compared as NO REAL CHANGE.
+fn mark_identical_files(mut result: PipelineResult, digest: &str) -> PipelineResult {
+    if result.outcome != Outcome::NoRealChange {
+        return result;
+    }
+    match result.report.as_deref_mut() {
+        Some(report) => {
+            report.identical_files = Some(true);
+            result.output = report.to_string().unwrap_or_else(|_| "{}".to_string());
+        }
+        None => append_footer_line(&mut result.output, &render_identical_files_line(digest)),
+    }
+    result
+}
+
 fn render_real_change(
     args: &Args,
     ctx: JsonContext,
@@ -1538,6 +3755,7 @@ fn render_real_change(
     coverage: f64,
     alignment_label: Option<&str>,
     field_details: &[FieldChangeDetail],
+    expected: Option<&ExpectedBaseline>,
 ) -> PipelineResult {
     let run_profile = profile_from_json_context(&ctx);
     let total_change = ctx.metrics.total_change.unwrap_or(0.0);
@@ -1545,22 +3763,39 @@ fn render_real_change(
     let contributor_summary = build_capsule_contributor_summary(details, total_change, coverage);
 
     let result = if args.json {
-        let contributors = build_json_contributors(details, total_change, args.explicit);
-        let output = JsonOutput::real_change(ctx, contributors)
-            .to_string()
-            .unwrap_or_else(|_| "{}".to_string());
-        PipelineResult {
-            outcome: Outcome::RealChange,
-            output,
-            profile: run_profile.clone(),
-        }
+        let mut contributors = build_json_contributors(details, total_change, args.explicit);
+        let expected = expected.map(|baseline| {
+            for contributor in &mut contributors {
+                contributor.baseline = Some(
+                    if baseline.is_approved(&contributor.row_id, &contributor.column) {
+                        "approved"
+                    } else {
+                        "new"
+                    },
+                );
+            }
+            let verdict = baseline.verdict(
+                contributors
+                    .iter()
+                    .map(|contributor| (contributor.row_id.as_str(), contributor.column.as_str())),
+            );
+            JsonExpected {
+                file: baseline.file.clone(),
+                approved: verdict.approved,
+                new: verdict.new,
+                verdict: verdict.label(),
+            }
+        });
+        let mut json = JsonOutput::real_change(ctx, contributors);
+        json.expected = expected;
+        PipelineResult::json(Outcome::RealChange, json, run_profile.clone())
     } else {
-        let old_display = display_name(args.old_path());
-        let new_display = display_name(args.new_path());
+        let old_display = source_label(args.old_path());
+        let new_display = source_label(args.new_path());
         let mut lines = vec![
             "RVL".to_string(),
             String::new(),
-            "REAL CHANGE".to_string(),
+            sampled_verdict("REAL CHANGE", &ctx.alignment),
             String::new(),
         ];
         lines.extend(render_human_header_lines(
@@ -1571,6 +3806,58 @@ fn render_real_change(
             &new_display,
         ));
         lines.push(String::new());
+        if !ctx.warnings.is_empty() {
+            lines.extend(render_warning_lines(
+                &ctx.warnings,
+                &old_display,
+                &new_display,
+            ));
+            lines.push(String::new());
+        }
+        if let Some(change) = ctx.schema_change.as_ref() {
+            lines.extend(render_schema_change_lines(change));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx.key_changes.as_ref() {
+            lines.extend(render_key_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx
+            .date_changes
+            .as_ref()
+            .filter(|report| report.cells_changed > 0)
+        {
+            lines.extend(render_date_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(report) = ctx.text_changes.as_ref() {
+            lines.extend(render_text_change_lines(report));
+            lines.push(String::new());
+        }
+        if let Some(diffuse) = ctx.diffuse.as_ref() {
+            lines.push(render_diffuse_line(
+                diffuse.top_k_coverage,
+                diffuse.threshold,
+                diffuse.column.as_deref(),
+            ));
+            lines.push(String::new());
+        }
+        if !ctx.rescaled_columns.is_empty() {
+            lines.extend(render_rescaled_lines(&ctx.rescaled_columns));
+            lines.push(String::new());
+        }
+        if let Some(rollup) = ctx
+            .by_column
+            .as_ref()
+            .filter(|rollup| !rollup.columns.is_empty())
+        {
+            lines.extend(render_by_column_lines(rollup, args.explicit));
+            lines.push(String::new());
+        }
+        if let Some(rollup) = ctx.by_row.as_ref().filter(|rollup| !rollup.rows.is_empty()) {
+            lines.extend(render_by_row_lines(rollup, args.explicit));
+            lines.push(String::new());
+        }
         let contributors = build_human_contributors(details, total_change);
         let field_changes = build_human_field_changes(field_details);
         let body = RealChangeBody {
@@ -1583,11 +3870,81 @@ fn render_real_change(
             audit_fields: args.audit_fields,
         };
         lines.extend(render_real_change_body(&body));
-        PipelineResult {
-            outcome: Outcome::RealChange,
-            output: lines.join("\n"),
-            profile: run_profile.clone(),
+        if let Some(line) = ctx
+            .metrics
+            .coverage_curve
+            .as_deref()
+            .and_then(render_coverage_curve_line)
+        {
+            lines.push(String::new());
+            lines.push(line);
+        }
+        if let Some(count) = ctx.counts.format_only_changes {
+            lines.push(String::new());
+            lines.push(render_format_only_line(count));
+        }
+        if let Some(count) = ctx.counts.missing_skipped {
+            lines.push(String::new());
+            lines.push(render_missing_cells_line(count, MissingPolicy::Skip));
+        }
+        if let Some(count) = ctx.counts.missing_zeroed {
+            lines.push(String::new());
+            lines.push(render_missing_cells_line(count, MissingPolicy::Zero));
+        }
+        if let Some(line) = render_uncompared_cells(&ctx.counts) {
+            lines.push(String::new());
+            lines.push(line);
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.footer_rows_old, ctx.counts.footer_rows_new) {
+            lines.push(String::new());
+            lines.push(render_footer_rows_line(old, new));
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.comment_lines_old, ctx.counts.comment_lines_new)
+        {
+            lines.push(String::new());
+            lines.push(render_comment_lines_line(old, new));
+        }
+        if let (Some(old), Some(new)) = (ctx.counts.rows_filtered_old, ctx.counts.rows_filtered_new)
+        {
+            lines.push(String::new());
+            lines.push(render_filtered_rows_line(old, new));
+        }
+        if let Some(sample) = ctx.alignment.sample.as_ref() {
+            lines.push(String::new());
+            lines.push(render_sampled_rows_line(
+                sample.rows_sampled,
+                sample.rows_eligible,
+                sample.seed,
+            ));
+        }
+        if let Some(baseline) = expected {
+            lines.push(String::new());
+            let mut approved = 0;
+            let mut new_labels = Vec::new();
+            for detail in details {
+                let row_id = encode_identifier_json(&row_id_bytes(&detail.id.row_id));
+                let column = encode_identifier_json(&detail.id.column);
+                if baseline.is_approved(&row_id, &column) {
+                    approved += 1;
+                } else {
+                    new_labels.push(render_cell_label(&detail.id));
+                }
+            }
+            lines.extend(render_expected_lines(&baseline.file, approved, &new_labels));
         }
+        if let Some(report) = ctx.drift.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_drift_lines(report));
+        }
+        if let Some(sweep) = ctx.tolerance_sweep.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_tolerance_sweep_lines(sweep));
+        }
+        if let Some(columns) = ctx.column_types.as_ref() {
+            lines.push(String::new());
+            lines.extend(render_column_type_lines(columns));
+        }
+        PipelineResult::human(Outcome::RealChange, &lines, run_profile.clone())
     };
 
     capsule::write_capsule(
@@ -1598,6 +3955,55 @@ fn render_real_change(
     result
 }
 
+/// The uncompared-cells line when any cell was left out of the checked count.
+fn render_uncompared_cells(counts: &Counts) -> Option<String> {
+    if counts.missing_both.is_none() && counts.missing_row.is_none() && counts.unparsable.is_none()
+    {
+        return None;
+    }
+    Some(render_uncompared_cells_line(
+        counts.missing_both.unwrap_or(0),
+        counts.missing_row.unwrap_or(0),
+        counts.unparsable.unwrap_or(0),
+    ))
+}
+
+/// Verdict line, marked SAMPLED under `--sample`.
+fn sampled_verdict(verdict: &str, alignment: &JsonAlignment) -> String {
+    match alignment.sample {
+        Some(_) => format!("{verdict} (SAMPLED)"),
+        None => verdict.to_string(),
+    }
+}
+
+fn human_alignment<'a>(label: Option<&'a str>, alignment: &'a JsonAlignment) -> HumanAlignment<'a> {
+    if let (Some(label), JsonAlignmentMode::GroupBy) = (label, alignment.mode) {
+        return HumanAlignment::GroupBy {
+            column: label,
+            key_map: alignment
+                .key_map
+                .as_ref()
+                .map(|key_map| key_map.file.as_str()),
+            key_normalize: &alignment.key_normalize,
+            dedup: alignment.dedup.as_ref(),
+        };
+    }
+    match (label, alignment.key_map.as_ref()) {
+        (Some(label), Some(key_map)) => HumanAlignment::MappedKey {
+            column: label,
+            key_map: &key_map.file,
+            key_normalize: &alignment.key_normalize,
+            dedup: alignment.dedup.as_ref(),
+        },
+        (Some(label), None) => HumanAlignment::Key {
+            column: label,
+            key_normalize: &alignment.key_normalize,
+            dedup: alignment.dedup.as_ref(),
+        },
+        (None, _) => HumanAlignment::RowOrder,
+    }
+}
+
 fn render_human_header_lines(
     args: &Args,
     ctx: &JsonContext,
@@ -1605,10 +4011,7 @@ fn render_human_header_lines(
     old_name: &str,
     new_name: &str,
 ) -> Vec<String> {
-    let alignment = match alignment_label {
-        Some(label) => HumanAlignment::Key { column: label },
-        None => HumanAlignment::RowOrder,
-    };
+    let alignment = human_alignment(alignment_label, &ctx.alignment);
 
     let columns = ColumnCounts {
         common: ctx.counts.columns_common.unwrap_or(0),
@@ -1630,11 +4033,19 @@ fn render_human_header_lines(
             delimiter: dialect.delimiter.as_bytes()[0],
             quote: dialect.quote.as_bytes()[0],
             escape: dialect.escape.as_ref().map(|s| s.as_bytes()[0]),
+            compression: dialect.compression,
+            encoding: dialect.encoding,
+            confidence: dialect.confidence,
+            decimal: dialect.decimal.unwrap_or_default(),
         })
         .unwrap_or(DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         });
     let dialect_new = ctx
         .dialect
@@ -1644,11 +4055,19 @@ fn render_human_header_lines(
             delimiter: dialect.delimiter.as_bytes()[0],
             quote: dialect.quote.as_bytes()[0],
             escape: dialect.escape.as_ref().map(|s| s.as_bytes()[0]),
+            compression: dialect.compression,
+            encoding: dialect.encoding,
+            confidence: dialect.confidence,
+            decimal: dialect.decimal.unwrap_or_default(),
         })
         .unwrap_or(DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         });
     let profile = profile_from_json_context(ctx);
 
@@ -1656,11 +4075,17 @@ fn render_human_header_lines(
         old_name,
         new_name,
         alignment,
+        column_map: &ctx.column_map,
+        suggested_maps: &ctx.suggested_maps,
         profile: to_human_profile(&profile),
+        percent_columns: &ctx.percent_columns,
+        transforms: &ctx.transforms,
+        columns_excluded_mixed: &ctx.columns_excluded_mixed,
         columns,
         checked,
         dialect_old,
         dialect_new,
+        ranking: &ctx.ranking,
         settings: HumanSettings {
             threshold: args.threshold,
             tolerance: args.tolerance,
@@ -1716,16 +4141,27 @@ fn json_context_with_field_changes(
         None
     };
     JsonContext {
+        version: args.output_version,
         files: Files {
             old: args.old_path().to_string_lossy().to_string(),
             new: args.new_path().to_string_lossy().to_string(),
         },
         alignment,
         dialect: Dialect {
-            old: dialect_old
-                .map(|dialect| DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)),
-            new: dialect_new
-                .map(|dialect| DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)),
+            old: dialect_old.map(|dialect| {
+                DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)
+                    .with_compression(dialect.compression)
+                    .with_encoding(dialect.encoding)
+                    .with_confidence(dialect.confidence)
+                    .with_decimal(dialect.decimal)
+            }),
+            new: dialect_new.map(|dialect| {
+                DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)
+                    .with_compression(dialect.compression)
+                    .with_encoding(dialect.encoding)
+                    .with_confidence(dialect.confidence)
+                    .with_decimal(dialect.decimal)
+            }),
         },
         profile_used: profile.used,
         profile_id: profile.profile_id.clone(),
@@ -1740,9 +4176,34 @@ fn json_context_with_field_changes(
         audit,
         threshold: args.threshold,
         tolerance: args.tolerance,
+        ranking: args.ranking(),
+        missing_policy: (args.on_missing != MissingPolicy::Refuse).then_some(args.on_missing),
+        config: args.config_receipt.clone(),
         counts,
         metrics,
         field_changes,
+        schema_change: None,
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        diffuse: None,
+        by_column: None,
+        by_row: None,
+        column_deltas: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        warnings: Vec::new(),
+        column_map: Vec::new(),
+        suggested_maps: Vec::new(),
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
+        limits: JsonLimits {
+            max_contributors: args.top as u64,
+        },
     }
 }
 
@@ -1808,7 +4269,7 @@ fn build_json_contributors(
             0.0
         };
         cumulative += share;
-        contributors.push(crate::output::json::Contributor::from_bytes(
+        let mut contributor = crate::output::json::Contributor::from_bytes(
             &row_id_bytes(&detail.id.row_id),
             &detail.id.column,
             detail.old,
@@ -1818,11 +4279,44 @@ fn build_json_contributors(
             share,
             cumulative,
             explicit,
-        ));
+        );
+        contributor.kind = detail.id.kind.entity_label();
+        contributors.push(contributor);
     }
     contributors
 }
 
+fn build_json_subtolerance(
+    details: &[ContributionDetail],
+    explicit: bool,
+) -> Vec<JsonSubtolerance> {
+    details
+        .iter()
+        .map(|detail| {
+            JsonSubtolerance::from_bytes(
+                &row_id_bytes(&detail.id.row_id),
+                &detail.id.column,
+                detail.old,
+                detail.new,
+                detail.delta,
+                explicit,
+            )
+        })
+        .collect()
+}
+
+fn build_human_subtolerance(details: &[ContributionDetail]) -> Vec<SubtoleranceDelta> {
+    details
+        .iter()
+        .map(|detail| SubtoleranceDelta {
+            label: render_cell_label(&detail.id),
+            old: detail.old,
+            new: detail.new,
+            delta: detail.delta,
+        })
+        .collect()
+}
+
 fn build_json_field_changes(details: &[FieldChangeDetail], explicit: bool) -> Vec<JsonFieldChange> {
     details
         .iter()
@@ -1882,7 +4376,7 @@ fn build_capsule_contributor_summary(
 fn row_id_bytes(row_id: &RowId) -> Vec<u8> {
     match row_id {
         RowId::RowIndex(index) => index.to_string().into_bytes(),
-        RowId::Key(bytes) => bytes.clone(),
+        RowId::Key(bytes) => bytes.to_vec(),
     }
 }
 
@@ -1892,7 +4386,11 @@ fn render_cell_label(cell_id: &CellId) -> String {
         RowId::Key(bytes) => render_identifier_human(bytes),
     };
     let column = render_identifier_human(&cell_id.column);
-    format!("{row_label}.{column}")
+    match cell_id.kind {
+        CellKind::Changed => format!("{row_label}.{column}"),
+        CellKind::RowAdded => format!("{row_label}.{column} (row added)"),
+        CellKind::RowRemoved => format!("{row_label}.{column} (row removed)"),
+    }
 }
 
 fn scope_intersection(
@@ -1947,20 +4445,30 @@ fn count_columns(
         .count() as u64
 }
 
+fn emit_dialect(events: &mut EventStream, side: FileSide, parsed: &ParsedCsv) {
+    let dialect = dialect_receipt(parsed);
+    events.emit(&Event::DialectDetected {
+        file: side.as_str(),
+        dialect: DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)
+            .with_compression(dialect.compression)
+            .with_encoding(dialect.encoding)
+            .with_confidence(dialect.confidence)
+            .with_decimal(dialect.decimal),
+    });
+}
+
 fn dialect_receipt(parsed: &ParsedCsv) -> DialectReceipt {
     DialectReceipt {
         delimiter: parsed.delimiter,
         quote: b'"',
         escape: parsed.escape.escape_byte(),
+        compression: parsed.compression,
+        encoding: parsed.encoding,
+        confidence: parsed.confidence,
+        decimal: parsed.decimal,
     }
 }
 
-fn display_name(path: &Path) -> String {
-    path.file_name()
-        .map(|name| name.to_string_lossy().to_string())
-        .unwrap_or_else(|| path.to_string_lossy().to_string())
-}
-
 fn map_encoding_issue(bytes: &[u8], issue: InputEncodingIssue) -> EncodingIssue {
     match issue {
         InputEncodingIssue::Utf16Or32Bom => {
@@ -1981,6 +4489,8 @@ struct ContributionDetail {
     new: f64,
     delta: f64,
     contribution: f64,
+    /// Set for ledger cells so `--explore` can link to the source rows.
+    lines: Option<RowLines>,
 }
 
 #[derive(Clone, Default)]
@@ -2010,8 +4520,8 @@ fn sort_field_change_details(details: &mut [FieldChangeDetail]) {
 }
 
 fn non_numeric_columns(
-    common: &[crate::numeric::columns::CommonColumn],
-    numeric: &[crate::numeric::columns::CommonColumn],
+    common: &[CommonColumn],
+    numeric: &[CommonColumn],
 ) -> Vec<crate::numeric::columns::CommonColumn> {
     let numeric_names: HashSet<&[u8]> = numeric
         .iter()
@@ -2024,37 +4534,36 @@ fn non_numeric_columns(
         .collect()
 }
 
+fn intern_column_names(interner: &mut Interner, columns: &[CommonColumn]) -> Vec<Symbol> {
+    columns
+        .iter()
+        .map(|column| interner.intern(&column.name))
+        .collect()
+}
+
 fn collect_field_changes(
     alignment: &AlignmentContext,
-    columns: &[crate::numeric::columns::CommonColumn],
+    columns: &[CommonColumn],
     max_details: u64,
+    interner: &mut Interner,
 ) -> FieldChangeAudit {
     let mut audit = FieldChangeAudit::default();
+    let column_names = intern_column_names(interner, columns);
 
     match alignment {
         AlignmentContext::Key { key_rows, .. } => {
             for row in key_rows {
-                let row_id = RowId::key(row.key.clone());
-                for column in columns {
-                    let old_raw = row
-                        .old
-                        .fields
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = row
-                        .new
-                        .fields
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
+                let row_id = RowId::key(interner.intern(&row.key));
+                for (column, column_name) in columns.iter().zip(&column_names) {
+                    let old_raw = row.old.fields.field(column.old_index);
+                    let new_raw = row.new.fields.field(column.new_index);
                     if old_raw == new_raw {
                         continue;
                     }
                     audit.changed = audit.changed.saturating_add(1);
                     if audit.details.len() < max_details as usize {
                         audit.details.push(FieldChangeDetail {
-                            id: CellId::new(row_id.clone(), column.name.clone()),
+                            id: CellId::new(row_id.clone(), Arc::clone(column_name)),
                             old: old_raw.to_vec(),
                             new: new_raw.to_vec(),
                         });
@@ -2065,22 +4574,16 @@ fn collect_field_changes(
         AlignmentContext::RowOrder { old_rows, new_rows } => {
             for (idx, (old_row, new_row)) in old_rows.iter().zip(new_rows.iter()).enumerate() {
                 let row_id = RowId::row_index(idx + 1);
-                for column in columns {
-                    let old_raw = old_row
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = new_row
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
+                for (column, column_name) in columns.iter().zip(&column_names) {
+                    let old_raw = old_row.field(column.old_index);
+                    let new_raw = new_row.field(column.new_index);
                     if old_raw == new_raw {
                         continue;
                     }
                     audit.changed = audit.changed.saturating_add(1);
                     if audit.details.len() < max_details as usize {
                         audit.details.push(FieldChangeDetail {
-                            id: CellId::new(row_id.clone(), column.name.clone()),
+                            id: CellId::new(row_id.clone(), Arc::clone(column_name)),
                             old: old_raw.to_vec(),
                             new: new_raw.to_vec(),
                         });
@@ -2093,103 +4596,154 @@ fn collect_field_changes(
     audit
 }
 
-fn collect_details(
+/// Visit the old/new fields of every aligned row pair.
+fn for_each_aligned_row(
     alignment: &AlignmentContext,
-    columns: &[crate::numeric::columns::CommonColumn],
-    top: &[crate::diff::heap::Contributor<CellId>],
-    tolerance: f64,
-) -> Vec<ContributionDetail> {
-    let mut details: Vec<Option<ContributionDetail>> = vec![None; top.len()];
-    let mut tracker = ToleranceTracker::new(tolerance);
-
+    mut visit: impl FnMut(&OwnedRecord, &OwnedRecord),
+) {
     match alignment {
         AlignmentContext::Key { key_rows, .. } => {
             for row in key_rows.iter() {
-                let row_id = RowId::key(row.key.clone());
-                for column in columns {
-                    let old_raw = row
-                        .old
-                        .fields
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = row
-                        .new
-                        .fields
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    if is_missing_token(old_raw) && is_missing_token(new_raw) {
-                        continue;
-                    }
-                    let (old_val, new_val) = match (parse_numeric(old_raw), parse_numeric(new_raw))
-                    {
-                        (Some(old_val), Some(new_val)) => (old_val, new_val),
-                        _ => continue,
-                    };
-                    let (delta, contribution) = tracker.apply(old_val, new_val);
-                    if contribution == 0.0 {
-                        continue;
-                    }
-                    let cell_id = CellId::new(row_id.clone(), column.name.clone());
-                    for (idx, top_item) in top.iter().enumerate() {
-                        if top_item.id == cell_id {
-                            details[idx] = Some(ContributionDetail {
-                                id: cell_id.clone(),
-                                old: old_val,
-                                new: new_val,
-                                delta,
-                                contribution,
-                            });
-                        }
-                    }
-                }
+                visit(&row.old.fields, &row.new.fields);
             }
         }
-        AlignmentContext::RowOrder {
-            old_rows, new_rows, ..
-        } => {
+        AlignmentContext::RowOrder { old_rows, new_rows } => {
+            for (old_row, new_row) in old_rows.iter().zip(new_rows.iter()) {
+                visit(old_row, new_row);
+            }
+        }
+    }
+}
+
+/// Like [`for_each_aligned_row`], with each row's label: its key, or its
+/// 1-based position in row-order mode.
+fn for_each_labelled_row(
+    alignment: &AlignmentContext,
+    mut visit: impl FnMut(&[u8], &OwnedRecord, &OwnedRecord),
+) {
+    match alignment {
+        AlignmentContext::Key { key_rows, .. } => {
+            for row in key_rows {
+                visit(&row.key, &row.old.fields, &row.new.fields);
+            }
+        }
+        AlignmentContext::RowOrder { old_rows, new_rows } => {
             for (idx, (old_row, new_row)) in old_rows.iter().zip(new_rows.iter()).enumerate() {
-                let row_id = RowId::row_index(idx + 1);
-                for column in columns {
-                    let old_raw = old_row
-                        .get(column.old_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    let new_raw = new_row
-                        .get(column.new_index)
-                        .map(|v| v.as_slice())
-                        .unwrap_or(b"");
-                    if is_missing_token(old_raw) && is_missing_token(new_raw) {
-                        continue;
-                    }
-                    let (old_val, new_val) = match (parse_numeric(old_raw), parse_numeric(new_raw))
-                    {
-                        (Some(old_val), Some(new_val)) => (old_val, new_val),
-                        _ => continue,
-                    };
-                    let (delta, contribution) = tracker.apply(old_val, new_val);
-                    if contribution == 0.0 {
-                        continue;
-                    }
-                    let cell_id = CellId::new(row_id.clone(), column.name.clone());
-                    for (idx, top_item) in top.iter().enumerate() {
-                        if top_item.id == cell_id {
-                            details[idx] = Some(ContributionDetail {
-                                id: cell_id.clone(),
-                                old: old_val,
-                                new: new_val,
-                                delta,
-                                contribution,
-                            });
-                        }
-                    }
-                }
+                visit((idx + 1).to_string().as_bytes(), old_row, new_row);
             }
         }
     }
+}
+
+/// Changed text cells per column for `--include-text`.
+fn text_change_report(
+    alignment: &AlignmentContext,
+    columns: &[CommonColumn],
+    args: &Args,
+) -> TextChangeReport {
+    let mut changes: Vec<TextColumnChanges> = columns
+        .iter()
+        .map(|column| TextColumnChanges::new(column.name.clone()))
+        .collect();
+    for_each_labelled_row(alignment, |row_id, old_row, new_row| {
+        for (column, changes) in columns.iter().zip(changes.iter_mut()) {
+            let old = old_row.field(column.old_index);
+            let new = new_row.field(column.new_index);
+            changes.observe(row_id, old, new, args.explicit);
+        }
+    });
+    TextChangeReport { columns: changes }
+}
 
-    details.into_iter().flatten().collect()
+/// Currency symbols written in each numeric column's aligned cells; columns
+/// without any are left out.
+fn column_normalizations(
+    alignment: &AlignmentContext,
+    columns: &[CommonColumn],
+    number_format: NumberFormat<'_>,
+) -> Vec<ColumnNormalization> {
+    let mut symbols = vec![BTreeSet::new(); columns.len()];
+    for_each_aligned_row(alignment, |old_row, new_row| {
+        for (column, symbols) in columns.iter().zip(symbols.iter_mut()) {
+            let sides = [old_row.get(column.old_index), new_row.get(column.new_index)];
+            for raw in sides.into_iter().flatten() {
+                if let Some(symbol) = number_format.currency_symbol(raw)
+                    && number_format.parse(raw).is_some()
+                {
+                    symbols.insert(symbol);
+                }
+            }
+        }
+    });
+    columns
+        .iter()
+        .zip(symbols)
+        .filter(|(_, symbols)| !symbols.is_empty())
+        .map(|(column, symbols)| ColumnNormalization {
+            column: column.name.clone(),
+            currency_symbols: symbols.into_iter().map(str::to_string).collect(),
+        })
+        .collect()
+}
+
+/// Numeric columns where at least one aligned cell was written as `N%`.
+fn percent_columns(alignment: &AlignmentContext, columns: &[CommonColumn]) -> Vec<Vec<u8>> {
+    let mut seen = vec![false; columns.len()];
+    let observe = |old_row: &OwnedRecord, new_row: &OwnedRecord| {
+        for (column, seen) in columns.iter().zip(seen.iter_mut()) {
+            let sides = [old_row.get(column.old_index), new_row.get(column.new_index)];
+            *seen |= sides.into_iter().flatten().any(has_percent_suffix);
+        }
+    };
+    for_each_aligned_row(alignment, observe);
+    columns
+        .iter()
+        .zip(seen)
+        .filter(|(_, seen)| *seen)
+        .map(|(column, _)| column.name.clone())
+        .collect()
+}
+
+/// Classify each numeric column from the header and the values on both sides.
+fn semantic_column_types(
+    alignment: &AlignmentContext,
+    columns: &[CommonColumn],
+    base_tolerance: f64,
+    number_format: NumberFormat<'_>,
+) -> Vec<ColumnSemantic> {
+    let mut evidence = vec![ColumnEvidence::default(); columns.len()];
+    let observe = |old_row: &OwnedRecord, new_row: &OwnedRecord| {
+        for (column, evidence) in columns.iter().zip(evidence.iter_mut()) {
+            let sides = [old_row.get(column.old_index), new_row.get(column.new_index)];
+            for raw in sides.into_iter().flatten() {
+                if let Some(value) = number_format.parse(raw) {
+                    evidence.observe(raw, value);
+                }
+            }
+        }
+    };
+    for_each_aligned_row(alignment, observe);
+    let names: Vec<&[u8]> = columns
+        .iter()
+        .map(|column| column.name.as_slice())
+        .collect();
+    classify_columns(&names, &evidence, base_tolerance)
+}
+
+/// Details for the listed contributors. Heap entries already hold the old
+/// and new values observed during the scan, so no second pass over the rows
+/// is needed.
+fn collect_details(top: &[crate::diff::heap::Contributor<CellId>]) -> Vec<ContributionDetail> {
+    top.iter()
+        .map(|item| ContributionDetail {
+            id: item.id.clone(),
+            old: item.old,
+            new: item.new,
+            delta: item.delta,
+            contribution: item.contribution,
+            lines: None,
+        })
+        .collect()
 }
 
 impl RefusalPayload {
@@ -2197,10 +4751,19 @@ impl RefusalPayload {
         Self {
             code,
             detail: RefusalDetail::with_default_next(kind, paths),
+            also: Vec::new(),
         }
     }
 }
 
+fn json_refusal(refusal: &RefusalPayload) -> JsonRefusal {
+    JsonRefusal::new(
+        refusal.code,
+        refusal.detail.reason(refusal.code),
+        refusal_detail_json(&refusal.detail),
+    )
+}
+
 fn refusal_detail_json(detail: &RefusalDetail) -> Value {
     match &detail.kind {
         RefusalKind::Io { file, error } => json!({
@@ -2213,6 +4776,7 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
                 EncodingIssue::Utf16 => "utf16",
                 EncodingIssue::Utf32 => "utf32",
                 EncodingIssue::NulByte => "nul_byte",
+                EncodingIssue::InvalidUtf16 => "invalid_utf16",
             },
         }),
         RefusalKind::CsvParse { file, line, column } => json!({
@@ -2235,6 +4799,11 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
                 "issue": "extra_fields",
                 "record": record,
             }),
+            HeadersIssue::MissingColumn { name } => json!({
+                "file": file.as_str(),
+                "issue": "missing_column",
+                "name": encode_identifier_json(name),
+            }),
         },
         RefusalKind::NoKey { key_column } => json!({
             "key_column": encode_identifier_json(key_column),
@@ -2262,12 +4831,23 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
             extra_in_new,
             missing_samples,
             extra_samples,
-        } => json!({
-            "missing_in_new": missing_in_new,
-            "extra_in_new": extra_in_new,
-            "missing_samples": missing_samples.iter().map(|k| encode_identifier_json(k)).collect::<Vec<_>>(),
-            "extra_samples": extra_samples.iter().map(|k| encode_identifier_json(k)).collect::<Vec<_>>(),
-        }),
+            normalize_hint,
+        } => {
+            let mut detail = json!({
+                "missing_in_new": missing_in_new,
+                "extra_in_new": extra_in_new,
+                "missing_samples": missing_samples.iter().map(|k| encode_identifier_json(k)).collect::<Vec<_>>(),
+                "extra_samples": extra_samples.iter().map(|k| encode_identifier_json(k)).collect::<Vec<_>>(),
+            });
+            if let Some(hint) = normalize_hint {
+                detail["normalize_hint"] = json!({
+                    "step": hint.step.as_str(),
+                    "matched": hint.matched,
+                    "missing": hint.missing,
+                });
+            }
+            detail
+        }
         RefusalKind::RowCount {
             rows_old,
             rows_new,
@@ -2295,6 +4875,13 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
                 DialectSuggestion::SepDirective(byte) => format!("sep={}", byte_to_string(*byte)),
             },
         }),
+        RefusalKind::DialectMismatch {
+            old_delimiter,
+            new_delimiter,
+        } => json!({
+            "old_delimiter": byte_to_string(*old_delimiter),
+            "new_delimiter": byte_to_string(*new_delimiter),
+        }),
         RefusalKind::AmbiguousProfile {
             profile_path,
             profile_id,
@@ -2329,11 +4916,36 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
             column,
             value,
             key_value,
+            profile,
+            cross_tab,
         } => {
+            let histogram = &profile.histogram;
+            let samples: Vec<Value> = profile
+                .samples
+                .iter()
+                .map(|sample| {
+                    json!({
+                        "file": match sample.side {
+                            ColumnSide::Old => "old",
+                            ColumnSide::New => "new",
+                        },
+                        "record": sample.record,
+                        "value": encode_identifier_json(&sample.value),
+                    })
+                })
+                .collect();
             let mut obj = json!({
                 "file": file.as_str(),
                 "column": encode_identifier_json(column),
                 "value": encode_identifier_json(value),
+                "types": {
+                    "numeric": histogram.numeric,
+                    "currency": histogram.currency,
+                    "percent": histogram.percent,
+                    "text": histogram.text,
+                    "dates": histogram.dates,
+                },
+                "samples": samples,
             });
             if let Some(record) = record {
                 obj["record"] = json!(record);
@@ -2341,6 +4953,12 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
             if let Some(key) = key_value {
                 obj["key"] = json!(encode_identifier_json(key));
             }
+            if let Some(signal) = cross_tab {
+                obj["cross_tab"] = json!({
+                    "mixed_columns": signal.mixed_columns,
+                    "columns": signal.columns,
+                });
+            }
             obj
         }
         RefusalKind::NoNumeric => json!({}),
@@ -2367,10 +4985,17 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
         RefusalKind::Diffuse {
             top_k_coverage,
             threshold,
-        } => json!({
-            "top_k_coverage": top_k_coverage,
-            "threshold": threshold,
-        }),
+            column,
+        } => {
+            let mut detail = json!({
+                "top_k_coverage": top_k_coverage,
+                "threshold": threshold,
+            });
+            if let Some(column) = column {
+                detail["column"] = json!(encode_identifier_json(column));
+            }
+            detail
+        }
         RefusalKind::AuditLimit {
             changed_cells,
             max_audit_changes,
@@ -2380,6 +5005,37 @@ fn refusal_detail_json(detail: &RefusalDetail) -> Value {
         }),
         RefusalKind::AuditFieldsRequiresExhaustive => json!({}),
         RefusalKind::AuditFieldsRequiresProfile => json!({}),
+        RefusalKind::HeaderOverlap {
+            columns_old,
+            columns_new,
+            columns_common,
+            overlap,
+            min_overlap,
+            ..
+        } => json!({
+            "columns_old": columns_old,
+            "columns_new": columns_new,
+            "columns_common": columns_common,
+            "overlap": overlap,
+            "min_overlap": min_overlap,
+        }),
+        RefusalKind::Resource {
+            estimated_bytes,
+            max_memory,
+            row_order_bytes,
+        } => json!({
+            "estimated_bytes": estimated_bytes,
+            "max_memory": max_memory,
+            "row_order_bytes": row_order_bytes,
+        }),
+        RefusalKind::KeyMap { file, reason } => json!({
+            "file": file,
+            "reason": reason,
+        }),
+        RefusalKind::Expected { file, reason } => json!({
+            "file": file,
+            "reason": reason,
+        }),
     }
 }
 
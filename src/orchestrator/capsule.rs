@@ -3,8 +3,21 @@ use std::path::Path;
 
 use serde::Serialize;
 
-use crate::cli::args::Args;
+use crate::alignment::key_dedup::DedupMode;
+use crate::alignment::row_filter::RowPredicate;
+use crate::cli::args::{Args, DEFAULT_MIN_HEADER_OVERLAP};
 use crate::cli::exit::Outcome;
+use crate::csv::encoding::EncodingMode;
+use crate::diff::coverage::{CoverageMode, DiffusePolicy};
+use crate::diff::heap::MAX_CONTRIBUTORS;
+use crate::diff::rank::RankMode;
+use crate::diff::tolerance::ColumnTolerance;
+use crate::normalize::column_map::ColumnMapping;
+use crate::normalize::headers::HeaderMatch;
+use crate::numeric::columns::MixedTypesPolicy;
+use crate::numeric::missing::MissingPolicy;
+use crate::numeric::parse::DecimalSeparator;
+use crate::numeric::transform::ColumnTransform;
 use crate::profile::render_profile_yaml_with_registry_override;
 use crate::witness::hash::hash_bytes;
 
@@ -87,14 +100,108 @@ struct CapsuleArgs {
     old: String,
     new: String,
     key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key_map: Option<String>,
+    /// `--key-normalize` steps, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    key_normalize: Vec<&'static str>,
+    /// `--map` values as `old=new`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    column_map: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    columns: Vec<String>,
+    /// `--header-match` when not the default `exact`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    header_match: Option<&'static str>,
+    allow_missing_keys: bool,
+    include_entity_changes: bool,
+    /// `--allow-trailing-rows` when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_trailing_rows: Option<u64>,
+    /// `--where` predicates.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    where_filters: Vec<String>,
+    /// `--sample` and `--seed` when sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sample: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    /// `--dedup` when not the default `refuse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dedup: Option<&'static str>,
     profile: Option<String>,
     profile_id: Option<String>,
     threshold: f64,
     tolerance: f64,
+    smart_tolerance: bool,
+    /// `--column-tolerance` values as `column=tolerance`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    column_tolerances: Vec<String>,
+    /// `--transform` values as `[old:|new:]column=transform`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    transforms: Vec<String>,
+    /// `--tolerance-sweep` levels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tolerance_sweep: Option<Vec<f64>>,
+    min_header_overlap: f64,
+    top: usize,
+    /// `--rank` when not the default `abs`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rank: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight_col: Option<String>,
     delimiter: Option<String>,
+    /// `--skip-rows` when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skip_rows: Option<String>,
+    /// `--skip-footer` when set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skip_footer: Option<String>,
+    /// `--encoding` when not the `utf8` default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    strict_dialect: bool,
     exhaustive: bool,
     audit_fields: bool,
     max_audit_changes: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_memory: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show_subtolerance: Option<usize>,
+    dedupe_rows: bool,
+    cross_tab: bool,
+    parse_percent: bool,
+    /// `--decimal` when not the default `dot`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decimal: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    currency_symbols: Vec<String>,
+    lenient_numbers: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    na_values: Vec<String>,
+    /// `--on-missing` when not the default `refuse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_missing: Option<&'static str>,
+    /// `--on-mixed-types` when not the default `refuse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_mixed_types: Option<&'static str>,
+    collect_refusals: bool,
+    /// `--on-diffuse` when not the default `refuse`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    on_diffuse: Option<&'static str>,
+    /// `--coverage` when not the default `total`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    coverage: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    drift: Option<&'static str>,
+    include_text: bool,
+    schema_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
     json: bool,
     no_witness: bool,
 }
@@ -132,11 +239,11 @@ pub(super) fn write_capsule(args: &Args, result: &PipelineResult, summary: &Caps
 
     let old_path = args.old_path().to_string_lossy().to_string();
     let new_path = args.new_path().to_string_lossy().to_string();
-    let old_bytes = match fs::read(args.old_path()) {
+    let old_bytes = match args.old_source().read() {
         Ok(bytes) => bytes,
         Err(_) => return,
     };
-    let new_bytes = match fs::read(args.new_path()) {
+    let new_bytes = match args.new_source().read() {
         Ok(bytes) => bytes,
         Err(_) => return,
     };
@@ -161,6 +268,30 @@ pub(super) fn write_capsule(args: &Args, result: &PipelineResult, summary: &Caps
         old: old_path,
         new: new_path,
         key: args.key.clone(),
+        group_by: args.group_by.clone(),
+        key_normalize: args
+            .key_normalize
+            .iter()
+            .map(|step| step.as_str())
+            .collect(),
+        key_map: args
+            .key_map
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
+        column_map: args.column_map.iter().map(column_map_arg).collect(),
+        columns: args.columns.clone(),
+        header_match: (args.header_match != HeaderMatch::Exact).then(|| args.header_match.as_str()),
+        allow_missing_keys: args.allow_missing_keys,
+        include_entity_changes: args.include_entity_changes,
+        allow_trailing_rows: (args.allow_trailing_rows > 0).then_some(args.allow_trailing_rows),
+        where_filters: args
+            .where_filters
+            .iter()
+            .map(RowPredicate::as_arg)
+            .collect(),
+        sample: args.sample,
+        seed: args.sample.map(|_| args.seed),
+        dedup: (args.dedup != DedupMode::Refuse).then(|| args.dedup.as_str()),
         profile: args
             .profile
             .as_ref()
@@ -168,10 +299,56 @@ pub(super) fn write_capsule(args: &Args, result: &PipelineResult, summary: &Caps
         profile_id: args.profile_id.clone(),
         threshold: args.threshold,
         tolerance: args.tolerance,
+        smart_tolerance: args.smart_tolerance,
+        column_tolerances: args
+            .column_tolerances
+            .iter()
+            .map(column_tolerance_arg)
+            .collect(),
+        transforms: args
+            .transforms
+            .iter()
+            .map(ColumnTransform::as_arg)
+            .collect(),
+        tolerance_sweep: args
+            .tolerance_sweep
+            .as_ref()
+            .map(|sweep| sweep.levels.clone()),
+        min_header_overlap: args.min_header_overlap,
+        top: args.top,
+        rank: (args.rank != RankMode::Abs).then(|| args.rank.as_str()),
+        weight_col: args.weight_col.clone(),
         delimiter: args.delimiter.map(|d| format!("0x{d:02x}")),
+        skip_rows: args.skip_rows.is_set().then(|| args.skip_rows.as_arg()),
+        skip_footer: args.skip_footer.is_set().then(|| args.skip_footer.as_arg()),
+        encoding: (args.encoding != EncodingMode::Utf8).then(|| args.encoding.as_str()),
+        comment: args.comment.clone(),
+        strict_dialect: args.strict_dialect,
         exhaustive: args.exhaustive,
         audit_fields: args.audit_fields,
         max_audit_changes: args.max_audit_changes,
+        max_memory: args.max_memory,
+        show_subtolerance: args.show_subtolerance,
+        dedupe_rows: args.dedupe_rows,
+        cross_tab: args.cross_tab,
+        parse_percent: args.parse_percent,
+        decimal: (args.decimal != DecimalSeparator::Dot).then(|| args.decimal.as_str()),
+        currency_symbols: args.currency_symbols.clone(),
+        lenient_numbers: args.lenient_numbers,
+        na_values: args.na_values.clone(),
+        on_missing: (args.on_missing != MissingPolicy::Refuse).then(|| args.on_missing.as_str()),
+        on_mixed_types: (args.on_mixed_types != MixedTypesPolicy::Refuse)
+            .then(|| args.on_mixed_types.as_str()),
+        collect_refusals: args.collect_refusals,
+        on_diffuse: (args.on_diffuse != DiffusePolicy::Refuse).then(|| args.on_diffuse.as_str()),
+        coverage: (args.coverage != CoverageMode::Total).then(|| args.coverage.as_str()),
+        drift: args.drift.map(|metric| metric.as_str()),
+        include_text: args.include_text,
+        schema_only: args.schema_only,
+        expected: args
+            .expected
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string()),
         json: args.json,
         no_witness: args.no_witness,
     };
@@ -324,6 +501,22 @@ pub(super) fn write_capsule(args: &Args, result: &PipelineResult, summary: &Caps
     let _ = fs::write(capsule_dir.join(Path::new("manifest.json")), manifest_json);
 }
 
+fn column_map_arg(mapping: &ColumnMapping) -> String {
+    format!(
+        "{}={}",
+        String::from_utf8_lossy(&mapping.old),
+        String::from_utf8_lossy(&mapping.new)
+    )
+}
+
+fn column_tolerance_arg(entry: &ColumnTolerance) -> String {
+    format!(
+        "{}={}",
+        String::from_utf8_lossy(&entry.column),
+        entry.tolerance
+    )
+}
+
 fn build_replay_command(args: &Args, use_local_profile: bool) -> String {
     let mut parts = vec![
         "rvl".to_string(),
@@ -335,6 +528,59 @@ fn build_replay_command(args: &Args, use_local_profile: bool) -> String {
         parts.push("--key".to_string());
         parts.push(shell_escape(key));
     }
+    if let Some(group_by) = args.group_by.as_deref() {
+        parts.push("--group-by".to_string());
+        parts.push(shell_escape(group_by));
+    }
+    if let Some(key_map) = args.key_map.as_ref() {
+        parts.push("--key-map".to_string());
+        parts.push(shell_escape(&key_map.to_string_lossy()));
+    }
+    if !args.key_normalize.is_empty() {
+        let steps: Vec<&str> = args
+            .key_normalize
+            .iter()
+            .map(|step| step.as_str())
+            .collect();
+        parts.push("--key-normalize".to_string());
+        parts.push(steps.join(","));
+    }
+    for mapping in &args.column_map {
+        parts.push("--map".to_string());
+        parts.push(shell_escape(&column_map_arg(mapping)));
+    }
+    for column in &args.columns {
+        parts.push("--columns".to_string());
+        parts.push(shell_escape(column));
+    }
+    if args.header_match != HeaderMatch::Exact {
+        parts.push("--header-match".to_string());
+        parts.push(args.header_match.as_str().to_string());
+    }
+    if args.allow_missing_keys {
+        parts.push("--allow-missing-keys".to_string());
+    }
+    if args.include_entity_changes {
+        parts.push("--include-entity-changes".to_string());
+    }
+    if args.allow_trailing_rows > 0 {
+        parts.push("--allow-trailing-rows".to_string());
+        parts.push(args.allow_trailing_rows.to_string());
+    }
+    for predicate in &args.where_filters {
+        parts.push("--where".to_string());
+        parts.push(shell_escape(&predicate.as_arg()));
+    }
+    if let Some(sample) = args.sample {
+        parts.push("--sample".to_string());
+        parts.push(sample.to_string());
+        parts.push("--seed".to_string());
+        parts.push(args.seed.to_string());
+    }
+    if args.dedup != DedupMode::Refuse {
+        parts.push("--dedup".to_string());
+        parts.push(args.dedup.as_str().to_string());
+    }
     if use_local_profile {
         parts.push("--profile".to_string());
         parts.push(PROFILE_ARTIFACT_PATH.to_string());
@@ -349,10 +595,59 @@ fn build_replay_command(args: &Args, use_local_profile: bool) -> String {
     parts.push(args.threshold.to_string());
     parts.push("--tolerance".to_string());
     parts.push(args.tolerance.to_string());
+    if args.smart_tolerance {
+        parts.push("--smart-tolerance".to_string());
+    }
+    for entry in &args.column_tolerances {
+        parts.push("--column-tolerance".to_string());
+        parts.push(shell_escape(&column_tolerance_arg(entry)));
+    }
+    for entry in &args.transforms {
+        parts.push("--transform".to_string());
+        parts.push(shell_escape(&entry.as_arg()));
+    }
+    if let Some(sweep) = args.tolerance_sweep.as_ref() {
+        parts.push(format!("--tolerance-sweep={}", sweep.as_arg()));
+    }
+    if args.min_header_overlap != DEFAULT_MIN_HEADER_OVERLAP {
+        parts.push("--min-header-overlap".to_string());
+        parts.push(args.min_header_overlap.to_string());
+    }
+    if args.top != MAX_CONTRIBUTORS {
+        parts.push("--top".to_string());
+        parts.push(args.top.to_string());
+    }
+    if args.rank != RankMode::Abs {
+        parts.push("--rank".to_string());
+        parts.push(args.rank.as_str().to_string());
+    }
+    if let Some(column) = args.weight_col.as_deref() {
+        parts.push("--weight-col".to_string());
+        parts.push(shell_escape(column));
+    }
     if let Some(delimiter) = args.delimiter {
         parts.push("--delimiter".to_string());
         parts.push(format!("0x{delimiter:02x}"));
     }
+    if args.skip_rows.is_set() {
+        parts.push("--skip-rows".to_string());
+        parts.push(args.skip_rows.as_arg());
+    }
+    if args.skip_footer.is_set() {
+        parts.push("--skip-footer".to_string());
+        parts.push(args.skip_footer.as_arg());
+    }
+    if args.encoding != EncodingMode::Utf8 {
+        parts.push("--encoding".to_string());
+        parts.push(args.encoding.as_str().to_string());
+    }
+    if let Some(prefix) = args.comment.as_deref() {
+        parts.push("--comment".to_string());
+        parts.push(shell_escape(prefix));
+    }
+    if args.strict_dialect {
+        parts.push("--strict-dialect".to_string());
+    }
     if args.exhaustive {
         parts.push("--exhaustive".to_string());
         parts.push("--max-audit-changes".to_string());
@@ -361,6 +656,71 @@ fn build_replay_command(args: &Args, use_local_profile: bool) -> String {
     if args.audit_fields {
         parts.push("--audit-fields".to_string());
     }
+    if let Some(max_memory) = args.max_memory {
+        parts.push("--max-memory".to_string());
+        parts.push(max_memory.to_string());
+    }
+    if let Some(count) = args.show_subtolerance {
+        parts.push("--show-subtolerance".to_string());
+        parts.push(count.to_string());
+    }
+    if args.dedupe_rows {
+        parts.push("--dedupe-rows".to_string());
+    }
+    if args.cross_tab {
+        parts.push("--cross-tab".to_string());
+    }
+    if args.parse_percent {
+        parts.push("--parse-percent".to_string());
+    }
+    if args.decimal != DecimalSeparator::Dot {
+        parts.push("--decimal".to_string());
+        parts.push(args.decimal.as_str().to_string());
+    }
+    for symbol in &args.currency_symbols {
+        parts.push("--currency-symbols".to_string());
+        parts.push(shell_escape(symbol));
+    }
+    if args.lenient_numbers {
+        parts.push("--lenient-numbers".to_string());
+    }
+    for token in &args.na_values {
+        parts.push("--na-values".to_string());
+        parts.push(shell_escape(token));
+    }
+    if args.on_missing != MissingPolicy::Refuse {
+        parts.push("--on-missing".to_string());
+        parts.push(args.on_missing.as_str().to_string());
+    }
+    if args.on_mixed_types != MixedTypesPolicy::Refuse {
+        parts.push("--on-mixed-types".to_string());
+        parts.push(args.on_mixed_types.as_str().to_string());
+    }
+    if args.collect_refusals {
+        parts.push("--collect-refusals".to_string());
+    }
+    if args.on_diffuse != DiffusePolicy::Refuse {
+        parts.push("--on-diffuse".to_string());
+        parts.push(args.on_diffuse.as_str().to_string());
+    }
+    if args.coverage != CoverageMode::Total {
+        parts.push("--coverage".to_string());
+        parts.push(args.coverage.as_str().to_string());
+    }
+    if let Some(metric) = args.drift {
+        parts.push("--drift".to_string());
+        parts.push(metric.as_str().to_string());
+    }
+    if args.include_text {
+        parts.push("--include-text".to_string());
+    }
+    if args.schema_only {
+        parts.push("--schema-only".to_string());
+    }
+    if let Some(expected) = args.expected.as_ref() {
+        parts.push("--expected".to_string());
+        parts.push(shell_escape(&expected.to_string_lossy()));
+    }
     if args.json {
         parts.push("--json".to_string());
     }
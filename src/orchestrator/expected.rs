@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+/// Contributor identities from a previously approved `rvl --json` result.
+///
+/// Cells are matched on their encoded `row_id`/`column` pair, so a redacted
+/// baseline (the default) works as well as an `--explicit` one.
+pub(super) struct ExpectedBaseline {
+    pub(super) file: String,
+    cells: HashSet<(String, String)>,
+}
+
+/// Current contributors split into already-approved and new cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct IncrementalVerdict {
+    pub(super) approved: u64,
+    pub(super) new: u64,
+}
+
+impl IncrementalVerdict {
+    pub(super) fn label(self) -> &'static str {
+        if self.new == 0 {
+            "NO_NEW_CHANGE"
+        } else {
+            "NEW_CHANGE"
+        }
+    }
+}
+
+impl ExpectedBaseline {
+    /// Load a baseline, returning a short reason on failure.
+    pub(super) fn load(path: &Path) -> Result<Self, String> {
+        let file = path.to_string_lossy().to_string();
+        let text = fs::read_to_string(path).map_err(|err| format!("cannot read file ({err})"))?;
+        let cells = parse_baseline(&text)?;
+        Ok(Self { file, cells })
+    }
+
+    pub(super) fn is_approved(&self, row_id: &str, column: &str) -> bool {
+        self.cells
+            .contains(&(row_id.to_string(), column.to_string()))
+    }
+
+    pub(super) fn verdict<'a, I>(&self, cells: I) -> IncrementalVerdict
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut verdict = IncrementalVerdict {
+            approved: 0,
+            new: 0,
+        };
+        for (row_id, column) in cells {
+            if self.is_approved(row_id, column) {
+                verdict.approved += 1;
+            } else {
+                verdict.new += 1;
+            }
+        }
+        verdict
+    }
+}
+
+fn parse_baseline(text: &str) -> Result<HashSet<(String, String)>, String> {
+    let value: Value = serde_json::from_str(text).map_err(|_| "not a JSON document".to_string())?;
+    if value.get("version").and_then(Value::as_str) != Some("rvl.v0") {
+        return Err("not an rvl.v0 JSON result".to_string());
+    }
+    match value.get("outcome").and_then(Value::as_str) {
+        Some("REAL_CHANGE") | Some("NO_REAL_CHANGE") => {}
+        Some("REFUSAL") => return Err("baseline is a REFUSAL, not an approved verdict".to_string()),
+        _ => return Err("missing outcome".to_string()),
+    }
+    let contributors = value
+        .get("contributors")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "missing contributors array".to_string())?;
+
+    let mut cells = HashSet::with_capacity(contributors.len());
+    for contributor in contributors {
+        let row_id = contributor.get("row_id").and_then(Value::as_str);
+        let column = contributor.get("column").and_then(Value::as_str);
+        match (row_id, column) {
+            (Some(row_id), Some(column)) => {
+                cells.insert((row_id.to_string(), column.to_string()));
+            }
+            _ => return Err("contributor without row_id/column".to_string()),
+        }
+    }
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(text: &str) -> ExpectedBaseline {
+        ExpectedBaseline {
+            file: "approved.json".to_string(),
+            cells: parse_baseline(text).unwrap(),
+        }
+    }
+
+    #[test]
+    fn splits_current_cells_into_approved_and_new() {
+        let expected = baseline(
+            r#"{"version":"rvl.v0","outcome":"REAL_CHANGE","contributors":[
+                {"row_id":"u8:A","column":"u8:v","share":1.0,"cumulative_share":1.0}
+            ]}"#,
+        );
+        let verdict = expected.verdict([("u8:A", "u8:v"), ("u8:B", "u8:v")]);
+        assert_eq!(
+            verdict,
+            IncrementalVerdict {
+                approved: 1,
+                new: 1
+            }
+        );
+        assert_eq!(verdict.label(), "NEW_CHANGE");
+
+        let verdict = expected.verdict([("u8:A", "u8:v")]);
+        assert_eq!(verdict.label(), "NO_NEW_CHANGE");
+    }
+
+    #[test]
+    fn rejects_refusals_and_foreign_documents() {
+        let refusal = r#"{"version":"rvl.v0","outcome":"REFUSAL","contributors":[]}"#;
+        assert!(parse_baseline(refusal).unwrap_err().contains("REFUSAL"));
+        assert_eq!(
+            parse_baseline(r#"{"version":"shape.v0"}"#).unwrap_err(),
+            "not an rvl.v0 JSON result"
+        );
+        assert_eq!(
+            parse_baseline("not json").unwrap_err(),
+            "not a JSON document"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cli::args::Args;
+use crate::cli::exit::Outcome;
+use crate::csv::source::{InputSource, source_label};
+use crate::diff::order::RowId;
+use crate::format::ident_human::render_identifier_human;
+
+use super::{LedgerSummary, PipelineResult};
+
+const EXPLORE_DATA_VERSION: &str = "rvl.explore.v0";
+const EXPLORE_TEMPLATE: &str = include_str!("explore.html");
+
+#[derive(Debug, Serialize)]
+struct ExploreData {
+    version: &'static str,
+    old: String,
+    new: String,
+    /// `file://` URLs for linking rows back to regular-file inputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_href: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_href: Option<String>,
+    outcome: &'static str,
+    alignment: String,
+    threshold: f64,
+    tolerance: f64,
+    total_change: f64,
+    cells_changed: u64,
+    cells_listed: usize,
+    explicit: bool,
+    cells: Vec<ExploreCell>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExploreCell {
+    row: String,
+    column: String,
+    share: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delta: Option<f64>,
+    /// Line where the row starts in each input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_line: Option<u64>,
+}
+
+/// Write the self-contained HTML explorer when `--explore` is set.
+pub(super) fn write_explorer(
+    args: &Args,
+    result: &PipelineResult,
+    input: &LedgerSummary<'_>,
+) -> io::Result<()> {
+    let Some(path) = args.explore.as_ref() else {
+        return Ok(());
+    };
+    let data = build_data(args, result.outcome, input);
+    write_html(path, &render_html(&data)?)
+}
+
+fn build_data(args: &Args, outcome: Outcome, input: &LedgerSummary<'_>) -> ExploreData {
+    let cells = input
+        .ledger
+        .iter()
+        .map(|detail| {
+            let row = match &detail.id.row_id {
+                RowId::RowIndex(index) => index.to_string(),
+                RowId::Key(bytes) => render_identifier_human(bytes),
+            };
+            let share = if input.total_change > 0.0 {
+                detail.contribution / input.total_change
+            } else {
+                0.0
+            };
+            ExploreCell {
+                row,
+                column: render_identifier_human(&detail.id.column),
+                share,
+                old: args.explicit.then_some(detail.old),
+                new: args.explicit.then_some(detail.new),
+                delta: args.explicit.then_some(detail.delta),
+                old_line: detail.lines.map(|lines| lines.old),
+                new_line: detail.lines.map(|lines| lines.new),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    ExploreData {
+        version: EXPLORE_DATA_VERSION,
+        old: source_label(args.old_path()),
+        new: source_label(args.new_path()),
+        old_href: file_href(args.old_source()),
+        new_href: file_href(args.new_source()),
+        outcome: match outcome {
+            Outcome::RealChange => "REAL CHANGE",
+            Outcome::NoRealChange => "NO REAL CHANGE",
+            Outcome::Refusal => "REFUSAL",
+        },
+        alignment: match input.alignment_label {
+            Some(key) => format!("key={key}"),
+            None => "row-order (no key)".to_string(),
+        },
+        threshold: args.threshold,
+        tolerance: args.tolerance,
+        total_change: input.total_change,
+        cells_changed: input.cells_changed,
+        cells_listed: cells.len(),
+        explicit: args.explicit,
+        cells,
+    }
+}
+
+/// `file://` URL for a regular-file input; streams and in-memory inputs have
+/// nothing to link to.
+fn file_href(source: InputSource<'_>) -> Option<String> {
+    if source.is_stream() {
+        return None;
+    }
+    let absolute = fs::canonicalize(source.path()).ok()?;
+    let mut href = String::from("file://");
+    for byte in absolute.to_string_lossy().bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-._~".contains(&byte) {
+            href.push(char::from(byte));
+        } else {
+            href.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    Some(href)
+}
+
+fn render_html(data: &ExploreData) -> io::Result<String> {
+    // `<` is escaped so data can never close the surrounding <script> element.
+    let json = serde_json::to_string(data)?.replace('<', "\\u003c");
+    Ok(EXPLORE_TEMPLATE.replace("/*RVL_DATA*/null", &json))
+}
+
+fn write_html(path: &Path, html: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::order::CellId;
+    use crate::orchestrator::{ContributionDetail, RowLines};
+    use std::path::PathBuf;
+
+    fn detail(key: &str, column: &str, contribution: f64) -> ContributionDetail {
+        ContributionDetail {
+            id: CellId::new(RowId::key(key.as_bytes().into()), column.as_bytes().into()),
+            old: 1.0,
+            new: 1.0 + contribution,
+            delta: contribution,
+            contribution,
+            lines: Some(RowLines { old: 2, new: 3 }),
+        }
+    }
+
+    fn args(explicit: bool) -> Args {
+        let mut args = Args::new(
+            PathBuf::from("dir/old.csv"),
+            PathBuf::from("dir/new.csv"),
+            Some("id".to_string()),
+            0.95,
+            1e-9,
+            None,
+            false,
+        );
+        args.explicit = explicit;
+        args
+    }
+
+    #[test]
+    fn redacted_data_omits_values_and_keeps_shares() {
+        let ledger = vec![detail("A", "value", 3.0), detail("B", "value", 1.0)];
+        let input = LedgerSummary {
+            alignment_label: Some("id"),
+            total_change: 4.0,
+            max_abs_delta: 3.0,
+            cells_changed: 2,
+            refusal: None,
+            ledger: &ledger,
+        };
+        let data = build_data(&args(false), Outcome::RealChange, &input);
+        let value = serde_json::to_value(&data).unwrap();
+        assert_eq!(value["old"], "old.csv");
+        assert_eq!(value["alignment"], "key=id");
+        assert_eq!(value["cells"][0]["row"], "A");
+        assert_eq!(value["cells"][0]["share"], 0.75);
+        assert_eq!(value["cells"][0]["old_line"], 2);
+        assert_eq!(value["cells"][0]["new_line"], 3);
+        assert!(value.get("old_href").is_none());
+        assert!(value["cells"][0].get("delta").is_none());
+
+        let explicit = build_data(&args(true), Outcome::RealChange, &input);
+        assert_eq!(explicit.cells[1].delta, Some(1.0));
+    }
+
+    #[test]
+    fn embedded_data_cannot_close_script_tag() {
+        let ledger = vec![detail("</script><b>", "value", 1.0)];
+        let input = LedgerSummary {
+            alignment_label: None,
+            total_change: 1.0,
+            max_abs_delta: 1.0,
+            cells_changed: 1,
+            refusal: None,
+            ledger: &ledger,
+        };
+        let html = render_html(&build_data(&args(false), Outcome::RealChange, &input)).unwrap();
+        assert!(!html.contains("</script><b>"));
+        assert!(html.contains("\\u003c/script>"));
+        assert!(!html.contains("/*RVL_DATA*/"));
+    }
+
+    #[test]
+    fn file_href_percent_encodes_absolute_paths() {
+        let file = |path: &str| file_href(InputSource::Path(Path::new(path)));
+        let href = file("Cargo.toml").unwrap();
+        assert!(href.starts_with("file:///"), "{href}");
+        assert!(href.ends_with("/Cargo.toml"), "{href}");
+        assert_eq!(file("-"), None);
+        assert_eq!(file("missing dir/old.csv"), None);
+        let inline = InputSource::Bytes {
+            label: Path::new("Cargo.toml"),
+            bytes: b"id\n",
+        };
+        assert_eq!(file_href(inline), None);
+    }
+}
@@ -0,0 +1,352 @@
+//! Single-file profile (`rvl inspect`).
+//!
+//! The file is read with the same encoding guards, dialect detection, and
+//! header normalization as either side of a comparison, so the profile shows
+//! what rvl would see before any refusal: the dialect, headers, data row
+//! count, how each column types, and which columns could serve as `--key`.
+//!
+//! A column types as `numeric` when every non-missing cell parses, `text`
+//! when none does, `missing` when every cell is a missing token, and `mixed`
+//! otherwise (a comparison would refuse it with `E_MIXED_TYPES`). A column is
+//! a key candidate when no cell is missing and every value is unique after
+//! ASCII trim.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::args::InspectArgs;
+use crate::csv::encoding::EncodingMode;
+use crate::csv::footer::FooterRule;
+use crate::csv::source::{InputSource, rerun_path, source_label};
+use crate::format::ident_human::render_identifier_human;
+use crate::format::ident_json::encode_identifier_json;
+use crate::format::numbers::format_int_with_commas;
+use crate::normalize::headers::HeaderMatch;
+use crate::normalize::trim::ascii_trim;
+use crate::numeric::parse::NumberFormat;
+use crate::output::human::refusal::{RefusalBody, render_refusal_body};
+use crate::output::json::{DialectSide, Refusal as JsonRefusal};
+use crate::refusal::details::{FileSide, RerunPaths};
+use crate::refusal::process::PipelineError;
+
+use super::{FileRules, ParsedCsv, dialect_receipt, parse_csv, refusal_detail_json};
+
+const INSPECT_SCHEMA_VERSION: &str = "rvl.inspect.v0";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnKind {
+    Numeric,
+    Text,
+    Missing,
+    Mixed,
+}
+
+impl ColumnKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ColumnKind::Numeric => "numeric",
+            ColumnKind::Text => "text",
+            ColumnKind::Missing => "missing",
+            ColumnKind::Mixed => "mixed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnProfile {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ColumnKind,
+    pub numeric: u64,
+    pub text: u64,
+    pub missing: u64,
+    /// Distinct non-missing values after ASCII trim.
+    pub distinct: u64,
+    pub key_candidate: bool,
+    #[serde(skip)]
+    raw_name: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InspectReport {
+    pub version: &'static str,
+    pub file: String,
+    pub dialect: Option<DialectSide>,
+    pub rows: u64,
+    pub columns: Vec<ColumnProfile>,
+    pub key_candidates: Vec<String>,
+    pub refusal: Option<JsonRefusal>,
+    #[serde(skip)]
+    human_refusal: Vec<String>,
+}
+
+/// Run `rvl inspect`. Exit codes: 0 = profiled, 2 = the file would refuse
+/// before comparison (its refusal is reported).
+pub fn run(args: &InspectArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let report = inspect(&args.file, args.delimiter);
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(if report.refusal.is_some() { 2 } else { 0 })
+}
+
+/// Profile one file.
+pub fn inspect(path: &Path, delimiter: Option<u8>) -> InspectReport {
+    let rerun = rerun_path(path, "FILE");
+    let rerun_paths = RerunPaths {
+        old: &rerun,
+        new: &rerun,
+    };
+    let file_rules = FileRules {
+        encoding: EncodingMode::default(),
+        comment: None,
+        skip_rows: 0,
+        footer: FooterRule::Rows(0),
+        key: None,
+        aliases: None,
+        column_map: &[],
+        matching: HeaderMatch::Exact,
+    };
+    let number_format = NumberFormat::default();
+    let parsed = parse_csv(
+        InputSource::Path(path),
+        FileSide::Old,
+        delimiter,
+        rerun_paths,
+        file_rules,
+        number_format,
+        false,
+    );
+    let file = path.to_string_lossy().to_string();
+    match parsed {
+        Ok(parsed) => profile(file, &parsed, number_format),
+        Err(refusal) => {
+            let label = source_label(path);
+            let human_refusal = render_refusal_body(&RefusalBody {
+                code: refusal.code,
+                detail: &refusal.detail,
+                old_name: &label,
+                new_name: &label,
+            });
+            InspectReport {
+                version: INSPECT_SCHEMA_VERSION,
+                file,
+                dialect: None,
+                rows: 0,
+                columns: Vec::new(),
+                key_candidates: Vec::new(),
+                refusal: Some(JsonRefusal::new(
+                    refusal.code,
+                    refusal.detail.reason(refusal.code),
+                    refusal_detail_json(&refusal.detail),
+                )),
+                human_refusal,
+            }
+        }
+    }
+}
+
+fn profile(file: String, parsed: &ParsedCsv, number_format: NumberFormat<'_>) -> InspectReport {
+    let columns: Vec<ColumnProfile> = parsed
+        .headers
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| profile_column(name, idx, parsed, number_format))
+        .collect();
+    let key_candidates = columns
+        .iter()
+        .filter(|column| column.key_candidate)
+        .map(|column| column.name.clone())
+        .collect();
+    let dialect = dialect_receipt(parsed);
+    InspectReport {
+        version: INSPECT_SCHEMA_VERSION,
+        file,
+        dialect: Some(
+            DialectSide::new(dialect.delimiter, dialect.quote, dialect.escape)
+                .with_compression(dialect.compression)
+                .with_encoding(dialect.encoding)
+                .with_confidence(dialect.confidence)
+                .with_decimal(dialect.decimal),
+        ),
+        rows: parsed.records.len() as u64,
+        columns,
+        key_candidates,
+        refusal: None,
+        human_refusal: Vec::new(),
+    }
+}
+
+fn profile_column(
+    name: &[u8],
+    idx: usize,
+    parsed: &ParsedCsv,
+    number_format: NumberFormat<'_>,
+) -> ColumnProfile {
+    let (mut numeric, mut text, mut missing) = (0u64, 0u64, 0u64);
+    let mut values: HashSet<&[u8]> = HashSet::new();
+    let mut duplicated = false;
+    for record in &parsed.records {
+        let field = record.field(idx);
+        if number_format.is_missing(field) {
+            missing += 1;
+            continue;
+        }
+        if number_format.parse(field).is_some() {
+            numeric += 1;
+        } else {
+            text += 1;
+        }
+        if !values.insert(ascii_trim(field)) {
+            duplicated = true;
+        }
+    }
+    let kind = match (numeric, text) {
+        (0, 0) => ColumnKind::Missing,
+        (_, 0) => ColumnKind::Numeric,
+        (0, _) => ColumnKind::Text,
+        _ => ColumnKind::Mixed,
+    };
+    ColumnProfile {
+        name: encode_identifier_json(name),
+        kind,
+        numeric,
+        text,
+        missing,
+        distinct: values.len() as u64,
+        key_candidate: !parsed.records.is_empty() && !duplicated && missing == 0,
+        raw_name: name.to_vec(),
+    }
+}
+
+fn render_human(report: &InspectReport) -> String {
+    let mut lines = vec!["RVL INSPECT".to_string(), String::new()];
+    lines.push(format!("File: {}", source_label(Path::new(&report.file))));
+    if report.refusal.is_some() {
+        lines.push(String::new());
+        lines.extend(report.human_refusal.iter().cloned());
+        return lines.join("\n");
+    }
+    if let Some(dialect) = report.dialect.as_ref() {
+        let mut line = format!(
+            "Dialect: delimiter={} quote={} escape={}",
+            render_byte(&dialect.delimiter),
+            dialect.quote,
+            dialect.escape.as_deref().unwrap_or("none")
+        );
+        if let Some(compression) = dialect.compression {
+            line.push_str(&format!(" compression={}", compression.as_str()));
+        }
+        if let Some(confidence) = dialect.confidence.filter(|confidence| *confidence < 1.0) {
+            line.push_str(&format!(" confidence={confidence:.2}"));
+        }
+        lines.push(line);
+    }
+    lines.push(format!(
+        "Rows: {}  Columns: {}",
+        format_int_with_commas(report.rows as i64),
+        report.columns.len()
+    ));
+    lines.push(String::new());
+    for column in &report.columns {
+        let mut line = format!(
+            "  {}  {}  ({} numeric, {} text, {} missing; {} distinct)",
+            render_identifier_human(&column.raw_name),
+            column.kind.as_str(),
+            column.numeric,
+            column.text,
+            column.missing,
+            column.distinct
+        );
+        if column.kind == ColumnKind::Mixed {
+            line.push_str("  would refuse E_MIXED_TYPES");
+        }
+        lines.push(line);
+    }
+    lines.push(String::new());
+    let key_candidates: Vec<String> = report
+        .columns
+        .iter()
+        .filter(|column| column.key_candidate)
+        .map(|column| render_identifier_human(&column.raw_name))
+        .collect();
+    if key_candidates.is_empty() {
+        lines.push(
+            "Key candidates: none (no column is present and unique in every row)".to_string(),
+        );
+    } else {
+        lines.push(format!("Key candidates: {}", key_candidates.join(", ")));
+        lines.push(format!(
+            "Next: rvl old.csv new.csv --key {}",
+            key_candidates[0]
+        ));
+    }
+    lines.join("\n")
+}
+
+fn render_byte(value: &str) -> String {
+    match value {
+        "\t" => "\\t".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspect_bytes(name: &str, bytes: &str) -> InspectReport {
+        let dir = std::env::temp_dir().join(format!("rvl_inspect_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, bytes).unwrap();
+        let report = inspect(&path, None);
+        let _ = std::fs::remove_file(&path);
+        report
+    }
+
+    #[test]
+    fn profiles_column_types_and_key_candidates() {
+        let report = inspect_bytes(
+            "unit_inspect_profile.csv",
+            "id,region,amount,note,mix\nA,east,10,,1\nB,east,NA,x,y\nC,west,30,,1\n",
+        );
+        assert!(report.refusal.is_none());
+        assert_eq!(report.rows, 3);
+        let kinds: Vec<ColumnKind> = report.columns.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ColumnKind::Text,
+                ColumnKind::Text,
+                ColumnKind::Numeric,
+                ColumnKind::Text,
+                ColumnKind::Mixed,
+            ]
+        );
+        assert_eq!(report.columns[2].missing, 1);
+        assert_eq!(report.columns[1].distinct, 2);
+        assert_eq!(report.key_candidates, vec!["u8:id"]);
+
+        let human = render_human(&report);
+        assert!(human.contains("\n  amount  numeric  ("), "{human}");
+        assert!(human.contains("Key candidates: id\n"), "{human}");
+        assert!(
+            human.contains("Next: rvl old.csv new.csv --key id"),
+            "{human}"
+        );
+    }
+
+    #[test]
+    fn unparseable_file_reports_its_refusal() {
+        let report = inspect_bytes("unit_inspect_refusal.csv", "id,v\nA,1,extra\n");
+        let refusal = report.refusal.expect("refusal");
+        assert_eq!(refusal.code, "E_DIALECT");
+        assert!(report.columns.is_empty());
+    }
+}
@@ -0,0 +1,403 @@
+//! Key candidate explorer (`rvl keys`).
+//!
+//! Both files are read the way a comparison reads them, and every column the
+//! two share is scored as a `--key`: how unique and how empty it is on each
+//! side, whether key discovery rates it `perfect` (same key set in both
+//! files) or `joinable` (unique and never empty on both sides, but the key
+//! sets differ), and how far the two key sets overlap. Refusals that suggest
+//! keys only name them; this shows why a column did or did not qualify.
+//!
+//! Values are compared after ASCII trim, and a cell is empty when nothing is
+//! left, matching key discovery (missing tokens such as `NA` are values here).
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::alignment::key_discovery::{CandidateKind, discover_key_candidates};
+use crate::cli::args::KeysArgs;
+use crate::csv::encoding::EncodingMode;
+use crate::csv::footer::FooterRule;
+use crate::csv::records::OwnedRecord;
+use crate::csv::source::{InputSource, rerun_path, source_label};
+use crate::format::ident_human::render_identifier_human;
+use crate::format::ident_json::encode_identifier_json;
+use crate::format::numbers::{format_int_with_commas, format_percent_one_decimal};
+use crate::normalize::headers::HeaderMatch;
+use crate::normalize::trim::ascii_trim;
+use crate::numeric::parse::NumberFormat;
+use crate::output::human::refusal::{RefusalBody, render_refusal_body};
+use crate::output::json::Refusal as JsonRefusal;
+use crate::refusal::details::{FileSide, RerunPaths};
+use crate::refusal::process::PipelineError;
+
+use super::{FileRules, ParsedCsv, parse_csv, refusal_detail_json};
+
+const KEYS_SCHEMA_VERSION: &str = "rvl.keys.v0";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyKind {
+    Perfect,
+    Joinable,
+    /// Empty or duplicated on at least one side.
+    Unusable,
+}
+
+impl KeyKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            KeyKind::Perfect => "perfect",
+            KeyKind::Joinable => "joinable",
+            KeyKind::Unusable => "unusable",
+        }
+    }
+}
+
+/// One side's view of a shared column.
+#[derive(Debug, Clone, Serialize)]
+pub struct KeySide {
+    pub rows: u64,
+    /// Distinct non-empty values after ASCII trim.
+    pub distinct: u64,
+    pub empty: u64,
+    /// Non-empty cells repeating an earlier value.
+    pub duplicated: u64,
+    /// `distinct / rows`; 1.0 when every row has its own value.
+    pub uniqueness: f64,
+    /// `empty / rows`.
+    pub empty_share: f64,
+}
+
+/// How the distinct values of a column line up across the two files.
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyOverlap {
+    pub shared: u64,
+    pub old_only: u64,
+    pub new_only: u64,
+    /// `shared` over every distinct value in either file.
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyColumn {
+    pub name: String,
+    pub kind: KeyKind,
+    pub old: KeySide,
+    pub new: KeySide,
+    pub overlap: KeyOverlap,
+    #[serde(skip)]
+    raw_name: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeysReport {
+    pub version: &'static str,
+    pub old: String,
+    pub new: String,
+    pub columns: Vec<KeyColumn>,
+    /// Perfect candidates first, then joinable ones, each in header order.
+    pub key_candidates: Vec<String>,
+    pub refusal: Option<JsonRefusal>,
+    #[serde(skip)]
+    human_refusal: Vec<String>,
+    #[serde(skip)]
+    rerun: (String, String),
+}
+
+/// Run `rvl keys`. Exit codes: 0 = explored, 2 = a file would refuse before
+/// comparison (its refusal is reported).
+pub fn run(args: &KeysArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let report = explore_keys(&args.old, &args.new, args.delimiter);
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(if report.refusal.is_some() { 2 } else { 0 })
+}
+
+/// Score every column shared by `old` and `new` as a key.
+pub fn explore_keys(old: &Path, new: &Path, delimiter: Option<u8>) -> KeysReport {
+    let rerun_old = rerun_path(old, "OLD_CSV");
+    let rerun_new = rerun_path(new, "NEW_CSV");
+    let rerun_paths = RerunPaths {
+        old: &rerun_old,
+        new: &rerun_new,
+    };
+    let file_rules = || FileRules {
+        encoding: EncodingMode::default(),
+        comment: None,
+        skip_rows: 0,
+        footer: FooterRule::Rows(0),
+        key: None,
+        aliases: None,
+        column_map: &[],
+        matching: HeaderMatch::Exact,
+    };
+    let number_format = NumberFormat::default();
+    let parsed = parse_csv(
+        InputSource::Path(old),
+        FileSide::Old,
+        delimiter,
+        rerun_paths,
+        file_rules(),
+        number_format,
+        false,
+    )
+    .and_then(|parsed_old| {
+        parse_csv(
+            InputSource::Path(new),
+            FileSide::New,
+            delimiter,
+            rerun_paths,
+            file_rules(),
+            number_format,
+            false,
+        )
+        .map(|parsed_new| (parsed_old, parsed_new))
+    });
+    let mut report = KeysReport {
+        version: KEYS_SCHEMA_VERSION,
+        old: old.to_string_lossy().to_string(),
+        new: new.to_string_lossy().to_string(),
+        columns: Vec::new(),
+        key_candidates: Vec::new(),
+        refusal: None,
+        human_refusal: Vec::new(),
+        rerun: (rerun_old, rerun_new),
+    };
+    match parsed {
+        Ok((parsed_old, parsed_new)) => {
+            let candidates = discover_key_candidates(
+                &parsed_old.headers,
+                &parsed_new.headers,
+                parsed_old.records.iter(),
+                parsed_new.records.iter(),
+            );
+            let kinds: HashMap<&[u8], CandidateKind> = candidates
+                .iter()
+                .map(|candidate| (candidate.name.as_slice(), candidate.kind))
+                .collect();
+            report.columns = score_columns(&parsed_old, &parsed_new, &kinds);
+            report.key_candidates = candidates
+                .iter()
+                .map(|candidate| encode_identifier_json(&candidate.name))
+                .collect();
+        }
+        Err(refusal) => {
+            report.human_refusal = render_refusal_body(&RefusalBody {
+                code: refusal.code,
+                detail: &refusal.detail,
+                old_name: &source_label(old),
+                new_name: &source_label(new),
+            });
+            report.refusal = Some(JsonRefusal::new(
+                refusal.code,
+                refusal.detail.reason(refusal.code),
+                refusal_detail_json(&refusal.detail),
+            ));
+        }
+    }
+    report
+}
+
+fn score_columns(
+    old: &ParsedCsv,
+    new: &ParsedCsv,
+    kinds: &HashMap<&[u8], CandidateKind>,
+) -> Vec<KeyColumn> {
+    let mut columns = Vec::new();
+    for (old_idx, name) in old.headers.iter().enumerate() {
+        let Some(new_idx) = new.headers.iter().position(|n| n == name) else {
+            continue;
+        };
+        let (old_side, old_values) = score_side(&old.records, old_idx);
+        let (new_side, new_values) = score_side(&new.records, new_idx);
+        let shared = old_values.intersection(&new_values).count() as u64;
+        let old_only = old_values.len() as u64 - shared;
+        let new_only = new_values.len() as u64 - shared;
+        let kind = match kinds.get(name.as_slice()) {
+            Some(CandidateKind::Perfect) => KeyKind::Perfect,
+            Some(CandidateKind::Joinable) => KeyKind::Joinable,
+            None => KeyKind::Unusable,
+        };
+        columns.push(KeyColumn {
+            name: encode_identifier_json(name),
+            kind,
+            old: old_side,
+            new: new_side,
+            overlap: KeyOverlap {
+                shared,
+                old_only,
+                new_only,
+                share: ratio(shared, shared + old_only + new_only),
+            },
+            raw_name: name.clone(),
+        });
+    }
+    columns
+}
+
+fn score_side(records: &[OwnedRecord], idx: usize) -> (KeySide, HashSet<&[u8]>) {
+    let mut values: HashSet<&[u8]> = HashSet::new();
+    let (mut empty, mut duplicated) = (0u64, 0u64);
+    for record in records {
+        let value = ascii_trim(record.field(idx));
+        if value.is_empty() {
+            empty += 1;
+        } else if !values.insert(value) {
+            duplicated += 1;
+        }
+    }
+    let rows = records.len() as u64;
+    let distinct = values.len() as u64;
+    let side = KeySide {
+        rows,
+        distinct,
+        empty,
+        duplicated,
+        uniqueness: ratio(distinct, rows),
+        empty_share: ratio(empty, rows),
+    };
+    (side, values)
+}
+
+fn ratio(part: u64, whole: u64) -> f64 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f64 / whole as f64
+    }
+}
+
+fn render_human(report: &KeysReport) -> String {
+    let mut lines = vec!["RVL KEYS".to_string(), String::new()];
+    lines.push(format!("Old: {}", source_label(Path::new(&report.old))));
+    lines.push(format!("New: {}", source_label(Path::new(&report.new))));
+    if report.refusal.is_some() {
+        lines.push(String::new());
+        lines.extend(report.human_refusal.iter().cloned());
+        return lines.join("\n");
+    }
+    lines.push(String::new());
+    if report.columns.is_empty() {
+        lines.push("No shared columns.".to_string());
+        return lines.join("\n");
+    }
+    for column in &report.columns {
+        lines.push(format!(
+            "  {}  {}",
+            render_identifier_human(&column.raw_name),
+            column.kind.as_str()
+        ));
+        lines.push(format!("    old: {}", render_side(&column.old)));
+        lines.push(format!("    new: {}", render_side(&column.new)));
+        lines.push(format!(
+            "    overlap: {} shared, {} old only, {} new only ({})",
+            format_int_with_commas(column.overlap.shared as i64),
+            format_int_with_commas(column.overlap.old_only as i64),
+            format_int_with_commas(column.overlap.new_only as i64),
+            format_percent_one_decimal(column.overlap.share)
+        ));
+    }
+    lines.push(String::new());
+    let candidates: Vec<&KeyColumn> = report
+        .key_candidates
+        .iter()
+        .filter_map(|name| report.columns.iter().find(|column| &column.name == name))
+        .collect();
+    if candidates.is_empty() {
+        lines.push(
+            "Key candidates: none (no shared column is unique and never empty in both files)"
+                .to_string(),
+        );
+    } else {
+        let names: Vec<String> = candidates
+            .iter()
+            .map(|column| render_identifier_human(&column.raw_name))
+            .collect();
+        lines.push(format!("Key candidates: {}", names.join(", ")));
+        lines.push(format!(
+            "Next: rvl {} {} --key {}",
+            report.rerun.0, report.rerun.1, names[0]
+        ));
+    }
+    lines.join("\n")
+}
+
+fn render_side(side: &KeySide) -> String {
+    format!(
+        "{} unique, {} empty ({} rows, {} distinct, {} duplicated)",
+        format_percent_one_decimal(side.uniqueness),
+        format_percent_one_decimal(side.empty_share),
+        format_int_with_commas(side.rows as i64),
+        format_int_with_commas(side.distinct as i64),
+        format_int_with_commas(side.duplicated as i64)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explore_bytes(name: &str, old: &str, new: &str) -> KeysReport {
+        let dir = std::env::temp_dir().join(format!("rvl_keys_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let old_path = dir.join(format!("{name}_old.csv"));
+        let new_path = dir.join(format!("{name}_new.csv"));
+        std::fs::write(&old_path, old).unwrap();
+        std::fs::write(&new_path, new).unwrap();
+        let report = explore_keys(&old_path, &new_path, None);
+        let _ = std::fs::remove_file(&old_path);
+        let _ = std::fs::remove_file(&new_path);
+        report
+    }
+
+    #[test]
+    fn scores_every_shared_column() {
+        let report = explore_bytes(
+            "unit_keys_scores",
+            "id,sku,region,old_only\nA,s1,east,1\nB,s2,east,2\nC,s3,,3\n",
+            "id,sku,region\nC,s3,west\nA,s1,east\nB,s4,west\n",
+        );
+        assert!(report.refusal.is_none());
+        let names: Vec<&str> = report.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["u8:id", "u8:sku", "u8:region"]);
+        let kinds: Vec<KeyKind> = report.columns.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![KeyKind::Perfect, KeyKind::Joinable, KeyKind::Unusable]
+        );
+
+        let sku = &report.columns[1];
+        assert_eq!(sku.overlap.shared, 2);
+        assert_eq!(sku.overlap.old_only, 1);
+        assert_eq!(sku.overlap.new_only, 1);
+        assert!((sku.overlap.share - 0.5).abs() < 1e-12);
+
+        let region = &report.columns[2];
+        assert_eq!(region.old.empty, 1);
+        assert_eq!(region.old.duplicated, 1);
+        assert_eq!(region.old.distinct, 1);
+        assert!((region.old.uniqueness - 1.0 / 3.0).abs() < 1e-12);
+        assert!((region.old.empty_share - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(report.key_candidates, vec!["u8:id", "u8:sku"]);
+
+        let human = render_human(&report);
+        assert!(human.contains("Key candidates: id, sku"), "{human}");
+        assert!(
+            human.contains("overlap: 2 shared, 1 old only, 1 new only (50.0%)"),
+            "{human}"
+        );
+    }
+
+    #[test]
+    fn unparseable_file_reports_its_refusal() {
+        let report = explore_bytes("unit_keys_refusal", "id,v\nA,1\n", "id,v\nA,1,extra\n");
+        let refusal = report.refusal.expect("refusal");
+        assert_eq!(refusal.code, "E_DIALECT");
+        assert!(report.columns.is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Up-front peak-memory estimate for `--max-memory`.
+//!
+//! rvl holds both inputs in memory at once: the raw bytes, a decoded copy of
+//! compressed input, and every record's fields in one buffer per row. Key mode
+//! adds a join-map entry per row on each side. A streaming pre-pass counts
+//! bytes and line breaks so an oversized comparison refuses before any of that
+//! is allocated.
+
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+
+use crate::csv::compression::{Compression, decode_reader};
+use crate::csv::source::InputSource;
+
+/// Per-row bookkeeping beyond the field bytes: the record's buffer and offset
+/// vectors, offsets for a typical row, and its source line number.
+const ROW_OVERHEAD: u64 = 128;
+/// Per-row cost of the key join: hash slot, entry, and key copy.
+const KEY_ENTRY_OVERHEAD: u64 = 96;
+
+/// Size of one input as the parser will see it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct InputFootprint {
+    /// Bytes read from the source.
+    pub(super) raw_bytes: u64,
+    /// Bytes after decompression; equal to `raw_bytes` for plain input.
+    pub(super) decoded_bytes: u64,
+    /// Compressed input is held twice: as read and decoded.
+    pub(super) compressed: bool,
+    /// Line count of the decoded input, an upper bound on its records.
+    pub(super) lines: u64,
+}
+
+impl InputFootprint {
+    /// Measure an input without loading a regular file into memory. Streams
+    /// are drained through the shared source cache, so the parse that follows
+    /// sees the same bytes.
+    pub(super) fn measure(source: InputSource<'_>) -> io::Result<Self> {
+        if source.is_stream() {
+            let bytes = source.read()?;
+            let raw_bytes = bytes.len() as u64;
+            return Self::count(raw_bytes, Cursor::new(bytes));
+        }
+        let path = source.path();
+        let raw_bytes = std::fs::metadata(path)?.len();
+        Self::count(raw_bytes, File::open(path)?)
+    }
+
+    fn count(raw_bytes: u64, mut reader: impl Read) -> io::Result<Self> {
+        let mut magic = Vec::with_capacity(4);
+        (&mut reader).take(4).read_to_end(&mut magic)?;
+        let compression = Compression::detect(&magic);
+        let input = Cursor::new(magic).chain(reader);
+        let (decoded_bytes, lines) = match compression {
+            Some(compression) => count_lines(decode_reader(compression, input)?)?,
+            None => count_lines(input)?,
+        };
+        Ok(Self {
+            raw_bytes,
+            decoded_bytes,
+            compressed: compression.is_some(),
+            lines,
+        })
+    }
+
+    fn resident_bytes(self) -> u64 {
+        let decoded_copy = if self.compressed {
+            self.decoded_bytes
+        } else {
+            0
+        };
+        self.raw_bytes + decoded_copy + self.decoded_bytes + self.lines * ROW_OVERHEAD
+    }
+}
+
+/// Estimated peak heap use of one comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct MemoryEstimate {
+    pub(super) peak_bytes: u64,
+    /// The same inputs compared by row order, which skips the key join;
+    /// `None` when the run is already in row-order mode.
+    pub(super) row_order_bytes: Option<u64>,
+}
+
+impl MemoryEstimate {
+    pub(super) fn new(old: InputFootprint, new: InputFootprint, key_mode: bool) -> Self {
+        let row_order = old.resident_bytes() + new.resident_bytes();
+        if key_mode {
+            Self {
+                peak_bytes: row_order + (old.lines + new.lines) * KEY_ENTRY_OVERHEAD,
+                row_order_bytes: Some(row_order),
+            }
+        } else {
+            Self {
+                peak_bytes: row_order,
+                row_order_bytes: None,
+            }
+        }
+    }
+}
+
+/// Decoded byte count and line count; a final line without a terminator counts.
+fn count_lines(mut reader: impl Read) -> io::Result<(u64, u64)> {
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut bytes = 0u64;
+    let mut lines = 0u64;
+    let mut last = b'\n';
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        let chunk = &buffer[..read];
+        bytes += read as u64;
+        lines += chunk.iter().filter(|&&byte| byte == b'\n').count() as u64;
+        last = chunk[read - 1];
+    }
+    if last != b'\n' {
+        lines += 1;
+    }
+    Ok((bytes, lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn footprint(input: &[u8]) -> InputFootprint {
+        InputFootprint::count(input.len() as u64, input).unwrap()
+    }
+
+    #[test]
+    fn counts_bytes_and_lines() {
+        assert_eq!(
+            footprint(b"id,v\nA,1\nB,2"),
+            InputFootprint {
+                raw_bytes: 12,
+                decoded_bytes: 12,
+                compressed: false,
+                lines: 3,
+            }
+        );
+        assert_eq!(footprint(b"id,v\n").lines, 1);
+        assert_eq!(footprint(b"").lines, 0);
+    }
+
+    #[test]
+    fn measures_gzip_input_decoded() {
+        use std::io::Write;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"id,v\nA,1\nB,2\n").unwrap();
+        let compressed = encoder.finish().unwrap();
+        let measured = footprint(&compressed);
+        assert_eq!(measured.raw_bytes, compressed.len() as u64);
+        assert_eq!(measured.decoded_bytes, 13);
+        assert!(measured.compressed);
+        assert_eq!(measured.lines, 3);
+    }
+
+    #[test]
+    fn key_mode_adds_join_entries() {
+        let input = footprint(b"id,v\nA,1\n");
+        let keyed = MemoryEstimate::new(input, input, true);
+        let row_order = MemoryEstimate::new(input, input, false);
+        assert_eq!(keyed.row_order_bytes, Some(row_order.peak_bytes));
+        assert_eq!(
+            keyed.peak_bytes,
+            row_order.peak_bytes + 4 * KEY_ENTRY_OVERHEAD
+        );
+        assert_eq!(row_order.row_order_bytes, None);
+    }
+}
@@ -0,0 +1,135 @@
+use std::fs;
+use std::io;
+
+use crate::cli::args::Args;
+use crate::cli::exit::Outcome;
+use crate::csv::source::source_label;
+use crate::diff::order::RowId;
+use crate::format::ident_human::render_identifier_human;
+use crate::output::xlsx::{Cell, Sheet, workbook_bytes};
+
+use super::{LedgerSummary, PipelineResult};
+
+/// Write the `--report-xlsx` workbook: a Summary sheet and a Changes sheet.
+pub(super) fn write_xlsx_report(
+    args: &Args,
+    result: &PipelineResult,
+    summary: &LedgerSummary<'_>,
+) -> io::Result<()> {
+    let Some(path) = args.report_xlsx.as_ref() else {
+        return Ok(());
+    };
+    let sheets = [
+        summary_sheet(args, result.outcome, summary),
+        changes_sheet(args, summary),
+    ];
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, workbook_bytes(&sheets))
+}
+
+fn summary_sheet(args: &Args, outcome: Outcome, summary: &LedgerSummary<'_>) -> Sheet {
+    let outcome_label = match outcome {
+        Outcome::RealChange => "REAL CHANGE",
+        Outcome::NoRealChange => "NO REAL CHANGE",
+        Outcome::Refusal => "REFUSAL",
+    };
+    let label = |text: &str| Cell::Header(text.to_string());
+    let text = |value: String| Cell::Text(value);
+    let mut rows = vec![
+        vec![label("Outcome"), text(outcome_label.to_string())],
+        vec![label("Old"), text(source_label(args.old_path()))],
+        vec![label("New"), text(source_label(args.new_path()))],
+        vec![
+            label("Alignment"),
+            text(match summary.alignment_label {
+                Some(key) => format!("key={key}"),
+                None => "row-order (no key)".to_string(),
+            }),
+        ],
+        vec![label("Threshold"), Cell::Percent(args.threshold)],
+        vec![label("Tolerance"), Cell::Number(args.tolerance)],
+        vec![label("Total change"), Cell::Number(summary.total_change)],
+        vec![label("Max abs delta"), Cell::Number(summary.max_abs_delta)],
+        vec![
+            label("Cells changed"),
+            Cell::Number(summary.cells_changed as f64),
+        ],
+        vec![
+            label("Cells listed"),
+            Cell::Number(summary.ledger.len() as f64),
+        ],
+    ];
+    if let Some(code) = summary.refusal {
+        rows.insert(1, vec![label("Refusal"), text(code.as_str().to_string())]);
+    }
+    if (summary.ledger.len() as u64) < summary.cells_changed {
+        rows.push(vec![
+            label("Note"),
+            text("Ledger truncated at --max-audit-changes.".to_string()),
+        ]);
+    }
+    if !args.explicit {
+        rows.push(vec![
+            label("Note"),
+            text("Values redacted; rerun with --explicit to include old/new/delta.".to_string()),
+        ]);
+    }
+    Sheet {
+        name: "Summary".to_string(),
+        rows,
+        column_widths: vec![18.0, 48.0],
+        freeze_header: false,
+    }
+}
+
+fn changes_sheet(args: &Args, summary: &LedgerSummary<'_>) -> Sheet {
+    let mut header = ["Rank", "Row", "Column", "Share", "Cumulative share"]
+        .iter()
+        .map(|name| Cell::Header(name.to_string()))
+        .collect::<Vec<_>>();
+    if args.explicit {
+        header.extend(["Delta", "Old", "New"].map(|name| Cell::Header(name.to_string())));
+    }
+
+    let mut rows = Vec::with_capacity(summary.ledger.len() + 1);
+    rows.push(header);
+    let mut cumulative = 0.0;
+    for (idx, detail) in summary.ledger.iter().enumerate() {
+        let share = if summary.total_change > 0.0 {
+            detail.contribution / summary.total_change
+        } else {
+            0.0
+        };
+        cumulative += share;
+        let row = match &detail.id.row_id {
+            RowId::RowIndex(index) => index.to_string(),
+            RowId::Key(bytes) => render_identifier_human(bytes),
+        };
+        let mut cells = vec![
+            Cell::Number((idx + 1) as f64),
+            Cell::Text(row),
+            Cell::Text(render_identifier_human(&detail.id.column)),
+            Cell::Percent(share),
+            Cell::Percent(cumulative),
+        ];
+        if args.explicit {
+            cells.extend([
+                Cell::Number(detail.delta),
+                Cell::Number(detail.old),
+                Cell::Number(detail.new),
+            ]);
+        }
+        rows.push(cells);
+    }
+
+    Sheet {
+        name: "Changes".to_string(),
+        rows,
+        column_widths: vec![8.0, 24.0, 24.0, 10.0, 16.0, 14.0, 14.0, 14.0],
+        freeze_header: true,
+    }
+}
@@ -0,0 +1,341 @@
+// Numeric cell scan over aligned rows, one chunk at a time.
+//
+// Each chunk of rows is scanned into its own `CellScan`; chunks are then
+// merged in row order (see `diff::chunks`). Tie-breaks are offset per chunk
+// so the merged top-K, ledger prefix, and counts match a single serial scan.
+
+use std::io;
+
+use super::{ContributionDetail, RowLines, SourceLines};
+use crate::csv::records::OwnedRecord;
+use crate::diff::heap::{Contributor, DiffAccumulator, TopContributors};
+use crate::diff::intern::Symbol;
+use crate::diff::order::{CellId, RowId, TieBreaker};
+use crate::diff::rank::{RankMode, row_weight};
+use crate::diff::rescale::RescaleTracker;
+use crate::diff::rollup::{ColumnDeltas, ColumnTotals, RowTotals};
+use crate::diff::tolerance::{ToleranceSweep, ToleranceTracker};
+use crate::numeric::columns::CommonColumn;
+use crate::numeric::drift::{DriftMetric, DriftSamples};
+use crate::numeric::missing::{CellPair, MissingPolicy};
+use crate::numeric::parse::{NumberFormat, is_format_only_change};
+use crate::numeric::transform::ValueTransforms;
+use crate::output::changes_csv::ChangesCsvWriter;
+
+/// Run-wide inputs shared by every chunk.
+pub(super) struct ScanSettings<'a> {
+    pub columns: &'a [CommonColumn],
+    pub names: &'a [Symbol],
+    pub tolerances: &'a [f64],
+    /// `--transform` per column, indexed like `columns`.
+    pub transforms: &'a [ValueTransforms],
+    pub base_tolerance: f64,
+    /// `--tolerance-sweep` levels, ascending.
+    pub sweep: Option<&'a [f64]>,
+    pub number_format: NumberFormat<'a>,
+    pub missing: MissingPolicy,
+    pub top: usize,
+    pub rank: RankMode,
+    /// `--weight-col`, resolved in both files.
+    pub weight: Option<&'a CommonColumn>,
+    pub subtolerance: Option<usize>,
+    /// Keep a top-K per column (`--coverage per-column`).
+    pub column_top: bool,
+    pub drift: Option<DriftMetric>,
+    pub collect_ledger: bool,
+    pub max_audit_changes: u64,
+    pub report_csv: bool,
+    pub source_lines: &'a SourceLines,
+}
+
+impl ScanSettings<'_> {
+    /// First tie-break of the chunk starting at `row`: every row draws at
+    /// most one value per numeric column, so later chunks always sort after.
+    pub fn first_tie_break(&self, row: usize) -> u64 {
+        (row as u64).saturating_mul(self.columns.len() as u64)
+    }
+
+    /// Contribution multiplier for a row under `--weight-col`: the new
+    /// side's weight, else the old side's (1 without a weight column).
+    pub fn row_weight(&self, old: Option<&OwnedRecord>, new: Option<&OwnedRecord>) -> f64 {
+        let Some(column) = self.weight else {
+            return 1.0;
+        };
+        let parse = |record: Option<&OwnedRecord>, index: usize| {
+            record.and_then(|record| self.number_format.parse(record.field(index)))
+        };
+        row_weight(parse(new, column.new_index).or_else(|| parse(old, column.old_index)))
+    }
+}
+
+/// How a row is named in the `--report-csv` ledger.
+#[derive(Clone, Copy)]
+pub(super) enum RowLabel<'a> {
+    Key(&'a [u8]),
+    Record(u64),
+}
+
+impl RowLabel<'_> {
+    fn to_bytes(self) -> Vec<u8> {
+        match self {
+            RowLabel::Key(key) => key.to_vec(),
+            RowLabel::Record(record) => record.to_string().into_bytes(),
+        }
+    }
+}
+
+/// A changed cell waiting to be written to `--report-csv` in row order.
+pub(super) struct CsvCell {
+    row: Vec<u8>,
+    column: Symbol,
+    old: f64,
+    new: f64,
+    delta: f64,
+}
+
+/// Scan results for one chunk of rows, or the merge of several.
+pub(super) struct CellScan {
+    pub accumulator: DiffAccumulator<CellId>,
+    pub subtolerance: Option<TopContributors<CellId>>,
+    /// Per-column top-K, indexed like `columns`.
+    pub column_top: Option<Vec<TopContributors<CellId>>>,
+    pub drift: Option<DriftSamples>,
+    pub sweep: Option<ToleranceSweep>,
+    pub column_totals: ColumnTotals,
+    /// |delta| statistics per column, tolerated cells included.
+    pub column_deltas: ColumnDeltas,
+    pub rescale: RescaleTracker,
+    pub row_totals: RowTotals,
+    pub cells_changed: u64,
+    /// Cells whose values were compared (zeroed cells and entity cells included).
+    pub cells_checked: u64,
+    /// Aligned cells left out because both sides are missing.
+    pub cells_missing_both: u64,
+    /// Added/removed-row cells left out because the present side is missing.
+    pub cells_missing_row: u64,
+    /// Aligned cells left out because a side is not a number.
+    pub cells_unparsable: u64,
+    /// Ledger cells, up to `--max-audit-changes`, in scan order.
+    pub details: Vec<ContributionDetail>,
+    pub format_only_changes: u64,
+    pub missing_cells: u64,
+    csv_cells: Vec<CsvCell>,
+    tolerance: ToleranceTracker,
+    tie_breaker: TieBreaker,
+}
+
+impl CellScan {
+    pub fn new(settings: &ScanSettings<'_>, first_tie_break: u64) -> Self {
+        Self {
+            accumulator: DiffAccumulator::new(settings.top),
+            subtolerance: settings.subtolerance.map(TopContributors::new),
+            column_top: settings.column_top.then(|| {
+                (0..settings.columns.len())
+                    .map(|_| TopContributors::new(settings.top))
+                    .collect()
+            }),
+            drift: settings
+                .drift
+                .map(|metric| DriftSamples::new(metric, settings.columns.len())),
+            sweep: settings.sweep.map(ToleranceSweep::new),
+            column_totals: ColumnTotals::new(settings.columns.len()),
+            column_deltas: ColumnDeltas::new(settings.columns.len()),
+            rescale: RescaleTracker::new(settings.columns.len()),
+            row_totals: RowTotals::new(settings.top),
+            cells_changed: 0,
+            cells_checked: 0,
+            cells_missing_both: 0,
+            cells_missing_row: 0,
+            cells_unparsable: 0,
+            details: Vec::new(),
+            format_only_changes: 0,
+            missing_cells: 0,
+            csv_cells: Vec::new(),
+            tolerance: ToleranceTracker::new(settings.base_tolerance),
+            tie_breaker: TieBreaker::starting_at(first_tie_break),
+        }
+    }
+
+    /// Compare every numeric column of one aligned row.
+    pub fn scan_row(
+        &mut self,
+        settings: &ScanSettings<'_>,
+        row_id: &RowId,
+        old: &OwnedRecord,
+        new: &OwnedRecord,
+        records: (u64, u64),
+        label: RowLabel<'_>,
+    ) {
+        let weight = settings.row_weight(Some(old), Some(new));
+        for (column_idx, (column, column_name)) in
+            settings.columns.iter().zip(settings.names).enumerate()
+        {
+            let old_raw = old.field(column.old_index);
+            let new_raw = new.field(column.new_index);
+            let pair = settings
+                .missing
+                .pair(settings.number_format, old_raw, new_raw);
+            if matches!(pair, CellPair::Skipped | CellPair::Zeroed(..)) {
+                self.missing_cells += 1;
+            }
+            if matches!(pair, CellPair::NotCompared) {
+                if settings.number_format.is_missing(old_raw)
+                    && settings.number_format.is_missing(new_raw)
+                {
+                    self.cells_missing_both += 1;
+                } else {
+                    self.cells_unparsable += 1;
+                }
+            }
+            let (CellPair::Values(old_val, new_val) | CellPair::Zeroed(old_val, new_val)) = pair
+            else {
+                continue;
+            };
+            let transforms = &settings.transforms[column_idx];
+            let (old_val, new_val) = (transforms.apply_old(old_val), transforms.apply_new(new_val));
+            self.rescale.observe(column_idx, old_val, new_val);
+            if let Some(drift) = self.drift.as_mut() {
+                drift.observe(column_idx, old_val, new_val);
+            }
+            if is_format_only_change(old_raw, new_raw, old_val, new_val) {
+                self.format_only_changes += 1;
+            }
+            let cell_id = CellId::new(row_id.clone(), Symbol::clone(column_name));
+            let lines = || settings.source_lines.locate(records.0, records.1);
+            self.observe(
+                settings, cell_id, column_idx, old_val, new_val, weight, lines, label, true,
+            );
+        }
+    }
+
+    /// Rank one numeric cell pair, scored by `--rank`; `weight` scales its
+    /// contribution (`--weight-col`). Sub-tolerance deltas and per-column delta
+    /// statistics are kept only for cells present on both sides.
+    #[allow(clippy::too_many_arguments)]
+    pub fn observe(
+        &mut self,
+        settings: &ScanSettings<'_>,
+        cell_id: CellId,
+        column_idx: usize,
+        old_val: f64,
+        new_val: f64,
+        weight: f64,
+        lines: impl FnOnce() -> Option<RowLines>,
+        label: RowLabel<'_>,
+        track_subtolerance: bool,
+    ) {
+        self.cells_checked += 1;
+        let (delta, contribution) =
+            self.tolerance
+                .apply_with(old_val, new_val, settings.tolerances[column_idx]);
+        if let Some(sweep) = self.sweep.as_mut() {
+            sweep.observe(delta);
+        }
+        if track_subtolerance {
+            self.column_deltas.observe(column_idx, delta);
+        }
+        let contribution = settings.rank.score(old_val, new_val, contribution) * weight;
+        self.column_totals.observe(column_idx, contribution);
+        if contribution > 0.0 {
+            self.cells_changed += 1;
+            if settings.collect_ledger && self.cells_changed <= settings.max_audit_changes {
+                self.details.push(ContributionDetail {
+                    id: cell_id.clone(),
+                    old: old_val,
+                    new: new_val,
+                    delta,
+                    contribution,
+                    lines: lines(),
+                });
+            }
+            if settings.report_csv {
+                self.csv_cells.push(CsvCell {
+                    row: label.to_bytes(),
+                    column: Symbol::clone(&cell_id.column),
+                    old: old_val,
+                    new: new_val,
+                    delta,
+                });
+            }
+        }
+        let tie_break = self.tie_breaker.next_value();
+        self.row_totals
+            .observe(&cell_id.row_id, contribution, tie_break);
+        if track_subtolerance
+            && let Some(subtolerance) = self.subtolerance.as_mut()
+            && contribution == 0.0
+            && delta != 0.0
+        {
+            subtolerance.push(Contributor::new(
+                cell_id.clone(),
+                old_val,
+                new_val,
+                delta,
+                delta.abs(),
+                tie_break,
+            ));
+        }
+        if let Some(column_top) = self.column_top.as_mut()
+            && contribution > 0.0
+        {
+            column_top[column_idx].push(Contributor::new(
+                cell_id.clone(),
+                old_val,
+                new_val,
+                delta,
+                contribution,
+                tie_break,
+            ));
+        }
+        self.accumulator
+            .observe(cell_id, old_val, new_val, delta, contribution, tie_break);
+    }
+
+    /// Write pending `--report-csv` rows, in scan order.
+    pub fn flush_csv(&mut self, writer: Option<&mut ChangesCsvWriter>) -> io::Result<()> {
+        let cells = std::mem::take(&mut self.csv_cells);
+        if let Some(writer) = writer {
+            for cell in cells {
+                writer.write(&cell.row, &cell.column, cell.old, cell.new, cell.delta)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fold in the next chunk: its cells come after all of this scan's.
+    pub fn merge(&mut self, next: CellScan, max_audit_changes: u64) {
+        let room = max_audit_changes.saturating_sub(self.cells_changed);
+        self.details.extend(
+            next.details
+                .into_iter()
+                .take(usize::try_from(room).unwrap_or(usize::MAX)),
+        );
+        self.cells_changed += next.cells_changed;
+        self.cells_checked += next.cells_checked;
+        self.cells_missing_both += next.cells_missing_both;
+        self.cells_missing_row += next.cells_missing_row;
+        self.cells_unparsable += next.cells_unparsable;
+        self.format_only_changes += next.format_only_changes;
+        self.missing_cells += next.missing_cells;
+        self.csv_cells.extend(next.csv_cells);
+        self.accumulator.merge(next.accumulator);
+        self.column_totals.merge(next.column_totals);
+        self.column_deltas.merge(next.column_deltas);
+        self.rescale.merge(next.rescale);
+        self.row_totals.merge(next.row_totals);
+        if let (Some(subtolerance), Some(next)) = (self.subtolerance.as_mut(), next.subtolerance) {
+            subtolerance.merge(next);
+        }
+        if let (Some(column_top), Some(next)) = (self.column_top.as_mut(), next.column_top) {
+            for (top, next) in column_top.iter_mut().zip(next) {
+                top.merge(next);
+            }
+        }
+        if let (Some(drift), Some(next)) = (self.drift.as_mut(), next.drift) {
+            drift.append(next);
+        }
+        if let (Some(sweep), Some(next)) = (self.sweep.as_mut(), next.sweep) {
+            sweep.merge(next);
+        }
+    }
+}
@@ -0,0 +1,552 @@
+//! Baseline snapshots (`rvl snapshot`) and verification (`rvl verify`).
+//!
+//! A snapshot records what a file contains without the data itself: the row
+//! count, and per column its type, cell counts, numeric sum/min/max, and a
+//! BLAKE3 digest of its values. A `rows_digest` over whole rows catches
+//! values that moved between rows while every column kept the same values.
+//!
+//! Cells are compared as rvl reads them: numbers by parsed value (so
+//! `1,000` and `1000` match), text after ASCII trim, and missing tokens as
+//! missing. Digests are over sorted values, so reordering rows alone still
+//! verifies. Verification is exact; there is no tolerance.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::args::{SnapshotArgs, VerifyArgs};
+use crate::csv::encoding::EncodingMode;
+use crate::csv::footer::FooterRule;
+use crate::csv::records::OwnedRecord;
+use crate::csv::source::{InputSource, rerun_path, source_label};
+use crate::format::ident_json::encode_identifier_json;
+use crate::format::numbers::{format_delta, format_float_shortest, format_int_with_commas};
+use crate::normalize::headers::HeaderMatch;
+use crate::normalize::trim::ascii_trim;
+use crate::numeric::parse::NumberFormat;
+use crate::output::human::refusal::{RefusalBody, render_refusal_body};
+use crate::output::json::Refusal as JsonRefusal;
+use crate::refusal::details::{FileSide, RerunPaths};
+use crate::refusal::process::{PipelineError, ProcessError};
+
+use super::inspect::ColumnKind;
+use super::{FileRules, ParsedCsv, parse_csv, refusal_detail_json};
+
+const SNAPSHOT_SCHEMA_VERSION: &str = "rvl.snapshot.v0";
+const VERIFY_SCHEMA_VERSION: &str = "rvl.verify.v0";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: String,
+    pub file: String,
+    pub rows: u64,
+    /// `blake3:` digest of the sorted row digests.
+    pub rows_digest: String,
+    pub columns: Vec<ColumnSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSnapshot {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ColumnKind,
+    pub numeric: u64,
+    pub text: u64,
+    pub missing: u64,
+    /// Sum, min, and max of the numeric cells; absent when there are none.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub sum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max: Option<f64>,
+    /// `blake3:` digest of the column's sorted cell values.
+    pub digest: String,
+}
+
+/// One column whose values differ from the snapshot.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangedColumn {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ColumnKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_type: Option<ColumnKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_sum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyReport {
+    pub version: &'static str,
+    pub file: String,
+    pub snapshot: String,
+    pub outcome: &'static str,
+    pub rows_snapshot: u64,
+    pub rows: u64,
+    pub columns_added: Vec<String>,
+    pub columns_removed: Vec<String>,
+    pub columns_changed: Vec<ChangedColumn>,
+    /// Every column matches, but values moved between rows.
+    pub rows_rearranged: bool,
+    pub refusal: Option<JsonRefusal>,
+}
+
+/// Run `rvl snapshot`. Exit codes: 0 = written, 2 = the file would refuse
+/// before comparison (its refusal is reported and nothing is written).
+pub fn run_snapshot(args: &SnapshotArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let json = args.json || json_output;
+    let snapshot = match take_snapshot(&args.file, args.delimiter) {
+        Ok(snapshot) => snapshot,
+        Err(refusal) => return Ok(emit_refusal(&args.file, None, *refusal, json)),
+    };
+    let mut body = serde_json::to_string_pretty(&snapshot)?;
+    body.push('\n');
+    fs::write(&args.out, body)
+        .map_err(|err| ProcessError::new(format!("cannot write {}: {err}", args.out.display())))?;
+    if json {
+        println!("{}", serde_json::to_string(&snapshot)?);
+    } else {
+        println!(
+            "RVL SNAPSHOT\n\nFile: {}\nRows: {}  Columns: {}\nWrote: {}",
+            source_label(&args.file),
+            format_int_with_commas(snapshot.rows as i64),
+            snapshot.columns.len(),
+            args.out.display()
+        );
+    }
+    Ok(0)
+}
+
+/// Run `rvl verify`. Exit codes: 0 = matches the snapshot, 1 = differs,
+/// 2 = the file would refuse before comparison.
+pub fn run_verify(args: &VerifyArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let json = args.json || json_output;
+    let baseline = read_snapshot(&args.snapshot)?;
+    let current = match take_snapshot(&args.file, args.delimiter) {
+        Ok(snapshot) => snapshot,
+        Err(refusal) => {
+            return Ok(emit_refusal(
+                &args.file,
+                Some(&args.snapshot),
+                *refusal,
+                json,
+            ));
+        }
+    };
+    let report = verify(&current, &baseline, &args.snapshot.to_string_lossy());
+    if json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_verify_human(&report, &baseline));
+    }
+    Ok(if report.outcome == "REAL_CHANGE" {
+        1
+    } else {
+        0
+    })
+}
+
+fn read_snapshot(path: &Path) -> Result<Snapshot, PipelineError> {
+    let raw = fs::read_to_string(path).map_err(|err| {
+        ProcessError::new(format!("cannot read snapshot {}: {err}", path.display()))
+    })?;
+    let snapshot: Snapshot = serde_json::from_str(&raw).map_err(|err| {
+        ProcessError::new(format!("{} is not an rvl snapshot: {err}", path.display()))
+    })?;
+    if snapshot.version != SNAPSHOT_SCHEMA_VERSION {
+        return Err(ProcessError::new(format!(
+            "{} has snapshot version {}; this rvl reads {SNAPSHOT_SCHEMA_VERSION}",
+            path.display(),
+            snapshot.version
+        ))
+        .into());
+    }
+    Ok(snapshot)
+}
+
+/// Read one file the way either side of a comparison is read and record it.
+fn take_snapshot(
+    path: &Path,
+    delimiter: Option<u8>,
+) -> Result<Snapshot, Box<super::RefusalPayload>> {
+    let rerun = rerun_path(path, "FILE");
+    let rerun_paths = RerunPaths {
+        old: &rerun,
+        new: &rerun,
+    };
+    let file_rules = FileRules {
+        encoding: EncodingMode::default(),
+        comment: None,
+        skip_rows: 0,
+        footer: FooterRule::Rows(0),
+        key: None,
+        aliases: None,
+        column_map: &[],
+        matching: HeaderMatch::Exact,
+    };
+    let number_format = NumberFormat::default();
+    let parsed = parse_csv(
+        InputSource::Path(path),
+        FileSide::New,
+        delimiter,
+        rerun_paths,
+        file_rules,
+        number_format,
+        false,
+    )?;
+    Ok(snapshot_parsed(path, &parsed, number_format))
+}
+
+fn snapshot_parsed(path: &Path, parsed: &ParsedCsv, number_format: NumberFormat<'_>) -> Snapshot {
+    let columns = parsed
+        .headers
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| snapshot_column(name, idx, &parsed.records, number_format))
+        .collect();
+    let mut rows: Vec<[u8; 32]> = parsed
+        .records
+        .iter()
+        .map(|record| {
+            let mut hasher = blake3::Hasher::new();
+            for idx in 0..parsed.headers.len() {
+                let cell = Cell::read(record.field(idx), number_format);
+                hash_value(&mut hasher, &cell.canonical());
+            }
+            *hasher.finalize().as_bytes()
+        })
+        .collect();
+    rows.sort_unstable();
+    let mut hasher = blake3::Hasher::new();
+    for row in &rows {
+        hasher.update(row);
+    }
+    Snapshot {
+        version: SNAPSHOT_SCHEMA_VERSION.to_string(),
+        file: source_label(path),
+        rows: parsed.records.len() as u64,
+        rows_digest: format!("blake3:{}", hasher.finalize().to_hex()),
+        columns,
+    }
+}
+
+fn snapshot_column(
+    name: &[u8],
+    idx: usize,
+    records: &[OwnedRecord],
+    number_format: NumberFormat<'_>,
+) -> ColumnSnapshot {
+    let (mut numeric, mut text, mut missing) = (0u64, 0u64, 0u64);
+    let (mut sum, mut min, mut max) = (0.0, f64::INFINITY, f64::NEG_INFINITY);
+    let mut values: Vec<Vec<u8>> = Vec::with_capacity(records.len());
+    for record in records {
+        let cell = Cell::read(record.field(idx), number_format);
+        match cell {
+            Cell::Missing => missing += 1,
+            Cell::Number(value) => {
+                numeric += 1;
+                sum += value;
+                min = min.min(value);
+                max = max.max(value);
+            }
+            Cell::Text(_) => text += 1,
+        }
+        values.push(cell.canonical());
+    }
+    values.sort_unstable();
+    let mut hasher = blake3::Hasher::new();
+    for value in &values {
+        hash_value(&mut hasher, value);
+    }
+    let kind = match (numeric, text) {
+        (0, 0) => ColumnKind::Missing,
+        (_, 0) => ColumnKind::Numeric,
+        (0, _) => ColumnKind::Text,
+        _ => ColumnKind::Mixed,
+    };
+    let has_numbers = numeric > 0;
+    ColumnSnapshot {
+        name: encode_identifier_json(name),
+        kind,
+        numeric,
+        text,
+        missing,
+        sum: has_numbers.then_some(sum),
+        min: has_numbers.then_some(min),
+        max: has_numbers.then_some(max),
+        digest: format!("blake3:{}", hasher.finalize().to_hex()),
+    }
+}
+
+/// A cell as rvl compares it.
+enum Cell<'a> {
+    Missing,
+    Number(f64),
+    Text(&'a [u8]),
+}
+
+impl<'a> Cell<'a> {
+    fn read(field: &'a [u8], number_format: NumberFormat<'_>) -> Self {
+        if number_format.is_missing(field) {
+            return Cell::Missing;
+        }
+        match number_format.parse(field) {
+            Some(value) => Cell::Number(value),
+            None => Cell::Text(ascii_trim(field)),
+        }
+    }
+
+    /// `m` for missing, `n` + the value's bits (with `-0` folded into `0`),
+    /// or `t` + the trimmed text.
+    fn canonical(&self) -> Vec<u8> {
+        match self {
+            Cell::Missing => b"m".to_vec(),
+            Cell::Number(value) => {
+                let value = if *value == 0.0 { 0.0 } else { *value };
+                let mut cell = b"n".to_vec();
+                cell.extend_from_slice(&value.to_bits().to_be_bytes());
+                cell
+            }
+            Cell::Text(text) => {
+                let mut cell = b"t".to_vec();
+                cell.extend_from_slice(text);
+                cell
+            }
+        }
+    }
+}
+
+fn hash_value(hasher: &mut blake3::Hasher, value: &[u8]) {
+    hasher.update(&(value.len() as u64).to_le_bytes());
+    hasher.update(value);
+}
+
+/// Compare a file's snapshot with the baseline.
+pub fn verify(current: &Snapshot, baseline: &Snapshot, snapshot_label: &str) -> VerifyReport {
+    let find = |columns: &[ColumnSnapshot], name: &str| {
+        columns.iter().position(|column| column.name == name)
+    };
+    let columns_added: Vec<String> = current
+        .columns
+        .iter()
+        .filter(|column| find(&baseline.columns, &column.name).is_none())
+        .map(|column| column.name.clone())
+        .collect();
+    let columns_removed: Vec<String> = baseline
+        .columns
+        .iter()
+        .filter(|column| find(&current.columns, &column.name).is_none())
+        .map(|column| column.name.clone())
+        .collect();
+    let columns_changed: Vec<ChangedColumn> = current
+        .columns
+        .iter()
+        .filter_map(|column| {
+            let before = &baseline.columns[find(&baseline.columns, &column.name)?];
+            (before.digest != column.digest).then(|| ChangedColumn {
+                name: column.name.clone(),
+                kind: column.kind,
+                snapshot_type: (before.kind != column.kind).then_some(before.kind),
+                snapshot_sum: before.sum,
+                sum: column.sum,
+                delta: before.sum.zip(column.sum).map(|(old, new)| new - old),
+            })
+        })
+        .collect();
+    let schema_changed = !columns_added.is_empty() || !columns_removed.is_empty();
+    let rows_rearranged = !schema_changed
+        && columns_changed.is_empty()
+        && current.rows_digest != baseline.rows_digest;
+    let changed = schema_changed
+        || current.rows != baseline.rows
+        || !columns_changed.is_empty()
+        || rows_rearranged;
+    VerifyReport {
+        version: VERIFY_SCHEMA_VERSION,
+        file: current.file.clone(),
+        snapshot: snapshot_label.to_string(),
+        outcome: if changed {
+            "REAL_CHANGE"
+        } else {
+            "NO_REAL_CHANGE"
+        },
+        rows_snapshot: baseline.rows,
+        rows: current.rows,
+        columns_added,
+        columns_removed,
+        columns_changed,
+        rows_rearranged,
+        refusal: None,
+    }
+}
+
+fn emit_refusal(
+    path: &Path,
+    snapshot: Option<&Path>,
+    refusal: super::RefusalPayload,
+    json: bool,
+) -> u8 {
+    let label = source_label(path);
+    if json {
+        let json_refusal = JsonRefusal::new(
+            refusal.code,
+            refusal.detail.reason(refusal.code),
+            refusal_detail_json(&refusal.detail),
+        );
+        let output = match snapshot {
+            Some(snapshot) => serde_json::to_string(&VerifyReport {
+                version: VERIFY_SCHEMA_VERSION,
+                file: label,
+                snapshot: snapshot.to_string_lossy().to_string(),
+                outcome: "REFUSAL",
+                rows_snapshot: 0,
+                rows: 0,
+                columns_added: Vec::new(),
+                columns_removed: Vec::new(),
+                columns_changed: Vec::new(),
+                rows_rearranged: false,
+                refusal: Some(json_refusal),
+            }),
+            None => serde_json::to_string(&serde_json::json!({
+                "version": SNAPSHOT_SCHEMA_VERSION,
+                "file": label,
+                "refusal": json_refusal,
+            })),
+        };
+        println!("{}", output.unwrap_or_else(|_| "{}".to_string()));
+    } else {
+        let title = if snapshot.is_some() {
+            "RVL VERIFY"
+        } else {
+            "RVL SNAPSHOT"
+        };
+        let mut lines = vec![title.to_string(), String::new(), format!("File: {label}")];
+        lines.push(String::new());
+        lines.extend(render_refusal_body(&RefusalBody {
+            code: refusal.code,
+            detail: &refusal.detail,
+            old_name: &label,
+            new_name: &label,
+        }));
+        println!("{}", lines.join("\n"));
+    }
+    2
+}
+
+fn render_verify_human(report: &VerifyReport, baseline: &Snapshot) -> String {
+    let mut lines = vec!["RVL VERIFY".to_string(), String::new()];
+    lines.push(format!("File: {}", report.file));
+    lines.push(format!(
+        "Snapshot: {} (of {}, {} rows, {} columns)",
+        report.snapshot,
+        baseline.file,
+        format_int_with_commas(report.rows_snapshot as i64),
+        baseline.columns.len()
+    ));
+    lines.push(String::new());
+    if report.outcome == "NO_REAL_CHANGE" {
+        lines.push("VERIFIED: content matches the snapshot.".to_string());
+        return lines.join("\n");
+    }
+    lines.push("CHANGED: content differs from the snapshot.".to_string());
+    lines.push(String::new());
+    if report.rows != report.rows_snapshot {
+        lines.push(format!(
+            "Rows: {} -> {}",
+            format_int_with_commas(report.rows_snapshot as i64),
+            format_int_with_commas(report.rows as i64)
+        ));
+    }
+    if !report.columns_added.is_empty() {
+        lines.push(format!(
+            "Columns added: {}",
+            report.columns_added.join(", ")
+        ));
+    }
+    if !report.columns_removed.is_empty() {
+        lines.push(format!(
+            "Columns removed: {}",
+            report.columns_removed.join(", ")
+        ));
+    }
+    for column in &report.columns_changed {
+        let mut line = format!("  {}  values changed", column.name);
+        if let Some(before) = column.snapshot_type {
+            line.push_str(&format!(
+                "; type {} -> {}",
+                before.as_str(),
+                column.kind.as_str()
+            ));
+        }
+        if let (Some(old), Some(new), Some(delta)) = (column.snapshot_sum, column.sum, column.delta)
+        {
+            line.push_str(&format!(
+                "; sum {} -> {} ({})",
+                format_float_shortest(old),
+                format_float_shortest(new),
+                format_delta(delta)
+            ));
+        }
+        lines.push(line);
+    }
+    if report.rows_rearranged {
+        lines.push(
+            "Rows rearranged: every column matches, but values moved between rows.".to_string(),
+        );
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot_bytes(name: &str, bytes: &str) -> Snapshot {
+        let dir = std::env::temp_dir().join(format!("rvl_snapshot_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, bytes).unwrap();
+        let snapshot = take_snapshot(&path, None);
+        let _ = std::fs::remove_file(&path);
+        snapshot.unwrap_or_else(|_| panic!("{name} should snapshot"))
+    }
+
+    #[test]
+    fn format_and_row_order_changes_verify() {
+        let baseline = snapshot_bytes("unit_snapshot_base.csv", "id,amount\nA,1000\nB,5\n");
+        let current = snapshot_bytes("unit_snapshot_same.csv", "id,amount\nB,5.0\nA,\"1,000\"\n");
+        let report = verify(&current, &baseline, "base.rvlsnap");
+        assert_eq!(report.outcome, "NO_REAL_CHANGE");
+        assert_eq!(baseline.columns[1].sum, Some(1005.0));
+    }
+
+    #[test]
+    fn changed_values_report_sum_deltas() {
+        let baseline = snapshot_bytes("unit_snapshot_base2.csv", "id,amount\nA,10\nB,5\n");
+        let current = snapshot_bytes(
+            "unit_snapshot_changed.csv",
+            "id,amount,note\nA,12,x\nB,5,y\n",
+        );
+        let report = verify(&current, &baseline, "base.rvlsnap");
+        assert_eq!(report.outcome, "REAL_CHANGE");
+        assert_eq!(report.columns_added, vec!["u8:note"]);
+        assert_eq!(report.columns_changed.len(), 1);
+        assert_eq!(report.columns_changed[0].delta, Some(2.0));
+    }
+
+    #[test]
+    fn values_moved_between_rows_are_caught() {
+        let baseline = snapshot_bytes("unit_snapshot_base3.csv", "id,amount\nA,1\nB,2\n");
+        let current = snapshot_bytes("unit_snapshot_swapped.csv", "id,amount\nA,2\nB,1\n");
+        let report = verify(&current, &baseline, "base.rvlsnap");
+        assert_eq!(report.outcome, "REAL_CHANGE");
+        assert!(report.columns_changed.is_empty());
+        assert!(report.rows_rearranged);
+    }
+}
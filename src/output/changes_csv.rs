@@ -0,0 +1,135 @@
+//! Streaming `--report-csv` writer: one line per changed numeric cell.
+//!
+//! Cells are written as the diff loop finds them, so the file is not bounded
+//! by `--top` or `--max-audit-changes` and memory stays flat however many
+//! cells change. Rows follow input order (row, then column), not rank.
+//!
+//! The file is built next to its destination as `<name>.partial` and renamed
+//! into place by [`ChangesCsvWriter::finish`]. A writer dropped without
+//! finishing (a refusal after the diff loop) removes the partial file, so a
+//! report on disk always belongs to a completed verdict.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::{Path, PathBuf};
+
+use crate::format::numbers::format_float_shortest;
+
+pub struct ChangesCsvWriter {
+    writer: Option<csv::Writer<BufWriter<File>>>,
+    path: PathBuf,
+    partial: PathBuf,
+    explicit: bool,
+}
+
+impl ChangesCsvWriter {
+    /// Open the partial file and write the header. Values (`old`, `new`,
+    /// `delta`) are included only when `explicit` is set.
+    pub fn create(path: &Path, explicit: bool) -> io::Result<Self> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".partial");
+        let partial = PathBuf::from(partial);
+
+        let mut writer = csv::Writer::from_writer(BufWriter::new(File::create(&partial)?));
+        let header: &[&str] = if explicit {
+            &["row", "column", "old", "new", "delta"]
+        } else {
+            &["row", "column"]
+        };
+        writer.write_record(header)?;
+        Ok(Self {
+            writer: Some(writer),
+            path: path.to_path_buf(),
+            partial,
+            explicit,
+        })
+    }
+
+    /// Append one changed cell. `row` is the key value in key mode or the
+    /// 1-based data row number in row-order mode; both are written as raw bytes.
+    pub fn write(
+        &mut self,
+        row: &[u8],
+        column: &[u8],
+        old: f64,
+        new: f64,
+        delta: f64,
+    ) -> io::Result<()> {
+        let Some(writer) = self.writer.as_mut() else {
+            return Ok(());
+        };
+        if self.explicit {
+            writer.write_record([
+                row,
+                column,
+                format_float_shortest(old).as_bytes(),
+                format_float_shortest(new).as_bytes(),
+                format_float_shortest(delta).as_bytes(),
+            ])?;
+        } else {
+            writer.write_record([row, column])?;
+        }
+        Ok(())
+    }
+
+    /// Flush and move the report into place.
+    pub fn finish(mut self) -> io::Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            writer.flush()?;
+        }
+        fs::rename(&self.partial, &self.path)
+    }
+}
+
+impl Drop for ChangesCsvWriter {
+    fn drop(&mut self) {
+        if self.writer.take().is_some() {
+            let _ = fs::remove_file(&self.partial);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir()
+            .join(format!("rvl_changes_csv_{label}_{nanos}"))
+            .join("changes.csv")
+    }
+
+    #[test]
+    fn finished_report_replaces_the_partial_file() {
+        let path = temp_path("finish");
+        let mut writer = ChangesCsvWriter::create(&path, true).unwrap();
+        writer.write(b"A,1", b"amount", 1.0, 2.5, 1.5).unwrap();
+        writer.finish().unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert_eq!(text, "row,column,old,new,delta\n\"A,1\",amount,1,2.5,1.5\n");
+        assert!(!path.with_file_name("changes.csv.partial").exists());
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+
+    #[test]
+    fn unfinished_report_leaves_nothing_behind() {
+        let path = temp_path("drop");
+        let mut writer = ChangesCsvWriter::create(&path, false).unwrap();
+        writer.write(b"1", b"amount", 1.0, 2.0, 1.0).unwrap();
+        drop(writer);
+
+        assert!(!path.exists());
+        assert!(!path.with_file_name("changes.csv.partial").exists());
+        fs::remove_dir_all(path.parent().unwrap()).ok();
+    }
+}
@@ -0,0 +1,150 @@
+//! `--events` NDJSON lifecycle stream.
+//!
+//! One JSON object per line, written and flushed as the run reaches each
+//! stage, so a pipeline orchestrator can follow a comparison without waiting
+//! for the verdict document. Every line carries `version`, `event`, and
+//! `elapsed_ms` since the stream opened. A run ends with exactly one
+//! `refusal` or `verdict` event.
+//!
+//! The stream is a side channel: once a write fails (the reader went away),
+//! later events are dropped and the run still completes normally.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::output::json::DialectSide;
+
+/// Where `--events` writes: an inherited file descriptor (`fd:3`) or a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventTarget {
+    Fd(u32),
+    Path(PathBuf),
+}
+
+impl EventTarget {
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Err("events target must be fd:N or a file path".to_string());
+        }
+        match raw.strip_prefix("fd:") {
+            Some(fd) => fd
+                .parse::<u32>()
+                .map(EventTarget::Fd)
+                .map_err(|_| format!("events fd must be a non-negative integer, got `{fd}`")),
+            None => Ok(EventTarget::Path(PathBuf::from(raw))),
+        }
+    }
+
+    fn open(&self) -> io::Result<File> {
+        match self {
+            // Reopen through the fd directory rather than adopting the raw
+            // descriptor, which would need `unsafe`.
+            EventTarget::Fd(fd) => OpenOptions::new()
+                .append(true)
+                .open(format!("/dev/fd/{fd}")),
+            EventTarget::Path(path) => File::create(path),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event<'a> {
+    /// Both inputs are about to be read and parsed.
+    ParseStarted { old: &'a str, new: &'a str },
+    /// One input parsed; `file` is `old` or `new`.
+    DialectDetected {
+        file: &'static str,
+        dialect: DialectSide,
+    },
+    /// The run refused; terminal.
+    Refusal { code: &'a str, message: &'a str },
+    /// The run reached REAL_CHANGE or NO_REAL_CHANGE; terminal.
+    Verdict { outcome: &'a str, exit_code: u8 },
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    version: &'static str,
+    elapsed_ms: f64,
+    #[serde(flatten)]
+    event: &'a Event<'a>,
+}
+
+pub struct EventStream {
+    writer: Option<BufWriter<File>>,
+    started: Instant,
+}
+
+impl EventStream {
+    /// A stream that drops every event (no `--events`).
+    pub fn disabled() -> Self {
+        Self {
+            writer: None,
+            started: Instant::now(),
+        }
+    }
+
+    pub fn open(target: &EventTarget) -> io::Result<Self> {
+        Ok(Self {
+            writer: Some(BufWriter::new(target.open()?)),
+            started: Instant::now(),
+        })
+    }
+
+    pub fn emit(&mut self, event: &Event<'_>) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+        let line = Line {
+            version: "rvl.v0",
+            elapsed_ms: self.started.elapsed().as_secs_f64() * 1000.0,
+            event,
+        };
+        let written = serde_json::to_writer(&mut *writer, &line)
+            .map_err(io::Error::from)
+            .and_then(|()| writer.write_all(b"\n"))
+            .and_then(|()| writer.flush());
+        if written.is_err() {
+            self.writer = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fd_and_path_targets() {
+        assert_eq!(EventTarget::parse("fd:3"), Ok(EventTarget::Fd(3)));
+        assert_eq!(
+            EventTarget::parse("events.ndjson"),
+            Ok(EventTarget::Path(PathBuf::from("events.ndjson")))
+        );
+        assert!(EventTarget::parse("fd:x").is_err());
+        assert!(EventTarget::parse(" ").is_err());
+    }
+
+    #[test]
+    fn events_serialize_flat_with_envelope() {
+        let event = Event::Refusal {
+            code: "E_IO",
+            message: "cannot read file",
+        };
+        let line = Line {
+            version: "rvl.v0",
+            elapsed_ms: 1.5,
+            event: &event,
+        };
+        assert_eq!(
+            serde_json::to_string(&line).unwrap(),
+            r#"{"version":"rvl.v0","elapsed_ms":1.5,"event":"refusal","code":"E_IO","message":"cannot read file"}"#
+        );
+    }
+}
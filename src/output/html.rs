@@ -0,0 +1,317 @@
+// Self-contained HTML report (`--format html`)
+
+use serde_json::Value;
+
+use crate::format::numbers::{
+    format_delta, format_float_shortest, format_int_with_commas, format_percent_one_decimal,
+};
+use crate::output::display_identifier;
+use crate::output::json::{AlignmentMode, Contributor, JsonOutput, Outcome, Refusal};
+
+const STYLE: &str = "\
+body{font:14px/1.45 system-ui,-apple-system,Segoe UI,sans-serif;color:#1f2328;margin:0 auto;max-width:960px;padding:24px}
+h2{font-size:16px;margin:28px 0 8px}
+.banner{border-radius:6px;padding:16px 20px;color:#fff}
+.banner h1{font-size:22px;margin:0 0 4px}
+.banner p{margin:0}
+.real-change{background:#b35900}
+.no-real-change{background:#1a7f37}
+.refusal{background:#a40e26}
+table{border-collapse:collapse;width:100%}
+th,td{border-bottom:1px solid #d0d7de;padding:4px 8px;text-align:left;vertical-align:top}
+th{background:#f6f8fa}
+td.num{text-align:right;font-variant-numeric:tabular-nums;white-space:nowrap}
+.receipt th{width:30%}
+.bar{position:relative;height:12px;min-width:160px;background:#f6f8fa}
+.bar span{position:absolute;top:0;height:12px}
+.bar .up{left:50%;background:#1a7f37}
+.bar .down{right:50%;background:#cf222e}
+.bar .share{left:0;background:#0969da}
+.note{color:#59636e}
+pre{background:#f6f8fa;padding:8px;overflow-x:auto}
+footer{margin-top:28px;color:#59636e;font-size:12px}
+";
+
+/// Render an `rvl.v0` report as one HTML page with inline styles and no
+/// scripts, so it can be attached or archived as-is.
+pub fn render_html_report(report: &JsonOutput) -> String {
+    let mut html = String::with_capacity(8 * 1024);
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>rvl: {} vs {}</title>\n",
+        escape(&report.files.old),
+        escape(&report.files.new)
+    ));
+    html.push_str(&format!("<style>\n{STYLE}</style>\n</head>\n<body>\n"));
+    push_banner(&mut html, report);
+    if let Some(refusal) = report.refusal.as_ref() {
+        push_refusal(&mut html, refusal);
+    }
+    push_receipt(&mut html, report);
+    if !report.contributors.is_empty() {
+        push_contributors(&mut html, &report.contributors);
+    }
+    html.push_str(&format!(
+        "<footer>{} &middot; rvl {}</footer>\n</body>\n</html>\n",
+        report.version,
+        env!("CARGO_PKG_VERSION")
+    ));
+    html
+}
+
+fn push_banner(html: &mut String, report: &JsonOutput) {
+    let sampled = if report.alignment.sample.is_some() {
+        " (SAMPLED)"
+    } else {
+        ""
+    };
+    let (class, title) = match report.outcome {
+        Outcome::RealChange => ("real-change", format!("REAL CHANGE{sampled}")),
+        Outcome::NoRealChange => ("no-real-change", format!("NO REAL CHANGE{sampled}")),
+        Outcome::Refusal => (
+            "refusal",
+            match report.refusal.as_ref() {
+                Some(refusal) => format!("REFUSAL ({})", refusal.code),
+                None => "REFUSAL".to_string(),
+            },
+        ),
+    };
+    html.push_str(&format!(
+        "<header class=\"banner {class}\">\n<h1>{}</h1>\n<p>{}</p>\n</header>\n",
+        escape(&title),
+        escape(&summary(report))
+    ));
+}
+
+fn summary(report: &JsonOutput) -> String {
+    let files = format!("{} vs {}", report.files.old, report.files.new);
+    match report.outcome {
+        Outcome::RealChange => {
+            let count = report.contributors.len();
+            let cells = if count == 1 { "cell" } else { "cells" };
+            match report.metrics.top_k_coverage {
+                Some(coverage) => format!(
+                    "{files}: {count} {cells} explain {} of total numeric change (threshold {}).",
+                    format_percent_one_decimal(coverage),
+                    format_percent_one_decimal(report.threshold)
+                ),
+                None => format!("{files}: {count} {cells} changed above tolerance."),
+            }
+        }
+        Outcome::NoRealChange => format!(
+            "{files}: no numeric change above tolerance {}.",
+            format_float_shortest(report.tolerance)
+        ),
+        Outcome::Refusal => match report.refusal.as_ref() {
+            Some(refusal) => format!("{files}: {}", refusal.message),
+            None => files,
+        },
+    }
+}
+
+fn push_refusal(html: &mut String, refusal: &Refusal) {
+    html.push_str("<h2>Why rvl refused</h2>\n");
+    html.push_str(&format!(
+        "<p><strong>{}</strong>: {}</p>\n",
+        escape(&refusal.code),
+        escape(&refusal.message)
+    ));
+    let Value::Object(detail) = &refusal.detail else {
+        return;
+    };
+    if detail.is_empty() {
+        return;
+    }
+    html.push_str("<table class=\"receipt\">\n");
+    for (field, value) in detail {
+        let value = match value {
+            Value::String(text) => escape(text),
+            other => format!(
+                "<pre>{}</pre>",
+                escape(&serde_json::to_string_pretty(other).unwrap_or_default())
+            ),
+        };
+        html.push_str(&format!(
+            "<tr><th>{}</th><td>{value}</td></tr>\n",
+            escape(field)
+        ));
+    }
+    html.push_str("</table>\n");
+}
+
+fn push_receipt(html: &mut String, report: &JsonOutput) {
+    let mut rows: Vec<(&str, String)> = vec![
+        ("Old file", report.files.old.clone()),
+        ("New file", report.files.new.clone()),
+        ("Alignment", alignment_label(report)),
+        ("Threshold", format_percent_one_decimal(report.threshold)),
+        ("Tolerance", format_float_shortest(report.tolerance)),
+    ];
+    if let Some(profile) = report.profile_id.as_ref() {
+        rows.push(("Profile", profile.clone()));
+    }
+    let counts = &report.counts;
+    let count_rows = [
+        ("Rows (old)", counts.rows_old),
+        ("Rows (new)", counts.rows_new),
+        ("Rows aligned", counts.rows_aligned),
+        ("Numeric columns", counts.numeric_columns),
+        ("Numeric cells checked", counts.numeric_cells_checked),
+        ("Numeric cells changed", counts.numeric_cells_changed),
+    ];
+    for (label, value) in count_rows {
+        if let Some(value) = value {
+            rows.push((label, format_count(value)));
+        }
+    }
+    let metrics = &report.metrics;
+    if let Some(total) = metrics.total_change {
+        rows.push(("Total change", format_float_shortest(total)));
+    }
+    if let Some(max) = metrics.max_abs_delta {
+        rows.push(("Max abs delta", format_float_shortest(max)));
+    }
+    if let Some(coverage) = metrics.top_k_coverage {
+        rows.push(("Top-k coverage", format_percent_one_decimal(coverage)));
+    }
+
+    html.push_str("<h2>Receipt</h2>\n<table class=\"receipt\">\n");
+    for (label, value) in rows {
+        html.push_str(&format!(
+            "<tr><th>{label}</th><td>{}</td></tr>\n",
+            escape(&value)
+        ));
+    }
+    html.push_str("</table>\n");
+}
+
+fn alignment_label(report: &JsonOutput) -> String {
+    let alignment = &report.alignment;
+    let column = alignment
+        .key_column
+        .as_deref()
+        .map(display_identifier)
+        .unwrap_or_default();
+    let mut label = match alignment.mode {
+        AlignmentMode::Key => format!("key={column}"),
+        AlignmentMode::GroupBy => format!("group-by={column} (sum per group)"),
+        AlignmentMode::RowOrder => "row-order".to_string(),
+    };
+    if let Some(map) = alignment.key_map.as_ref() {
+        label.push_str(&format!(" (old keys via {})", map.file));
+    }
+    if !alignment.key_normalize.is_empty() {
+        label.push_str(&format!(
+            " (keys normalized: {})",
+            alignment.key_normalize.join(", ")
+        ));
+    }
+    if let Some(dedup) = alignment.dedup.as_ref() {
+        label.push_str(&format!(
+            " (dedup={}: collapsed {} old, {} new rows)",
+            dedup.mode, dedup.rows_collapsed_old, dedup.rows_collapsed_new
+        ));
+    }
+    if let Some(sample) = alignment.sample.as_ref() {
+        label.push_str(&format!(
+            " (sampled {} of {} rows, seed {})",
+            sample.rows_sampled, sample.rows_eligible, sample.seed
+        ));
+    }
+    label
+}
+
+/// Bars are scaled to the largest absolute delta, growing right for
+/// increases and left for decreases. Redacted runs have no deltas, so each
+/// bar shows the contributor's share of total change instead.
+fn push_contributors(html: &mut String, contributors: &[Contributor]) {
+    let explicit = contributors.iter().any(|c| c.delta.is_some());
+    let max_delta = contributors
+        .iter()
+        .filter_map(|c| c.delta)
+        .fold(0.0_f64, |max, delta| max.max(delta.abs()));
+
+    html.push_str("<h2>Contributors</h2>\n");
+    if !explicit {
+        html.push_str(
+            "<p class=\"note\">Values are redacted; rerun with --explicit to include old, new, and delta.</p>\n",
+        );
+    }
+    html.push_str("<table>\n<tr><th>#</th><th>Row</th><th>Column</th>");
+    if explicit {
+        html.push_str("<th>Old</th><th>New</th><th>Delta</th>");
+    }
+    html.push_str("<th>Share</th><th>Cumulative</th><th></th></tr>\n");
+    for (idx, contributor) in contributors.iter().enumerate() {
+        html.push_str(&format!(
+            "<tr><td class=\"num\">{}</td><td>{}</td><td>{}</td>",
+            idx + 1,
+            escape(display_identifier(&contributor.row_id)),
+            escape(display_identifier(&contributor.column))
+        ));
+        if explicit {
+            html.push_str(&format!(
+                "<td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>",
+                format_optional(contributor.old, format_float_shortest),
+                format_optional(contributor.new, format_float_shortest),
+                format_optional(contributor.delta, format_delta)
+            ));
+        }
+        html.push_str(&format!(
+            "<td class=\"num\">{}</td><td class=\"num\">{}</td><td>{}</td></tr>\n",
+            format_percent_one_decimal(contributor.share),
+            format_percent_one_decimal(contributor.cumulative_share),
+            bar(contributor, explicit, max_delta)
+        ));
+    }
+    html.push_str("</table>\n");
+}
+
+fn bar(contributor: &Contributor, explicit: bool, max_delta: f64) -> String {
+    let (class, width) = match contributor.delta {
+        Some(delta) if explicit && max_delta > 0.0 => (
+            if delta < 0.0 { "down" } else { "up" },
+            delta.abs() / max_delta * 50.0,
+        ),
+        _ => ("share", contributor.share * 100.0),
+    };
+    format!("<div class=\"bar\"><span class=\"{class}\" style=\"width:{width:.1}%\"></span></div>")
+}
+
+fn format_optional(value: Option<f64>, format: fn(f64) -> String) -> String {
+    value.map(format).unwrap_or_default()
+}
+
+fn format_count(value: u64) -> String {
+    format_int_with_commas(i64::try_from(value).unwrap_or(i64::MAX))
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_markup_in_identifiers() {
+        assert_eq!(
+            escape("<b>\"Q&A\"</b>"),
+            "&lt;b&gt;&quot;Q&amp;A&quot;&lt;/b&gt;"
+        );
+        assert_eq!(display_identifier("u8:acct 1"), "acct 1");
+        assert_eq!(display_identifier("hex:00ff"), "hex:00ff");
+    }
+}
@@ -0,0 +1,50 @@
+// Human semantic column type section formatting
+
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_float_shortest;
+use crate::numeric::semantic::ColumnSemantic;
+
+/// Lines for `--smart-tolerance`: the type and tolerance applied to each column.
+pub fn render_column_type_lines(columns: &[ColumnSemantic]) -> Vec<String> {
+    let mut lines = Vec::with_capacity(columns.len() + 1);
+    lines.push("Column types (--smart-tolerance):".to_string());
+    for column in columns {
+        lines.push(format!(
+            "  {}  {}  tolerance={}",
+            render_identifier_human(&column.column),
+            column.semantic.as_str(),
+            format_float_shortest(column.tolerance)
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::semantic::SemanticType;
+
+    #[test]
+    fn renders_one_line_per_column() {
+        let columns = vec![
+            ColumnSemantic {
+                column: b"balance".to_vec(),
+                semantic: SemanticType::Currency,
+                tolerance: 0.005,
+            },
+            ColumnSemantic {
+                column: b"units".to_vec(),
+                semantic: SemanticType::Count,
+                tolerance: 0.0,
+            },
+        ];
+        assert_eq!(
+            render_column_type_lines(&columns),
+            vec![
+                "Column types (--smart-tolerance):",
+                "  balance  currency  tolerance=5e-3",
+                "  units  count  tolerance=0",
+            ]
+        );
+    }
+}
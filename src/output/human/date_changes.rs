@@ -0,0 +1,84 @@
+// Human date-change section formatting
+
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_int_with_commas;
+use crate::numeric::dates::{DateChangeReport, format_date};
+
+/// Lines for changed date cells: a count per run, then one line per shifted
+/// cell. Shifts in days and the dates themselves appear only when values
+/// may be shown (`--explicit`); otherwise just the direction.
+pub fn render_date_change_lines(report: &DateChangeReport) -> Vec<String> {
+    let columns: Vec<String> = report
+        .columns
+        .iter()
+        .map(|column| render_identifier_human(column))
+        .collect();
+    let noun = if report.cells_changed == 1 {
+        "cell"
+    } else {
+        "cells"
+    };
+    let mut lines = vec![format!(
+        "Date changes: {} {noun} shifted in {}",
+        format_int_with_commas(report.cells_changed as i64),
+        columns.join(", ")
+    )];
+    for change in &report.changes {
+        let label = format!(
+            "{}.{}",
+            render_identifier_human(&change.row_id),
+            render_identifier_human(&change.column)
+        );
+        let detail = match change.values {
+            Some(values) => {
+                let days = values.shift_days();
+                let unit = if days.abs() == 1 { "day" } else { "days" };
+                format!(
+                    "{days:+} {unit} ({} -> {})",
+                    format_date(values.old),
+                    format_date(values.new)
+                )
+            }
+            None if change.later => "later".to_string(),
+            None => "earlier".to_string(),
+        };
+        lines.push(format!("  {label}  {detail}"));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::dates::{DateChange, DateValues};
+
+    #[test]
+    fn renders_direction_or_explicit_shift() {
+        let mut report = DateChangeReport {
+            columns: vec![b"lease_start".to_vec()],
+            cells_changed: 1,
+            changes: vec![DateChange {
+                row_id: b"A".to_vec(),
+                column: b"lease_start".to_vec(),
+                later: true,
+                values: None,
+            }],
+        };
+        assert_eq!(
+            render_date_change_lines(&report),
+            vec![
+                "Date changes: 1 cell shifted in lease_start",
+                "  A.lease_start  later",
+            ]
+        );
+
+        report.changes[0].values = Some(DateValues {
+            old: 19_723,
+            new: 19_753,
+        });
+        assert_eq!(
+            render_date_change_lines(&report)[1],
+            "  A.lease_start  +30 days (2024-01-01 -> 2024-01-31)"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+// Human distribution drift section formatting
+
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_int_with_commas;
+use crate::numeric::drift::DriftReport;
+
+/// Lines for `--drift`: one statistic per numeric column, largest first.
+pub fn render_drift_lines(report: &DriftReport) -> Vec<String> {
+    let mut lines = Vec::with_capacity(report.columns.len() + 1);
+    lines.push(format!("Distribution drift ({}):", report.metric.label()));
+    for column in &report.columns {
+        lines.push(format!(
+            "  {}  {:.4}  ({} pairs)",
+            render_identifier_human(&column.column),
+            column.value,
+            format_int_with_commas(column.pairs as i64)
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::drift::{ColumnDrift, DriftMetric};
+
+    #[test]
+    fn renders_one_line_per_column() {
+        let report = DriftReport {
+            metric: DriftMetric::Psi,
+            columns: vec![
+                ColumnDrift {
+                    column: b"amount".to_vec(),
+                    value: 0.41234,
+                    pairs: 1200,
+                },
+                ColumnDrift {
+                    column: b"fee".to_vec(),
+                    value: 0.0,
+                    pairs: 1200,
+                },
+            ],
+        };
+        assert_eq!(
+            render_drift_lines(&report),
+            vec![
+                "Distribution drift (PSI):",
+                "  amount  0.4123  (1,200 pairs)",
+                "  fee  0.0000  (1,200 pairs)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+// Human incremental-verdict section formatting
+
+/// Lines for `--expected`: the incremental verdict, then each contributor
+/// missing from the approved baseline.
+pub fn render_expected_lines(file: &str, approved: u64, new_labels: &[String]) -> Vec<String> {
+    let verdict = if new_labels.is_empty() {
+        "NO NEW CHANGE"
+    } else {
+        "NEW CHANGE"
+    };
+    let mut lines = vec![format!(
+        "Expected ({file}): {verdict} ({} new, {approved} approved)",
+        new_labels.len()
+    )];
+    if !new_labels.is_empty() {
+        lines.push("New since baseline:".to_string());
+        lines.extend(new_labels.iter().map(|label| format!("  {label}")));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_only_new_contributors() {
+        assert_eq!(
+            render_expected_lines("approved.json", 2, &["B.v".to_string()]),
+            vec![
+                "Expected (approved.json): NEW CHANGE (1 new, 2 approved)",
+                "New since baseline:",
+                "  B.v",
+            ]
+        );
+        assert_eq!(
+            render_expected_lines("approved.json", 3, &[]),
+            vec!["Expected (approved.json): NO NEW CHANGE (0 new, 3 approved)"]
+        );
+    }
+}
@@ -1,24 +1,109 @@
 // Human output headers (bd-2z3)
 
+use crate::csv::compression::Compression;
+use crate::csv::encoding::SourceEncoding;
+use crate::diff::rank::Ranking;
+use crate::format::ident_human::render_identifier_human;
 use crate::format::numbers::{
     format_float_shortest, format_int_with_commas, format_percent_one_decimal,
 };
+use crate::normalize::column_map::ColumnMapping;
+use crate::numeric::parse::DecimalSeparator;
+use crate::numeric::transform::{ColumnTransform, TransformSide};
+use crate::output::json::KeyDedup;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Alignment<'a> {
-    Key { column: &'a str },
+    Key {
+        column: &'a str,
+        /// `--key-normalize` steps, in order.
+        key_normalize: &'a [&'static str],
+        /// Repeated keys collapsed by `--dedup sum|first`.
+        dedup: Option<&'a KeyDedup>,
+    },
+    /// Key join after translating old-file keys through a `--key-map` file.
+    MappedKey {
+        column: &'a str,
+        key_map: &'a str,
+        key_normalize: &'a [&'static str],
+        dedup: Option<&'a KeyDedup>,
+    },
+    /// Key join on per-group sums (`--group-by`).
+    GroupBy {
+        column: &'a str,
+        key_map: Option<&'a str>,
+        key_normalize: &'a [&'static str],
+        dedup: Option<&'a KeyDedup>,
+    },
     RowOrder,
 }
 
 impl<'a> Alignment<'a> {
     fn render(self) -> String {
         match self {
-            Alignment::Key { column } => format!("key={column}"),
+            Alignment::Key {
+                column,
+                key_normalize,
+                dedup,
+            } => format!(
+                "key={column}{}{}",
+                render_key_normalize(key_normalize),
+                render_dedup(dedup)
+            ),
+            Alignment::MappedKey {
+                column,
+                key_map,
+                key_normalize,
+                dedup,
+            } => format!(
+                "key={column} (old keys via {key_map}){}{}",
+                render_key_normalize(key_normalize),
+                render_dedup(dedup)
+            ),
+            Alignment::GroupBy {
+                column,
+                key_map,
+                key_normalize,
+                dedup,
+            } => {
+                let mut rendered = format!("group-by={column} (sum per group)");
+                if let Some(key_map) = key_map {
+                    rendered.push_str(&format!(" (old keys via {key_map})"));
+                }
+                rendered.push_str(&render_key_normalize(key_normalize));
+                if let Some(dedup) = dedup {
+                    rendered.push_str(&format!(
+                        " (collapsed {} old, {} new rows)",
+                        format_count(dedup.rows_collapsed_old),
+                        format_count(dedup.rows_collapsed_new)
+                    ));
+                }
+                rendered
+            }
             Alignment::RowOrder => "row-order (no key)".to_string(),
         }
     }
 }
 
+fn render_key_normalize(steps: &[&str]) -> String {
+    if steps.is_empty() {
+        return String::new();
+    }
+    format!(" (keys normalized: {})", steps.join(", "))
+}
+
+fn render_dedup(dedup: Option<&KeyDedup>) -> String {
+    match dedup {
+        Some(dedup) => format!(
+            " (dedup={}: collapsed {} old, {} new rows)",
+            dedup.mode,
+            format_count(dedup.rows_collapsed_old),
+            format_count(dedup.rows_collapsed_new)
+        ),
+        None => String::new(),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct ColumnCounts {
     pub common: u64,
@@ -38,6 +123,14 @@ pub struct DialectReceipt {
     pub delimiter: u8,
     pub quote: u8,
     pub escape: Option<u8>,
+    /// Set when the input was gzip/zstd-compressed.
+    pub compression: Option<Compression>,
+    /// Set when the input was transcoded to UTF-8 (`--encoding`).
+    pub encoding: Option<SourceEncoding>,
+    /// Auto-detection confidence; `None` when the delimiter was not detected.
+    pub confidence: Option<f64>,
+    /// Echoed only when not the default dot (`--decimal comma`).
+    pub decimal: DecimalSeparator,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -74,11 +167,22 @@ pub struct HumanHeader<'a> {
     pub old_name: &'a str,
     pub new_name: &'a str,
     pub alignment: Alignment<'a>,
+    /// Old-file columns compared under new-file names (`--map`).
+    pub column_map: &'a [ColumnMapping],
+    /// Leftover old-only/new-only pairs that look like renames.
+    pub suggested_maps: &'a [ColumnMapping],
     pub profile: Option<Profile<'a>>,
+    /// Numeric columns where `--parse-percent` read `N%` tokens.
+    pub percent_columns: &'a [Vec<u8>],
+    /// `--transform` entries applied to numeric columns.
+    pub transforms: &'a [ColumnTransform],
+    /// Columns dropped by `--on-mixed-types exclude`.
+    pub columns_excluded_mixed: &'a [Vec<u8>],
     pub columns: ColumnCounts,
     pub checked: CheckedCounts,
     pub dialect_old: DialectReceipt,
     pub dialect_new: DialectReceipt,
+    pub ranking: &'a Ranking,
     pub settings: Settings,
 }
 
@@ -86,7 +190,12 @@ pub struct RefusalHeader<'a> {
     pub old_name: &'a str,
     pub new_name: &'a str,
     pub alignment: Alignment<'a>,
+    pub column_map: &'a [ColumnMapping],
+    pub suggested_maps: &'a [ColumnMapping],
     pub profile: Option<Profile<'a>>,
+    pub percent_columns: &'a [Vec<u8>],
+    pub transforms: &'a [ColumnTransform],
+    pub columns_excluded_mixed: &'a [Vec<u8>],
     pub dialect_old: Option<DialectReceipt>,
     pub dialect_new: Option<DialectReceipt>,
     pub settings: Settings,
@@ -97,6 +206,8 @@ pub fn render_real_no_real_header(ctx: &HumanHeader<'_>) -> Vec<String> {
         format!("Compared: {} -> {}", ctx.old_name, ctx.new_name),
         format!("Alignment: {}", ctx.alignment.render()),
     ];
+    lines.extend(render_column_map(ctx.column_map));
+    lines.extend(render_suggested_maps(ctx.suggested_maps));
     if let Some(profile) = ctx.profile {
         lines.push(format!("Profile: {}", profile.render()));
     }
@@ -113,9 +224,14 @@ pub fn render_real_no_real_header(ctx: &HumanHeader<'_>) -> Vec<String> {
             format_count(ctx.checked.numeric_columns),
             format_count(ctx.checked.cells)
         ),
+    ]);
+    lines.extend(render_percent_columns(ctx.percent_columns));
+    lines.extend(render_transforms(ctx.transforms));
+    lines.extend(render_excluded_mixed(ctx.columns_excluded_mixed));
+    lines.extend([
         format!("Dialect(old): {}", render_dialect(ctx.dialect_old)),
         format!("Dialect(new): {}", render_dialect(ctx.dialect_new)),
-        "Ranking: abs(delta) (unscaled)".to_string(),
+        format!("Ranking: {}", render_ranking(ctx.ranking)),
         format!(
             "Settings: threshold={} tolerance={}",
             format_percent_one_decimal(ctx.settings.threshold),
@@ -129,9 +245,14 @@ pub fn render_refusal_header(ctx: &RefusalHeader<'_>) -> Vec<String> {
     let mut lines = Vec::with_capacity(5);
     lines.push(format!("Compared: {} -> {}", ctx.old_name, ctx.new_name));
     lines.push(format!("Alignment: {}", ctx.alignment.render()));
+    lines.extend(render_column_map(ctx.column_map));
+    lines.extend(render_suggested_maps(ctx.suggested_maps));
     if let Some(profile) = ctx.profile {
         lines.push(format!("Profile: {}", profile.render()));
     }
+    lines.extend(render_percent_columns(ctx.percent_columns));
+    lines.extend(render_transforms(ctx.transforms));
+    lines.extend(render_excluded_mixed(ctx.columns_excluded_mixed));
     if let (Some(old), Some(new)) = (ctx.dialect_old, ctx.dialect_new) {
         lines.push(format!("Dialect(old): {}", render_dialect(old)));
         lines.push(format!("Dialect(new): {}", render_dialect(new)));
@@ -144,6 +265,117 @@ pub fn render_refusal_header(ctx: &RefusalHeader<'_>) -> Vec<String> {
     lines
 }
 
+fn render_ranking(ranking: &Ranking) -> String {
+    match ranking {
+        Ranking::AbsDelta => "abs(delta) (unscaled)".to_string(),
+        Ranking::Relative => "abs(delta) / max(abs(old), abs(new)) (relative)".to_string(),
+        Ranking::Weighted { column } => format!(
+            "abs(delta) x abs(weight) (weight={})",
+            render_identifier_human(column)
+        ),
+    }
+}
+
+fn render_column_map(column_map: &[ColumnMapping]) -> Option<String> {
+    if column_map.is_empty() {
+        return None;
+    }
+    let renames: Vec<String> = column_map
+        .iter()
+        .map(|mapping| {
+            format!(
+                "{} -> {}",
+                render_identifier_human(&mapping.old),
+                render_identifier_human(&mapping.new)
+            )
+        })
+        .collect();
+    Some(format!("Columns mapped: {}", renames.join(", ")))
+}
+
+fn render_suggested_maps(suggested_maps: &[ColumnMapping]) -> Option<String> {
+    if suggested_maps.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "Did you mean: {}",
+        map_flags(suggested_maps).join(" ")
+    ))
+}
+
+/// `--map OLD=NEW` flags for `mappings`, quoted for a shell.
+pub fn map_flags(mappings: &[ColumnMapping]) -> Vec<String> {
+    mappings
+        .iter()
+        .map(|mapping| {
+            let value = format!(
+                "{}={}",
+                render_identifier_human(&mapping.old),
+                render_identifier_human(&mapping.new)
+            );
+            format!("--map {}", shell_word(&value))
+        })
+        .collect()
+}
+
+fn render_percent_columns(columns: &[Vec<u8>]) -> Option<String> {
+    if columns.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = columns
+        .iter()
+        .map(|column| render_identifier_human(column))
+        .collect();
+    Some(format!(
+        "Percent parsed: {} (N% read as N/100)",
+        names.join(", ")
+    ))
+}
+
+fn render_transforms(transforms: &[ColumnTransform]) -> Option<String> {
+    if transforms.is_empty() {
+        return None;
+    }
+    let entries: Vec<String> = transforms
+        .iter()
+        .map(|entry| {
+            let side = match entry.side {
+                TransformSide::Both => "",
+                TransformSide::Old => " (old)",
+                TransformSide::New => " (new)",
+            };
+            format!(
+                "{} {}{side}",
+                render_identifier_human(&entry.column),
+                entry.op.as_arg()
+            )
+        })
+        .collect();
+    Some(format!("Transforms: {}", entries.join(", ")))
+}
+
+fn render_excluded_mixed(columns: &[Vec<u8>]) -> Option<String> {
+    if columns.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = columns
+        .iter()
+        .map(|column| render_identifier_human(column))
+        .collect();
+    Some(format!("Excluded (mixed types): {}", names.join(", ")))
+}
+
+/// Single-quote a flag value unless it is plain enough to paste as-is.
+pub(super) fn shell_word(raw: &str) -> String {
+    if raw
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | '/' | ':' | '='))
+    {
+        return raw.to_string();
+    }
+    format!("'{}'", raw.replace('\'', "'\"'\"'"))
+}
+
 fn format_count(value: u64) -> String {
     match i64::try_from(value) {
         Ok(v) => format_int_with_commas(v),
@@ -155,7 +387,21 @@ fn render_dialect(dialect: DialectReceipt) -> String {
     let delimiter = format_delimiter(dialect.delimiter);
     let quote = format_quote(dialect.quote);
     let escape = format_escape(dialect.escape);
-    format!("delimiter={delimiter} quote={quote} escape={escape}")
+    let mut rendered = format!("delimiter={delimiter} quote={quote} escape={escape}");
+    if let Some(compression) = dialect.compression {
+        rendered.push_str(&format!(" compression={}", compression.as_str()));
+    }
+    if let Some(encoding) = dialect.encoding {
+        rendered.push_str(&format!(" encoding={}", encoding.as_str()));
+    }
+    if dialect.decimal != DecimalSeparator::Dot {
+        rendered.push_str(&format!(" decimal={}", dialect.decimal.as_str()));
+    }
+    // Only a less-than-certain detection is worth a mention.
+    if let Some(confidence) = dialect.confidence.filter(|confidence| *confidence < 1.0) {
+        rendered.push_str(&format!(" confidence={confidence:.2}"));
+    }
+    rendered
 }
 
 fn format_delimiter(delimiter: u8) -> String {
@@ -206,7 +452,16 @@ mod tests {
         let ctx = HumanHeader {
             old_name: "old.csv",
             new_name: "new.csv",
-            alignment: Alignment::Key { column: "id" },
+            alignment: Alignment::Key {
+                column: "id",
+                key_normalize: &[],
+                dedup: None,
+            },
+            column_map: &[],
+            suggested_maps: &[],
+            percent_columns: &[],
+            transforms: &[],
+            columns_excluded_mixed: &[],
             profile: None,
             columns: ColumnCounts {
                 common: 15,
@@ -222,12 +477,21 @@ mod tests {
                 delimiter: b',',
                 quote: b'"',
                 escape: None,
+                compression: None,
+                encoding: None,
+                confidence: None,
+                decimal: DecimalSeparator::Dot,
             },
             dialect_new: DialectReceipt {
                 delimiter: b',',
                 quote: b'"',
                 escape: None,
+                compression: None,
+                encoding: None,
+                confidence: None,
+                decimal: DecimalSeparator::Dot,
             },
+            ranking: &Ranking::AbsDelta,
             settings: Settings {
                 threshold: 0.95,
                 tolerance: 1e-9,
@@ -254,6 +518,11 @@ mod tests {
             old_name: "old.csv",
             new_name: "new.csv",
             alignment: Alignment::RowOrder,
+            column_map: &[],
+            suggested_maps: &[],
+            percent_columns: &[],
+            transforms: &[],
+            columns_excluded_mixed: &[],
             profile: None,
             dialect_old: None,
             dialect_new: None,
@@ -270,12 +539,130 @@ mod tests {
         assert_eq!(lines[2], "Settings: threshold=95.0% tolerance=1e-9");
     }
 
+    #[test]
+    fn renders_column_map_after_alignment() {
+        let column_map = [ColumnMapping {
+            old: b"net_operating_income".to_vec(),
+            new: b"noi".to_vec(),
+        }];
+        let ctx = RefusalHeader {
+            old_name: "old.csv",
+            new_name: "new.csv",
+            alignment: Alignment::Key {
+                column: "id",
+                key_normalize: &[],
+                dedup: None,
+            },
+            column_map: &column_map,
+            suggested_maps: &[],
+            percent_columns: &[],
+            transforms: &[],
+            columns_excluded_mixed: &[],
+            profile: None,
+            dialect_old: None,
+            dialect_new: None,
+            settings: Settings {
+                threshold: 0.95,
+                tolerance: 1e-9,
+            },
+        };
+
+        let lines = render_refusal_header(&ctx);
+        assert_eq!(lines[2], "Columns mapped: net_operating_income -> noi");
+    }
+
+    #[test]
+    fn renders_suggested_maps_as_flags() {
+        let suggested = [
+            ColumnMapping {
+                old: b"Unit Count".to_vec(),
+                new: b"unit_count".to_vec(),
+            },
+            ColumnMapping {
+                old: b"net_operating_income".to_vec(),
+                new: b"noi".to_vec(),
+            },
+        ];
+        assert_eq!(
+            render_suggested_maps(&suggested).unwrap(),
+            "Did you mean: --map 'Unit Count=unit_count' --map net_operating_income=noi"
+        );
+        assert_eq!(render_suggested_maps(&[]), None);
+    }
+
+    #[test]
+    fn renders_percent_columns() {
+        let columns = [b"occupancy".to_vec(), b"cap rate".to_vec()];
+        assert_eq!(
+            render_percent_columns(&columns).unwrap(),
+            "Percent parsed: occupancy, cap rate (N% read as N/100)"
+        );
+        assert_eq!(render_percent_columns(&[]), None);
+    }
+
+    #[test]
+    fn renders_transforms() {
+        let transforms = [
+            ColumnTransform::parse("new:value=scale:1000").unwrap(),
+            ColumnTransform::parse("area=round:0").unwrap(),
+        ];
+        assert_eq!(
+            render_transforms(&transforms).unwrap(),
+            "Transforms: value scale:1000 (new), area round:0"
+        );
+        assert_eq!(render_transforms(&[]), None);
+    }
+
+    #[test]
+    fn renders_weighted_ranking() {
+        let ranking = Ranking::Weighted {
+            column: b"market_value".to_vec(),
+        };
+        assert_eq!(
+            render_ranking(&ranking),
+            "abs(delta) x abs(weight) (weight=market_value)"
+        );
+    }
+
+    #[test]
+    fn renders_key_normalize_and_dedup_in_alignment() {
+        let dedup = KeyDedup {
+            mode: "sum",
+            rows_collapsed_old: 1_204,
+            rows_collapsed_new: 0,
+        };
+        let alignment = Alignment::Key {
+            column: "account",
+            key_normalize: &["lower", "strip-leading-zeros"],
+            dedup: Some(&dedup),
+        };
+        assert_eq!(
+            alignment.render(),
+            "key=account (keys normalized: lower, strip-leading-zeros) (dedup=sum: collapsed 1,204 old, 0 new rows)"
+        );
+    }
+
+    #[test]
+    fn renders_excluded_mixed_columns() {
+        let columns = [b"units".to_vec(), b"notes".to_vec()];
+        assert_eq!(
+            render_excluded_mixed(&columns).unwrap(),
+            "Excluded (mixed types): units, notes"
+        );
+        assert_eq!(render_excluded_mixed(&[]), None);
+    }
+
     #[test]
     fn renders_profile_line_for_draft() {
         let ctx = HumanHeader {
             old_name: "old.csv",
             new_name: "new.csv",
             alignment: Alignment::RowOrder,
+            column_map: &[],
+            suggested_maps: &[],
+            percent_columns: &[],
+            transforms: &[],
+            columns_excluded_mixed: &[],
             profile: Some(Profile::Draft),
             columns: ColumnCounts {
                 common: 1,
@@ -291,12 +678,21 @@ mod tests {
                 delimiter: b',',
                 quote: b'"',
                 escape: None,
+                compression: None,
+                encoding: None,
+                confidence: None,
+                decimal: DecimalSeparator::Dot,
             },
             dialect_new: DialectReceipt {
                 delimiter: b',',
                 quote: b'"',
                 escape: None,
+                compression: None,
+                encoding: None,
+                confidence: None,
+                decimal: DecimalSeparator::Dot,
             },
+            ranking: &Ranking::AbsDelta,
             settings: Settings {
                 threshold: 0.95,
                 tolerance: 1e-9,
@@ -0,0 +1,83 @@
+// Human key-change section formatting (--allow-missing-keys, --allow-trailing-rows)
+
+use crate::alignment::key_join::KeyChangeReport;
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::{format_float_shortest, format_int_with_commas};
+
+/// Lines for `--allow-missing-keys` and `--allow-trailing-rows`:
+/// added/removed row counts, then per-column totals when values are shown
+/// (`--explicit`).
+pub fn render_key_change_lines(report: &KeyChangeReport) -> Vec<String> {
+    let (label, scope) = if report.trailing {
+        ("Trailing rows", "only the common prefix compared")
+    } else if report.ranked {
+        ("Key changes", "ranked as contributors")
+    } else {
+        ("Key changes", "only common keys compared")
+    };
+    let mut lines = vec![format!(
+        "{label}: {} added, {} removed ({scope})",
+        rows_label(report.rows_added),
+        rows_label(report.rows_removed)
+    )];
+    for total in report.totals.iter().flatten() {
+        lines.push(format!(
+            "  {}  +{} added  -{} removed",
+            render_identifier_human(&total.column),
+            format_float_shortest(total.added),
+            format_float_shortest(total.removed)
+        ));
+    }
+    lines
+}
+
+fn rows_label(count: u64) -> String {
+    let noun = if count == 1 { "row" } else { "rows" };
+    format!("{} {noun}", format_int_with_commas(count as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::key_join::KeyChangeTotal;
+
+    #[test]
+    fn renders_counts_and_explicit_totals() {
+        let mut report = KeyChangeReport {
+            rows_added: 1,
+            rows_removed: 1200,
+            totals: None,
+            ranked: false,
+            trailing: false,
+        };
+        assert_eq!(
+            render_key_change_lines(&report),
+            vec!["Key changes: 1 row added, 1,200 rows removed (only common keys compared)"]
+        );
+
+        report.totals = Some(vec![KeyChangeTotal {
+            column: b"amount".to_vec(),
+            added: 250.5,
+            removed: 1200.25,
+        }]);
+        assert_eq!(
+            render_key_change_lines(&report)[1],
+            "  amount  +250.5 added  -1200.25 removed"
+        );
+    }
+
+    #[test]
+    fn renders_trailing_rows() {
+        let report = KeyChangeReport {
+            rows_added: 3,
+            rows_removed: 0,
+            totals: None,
+            ranked: false,
+            trailing: true,
+        };
+        assert_eq!(
+            render_key_change_lines(&report),
+            vec!["Trailing rows: 3 rows added, 0 rows removed (only the common prefix compared)"]
+        );
+    }
+}
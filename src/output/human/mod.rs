@@ -1,4 +1,16 @@
+pub mod column_types;
+pub mod date_changes;
+pub mod drift;
+pub mod expected;
 pub mod header;
+pub mod key_changes;
 pub mod no_real;
 pub mod real_change;
 pub mod refusal;
+pub mod rescale;
+pub mod rollup;
+pub mod schema;
+pub mod text_changes;
+pub mod timings;
+pub mod tolerance_sweep;
+pub mod warnings;
@@ -1,6 +1,7 @@
 // Human NO REAL CHANGE body formatting (bd-7eg)
 
-use crate::format::numbers::format_float_shortest;
+use crate::format::numbers::{format_delta, format_float_shortest, format_int_with_commas};
+use crate::numeric::missing::MissingPolicy;
 
 #[derive(Debug, Clone, Copy)]
 pub struct NoRealBody {
@@ -19,6 +20,122 @@ pub fn render_no_real_body(ctx: &NoRealBody) -> Vec<String> {
     ]
 }
 
+/// Cells with an identical value written differently (`1000` -> `1,000.00`).
+pub fn render_format_only_line(count: u64) -> String {
+    format!(
+        "Format-only changes: {} numeric cell{} (same value, different text).",
+        format_int_with_commas(count as i64),
+        if count == 1 { "" } else { "s" }
+    )
+}
+
+/// Cells missing on one side and numeric on the other, under `--on-missing`.
+pub fn render_missing_cells_line(count: u64, policy: MissingPolicy) -> String {
+    let handling = match policy {
+        MissingPolicy::Zero => "read as 0",
+        _ => "skipped",
+    };
+    format!(
+        "Missing cells: {} numeric cell{} {handling} (missing on one side; --on-missing {}).",
+        format_int_with_commas(count as i64),
+        if count == 1 { "" } else { "s" },
+        policy.as_str()
+    )
+}
+
+/// Numeric cells left out of the checked count: missing on both sides,
+/// missing in an added/removed row, or not a number on one side. The
+/// added/removed clause appears only when nonzero.
+pub fn render_uncompared_cells_line(
+    missing_both: u64,
+    missing_row: u64,
+    unparsable: u64,
+) -> String {
+    let missing_row = if missing_row > 0 {
+        format!(
+            ", {} missing in added/removed rows",
+            format_int_with_commas(missing_row as i64)
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "Uncompared cells: {} missing on both sides{missing_row}, {} not numeric (not in the checked count).",
+        format_int_with_commas(missing_both as i64),
+        format_int_with_commas(unparsable as i64)
+    )
+}
+
+/// Trailing rows excluded by `--skip-footer`.
+pub fn render_footer_rows_line(old: u64, new: u64) -> String {
+    format!(
+        "Footer rows: {} old, {} new excluded (--skip-footer).",
+        format_int_with_commas(old as i64),
+        format_int_with_commas(new as i64)
+    )
+}
+
+/// Comment lines skipped by `--comment`.
+pub fn render_comment_lines_line(old: u64, new: u64) -> String {
+    format!(
+        "Comment lines: {} old, {} new skipped (--comment).",
+        format_int_with_commas(old as i64),
+        format_int_with_commas(new as i64)
+    )
+}
+
+/// Rows excluded by `--where`.
+pub fn render_filtered_rows_line(old: u64, new: u64) -> String {
+    format!(
+        "Filtered rows: {} old, {} new excluded (--where).",
+        format_int_with_commas(old as i64),
+        format_int_with_commas(new as i64)
+    )
+}
+
+/// Aligned rows kept by `--sample`; the verdict covers only these.
+pub fn render_sampled_rows_line(sampled: u64, eligible: u64, seed: u64) -> String {
+    format!(
+        "Sampled rows: {} of {} aligned rows by key hash (--sample, seed {seed}); unsampled rows were not checked.",
+        format_int_with_commas(sampled as i64),
+        format_int_with_commas(eligible as i64)
+    )
+}
+
+/// Byte-identical inputs, parsed once for both sides.
+pub fn render_identical_files_line(digest: &str) -> String {
+    format!("Inputs are byte-identical (blake3:{digest}); parsed once for both sides.")
+}
+
+#[derive(Debug, Clone)]
+pub struct SubtoleranceDelta {
+    pub label: String,
+    pub old: f64,
+    pub new: f64,
+    pub delta: f64,
+}
+
+/// Lines for `--show-subtolerance`: the largest nonzero deltas that stayed within tolerance.
+pub fn render_subtolerance_lines(deltas: &[SubtoleranceDelta], explicit: bool) -> Vec<String> {
+    let mut lines = Vec::with_capacity(deltas.len() + 1);
+    lines.push("Largest sub-tolerance deltas:".to_string());
+    for (idx, item) in deltas.iter().enumerate() {
+        if explicit {
+            lines.push(format!(
+                "{}. {}  {}  ({} -> {})",
+                idx + 1,
+                item.label,
+                format_delta(item.delta),
+                format_float_shortest(item.old),
+                format_float_shortest(item.new)
+            ));
+        } else {
+            lines.push(format!("{}. {}  within tolerance", idx + 1, item.label));
+        }
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +153,21 @@ mod tests {
             "No numeric deltas above tolerance in common numeric columns."
         );
     }
+
+    #[test]
+    fn renders_subtolerance_lines_redacted_and_explicit() {
+        let deltas = vec![SubtoleranceDelta {
+            label: "A.value".to_string(),
+            old: 1.0,
+            new: 1.0005,
+            delta: 0.0005,
+        }];
+        let redacted = render_subtolerance_lines(&deltas, false);
+        assert_eq!(redacted[0], "Largest sub-tolerance deltas:");
+        assert_eq!(redacted[1], "1. A.value  within tolerance");
+
+        let explicit = render_subtolerance_lines(&deltas, true);
+        assert!(explicit[1].starts_with("1. A.value  +"));
+        assert!(explicit[1].ends_with("(1 -> 1.0005)"));
+    }
 }
@@ -1,7 +1,8 @@
 // Human REFUSAL output formatting (bd-bk0)
 
 use crate::format::ident_human::render_identifier_human;
-use crate::format::numbers::{format_int_with_commas, format_percent_one_decimal};
+use crate::format::numbers::{format_bytes, format_int_with_commas, format_percent_one_decimal};
+use crate::numeric::columns::Side;
 use crate::refusal::codes::RefusalCode;
 use crate::refusal::details::{EncodingIssue, FileSide, HeadersIssue, RefusalDetail, RefusalKind};
 
@@ -16,7 +17,11 @@ pub struct RefusalBody<'a> {
 pub fn render_refusal_body(ctx: &RefusalBody<'_>) -> Vec<String> {
     let mut lines = Vec::with_capacity(4);
     lines.push("Cannot produce a verdict.".to_string());
-    lines.push(format!("Reason ({}): {}.", ctx.code, ctx.code.reason()));
+    lines.push(format!(
+        "Reason ({}): {}.",
+        ctx.code,
+        ctx.detail.reason(ctx.code)
+    ));
     lines.push(render_example_line(ctx.detail, ctx.old_name, ctx.new_name));
     lines.push(format!("Next: {}", ctx.detail.next));
     lines
@@ -62,6 +67,10 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
                         format_count_u64(*record)
                     )
                 }
+                HeadersIssue::MissingColumn { name } => {
+                    let name = render_identifier_human(name);
+                    format!("Example: {file} has no column \"{name}\".")
+                }
             }
         }
         RefusalKind::NoKey { key_column } => {
@@ -97,6 +106,7 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
             extra_in_new,
             missing_samples,
             extra_samples,
+            normalize_hint,
         } => {
             let missing = format_count_u64(*missing_in_new as u64);
             let extra = format_count_u64(*extra_in_new as u64);
@@ -109,6 +119,15 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
             if !extra_samples.is_empty() {
                 line.push_str(&format!(" extra samples: [{extra_samples}]."));
             }
+            if let Some(hint) = normalize_hint {
+                line.push_str(&format!(
+                    " {} of {} missing keys match extras {}; consider --key-normalize {}.",
+                    format_count_u64(hint.matched as u64),
+                    format_count_u64(hint.missing as u64),
+                    hint.step.describe(),
+                    hint.step.as_str()
+                ));
+            }
             line
         }
         RefusalKind::RowCount {
@@ -144,6 +163,15 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
             let list = render_delimiters(tied_delimiters);
             format!("Example: {file} delimiter ambiguous among [{list}].")
         }
+        RefusalKind::DialectMismatch {
+            old_delimiter,
+            new_delimiter,
+            ..
+        } => format!(
+            "Example: {old_name} delimiter {} but {new_name} delimiter {}.",
+            render_delimiters(&[*old_delimiter]),
+            render_delimiters(&[*new_delimiter])
+        ),
         RefusalKind::AmbiguousProfile {
             profile_path,
             profile_id,
@@ -184,10 +212,12 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
             column,
             value,
             key_value,
+            profile,
+            cross_tab,
         } => {
             let column = render_identifier_human(column);
             let value = render_identifier_human(value);
-            if let Some(key) = key_value {
+            let mut line = if let Some(key) = key_value {
                 let key = render_identifier_human(key);
                 format!(
                     "Example: key \"{key}\" column \"{column}\" has non-numeric value \"{value}\"."
@@ -204,7 +234,48 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
                         "Example: {file} column \"{column}\" has non-numeric value \"{value}\"."
                     )
                 }
+            };
+            let histogram = &profile.histogram;
+            let types: Vec<String> = [
+                ("numeric", histogram.numeric),
+                ("currency", histogram.currency),
+                ("percent", histogram.percent),
+                ("text", histogram.text),
+                ("dates", histogram.dates),
+            ]
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(name, count)| format!("{name} {}", format_count_u64(count)))
+            .collect();
+            if !types.is_empty() {
+                line.push_str(&format!(" Column types: {}.", types.join(", ")));
+            }
+            if !profile.samples.is_empty() {
+                let samples: Vec<String> = profile
+                    .samples
+                    .iter()
+                    .map(|sample| {
+                        let file = match sample.side {
+                            Side::Old => old_name,
+                            Side::New => new_name,
+                        };
+                        format!(
+                            "{file} record {} \"{}\"",
+                            format_count_u64(sample.record),
+                            render_identifier_human(&sample.value)
+                        )
+                    })
+                    .collect();
+                line.push_str(&format!(" Non-numeric samples: {}.", samples.join(", ")));
             }
+            if let Some(signal) = cross_tab {
+                line.push_str(&format!(
+                    " {} of {} common columns are mixed and row labels read like field names.",
+                    format_count_u64(signal.mixed_columns),
+                    format_count_u64(signal.columns)
+                ));
+            }
+            line
         }
         RefusalKind::NoNumeric => "Example: no numeric columns in common.".to_string(),
         RefusalKind::Missingness {
@@ -238,11 +309,20 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
         RefusalKind::Diffuse {
             top_k_coverage,
             threshold,
-        } => format!(
-            "Example: top_k_coverage={} threshold={}.",
-            format_percent_one_decimal(*top_k_coverage),
-            format_percent_one_decimal(*threshold)
-        ),
+            column,
+        } => match column {
+            Some(column) => format!(
+                "Example: column \"{}\" top_k_coverage={} threshold={}.",
+                render_identifier_human(column),
+                format_percent_one_decimal(*top_k_coverage),
+                format_percent_one_decimal(*threshold)
+            ),
+            None => format!(
+                "Example: top_k_coverage={} threshold={}.",
+                format_percent_one_decimal(*top_k_coverage),
+                format_percent_one_decimal(*threshold)
+            ),
+        },
         RefusalKind::AuditLimit {
             changed_cells,
             max_audit_changes,
@@ -257,6 +337,41 @@ fn render_example_line(detail: &RefusalDetail, old_name: &str, new_name: &str) -
         RefusalKind::AuditFieldsRequiresProfile => {
             "Example: --audit-fields requires profile-scoped columns.".to_string()
         }
+        RefusalKind::HeaderOverlap {
+            columns_old,
+            columns_new,
+            columns_common,
+            overlap,
+            min_overlap,
+            ..
+        } => format!(
+            "Example: only {} of {} old / {} new columns share a header name ({} overlap, minimum {}).",
+            format_count_u64(*columns_common),
+            format_count_u64(*columns_old),
+            format_count_u64(*columns_new),
+            format_percent_one_decimal(*overlap),
+            format_percent_one_decimal(*min_overlap)
+        ),
+        RefusalKind::Resource {
+            estimated_bytes,
+            max_memory,
+            row_order_bytes,
+        } => {
+            let row_order = row_order_bytes
+                .map(|bytes| format!(" (row-order mode: about {})", format_bytes(bytes)))
+                .unwrap_or_default();
+            format!(
+                "Example: estimated peak memory {} exceeds --max-memory {}{row_order}.",
+                format_bytes(*estimated_bytes),
+                format_bytes(*max_memory)
+            )
+        }
+        RefusalKind::KeyMap { file, reason } => {
+            format!("Example: --key-map \"{file}\" failed: {reason}.")
+        }
+        RefusalKind::Expected { file, reason } => {
+            format!("Example: --expected \"{file}\" failed: {reason}.")
+        }
     }
 }
 
@@ -272,6 +387,7 @@ fn encoding_issue_label(issue: EncodingIssue) -> &'static str {
         EncodingIssue::Utf16 => "a UTF-16 BOM",
         EncodingIssue::Utf32 => "a UTF-32 BOM",
         EncodingIssue::NulByte => "a NUL byte in the first 8KB",
+        EncodingIssue::InvalidUtf16 => "invalid UTF-16 data",
     }
 }
 
@@ -345,6 +461,7 @@ mod tests {
             RefusalKind::Diffuse {
                 top_k_coverage: 0.8,
                 threshold: 0.95,
+                column: None,
             },
             "rvl old.csv new.csv --threshold 0.80".to_string(),
         );
@@ -0,0 +1,53 @@
+// Human unit-rescale note formatting
+
+use super::header::shell_word;
+use crate::diff::rescale::RescaledColumn;
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_int_with_commas;
+
+/// One note per column whose values look rescaled by a power of ten, with
+/// the `--transform` that compares it in the new file's units.
+pub fn render_rescaled_lines(columns: &[RescaledColumn]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|column| {
+            let name = render_identifier_human(&column.column);
+            let transform = format!("old:{name}=scale:{}", column.factor);
+            format!(
+                "Note: column {name} appears rescaled by ~{}x ({} cell{}); --transform {} compares it in the new units.",
+                column.factor,
+                format_int_with_commas(column.cells as i64),
+                if column.cells == 1 { "" } else { "s" },
+                shell_word(&transform)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_a_note_per_rescaled_column() {
+        let columns = [
+            RescaledColumn {
+                column: b"value".to_vec(),
+                factor: 1000.0,
+                cells: 1200,
+            },
+            RescaledColumn {
+                column: b"unit rent".to_vec(),
+                factor: 0.01,
+                cells: 3,
+            },
+        ];
+        assert_eq!(
+            render_rescaled_lines(&columns),
+            vec![
+                "Note: column value appears rescaled by ~1000x (1,200 cells); --transform old:value=scale:1000 compares it in the new units.",
+                "Note: column unit rent appears rescaled by ~0.01x (3 cells); --transform 'old:unit rent=scale:0.01' compares it in the new units.",
+            ]
+        );
+    }
+}
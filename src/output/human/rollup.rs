@@ -0,0 +1,194 @@
+// Human per-column and per-row change rollup formatting
+
+use crate::diff::order::RowId;
+use crate::diff::rollup::{ColumnRollup, RowRollup};
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::{
+    format_float_shortest, format_int_with_commas, format_percent_one_decimal,
+};
+
+/// Lines for the per-column rollup, largest share first. `--explicit` adds
+/// each column's summed contribution.
+pub fn render_by_column_lines(rollup: &ColumnRollup, explicit: bool) -> Vec<String> {
+    let mut lines = Vec::with_capacity(rollup.columns.len() + 1);
+    lines.push("Change by column:".to_string());
+    for column in &rollup.columns {
+        lines.push(render_group_line(
+            &render_identifier_human(&column.column),
+            column.share,
+            column.cells_changed,
+            column.contribution,
+            explicit,
+        ));
+    }
+    lines
+}
+
+/// Lines for the per-row rollup (top rows by summed contribution).
+pub fn render_by_row_lines(rollup: &RowRollup, explicit: bool) -> Vec<String> {
+    let mut lines = Vec::with_capacity(rollup.rows.len() + 1);
+    lines.push("Change by row:".to_string());
+    for row in &rollup.rows {
+        let label = match &row.row_id {
+            RowId::RowIndex(index) => index.to_string(),
+            RowId::Key(bytes) => render_identifier_human(bytes),
+        };
+        lines.push(render_group_line(
+            &label,
+            row.share,
+            row.cells_changed,
+            row.contribution,
+            explicit,
+        ));
+    }
+    lines
+}
+
+fn render_group_line(
+    label: &str,
+    share: f64,
+    cells_changed: u64,
+    contribution: f64,
+    explicit: bool,
+) -> String {
+    let cells_word = if cells_changed == 1 { "cell" } else { "cells" };
+    let mut detail = format!(
+        "{} {cells_word}",
+        format_int_with_commas(cells_changed as i64)
+    );
+    if explicit {
+        detail.push_str(&format!(
+            ", sum |delta| {}",
+            format_float_shortest(contribution)
+        ));
+    }
+    format!(
+        "  {label}  {} of total change  ({detail})",
+        format_percent_one_decimal(share)
+    )
+}
+
+/// Lead line when `--on-diffuse expand` reports instead of `E_DIFFUSE`;
+/// `column` names the least-explained column under `--coverage per-column`.
+pub fn render_diffuse_line(top_k_coverage: f64, threshold: f64, column: Option<&[u8]>) -> String {
+    match column {
+        Some(column) => format!(
+            "Diffuse change: the top contributors in {} explain {} of its change, below the threshold ({}).",
+            render_identifier_human(column),
+            format_percent_one_decimal(top_k_coverage),
+            format_percent_one_decimal(threshold)
+        ),
+        None => format!(
+            "Diffuse change: the top contributors explain {} of total change, below the threshold ({}).",
+            format_percent_one_decimal(top_k_coverage),
+            format_percent_one_decimal(threshold)
+        ),
+    }
+}
+
+/// Summary of `metrics.coverage_curve` at 1, 2, 5, 10, 20, 50, ...
+/// contributors and the last one; `None` for fewer than two contributors.
+pub fn render_coverage_curve_line(curve: &[f64]) -> Option<String> {
+    if curve.len() < 2 {
+        return None;
+    }
+    let mut points = Vec::new();
+    let mut step = 1;
+    while step < curve.len() {
+        for count in [step, step * 2, step * 5] {
+            if count < curve.len() {
+                points.push(count);
+            }
+        }
+        step *= 10;
+    }
+    points.push(curve.len());
+    let points: Vec<String> = points
+        .iter()
+        .map(|&count| {
+            format!(
+                "top {count} {}",
+                format_percent_one_decimal(curve[count - 1])
+            )
+        })
+        .collect();
+    Some(format!("Coverage curve: {}.", points.join(", ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::rollup::{ColumnContribution, RowContribution};
+
+    #[test]
+    fn renders_one_line_per_column() {
+        let rollup = ColumnRollup {
+            columns: vec![
+                ColumnContribution {
+                    column: b"amount".to_vec(),
+                    contribution: 1250.5,
+                    cells_changed: 1200,
+                    share: 0.625,
+                },
+                ColumnContribution {
+                    column: b"fee".to_vec(),
+                    contribution: 3.0,
+                    cells_changed: 1,
+                    share: 0.002,
+                },
+            ],
+        };
+        assert_eq!(
+            render_by_column_lines(&rollup, false),
+            vec![
+                "Change by column:",
+                "  amount  62.5% of total change  (1,200 cells)",
+                "  fee  0.2% of total change  (1 cell)",
+            ]
+        );
+        assert_eq!(
+            render_by_column_lines(&rollup, true)[1],
+            "  amount  62.5% of total change  (1,200 cells, sum |delta| 1250.5)"
+        );
+    }
+
+    #[test]
+    fn renders_rows_by_key_or_record_number() {
+        let rollup = RowRollup {
+            rows: vec![
+                RowContribution {
+                    row_id: RowId::key(b"unit-12".as_slice().into()),
+                    contribution: 900.0,
+                    cells_changed: 3,
+                    share: 0.9,
+                },
+                RowContribution {
+                    row_id: RowId::row_index(7),
+                    contribution: 100.0,
+                    cells_changed: 1,
+                    share: 0.1,
+                },
+            ],
+        };
+        assert_eq!(
+            render_by_row_lines(&rollup, false),
+            vec![
+                "Change by row:",
+                "  unit-12  90.0% of total change  (3 cells)",
+                "  7  10.0% of total change  (1 cell)",
+            ]
+        );
+    }
+
+    #[test]
+    fn coverage_curve_line_samples_the_curve() {
+        let curve: Vec<f64> = (1..=12).map(|count| f64::from(count) / 12.0).collect();
+        assert_eq!(
+            render_coverage_curve_line(&curve).as_deref(),
+            Some(
+                "Coverage curve: top 1 8.3%, top 2 16.7%, top 5 41.7%, top 10 83.3%, top 12 100.0%."
+            )
+        );
+        assert_eq!(render_coverage_curve_line(&[1.0]), None);
+    }
+}
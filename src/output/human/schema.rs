@@ -0,0 +1,89 @@
+// Human schema-change section formatting
+
+use crate::format::ident_human::render_identifier_human;
+use crate::numeric::schema::SchemaChange;
+
+/// Hex digits of each fingerprint shown in human output.
+const FINGERPRINT_PREFIX_LEN: usize = 12;
+
+/// Pre-verdict lines describing how the two schema fingerprints differ.
+pub fn render_schema_change_lines(change: &SchemaChange) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Schema changed: {} -> {} ({} -> {} columns)",
+        short_fingerprint(&change.old_digest),
+        short_fingerprint(&change.new_digest),
+        change.columns_old,
+        change.columns_new
+    )];
+    if !change.added.is_empty() {
+        lines.push(format!("  Added: {}", join_names(&change.added)));
+    }
+    if !change.removed.is_empty() {
+        lines.push(format!("  Removed: {}", join_names(&change.removed)));
+    }
+    for column in &change.retyped {
+        lines.push(format!(
+            "  Retyped: {} ({} -> {})",
+            render_identifier_human(&column.name),
+            column.old.as_str(),
+            column.new.as_str()
+        ));
+    }
+    if change.reordered {
+        lines.push("  Reordered: common columns appear in a different order".to_string());
+    }
+    lines
+}
+
+fn join_names(names: &[Vec<u8>]) -> String {
+    names
+        .iter()
+        .map(|name| render_identifier_human(name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn short_fingerprint(digest: &str) -> String {
+    let (prefix, hex) = digest.split_once(':').unwrap_or(("", digest));
+    let hex = &hex[..hex.len().min(FINGERPRINT_PREFIX_LEN)];
+    if prefix.is_empty() {
+        hex.to_string()
+    } else {
+        format!("{prefix}:{hex}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::schema::{ColumnKind, RetypedColumn};
+
+    #[test]
+    fn renders_each_kind_of_schema_change() {
+        let change = SchemaChange {
+            old_digest: format!("blake3:{}", "a".repeat(64)),
+            new_digest: format!("blake3:{}", "b".repeat(64)),
+            columns_old: 3,
+            columns_new: 3,
+            added: vec![b"fresh".to_vec()],
+            removed: vec![b"gone".to_vec(), b"old note".to_vec()],
+            retyped: vec![RetypedColumn {
+                name: b"amount".to_vec(),
+                old: ColumnKind::Numeric,
+                new: ColumnKind::Text,
+            }],
+            reordered: true,
+        };
+        let lines = render_schema_change_lines(&change);
+        assert_eq!(
+            lines,
+            vec![
+                "Schema changed: blake3:aaaaaaaaaaaa -> blake3:bbbbbbbbbbbb (3 -> 3 columns)",
+                "  Added: fresh",
+                "  Removed: gone, old note",
+                "  Retyped: amount (numeric -> text)",
+                "  Reordered: common columns appear in a different order",
+            ]
+        );
+    }
+}
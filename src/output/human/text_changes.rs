@@ -0,0 +1,89 @@
+// Human text-change section formatting (--include-text)
+
+use crate::diff::text::{TextChangeReport, TextColumnChanges};
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_int_with_commas;
+
+/// Lines for `--include-text`: a total, then one line per changed column
+/// with its sample rows. Sample text appears only when values may be shown
+/// (`--explicit`).
+pub fn render_text_change_lines(report: &TextChangeReport) -> Vec<String> {
+    let changed: Vec<&TextColumnChanges> = report
+        .columns
+        .iter()
+        .filter(|column| column.changed > 0)
+        .collect();
+    if changed.is_empty() {
+        return vec![format!(
+            "Text changes: none in {}",
+            count_label(report.columns.len() as u64, "text column", "text columns")
+        )];
+    }
+
+    let mut lines = vec![format!(
+        "Text changes: {} in {}",
+        count_label(report.cells_changed(), "cell", "cells"),
+        count_label(changed.len() as u64, "column", "columns")
+    )];
+    for column in changed {
+        let mut samples: Vec<String> = column
+            .samples
+            .iter()
+            .map(|sample| {
+                let row = render_identifier_human(&sample.row_id);
+                match &sample.values {
+                    Some((old, new)) => format!(
+                        "{row}: {} -> {}",
+                        render_identifier_human(old),
+                        render_identifier_human(new)
+                    ),
+                    None => row,
+                }
+            })
+            .collect();
+        if column.changed > column.samples.len() as u64 {
+            samples.push("...".to_string());
+        }
+        lines.push(format!(
+            "  {}  {}  ({})",
+            render_identifier_human(&column.column),
+            count_label(column.changed, "cell", "cells"),
+            samples.join(", ")
+        ));
+    }
+    lines
+}
+
+fn count_label(count: u64, singular: &str, plural: &str) -> String {
+    let noun = if count == 1 { singular } else { plural };
+    format!("{} {noun}", format_int_with_commas(count as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_changed_columns_with_samples() {
+        let mut status = TextColumnChanges::new(b"status".to_vec());
+        status.observe(b"A", b"Active", b"Sold", true);
+        let report = TextChangeReport {
+            columns: vec![status, TextColumnChanges::new(b"notes".to_vec())],
+        };
+        assert_eq!(
+            render_text_change_lines(&report),
+            vec![
+                "Text changes: 1 cell in 1 column",
+                "  status  1 cell  (A: Active -> Sold)",
+            ]
+        );
+
+        let unchanged = TextChangeReport {
+            columns: vec![TextColumnChanges::new(b"notes".to_vec())],
+        };
+        assert_eq!(
+            render_text_change_lines(&unchanged),
+            vec!["Text changes: none in 1 text column"]
+        );
+    }
+}
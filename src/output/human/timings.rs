@@ -0,0 +1,56 @@
+// Human `--timings` footer formatting
+
+use crate::output::json::PhaseTimings;
+
+/// One line with each phase's wall-clock time, in pipeline order.
+pub fn render_timings_line(timings: &PhaseTimings) -> String {
+    let phases = [
+        ("setup", timings.setup_ms),
+        ("read", timings.read_ms),
+        ("parse", timings.parse_ms),
+        ("align", timings.align_ms),
+        ("type", timings.type_ms),
+        ("diff", timings.diff_ms),
+        ("render", timings.render_ms),
+    ];
+    let phases: Vec<String> = phases
+        .iter()
+        .map(|(name, ms)| format!("{name} {}", format_ms(*ms)))
+        .collect();
+    format!(
+        "Timings: {}; total {} (--timings).",
+        phases.join(", "),
+        format_ms(timings.total_ms)
+    )
+}
+
+fn format_ms(ms: f64) -> String {
+    if ms >= 100.0 {
+        format!("{ms:.0} ms")
+    } else {
+        format!("{ms:.1} ms")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_render_in_pipeline_order() {
+        let timings = PhaseTimings {
+            setup_ms: 0.04,
+            read_ms: 1.24,
+            parse_ms: 310.4,
+            align_ms: 12.0,
+            type_ms: 0.5,
+            diff_ms: 2.0,
+            render_ms: 0.3,
+            total_ms: 326.49,
+        };
+        assert_eq!(
+            render_timings_line(&timings),
+            "Timings: setup 0.0 ms, read 1.2 ms, parse 310 ms, align 12.0 ms, type 0.5 ms, diff 2.0 ms, render 0.3 ms; total 326 ms (--timings)."
+        );
+    }
+}
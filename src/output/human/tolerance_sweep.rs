@@ -0,0 +1,44 @@
+// Human tolerance sweep section formatting
+
+use crate::diff::tolerance::ToleranceSweep;
+use crate::format::numbers::{format_float_shortest, format_int_with_commas};
+use crate::output::json::sweep_verdict;
+
+/// Lines for `--tolerance-sweep`: cells above each level and the verdict it
+/// would give, smallest tolerance first.
+pub fn render_tolerance_sweep_lines(sweep: &ToleranceSweep) -> Vec<String> {
+    let mut lines = Vec::with_capacity(sweep.levels.len() + 1);
+    lines.push("Tolerance sweep:".to_string());
+    for (&level, &cells_above) in sweep.levels.iter().zip(&sweep.cells_above) {
+        lines.push(format!(
+            "  {}  {} cell{} above  {}",
+            format_float_shortest(level),
+            format_int_with_commas(cells_above as i64),
+            if cells_above == 1 { "" } else { "s" },
+            sweep_verdict(cells_above).as_str()
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_one_line_per_level() {
+        let sweep = ToleranceSweep {
+            levels: vec![1e-9, 0.01, 1.0],
+            cells_above: vec![1200, 1, 0],
+        };
+        assert_eq!(
+            render_tolerance_sweep_lines(&sweep),
+            vec![
+                "Tolerance sweep:",
+                "  1e-9  1,200 cells above  REAL_CHANGE",
+                "  0.01  1 cell above  REAL_CHANGE",
+                "  1  0 cells above  NO_REAL_CHANGE",
+            ]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+// Human input-warning section formatting
+
+use crate::format::numbers::format_int_with_commas;
+use crate::output::json::Warning;
+
+/// One line per warning, naming the file it came from.
+pub fn render_warning_lines(warnings: &[Warning], old_name: &str, new_name: &str) -> Vec<String> {
+    warnings
+        .iter()
+        .map(|warning| match warning {
+            Warning::DuplicateRows {
+                file,
+                rows,
+                samples,
+                deduped,
+            } => {
+                let file = if *file == "old" { old_name } else { new_name };
+                let mut samples = samples
+                    .iter()
+                    .map(|sample| {
+                        format!(
+                            "record {} repeats record {}",
+                            sample.record, sample.duplicate_of
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if *rows > samples.len() as u64 {
+                    samples.push("...".to_string());
+                }
+                let action = if *deduped {
+                    "removed by --dedupe-rows"
+                } else {
+                    "rerun with --dedupe-rows to drop them"
+                };
+                format!(
+                    "Warning: {file} has {} duplicated data row{} ({}); {action}.",
+                    format_int_with_commas(*rows as i64),
+                    if *rows == 1 { "" } else { "s" },
+                    samples.join(", ")
+                )
+            }
+            Warning::DialectMismatch {
+                old_delimiter,
+                new_delimiter,
+            } => format!(
+                "Warning: {old_name} was read with delimiter {} but {new_name} with {}; check that the columns line up. If one file was misread, re-export it to match the other; --strict-dialect refuses instead of warning.",
+                render_delimiter(old_delimiter),
+                render_delimiter(new_delimiter)
+            ),
+        })
+        .collect()
+}
+
+fn render_delimiter(delimiter: &str) -> String {
+    match delimiter {
+        "\t" => "TAB".to_string(),
+        _ => format!("'{delimiter}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::json::DuplicateSample;
+
+    #[test]
+    fn renders_duplicate_rows_with_samples() {
+        let warnings = [Warning::DuplicateRows {
+            file: "new",
+            rows: 3,
+            samples: vec![
+                DuplicateSample {
+                    record: 4,
+                    duplicate_of: 2,
+                },
+                DuplicateSample {
+                    record: 5,
+                    duplicate_of: 2,
+                },
+            ],
+            deduped: false,
+        }];
+        assert_eq!(
+            render_warning_lines(&warnings, "a.csv", "b.csv"),
+            vec![
+                "Warning: b.csv has 3 duplicated data rows (record 4 repeats record 2, record 5 repeats record 2, ...); rerun with --dedupe-rows to drop them."
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_dialect_mismatch() {
+        let warnings = [Warning::dialect_mismatch(b',', b';')];
+        assert_eq!(
+            render_warning_lines(&warnings, "a.csv", "b.csv"),
+            vec![
+                "Warning: a.csv was read with delimiter ',' but b.csv with ';'; check that the columns line up. If one file was misread, re-export it to match the other; --strict-dialect refuses instead of warning."
+            ]
+        );
+    }
+}
@@ -1,7 +1,30 @@
 // JSON output schema assembly (bd-1lt)
 
+use crate::alignment::key_dedup::KeyDedup as CoreKeyDedup;
+use crate::alignment::key_join::KeyChangeReport;
+use crate::alignment::sample::RowSample;
+use crate::cli::config::ConfigReceipt;
+use crate::csv::compression::Compression;
+use crate::csv::duplicates::DuplicateRows;
+use crate::csv::encoding::SourceEncoding;
+use crate::diff::coverage::DiffuseShortfall;
 use crate::diff::heap::MAX_CONTRIBUTORS;
+use crate::diff::order::RowId;
+use crate::diff::rank::Ranking;
+use crate::diff::rescale::RescaledColumn as DetectedRescale;
+use crate::diff::rollup::{ColumnDeltaStats, ColumnRollup, RowRollup};
+use crate::diff::text::TextChangeReport;
+use crate::diff::tolerance::ToleranceSweep;
 use crate::format::ident_json::encode_identifier_json;
+use crate::normalize::column_map::ColumnMapping;
+use crate::numeric::columns::ColumnNormalization as NormalizedColumn;
+use crate::numeric::dates::{DateChangeReport, format_date};
+use crate::numeric::drift::DriftReport;
+use crate::numeric::missing::MissingPolicy;
+use crate::numeric::parse::DecimalSeparator;
+use crate::numeric::schema::SchemaChange as SchemaDiff;
+use crate::numeric::semantic::ColumnSemantic;
+use crate::numeric::transform::ColumnTransform;
 use crate::profile::{ColumnRegistryRunInfo, ResolvedProfile};
 use crate::refusal::codes::RefusalCode;
 use serde::Serialize;
@@ -15,16 +38,56 @@ pub enum Outcome {
     Refusal,
 }
 
+impl Outcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::RealChange => "REAL_CHANGE",
+            Outcome::NoRealChange => "NO_REAL_CHANGE",
+            Outcome::Refusal => "REFUSAL",
+        }
+    }
+}
+
+/// Output schema version, pinned with `--output-version`.
+///
+/// Fields are only added within a version. A breaking change ships as the
+/// next version, and earlier versions stay selectable so pinned integrations
+/// keep their shape. `rvl.v0` is the only version so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputVersion {
+    #[default]
+    V0,
+}
+
+impl OutputVersion {
+    /// Accepts the short (`v0`) and full (`rvl.v0`) names.
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "v0" | "rvl.v0" => Some(OutputVersion::V0),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutputVersion::V0 => "rvl.v0",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum OutputMode {
     ExhaustiveNumeric,
+    SchemaOnly,
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AlignmentMode {
     Key,
+    /// Key join on group totals (`--group-by`).
+    GroupBy,
     RowOrder,
 }
 
@@ -38,6 +101,115 @@ pub struct Files {
 pub struct Alignment {
     pub mode: AlignmentMode,
     pub key_column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_map: Option<KeyMap>,
+    /// `--key-normalize` steps, in the order applied.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub key_normalize: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedup: Option<KeyDedup>,
+    /// Set under `--sample`: the verdict covers only the sampled rows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample: Option<Sample>,
+}
+
+/// An old-file column compared under its new-file name (`--map`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnMap {
+    pub old: String,
+    pub new: String,
+}
+
+impl ColumnMap {
+    pub fn from_mapping(mapping: &ColumnMapping) -> Self {
+        Self {
+            old: encode_identifier_json(&mapping.old),
+            new: encode_identifier_json(&mapping.new),
+        }
+    }
+}
+
+/// Per-column token normalizations (currency symbols stripped).
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnNormalization {
+    pub column: String,
+    pub currency_symbols: Vec<String>,
+}
+
+impl ColumnNormalization {
+    pub fn from_column(column: &NormalizedColumn) -> Self {
+        Self {
+            column: encode_identifier_json(&column.column),
+            currency_symbols: column.currency_symbols.clone(),
+        }
+    }
+}
+
+/// A value transform applied before the diff (`--transform`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Transform {
+    pub column: String,
+    /// `both`, `old`, or `new`.
+    pub side: &'static str,
+    /// `abs`, `round:N`, or `scale:F`.
+    pub transform: String,
+}
+
+impl Transform {
+    pub fn from_transform(transform: &ColumnTransform) -> Self {
+        Self {
+            column: encode_identifier_json(&transform.column),
+            side: transform.side.as_str(),
+            transform: transform.op.as_arg(),
+        }
+    }
+}
+
+/// Old→new key translations applied before the join (`--key-map`).
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyMap {
+    pub file: String,
+    pub entries: u64,
+}
+
+/// Records sharing a key collapsed before the join (`--dedup sum|first`).
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyDedup {
+    pub mode: &'static str,
+    pub rows_collapsed_old: u64,
+    pub rows_collapsed_new: u64,
+}
+
+impl KeyDedup {
+    pub fn from_dedup(dedup: CoreKeyDedup) -> Self {
+        Self {
+            mode: dedup.mode.as_str(),
+            rows_collapsed_old: dedup.collapsed_old,
+            rows_collapsed_new: dedup.collapsed_new,
+        }
+    }
+}
+
+/// Aligned rows kept by `--sample`; a verdict carrying this is SAMPLED.
+#[derive(Debug, Clone, Serialize)]
+pub struct Sample {
+    pub method: &'static str,
+    pub size: u64,
+    pub seed: u64,
+    pub rows_eligible: u64,
+    pub rows_sampled: u64,
+}
+
+impl Sample {
+    pub fn from_sample(sample: RowSample) -> Self {
+        Self {
+            method: "key_hash",
+            size: sample.size,
+            seed: sample.seed,
+            rows_eligible: sample.rows_eligible,
+            rows_sampled: sample.rows_sampled,
+        }
+    }
 }
 
 impl Alignment {
@@ -45,6 +217,10 @@ impl Alignment {
         Self {
             mode: AlignmentMode::Key,
             key_column: Some(encoded_key_column),
+            key_map: None,
+            key_normalize: Vec::new(),
+            dedup: None,
+            sample: None,
         }
     }
 
@@ -52,6 +228,10 @@ impl Alignment {
         Self {
             mode: AlignmentMode::RowOrder,
             key_column: None,
+            key_map: None,
+            key_normalize: Vec::new(),
+            dedup: None,
+            sample: None,
         }
     }
 }
@@ -61,6 +241,17 @@ pub struct DialectSide {
     pub delimiter: String,
     pub quote: String,
     pub escape: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compression: Option<Compression>,
+    /// Set when the input was transcoded to UTF-8 (`--encoding`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<SourceEncoding>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimal: Option<DecimalSeparator>,
+    /// Share of sampled records whose width matched the detected delimiter;
+    /// absent when the delimiter was forced or set by `sep=`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
 }
 
 impl DialectSide {
@@ -69,8 +260,33 @@ impl DialectSide {
             delimiter: byte_to_string(delimiter),
             quote: byte_to_string(quote),
             escape: escape.map(byte_to_string),
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: None,
         }
     }
+
+    pub fn with_compression(mut self, compression: Option<Compression>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    pub fn with_encoding(mut self, encoding: Option<SourceEncoding>) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn with_confidence(mut self, confidence: Option<f64>) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Record a non-default decimal separator.
+    pub fn with_decimal(mut self, decimal: DecimalSeparator) -> Self {
+        self.decimal = (decimal != DecimalSeparator::Dot).then_some(decimal);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -92,6 +308,49 @@ pub struct Counts {
     pub numeric_columns: Option<u64>,
     pub numeric_cells_checked: Option<u64>,
     pub numeric_cells_changed: Option<u64>,
+    /// Numeric cells whose value is identical but whose text changed (e.g. `1000` -> `1,000.00`);
+    /// present only when nonzero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format_only_changes: Option<u64>,
+    /// Cells missing on one side and numeric on the other that `--on-missing skip`
+    /// left out; present only under that policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_skipped: Option<u64>,
+    /// Such cells that `--on-missing zero` compared against 0; present only under that policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_zeroed: Option<u64>,
+    /// Aligned numeric cells left out of `numeric_cells_checked` because both sides are
+    /// missing; present only when nonzero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_both: Option<u64>,
+    /// Cells of added/removed rows (`--include-entity-changes`) left out because the
+    /// present side is missing too; present only when nonzero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_row: Option<u64>,
+    /// Aligned numeric cells left out because a side is not a number (cross-tab text
+    /// cells); present only when nonzero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unparsable: Option<u64>,
+    /// Trailing rows `--skip-footer` excluded from the old file (not in `rows_old`);
+    /// present only under that flag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_rows_old: Option<u64>,
+    /// Same for the new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_rows_new: Option<u64>,
+    /// Lines `--comment` skipped in the old file; present only under that flag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_lines_old: Option<u64>,
+    /// Same for the new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_lines_new: Option<u64>,
+    /// Rows `--where` excluded from the old file after alignment (still in `rows_old`);
+    /// present only under that flag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_filtered_old: Option<u64>,
+    /// Same for the new file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows_filtered_new: Option<u64>,
 }
 
 #[derive(Debug, Clone, Default, Serialize)]
@@ -99,6 +358,35 @@ pub struct Metrics {
     pub total_change: Option<f64>,
     pub max_abs_delta: Option<f64>,
     pub top_k_coverage: Option<f64>,
+    /// Cumulative coverage after the first 1..=K contributors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coverage_curve: Option<Vec<f64>>,
+    /// Wall-clock time per phase; set only under `--timings`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<PhaseTimings>,
+}
+
+/// Wall-clock milliseconds per pipeline phase (`--timings`). Both files are
+/// read and parsed in parallel, so `read_ms` is the slower file's read and
+/// `parse_ms` the rest of the parse stage. A run that stops early reports
+/// its remaining time in the phase where it stopped and zero after it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct PhaseTimings {
+    /// Profile, key map, and expected-baseline loading.
+    pub setup_ms: f64,
+    /// Reading, decompressing, and transcoding the inputs.
+    pub read_ms: f64,
+    /// Dialect detection, record parsing, and header normalization.
+    pub parse_ms: f64,
+    /// Key or row-order alignment.
+    pub align_ms: f64,
+    /// Numeric column typing.
+    pub type_ms: f64,
+    /// Cell scan, ranking, and coverage.
+    pub diff_ms: f64,
+    /// Building the human or JSON report.
+    pub render_ms: f64,
+    pub total_ms: f64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -121,6 +409,13 @@ impl Default for Limits {
     }
 }
 
+/// `--max-memory` receipt: the up-front peak estimate and the cap it was checked against.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Memory {
+    pub estimated_peak_bytes: u64,
+    pub max_memory: u64,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Contributor {
     pub row_id: String,
@@ -135,6 +430,12 @@ pub struct Contributor {
     pub contribution: Option<f64>,
     pub share: f64,
     pub cumulative_share: f64,
+    /// `row_added` or `row_removed` for whole-row changes (`--include-entity-changes`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<&'static str>,
+    /// `approved` or `new` relative to an `--expected` baseline.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<&'static str>,
 }
 
 impl Contributor {
@@ -160,6 +461,8 @@ impl Contributor {
                 contribution: Some(contribution),
                 share,
                 cumulative_share,
+                kind: None,
+                baseline: None,
             }
         } else {
             Self {
@@ -171,6 +474,8 @@ impl Contributor {
                 contribution: None,
                 share,
                 cumulative_share,
+                kind: None,
+                baseline: None,
             }
         }
     }
@@ -212,6 +517,507 @@ impl FieldChange {
     }
 }
 
+/// A numeric delta that was nonzero but fell within tolerance (`--show-subtolerance`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Subtolerance {
+    pub row_id: String,
+    pub column: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta: Option<f64>,
+}
+
+impl Subtolerance {
+    pub fn from_bytes(
+        row_id: &[u8],
+        column: &[u8],
+        old: f64,
+        new: f64,
+        delta: f64,
+        explicit: bool,
+    ) -> Self {
+        Self {
+            row_id: encode_identifier_json(row_id),
+            column: encode_identifier_json(column),
+            old: explicit.then_some(old),
+            new: explicit.then_some(new),
+            delta: explicit.then_some(delta),
+        }
+    }
+}
+
+/// Column whose inferred type differs between the two files.
+#[derive(Debug, Clone, Serialize)]
+pub struct RetypedColumn {
+    pub column: String,
+    pub old: &'static str,
+    pub new: &'static str,
+}
+
+/// Pre-verdict report emitted when the schema fingerprints differ.
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemaChange {
+    pub old_fingerprint: String,
+    pub new_fingerprint: String,
+    pub columns_old: u64,
+    pub columns_new: u64,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub retyped: Vec<RetypedColumn>,
+    pub reordered: bool,
+}
+
+impl SchemaChange {
+    pub fn from_diff(diff: &SchemaDiff) -> Self {
+        let encode = |names: &[Vec<u8>]| {
+            names
+                .iter()
+                .map(|name| encode_identifier_json(name))
+                .collect::<Vec<_>>()
+        };
+        Self {
+            old_fingerprint: diff.old_digest.clone(),
+            new_fingerprint: diff.new_digest.clone(),
+            columns_old: diff.columns_old as u64,
+            columns_new: diff.columns_new as u64,
+            added: encode(&diff.added),
+            removed: encode(&diff.removed),
+            retyped: diff
+                .retyped
+                .iter()
+                .map(|column| RetypedColumn {
+                    column: encode_identifier_json(&column.name),
+                    old: column.old.as_str(),
+                    new: column.new.as_str(),
+                })
+                .collect(),
+            reordered: diff.reordered,
+        }
+    }
+}
+
+/// Non-fatal input finding reported alongside the verdict.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "code")]
+pub enum Warning {
+    #[serde(rename = "W_DUPLICATE_ROWS")]
+    DuplicateRows {
+        file: &'static str,
+        rows: u64,
+        samples: Vec<DuplicateSample>,
+        /// True when `--dedupe-rows` removed the repeats before alignment.
+        deduped: bool,
+    },
+    /// The two files were read with different delimiters.
+    #[serde(rename = "W_DIALECT_MISMATCH")]
+    DialectMismatch {
+        old_delimiter: String,
+        new_delimiter: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateSample {
+    pub record: u64,
+    pub duplicate_of: u64,
+}
+
+impl Warning {
+    pub fn duplicate_rows(file: &'static str, duplicates: &DuplicateRows, deduped: bool) -> Self {
+        Self::DuplicateRows {
+            file,
+            rows: duplicates.rows,
+            samples: duplicates
+                .samples
+                .iter()
+                .map(|sample| DuplicateSample {
+                    record: sample.record,
+                    duplicate_of: sample.duplicate_of,
+                })
+                .collect(),
+            deduped,
+        }
+    }
+
+    pub fn dialect_mismatch(old: u8, new: u8) -> Self {
+        Self::DialectMismatch {
+            old_delimiter: byte_to_string(old),
+            new_delimiter: byte_to_string(new),
+        }
+    }
+}
+
+/// Incremental verdict against an approved baseline (`--expected`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Expected {
+    pub file: String,
+    pub approved: u64,
+    pub new: u64,
+    pub verdict: &'static str,
+}
+
+/// A numeric column whose new values look like its old values times a
+/// power of ten (a unit change).
+#[derive(Debug, Clone, Serialize)]
+pub struct RescaledColumn {
+    pub column: String,
+    /// `new / old`.
+    pub factor: f64,
+    /// Compared cell pairs with a non-zero value.
+    pub cells: u64,
+}
+
+impl RescaledColumn {
+    pub fn from_column(column: &DetectedRescale) -> Self {
+        Self {
+            column: encode_identifier_json(&column.column),
+            factor: column.factor,
+            cells: column.cells,
+        }
+    }
+}
+
+/// Per-column distribution drift (`--drift`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Drift {
+    pub metric: &'static str,
+    pub columns: Vec<ColumnDrift>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnDrift {
+    pub column: String,
+    pub value: f64,
+    pub pairs: u64,
+}
+
+impl Drift {
+    pub fn from_report(report: &DriftReport) -> Self {
+        Self {
+            metric: report.metric.as_str(),
+            columns: report
+                .columns
+                .iter()
+                .map(|column| ColumnDrift {
+                    column: encode_identifier_json(&column.column),
+                    value: column.value,
+                    pairs: column.pairs,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Cells above, and the verdict at, one `--tolerance-sweep` level.
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepLevel {
+    pub tolerance: f64,
+    pub cells_above: u64,
+    pub verdict: Outcome,
+}
+
+impl SweepLevel {
+    pub fn from_sweep(sweep: &ToleranceSweep) -> Vec<Self> {
+        sweep
+            .levels
+            .iter()
+            .zip(&sweep.cells_above)
+            .map(|(&tolerance, &cells_above)| Self {
+                tolerance,
+                cells_above,
+                verdict: sweep_verdict(cells_above),
+            })
+            .collect()
+    }
+}
+
+/// Verdict a tolerance alone would give: any cell above it is a real change.
+pub fn sweep_verdict(cells_above: u64) -> Outcome {
+    if cells_above > 0 {
+        Outcome::RealChange
+    } else {
+        Outcome::NoRealChange
+    }
+}
+
+/// Change summed per numeric column.
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnChange {
+    pub column: String,
+    pub contribution: f64,
+    pub cells_changed: u64,
+    pub share: f64,
+}
+
+impl ColumnChange {
+    pub fn from_rollup(rollup: &ColumnRollup) -> Vec<Self> {
+        rollup
+            .columns
+            .iter()
+            .map(|column| ColumnChange {
+                column: encode_identifier_json(&column.column),
+                contribution: column.contribution,
+                cells_changed: column.cells_changed,
+                share: column.share,
+            })
+            .collect()
+    }
+}
+
+/// |delta| statistics for one numeric column (`column_deltas`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnDelta {
+    pub column: String,
+    pub cells: u64,
+    pub cells_differing: u64,
+    pub min_abs_delta: Option<f64>,
+    pub max_abs_delta: Option<f64>,
+    pub mean_abs_delta: Option<f64>,
+}
+
+impl ColumnDelta {
+    pub fn from_stats(stats: &[ColumnDeltaStats]) -> Vec<Self> {
+        stats
+            .iter()
+            .map(|column| ColumnDelta {
+                column: encode_identifier_json(&column.column),
+                cells: column.cells,
+                cells_differing: column.cells_differing,
+                min_abs_delta: column.min_abs_delta,
+                max_abs_delta: column.max_abs_delta,
+                mean_abs_delta: column.mean_abs_delta,
+            })
+            .collect()
+    }
+}
+
+/// Change summed per aligned row (top rows only).
+#[derive(Debug, Clone, Serialize)]
+pub struct RowChange {
+    pub row_id: String,
+    pub contribution: f64,
+    pub cells_changed: u64,
+    pub share: f64,
+}
+
+impl RowChange {
+    pub fn from_rollup(rollup: &RowRollup) -> Vec<Self> {
+        rollup
+            .rows
+            .iter()
+            .map(|row| RowChange {
+                row_id: match &row.row_id {
+                    RowId::RowIndex(index) => encode_identifier_json(index.to_string().as_bytes()),
+                    RowId::Key(bytes) => encode_identifier_json(bytes),
+                },
+                contribution: row.contribution,
+                cells_changed: row.cells_changed,
+                share: row.share,
+            })
+            .collect()
+    }
+}
+
+/// How contributions were ranked (`abs_delta`, or `weighted` by `--weight-col`).
+#[derive(Debug, Clone, Serialize)]
+pub struct RankingReceipt {
+    pub mode: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_column: Option<String>,
+}
+
+impl RankingReceipt {
+    pub fn from_ranking(ranking: &Ranking) -> Self {
+        Self {
+            mode: ranking.as_str(),
+            weight_column: ranking.weight_column().map(encode_identifier_json),
+        }
+    }
+}
+
+/// Coverage that fell short of the threshold, reported instead of
+/// `E_DIFFUSE` under `--on-diffuse expand`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diffuse {
+    pub top_k_coverage: f64,
+    pub threshold: f64,
+    /// The least-explained column under `--coverage per-column`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+}
+
+impl Diffuse {
+    pub fn from_shortfall(shortfall: &DiffuseShortfall) -> Self {
+        Self {
+            top_k_coverage: shortfall.top_k_coverage,
+            threshold: shortfall.threshold,
+            column: shortfall.column.as_deref().map(encode_identifier_json),
+        }
+    }
+}
+
+/// Rows outside the compared key intersection (`--allow-missing-keys`), or
+/// past the common prefix in row-order mode (`--allow-trailing-rows`).
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyChanges {
+    pub rows_added: u64,
+    pub rows_removed: u64,
+    /// Per-column numeric totals of added/removed rows; `null` unless `--explicit`.
+    pub totals: Option<Vec<KeyChangeTotal>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyChangeTotal {
+    pub column: String,
+    pub added: f64,
+    pub removed: f64,
+}
+
+impl KeyChanges {
+    pub fn from_report(report: &KeyChangeReport) -> Self {
+        Self {
+            rows_added: report.rows_added,
+            rows_removed: report.rows_removed,
+            totals: report.totals.as_ref().map(|totals| {
+                totals
+                    .iter()
+                    .map(|total| KeyChangeTotal {
+                        column: encode_identifier_json(&total.column),
+                        added: total.added,
+                        removed: total.removed,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Changed cells in columns detected as dates.
+#[derive(Debug, Clone, Serialize)]
+pub struct DateChanges {
+    pub columns: Vec<String>,
+    pub cells_changed: u64,
+    pub changes: Vec<DateChange>,
+}
+
+/// One shifted date; `old`, `new`, and `shift_days` only with `--explicit`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DateChange {
+    pub kind: &'static str,
+    pub row_id: String,
+    pub column: String,
+    pub direction: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_days: Option<i64>,
+}
+
+impl DateChanges {
+    pub fn from_report(report: &DateChangeReport) -> Self {
+        Self {
+            columns: report
+                .columns
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            cells_changed: report.cells_changed,
+            changes: report
+                .changes
+                .iter()
+                .map(|change| DateChange {
+                    kind: "date_change",
+                    row_id: encode_identifier_json(&change.row_id),
+                    column: encode_identifier_json(&change.column),
+                    direction: if change.later { "later" } else { "earlier" },
+                    old: change.values.map(|values| format_date(values.old)),
+                    new: change.values.map(|values| format_date(values.new)),
+                    shift_days: change.values.map(|values| values.shift_days()),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Changed text cells per column (`--include-text`).
+#[derive(Debug, Clone, Serialize)]
+pub struct TextChanges {
+    pub cells_changed: u64,
+    pub columns: Vec<TextColumn>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextColumn {
+    pub column: String,
+    pub changed: u64,
+    pub samples: Vec<TextSample>,
+}
+
+/// One changed text cell; `old`/`new` only with `--explicit`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TextSample {
+    pub row_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<String>,
+}
+
+impl TextChanges {
+    pub fn from_report(report: &TextChangeReport) -> Self {
+        Self {
+            cells_changed: report.cells_changed(),
+            columns: report
+                .columns
+                .iter()
+                .map(|column| TextColumn {
+                    column: encode_identifier_json(&column.column),
+                    changed: column.changed,
+                    samples: column
+                        .samples
+                        .iter()
+                        .map(|sample| TextSample {
+                            row_id: encode_identifier_json(&sample.row_id),
+                            old: sample
+                                .values
+                                .as_ref()
+                                .map(|(old, _)| encode_identifier_json(old)),
+                            new: sample
+                                .values
+                                .as_ref()
+                                .map(|(_, new)| encode_identifier_json(new)),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Semantic type and effective tolerance of one numeric column (`--smart-tolerance`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnType {
+    pub column: String,
+    #[serde(rename = "type")]
+    pub semantic: &'static str,
+    pub tolerance: f64,
+}
+
+impl ColumnType {
+    pub fn from_semantic(column: &ColumnSemantic) -> Self {
+        Self {
+            column: encode_identifier_json(&column.column),
+            semantic: column.semantic.as_str(),
+            tolerance: column.tolerance,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Refusal {
     pub code: String,
@@ -231,6 +1037,7 @@ impl Refusal {
 
 #[derive(Debug, Clone)]
 pub struct JsonContext {
+    pub version: OutputVersion,
     pub files: Files,
     pub alignment: Alignment,
     pub dialect: Dialect,
@@ -243,40 +1050,136 @@ pub struct JsonContext {
     pub audit: Option<Audit>,
     pub threshold: f64,
     pub tolerance: f64,
+    pub ranking: Ranking,
+    /// `--on-missing` when not the default `refuse`.
+    pub missing_policy: Option<MissingPolicy>,
+    /// Config file settings used for this run.
+    pub config: Option<ConfigReceipt>,
     pub counts: Counts,
     pub metrics: Metrics,
     pub field_changes: Option<Vec<FieldChange>>,
+    pub schema_change: Option<SchemaDiff>,
+    pub drift: Option<DriftReport>,
+    /// Columns whose values look rescaled by a power of ten.
+    pub rescaled_columns: Vec<DetectedRescale>,
+    pub tolerance_sweep: Option<ToleranceSweep>,
+    /// Set when `--on-diffuse expand` replaced `E_DIFFUSE`.
+    pub diffuse: Option<DiffuseShortfall>,
+    /// Per-column rollup of numeric change (REAL_CHANGE).
+    pub by_column: Option<ColumnRollup>,
+    /// Per-row rollup of numeric change (REAL_CHANGE).
+    pub by_row: Option<RowRollup>,
+    /// Per-column |delta| statistics (NO_REAL_CHANGE).
+    pub column_deltas: Option<Vec<ColumnDeltaStats>>,
+    pub key_changes: Option<KeyChangeReport>,
+    pub date_changes: Option<DateChangeReport>,
+    pub text_changes: Option<TextChangeReport>,
+    pub warnings: Vec<Warning>,
+    pub column_map: Vec<ColumnMapping>,
+    /// Old-only/new-only pairs that look like renames.
+    pub suggested_maps: Vec<ColumnMapping>,
+    pub column_types: Option<Vec<ColumnSemantic>>,
+    /// Numeric columns where `--parse-percent` read at least one `N%` token.
+    pub percent_columns: Vec<Vec<u8>>,
+    /// `--transform` entries applied to numeric columns.
+    pub transforms: Vec<ColumnTransform>,
+    /// Columns dropped from the numeric set by `--on-mixed-types exclude`.
+    pub columns_excluded_mixed: Vec<Vec<u8>>,
+    pub column_normalizations: Vec<NormalizedColumn>,
+    pub limits: Limits,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct JsonOutput {
     pub version: &'static str,
     pub outcome: Outcome,
+    /// `true` under `--sample`: the outcome covers only `alignment.sample.rows_sampled` rows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sampled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mode: Option<OutputMode>,
     pub profile_id: Option<String>,
     pub profile_sha256: Option<String>,
     pub files: Files,
     pub alignment: Alignment,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub column_map: Vec<ColumnMap>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggested_column_maps: Vec<ColumnMap>,
     pub dialect: Dialect,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_change: Option<SchemaChange>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
     pub threshold: f64,
     pub tolerance: f64,
+    /// Declared on REAL_CHANGE and NO_REAL_CHANGE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking: Option<RankingReceipt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_policy: Option<MissingPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<ConfigReceipt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_types: Option<Vec<ColumnType>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub percent_columns: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transforms: Vec<Transform>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub columns_excluded_mixed: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub column_normalizations: Vec<ColumnNormalization>,
     pub counts: Counts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_changes: Option<KeyChanges>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_changes: Option<DateChanges>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_changes: Option<TextChanges>,
     pub metrics: Metrics,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffuse: Option<Diffuse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub audit: Option<Audit>,
     pub limits: Limits,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
     pub contributors: Vec<Contributor>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_column: Option<Vec<ColumnChange>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub by_row: Option<Vec<RowChange>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub field_changes: Option<Vec<FieldChange>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtolerance: Option<Vec<Subtolerance>>,
+    /// Per-column |delta| statistics, tolerated deltas included; NO_REAL_CHANGE only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column_deltas: Option<Vec<ColumnDelta>>,
+    /// `true` when byte-identical inputs were parsed once and compared as NO_REAL_CHANGE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identical_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drift: Option<Drift>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rescaled_columns: Vec<RescaledColumn>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolerance_sweep: Option<Vec<SweepLevel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Expected>,
     pub refusal: Option<Refusal>,
+    /// Every blocking issue found under `--collect-refusals`, `refusal` first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusals: Option<Vec<Refusal>>,
 }
 
 impl JsonOutput {
     pub fn real_change(ctx: JsonContext, contributors: Vec<Contributor>) -> Self {
         Self {
-            version: "rvl.v0",
+            version: ctx.version.as_str(),
             outcome: Outcome::RealChange,
+            sampled: ctx.alignment.sample.is_some().then_some(true),
             mode: ctx.mode,
             profile_id: if ctx.profile_used {
                 ctx.profile_id.clone()
@@ -290,23 +1193,78 @@ impl JsonOutput {
             },
             files: ctx.files,
             alignment: ctx.alignment,
+            column_map: ctx.column_map.iter().map(ColumnMap::from_mapping).collect(),
+            suggested_column_maps: ctx
+                .suggested_maps
+                .iter()
+                .map(ColumnMap::from_mapping)
+                .collect(),
             dialect: ctx.dialect,
+            schema_change: ctx.schema_change.as_ref().map(SchemaChange::from_diff),
+            warnings: ctx.warnings,
             threshold: ctx.threshold,
             tolerance: ctx.tolerance,
+            ranking: Some(RankingReceipt::from_ranking(&ctx.ranking)),
+            missing_policy: ctx.missing_policy,
+            config: ctx.config.clone(),
+            column_types: ctx
+                .column_types
+                .as_ref()
+                .map(|columns| columns.iter().map(ColumnType::from_semantic).collect()),
+            percent_columns: ctx
+                .percent_columns
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            transforms: ctx
+                .transforms
+                .iter()
+                .map(Transform::from_transform)
+                .collect(),
+            columns_excluded_mixed: ctx
+                .columns_excluded_mixed
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            column_normalizations: ctx
+                .column_normalizations
+                .iter()
+                .map(ColumnNormalization::from_column)
+                .collect(),
             counts: ctx.counts,
+            key_changes: ctx.key_changes.as_ref().map(KeyChanges::from_report),
+            date_changes: ctx.date_changes.as_ref().map(DateChanges::from_report),
+            text_changes: ctx.text_changes.as_ref().map(TextChanges::from_report),
             metrics: ctx.metrics,
+            diffuse: ctx.diffuse.as_ref().map(Diffuse::from_shortfall),
             audit: ctx.audit,
-            limits: Limits::default(),
+            limits: ctx.limits,
+            memory: None,
             contributors,
+            by_column: ctx.by_column.as_ref().map(ColumnChange::from_rollup),
+            by_row: ctx.by_row.as_ref().map(RowChange::from_rollup),
             field_changes: ctx.field_changes,
+            subtolerance: None,
+            column_deltas: None,
+            identical_files: None,
+            drift: ctx.drift.as_ref().map(Drift::from_report),
+            rescaled_columns: ctx
+                .rescaled_columns
+                .iter()
+                .map(RescaledColumn::from_column)
+                .collect(),
+            tolerance_sweep: ctx.tolerance_sweep.as_ref().map(SweepLevel::from_sweep),
+            expected: None,
             refusal: None,
+            refusals: None,
         }
     }
 
     pub fn no_real_change(ctx: JsonContext) -> Self {
         Self {
-            version: "rvl.v0",
+            version: ctx.version.as_str(),
             outcome: Outcome::NoRealChange,
+            sampled: ctx.alignment.sample.is_some().then_some(true),
             mode: ctx.mode,
             profile_id: if ctx.profile_used {
                 ctx.profile_id.clone()
@@ -320,23 +1278,78 @@ impl JsonOutput {
             },
             files: ctx.files,
             alignment: ctx.alignment,
+            column_map: ctx.column_map.iter().map(ColumnMap::from_mapping).collect(),
+            suggested_column_maps: ctx
+                .suggested_maps
+                .iter()
+                .map(ColumnMap::from_mapping)
+                .collect(),
             dialect: ctx.dialect,
+            schema_change: ctx.schema_change.as_ref().map(SchemaChange::from_diff),
+            warnings: ctx.warnings,
             threshold: ctx.threshold,
             tolerance: ctx.tolerance,
+            ranking: Some(RankingReceipt::from_ranking(&ctx.ranking)),
+            missing_policy: ctx.missing_policy,
+            config: ctx.config.clone(),
+            column_types: ctx
+                .column_types
+                .as_ref()
+                .map(|columns| columns.iter().map(ColumnType::from_semantic).collect()),
+            percent_columns: ctx
+                .percent_columns
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            transforms: ctx
+                .transforms
+                .iter()
+                .map(Transform::from_transform)
+                .collect(),
+            columns_excluded_mixed: ctx
+                .columns_excluded_mixed
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            column_normalizations: ctx
+                .column_normalizations
+                .iter()
+                .map(ColumnNormalization::from_column)
+                .collect(),
             counts: ctx.counts,
+            key_changes: ctx.key_changes.as_ref().map(KeyChanges::from_report),
+            date_changes: ctx.date_changes.as_ref().map(DateChanges::from_report),
+            text_changes: ctx.text_changes.as_ref().map(TextChanges::from_report),
             metrics: ctx.metrics,
+            diffuse: ctx.diffuse.as_ref().map(Diffuse::from_shortfall),
             audit: ctx.audit,
-            limits: Limits::default(),
+            limits: ctx.limits,
+            memory: None,
             contributors: Vec::new(),
+            by_column: ctx.by_column.as_ref().map(ColumnChange::from_rollup),
+            by_row: ctx.by_row.as_ref().map(RowChange::from_rollup),
             field_changes: ctx.field_changes,
+            subtolerance: None,
+            column_deltas: ctx.column_deltas.as_deref().map(ColumnDelta::from_stats),
+            identical_files: None,
+            drift: ctx.drift.as_ref().map(Drift::from_report),
+            rescaled_columns: ctx
+                .rescaled_columns
+                .iter()
+                .map(RescaledColumn::from_column)
+                .collect(),
+            tolerance_sweep: ctx.tolerance_sweep.as_ref().map(SweepLevel::from_sweep),
+            expected: None,
             refusal: None,
+            refusals: None,
         }
     }
 
     pub fn refusal(ctx: JsonContext, refusal: Refusal) -> Self {
         Self {
-            version: "rvl.v0",
+            version: ctx.version.as_str(),
             outcome: Outcome::Refusal,
+            sampled: ctx.alignment.sample.is_some().then_some(true),
             mode: ctx.mode,
             profile_id: if ctx.profile_used {
                 ctx.profile_id.clone()
@@ -350,16 +1363,70 @@ impl JsonOutput {
             },
             files: ctx.files,
             alignment: ctx.alignment,
+            column_map: ctx.column_map.iter().map(ColumnMap::from_mapping).collect(),
+            suggested_column_maps: ctx
+                .suggested_maps
+                .iter()
+                .map(ColumnMap::from_mapping)
+                .collect(),
             dialect: ctx.dialect,
+            schema_change: ctx.schema_change.as_ref().map(SchemaChange::from_diff),
+            warnings: ctx.warnings,
             threshold: ctx.threshold,
             tolerance: ctx.tolerance,
+            ranking: None,
+            missing_policy: ctx.missing_policy,
+            config: ctx.config.clone(),
+            column_types: ctx
+                .column_types
+                .as_ref()
+                .map(|columns| columns.iter().map(ColumnType::from_semantic).collect()),
+            percent_columns: ctx
+                .percent_columns
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            transforms: ctx
+                .transforms
+                .iter()
+                .map(Transform::from_transform)
+                .collect(),
+            columns_excluded_mixed: ctx
+                .columns_excluded_mixed
+                .iter()
+                .map(|column| encode_identifier_json(column))
+                .collect(),
+            column_normalizations: ctx
+                .column_normalizations
+                .iter()
+                .map(ColumnNormalization::from_column)
+                .collect(),
             counts: ctx.counts,
+            key_changes: ctx.key_changes.as_ref().map(KeyChanges::from_report),
+            date_changes: ctx.date_changes.as_ref().map(DateChanges::from_report),
+            text_changes: ctx.text_changes.as_ref().map(TextChanges::from_report),
             metrics: ctx.metrics,
+            diffuse: ctx.diffuse.as_ref().map(Diffuse::from_shortfall),
             audit: ctx.audit,
-            limits: Limits::default(),
+            limits: ctx.limits,
+            memory: None,
             contributors: Vec::new(),
+            by_column: ctx.by_column.as_ref().map(ColumnChange::from_rollup),
+            by_row: ctx.by_row.as_ref().map(RowChange::from_rollup),
             field_changes: ctx.field_changes,
+            subtolerance: None,
+            column_deltas: None,
+            identical_files: None,
+            drift: ctx.drift.as_ref().map(Drift::from_report),
+            rescaled_columns: ctx
+                .rescaled_columns
+                .iter()
+                .map(RescaledColumn::from_column)
+                .collect(),
+            tolerance_sweep: ctx.tolerance_sweep.as_ref().map(SweepLevel::from_sweep),
+            expected: None,
             refusal: Some(refusal),
+            refusals: None,
         }
     }
 
@@ -387,6 +1454,7 @@ mod tests {
 
     fn sample_context() -> JsonContext {
         JsonContext {
+            version: OutputVersion::V0,
             files: Files {
                 old: "old.csv".to_string(),
                 new: "new.csv".to_string(),
@@ -394,6 +1462,10 @@ mod tests {
             alignment: Alignment {
                 mode: AlignmentMode::Key,
                 key_column: Some("u8:id".to_string()),
+                key_map: None,
+                key_normalize: Vec::new(),
+                dedup: None,
+                sample: None,
             },
             dialect: Dialect {
                 old: Some(DialectSide::new(b',', b'"', None)),
@@ -408,6 +1480,9 @@ mod tests {
             audit: None,
             threshold: 0.95,
             tolerance: 1e-9,
+            ranking: Ranking::AbsDelta,
+            missing_policy: None,
+            config: None,
             counts: Counts {
                 rows_old: Some(10),
                 rows_new: Some(10),
@@ -420,13 +1495,47 @@ mod tests {
                 numeric_columns: Some(2),
                 numeric_cells_checked: Some(20),
                 numeric_cells_changed: Some(3),
+                format_only_changes: None,
+                missing_skipped: None,
+                missing_zeroed: None,
+                missing_both: None,
+                missing_row: None,
+                unparsable: None,
+                footer_rows_old: None,
+                footer_rows_new: None,
+                comment_lines_old: None,
+                comment_lines_new: None,
+                rows_filtered_old: None,
+                rows_filtered_new: None,
             },
             metrics: Metrics {
                 total_change: Some(10.0),
                 max_abs_delta: Some(5.0),
                 top_k_coverage: Some(0.95),
+                coverage_curve: None,
+                timings: None,
             },
             field_changes: None,
+            schema_change: None,
+            drift: None,
+            rescaled_columns: Vec::new(),
+            tolerance_sweep: None,
+            diffuse: None,
+            by_column: None,
+            by_row: None,
+            column_deltas: None,
+            key_changes: None,
+            date_changes: None,
+            text_changes: None,
+            warnings: Vec::new(),
+            column_map: Vec::new(),
+            suggested_maps: Vec::new(),
+            column_types: None,
+            percent_columns: Vec::new(),
+            transforms: Vec::new(),
+            columns_excluded_mixed: Vec::new(),
+            column_normalizations: Vec::new(),
+            limits: Limits::default(),
         }
     }
 
@@ -1,2 +1,47 @@
+pub mod changes_csv;
+pub mod events;
+pub mod html;
 pub mod human;
 pub mod json;
+pub mod sarif;
+pub mod xlsx;
+
+use json::JsonOutput;
+
+/// Document format requested with `--format`, rendered from the `rvl.v0`
+/// report instead of the human text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Html,
+    Sarif,
+}
+
+impl ReportFormat {
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.to_ascii_lowercase().as_str() {
+            "html" => Some(ReportFormat::Html),
+            "sarif" => Some(ReportFormat::Sarif),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Sarif => "sarif",
+        }
+    }
+
+    pub fn render(self, report: &JsonOutput) -> String {
+        match self {
+            ReportFormat::Html => html::render_html_report(report),
+            ReportFormat::Sarif => sarif::render_sarif_report(report),
+        }
+    }
+}
+
+/// JSON identifiers are `u8:<text>` or `hex:<bytes>`; documents show text
+/// identifiers without the prefix.
+pub(crate) fn display_identifier(encoded: &str) -> &str {
+    encoded.strip_prefix("u8:").unwrap_or(encoded)
+}
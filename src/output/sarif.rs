@@ -0,0 +1,115 @@
+// SARIF 2.1.0 report (`--format sarif`)
+
+use serde_json::{Value, json};
+
+use crate::format::numbers::{format_delta, format_float_shortest, format_percent_one_decimal};
+use crate::output::display_identifier;
+use crate::output::json::{Contributor, JsonOutput, Refusal};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const REAL_CHANGE_RULE: &str = "REAL_CHANGE";
+
+/// Render an `rvl.v0` report as a SARIF log for code-review annotations.
+///
+/// Each contributor becomes a `warning` result on the new file and a refusal
+/// becomes one `error` result on the file it names; NO REAL CHANGE has no
+/// results. Contributors carry no source line, so results anchor at line 1
+/// and name the row and column in the message and logical location.
+pub fn render_sarif_report(report: &JsonOutput) -> String {
+    let mut rules = Vec::new();
+    let mut results = Vec::new();
+    if !report.contributors.is_empty() {
+        rules.push(json!({
+            "id": REAL_CHANGE_RULE,
+            "shortDescription": { "text": "Numeric change above tolerance" },
+        }));
+        results.extend(
+            report
+                .contributors
+                .iter()
+                .map(|contributor| contributor_result(contributor, &report.files.new)),
+        );
+    }
+    if let Some(refusal) = report.refusal.as_ref() {
+        rules.push(json!({
+            "id": refusal.code,
+            "shortDescription": { "text": refusal.message },
+        }));
+        results.push(refusal_result(refusal, report));
+    }
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "rvl",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "properties": {
+                "outcome": report.outcome.as_str(),
+                "old": report.files.old,
+                "new": report.files.new,
+            },
+            "results": results,
+        }]
+    });
+    let mut out = serde_json::to_string_pretty(&log).unwrap_or_else(|_| "{}".to_string());
+    out.push('\n');
+    out
+}
+
+fn contributor_result(contributor: &Contributor, file: &str) -> Value {
+    let row = display_identifier(&contributor.row_id);
+    let column = display_identifier(&contributor.column);
+    let share = format_percent_one_decimal(contributor.share);
+    let text = match (contributor.old, contributor.new, contributor.delta) {
+        (Some(old), Some(new), Some(delta)) => format!(
+            "{column} changed in row {row}: {} -> {} ({}), {share} of total numeric change.",
+            format_float_shortest(old),
+            format_float_shortest(new),
+            format_delta(delta)
+        ),
+        _ => format!("{column} changed in row {row}: {share} of total numeric change."),
+    };
+    json!({
+        "ruleId": REAL_CHANGE_RULE,
+        "level": "warning",
+        "message": { "text": text },
+        "locations": [location(file, Some(format!("{row}/{column}")))],
+        "partialFingerprints": {
+            "rvlCell/v1": format!("{}/{}", contributor.row_id, contributor.column),
+        },
+    })
+}
+
+fn refusal_result(refusal: &Refusal, report: &JsonOutput) -> Value {
+    let file = match refusal.detail.get("file").and_then(Value::as_str) {
+        Some("old") => &report.files.old,
+        _ => &report.files.new,
+    };
+    json!({
+        "ruleId": refusal.code,
+        "level": "error",
+        "message": { "text": format!("{}: {}", refusal.code, refusal.message) },
+        "locations": [location(file, None)],
+        "properties": { "detail": refusal.detail },
+    })
+}
+
+fn location(file: &str, cell: Option<String>) -> Value {
+    let mut location = json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": file.replace('\\', "/") },
+            "region": { "startLine": 1 },
+        }
+    });
+    if let Some(cell) = cell {
+        location["logicalLocations"] = json!([{ "fullyQualifiedName": cell, "kind": "element" }]);
+    }
+    location
+}
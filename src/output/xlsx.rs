@@ -0,0 +1,387 @@
+// Minimal, dependency-free .xlsx writer: SpreadsheetML parts in a stored
+// (uncompressed) ZIP container with fixed timestamps, so identical inputs
+// produce byte-identical workbooks.
+
+use std::fmt::Write as _;
+
+/// One worksheet cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Empty,
+    Text(String),
+    /// Bold text, used for header rows and summary labels.
+    Header(String),
+    Number(f64),
+    /// Ratio rendered with a percentage number format (0.5 -> 50.00%).
+    Percent(f64),
+}
+
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub name: String,
+    pub rows: Vec<Vec<Cell>>,
+    pub column_widths: Vec<f64>,
+    /// Keep the first row visible while scrolling.
+    pub freeze_header: bool,
+}
+
+/// Serialize sheets into `.xlsx` bytes.
+pub fn workbook_bytes(sheets: &[Sheet]) -> Vec<u8> {
+    let mut zip = StoredZip::default();
+    zip.add(
+        "[Content_Types].xml",
+        content_types(sheets.len()).as_bytes(),
+    );
+    zip.add("_rels/.rels", ROOT_RELS.as_bytes());
+    zip.add("xl/workbook.xml", workbook_xml(sheets).as_bytes());
+    zip.add(
+        "xl/_rels/workbook.xml.rels",
+        workbook_rels(sheets.len()).as_bytes(),
+    );
+    zip.add("xl/styles.xml", STYLES_XML.as_bytes());
+    for (idx, sheet) in sheets.iter().enumerate() {
+        zip.add(
+            &format!("xl/worksheets/sheet{}.xml", idx + 1),
+            sheet_xml(sheet).as_bytes(),
+        );
+    }
+    zip.finish()
+}
+
+const ROOT_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+    r#"</Relationships>"#
+);
+
+// Style indexes: 0 = default, 1 = bold, 2 = percent (0.00%).
+const STYLES_XML: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+    r#"<fonts count="2"><font><sz val="11"/><name val="Calibri"/></font>"#,
+    r#"<font><b/><sz val="11"/><name val="Calibri"/></font></fonts>"#,
+    r#"<fills count="2"><fill><patternFill patternType="none"/></fill>"#,
+    r#"<fill><patternFill patternType="gray125"/></fill></fills>"#,
+    r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+    r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+    r#"<cellXfs count="3">"#,
+    r#"<xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>"#,
+    r#"<xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/>"#,
+    r#"<xf numFmtId="10" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/>"#,
+    r#"</cellXfs>"#,
+    r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles>"#,
+    r#"</styleSheet>"#
+);
+
+fn content_types(sheet_count: usize) -> String {
+    let mut out = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+        r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+        r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+        r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+        r#"<Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>"#,
+    ));
+    for idx in 1..=sheet_count {
+        let _ = write!(
+            out,
+            r#"<Override PartName="/xl/worksheets/sheet{idx}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+        );
+    }
+    out.push_str("</Types>");
+    out
+}
+
+fn workbook_xml(sheets: &[Sheet]) -> String {
+    let mut out = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" "#,
+        r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>"#,
+    ));
+    for (idx, sheet) in sheets.iter().enumerate() {
+        let _ = write!(
+            out,
+            r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+            escape_xml(&sheet_name(&sheet.name)),
+            idx + 1,
+            idx + 1
+        );
+    }
+    out.push_str("</sheets></workbook>");
+    out
+}
+
+fn workbook_rels(sheet_count: usize) -> String {
+    let mut out = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    ));
+    for idx in 1..=sheet_count {
+        let _ = write!(
+            out,
+            r#"<Relationship Id="rId{idx}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{idx}.xml"/>"#
+        );
+    }
+    let _ = write!(
+        out,
+        r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>"#,
+        sheet_count + 1
+    );
+    out.push_str("</Relationships>");
+    out
+}
+
+fn sheet_xml(sheet: &Sheet) -> String {
+    let mut out = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+        r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+    ));
+    if sheet.freeze_header {
+        out.push_str(concat!(
+            r#"<sheetViews><sheetView workbookViewId="0">"#,
+            r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#,
+            r#"</sheetView></sheetViews>"#,
+        ));
+    }
+    if !sheet.column_widths.is_empty() {
+        out.push_str("<cols>");
+        for (idx, width) in sheet.column_widths.iter().enumerate() {
+            let _ = write!(
+                out,
+                r#"<col min="{0}" max="{0}" width="{1}" customWidth="1"/>"#,
+                idx + 1,
+                width
+            );
+        }
+        out.push_str("</cols>");
+    }
+    out.push_str("<sheetData>");
+    for (row_idx, row) in sheet.rows.iter().enumerate() {
+        let row_number = row_idx + 1;
+        let _ = write!(out, r#"<row r="{row_number}">"#);
+        for (col_idx, cell) in row.iter().enumerate() {
+            let reference = format!("{}{row_number}", column_letters(col_idx));
+            match cell {
+                Cell::Empty => {}
+                Cell::Text(text) => {
+                    let _ = write!(
+                        out,
+                        r#"<c r="{reference}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                        escape_xml(text)
+                    );
+                }
+                Cell::Header(text) => {
+                    let _ = write!(
+                        out,
+                        r#"<c r="{reference}" s="1" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                        escape_xml(text)
+                    );
+                }
+                Cell::Number(value) => write_number(&mut out, &reference, *value, None),
+                Cell::Percent(value) => write_number(&mut out, &reference, *value, Some(2)),
+            }
+        }
+        out.push_str("</row>");
+    }
+    out.push_str("</sheetData></worksheet>");
+    out
+}
+
+fn write_number(out: &mut String, reference: &str, value: f64, style: Option<u8>) {
+    if !value.is_finite() {
+        return;
+    }
+    let style = style.map(|s| format!(r#" s="{s}""#)).unwrap_or_default();
+    let _ = write!(out, r#"<c r="{reference}"{style}><v>{value}</v></c>"#);
+}
+
+/// Zero-based column index to spreadsheet letters (0 -> A, 26 -> AA).
+fn column_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap_or_default()
+}
+
+/// Excel sheet names: max 31 chars, none of `[]:*?/\`.
+fn sheet_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+        .take(31)
+        .collect()
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => out.push('\u{FFFD}'),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// ZIP archive with stored entries and a fixed 1980-01-01 timestamp.
+#[derive(Default)]
+struct StoredZip {
+    body: Vec<u8>,
+    central: Vec<u8>,
+    entries: u16,
+}
+
+const ZIP_VERSION: u16 = 20;
+const ZIP_DOS_DATE: u16 = 0x0021;
+
+impl StoredZip {
+    fn add(&mut self, name: &str, data: &[u8]) {
+        let offset = self.body.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+        let name = name.as_bytes();
+
+        put_u32(&mut self.body, 0x0403_4b50);
+        put_u16(&mut self.body, ZIP_VERSION);
+        put_u16(&mut self.body, 0); // flags
+        put_u16(&mut self.body, 0); // method: stored
+        put_u16(&mut self.body, 0); // time
+        put_u16(&mut self.body, ZIP_DOS_DATE);
+        put_u32(&mut self.body, crc);
+        put_u32(&mut self.body, size);
+        put_u32(&mut self.body, size);
+        put_u16(&mut self.body, name.len() as u16);
+        put_u16(&mut self.body, 0); // extra length
+        self.body.extend_from_slice(name);
+        self.body.extend_from_slice(data);
+
+        put_u32(&mut self.central, 0x0201_4b50);
+        put_u16(&mut self.central, ZIP_VERSION); // made by
+        put_u16(&mut self.central, ZIP_VERSION); // needed
+        put_u16(&mut self.central, 0);
+        put_u16(&mut self.central, 0);
+        put_u16(&mut self.central, 0);
+        put_u16(&mut self.central, ZIP_DOS_DATE);
+        put_u32(&mut self.central, crc);
+        put_u32(&mut self.central, size);
+        put_u32(&mut self.central, size);
+        put_u16(&mut self.central, name.len() as u16);
+        put_u16(&mut self.central, 0); // extra length
+        put_u16(&mut self.central, 0); // comment length
+        put_u16(&mut self.central, 0); // disk start
+        put_u16(&mut self.central, 0); // internal attrs
+        put_u32(&mut self.central, 0); // external attrs
+        put_u32(&mut self.central, offset);
+        self.central.extend_from_slice(name);
+
+        self.entries += 1;
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        let central_offset = self.body.len() as u32;
+        let central_size = self.central.len() as u32;
+        self.body.extend_from_slice(&self.central);
+        put_u32(&mut self.body, 0x0605_4b50);
+        put_u16(&mut self.body, 0); // disk
+        put_u16(&mut self.body, 0); // central directory disk
+        put_u16(&mut self.body, self.entries);
+        put_u16(&mut self.body, self.entries);
+        put_u32(&mut self.body, central_size);
+        put_u32(&mut self.body, central_offset);
+        put_u16(&mut self.body, 0); // comment length
+        self.body
+    }
+}
+
+fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// CRC-32 (IEEE 802.3), as required by the ZIP format.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_sheet() -> Sheet {
+        Sheet {
+            name: "Changes".to_string(),
+            rows: vec![
+                vec![
+                    Cell::Header("Row".to_string()),
+                    Cell::Header("Share".to_string()),
+                ],
+                vec![Cell::Text("A & <B>".to_string()), Cell::Percent(0.5)],
+            ],
+            column_widths: vec![12.0, 10.0],
+            freeze_header: true,
+        }
+    }
+
+    #[test]
+    fn crc32_matches_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn column_letters_roll_over() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(27), "AB");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn sheet_xml_escapes_text_and_styles_cells() {
+        let xml = sheet_xml(&sample_sheet());
+        assert!(xml.contains(r#"<c r="A1" s="1" t="inlineStr">"#));
+        assert!(xml.contains("A &amp; &lt;B&gt;"));
+        assert!(xml.contains(r#"<c r="B2" s="2"><v>0.5</v></c>"#));
+        assert!(xml.contains(r#"state="frozen""#));
+    }
+
+    #[test]
+    fn workbook_is_deterministic_zip_with_all_parts() {
+        let sheets = vec![sample_sheet()];
+        let bytes = workbook_bytes(&sheets);
+        assert_eq!(bytes, workbook_bytes(&sheets));
+        assert_eq!(&bytes[..4], &[0x50, 0x4b, 0x03, 0x04]);
+
+        let end = &bytes[bytes.len() - 22..];
+        assert_eq!(&end[..4], &[0x50, 0x4b, 0x05, 0x06]);
+        let entries = u16::from_le_bytes([end[10], end[11]]);
+        assert_eq!(entries, 6);
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("xl/worksheets/sheet1.xml"));
+        assert!(text.contains("[Content_Types].xml"));
+    }
+}
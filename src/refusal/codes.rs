@@ -26,13 +26,17 @@ pub enum RefusalCode {
     AuditLimit,
     AuditFieldsRequiresExhaustive,
     AuditFieldsRequiresProfile,
+    Expected,
+    KeyMap,
+    HeaderOverlap,
+    Resource,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UnknownRefusalCode;
 
 impl RefusalCode {
-    pub const ALL: [RefusalCode; 22] = [
+    pub const ALL: [RefusalCode; 26] = [
         RefusalCode::Io,
         RefusalCode::Encoding,
         RefusalCode::CsvParse,
@@ -55,6 +59,10 @@ impl RefusalCode {
         RefusalCode::AuditLimit,
         RefusalCode::AuditFieldsRequiresExhaustive,
         RefusalCode::AuditFieldsRequiresProfile,
+        RefusalCode::Expected,
+        RefusalCode::KeyMap,
+        RefusalCode::HeaderOverlap,
+        RefusalCode::Resource,
     ];
 
     #[inline]
@@ -82,6 +90,10 @@ impl RefusalCode {
             RefusalCode::AuditLimit => "E_AUDIT_LIMIT",
             RefusalCode::AuditFieldsRequiresExhaustive => "E_AUDIT_FIELDS_REQUIRES_EXHAUSTIVE",
             RefusalCode::AuditFieldsRequiresProfile => "E_AUDIT_FIELDS_REQUIRES_PROFILE",
+            RefusalCode::Expected => "E_EXPECTED",
+            RefusalCode::KeyMap => "E_KEY_MAP",
+            RefusalCode::HeaderOverlap => "E_HEADER_OVERLAP",
+            RefusalCode::Resource => "E_RESOURCE",
         }
     }
 
@@ -111,6 +123,10 @@ impl RefusalCode {
             RefusalCode::AuditLimit => "audit output limit exceeded",
             RefusalCode::AuditFieldsRequiresExhaustive => "field audit requires exhaustive mode",
             RefusalCode::AuditFieldsRequiresProfile => "field audit requires an active profile",
+            RefusalCode::Expected => "expected baseline could not be loaded",
+            RefusalCode::KeyMap => "key map could not be applied",
+            RefusalCode::HeaderOverlap => "too few header names match between files",
+            RefusalCode::Resource => "estimated memory exceeds --max-memory",
         }
     }
 }
@@ -156,6 +172,10 @@ impl FromStr for RefusalCode {
             "E_AUDIT_LIMIT" => Ok(RefusalCode::AuditLimit),
             "E_AUDIT_FIELDS_REQUIRES_EXHAUSTIVE" => Ok(RefusalCode::AuditFieldsRequiresExhaustive),
             "E_AUDIT_FIELDS_REQUIRES_PROFILE" => Ok(RefusalCode::AuditFieldsRequiresProfile),
+            "E_EXPECTED" => Ok(RefusalCode::Expected),
+            "E_KEY_MAP" => Ok(RefusalCode::KeyMap),
+            "E_HEADER_OVERLAP" => Ok(RefusalCode::HeaderOverlap),
+            "E_RESOURCE" => Ok(RefusalCode::Resource),
             _ => Err(UnknownRefusalCode),
         }
     }
@@ -5,7 +5,10 @@
 //! raw bytes and should be rendered using the identifier formatters at output
 //! time.
 
+use crate::alignment::key_normalize::KeyNormalizeHint;
 use crate::format::ident_json::encode_identifier_json;
+use crate::numeric::columns::{CrossTabSignal, MixedColumnProfile};
+use crate::refusal::codes::RefusalCode;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileSide {
@@ -44,6 +47,14 @@ impl RefusalDetail {
         let next = kind.default_next(paths);
         Self { kind, next }
     }
+
+    /// Reason label for `code`, narrowed where one code covers distinct causes.
+    pub fn reason(&self, code: RefusalCode) -> &'static str {
+        match self.kind {
+            RefusalKind::DialectMismatch { .. } => "old and new use different delimiters",
+            _ => code.reason(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -83,6 +94,7 @@ pub enum RefusalKind {
         extra_in_new: usize,
         missing_samples: Vec<Vec<u8>>,
         extra_samples: Vec<Vec<u8>>,
+        normalize_hint: Option<KeyNormalizeHint>,
     },
     RowCount {
         rows_old: u64,
@@ -97,6 +109,11 @@ pub enum RefusalKind {
         tied_delimiters: Vec<u8>,
         suggestion: DialectSuggestion,
     },
+    /// Old and new were read with different delimiters (`--strict-dialect`).
+    DialectMismatch {
+        old_delimiter: u8,
+        new_delimiter: u8,
+    },
     AmbiguousProfile {
         profile_path: String,
         profile_id: String,
@@ -120,6 +137,10 @@ pub enum RefusalKind {
         column: Vec<u8>,
         value: Vec<u8>,
         key_value: Option<Vec<u8>>,
+        /// The column's type histogram and first non-numeric cells.
+        profile: MixedColumnProfile,
+        /// Set when the table looks like a pivoted grid (without `--cross-tab`).
+        cross_tab: Option<CrossTabSignal>,
     },
     NoNumeric,
     Missingness {
@@ -132,6 +153,8 @@ pub enum RefusalKind {
     Diffuse {
         top_k_coverage: f64,
         threshold: f64,
+        /// The least-explained column under `--coverage per-column`.
+        column: Option<Vec<u8>>,
     },
     AuditLimit {
         changed_cells: u64,
@@ -139,6 +162,29 @@ pub enum RefusalKind {
     },
     AuditFieldsRequiresExhaustive,
     AuditFieldsRequiresProfile,
+    Expected {
+        file: String,
+        reason: String,
+    },
+    KeyMap {
+        file: String,
+        reason: String,
+    },
+    HeaderOverlap {
+        columns_old: u64,
+        columns_new: u64,
+        columns_common: u64,
+        overlap: f64,
+        min_overlap: f64,
+        /// `--map` flags pairing likely renames, as in the "Did you mean" line.
+        map_flags: Vec<String>,
+    },
+    Resource {
+        estimated_bytes: u64,
+        max_memory: u64,
+        /// Estimate without the key join, when the run is in key mode.
+        row_order_bytes: Option<u64>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -146,6 +192,8 @@ pub enum EncodingIssue {
     Utf16,
     Utf32,
     NulByte,
+    /// Malformed UTF-16 under `--encoding`.
+    InvalidUtf16,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -153,6 +201,7 @@ pub enum HeadersIssue {
     MissingHeader,
     Duplicate { name: Vec<u8> },
     ExtraFields { record: u64 },
+    MissingColumn { name: Vec<u8> },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -180,6 +229,10 @@ impl RefusalKind {
     pub fn default_next(&self, paths: RerunPaths<'_>) -> String {
         match self {
             RefusalKind::Io { .. } => "check file paths/permissions and rerun".to_string(),
+            RefusalKind::Encoding {
+                issue: EncodingIssue::Utf16,
+                ..
+            } => format!("rvl {} {} --encoding auto", paths.old, paths.new),
             RefusalKind::Encoding { .. } => {
                 "convert/re-export both files as UTF-8 CSV and rerun".to_string()
             }
@@ -195,6 +248,9 @@ impl RefusalKind {
                     "remove extra columns or re-export with consistent headers, then rerun"
                         .to_string()
                 }
+                HeadersIssue::MissingColumn { .. } => {
+                    "name a column present in both files and rerun".to_string()
+                }
             },
             RefusalKind::NoKey { key_column } => {
                 let key = encode_identifier_json(key_column);
@@ -205,10 +261,19 @@ impl RefusalKind {
                     .to_string()
             }
             RefusalKind::KeyDup { .. } => {
-                "choose a unique key column or dedupe the data, then rerun".to_string()
+                "choose a unique key column or dedupe the data, then rerun (or add --dedup sum|first to collapse repeated keys)"
+                    .to_string()
             }
+            RefusalKind::KeyMismatch {
+                normalize_hint: Some(hint),
+                ..
+            } => format!(
+                "rerun with --key-normalize {} (or add --allow-missing-keys to compare the common keys)",
+                hint.step.as_str()
+            ),
             RefusalKind::KeyMismatch { .. } => {
-                "export comparable scopes or fix the join key, then rerun".to_string()
+                "export comparable scopes or fix the join key, then rerun (or add --allow-missing-keys to compare the common keys)"
+                    .to_string()
             }
             RefusalKind::RowCount { suggested_keys, .. } => {
                 if let Some(key) = suggested_keys.first() {
@@ -255,6 +320,12 @@ impl RefusalKind {
                     }
                 }
             },
+            // Each file may legitimately use its own delimiter, so a single
+            // `--delimiter` for both is never the fix.
+            RefusalKind::DialectMismatch { .. } => format!(
+                "the files use different delimiters; re-export one to match the other, or rerun `rvl {} {}` without --strict-dialect to accept per-file detection",
+                paths.old, paths.new
+            ),
             RefusalKind::AmbiguousProfile { .. } => {
                 "provide exactly one profile selector (--profile OR --profile-id) and rerun"
                     .to_string()
@@ -271,14 +342,20 @@ impl RefusalKind {
                 "remove --key (profile already defines key) or use a profile without a key"
                     .to_string()
             }
+            RefusalKind::MixedTypes {
+                cross_tab: Some(_), ..
+            } => {
+                "this looks like a pivoted/cross-tab table; rerun with --cross-tab to type each cell independently, or reshape it to one row per record".to_string()
+            }
             RefusalKind::MixedTypes { .. } => {
-                "normalize column values to numeric (or exclude the column) and rerun. Hint: if this is a cross-tab where row labels determine value types, reshape to long-form or split into typed sections before comparison".to_string()
+                "normalize column values to numeric (or rerun with --on-mixed-types exclude to drop the column). Hint: if this is a cross-tab where row labels determine value types, rerun with --cross-tab to type each cell independently".to_string()
             }
             RefusalKind::NoNumeric => {
                 "ensure common numeric columns exist (or adjust inputs) and rerun".to_string()
             }
             RefusalKind::Missingness { .. } => {
-                "fill missing values or remove the column, then rerun".to_string()
+                "fill missing values or remove the column, then rerun (or rerun with --on-missing skip|zero)"
+                    .to_string()
             }
             RefusalKind::Diffuse { .. } => {
                 format!("rvl {} {} --threshold 0.80", paths.old, paths.new)
@@ -295,6 +372,38 @@ impl RefusalKind {
             RefusalKind::AuditFieldsRequiresProfile => {
                 "rerun with --exhaustive --audit-fields and an explicit --profile <path> or --profile-id <id>".to_string()
             }
+            RefusalKind::HeaderOverlap { map_flags, .. } if !map_flags.is_empty() => format!(
+                "rvl {} {} {} (or compare only the shared columns: rvl {} {} --min-header-overlap 0)",
+                paths.old,
+                paths.new,
+                map_flags.join(" "),
+                paths.old,
+                paths.new
+            ),
+            RefusalKind::HeaderOverlap { .. } => format!(
+                "pair renamed columns with --map OLD=NEW: rvl {} {} --map OLD=NEW (or compare only the shared columns: rvl {} {} --min-header-overlap 0)",
+                paths.old, paths.new, paths.old, paths.new
+            ),
+            RefusalKind::Resource {
+                estimated_bytes,
+                row_order_bytes,
+                ..
+            } => match row_order_bytes {
+                Some(row_order) => format!(
+                    "compare in row-order mode, which skips the key join: rvl {} {} --max-memory {row_order} (or rerun with --max-memory {estimated_bytes} on a machine with that much memory)",
+                    paths.old, paths.new
+                ),
+                None => format!(
+                    "rerun on a machine with more memory: rvl {} {} --max-memory {estimated_bytes}",
+                    paths.old, paths.new
+                ),
+            },
+            RefusalKind::KeyMap { .. } => {
+                "fix --key-map (a header row, then one old_key,new_key pair per line) and use it with --key, then rerun".to_string()
+            }
+            RefusalKind::Expected { .. } => {
+                "point --expected at a saved `rvl --json` REAL_CHANGE or NO_REAL_CHANGE result, then rerun".to_string()
+            }
         }
     }
 }
@@ -7,6 +7,7 @@
 use std::error::Error;
 use std::fmt;
 
+use crate::alignment::key_parse::KeyParseError;
 use crate::refusal::codes::RefusalCode;
 
 /// A domain refusal (E_*), distinct from process-level failures.
@@ -119,6 +120,24 @@ impl From<ProcessError> for PipelineError {
     }
 }
 
+impl From<std::io::Error> for PipelineError {
+    fn from(err: std::io::Error) -> Self {
+        PipelineError::Process(ProcessError::new(err.to_string()))
+    }
+}
+
+impl From<serde_json::Error> for PipelineError {
+    fn from(err: serde_json::Error) -> Self {
+        PipelineError::Process(ProcessError::new(err.to_string()))
+    }
+}
+
+impl From<KeyParseError> for PipelineError {
+    fn from(err: KeyParseError) -> Self {
+        PipelineError::Process(ProcessError::new(err.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,4 +164,14 @@ mod tests {
         assert_eq!(process.as_process().unwrap().message(), "panic");
         assert!(process.as_refusal().is_none());
     }
+
+    #[test]
+    fn io_and_key_parse_errors_are_process_errors() {
+        let io = PipelineError::from(std::io::Error::other("broken pipe"));
+        assert_eq!(io.as_process().unwrap().message(), "broken pipe");
+
+        let key = PipelineError::from(KeyParseError::InvalidHex);
+        assert!(key.is_process());
+        assert_eq!(key.to_string(), "invalid hex key identifier");
+    }
 }
@@ -0,0 +1,448 @@
+//! Three-way comparison (`rvl --base BASE ours.csv theirs.csv`).
+//!
+//! Both edited files are compared against the shared base with the regular
+//! pipeline in exhaustive mode, so every changed numeric cell is listed, not
+//! just the explaining prefix. Cells are then classified by who changed them:
+//! ours only, theirs only, or both. A cell both sides changed is a conflict
+//! when their new values differ by more than `--tolerance`.
+//!
+//! The verdict is the most severe side verdict: any refusal, then any real
+//! change, otherwise no real change. Values are read internally to detect
+//! conflicts but only reported with `--explicit`, so each side is summarized
+//! (outcome, counts, metrics, refusal) rather than embedding its full report.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::alignment::key_parse::parse_key_identifier;
+use crate::cli::args::Args;
+use crate::cli::exit::{Outcome, exit_code};
+use crate::csv::source::source_label;
+use crate::format::ident_human::render_identifier_human;
+use crate::format::numbers::format_float_shortest;
+use crate::output::json::{Contributor, Counts, JsonOutput, Metrics, Refusal};
+use crate::refusal::process::{PipelineError, ProcessError};
+
+const THREE_WAY_SCHEMA_VERSION: &str = "rvl.three_way.v0";
+
+/// Cell lines shown per section in human output.
+const HUMAN_CELL_LIMIT: usize = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CellChange {
+    OursOnly,
+    TheirsOnly,
+    /// Both sides changed the cell to the same value (within tolerance).
+    Both,
+    /// Both sides changed the cell to different values.
+    Conflict,
+}
+
+impl CellChange {
+    fn label(self) -> &'static str {
+        match self {
+            CellChange::OursOnly => "ours only",
+            CellChange::TheirsOnly => "theirs only",
+            CellChange::Both => "both (same value)",
+            CellChange::Conflict => "conflict",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreeWayCell {
+    pub row_id: String,
+    pub column: String,
+    pub change: CellChange,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theirs: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ThreeWayCounts {
+    pub ours_only: u64,
+    pub theirs_only: u64,
+    pub both: u64,
+    pub conflicts: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreeWayFiles {
+    pub base: String,
+    pub ours: String,
+    pub theirs: String,
+}
+
+/// One edited file's comparison against the base.
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreeWaySide {
+    pub outcome: &'static str,
+    pub counts: Counts,
+    pub metrics: Metrics,
+    pub refusal: Option<Refusal>,
+}
+
+impl ThreeWaySide {
+    fn from_report(report: &JsonOutput, verdict: Outcome) -> Self {
+        Self {
+            outcome: verdict.as_str(),
+            counts: report.counts.clone(),
+            metrics: report.metrics.clone(),
+            refusal: report.refusal.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreeWaySides {
+    pub ours: ThreeWaySide,
+    pub theirs: ThreeWaySide,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreeWayReport {
+    pub version: &'static str,
+    pub outcome: &'static str,
+    pub files: ThreeWayFiles,
+    pub counts: ThreeWayCounts,
+    pub cells: Vec<ThreeWayCell>,
+    pub sides: ThreeWaySides,
+    #[serde(skip)]
+    pub verdict: Outcome,
+}
+
+/// Run a three-way comparison. Exit codes follow the verdict (0/1/2).
+pub fn run(args: &Args) -> Result<u8, PipelineError> {
+    let report = compare(args)?;
+    if args.json {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report, args));
+    }
+    Ok(exit_code(report.verdict))
+}
+
+/// Compare ours and theirs against `--base` and classify every changed cell.
+pub fn compare(args: &Args) -> Result<ThreeWayReport, PipelineError> {
+    let base = args
+        .base
+        .clone()
+        .ok_or_else(|| ProcessError::new("three-way comparison requires --base"))?;
+    let (ours, ours_verdict) = run_side(args, &base, args.old_path())?;
+    let (theirs, theirs_verdict) = run_side(args, &base, args.new_path())?;
+
+    let mut verdict = Outcome::NoRealChange;
+    for side in [ours_verdict, theirs_verdict] {
+        if exit_code(side) > exit_code(verdict) {
+            verdict = side;
+        }
+    }
+    let cells = if verdict == Outcome::Refusal {
+        Vec::new()
+    } else {
+        classify(&ours.contributors, &theirs.contributors, args.tolerance)
+    };
+    let mut counts = ThreeWayCounts::default();
+    for cell in &cells {
+        match cell.change {
+            CellChange::OursOnly => counts.ours_only += 1,
+            CellChange::TheirsOnly => counts.theirs_only += 1,
+            CellChange::Both => counts.both += 1,
+            CellChange::Conflict => counts.conflicts += 1,
+        }
+    }
+    let cells = if args.explicit {
+        cells
+    } else {
+        cells.into_iter().map(redact).collect()
+    };
+
+    Ok(ThreeWayReport {
+        version: THREE_WAY_SCHEMA_VERSION,
+        outcome: verdict.as_str(),
+        files: ThreeWayFiles {
+            base: base.to_string_lossy().to_string(),
+            ours: args.old_path().to_string_lossy().to_string(),
+            theirs: args.new_path().to_string_lossy().to_string(),
+        },
+        counts,
+        cells,
+        sides: ThreeWaySides {
+            ours: ThreeWaySide::from_report(&ours, ours_verdict),
+            theirs: ThreeWaySide::from_report(&theirs, theirs_verdict),
+        },
+        verdict,
+    })
+}
+
+/// Compare one edited file against the base. Values are always requested so
+/// conflicts can be detected; redaction happens on the combined report.
+fn run_side(
+    args: &Args,
+    base: &Path,
+    edited: &Path,
+) -> Result<(Box<JsonOutput>, Outcome), PipelineError> {
+    let mut side = args.clone();
+    side.old = Some(base.to_path_buf());
+    side.new = Some(edited.to_path_buf());
+    side.base = None;
+    side.json = true;
+    side.exhaustive = true;
+    side.explicit = true;
+    side.capsule_out = None;
+    side.explore = None;
+    side.report_xlsx = None;
+    side.report_csv = None;
+    side.events = None;
+    side.expected = None;
+    let result = crate::orchestrator::run(&side)?;
+    let outcome = result.outcome;
+    Ok((result.into_report()?, outcome))
+}
+
+fn classify(ours: &[Contributor], theirs: &[Contributor], tolerance: f64) -> Vec<ThreeWayCell> {
+    let mut cells: BTreeMap<(&str, &str), ThreeWayCell> = BTreeMap::new();
+    for contributor in ours {
+        cells.insert(
+            (contributor.row_id.as_str(), contributor.column.as_str()),
+            ThreeWayCell {
+                row_id: contributor.row_id.clone(),
+                column: contributor.column.clone(),
+                change: CellChange::OursOnly,
+                base: contributor.old,
+                ours: contributor.new,
+                theirs: contributor.old,
+            },
+        );
+    }
+    for contributor in theirs {
+        let key = (contributor.row_id.as_str(), contributor.column.as_str());
+        match cells.get_mut(&key) {
+            Some(cell) => {
+                cell.theirs = contributor.new;
+                cell.change = match (cell.ours, cell.theirs) {
+                    (Some(ours), Some(theirs)) if (ours - theirs).abs() > tolerance => {
+                        CellChange::Conflict
+                    }
+                    _ => CellChange::Both,
+                };
+            }
+            None => {
+                cells.insert(
+                    key,
+                    ThreeWayCell {
+                        row_id: contributor.row_id.clone(),
+                        column: contributor.column.clone(),
+                        change: CellChange::TheirsOnly,
+                        base: contributor.old,
+                        ours: contributor.old,
+                        theirs: contributor.new,
+                    },
+                );
+            }
+        }
+    }
+    cells.into_values().collect()
+}
+
+fn redact(cell: ThreeWayCell) -> ThreeWayCell {
+    ThreeWayCell {
+        base: None,
+        ours: None,
+        theirs: None,
+        ..cell
+    }
+}
+
+fn render_human(report: &ThreeWayReport, args: &Args) -> String {
+    let mut lines = vec![
+        "RVL THREE-WAY".to_string(),
+        String::new(),
+        format!(
+            "Compared base {} -> ours {}, theirs {}",
+            source_label(Path::new(&report.files.base)),
+            source_label(Path::new(&report.files.ours)),
+            source_label(Path::new(&report.files.theirs)),
+        ),
+        format!("Verdict: {}", report.outcome.replace('_', " ")),
+    ];
+    if report.verdict == Outcome::Refusal {
+        let sides = [
+            ("ours", &report.files.ours, &report.sides.ours),
+            ("theirs", &report.files.theirs, &report.sides.theirs),
+        ];
+        lines.push(String::new());
+        for (name, path, side) in sides {
+            if let Some(refusal) = side.refusal.as_ref() {
+                lines.push(format!("{name}: {} ({})", refusal.code, refusal.message));
+                lines.push(format!(
+                    "  Next: rvl {} {path} for the full refusal and its next step",
+                    report.files.base
+                ));
+            }
+        }
+        return lines.join("\n");
+    }
+
+    let counts = &report.counts;
+    lines.push(String::new());
+    lines.push(format!(
+        "Changed cells: {} ours only, {} theirs only, {} both (same value), {} conflicts",
+        counts.ours_only, counts.theirs_only, counts.both, counts.conflicts
+    ));
+    for change in [
+        CellChange::Conflict,
+        CellChange::OursOnly,
+        CellChange::TheirsOnly,
+        CellChange::Both,
+    ] {
+        let cells: Vec<&ThreeWayCell> = report
+            .cells
+            .iter()
+            .filter(|cell| cell.change == change)
+            .collect();
+        if cells.is_empty() {
+            continue;
+        }
+        lines.push(String::new());
+        lines.push(format!("{} ({}):", capitalize(change.label()), cells.len()));
+        for cell in cells.iter().take(HUMAN_CELL_LIMIT) {
+            lines.push(render_cell(cell, args.explicit));
+        }
+        if cells.len() > HUMAN_CELL_LIMIT {
+            lines.push(format!(
+                "  ... {} more (use --json for the full list)",
+                cells.len() - HUMAN_CELL_LIMIT
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+fn render_cell(cell: &ThreeWayCell, explicit: bool) -> String {
+    let location = format!(
+        "  {}  {}",
+        human_identifier(&cell.row_id),
+        human_identifier(&cell.column)
+    );
+    match (explicit, cell.base, cell.ours, cell.theirs) {
+        (true, Some(base), Some(ours), Some(theirs)) => format!(
+            "{location}  base {}  ours {}  theirs {}",
+            format_float_shortest(base),
+            format_float_shortest(ours),
+            format_float_shortest(theirs)
+        ),
+        _ => location,
+    }
+}
+
+/// Cells carry JSON-encoded identifiers (`u8:`/`hex:`); human output shows
+/// them the way the rest of the human renderers do.
+fn human_identifier(encoded: &str) -> String {
+    parse_key_identifier(encoded)
+        .map(|bytes| render_identifier_human(&bytes))
+        .unwrap_or_else(|_| encoded.to_string())
+}
+
+fn capitalize(label: &str) -> String {
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contributor(row_id: &str, column: &str, old: f64, new: f64) -> Contributor {
+        Contributor::from_bytes(
+            row_id.as_bytes(),
+            column.as_bytes(),
+            old,
+            new,
+            new - old,
+            (new - old).abs(),
+            0.0,
+            0.0,
+            true,
+        )
+    }
+
+    #[test]
+    fn classifies_cells_by_which_side_changed_them() {
+        let ours = [
+            contributor("A", "amount", 10.0, 11.0),
+            contributor("B", "amount", 20.0, 25.0),
+            contributor("C", "amount", 30.0, 31.0),
+        ];
+        let theirs = [
+            contributor("B", "amount", 20.0, 25.0),
+            contributor("C", "amount", 30.0, 35.0),
+            contributor("D", "amount", 40.0, 41.0),
+        ];
+        let cells = classify(&ours, &theirs, 1e-9);
+        let changes: Vec<(&str, CellChange)> = cells
+            .iter()
+            .map(|cell| (cell.row_id.as_str(), cell.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("u8:A", CellChange::OursOnly),
+                ("u8:B", CellChange::Both),
+                ("u8:C", CellChange::Conflict),
+                ("u8:D", CellChange::TheirsOnly),
+            ]
+        );
+        assert_eq!(
+            (cells[0].base, cells[0].ours, cells[0].theirs),
+            (Some(10.0), Some(11.0), Some(10.0))
+        );
+        assert_eq!((cells[2].ours, cells[2].theirs), (Some(31.0), Some(35.0)));
+    }
+
+    #[test]
+    fn human_cells_show_plain_identifiers() {
+        let cells = classify(&[contributor("A", "amount", 10.0, 11.0)], &[], 1e-9);
+        assert_eq!(render_cell(&cells[0], false), "  A  amount");
+        assert_eq!(
+            render_cell(&cells[0], true),
+            "  A  amount  base 10  ours 11  theirs 10"
+        );
+    }
+
+    #[test]
+    fn compare_without_base_is_a_process_error() {
+        let args = Args::new(
+            "ours.csv".into(),
+            "theirs.csv".into(),
+            None,
+            0.95,
+            0.0,
+            None,
+            false,
+        );
+        let err = compare(&args).expect_err("--base is required");
+        assert!(err.is_process());
+    }
+
+    #[test]
+    fn same_edit_within_tolerance_is_not_a_conflict() {
+        let ours = [contributor("A", "amount", 10.0, 11.0)];
+        let theirs = [contributor("A", "amount", 10.0, 11.005)];
+        assert_eq!(classify(&ours, &theirs, 0.01)[0].change, CellChange::Both);
+        assert_eq!(
+            classify(&ours, &theirs, 0.001)[0].change,
+            CellChange::Conflict
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Pipeline phase spans (`tracing` feature).
+//!
+//! With the feature enabled, a comparison runs inside an `rvl.compare` span,
+//! and each phase (`rvl.parse` and `rvl.dialect` per file, then
+//! `rvl.align`, `rvl.typing`, and `rvl.diff`) opens an INFO span that
+//! records the phase's counts as fields and closes with a `phase complete`
+//! event carrying `elapsed_ms`. rvl never installs a subscriber: the CLI
+//! emits nothing, and an embedder sees the phases through whatever
+//! subscriber it already runs. Without the feature, phases are zero-sized
+//! and every call compiles away.
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+/// Open a phase span named `rvl.<name>` with the given (initially empty)
+/// count fields; the phase ends when the returned guard drops.
+#[cfg(feature = "tracing")]
+macro_rules! phase_span {
+    ($name:literal $(, $field:ident)*) => {
+        $crate::trace::Phase::enter(::tracing::info_span!(
+            concat!("rvl.", $name)
+            $(, $field = ::tracing::field::Empty)*
+        ))
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! phase_span {
+    ($name:literal $(, $field:ident)*) => {
+        $crate::trace::Phase::disabled()
+    };
+}
+
+pub(crate) use phase_span;
+
+/// An open phase; see [`phase_span!`].
+pub(crate) struct Phase {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+    #[cfg(feature = "tracing")]
+    started: Instant,
+}
+
+impl Phase {
+    #[cfg(feature = "tracing")]
+    pub(crate) fn enter(span: tracing::Span) -> Self {
+        Self {
+            span: span.entered(),
+            started: Instant::now(),
+        }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn disabled() -> Self {
+        Self {}
+    }
+
+    /// Record a count declared when the phase was opened.
+    pub(crate) fn count(&self, field: &'static str, value: u64) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        let _ = (field, value);
+    }
+
+    /// Record a short label (a file side, a delimiter, an outcome).
+    pub(crate) fn label(&self, field: &'static str, value: &str) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        let _ = (field, value);
+    }
+}
+
+impl Drop for Phase {
+    fn drop(&mut self) {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0,
+            "phase complete"
+        );
+    }
+}
+
+/// The caller's current span, re-entered on a worker thread so its phases
+/// nest under the run.
+#[derive(Clone)]
+pub(crate) struct Parent {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl Parent {
+    pub(crate) fn current() -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    pub(crate) fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        self.span.in_scope(f)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        f()
+    }
+}
@@ -0,0 +1,142 @@
+//! Re-run a comparison whenever either input changes (`rvl watch`).
+//!
+//! rvl has no filesystem-notification dependency, so watch polls each
+//! file's size and modification time. A change triggers a re-run once the
+//! file has stopped changing for one poll, so a half-written export is not
+//! compared. Each run prints a header line and then the regular output.
+//! Watch runs until interrupted and records no witness entries; iteration
+//! runs are not deliveries.
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::cli::args::Args;
+use crate::cli::exit::{OutputMode, OutputStream};
+use crate::orchestrator;
+use crate::refusal::process::PipelineError;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// What watch compares between polls: size and modification time, or
+/// `None` while the file is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint(Option<(u64, Option<SystemTime>)>);
+
+impl Fingerprint {
+    pub fn of(path: &Path) -> Self {
+        Self(
+            std::fs::metadata(path)
+                .ok()
+                .map(|meta| (meta.len(), meta.modified().ok())),
+        )
+    }
+}
+
+/// Run `rvl watch`. Only returns on an output write failure; process
+/// errors from a run (e.g. a file deleted mid-export) are printed and
+/// watching continues.
+pub fn run(args: &Args) -> Result<u8, PipelineError> {
+    let (Some(old), Some(new)) = (args.old.as_deref(), args.new.as_deref()) else {
+        return Ok(2);
+    };
+    let mut seen = [Fingerprint::of(old), Fingerprint::of(new)];
+    let mut runs = 0u64;
+    loop {
+        runs += 1;
+        eprintln!(
+            "rvl watch: run {runs}: {} vs {}",
+            old.display(),
+            new.display()
+        );
+        run_once(args)?;
+        eprintln!("rvl watch: waiting for changes (Ctrl-C to stop)");
+        seen = wait_for_change(&[old, new], seen, POLL_INTERVAL);
+    }
+}
+
+fn run_once(args: &Args) -> Result<(), PipelineError> {
+    let result = match orchestrator::run(args) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("rvl: {err}");
+            return Ok(());
+        }
+    };
+    let mode = if args.json || args.format.is_some() {
+        OutputMode::Json
+    } else {
+        OutputMode::Human
+    };
+    match args.output_stream.resolve(result.outcome, mode) {
+        OutputStream::Stdout => {
+            let mut stdout = io::stdout();
+            stdout.write_all(result.output.as_bytes())?;
+            stdout.flush()?;
+        }
+        OutputStream::Stderr => {
+            let mut stderr = io::stderr();
+            stderr.write_all(result.output.as_bytes())?;
+            stderr.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Block until any path's fingerprint differs from `seen` and then holds
+/// steady for one more poll; returns the settled fingerprints.
+pub fn wait_for_change(
+    paths: &[&Path; 2],
+    seen: [Fingerprint; 2],
+    interval: Duration,
+) -> [Fingerprint; 2] {
+    let current = || paths.map(Fingerprint::of);
+    let mut last = seen;
+    loop {
+        thread::sleep(interval);
+        let now = current();
+        if now != seen && now == last {
+            return now;
+        }
+        last = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn wait_for_change_returns_after_a_write_settles() {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("rvl_watch_{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        let old = dir.join("old.csv");
+        let new = dir.join("new.csv");
+        fs::write(&old, "id,amount\nA,1\n").unwrap();
+        fs::write(&new, "id,amount\nA,1\n").unwrap();
+        let seen = [Fingerprint::of(&old), Fingerprint::of(&new)];
+
+        let writer = {
+            let new = new.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(30));
+                fs::write(&new, "id,amount\nA,12\n").unwrap();
+            })
+        };
+        let settled = wait_for_change(&[&old, &new], seen, Duration::from_millis(10));
+        writer.join().unwrap();
+
+        assert_eq!(settled[0], seen[0]);
+        assert_ne!(settled[1], seen[1]);
+        assert_eq!(settled[1], Fingerprint::of(&new));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -9,9 +9,10 @@ use crate::orchestrator::PipelineResult;
 
 /// Build and append a witness record for a completed rvl run.
 ///
-/// Re-reads both input files to hash their contents. All errors are
-/// swallowed and logged to stderr — witness recording must never
-/// affect the tool's primary exit code or output.
+/// Re-reads both inputs to hash their contents (stdin and other streams
+/// come from the in-process copy). All errors are swallowed and logged to
+/// stderr — witness recording must never affect the tool's primary exit
+/// code or output.
 pub fn record_run(args: &Args, result: &PipelineResult) {
     let writer = match ledger::LedgerWriter::open() {
         Ok(w) => w,
@@ -33,8 +34,8 @@ fn record_run_with_writer(
     result: &PipelineResult,
     writer: &ledger::LedgerWriter,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let old_bytes = std::fs::read(args.old_path())?;
-    let new_bytes = std::fs::read(args.new_path())?;
+    let old_bytes = args.old_source().read()?;
+    let new_bytes = args.new_source().read()?;
 
     let old_path = args.old_path().to_string_lossy().to_string();
     let new_path = args.new_path().to_string_lossy().to_string();
@@ -79,6 +80,9 @@ mod tests {
             outcome,
             output: "test output".to_string(),
             profile: crate::orchestrator::ProfileRunInfo::default(),
+            report: None,
+            timings: Default::default(),
+            refusal: None,
         }
     }
 
@@ -334,6 +338,9 @@ mod tests {
             outcome: Outcome::RealChange,
             output: output_text.to_string(),
             profile: crate::orchestrator::ProfileRunInfo::default(),
+            report: None,
+            timings: Default::default(),
+            refusal: None,
         };
         record_run_with_writer(&args, &result, &writer).unwrap();
 
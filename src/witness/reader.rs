@@ -97,6 +97,9 @@ mod tests {
             outcome: pipeline_outcome,
             output: "test output".to_string(),
             profile: crate::orchestrator::ProfileRunInfo::default(),
+            report: None,
+            timings: Default::default(),
+            refusal: None,
         };
         let mut rec = WitnessRecord::from_run(&args, &result, b"old", b"new", "old.csv", "new.csv");
         rec.ts = ts.to_string();
@@ -140,6 +140,17 @@ impl WitnessRecord {
         );
         params.insert("threshold".to_string(), serde_json::json!(args.threshold));
         params.insert("tolerance".to_string(), serde_json::json!(args.tolerance));
+        if !args.param_sources.is_empty() {
+            let sources: serde_json::Map<String, serde_json::Value> = args
+                .param_sources
+                .iter()
+                .map(|(id, source)| (id.to_string(), serde_json::json!(source.as_str())))
+                .collect();
+            params.insert(
+                "param_sources".to_string(),
+                serde_json::Value::Object(sources),
+            );
+        }
         let params = serde_json::Value::Object(params);
 
         let ts = {
@@ -222,6 +233,9 @@ mod tests {
             outcome,
             output: "test output".to_string(),
             profile: crate::orchestrator::ProfileRunInfo::default(),
+            report: None,
+            timings: Default::default(),
+            refusal: None,
         }
     }
 
@@ -517,4 +531,23 @@ mod tests {
         // 2026-02-24 = day 20508
         assert_eq!(days_to_date(20508), (2026, 2, 24));
     }
+
+    #[test]
+    fn params_record_sources_only_for_parsed_args() {
+        let result = make_result(Outcome::NoRealChange);
+        let direct = WitnessRecord::from_run(
+            &make_args(None, false),
+            &result,
+            b"a",
+            b"b",
+            "a.csv",
+            "b.csv",
+        );
+        assert!(direct.params.get("param_sources").is_none());
+
+        let parsed = Args::parse_from(["rvl", "a.csv", "b.csv", "--key", "id"]).unwrap();
+        let rec = WitnessRecord::from_run(&parsed, &result, b"a", b"b", "a.csv", "b.csv");
+        assert_eq!(rec.params["param_sources"]["key"], "cli");
+        assert_eq!(rec.params["param_sources"]["tolerance"], "default");
+    }
 }
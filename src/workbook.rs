@@ -0,0 +1,278 @@
+//! Sheet-by-sheet comparison of Excel workbooks (`rvl xlsx`).
+//!
+//! Each worksheet is extracted to CSV in memory and handed to the regular
+//! comparison pipeline as an in-memory input labelled `<workbook>[<sheet>]`,
+//! so every sheet pair runs through it unchanged. Sheets are paired by exact name.
+//! The workbook verdict is the most severe sheet verdict: any refusal, then
+//! any real change, otherwise no real change. Sheets present in only one
+//! workbook are listed but do not change the verdict.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::args::{Args, XlsxArgs};
+use crate::cli::exit::{Outcome, exit_code};
+use crate::csv::source::InlineInputs;
+use crate::refusal::process::PipelineError;
+use crate::xlsx::{Worksheet, read_workbook};
+
+const WORKBOOK_SCHEMA_VERSION: &str = "rvl.workbook.v0";
+
+/// Comparison result for one sheet present in both workbooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct SheetResult {
+    pub name: String,
+    pub outcome: &'static str,
+    /// The sheet's full `rvl.v0` report.
+    pub result: Value,
+    #[serde(skip)]
+    pub human: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkbookFiles {
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkbookReport {
+    pub version: &'static str,
+    pub outcome: &'static str,
+    pub files: WorkbookFiles,
+    pub sheets: Vec<SheetResult>,
+    pub old_only: Vec<String>,
+    pub new_only: Vec<String>,
+    #[serde(skip)]
+    pub verdict: Outcome,
+}
+
+/// Run `rvl xlsx`. Exit codes follow the workbook verdict (0/1/2); an
+/// unreadable workbook also exits 2.
+pub fn run(args: &XlsxArgs, json_output: bool) -> Result<u8, PipelineError> {
+    let (old, new) = match (load(&args.old), load(&args.new)) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("rvl: {err}");
+            return Ok(2);
+        }
+    };
+
+    let report = compare(args, &old, &new)?;
+    if args.json || json_output {
+        println!("{}", serde_json::to_string(&report)?);
+    } else {
+        println!("{}", render_human(&report));
+    }
+    Ok(exit_code(report.verdict))
+}
+
+fn load(path: &Path) -> Result<Vec<Worksheet>, String> {
+    let bytes = fs::read(path).map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+    let sheets = read_workbook(&bytes)
+        .map_err(|reason| format!("cannot read workbook {}: {reason}", path.display()))?;
+    if sheets.is_empty() {
+        return Err(format!("workbook {} has no sheets", path.display()));
+    }
+    Ok(sheets)
+}
+
+/// Pair sheets and compare each pair with the regular pipeline.
+pub fn compare(
+    args: &XlsxArgs,
+    old: &[Worksheet],
+    new: &[Worksheet],
+) -> Result<WorkbookReport, PipelineError> {
+    let (pairs, old_only, new_only) = if args.all_sheets {
+        pair_by_name(old, new)
+    } else {
+        (
+            old.first().zip(new.first()).into_iter().collect(),
+            Vec::new(),
+            Vec::new(),
+        )
+    };
+
+    let mut sheets = Vec::with_capacity(pairs.len());
+    let mut verdict = Outcome::NoRealChange;
+    for (old_sheet, new_sheet) in pairs {
+        let old_path = sheet_path(&args.old, &old_sheet.name);
+        let new_path = sheet_path(&args.new, &new_sheet.name);
+        let inline = InlineInputs {
+            old: Some(old_sheet.to_csv().into()),
+            new: Some(new_sheet.to_csv().into()),
+        };
+
+        let human = run_sheet(args, old_path.clone(), new_path.clone(), &inline, false)?;
+        let json = run_sheet(args, old_path, new_path, &inline, true)?;
+        if exit_code(json.outcome) > exit_code(verdict) {
+            verdict = json.outcome;
+        }
+        sheets.push(SheetResult {
+            name: old_sheet.name.clone(),
+            outcome: json.outcome.as_str(),
+            result: serde_json::to_value(&json.report)?,
+            human: human.output,
+        });
+    }
+
+    Ok(WorkbookReport {
+        version: WORKBOOK_SCHEMA_VERSION,
+        outcome: verdict.as_str(),
+        files: WorkbookFiles {
+            old: args.old.to_string_lossy().to_string(),
+            new: args.new.to_string_lossy().to_string(),
+        },
+        sheets,
+        old_only,
+        new_only,
+        verdict,
+    })
+}
+
+type SheetPairs<'a> = Vec<(&'a Worksheet, &'a Worksheet)>;
+
+fn pair_by_name<'a>(
+    old: &'a [Worksheet],
+    new: &'a [Worksheet],
+) -> (SheetPairs<'a>, Vec<String>, Vec<String>) {
+    let find = |sheets: &'a [Worksheet], name: &str| sheets.iter().find(|sheet| sheet.name == name);
+    let pairs = old
+        .iter()
+        .filter_map(|sheet| Some((sheet, find(new, &sheet.name)?)))
+        .collect();
+    let old_only = old
+        .iter()
+        .filter(|sheet| find(new, &sheet.name).is_none())
+        .map(|sheet| sheet.name.clone())
+        .collect();
+    let new_only = new
+        .iter()
+        .filter(|sheet| find(old, &sheet.name).is_none())
+        .map(|sheet| sheet.name.clone())
+        .collect();
+    (pairs, old_only, new_only)
+}
+
+fn sheet_path(workbook: &Path, sheet: &str) -> PathBuf {
+    let mut path = workbook.as_os_str().to_owned();
+    path.push(format!("[{sheet}]"));
+    PathBuf::from(path)
+}
+
+fn run_sheet(
+    args: &XlsxArgs,
+    old: PathBuf,
+    new: PathBuf,
+    inline: &InlineInputs,
+    json: bool,
+) -> Result<crate::orchestrator::PipelineResult, PipelineError> {
+    let mut compare_args = Args::new(
+        old,
+        new,
+        args.key.clone(),
+        args.threshold,
+        args.tolerance,
+        None,
+        json,
+    );
+    compare_args.inline = inline.clone();
+    crate::orchestrator::run(&compare_args)
+}
+
+fn render_human(report: &WorkbookReport) -> String {
+    let mut lines = vec![
+        "RVL WORKBOOK".to_string(),
+        String::new(),
+        format!("Compared {} -> {}", report.files.old, report.files.new),
+        format!("Verdict: {}", report.outcome.replace('_', " ")),
+        String::new(),
+    ];
+    for sheet in &report.sheets {
+        lines.push(format!(
+            "{}  {}",
+            sheet.name,
+            sheet.outcome.replace('_', " ")
+        ));
+    }
+    for name in &report.old_only {
+        lines.push(format!("{name}  only in old workbook (not compared)"));
+    }
+    for name in &report.new_only {
+        lines.push(format!("{name}  only in new workbook (not compared)"));
+    }
+    for sheet in &report.sheets {
+        lines.push(String::new());
+        lines.push(format!("== Sheet: {} ==", sheet.name));
+        lines.push(sheet.human.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(name: &str, csv: &str) -> Worksheet {
+        Worksheet {
+            name: name.to_string(),
+            rows: csv
+                .lines()
+                .map(|line| line.split(',').map(str::to_string).collect())
+                .collect(),
+        }
+    }
+
+    fn xlsx_args(label: &str, all_sheets: bool) -> XlsxArgs {
+        XlsxArgs {
+            old: PathBuf::from(format!("unit_{label}_old.xlsx")),
+            new: PathBuf::from(format!("unit_{label}_new.xlsx")),
+            all_sheets,
+            key: Some("id".to_string()),
+            threshold: 0.95,
+            tolerance: 1e-9,
+            json: true,
+        }
+    }
+
+    #[test]
+    fn pairs_sheets_by_name_and_lists_unpaired_ones() {
+        let old = [sheet("a", "id\n"), sheet("b", "id\n"), sheet("c", "id\n")];
+        let new = [sheet("c", "id\n"), sheet("a", "id\n"), sheet("d", "id\n")];
+        let (pairs, old_only, new_only) = pair_by_name(&old, &new);
+        let names: Vec<&str> = pairs.iter().map(|(old, _)| old.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "c"]);
+        assert_eq!(old_only, vec!["b"]);
+        assert_eq!(new_only, vec!["d"]);
+    }
+
+    #[test]
+    fn workbook_verdict_is_the_most_severe_sheet_verdict() {
+        let old = [
+            sheet("Rent", "id,amount\n1,100\n2,200"),
+            sheet("Units", "id,count\n1,5"),
+        ];
+        let new = [
+            sheet("Rent", "id,amount\n1,100\n2,250"),
+            sheet("Units", "id,count\n1,5"),
+        ];
+        let report = compare(&xlsx_args("severity", true), &old, &new).unwrap();
+        assert_eq!(report.sheets[0].outcome, "REAL_CHANGE");
+        assert_eq!(report.sheets[1].outcome, "NO_REAL_CHANGE");
+        assert_eq!(report.outcome, "REAL_CHANGE");
+        assert_eq!(exit_code(report.verdict), 1);
+    }
+
+    #[test]
+    fn without_all_sheets_only_the_first_sheets_are_compared() {
+        let old = [sheet("Q1", "id,v\n1,1"), sheet("Q2", "id,v\n1,1")];
+        let new = [sheet("Quarter 1", "id,v\n1,1"), sheet("Q2", "id,v\n1,9")];
+        let report = compare(&xlsx_args("first", false), &old, &new).unwrap();
+        assert_eq!(report.sheets.len(), 1);
+        assert_eq!(report.sheets[0].name, "Q1");
+        assert_eq!(report.outcome, "NO_REAL_CHANGE");
+    }
+}
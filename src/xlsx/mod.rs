@@ -0,0 +1,356 @@
+//! Read-only `.xlsx` input: worksheets as grids of cell text (`rvl xlsx`).
+//!
+//! Cells keep the text Excel stored: numbers as written in the sheet XML,
+//! shared and inline strings decoded, booleans as `TRUE`/`FALSE`. Dates stay
+//! serial numbers because the date format lives in the style, not the cell.
+
+pub mod xml;
+pub mod zip;
+
+use std::collections::HashMap;
+
+use self::xml::{Token, attr, tokens, unescape};
+use self::zip::ZipArchive;
+
+const WORKBOOK_PART: &str = "xl/workbook.xml";
+const WORKBOOK_RELS_PART: &str = "xl/_rels/workbook.xml.rels";
+const SHARED_STRINGS_PART: &str = "xl/sharedStrings.xml";
+
+/// One worksheet: rows of cell text, padded to a rectangle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worksheet {
+    pub name: String,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Worksheet {
+    /// Render the sheet as RFC 4180 CSV for the regular comparison pipeline.
+    pub fn to_csv(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for row in &self.rows {
+            for (idx, cell) in row.iter().enumerate() {
+                if idx > 0 {
+                    out.push(b',');
+                }
+                if cell.contains([',', '"', '\n', '\r']) {
+                    out.push(b'"');
+                    out.extend_from_slice(cell.replace('"', "\"\"").as_bytes());
+                    out.push(b'"');
+                } else {
+                    out.extend_from_slice(cell.as_bytes());
+                }
+            }
+            out.push(b'\n');
+        }
+        out
+    }
+}
+
+/// Parse every worksheet, in workbook order. Errors are short reasons.
+pub fn read_workbook(bytes: &[u8]) -> Result<Vec<Worksheet>, String> {
+    let archive = ZipArchive::new(bytes)?;
+    let workbook =
+        read_part(&archive, WORKBOOK_PART)?.ok_or_else(|| format!("missing {WORKBOOK_PART}"))?;
+    let rels = read_part(&archive, WORKBOOK_RELS_PART)?
+        .ok_or_else(|| format!("missing {WORKBOOK_RELS_PART}"))?;
+    let shared = match read_part(&archive, SHARED_STRINGS_PART)? {
+        Some(xml) => parse_shared_strings(&xml),
+        None => Vec::new(),
+    };
+    let targets = parse_relationships(&rels);
+
+    let mut sheets = Vec::new();
+    for (name, rel_id) in parse_sheet_list(&workbook) {
+        let target = targets
+            .get(&rel_id)
+            .ok_or_else(|| format!("sheet \"{name}\" has no relationship {rel_id}"))?;
+        let xml = read_part(&archive, target)?
+            .ok_or_else(|| format!("sheet \"{name}\" part {target} is missing"))?;
+        let rows =
+            parse_sheet(&xml, &shared).map_err(|reason| format!("sheet \"{name}\": {reason}"))?;
+        sheets.push(Worksheet { name, rows });
+    }
+    Ok(sheets)
+}
+
+fn read_part(archive: &ZipArchive<'_>, name: &str) -> Result<Option<String>, String> {
+    Ok(archive
+        .read(name)?
+        .map(|bytes| String::from_utf8_lossy(&bytes).to_string()))
+}
+
+/// `(name, relationship id)` for each `<sheet>` in workbook order.
+fn parse_sheet_list(xml: &str) -> Vec<(String, String)> {
+    tokens(xml)
+        .filter_map(|token| match token {
+            Token::Start {
+                name: "sheet",
+                attrs,
+                ..
+            } => Some((attr(attrs, "name")?, attr(attrs, "id")?)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Relationship id -> archive part path. Targets are relative to `xl/`
+/// unless absolute.
+fn parse_relationships(xml: &str) -> HashMap<String, String> {
+    tokens(xml)
+        .filter_map(|token| match token {
+            Token::Start {
+                name: "Relationship",
+                attrs,
+                ..
+            } => {
+                let target = attr(attrs, "Target")?;
+                let path = match target.strip_prefix('/') {
+                    Some(absolute) => absolute.to_string(),
+                    None => format!("xl/{target}"),
+                };
+                Some((attr(attrs, "Id")?, path))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Shared string table: the `<t>` runs of each `<si>`, skipping phonetic
+/// (`<rPh>`) annotations.
+fn parse_shared_strings(xml: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut current: Option<String> = None;
+    let mut in_text = false;
+    let mut in_phonetic = false;
+    for token in tokens(xml) {
+        match token {
+            Token::Start {
+                name: "si",
+                self_closing,
+                ..
+            } => {
+                if self_closing {
+                    strings.push(String::new());
+                } else {
+                    current = Some(String::new());
+                }
+            }
+            Token::End { name: "si" } => strings.extend(current.take()),
+            Token::Start { name: "rPh", .. } => in_phonetic = true,
+            Token::End { name: "rPh" } => in_phonetic = false,
+            Token::Start {
+                name: "t",
+                self_closing: false,
+                ..
+            } => in_text = true,
+            Token::End { name: "t" } => in_text = false,
+            Token::Text(text) if in_text && !in_phonetic => {
+                if let Some(current) = current.as_mut() {
+                    current.push_str(&unescape(text));
+                }
+            }
+            _ => {}
+        }
+    }
+    strings
+}
+
+#[derive(Default)]
+struct PendingCell {
+    column: usize,
+    kind: Option<String>,
+    value: String,
+}
+
+fn parse_sheet(xml: &str, shared: &[String]) -> Result<Vec<Vec<String>>, String> {
+    let mut cells: Vec<(usize, usize, String)> = Vec::new();
+    let mut row = 0usize;
+    let mut next_column = 0usize;
+    let mut cell: Option<PendingCell> = None;
+    let mut in_value = false;
+
+    for token in tokens(xml) {
+        match token {
+            Token::Start {
+                name: "row", attrs, ..
+            } => {
+                row = match attr(attrs, "r") {
+                    Some(r) => r
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|r| *r > 0)
+                        .ok_or_else(|| format!("invalid row number {r}"))?,
+                    None => row + 1,
+                };
+                next_column = 0;
+            }
+            Token::Start {
+                name: "c",
+                attrs,
+                self_closing,
+            } => {
+                let column = match attr(attrs, "r") {
+                    Some(reference) => column_index(&reference)
+                        .ok_or_else(|| format!("invalid cell reference {reference}"))?,
+                    None => next_column,
+                };
+                next_column = column + 1;
+                if !self_closing {
+                    cell = Some(PendingCell {
+                        column,
+                        kind: attr(attrs, "t"),
+                        value: String::new(),
+                    });
+                }
+            }
+            Token::Start {
+                name: "v" | "t",
+                self_closing: false,
+                ..
+            } => in_value = true,
+            Token::End { name: "v" | "t" } => in_value = false,
+            Token::Text(text) if in_value => {
+                if let Some(cell) = cell.as_mut() {
+                    cell.value.push_str(&unescape(text));
+                }
+            }
+            Token::End { name: "c" } => {
+                if let Some(pending) = cell.take() {
+                    let text = cell_text(pending.kind.as_deref(), pending.value, shared)?;
+                    if !text.is_empty() {
+                        cells.push((row.max(1) - 1, pending.column, text));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let height = cells.iter().map(|(row, _, _)| row + 1).max().unwrap_or(0);
+    let width = cells
+        .iter()
+        .map(|(_, column, _)| column + 1)
+        .max()
+        .unwrap_or(0);
+    let mut rows = vec![vec![String::new(); width]; height];
+    for (row, column, text) in cells {
+        rows[row][column] = text;
+    }
+    Ok(rows)
+}
+
+fn cell_text(kind: Option<&str>, value: String, shared: &[String]) -> Result<String, String> {
+    match kind {
+        Some("s") => {
+            let index = value
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("invalid shared string index {value}"))?;
+            shared
+                .get(index)
+                .cloned()
+                .ok_or_else(|| format!("shared string {index} is out of range"))
+        }
+        Some("b") => Ok(if value.trim() == "1" { "TRUE" } else { "FALSE" }.to_string()),
+        _ => Ok(value),
+    }
+}
+
+/// Zero-based column index from a cell reference (`A1` -> 0, `AA7` -> 26).
+fn column_index(reference: &str) -> Option<usize> {
+    let letters: Vec<u8> = reference
+        .bytes()
+        .take_while(u8::is_ascii_alphabetic)
+        .collect();
+    if letters.is_empty() || letters.len() > 3 {
+        return None;
+    }
+    letters
+        .iter()
+        .try_fold(0usize, |acc, letter| {
+            Some(acc * 26 + usize::from(letter.to_ascii_uppercase() - b'A') + 1)
+        })
+        .map(|index| index - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::xlsx::{Cell, Sheet, workbook_bytes};
+
+    #[test]
+    fn reads_sheets_written_by_the_report_writer() {
+        let sheets = [
+            Sheet {
+                name: "Summary".to_string(),
+                rows: vec![
+                    vec![Cell::Header("id".into()), Cell::Header("amount".into())],
+                    vec![Cell::Text("A, Inc".into()), Cell::Number(1500.5)],
+                    vec![Cell::Text("B".into()), Cell::Empty, Cell::Number(3.0)],
+                ],
+                column_widths: Vec::new(),
+                freeze_header: true,
+            },
+            Sheet {
+                name: "Empty".to_string(),
+                rows: Vec::new(),
+                column_widths: Vec::new(),
+                freeze_header: false,
+            },
+        ];
+        let parsed = read_workbook(&workbook_bytes(&sheets)).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].name, "Summary");
+        assert_eq!(
+            parsed[0].rows,
+            vec![
+                vec!["id", "amount", ""],
+                vec!["A, Inc", "1500.5", ""],
+                vec!["B", "", "3"],
+            ]
+        );
+        assert_eq!(
+            String::from_utf8(parsed[0].to_csv()).unwrap(),
+            "id,amount,\n\"A, Inc\",1500.5,\nB,,3\n"
+        );
+        assert!(parsed[1].rows.is_empty());
+    }
+
+    #[test]
+    fn decodes_shared_strings_and_booleans() {
+        let shared = parse_shared_strings(
+            r#"<sst><si><t>rent</t></si><si><r><t>unit </t></r><r><t>type</t></r><rPh><t>x</t></rPh></si><si/></sst>"#,
+        );
+        assert_eq!(shared, vec!["rent", "unit type", ""]);
+
+        let rows = parse_sheet(
+            r#"<worksheet><sheetData><row r="1"><c r="A1" t="s"><v>1</v></c><c r="C1" t="b"><v>1</v></c></row><row r="3"><c r="B3"><v>42</v></c></row></sheetData></worksheet>"#,
+            &shared,
+        )
+        .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["unit type", "", "TRUE"],
+                vec!["", "", ""],
+                vec!["", "42", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn column_letters_map_to_indexes() {
+        assert_eq!(column_index("A1"), Some(0));
+        assert_eq!(column_index("Z9"), Some(25));
+        assert_eq!(column_index("AA10"), Some(26));
+        assert_eq!(column_index("12"), None);
+    }
+
+    #[test]
+    fn rejects_non_zip_input() {
+        assert_eq!(
+            read_workbook(b"id,v\n1,2\n").unwrap_err(),
+            "not a ZIP container"
+        );
+    }
+}
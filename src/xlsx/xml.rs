@@ -0,0 +1,194 @@
+// Minimal SpreadsheetML scanner: start/end tags with raw attributes and text
+// runs, enough for workbook, relationship, shared-string, and sheet parts.
+// Namespace prefixes are dropped from element names (`x:c` reads as `c`).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    Start {
+        name: &'a str,
+        attrs: &'a str,
+        self_closing: bool,
+    },
+    End {
+        name: &'a str,
+    },
+    Text(&'a str),
+}
+
+pub struct Tokens<'a> {
+    rest: &'a str,
+}
+
+pub fn tokens(xml: &str) -> Tokens<'_> {
+    Tokens { rest: xml }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            if !self.rest.starts_with('<') {
+                let end = self.rest.find('<').unwrap_or(self.rest.len());
+                let (text, rest) = self.rest.split_at(end);
+                self.rest = rest;
+                return Some(Token::Text(text));
+            }
+            if let Some(body) = self.rest.strip_prefix("<![CDATA[") {
+                let end = body.find("]]>").unwrap_or(body.len());
+                self.rest = body.get(end + 3..).unwrap_or("");
+                return Some(Token::Text(&body[..end]));
+            }
+            // Declarations, processing instructions, and comments carry no data.
+            for (open, close) in [("<?", "?>"), ("<!--", "-->"), ("<!", ">")] {
+                if let Some(body) = self.rest.strip_prefix(open) {
+                    let end = body.find(close).map_or(body.len(), |end| end + close.len());
+                    self.rest = &body[end..];
+                    break;
+                }
+            }
+            if !self.rest.starts_with('<') || self.rest.starts_with("<!") {
+                continue;
+            }
+
+            let end = self.rest.find('>').unwrap_or(self.rest.len());
+            let tag = &self.rest[1..end];
+            self.rest = self.rest.get(end + 1..).unwrap_or("");
+            if let Some(name) = tag.strip_prefix('/') {
+                return Some(Token::End {
+                    name: local_name(name.trim()),
+                });
+            }
+            let (tag, self_closing) = match tag.strip_suffix('/') {
+                Some(tag) => (tag, true),
+                None => (tag, false),
+            };
+            let split = tag
+                .find(|c: char| c.is_ascii_whitespace())
+                .unwrap_or(tag.len());
+            return Some(Token::Start {
+                name: local_name(&tag[..split]),
+                attrs: &tag[split..],
+                self_closing,
+            });
+        }
+    }
+}
+
+/// Value of attribute `name` (matched on its local name), unescaped.
+pub fn attr(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs;
+    loop {
+        rest = rest.trim_start();
+        let eq = rest.find('=')?;
+        let key = rest[..eq].trim();
+        let after = rest[eq + 1..].trim_start();
+        let quote = after.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value_end = after[1..].find(quote)? + 1;
+        if local_name(key) == name {
+            return Some(unescape(&after[1..value_end]));
+        }
+        rest = &after[value_end + 1..];
+    }
+}
+
+/// Decode the five predefined entities and numeric character references.
+pub fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let Some(semi) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..semi];
+        let decoded = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scans_tags_text_and_attributes() {
+        let xml = r#"<?xml version="1.0"?><!-- note --><x:row r="2"><x:c r="B2" t='s'/><v>1 &amp; 2</v></x:row>"#;
+        let tokens: Vec<Token<'_>> = tokens(xml).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Start {
+                    name: "row",
+                    attrs: r#" r="2""#,
+                    self_closing: false
+                },
+                Token::Start {
+                    name: "c",
+                    attrs: r#" r="B2" t='s'"#,
+                    self_closing: true
+                },
+                Token::Start {
+                    name: "v",
+                    attrs: "",
+                    self_closing: false
+                },
+                Token::Text("1 &amp; 2"),
+                Token::End { name: "v" },
+                Token::End { name: "row" },
+            ]
+        );
+        assert_eq!(attr(r#" r="B2" t='s'"#, "t").as_deref(), Some("s"));
+        assert_eq!(
+            attr(r#" name="A&amp;B" r:id="rId1""#, "id").as_deref(),
+            Some("rId1")
+        );
+        assert_eq!(attr(r#" r="B2""#, "t"), None);
+    }
+
+    #[test]
+    fn unescapes_entities_and_character_references() {
+        assert_eq!(
+            unescape("a &lt;b&gt; &#65;&#x42; &bogus; &"),
+            "a <b> AB &bogus; &"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+// Minimal ZIP reader for .xlsx containers: central directory lookup plus
+// stored and deflate entries. ZIP64, encryption, and multi-disk archives are
+// refused; spreadsheet exports never need them.
+
+use std::io::Read;
+
+const EOCD_SIGNATURE: u32 = 0x0605_4b50;
+const CENTRAL_SIGNATURE: u32 = 0x0201_4b50;
+const LOCAL_SIGNATURE: u32 = 0x0403_4b50;
+const EOCD_LEN: usize = 22;
+const CENTRAL_LEN: usize = 46;
+const LOCAL_LEN: usize = 30;
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+
+struct Entry {
+    name: String,
+    method: u16,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    local_offset: usize,
+}
+
+/// Read-only view of a ZIP archive held in memory.
+pub struct ZipArchive<'a> {
+    bytes: &'a [u8],
+    entries: Vec<Entry>,
+}
+
+impl<'a> ZipArchive<'a> {
+    pub fn new(bytes: &'a [u8]) -> Result<Self, String> {
+        let eocd = find_eocd(bytes).ok_or_else(|| "not a ZIP container".to_string())?;
+        let count = read_u16(bytes, eocd + 10)? as usize;
+        let mut offset = read_u32(bytes, eocd + 16)? as usize;
+        if count == 0xFFFF || offset == 0xFFFF_FFFF {
+            return Err("ZIP64 archives are not supported".to_string());
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            if read_u32(bytes, offset)? != CENTRAL_SIGNATURE {
+                return Err("corrupt ZIP central directory".to_string());
+            }
+            let flags = read_u16(bytes, offset + 8)?;
+            if flags & 0x1 != 0 {
+                return Err("encrypted workbooks are not supported".to_string());
+            }
+            let name_len = read_u16(bytes, offset + 28)? as usize;
+            let extra_len = read_u16(bytes, offset + 30)? as usize;
+            let comment_len = read_u16(bytes, offset + 32)? as usize;
+            let name = slice(bytes, offset + CENTRAL_LEN, name_len)?;
+            entries.push(Entry {
+                name: String::from_utf8_lossy(name).to_string(),
+                method: read_u16(bytes, offset + 10)?,
+                compressed_size: read_u32(bytes, offset + 20)? as usize,
+                uncompressed_size: read_u32(bytes, offset + 24)? as usize,
+                local_offset: read_u32(bytes, offset + 42)? as usize,
+            });
+            offset += CENTRAL_LEN + name_len + extra_len + comment_len;
+        }
+        Ok(Self { bytes, entries })
+    }
+
+    /// Decompressed contents of the entry named `name`, if present.
+    pub fn read(&self, name: &str) -> Result<Option<Vec<u8>>, String> {
+        let Some(entry) = self.entries.iter().find(|entry| entry.name == name) else {
+            return Ok(None);
+        };
+        let local = entry.local_offset;
+        if read_u32(self.bytes, local)? != LOCAL_SIGNATURE {
+            return Err(format!("corrupt ZIP entry {name}"));
+        }
+        let name_len = read_u16(self.bytes, local + 26)? as usize;
+        let extra_len = read_u16(self.bytes, local + 28)? as usize;
+        let data = slice(
+            self.bytes,
+            local + LOCAL_LEN + name_len + extra_len,
+            entry.compressed_size,
+        )?;
+        match entry.method {
+            METHOD_STORED => Ok(Some(data.to_vec())),
+            METHOD_DEFLATE => {
+                let mut out = Vec::with_capacity(entry.uncompressed_size);
+                flate2::read::DeflateDecoder::new(data)
+                    .read_to_end(&mut out)
+                    .map_err(|err| format!("cannot inflate ZIP entry {name} ({err})"))?;
+                Ok(Some(out))
+            }
+            method => Err(format!(
+                "ZIP entry {name} uses unsupported compression method {method}"
+            )),
+        }
+    }
+}
+
+fn find_eocd(bytes: &[u8]) -> Option<usize> {
+    if bytes.len() < EOCD_LEN {
+        return None;
+    }
+    // The record sits at the end, followed by a comment of at most 64 KiB.
+    let earliest = bytes.len().saturating_sub(EOCD_LEN + usize::from(u16::MAX));
+    (earliest..=bytes.len() - EOCD_LEN)
+        .rev()
+        .find(|&offset| read_u32(bytes, offset).ok() == Some(EOCD_SIGNATURE))
+}
+
+fn slice(bytes: &[u8], offset: usize, len: usize) -> Result<&[u8], String> {
+    offset
+        .checked_add(len)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or_else(|| "truncated ZIP container".to_string())
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16, String> {
+    let raw = slice(bytes, offset, 2)?;
+    Ok(u16::from_le_bytes([raw[0], raw[1]]))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, String> {
+    let raw = slice(bytes, offset, 4)?;
+    Ok(u32::from_le_bytes([raw[0], raw[1], raw[2], raw[3]]))
+}
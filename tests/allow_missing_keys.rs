@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_allow_missing_keys_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// B and C are common and unchanged; A was removed, D and E were added.
+fn write_key_drift(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,200\nC,300\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nB,200\nC,300\nD,40\nE,2.5\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn key_mismatch_refuses_by_default() {
+    let dir = temp_dir();
+    let args = write_key_drift(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_KEY_MISMATCH");
+    assert!(json.get("key_changes").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn common_keys_are_compared_and_unmatched_rows_counted() {
+    let dir = temp_dir();
+    let mut args = write_key_drift(&dir, true);
+    args.allow_missing_keys = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+    assert_eq!(json["key_changes"]["rows_added"], 2);
+    assert_eq!(json["key_changes"]["rows_removed"], 1);
+    assert!(json["key_changes"]["totals"].is_null());
+
+    args.explicit = true;
+    let json = run_json(&args);
+    let totals = &json["key_changes"]["totals"][0];
+    assert_eq!(totals["column"], "u8:amount");
+    assert_eq!(totals["added"], 42.5);
+    assert_eq!(totals["removed"], 100.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_reports_key_changes() {
+    let dir = temp_dir();
+    let mut args = write_key_drift(&dir, false);
+    args.allow_missing_keys = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("NO REAL CHANGE"), "{output}");
+    assert!(
+        output.contains("Key changes: 2 rows added, 1 row removed (only common keys compared)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn entity_changes_rank_added_and_removed_rows() {
+    let dir = temp_dir();
+    let mut args = write_key_drift(&dir, true);
+    args.include_entity_changes = true;
+    args.explicit = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["key_changes"]["rows_removed"], 1);
+
+    let contributors = json["contributors"].as_array().unwrap();
+    // A (100) and D (40) cover 140 of 142.5; E stays in the tail.
+    assert_eq!(contributors.len(), 2);
+    assert_eq!(contributors[0]["row_id"], "u8:A");
+    assert_eq!(contributors[0]["kind"], "row_removed");
+    assert_eq!(contributors[0]["delta"], -100.0);
+    assert_eq!(contributors[1]["row_id"], "u8:D");
+    assert_eq!(contributors[1]["kind"], "row_added");
+    assert_eq!(contributors[1]["new"], 40.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn entity_changes_are_labelled_in_human_output() {
+    let dir = temp_dir();
+    let mut args = write_key_drift(&dir, false);
+    args.include_entity_changes = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("A.amount (row removed)"), "{output}");
+    assert!(output.contains("D.amount (row added)"), "{output}");
+    assert!(
+        output.contains("Key changes: 2 rows added, 1 row removed (ranked as contributors)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_allow_trailing_rows_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// An append-only log: row 2 changed and two rows were appended.
+fn write_appended_log(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "day,amount\nmon,10\ntue,20\n").unwrap();
+    std::fs::write(&new_path, "day,amount\nmon,10\ntue,25\nwed,30\nthu,2.5\n").unwrap();
+    Args::new(old_path, new_path, None, 0.95, 1e-9, None, json)
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn row_count_difference_refuses_by_default() {
+    let dir = temp_dir();
+    let args = write_appended_log(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_ROWCOUNT");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn difference_above_the_allowance_still_refuses() {
+    let dir = temp_dir();
+    let mut args = write_appended_log(&dir, true);
+    args.allow_trailing_rows = 1;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_ROWCOUNT");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn common_prefix_is_compared_and_trailing_rows_counted() {
+    let dir = temp_dir();
+    let mut args = write_appended_log(&dir, true);
+    args.allow_trailing_rows = 2;
+    args.explicit = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["alignment"]["mode"], "row_order");
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+    assert_eq!(json["contributors"][0]["delta"], 5.0);
+    assert_eq!(json["key_changes"]["rows_added"], 2);
+    assert_eq!(json["key_changes"]["rows_removed"], 0);
+    assert_eq!(json["key_changes"]["totals"][0]["added"], 32.5);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_reports_trailing_rows() {
+    let dir = temp_dir();
+    let mut args = write_appended_log(&dir, false);
+    args.allow_trailing_rows = 2;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains(
+            "Trailing rows: 2 rows added, 0 rows removed (only the common prefix compared)"
+        ),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,110 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::assertion::{self, ExpectedVerdict};
+use rvl::cli::args::Args;
+use rvl::cli::exit::Outcome;
+use rvl::orchestrator;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_assert_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pair(dir: &Path, new: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,50\n").unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        false,
+    );
+    args.assert = true;
+    args.no_witness = true;
+    args
+}
+
+#[test]
+fn matching_verdict_passes() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, "id,amount\nB,50\nA,100\n");
+    args.expect = Some(ExpectedVerdict::Outcome(Outcome::NoRealChange));
+
+    let report = assertion::evaluate(&args).unwrap();
+    assert!(report.passed);
+    assert_eq!(report.actual, "NO_REAL_CHANGE");
+    assert!(report.differences.is_empty());
+    assert_eq!(report.result["version"], "rvl.v0");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn changed_values_fail_with_the_outcome_difference() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, "id,amount\nA,100\nB,20\n");
+    args.expect = Some(ExpectedVerdict::Outcome(Outcome::NoRealChange));
+
+    let report = assertion::evaluate(&args).unwrap();
+    assert!(!report.passed);
+    assert_eq!(report.expected, "NO_REAL_CHANGE");
+    assert_eq!(report.actual, "REAL_CHANGE");
+    assert_eq!(report.differences[0].path, "outcome");
+    assert_eq!(report.differences[0].actual, "REAL_CHANGE");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn saved_result_is_compared_leaf_by_leaf() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, "id,amount\nA,100\nB,20\n");
+    args.json = true;
+    let saved = orchestrator::run(&args).unwrap().output;
+    let expected_path = dir.join("expected.json");
+    std::fs::write(&expected_path, saved).unwrap();
+    args.json = false;
+    args.expect_json = Some(expected_path);
+
+    let report = assertion::evaluate(&args).unwrap();
+    assert!(report.passed, "{:?}", report.differences);
+
+    std::fs::write(dir.join("new.csv"), "id,amount\nA,100\nB,10\n").unwrap();
+    let report = assertion::evaluate(&args).unwrap();
+    assert!(!report.passed);
+    assert_eq!(report.expected, "REAL_CHANGE");
+    assert!(
+        report
+            .differences
+            .iter()
+            .any(|difference| difference.path == "metrics.total_change"),
+        "{:?}",
+        report.differences
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn missing_expectation_file_is_a_process_error() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, "id,amount\nA,100\nB,50\n");
+    args.expect_json = Some(dir.join("missing.json"));
+
+    assert!(assertion::evaluate(&args).is_err());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_bench_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pair(dir: &std::path::Path, name: &str, old: &str, new: &str) {
+    let pair = dir.join(name);
+    std::fs::create_dir_all(&pair).unwrap();
+    std::fs::write(pair.join("old.csv"), old).unwrap();
+    std::fs::write(pair.join("new.csv"), new).unwrap();
+}
+
+fn rvl(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn benches_a_directory_and_matches_the_same_binary() {
+    let dir = temp_dir();
+    write_pair(&dir, "a", "id,v\nA,1\nB,2\n", "id,v\nA,1\nB,9\n");
+    write_pair(&dir, "b", "id,v\nA,1\n", "id,v\nA,1\n");
+
+    let (code, stdout) = rvl(&[
+        "bench",
+        dir.to_str().unwrap(),
+        "--key",
+        "id",
+        "--iterations",
+        "2",
+        "--warmup",
+        "0",
+        "--against",
+        env!("CARGO_BIN_EXE_rvl"),
+        "--json",
+    ]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rvl.bench.v0");
+    assert_eq!(json["iterations"], 2);
+    assert_eq!(json["pairs"][0]["name"], "a");
+    assert_eq!(json["pairs"][0]["outcome"], "REAL_CHANGE");
+    assert_eq!(json["pairs"][0]["rows"], 4);
+    assert!(json["pairs"][0]["stages"]["parse_ms"].as_f64().unwrap() > 0.0);
+    assert_eq!(json["pairs"][0]["identity"]["identical"], true);
+    assert_eq!(json["pairs"][0]["identity"]["exit_code"], 1);
+    assert_eq!(json["pairs"][1]["outcome"], "NO_REAL_CHANGE");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn differing_output_exits_one() {
+    let dir = temp_dir();
+    let old = dir.join("old.csv");
+    let new = dir.join("new.csv");
+    std::fs::write(&old, "id,v\nA,1\n").unwrap();
+    std::fs::write(&new, "id,v\nA,2\n").unwrap();
+
+    let (code, stdout) = rvl(&[
+        "bench",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+        "--key",
+        "id",
+        "--iterations",
+        "1",
+        "--against",
+        "echo",
+    ]);
+    assert_eq!(code, Some(1), "{stdout}");
+    assert!(stdout.starts_with("RVL BENCH"), "{stdout}");
+    assert!(
+        stdout.contains("old.csv -> new.csv  REAL CHANGE"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("output: DIFFERS (exit 1 vs 0)"), "{stdout}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn empty_directory_exits_two() {
+    let dir = temp_dir();
+    let (code, _) = rvl(&["bench", dir.to_str().unwrap()]);
+    assert_eq!(code, Some(2));
+    std::fs::remove_dir_all(&dir).ok();
+}
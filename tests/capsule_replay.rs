@@ -108,24 +108,87 @@ fn run_with_capsule(
     let args = Args {
         old: Some(old.to_path_buf()),
         new: Some(new.to_path_buf()),
+        base: None,
         key: key.map(str::to_string),
         threshold: 0.95,
         tolerance: 1e-9,
         delimiter: None,
+        skip_rows: rvl::csv::input::SkipRows::default(),
+        skip_footer: rvl::csv::footer::FooterSkip::default(),
+        encoding: rvl::csv::encoding::EncodingMode::default(),
+        comment: None,
+        strict_dialect: false,
         exhaustive: false,
         audit_fields: false,
         max_audit_changes: 10_000,
+        max_memory: None,
         profile: None,
         profile_id: None,
         capsule_out: Some(capsule_root.to_path_buf()),
         json: true,
+        format: None,
+        output_version: rvl::output::json::OutputVersion::V0,
         no_witness: true,
         describe: false,
         explicit: false,
         schema: false,
         version: false,
+        show_subtolerance: None,
+        explore: None,
+        report_xlsx: None,
+        schema_only: false,
+        drift: None,
+        cross_tab: false,
+        output_stream: rvl::cli::exit::StreamPolicy::Auto,
+        expected: None,
+        expect: None,
+        expect_json: None,
+        dedupe_rows: false,
+        key_map: None,
+        key_normalize: Vec::new(),
+        smart_tolerance: false,
+        min_header_overlap: 0.5,
+        top: 25,
+        rank: rvl::diff::rank::RankMode::Abs,
+        weight_col: None,
+        report_csv: None,
+        events: None,
+        timings: false,
+        allow_missing_keys: false,
+        include_entity_changes: false,
+        allow_trailing_rows: 0,
+        where_filters: Vec::new(),
+        sample: None,
+        seed: 0,
+        dedup: rvl::alignment::key_dedup::DedupMode::Refuse,
+        group_by: None,
+        column_map: Vec::new(),
+        header_match: rvl::normalize::headers::HeaderMatch::Exact,
+        parse_percent: false,
+        decimal: rvl::numeric::parse::DecimalSeparator::Dot,
+        currency_symbols: Vec::new(),
+        lenient_numbers: false,
+        include_text: false,
+        na_values: Vec::new(),
+        on_missing: rvl::numeric::missing::MissingPolicy::Refuse,
+        on_mixed_types: rvl::numeric::columns::MixedTypesPolicy::Refuse,
+        collect_refusals: false,
+        on_diffuse: rvl::diff::coverage::DiffusePolicy::Refuse,
+        coverage: rvl::diff::coverage::CoverageMode::Total,
+        columns: Vec::new(),
+        column_tolerances: Vec::new(),
+        transforms: Vec::new(),
+        tolerance_sweep: None,
+        config: None,
+        no_config: false,
+        config_receipt: None,
+        inline: Default::default(),
+        threads: 0,
         robot_triage: false,
         command: None,
+        watch: false,
+        assert: false,
+        param_sources: Default::default(),
     };
 
     let first = orchestrator::run(&args).expect("first run should succeed");
@@ -154,6 +217,7 @@ fn replay_from_manifest(manifest: &Value, capsule_dir: &Path) -> Value {
     let args = Args {
         old: Some(capsule_dir.join("old.csv")),
         new: Some(capsule_dir.join("new.csv")),
+        base: None,
         key: args_block
             .get("key")
             .and_then(Value::as_str)
@@ -167,6 +231,11 @@ fn replay_from_manifest(manifest: &Value, capsule_dir: &Path) -> Value {
             .and_then(Value::as_f64)
             .expect("manifest.args.tolerance"),
         delimiter,
+        skip_rows: rvl::csv::input::SkipRows::default(),
+        skip_footer: rvl::csv::footer::FooterSkip::default(),
+        encoding: rvl::csv::encoding::EncodingMode::default(),
+        comment: None,
+        strict_dialect: false,
         exhaustive: args_block
             .get("exhaustive")
             .and_then(Value::as_bool)
@@ -179,6 +248,7 @@ fn replay_from_manifest(manifest: &Value, capsule_dir: &Path) -> Value {
             .get("max_audit_changes")
             .and_then(Value::as_u64)
             .unwrap_or(10_000),
+        max_memory: args_block.get("max_memory").and_then(Value::as_u64),
         profile: args_block
             .get("profile")
             .and_then(Value::as_str)
@@ -192,13 +262,69 @@ fn replay_from_manifest(manifest: &Value, capsule_dir: &Path) -> Value {
             .get("json")
             .and_then(Value::as_bool)
             .expect("manifest.args.json"),
+        format: None,
+        output_version: rvl::output::json::OutputVersion::V0,
         no_witness: true,
         describe: false,
         explicit: false,
         schema: false,
         version: false,
+        show_subtolerance: None,
+        explore: None,
+        report_xlsx: None,
+        schema_only: false,
+        drift: None,
+        cross_tab: false,
+        output_stream: rvl::cli::exit::StreamPolicy::Auto,
+        expected: None,
+        expect: None,
+        expect_json: None,
+        dedupe_rows: false,
+        key_map: None,
+        key_normalize: Vec::new(),
+        smart_tolerance: false,
+        min_header_overlap: 0.5,
+        top: 25,
+        rank: rvl::diff::rank::RankMode::Abs,
+        weight_col: None,
+        report_csv: None,
+        events: None,
+        timings: false,
+        allow_missing_keys: false,
+        include_entity_changes: false,
+        allow_trailing_rows: 0,
+        where_filters: Vec::new(),
+        sample: None,
+        seed: 0,
+        dedup: rvl::alignment::key_dedup::DedupMode::Refuse,
+        group_by: None,
+        column_map: Vec::new(),
+        header_match: rvl::normalize::headers::HeaderMatch::Exact,
+        parse_percent: false,
+        decimal: rvl::numeric::parse::DecimalSeparator::Dot,
+        currency_symbols: Vec::new(),
+        lenient_numbers: false,
+        include_text: false,
+        na_values: Vec::new(),
+        on_missing: rvl::numeric::missing::MissingPolicy::Refuse,
+        on_mixed_types: rvl::numeric::columns::MixedTypesPolicy::Refuse,
+        collect_refusals: false,
+        on_diffuse: rvl::diff::coverage::DiffusePolicy::Refuse,
+        coverage: rvl::diff::coverage::CoverageMode::Total,
+        columns: Vec::new(),
+        column_tolerances: Vec::new(),
+        transforms: Vec::new(),
+        tolerance_sweep: None,
+        config: None,
+        no_config: false,
+        config_receipt: None,
+        inline: Default::default(),
+        threads: 0,
         robot_triage: false,
         command: None,
+        watch: false,
+        assert: false,
+        param_sources: Default::default(),
     };
 
     let replay = orchestrator::run(&args).expect("replay run should succeed");
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::missing::MissingPolicy;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_checked_cells_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, key: &str, old: &str, new: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some(key.to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn skipped_cells_are_counted_by_category_and_reconcile() {
+    let dir = temp_dir();
+    // A: both sides blank in `a`; B: `b` missing on the new side only.
+    let mut args = make_args(
+        &dir,
+        "id",
+        "id,a,b\nA,,1\nB,2,3\nC,4,5\n",
+        "id,a,b\nA,,1\nB,2,\nC,4,6\n",
+    );
+    args.on_missing = MissingPolicy::Skip;
+
+    let json = run_json(&args);
+    let counts = &json["counts"];
+    assert_eq!(counts["rows_aligned"], 3);
+    assert_eq!(counts["numeric_columns"], 2);
+    assert_eq!(counts["numeric_cells_checked"], 4);
+    assert_eq!(counts["missing_skipped"], 1);
+    assert_eq!(counts["missing_both"], 1);
+    assert!(counts.get("unparsable").is_none());
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("Checked: 3 rows, 2 numeric columns (4 cells)"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Uncompared cells: 1 missing on both sides, 0 not numeric"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn cross_tab_text_cells_count_as_unparsable() {
+    let dir = temp_dir();
+    let mut args = make_args(
+        &dir,
+        "field",
+        "field,comp1,comp2\nrent,1500,1625\nunit_type,2BR,3BR\nsqft,900,1100\n",
+        "field,comp1,comp2\nrent,1550,1625\nunit_type,2BR,3BR\nsqft,900,1100\n",
+    );
+    args.cross_tab = true;
+
+    let json = run_json(&args);
+    let counts = &json["counts"];
+    assert_eq!(counts["numeric_cells_checked"], 4);
+    assert_eq!(counts["unparsable"], 2);
+    assert!(counts.get("missing_both").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn missing_cells_in_added_rows_have_their_own_category() {
+    let dir = temp_dir();
+    // C is added with `a` blank: that cell is missing because the row is new,
+    // not because both sides left it blank.
+    let mut args = make_args(&dir, "id", "id,a,b\nA,1,2\n", "id,a,b\nA,1,2\nC,,5\n");
+    args.include_entity_changes = true;
+
+    let json = run_json(&args);
+    let counts = &json["counts"];
+    assert_eq!(counts["rows_aligned"], 1);
+    assert_eq!(counts["numeric_columns"], 2);
+    assert_eq!(counts["numeric_cells_checked"], 3);
+    assert_eq!(counts["missing_row"], 1);
+    assert!(counts.get("missing_both").is_none());
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains(
+            "Uncompared cells: 0 missing on both sides, 1 missing in added/removed rows, 0 not numeric"
+        ),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_collect_refusals_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    );
+    args.collect_refusals = true;
+    args
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+// `price` and `qty` each hold a stray text cell.
+const MIXED_OLD: &str = "id,price,qty\nA,10,1\nB,n.a.,2\n";
+const MIXED_NEW: &str = "id,price,qty\nA,11,1\nB,12,two\n";
+
+#[test]
+fn first_refusal_only_without_the_flag() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, MIXED_OLD, MIXED_NEW, true);
+    args.collect_refusals = false;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+    assert!(json.get("refusals").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn every_mixed_column_is_reported() {
+    let dir = temp_dir();
+    let args = make_args(&dir, MIXED_OLD, MIXED_NEW, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["detail"]["column"], "u8:price");
+    let refusals = json["refusals"].as_array().unwrap();
+    assert_eq!(refusals.len(), 2);
+    assert_eq!(refusals[0], json["refusal"]);
+    assert_eq!(refusals[1]["code"], "E_MIXED_TYPES");
+    assert_eq!(refusals[1]["detail"]["column"], "u8:qty");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn parse_refusals_are_reported_for_both_files() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,a,a\nA,1,2\n", "id,b,b\nA,1,2\n", true);
+
+    let json = run_json(&args);
+    let refusals = json["refusals"].as_array().unwrap();
+    assert_eq!(refusals.len(), 2);
+    assert_eq!(refusals[0]["detail"]["file"], "old");
+    assert_eq!(refusals[1]["code"], "E_HEADERS");
+    assert_eq!(refusals[1]["detail"]["file"], "new");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_lists_each_further_refusal() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,v\nA,1\nA,2\n", "id,v\nB,1\nB,2\n", false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert_eq!(output.matches("Reason (E_KEY_DUP)").count(), 2, "{output}");
+    assert!(output.contains("duplicates key \"B\""), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
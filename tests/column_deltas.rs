@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_column_deltas_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run_json(old: &str, new: &str, tolerance: f64) -> Value {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        tolerance,
+        None,
+        true,
+    );
+    let output = orchestrator::run(&args).unwrap().output;
+    std::fs::remove_dir_all(&dir).ok();
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn no_real_change_separates_identical_from_tolerated_columns() {
+    let json = run_json(
+        "id,exact,drift\nA,1,1.0\nB,2,2.0\n",
+        "id,exact,drift\nA,1,1.004\nB,2,1.998\n",
+        0.01,
+    );
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    let columns = json["column_deltas"].as_array().expect("column_deltas");
+    assert_eq!(columns.len(), 2);
+
+    assert_eq!(columns[0]["column"], "u8:exact");
+    assert_eq!(columns[0]["cells"], 2);
+    assert_eq!(columns[0]["cells_differing"], 0);
+    assert_eq!(columns[0]["max_abs_delta"], 0.0);
+
+    assert_eq!(columns[1]["column"], "u8:drift");
+    assert_eq!(columns[1]["cells_differing"], 2);
+    let min = columns[1]["min_abs_delta"].as_f64().unwrap();
+    let max = columns[1]["max_abs_delta"].as_f64().unwrap();
+    let mean = columns[1]["mean_abs_delta"].as_f64().unwrap();
+    assert!((min - 0.002).abs() < 1e-12, "{min}");
+    assert!((max - 0.004).abs() < 1e-12, "{max}");
+    assert!((mean - 0.003).abs() < 1e-12, "{mean}");
+}
+
+#[test]
+fn real_change_omits_column_deltas() {
+    let json = run_json("id,value\nA,1\nB,2\n", "id,value\nA,1\nB,5\n", 1e-9);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert!(json.get("column_deltas").is_none());
+}
@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::normalize::column_map::ColumnMapping;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_column_map_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The vendor renamed net_operating_income to noi; only that column changed.
+fn write_renamed(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "unit_id,rent,net_operating_income\nU1,1000,500\nU2,1200,700\n",
+    )
+    .unwrap();
+    std::fs::write(&new_path, "unit_id,rent,noi\nU1,1000,500\nU2,1200,950\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("unit_id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn renamed_column_drops_out_without_a_map() {
+    let dir = temp_dir();
+    let args = write_renamed(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["columns_old_only"], 1);
+    assert!(json.get("column_map").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn mapped_column_is_compared_and_reported() {
+    let dir = temp_dir();
+    let mut args = write_renamed(&dir, true);
+    args.column_map = vec![
+        ColumnMapping::parse("net_operating_income=noi").unwrap(),
+        ColumnMapping::parse("vacancy=vacancy_rate").unwrap(),
+    ];
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["columns_common"], 2);
+    assert_eq!(json["contributors"][0]["column"], "u8:noi");
+    assert_eq!(
+        json["column_map"],
+        serde_json::json!([{ "old": "u8:net_operating_income", "new": "u8:noi" }])
+    );
+
+    args.json = false;
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Columns mapped: net_operating_income -> noi"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn cli_rejects_map_without_equals() {
+    let dir = temp_dir();
+    let args = write_renamed(&dir, true);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .arg(args.old_path())
+        .arg(args.new_path())
+        .args(["--key", "unit_id", "--map", "net_operating_income"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("old_name=new_name"), "{stderr}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn leftover_columns_suggest_maps() {
+    let dir = temp_dir();
+    let mut args = write_renamed(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(
+        json["suggested_column_maps"],
+        serde_json::json!([{ "old": "u8:net_operating_income", "new": "u8:noi" }])
+    );
+
+    args.column_map = vec![ColumnMapping::parse("net_operating_income=noi").unwrap()];
+    let json = run_json(&args);
+    assert!(json.get("suggested_column_maps").is_none());
+
+    args.column_map.clear();
+    args.json = false;
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Did you mean: --map net_operating_income=noi"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_comment_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The old export carries tool stamps with commas in them.
+fn write_stamped_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "# exported by tool X, v2, nightly\nid,amount\nA,100\n# batch 2, part 1, of 3\nB,50\n",
+    )
+    .unwrap();
+    std::fs::write(&new_path, "id,amount\nA,100\nB,20\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn comment_lines_break_parsing_without_the_flag() {
+    let dir = temp_dir();
+    let args = write_stamped_pair(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REFUSAL");
+    assert!(json["counts"].get("comment_lines_old").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn comment_lines_are_skipped_and_counted() {
+    let dir = temp_dir();
+    let mut args = write_stamped_pair(&dir, true);
+    args.comment = Some("#".to_string());
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["rows_old"], 2);
+    assert_eq!(json["counts"]["comment_lines_old"], 2);
+    assert_eq!(json["counts"]["comment_lines_new"], 0);
+    assert_eq!(json["metrics"]["total_change"], 30.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_reports_skipped_comment_lines() {
+    let dir = temp_dir();
+    let mut args = write_stamped_pair(&dir, false);
+    args.comment = Some("#".to_string());
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Comment lines: 2 old, 0 new skipped (--comment)."),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
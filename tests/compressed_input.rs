@@ -0,0 +1,76 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_compressed_input_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(bytes).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[test]
+fn gzip_input_diffs_like_plain_csv() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv.gz");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, gzip(b"id,v\nA,1\nB,2\n")).unwrap();
+    std::fs::write(&new_path, "id,v\nA,1\nB,5\n").unwrap();
+    let args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["compression"], "gzip");
+    assert!(json["dialect"]["new"].get("compression").is_none());
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("Dialect(old): delimiter=, quote=\" escape=none compression=gzip"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn truncated_gzip_refuses_with_io() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv.gz");
+    let new_path = dir.join("new.csv");
+    let mut truncated = gzip(b"id,v\nA,1\nB,2\n");
+    truncated.truncate(12);
+    std::fs::write(&old_path, truncated).unwrap();
+    std::fs::write(&new_path, "id,v\nA,1\nB,5\n").unwrap();
+    let args = Args::new(old_path, new_path, None, 0.95, 1e-9, None, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_IO");
+    assert_eq!(json["refusal"]["detail"]["file"], "old");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
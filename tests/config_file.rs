@@ -0,0 +1,153 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::{Args, ParamSource};
+use rvl::orchestrator;
+use serde_json::{Value, json};
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_config_file_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// `amount` moves by 0.4 on A; `rate` moves by 0.5 on C and has a `TBD` placeholder.
+fn write_inputs(dir: &Path) -> (String, String) {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount,rate\nA,100,1.0\nB,40,TBD\nC,7,2.0\n").unwrap();
+    std::fs::write(
+        &new_path,
+        "id,amount,rate\nA,100.4,1.0\nB,40,TBD\nC,7,2.5\n",
+    )
+    .unwrap();
+    (
+        old_path.to_string_lossy().to_string(),
+        new_path.to_string_lossy().to_string(),
+    )
+}
+
+fn write_config(dir: &Path, body: &str) -> String {
+    let path = dir.join("rvl.toml");
+    std::fs::write(&path, body).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+const CONFIG: &str = r#"
+key = "id"
+na_values = ["TBD"]
+json = true
+
+[tolerances]
+amount = 0.5
+"#;
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn config_supplies_flags_and_is_echoed_in_json() {
+    let dir = temp_dir();
+    let (old, new) = write_inputs(&dir);
+    let config = write_config(&dir, CONFIG);
+
+    let args = Args::parse_from(["rvl", &old, &new, "--config", &config, "--no-witness"]).unwrap();
+    assert_eq!(args.key.as_deref(), Some("id"));
+    assert_eq!(args.param_sources.get("key"), Some(ParamSource::Config));
+
+    let json = run_json(&args);
+    assert_eq!(json["alignment"]["mode"], "key");
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_cells_changed"], 1);
+    assert_eq!(json["config"]["path"], config.as_str());
+    assert_eq!(
+        json["config"]["settings"],
+        json!({
+            "key": "id",
+            "na_values": ["TBD"],
+            "json": true,
+            "tolerances": { "amount": 0.5 },
+        })
+    );
+    assert!(json["config"].get("overridden").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn cli_flags_override_config() {
+    let dir = temp_dir();
+    let (old, new) = write_inputs(&dir);
+    let config = write_config(&dir, CONFIG);
+
+    let args = Args::parse_from([
+        "rvl",
+        &old,
+        &new,
+        "--config",
+        &config,
+        "--no-witness",
+        "--column-tolerance",
+        "rate=1",
+    ])
+    .unwrap();
+    assert_eq!(args.column_tolerances.len(), 1);
+
+    let json = run_json(&args);
+    assert_eq!(json["counts"]["numeric_cells_changed"], 1);
+    assert_eq!(json["config"]["overridden"], json!(["tolerances"]));
+    assert!(json["config"]["settings"].get("tolerances").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn columns_narrow_the_comparison() {
+    let dir = temp_dir();
+    let (old, new) = write_inputs(&dir);
+    let config = write_config(
+        &dir,
+        "key = \"id\"\nna-values = \"TBD\"\ncolumns = [\"rate\"]\n",
+    );
+
+    let args = Args::parse_from([
+        "rvl",
+        &old,
+        &new,
+        "--config",
+        &config,
+        "--no-witness",
+        "--json",
+    ])
+    .unwrap();
+    let json = run_json(&args);
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+    assert_eq!(json["contributors"][0]["column"], "u8:rate");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn invalid_config_is_an_argument_error() {
+    let dir = temp_dir();
+    let (old, new) = write_inputs(&dir);
+
+    let unknown = write_config(&dir, "keys = \"id\"\n");
+    let err = Args::parse_from(["rvl", &old, &new, "--config", &unknown]).unwrap_err();
+    assert!(err.to_string().contains("unknown setting `keys`"), "{err}");
+
+    let invalid = write_config(&dir, "top = 0\n");
+    assert!(Args::parse_from(["rvl", &old, &new, "--config", &invalid]).is_err());
+
+    let missing = dir.join("absent.toml").to_string_lossy().to_string();
+    assert!(Args::parse_from(["rvl", &old, &new, "--config", &missing]).is_err());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
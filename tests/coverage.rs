@@ -0,0 +1,146 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::diff::coverage::{CoverageMode, DiffusePolicy};
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_coverage_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// `big` moves by 1000 in one cell and carries total coverage alone; `small`
+// moves by 1 in each of three cells.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,big,small\nA,0,1\nB,0,1\nC,0,1\n").unwrap();
+    std::fs::write(&new_path, "id,big,small\nA,1000,2\nB,0,2\nC,0,2\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run(args: &Args) -> (u8, Value) {
+    let result = orchestrator::run(args).unwrap();
+    (
+        exit_code(result.outcome),
+        serde_json::from_str(&result.output).unwrap(),
+    )
+}
+
+#[test]
+fn total_coverage_lets_one_column_carry_the_threshold() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let (exit, json) = run(&args);
+    assert_eq!(exit, 1);
+    assert_eq!(json["contributors"].as_array().unwrap().len(), 1);
+    assert_eq!(json["contributors"][0]["column"], "u8:big");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn per_column_coverage_explains_every_changed_column() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.coverage = CoverageMode::PerColumn;
+
+    let (exit, json) = run(&args);
+    assert_eq!(exit, 1);
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors.len(), 4);
+    assert_eq!(contributors[0]["column"], "u8:big");
+    assert!(contributors[1..].iter().all(|c| c["column"] == "u8:small"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn per_column_coverage_refuses_naming_the_short_column() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.coverage = CoverageMode::PerColumn;
+    args.top = 2;
+
+    let (exit, json) = run(&args);
+    assert_eq!(exit, 2);
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert_eq!(json["refusal"]["detail"]["column"], "u8:small");
+    let coverage = json["refusal"]["detail"]["top_k_coverage"]
+        .as_f64()
+        .unwrap();
+    assert!((coverage - 2.0 / 3.0).abs() < 1e-12);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn per_column_expand_names_the_short_column() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, false);
+    args.coverage = CoverageMode::PerColumn;
+    args.on_diffuse = DiffusePolicy::Expand;
+    args.top = 2;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains(
+            "Diffuse change: the top contributors in small explain 66.7% of its change, below the threshold (95.0%)."
+        ),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn coverage_curve_accumulates_over_every_contributor() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let (_, json) = run(&args);
+    let curve: Vec<f64> = json["metrics"]["coverage_curve"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|value| value.as_f64().unwrap())
+        .collect();
+    assert_eq!(curve.len(), 4);
+    assert!((curve[0] - 1000.0 / 1003.0).abs() < 1e-12);
+    assert!((curve[3] - 1.0).abs() < 1e-12);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_summarizes_the_coverage_curve() {
+    let dir = temp_dir();
+    let args = make_args(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Coverage curve: top 1 99.7%, top 2 99.8%, top 4 100.0%."),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_cross_tab_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, cross_tab: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("field".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.cross_tab = cross_tab;
+    args
+}
+
+const COMP_GRID_OLD: &str = "field,comp1,comp2\n\
+rent,1500,1625\n\
+unit_type,2BR,3BR\n\
+sqft,900,1100\n";
+
+const COMP_GRID_NEW: &str = "field,comp1,comp2\n\
+rent,1550,1625\n\
+unit_type,2BR,3BR\n\
+sqft,900,1100\n";
+
+#[test]
+fn comp_grid_refuses_without_cross_tab() {
+    let dir = temp_dir();
+    let args = make_args(&dir, COMP_GRID_OLD, COMP_GRID_NEW, false);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(output.contains("rerun with --cross-tab"), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn comp_grid_refusal_flags_the_pivoted_shape() {
+    let dir = temp_dir();
+    let args = make_args(&dir, COMP_GRID_OLD, COMP_GRID_NEW, false);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    let refusal = &json["refusal"];
+    assert_eq!(refusal["detail"]["cross_tab"]["mixed_columns"], 2);
+    assert_eq!(refusal["detail"]["cross_tab"]["columns"], 2);
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("2 of 2 common columns are mixed"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Next: this looks like a pivoted/cross-tab table"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn one_stray_cell_is_not_flagged_as_a_cross_tab() {
+    let dir = temp_dir();
+    let old = "id,amount,units\nA-1,100,3\nA-2,TBD,4\n";
+    let new = "id,amount,units\nA-1,110,3\nA-2,200,4\n";
+    let mut args = make_args(&dir, old, new, false);
+    args.key = Some("id".to_string());
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+    assert!(json["refusal"]["detail"].get("cross_tab").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn comp_grid_compares_numeric_cells_with_cross_tab() {
+    let dir = temp_dir();
+    let args = make_args(&dir, COMP_GRID_OLD, COMP_GRID_NEW, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_columns"], 2);
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors.len(), 1);
+    assert_eq!(contributors[0]["row_id"], "u8:rent");
+    assert_eq!(contributors[0]["column"], "u8:comp1");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn cross_tab_still_refuses_a_cell_that_changes_type() {
+    let dir = temp_dir();
+    let new = COMP_GRID_NEW.replace("sqft,900", "sqft,TBD");
+    let args = make_args(&dir, COMP_GRID_OLD, &new, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -46,3 +46,14 @@ fn detects_backslash_escape_when_rfc_fails() {
     assert_eq!(dialect.delimiter, b',');
     assert_eq!(dialect.escape, EscapeMode::Backslash);
 }
+
+#[test]
+fn confidence_drops_when_another_delimiter_splits_the_sample() {
+    let clean = auto_detect(b"id,note\nA,x\nB,y\n").expect("should detect");
+    assert_eq!(clean.confidence, 1.0);
+
+    // Semicolons split the data rows too, but not the header.
+    let contested = auto_detect(b"id,note\nA,x;y\nB,z;w\n").expect("should detect");
+    assert_eq!(contested.delimiter, b',');
+    assert!((contested.confidence - 1.0 / 3.0).abs() < 1e-12);
+}
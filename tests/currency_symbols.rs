@@ -0,0 +1,79 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::{Value, json};
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_currency_symbols_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// A multi-currency rent roll: rent mixes `$` and `€`, deposit is in CHF.
+fn write_rent_roll(dir: &Path) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "unit,rent,deposit\nA,$1200,CHF500\nB,€950,CHF400\nC,£700,CHF300\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "unit,rent,deposit\nA,$1250,CHF500\nB,€950,CHF400\nC,£700,CHF300\n",
+    )
+    .unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("unit".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn builtin_symbols_parse_and_are_recorded_per_column() {
+    let dir = temp_dir();
+    let args = write_rent_roll(&dir);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+    assert_eq!(
+        json["column_normalizations"],
+        json!([{ "column": "u8:rent", "currency_symbols": ["$", "£", "€"] }])
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn configured_symbols_make_more_columns_numeric() {
+    let dir = temp_dir();
+    let mut args = write_rent_roll(&dir);
+    args.currency_symbols = vec!["CHF".to_string()];
+
+    let json = run_json(&args);
+    assert_eq!(json["counts"]["numeric_columns"], 2);
+    assert_eq!(
+        json["column_normalizations"][1],
+        json!({ "column": "u8:deposit", "currency_symbols": ["CHF"] })
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
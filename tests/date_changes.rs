@@ -0,0 +1,126 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_date_changes_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// lease_start moves A later by 30 days and B earlier by one; C's US-format
+// date is unchanged.
+fn write_leases(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,rent,lease_start\nA,100,2024-01-01\nB,200,2024-03-01\nC,300,6/15/2024\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "id,rent,lease_start\nA,100,2024-01-31\nB,200,02/29/2024\nC,300,2024-06-15\n",
+    )
+    .unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn date_shifts_are_reported_without_values_by_default() {
+    let dir = temp_dir();
+    let args = write_leases(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    let report = &json["date_changes"];
+    assert_eq!(report["columns"][0], "u8:lease_start");
+    assert_eq!(report["cells_changed"], 2);
+    let first = &report["changes"][0];
+    assert_eq!(first["kind"], "date_change");
+    assert_eq!(first["row_id"], "u8:A");
+    assert_eq!(first["direction"], "later");
+    assert!(first.get("shift_days").is_none());
+    assert_eq!(report["changes"][1]["direction"], "earlier");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn explicit_shows_dates_and_shift_in_days() {
+    let dir = temp_dir();
+    let mut args = write_leases(&dir, true);
+    args.explicit = true;
+
+    let json = run_json(&args);
+    let first = &json["date_changes"]["changes"][0];
+    assert_eq!(first["old"], "2024-01-01");
+    assert_eq!(first["new"], "2024-01-31");
+    assert_eq!(first["shift_days"], 30);
+    assert_eq!(json["date_changes"]["changes"][1]["shift_days"], -1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_lists_date_changes() {
+    let dir = temp_dir();
+    let mut args = write_leases(&dir, false);
+    args.explicit = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Date changes: 2 cells shifted in lease_start"),
+        "{output}"
+    );
+    assert!(
+        output.contains("  A.lease_start  +30 days (2024-01-01 -> 2024-01-31)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn columns_with_non_date_cells_are_not_dates() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,rent,note\nA,100,2024-01-01\nB,200,tbd\n").unwrap();
+    std::fs::write(&new_path, "id,rent,note\nA,100,2024-02-01\nB,200,tbd\n").unwrap();
+    let args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+
+    let json = run_json(&args);
+    assert!(json.get("date_changes").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
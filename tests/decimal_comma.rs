@@ -0,0 +1,75 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::parse::DecimalSeparator;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_decimal_comma_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_european(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "konto;betrag\nA;1.234,56\nB;10,5\n").unwrap();
+    std::fs::write(&new_path, "konto;betrag\nA;1.334,56\nB;10,5\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("konto".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn comma_decimals_parse_and_are_echoed_in_the_dialect() {
+    let dir = temp_dir();
+    let mut args = write_european(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["counts"]["numeric_columns"], 0);
+    assert!(json["dialect"]["old"].get("decimal").is_none());
+
+    args.decimal = DecimalSeparator::Comma;
+    args.explicit = true;
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["decimal"], "comma");
+    assert_eq!(json["dialect"]["new"]["decimal"], "comma");
+    assert_eq!(json["contributors"][0]["old"], 1234.56);
+    assert_eq!(json["contributors"][0]["delta"], 100.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_dialect_lines_show_decimal_comma() {
+    let dir = temp_dir();
+    let mut args = write_european(&dir, false);
+    args.decimal = DecimalSeparator::Comma;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Dialect(old): delimiter=; quote=\" escape=none decimal=comma"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,83 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::alignment::key_dedup::DedupMode;
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_dedup_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The old ledger splits account A over two lines; the new one books it once.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "account,balance\nA,60\nB,10\nA,40\n").unwrap();
+    std::fs::write(&new_path, "account,balance\nA,100\nB,25\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("account".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+#[test]
+fn duplicate_keys_refuse_by_default() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let result = orchestrator::run(&args).unwrap();
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(exit_code(result.outcome), 2);
+    assert_eq!(json["refusal"]["code"], "E_KEY_DUP");
+    assert!(json["alignment"].get("dedup").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn dedup_sum_aggregates_before_comparing() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.dedup = DedupMode::Sum;
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["alignment"]["dedup"]["mode"], "sum");
+    assert_eq!(json["alignment"]["dedup"]["rows_collapsed_old"], 1);
+    assert_eq!(json["alignment"]["dedup"]["rows_collapsed_new"], 0);
+    assert_eq!(json["metrics"]["total_change"], 15.0);
+    assert_eq!(json["contributors"][0]["row_id"], "u8:B");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn dedup_first_keeps_first_row_and_declares_it() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, false);
+    args.dedup = DedupMode::First;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Alignment: key=account (dedup=first: collapsed 1 old, 0 new rows)"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
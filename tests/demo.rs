@@ -0,0 +1,53 @@
+use std::process::Command;
+
+use serde_json::Value;
+
+fn rvl(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn demo_runs_all_three_outcomes() {
+    let (code, stdout) = rvl(&["demo", "--json"]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rvl.demo.v0");
+    assert_eq!(json["ok"], true);
+    let outcomes: Vec<&str> = json["demos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|demo| demo["result"]["outcome"].as_str().unwrap())
+        .collect();
+    assert_eq!(outcomes, ["REAL_CHANGE", "NO_REAL_CHANGE", "REFUSAL"]);
+    assert_eq!(
+        json["demos"][0]["command"],
+        "rvl demo/real-change/old.csv demo/real-change/new.csv --key loan_id"
+    );
+}
+
+#[test]
+fn single_scenario_exits_with_its_verdict_code() {
+    let (code, stdout) = rvl(&["demo", "real-change", "--json"]);
+    assert_eq!(code, Some(1), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rvl.v0");
+    assert_eq!(json["contributors"][0]["row_id"], "u8:LN-4117");
+
+    let (code, stdout) = rvl(&["demo", "refusal"]);
+    assert_eq!(code, Some(2));
+    assert!(stdout.contains("E_NEED_KEY"), "{stdout}");
+}
+
+#[test]
+fn unknown_scenario_is_a_usage_error() {
+    let (code, _) = rvl(&["demo", "everything"]);
+    assert_eq!(code, Some(2));
+}
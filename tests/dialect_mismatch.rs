@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_dialect_mismatch_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Same table, exported once with commas and once with semicolons.
+fn write_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,value\nA,1\nB,2\n").unwrap();
+    std::fs::write(&new_path, "id;value\nA;1\nB;2\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn differing_delimiters_are_warned_about() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["confidence"], 1.0);
+    assert_eq!(json["warnings"][0]["code"], "W_DIALECT_MISMATCH");
+    assert_eq!(json["warnings"][0]["old_delimiter"], ",");
+    assert_eq!(json["warnings"][0]["new_delimiter"], ";");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn strict_dialect_refuses_without_forcing_one_delimiter() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, true);
+    args.strict_dialect = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_DIALECT");
+    assert_eq!(json["refusal"]["detail"]["old_delimiter"], ",");
+    assert_eq!(json["refusal"]["detail"]["new_delimiter"], ";");
+    assert_eq!(
+        json["refusal"]["message"],
+        "old and new use different delimiters"
+    );
+    assert!(json["refusal"]["detail"].get("suggestion").is_none());
+
+    args.json = false;
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("old.csv delimiter comma but "), "{output}");
+    assert!(output.contains("new.csv delimiter semicolon."), "{output}");
+    assert!(
+        output.contains("Reason (E_DIALECT): old and new use different delimiters."),
+        "{output}"
+    );
+    assert!(
+        output.contains("Next: the files use different delimiters; re-export one"),
+        "{output}"
+    );
+    assert!(!output.contains("--delimiter"), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
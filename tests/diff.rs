@@ -4,7 +4,7 @@ use rvl::diff::order::{CellId, RowId, TieBreaker, sort_contributors};
 use rvl::diff::tolerance::ToleranceTracker;
 
 fn cell_id(row: usize, column: &str) -> CellId {
-    CellId::new(RowId::row_index(row), column.as_bytes().to_vec())
+    CellId::new(RowId::row_index(row), column.as_bytes().into())
 }
 
 #[test]
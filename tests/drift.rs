@@ -0,0 +1,109 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::drift::DriftMetric;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_drift_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+/// Every row of `shifted` moves by 2%; `steady` never changes.
+fn systematic_shift() -> (String, String) {
+    let mut old = String::from("id,shifted,steady\n");
+    let mut new = String::from("id,shifted,steady\n");
+    for idx in 0..40 {
+        let value = 100 + idx;
+        old.push_str(&format!("k{idx},{value},{idx}\n"));
+        new.push_str(&format!("k{idx},{},{idx}\n", f64::from(value) * 1.02));
+    }
+    (old, new)
+}
+
+#[test]
+fn drift_characterizes_a_diffuse_systematic_shift() {
+    let dir = temp_dir();
+    let (old, new) = systematic_shift();
+    let mut args = make_args(&dir, &old, &new, true);
+    args.drift = Some(DriftMetric::Ks);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert_eq!(json["drift"]["metric"], "ks");
+    let columns = json["drift"]["columns"].as_array().unwrap();
+    assert_eq!(columns.len(), 2);
+    assert_eq!(columns[0]["column"], "u8:shifted");
+    assert!(columns[0]["value"].as_f64().unwrap() > 0.0);
+    assert_eq!(columns[0]["pairs"], 40);
+    assert_eq!(columns[1]["column"], "u8:steady");
+    assert_eq!(columns[1]["value"], 0.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn drift_is_reported_with_human_verdicts() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, "id,v\nA,1\nB,2\n", "id,v\nA,1\nB,2\n", false);
+    args.drift = Some(DriftMetric::Psi);
+
+    let result = orchestrator::run(&args).unwrap();
+    assert!(result.output.contains("NO REAL CHANGE"));
+    assert!(
+        result
+            .output
+            .contains("Distribution drift (PSI):\n  v  0.0000  (2 pairs)")
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn drift_is_absent_unless_requested() {
+    let dir = temp_dir();
+    let (old, new) = systematic_shift();
+    let args = make_args(&dir, &old, &new, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert!(json.get("drift").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unknown_drift_metric_is_a_usage_error() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(["old.csv", "new.csv", "--drift", "chi2"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("psi, ks"), "{stderr}");
+}
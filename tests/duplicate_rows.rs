@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_duplicate_rows_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, key: Option<&str>, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+const OLD: &str = "id,v\nA,1\nB,2\nC,3\n";
+const NEW_WITH_REPEAT: &str = "id,v\nA,1\nB,2\nB,2\nC,3\n";
+
+#[test]
+fn duplicated_rows_are_reported_as_warnings() {
+    let dir = temp_dir();
+    let args = make_args(&dir, OLD, NEW_WITH_REPEAT, Some("id"), true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_KEY_DUP");
+    let warnings = json["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0]["code"], "W_DUPLICATE_ROWS");
+    assert_eq!(warnings[0]["file"], "new");
+    assert_eq!(warnings[0]["rows"], 1);
+    assert_eq!(warnings[0]["samples"][0]["record"], 3);
+    assert_eq!(warnings[0]["samples"][0]["duplicate_of"], 2);
+    assert_eq!(warnings[0]["deduped"], false);
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains(
+            "Warning: new.csv has 1 duplicated data row (record 3 repeats record 2); rerun with --dedupe-rows to drop them."
+        ),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn dedupe_rows_drops_repeats_before_alignment() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, OLD, NEW_WITH_REPEAT, Some("id"), true);
+    args.dedupe_rows = true;
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["rows_new"], 3);
+    assert_eq!(json["warnings"][0]["deduped"], true);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn clean_files_have_no_warnings() {
+    let dir = temp_dir();
+    let args = make_args(&dir, OLD, OLD, None, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert!(json.get("warnings").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
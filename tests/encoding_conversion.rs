@@ -0,0 +1,102 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::csv::encoding::EncodingMode;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_encoding_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn utf16le(text: &str) -> Vec<u8> {
+    let mut bytes = vec![0xFF, 0xFE];
+    bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+    bytes
+}
+
+fn args_for(dir: &Path, old: &[u8], new: &[u8]) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn auto_transcodes_an_excel_unicode_export() {
+    let dir = temp_dir();
+    let mut args = args_for(
+        &dir,
+        &utf16le("id,value\r\nA,1\r\nB,2\r\n"),
+        b"id,value\nA,1\nB,2\n",
+    );
+
+    assert_eq!(run_json(&args)["refusal"]["code"], "E_ENCODING");
+    args.json = false;
+    let human = orchestrator::run(&args).unwrap().output;
+    assert!(human.contains("--encoding auto"), "{human}");
+
+    args.json = true;
+    args.encoding = EncodingMode::Auto;
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["encoding"], "utf-16le");
+    assert!(json["dialect"]["new"].get("encoding").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn windows_1252_keys_match_their_utf8_spelling() {
+    let dir = temp_dir();
+    let mut args = args_for(
+        &dir,
+        b"id,value\nCaf\xE9,1\nB,2\n",
+        "id,value\nCaf\u{e9},1\nB,3\n".as_bytes(),
+    );
+    args.encoding = EncodingMode::Auto;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["encoding"], "windows-1252");
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn malformed_utf16_refuses() {
+    let dir = temp_dir();
+    let mut old = utf16le("id,value\nA,1\n");
+    old.push(b'x');
+    let mut args = args_for(&dir, &old, b"id,value\nA,1\n");
+    args.encoding = EncodingMode::Utf16;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_ENCODING");
+    assert_eq!(json["refusal"]["detail"]["issue"], "invalid_utf16");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
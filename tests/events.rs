@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use rvl::output::events::EventTarget;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_events_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run_with_events(dir: &Path, old: &str, new: &str) -> Vec<Value> {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    let events_path = dir.join("events.ndjson");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.events = Some(EventTarget::Path(events_path.clone()));
+    orchestrator::run(&args).unwrap();
+    std::fs::read_to_string(events_path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn names(events: &[Value]) -> Vec<&str> {
+    events
+        .iter()
+        .map(|event| event["event"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn verdict_run_streams_lifecycle_events() {
+    let dir = temp_dir();
+    let events = run_with_events(&dir, "id,v\nA,1\nB,2\n", "id;v\nA;1\nB;9\n");
+
+    assert_eq!(
+        names(&events),
+        [
+            "parse_started",
+            "dialect_detected",
+            "dialect_detected",
+            "verdict"
+        ]
+    );
+    assert!(events.iter().all(|event| event["version"] == "rvl.v0"));
+    assert_eq!(events[1]["file"], "old");
+    assert_eq!(events[1]["dialect"]["delimiter"], ",");
+    assert_eq!(events[2]["file"], "new");
+    assert_eq!(events[2]["dialect"]["delimiter"], ";");
+    assert_eq!(events[3]["outcome"], "REAL_CHANGE");
+    assert_eq!(events[3]["exit_code"], 1);
+}
+
+#[test]
+fn refusal_run_ends_with_refusal_event() {
+    let dir = temp_dir();
+    let events = run_with_events(
+        &dir,
+        "id,v,w\nA,1,1\nB,2,oops\n",
+        "id,v,w\nA,1,1\nB,3,oops\n",
+    );
+
+    let last = events.last().unwrap();
+    assert_eq!(last["event"], "refusal");
+    assert_eq!(last["code"], "E_MIXED_TYPES");
+    assert!(!names(&events).contains(&"verdict"));
+}
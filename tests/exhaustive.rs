@@ -52,24 +52,87 @@ fn make_args(old: &Path, new: &Path) -> Args {
     Args {
         old: Some(old.to_path_buf()),
         new: Some(new.to_path_buf()),
+        base: None,
         key: None,
         threshold: 0.95,
         tolerance: 1e-9,
         delimiter: None,
+        skip_rows: rvl::csv::input::SkipRows::default(),
+        skip_footer: rvl::csv::footer::FooterSkip::default(),
+        encoding: rvl::csv::encoding::EncodingMode::default(),
+        comment: None,
+        strict_dialect: false,
         exhaustive: false,
         audit_fields: false,
         max_audit_changes: 10_000,
+        max_memory: None,
         profile: None,
         profile_id: None,
         capsule_out: None,
         json: true,
+        format: None,
+        output_version: rvl::output::json::OutputVersion::V0,
         no_witness: true,
         describe: false,
         explicit: false,
         schema: false,
         version: false,
+        show_subtolerance: None,
+        explore: None,
+        report_xlsx: None,
+        schema_only: false,
+        drift: None,
+        cross_tab: false,
+        output_stream: rvl::cli::exit::StreamPolicy::Auto,
+        expected: None,
+        expect: None,
+        expect_json: None,
+        dedupe_rows: false,
+        key_map: None,
+        key_normalize: Vec::new(),
+        smart_tolerance: false,
+        min_header_overlap: 0.5,
+        top: 25,
+        rank: rvl::diff::rank::RankMode::Abs,
+        weight_col: None,
+        report_csv: None,
+        events: None,
+        timings: false,
+        allow_missing_keys: false,
+        include_entity_changes: false,
+        allow_trailing_rows: 0,
+        where_filters: Vec::new(),
+        sample: None,
+        seed: 0,
+        dedup: rvl::alignment::key_dedup::DedupMode::Refuse,
+        group_by: None,
+        column_map: Vec::new(),
+        header_match: rvl::normalize::headers::HeaderMatch::Exact,
+        parse_percent: false,
+        decimal: rvl::numeric::parse::DecimalSeparator::Dot,
+        currency_symbols: Vec::new(),
+        lenient_numbers: false,
+        include_text: false,
+        na_values: Vec::new(),
+        on_missing: rvl::numeric::missing::MissingPolicy::Refuse,
+        on_mixed_types: rvl::numeric::columns::MixedTypesPolicy::Refuse,
+        collect_refusals: false,
+        on_diffuse: rvl::diff::coverage::DiffusePolicy::Refuse,
+        coverage: rvl::diff::coverage::CoverageMode::Total,
+        columns: Vec::new(),
+        column_tolerances: Vec::new(),
+        transforms: Vec::new(),
+        tolerance_sweep: None,
+        config: None,
+        no_config: false,
+        config_receipt: None,
+        inline: Default::default(),
+        threads: 0,
         robot_triage: false,
         command: None,
+        watch: false,
+        assert: false,
+        param_sources: Default::default(),
     }
 }
 
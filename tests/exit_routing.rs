@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
 use rvl::cli::exit::{Outcome, OutputMode, OutputStream, exit_code, output_stream};
 
 #[test]
@@ -38,3 +41,62 @@ fn human_mode_refusals_to_stderr() {
         OutputStream::Stderr
     );
 }
+
+fn write_pair(name: &str, old: &str, new: &str) -> (PathBuf, PathBuf) {
+    let dir = std::env::temp_dir().join(format!(
+        "rvl_test_exit_routing_{name}_{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    (old_path, new_path)
+}
+
+fn run_rvl(old: &PathBuf, new: &PathBuf, extra: &[&str], env: Option<&str>) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_rvl"));
+    command
+        .arg(old)
+        .arg(new)
+        .args(["--no-witness"])
+        .args(extra)
+        .env_remove("RVL_OUTPUT_STREAM");
+    if let Some(value) = env {
+        command.env("RVL_OUTPUT_STREAM", value);
+    }
+    command.output().unwrap()
+}
+
+#[test]
+fn output_stream_stdout_keeps_human_refusals_on_stdout() {
+    let (old, new) = write_pair("stdout", "id,v\nA,1\n", "id,v\nB,1\n");
+    let output = run_rvl(
+        &old,
+        &new,
+        &["--key", "id", "--output-stream", "stdout"],
+        None,
+    );
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("RVL ERROR"));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn output_stream_env_routes_verdicts_to_stderr() {
+    let (old, new) = write_pair("stderr", "id,v\nA,1\n", "id,v\nA,2\n");
+    let output = run_rvl(&old, &new, &["--key", "id"], Some("stderr"));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("REAL CHANGE"));
+
+    // An explicit flag wins over the environment default.
+    let output = run_rvl(
+        &old,
+        &new,
+        &["--key", "id", "--output-stream", "auto"],
+        Some("stderr"),
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("REAL CHANGE"));
+}
@@ -0,0 +1,111 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_expected_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+const OLD: &str = "id,v\nA,100\nB,200\nC,300\n";
+const APPROVED: &str = "id,v\nA,150\nB,200\nC,300\n";
+const CURRENT: &str = "id,v\nA,150\nB,260\nC,300\n";
+
+/// Run the approved comparison and save its JSON as the baseline.
+fn write_baseline(dir: &Path) -> PathBuf {
+    let approved_dir = dir.join("approved");
+    std::fs::create_dir_all(&approved_dir).unwrap();
+    let args = make_args(&approved_dir, OLD, APPROVED, true);
+    let path = dir.join("approved.json");
+    std::fs::write(&path, orchestrator::run(&args).unwrap().output).unwrap();
+    path
+}
+
+#[test]
+fn expected_marks_approved_and_new_contributors() {
+    let dir = temp_dir();
+    let baseline = write_baseline(&dir);
+    let mut args = make_args(&dir, OLD, CURRENT, true);
+    args.expected = Some(baseline);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["expected"]["approved"], 1);
+    assert_eq!(json["expected"]["new"], 1);
+    assert_eq!(json["expected"]["verdict"], "NEW_CHANGE");
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors[0]["row_id"], "u8:B");
+    assert_eq!(contributors[0]["baseline"], "new");
+    assert_eq!(contributors[1]["row_id"], "u8:A");
+    assert_eq!(contributors[1]["baseline"], "approved");
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("NEW CHANGE (1 new, 1 approved)\nNew since baseline:\n  B.v"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn rerunning_the_approved_comparison_has_no_new_change() {
+    let dir = temp_dir();
+    let baseline = write_baseline(&dir);
+    let mut args = make_args(&dir, OLD, APPROVED, true);
+    args.expected = Some(baseline);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["expected"]["verdict"], "NO_NEW_CHANGE");
+    assert_eq!(json["expected"]["new"], 0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn invalid_expected_baseline_refuses() {
+    let dir = temp_dir();
+    let baseline = dir.join("notes.json");
+    std::fs::write(&baseline, "{\"version\":\"other\"}").unwrap();
+    let mut args = make_args(&dir, OLD, CURRENT, true);
+    args.expected = Some(baseline);
+
+    let result = orchestrator::run(&args).unwrap();
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_EXPECTED");
+    assert_eq!(
+        json["refusal"]["detail"]["reason"],
+        "not an rvl.v0 JSON result"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,112 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_explore_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_file(dir: &Path, name: &str, content: &str) -> PathBuf {
+    let path = dir.join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn embedded_data(html: &str) -> Value {
+    let start = html
+        .find(r#"<script id="rvl-data" type="application/json">"#)
+        .expect("data script");
+    let rest = &html[start..];
+    let open = rest.find('>').unwrap() + 1;
+    let close = rest.find("</script>").unwrap();
+    serde_json::from_str(&rest[open..close]).expect("embedded JSON")
+}
+
+fn make_args(dir: &Path, old: &str, new: &str) -> Args {
+    let old = write_file(dir, "old.csv", old);
+    let new = write_file(dir, "new.csv", new);
+    let mut args = Args::new(old, new, Some("id".to_string()), 0.95, 1e-9, None, true);
+    args.explore = Some(dir.join("report").join("explore.html"));
+    args
+}
+
+#[test]
+fn explore_writes_full_ledger_beyond_explanation_prefix() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "id,a,b\nA,100,1\nB,200,2\nC,300,3\n",
+        "id,a,b\nA,200,1.5\nB,200,2\nC,300,3.1\n",
+    );
+
+    let result = orchestrator::run(&args).unwrap();
+    let verdict: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(verdict["outcome"], "REAL_CHANGE");
+
+    let html = std::fs::read_to_string(args.explore.as_ref().unwrap()).unwrap();
+    let data = embedded_data(&html);
+    assert_eq!(data["outcome"], "REAL CHANGE");
+    assert_eq!(data["cells_changed"], 3);
+    let cells = data["cells"].as_array().unwrap();
+    assert_eq!(cells.len(), 3);
+    assert_eq!(cells[0]["row"], "A");
+    assert_eq!(cells[0]["column"], "a");
+    assert!(cells[0].get("old").is_none(), "values redacted by default");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn explore_is_written_for_diffuse_refusal() {
+    let dir = temp_dir();
+    let mut old = String::from("id,v\n");
+    let mut new = String::from("id,v\n");
+    for idx in 0..40 {
+        old.push_str(&format!("k{idx},1\n"));
+        new.push_str(&format!("k{idx},2\n"));
+    }
+    let args = make_args(&dir, &old, &new);
+
+    let result = orchestrator::run(&args).unwrap();
+    let verdict: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(verdict["refusal"]["code"], "E_DIFFUSE");
+
+    let html = std::fs::read_to_string(args.explore.as_ref().unwrap()).unwrap();
+    let data = embedded_data(&html);
+    assert_eq!(data["outcome"], "REFUSAL");
+    assert_eq!(data["cells"].as_array().unwrap().len(), 40);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn explore_cells_link_to_source_lines() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,a\nA,1\nB,2\n\nC,3\n", "id,a\nC,3\nB,2\nA,9\n");
+
+    orchestrator::run(&args).unwrap();
+    let html = std::fs::read_to_string(args.explore.as_ref().unwrap()).unwrap();
+    let data = embedded_data(&html);
+    let href = data["old_href"].as_str().unwrap();
+    assert!(
+        href.starts_with("file:///") && href.ends_with("/old.csv"),
+        "{href}"
+    );
+    let cell = &data["cells"][0];
+    assert_eq!(cell["row"], "A");
+    assert_eq!(cell["old_line"], 2);
+    assert_eq!(cell["new_line"], 4);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
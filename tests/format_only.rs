@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_format_only_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+#[test]
+fn reformatted_values_are_counted_but_not_changes() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "id,amount,fee\nA,1000,-5\nB,2000,3\n",
+        "id,amount,fee\nA,\"1,000.00\",(5)\nB,2000,3\n",
+        true,
+    );
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["format_only_changes"], 2);
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("Format-only changes: 2 numeric cells (same value, different text)."),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn format_only_count_is_omitted_when_zero() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,v\nA,1\n", "id,v\nA,2\n", true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert!(json["counts"].get("format_only_changes").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
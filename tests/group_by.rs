@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_group_by_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Positions move between lines, but only account B's total changes.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "account,position,mv\nA,p1,60\nA,p2,40\nB,p3,10\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "account,position,mv\nB,p3,12\nA,p1,30\nA,p2,50\nA,p4,20\n",
+    )
+    .unwrap();
+    let mut args = Args::new(old_path, new_path, None, 0.95, 1e-9, None, json);
+    args.group_by = Some("account".to_string());
+    args
+}
+
+#[test]
+fn group_by_compares_group_totals() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["alignment"]["mode"], "group_by");
+    assert_eq!(json["alignment"]["key_column"], "u8:account");
+    assert_eq!(json["alignment"]["dedup"]["rows_collapsed_old"], 1);
+    assert_eq!(json["alignment"]["dedup"]["rows_collapsed_new"], 2);
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+    assert_eq!(json["metrics"]["total_change"], 2.0);
+    assert_eq!(json["contributors"][0]["row_id"], "u8:B");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn group_by_is_declared_in_human_alignment_line() {
+    let dir = temp_dir();
+    let args = make_args(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output
+            .contains("Alignment: group-by=account (sum per group) (collapsed 1 old, 2 new rows)"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
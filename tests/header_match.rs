@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::normalize::headers::HeaderMatch;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_header_match_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pair(dir: &Path, old: &str, new: &str, key: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some(key.to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn loose_matching_pairs_case_and_whitespace_variants() {
+    let dir = temp_dir();
+    let mut args = write_pair(
+        &dir,
+        "\u{EF}\u{BB}\u{BF}ID,Unit Count\nA,10\nB,20\n",
+        "id,\"unit  count\"\nA,10\nB,25\n",
+        "Id",
+    );
+
+    // Exact matching sees no shared columns.
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_HEADER_OVERLAP");
+
+    args.header_match = HeaderMatch::Loose;
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["columns_common"], 1);
+    assert_eq!(json["contributors"][0]["column"], "u8:unit count");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn headers_that_fold_together_are_duplicates() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, "id,Amount,amount\nA,1,2\n", "id,amount\nA,1\n", "id");
+    args.header_match = HeaderMatch::Loose;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_HEADERS");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_header_overlap_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+// Only `total` survives a header rename; its values are unchanged.
+const OLD: &str = "id,total,rent,fees,taxes\nA,10,1,2,3\nB,20,4,5,6\n";
+const NEW: &str = "id,total,rent_usd,fees_usd,taxes_usd\nA,10,100,200,300\nB,20,400,500,600\n";
+
+#[test]
+fn renamed_headers_refuse_instead_of_a_false_no_change() {
+    let dir = temp_dir();
+    let args = make_args(&dir, OLD, NEW);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REFUSAL");
+    assert_eq!(json["refusal"]["code"], "E_HEADER_OVERLAP");
+    assert_eq!(json["refusal"]["detail"]["columns_common"], 1);
+    assert_eq!(json["refusal"]["detail"]["overlap"], 0.25);
+    assert_eq!(json["counts"]["columns_old_only"], 3);
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("only 1 of 4 old / 4 new columns share a header name"),
+        "{output}"
+    );
+    assert!(output.contains("--min-header-overlap 0"), "{output}");
+    // `rent` -> `rent_usd` is too far apart to suggest, so Next names the flag.
+    assert!(
+        output.contains("Next: pair renamed columns with --map OLD=NEW"),
+        "{output}"
+    );
+    assert!(!output.contains("column_registry"), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn suggested_maps_fill_in_the_next_command() {
+    let dir = temp_dir();
+    let mut args = make_args(
+        &dir,
+        "id,total,Unit Count,net_operating_income\nA,1,2,3\n",
+        "id,total,unit_count,noi\nA,1,2,3\n",
+    );
+    args.json = false;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("E_HEADER_OVERLAP"), "{output}");
+    let next = output
+        .lines()
+        .find(|line| line.starts_with("Next: "))
+        .expect("refusal has a Next line");
+    assert!(
+        next.contains(" --map 'Unit Count=unit_count' --map net_operating_income=noi"),
+        "{next}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn min_header_overlap_zero_compares_the_shared_columns() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, OLD, NEW);
+    args.min_header_overlap = 0.0;
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["columns_common"], 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn added_columns_keep_full_overlap() {
+    let dir = temp_dir();
+    let new = "id,total,rent,fees,taxes,a,b,c,d\nA,10,1,2,3,0,0,0,0\nB,20,4,5,6,0,0,0,0\n";
+    let args = make_args(&dir, OLD, new);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
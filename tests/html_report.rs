@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::orchestrator;
+use rvl::output::ReportFormat;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_html_report_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        false,
+    );
+    args.format = Some(ReportFormat::Html);
+    args
+}
+
+#[test]
+fn html_report_renders_real_change_with_contributor_bars() {
+    let dir = temp_dir();
+    let mut args = make_args(
+        &dir,
+        "id,amount\nA,100\n<b>,50\n",
+        "id,amount\nA,100\n<b>,20\n",
+    );
+    args.explicit = true;
+
+    let result = orchestrator::run(&args).unwrap();
+    let html = &result.output;
+    assert_eq!(exit_code(result.outcome), 1);
+    assert!(html.starts_with("<!DOCTYPE html>"), "output:\n{html}");
+    assert!(html.contains("<h1>REAL CHANGE</h1>"), "output:\n{html}");
+    assert!(html.contains("<tr><th>Alignment</th><td>key=id</td></tr>"));
+    assert!(
+        html.contains("<td>&lt;b&gt;</td><td>amount</td>"),
+        "output:\n{html}"
+    );
+    assert!(
+        html.contains("<td class=\"num\">-30</td>"),
+        "output:\n{html}"
+    );
+    assert!(html.contains("<span class=\"down\" style=\"width:50.0%\">"));
+    assert!(!html.contains("<script"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn html_report_redacts_values_by_default() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,amount\nA,100\n", "id,amount\nA,120\n");
+
+    let html = orchestrator::run(&args).unwrap().output;
+    assert!(html.contains("Values are redacted"), "output:\n{html}");
+    assert!(!html.contains("<th>Delta</th>"));
+    assert!(html.contains("<span class=\"share\" style=\"width:100.0%\">"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn html_report_explains_refusals() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,amount\nA,1\nA,2\n", "id,amount\nA,3\n");
+
+    let result = orchestrator::run(&args).unwrap();
+    let html = &result.output;
+    assert_eq!(exit_code(result.outcome), 2);
+    assert!(
+        html.contains("<h1>REFUSAL (E_KEY_DUP)</h1>"),
+        "output:\n{html}"
+    );
+    assert!(html.contains("<h2>Why rvl refused</h2>"), "output:\n{html}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::csv::footer::FooterSkip;
+use rvl::csv::input::SkipRows;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_identical_files_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &std::path::Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn identical_inputs_parse_once_and_report_counts() {
+    let dir = temp_dir();
+    let csv = "id,amount\nA,1\nB,2\n";
+    let args = make_args(&dir, csv, csv, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["identical_files"], true);
+    assert_eq!(json["alignment"]["key_column"], "u8:id");
+    assert_eq!(json["dialect"]["old"], json["dialect"]["new"]);
+    assert_eq!(json["counts"]["rows_old"], 2);
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+    assert_eq!(json["counts"]["numeric_cells_checked"], 2);
+    assert_eq!(json["metrics"]["max_abs_delta"], 0.0);
+
+    let mut human_args = args.clone();
+    human_args.json = false;
+    let output = orchestrator::run(&human_args).unwrap().output;
+    assert!(output.contains("\nNO REAL CHANGE\n"), "{output}");
+    assert!(output.contains("Checked: 2 rows"), "{output}");
+    assert!(
+        output.contains("Inputs are byte-identical (blake3:"),
+        "{output}"
+    );
+    assert!(output.contains("parsed once for both sides."), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+fn assert_identical_refusal(args: &Args, code: &str) {
+    let json = run_json(args);
+    assert_eq!(json["outcome"], "REFUSAL", "{json}");
+    assert_eq!(json["refusal"]["code"], code, "{json}");
+    assert!(json.get("identical_files").is_none());
+}
+
+#[test]
+fn identical_binary_inputs_still_refuse() {
+    let dir = temp_dir();
+    let bytes = b"id,amount\nA,\x001\n";
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, bytes).unwrap();
+    std::fs::write(&new_path, bytes).unwrap();
+    let args = Args::new(old_path, new_path, None, 0.95, 1e-9, None, true);
+    assert_identical_refusal(&args, "E_ENCODING");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn identical_inputs_without_the_key_column_still_refuse() {
+    let dir = temp_dir();
+    let csv = "code,amount\nA,1\nB,2\n";
+    let args = make_args(&dir, csv, csv, true);
+    assert_identical_refusal(&args, "E_NO_KEY");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn identical_inputs_with_duplicate_keys_still_refuse() {
+    let dir = temp_dir();
+    let csv = "id,amount\nA,1\nA,2\n";
+    let args = make_args(&dir, csv, csv, true);
+    assert_identical_refusal(&args, "E_KEY_DUP");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn differing_inputs_and_per_side_rules_parse_both_files() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "id,amount\nA,1\nB,2\n",
+        "id,amount\nA,1\nB,2.0\n",
+        true,
+    );
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert!(json.get("identical_files").is_none());
+    assert_eq!(json["counts"]["rows_old"], 2);
+
+    // The old file drops its last row, so identical bytes read differently.
+    let csv = "id,amount\nA,1\nB,2\n";
+    let mut args = make_args(&dir, csv, csv, true);
+    args.skip_footer = FooterSkip::Rows(SkipRows { old: 1, new: 0 });
+    let json = run_json(&args);
+    assert!(json.get("identical_files").is_none(), "{json}");
+    assert_eq!(json["outcome"], "REFUSAL", "{json}");
+    assert_eq!(json["refusal"]["code"], "E_KEY_MISMATCH", "{json}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_key_map_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, map: &str, key: Option<&str>) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    let map_path = dir.join("map.csv");
+    std::fs::write(&old_path, OLD).unwrap();
+    std::fs::write(&new_path, NEW).unwrap();
+    std::fs::write(&map_path, map).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.key_map = Some(map_path);
+    args
+}
+
+const OLD: &str = "loan_id,balance\nL-1,100\nL-2,200\nLN-003,300\n";
+const NEW: &str = "loan_id,balance\nLN-001,100\nLN-002,250\nLN-003,300\n";
+
+#[test]
+fn key_map_translates_old_keys_before_the_join() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "old_id,new_id\nL-1,LN-001\nL-2,LN-002\n",
+        Some("loan_id"),
+    );
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["alignment"]["key_map"]["entries"], 2);
+    assert_eq!(json["contributors"][0]["row_id"], "u8:LN-002");
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(
+        output.contains("Alignment: key=loan_id (old keys via "),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn without_key_map_the_migration_is_a_key_mismatch() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, "old_id,new_id\nL-1,LN-001\n", Some("loan_id"));
+    args.key_map = None;
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_KEY_MISMATCH");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn conflicting_or_keyless_key_map_refuses() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "old_id,new_id\nL-1,LN-001\nL-1,LN-009\n",
+        Some("loan_id"),
+    );
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_KEY_MAP");
+    assert_eq!(
+        json["refusal"]["detail"]["reason"],
+        "old key \"L-1\" maps to more than one new key"
+    );
+
+    let args = make_args(&dir, "old_id,new_id\nL-1,LN-001\n", None);
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_KEY_MAP");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
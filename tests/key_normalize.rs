@@ -0,0 +1,119 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::alignment::key_normalize::KeyNormalizeStep;
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_key_normalize_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The new export upper-cases and space-pads the same account IDs.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,balance\nab1234,100\ncd77,50\n").unwrap();
+    std::fs::write(&new_path, "id,balance\n AB1234 ,100\nCD77,80\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+#[test]
+fn unnormalized_keys_refuse_with_a_normalize_hint() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let result = orchestrator::run(&args).unwrap();
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(exit_code(result.outcome), 2);
+    assert_eq!(json["refusal"]["code"], "E_KEY_MISMATCH");
+    let hint = &json["refusal"]["detail"]["normalize_hint"];
+    assert_eq!(hint["step"], "lower");
+    assert_eq!(hint["matched"], 2);
+    assert_eq!(hint["missing"], 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn key_mismatch_hint_names_the_flag_in_human_output() {
+    let dir = temp_dir();
+    let args = make_args(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains(
+            "2 of 2 missing keys match extras case-insensitively; consider --key-normalize lower."
+        ),
+        "output:\n{output}"
+    );
+    assert!(
+        output.contains("Next: rerun with --key-normalize lower"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn key_normalize_lower_joins_and_echoes_recipe() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.key_normalize = vec![KeyNormalizeStep::Trim, KeyNormalizeStep::Lower];
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(
+        json["alignment"]["key_normalize"],
+        serde_json::json!(["trim", "lower"])
+    );
+    assert_eq!(json["contributors"][0]["row_id"], "u8:cd77");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn key_normalize_strips_leading_zeros() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,balance\n0001234,100\n0042,50\n").unwrap();
+    std::fs::write(&new_path, "id,balance\n1234,100\n42,50\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        false,
+    );
+    args.key_normalize = vec![KeyNormalizeStep::StripLeadingZeros];
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Alignment: key=id (keys normalized: strip-leading-zeros)"),
+        "output:\n{output}"
+    );
+    assert!(output.contains("NO REAL CHANGE"), "output:\n{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
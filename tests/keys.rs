@@ -1,9 +1,10 @@
 use rvl::alignment::key_join::{KeyJoinError, build_key_map, join_key_maps};
 use rvl::alignment::key_parse::parse_key_identifier;
+use rvl::csv::records::OwnedRecord;
 use rvl::refusal::details::{RefusalDetail, RefusalKind, RerunPaths};
 
-fn record(fields: &[&[u8]]) -> Vec<Vec<u8>> {
-    fields.iter().map(|field| field.to_vec()).collect()
+fn record(fields: &[&[u8]]) -> OwnedRecord {
+    fields.iter().copied().collect()
 }
 
 #[test]
@@ -60,6 +61,7 @@ fn key_set_mismatch_reports_samples() {
             extra_count: 1,
             missing_samples: vec![b"B".to_vec()],
             extra_samples: vec![b"C".to_vec()],
+            normalize_hint: None,
         }
     );
 }
@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_lenient_numbers_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// SAP-style export: U+2212 minus, thin-space thousands, NBSP padding.
+fn write_sap_export(dir: &Path) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "account,balance\n4000,\u{2212}1\u{2009}250.00\n4100,\u{00A0}980.50\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "account,balance\n4000,\u{2212}1\u{2009}450.00\n4100,\u{00A0}980.50\n",
+    )
+    .unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("account".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn unicode_formatted_numbers_are_text_by_default() {
+    let dir = temp_dir();
+    let args = write_sap_export(&dir);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_NO_NUMERIC");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn lenient_numbers_compare_unicode_formatted_values() {
+    let dir = temp_dir();
+    let mut args = write_sap_export(&dir);
+    args.lenient_numbers = true;
+    args.explicit = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+    assert_eq!(json["contributors"][0]["old"], -1250.0);
+    assert_eq!(json["contributors"][0]["delta"], -200.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_max_memory_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, key: Option<&str>, max_memory: u64) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,10\nB,20\nC,30\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,10\nB,25\nC,30\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.max_memory = Some(max_memory);
+    args
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn refuses_when_estimate_exceeds_cap() {
+    let dir = temp_dir();
+    let args = make_args(&dir, Some("id"), 64);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REFUSAL");
+    assert_eq!(json["refusal"]["code"], "E_RESOURCE");
+    let detail = &json["refusal"]["detail"];
+    assert_eq!(detail["max_memory"], 64);
+    let estimated = detail["estimated_bytes"].as_u64().unwrap();
+    let row_order = detail["row_order_bytes"].as_u64().unwrap();
+    assert!(estimated > row_order && row_order > 64, "{detail}");
+    assert_eq!(json["memory"]["estimated_peak_bytes"], estimated);
+
+    let mut human = args.clone();
+    human.json = false;
+    let output = orchestrator::run(&human).unwrap().output;
+    assert!(output.contains("E_RESOURCE"), "{output}");
+    assert!(output.contains("exceeds --max-memory 64 B"), "{output}");
+    assert!(
+        output.contains(&format!("--max-memory {row_order}")),
+        "{output}"
+    );
+}
+
+#[test]
+fn row_order_refusal_has_no_row_order_alternative() {
+    let dir = temp_dir();
+    let json = run_json(&make_args(&dir, None, 64));
+    assert_eq!(json["refusal"]["code"], "E_RESOURCE");
+    assert!(json["refusal"]["detail"]["row_order_bytes"].is_null());
+}
+
+#[test]
+fn generous_cap_runs_and_reports_the_estimate() {
+    let dir = temp_dir();
+    let json = run_json(&make_args(&dir, Some("id"), 1 << 30));
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["memory"]["max_memory"], 1u64 << 30);
+    assert!(json["memory"]["estimated_peak_bytes"].as_u64().unwrap() > 0);
+
+    let mut unguarded = make_args(&dir, Some("id"), 1);
+    unguarded.max_memory = None;
+    assert!(run_json(&unguarded).get("memory").is_none());
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_na_values_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_placeholders(dir: &Path) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,TBD\nC,300\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,150\nB, tbd \nC,300\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn placeholder_is_text_by_default() {
+    let dir = temp_dir();
+    let args = write_placeholders(&dir);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn na_values_treat_placeholder_as_missing() {
+    let dir = temp_dir();
+    let mut args = write_placeholders(&dir);
+    args.na_values = vec!["TBD".to_string()];
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["metrics"]["total_change"], 50.0);
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -1,6 +1,9 @@
-use rvl::numeric::columns::{ColumnTypingError, CommonColumn, Side, detect_numeric_columns};
-use rvl::numeric::missing::is_missing_token;
+use rvl::numeric::columns::{
+    ColumnTypingError, CommonColumn, MixedTypesPolicy, Side, detect_numeric_columns,
+};
+use rvl::numeric::missing::{MissingPolicy, is_missing_token};
 use rvl::numeric::no_numeric::{NoNumericError, ensure_numeric_columns};
+use rvl::numeric::parse::NumberFormat;
 
 #[test]
 fn missing_tokens_case_insensitive_and_trimmed() {
@@ -42,7 +45,14 @@ fn mixed_types_refused_when_numeric_then_text() {
         (1u64, record(&[b"10"]), record(&[b"11"])),
         (2u64, record(&[b"oops"]), record(&[b"12"])),
     ];
-    let err = detect_numeric_columns(&columns, rows).expect_err("mixed types");
+    let err = detect_numeric_columns(
+        &columns,
+        rows,
+        NumberFormat::default(),
+        MissingPolicy::Refuse,
+        MixedTypesPolicy::Refuse,
+    )
+    .expect_err("mixed types");
     match err {
         ColumnTypingError::MixedTypes(detail) => {
             assert_eq!(detail.row_id, 2);
@@ -57,7 +67,14 @@ fn mixed_types_refused_when_numeric_then_text() {
 fn missingness_refused_when_one_side_missing_numeric() {
     let columns = vec![column("amount", 0, 0)];
     let rows = vec![(7u64, record(&[b""]), record(&[b"9"]))];
-    let err = detect_numeric_columns(&columns, rows).expect_err("missingness");
+    let err = detect_numeric_columns(
+        &columns,
+        rows,
+        NumberFormat::default(),
+        MissingPolicy::Refuse,
+        MixedTypesPolicy::Refuse,
+    )
+    .expect_err("missingness");
     match err {
         ColumnTypingError::Missingness(detail) => {
             assert_eq!(detail.row_id, 7);
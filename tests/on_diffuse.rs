@@ -0,0 +1,110 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::diff::coverage::DiffusePolicy;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_on_diffuse_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Six cells move by 1 (five in `a`, one in `b`); with `--top 2` no prefix of
+// contributors reaches the threshold.
+fn write_diffuse(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,a,b\nA,1,1\nB,1,1\nC,1,1\nD,1,1\nE,1,1\n").unwrap();
+    std::fs::write(&new_path, "id,a,b\nA,2,2\nB,2,1\nC,2,1\nD,2,1\nE,2,1\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    );
+    args.top = 2;
+    args
+}
+
+fn run(args: &Args) -> (u8, String) {
+    let result = orchestrator::run(args).unwrap();
+    (exit_code(result.outcome), result.output)
+}
+
+#[test]
+fn diffuse_change_refuses_by_default() {
+    let dir = temp_dir();
+    let args = write_diffuse(&dir, true);
+
+    let (exit, output) = run(&args);
+    let json: Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(exit, 2);
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert!(json.get("by_column").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn expand_reports_real_change_with_column_rollup() {
+    let dir = temp_dir();
+    let mut args = write_diffuse(&dir, true);
+    args.on_diffuse = DiffusePolicy::Expand;
+
+    let (exit, output) = run(&args);
+    let json: Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(exit, 1);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["diffuse"]["threshold"], 0.95);
+    let coverage = json["diffuse"]["top_k_coverage"].as_f64().unwrap();
+    assert!((coverage - 2.0 / 6.0).abs() < 1e-12);
+    assert_eq!(json["contributors"].as_array().unwrap().len(), 2);
+
+    let by_column = json["by_column"].as_array().unwrap();
+    assert_eq!(by_column.len(), 2);
+    assert_eq!(by_column[0]["column"], "u8:a");
+    assert_eq!(by_column[0]["contribution"], 5.0);
+    assert_eq!(by_column[0]["cells_changed"], 5);
+    assert_eq!(by_column[1]["column"], "u8:b");
+    assert_eq!(by_column[1]["cells_changed"], 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn expand_lists_columns_in_human_output() {
+    let dir = temp_dir();
+    let mut args = write_diffuse(&dir, false);
+    args.on_diffuse = DiffusePolicy::Expand;
+
+    let (exit, output) = run(&args);
+    assert_eq!(exit, 1);
+    assert!(output.contains("REAL CHANGE"), "output:\n{output}");
+    assert!(
+        output.contains("Diffuse change: the top contributors explain 33.3% of total change"),
+        "output:\n{output}"
+    );
+    assert!(
+        output.contains("  a  83.3% of total change  (5 cells)"),
+        "output:\n{output}"
+    );
+    assert!(
+        output.contains("  b  16.7% of total change  (1 cell)"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
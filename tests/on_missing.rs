@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::missing::MissingPolicy;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_on_missing_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// B is blank in the new file; A moves by 10.
+fn write_gap(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,40\nC,300\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,110\nB,\nC,300\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn missing_opposite_number_refuses_by_default() {
+    let dir = temp_dir();
+    let args = write_gap(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_MISSINGNESS");
+    assert!(json.get("missing_policy").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn skip_leaves_the_cell_out_and_counts_it() {
+    let dir = temp_dir();
+    let mut args = write_gap(&dir, true);
+    args.on_missing = MissingPolicy::Skip;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["missing_policy"], "skip");
+    assert_eq!(json["counts"]["missing_skipped"], 1);
+    assert!(json["counts"].get("missing_zeroed").is_none());
+    assert_eq!(json["metrics"]["total_change"], 10.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn zero_reads_the_missing_side_as_zero() {
+    let dir = temp_dir();
+    let mut args = write_gap(&dir, true);
+    args.on_missing = MissingPolicy::Zero;
+    args.explicit = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["missing_policy"], "zero");
+    assert_eq!(json["counts"]["missing_zeroed"], 1);
+    assert_eq!(json["metrics"]["total_change"], 50.0);
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors[0]["row_id"], "u8:B");
+    assert_eq!(contributors[0]["new"], 0.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_reports_missing_cells() {
+    let dir = temp_dir();
+    let mut args = write_gap(&dir, false);
+    args.on_missing = MissingPolicy::Skip;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains(
+            "Missing cells: 1 numeric cell skipped (missing on one side; --on-missing skip)."
+        ),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,138 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::columns::MixedTypesPolicy;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_on_mixed_types_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// `junk` mixes numbers with text; `amount` moves by 10 on A.
+fn write_mixed(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount,junk\nA,100,1\nB,40,oops\nC,300,3\n").unwrap();
+    std::fs::write(&new_path, "id,amount,junk\nA,110,1\nB,40,oops\nC,300,3\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_output(args: &Args) -> String {
+    orchestrator::run(args).unwrap().output
+}
+
+#[test]
+fn mixed_column_refuses_by_default() {
+    let dir = temp_dir();
+    let args = write_mixed(&dir, true);
+
+    let json: Value = serde_json::from_str(&run_output(&args)).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_MIXED_TYPES");
+    assert!(json.get("columns_excluded_mixed").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn refusal_reports_type_histogram_and_samples() {
+    let dir = temp_dir();
+    let args = write_mixed(&dir, true);
+
+    let json: Value = serde_json::from_str(&run_output(&args)).unwrap();
+    let detail = &json["refusal"]["detail"];
+    assert_eq!(detail["types"]["numeric"], 4);
+    assert_eq!(detail["types"]["text"], 2);
+    assert_eq!(detail["types"]["dates"], 0);
+    assert_eq!(
+        detail["samples"],
+        serde_json::json!([
+            { "file": "old", "record": 2, "value": "u8:oops" },
+            { "file": "new", "record": 2, "value": "u8:oops" },
+        ])
+    );
+
+    let output = run_output(&write_mixed(&dir, false));
+    assert!(
+        output.contains(
+            "Column types: numeric 4, text 2. Non-numeric samples: old.csv record 2 \"oops\", new.csv record 2 \"oops\"."
+        ),
+        "output: {output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn exclude_drops_the_column_and_lists_it() {
+    let dir = temp_dir();
+    let mut args = write_mixed(&dir, true);
+    args.on_mixed_types = MixedTypesPolicy::Exclude;
+
+    let json: Value = serde_json::from_str(&run_output(&args)).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(
+        json["columns_excluded_mixed"],
+        serde_json::json!(["u8:junk"])
+    );
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+    assert_eq!(json["metrics"]["total_change"], 10.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn exclude_is_listed_in_human_header() {
+    let dir = temp_dir();
+    let mut args = write_mixed(&dir, false);
+    args.on_mixed_types = MixedTypesPolicy::Exclude;
+
+    let output = run_output(&args);
+    assert!(
+        output.contains("Excluded (mixed types): junk"),
+        "output: {output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn exclude_still_refuses_when_nothing_numeric_remains() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,junk\nA,1\nB,oops\n").unwrap();
+    std::fs::write(&new_path, "id,junk\nA,2\nB,oops\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.on_mixed_types = MixedTypesPolicy::Exclude;
+
+    let json: Value = serde_json::from_str(&run_output(&args)).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_NO_NUMERIC");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -1,4 +1,6 @@
+use rvl::diff::rank::Ranking;
 use rvl::format::ident_human::render_identifier_human;
+use rvl::numeric::parse::DecimalSeparator;
 use rvl::output::human::header::{
     Alignment, CheckedCounts, ColumnCounts, DialectReceipt, HumanHeader, RefusalHeader, Settings,
     render_real_no_real_header, render_refusal_header,
@@ -10,7 +12,7 @@ use rvl::output::human::real_change::{
 use rvl::output::human::refusal::{RefusalBody, render_refusal_body};
 use rvl::output::json::{
     Alignment as JsonAlignment, Counts, Dialect, DialectSide, Files, JsonContext, JsonOutput,
-    Metrics, Refusal,
+    Limits, Metrics, OutputVersion, Refusal,
 };
 use rvl::refusal::codes::RefusalCode;
 use rvl::refusal::details::{FileSide, RefusalDetail, RefusalKind, RerunPaths};
@@ -25,7 +27,16 @@ fn golden_real_change_human_output() {
     let header = HumanHeader {
         old_name: "old.csv",
         new_name: "new.csv",
-        alignment: Alignment::Key { column: "id" },
+        alignment: Alignment::Key {
+            column: "id",
+            key_normalize: &[],
+            dedup: None,
+        },
+        column_map: &[],
+        suggested_maps: &[],
+        percent_columns: &[],
+        transforms: &[],
+        columns_excluded_mixed: &[],
         profile: None,
         columns: ColumnCounts {
             common: 3,
@@ -41,12 +52,21 @@ fn golden_real_change_human_output() {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         },
         dialect_new: DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         },
+        ranking: &Ranking::AbsDelta,
         settings: Settings {
             threshold: 0.95,
             tolerance: 1e-9,
@@ -106,6 +126,11 @@ fn golden_no_real_change_human_output() {
         old_name: "old.csv",
         new_name: "new.csv",
         alignment: Alignment::RowOrder,
+        column_map: &[],
+        suggested_maps: &[],
+        percent_columns: &[],
+        transforms: &[],
+        columns_excluded_mixed: &[],
         profile: None,
         columns: ColumnCounts {
             common: 2,
@@ -121,12 +146,21 @@ fn golden_no_real_change_human_output() {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         },
         dialect_new: DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         },
+        ranking: &Ranking::AbsDelta,
         settings: Settings {
             threshold: 0.95,
             tolerance: 1e-9,
@@ -171,17 +205,34 @@ fn golden_refusal_human_output() {
     let header = RefusalHeader {
         old_name: "old.csv",
         new_name: "new.csv",
-        alignment: Alignment::Key { column: "id" },
+        alignment: Alignment::Key {
+            column: "id",
+            key_normalize: &[],
+            dedup: None,
+        },
+        column_map: &[],
+        suggested_maps: &[],
+        percent_columns: &[],
+        transforms: &[],
+        columns_excluded_mixed: &[],
         profile: None,
         dialect_old: Some(DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         }),
         dialect_new: Some(DialectReceipt {
             delimiter: b',',
             quote: b'"',
             escape: None,
+            compression: None,
+            encoding: None,
+            confidence: None,
+            decimal: DecimalSeparator::Dot,
         }),
         settings: Settings {
             threshold: 0.95,
@@ -226,13 +277,14 @@ Settings: threshold=95.0% tolerance=1e-9
 Cannot produce a verdict.
 Reason (E_KEY_DUP): duplicate key values.
 Example: old.csv data record 184 duplicates key "A123".
-Next: choose a unique key column or dedupe the data, then rerun"#;
+Next: choose a unique key column or dedupe the data, then rerun (or add --dedup sum|first to collapse repeated keys)"#;
     assert_eq!(actual, expected);
 }
 
 #[test]
 fn golden_json_real_change_output() {
     let ctx = JsonContext {
+        version: OutputVersion::V0,
         files: Files {
             old: "old.csv".to_string(),
             new: "new.csv".to_string(),
@@ -251,6 +303,9 @@ fn golden_json_real_change_output() {
         audit: None,
         threshold: 0.95,
         tolerance: 1e-9,
+        ranking: Ranking::AbsDelta,
+        missing_policy: None,
+        config: None,
         counts: Counts {
             rows_old: Some(2),
             rows_new: Some(2),
@@ -263,13 +318,47 @@ fn golden_json_real_change_output() {
             numeric_columns: Some(1),
             numeric_cells_checked: Some(2),
             numeric_cells_changed: Some(1),
+            format_only_changes: None,
+            missing_skipped: None,
+            missing_zeroed: None,
+            missing_both: None,
+            missing_row: None,
+            unparsable: None,
+            footer_rows_old: None,
+            footer_rows_new: None,
+            comment_lines_old: None,
+            comment_lines_new: None,
+            rows_filtered_old: None,
+            rows_filtered_new: None,
         },
         metrics: Metrics {
             total_change: Some(5.0),
             max_abs_delta: Some(5.0),
             top_k_coverage: Some(1.0),
+            coverage_curve: None,
+            timings: None,
         },
         field_changes: None,
+        schema_change: None,
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        diffuse: None,
+        by_column: None,
+        by_row: None,
+        column_deltas: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        warnings: Vec::new(),
+        column_map: Vec::new(),
+        suggested_maps: Vec::new(),
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
+        limits: Limits::default(),
     };
     let contributors = vec![rvl::output::json::Contributor::from_bytes(
         b"A", b"value", 1.0, 6.0, 5.0, 5.0, 1.0, 1.0, true,
@@ -290,6 +379,7 @@ fn golden_json_real_change_output() {
         },
         "threshold": 0.95,
         "tolerance": 1e-9,
+        "ranking": { "mode": "abs_delta" },
         "counts": {
             "rows_old": 2,
             "rows_new": 2,
@@ -325,6 +415,7 @@ fn golden_json_real_change_output() {
 #[test]
 fn golden_json_no_real_change_output() {
     let ctx = JsonContext {
+        version: OutputVersion::V0,
         files: Files {
             old: "old.csv".to_string(),
             new: "new.csv".to_string(),
@@ -343,6 +434,9 @@ fn golden_json_no_real_change_output() {
         audit: None,
         threshold: 0.95,
         tolerance: 1e-9,
+        ranking: Ranking::AbsDelta,
+        missing_policy: None,
+        config: None,
         counts: Counts {
             rows_old: Some(2),
             rows_new: Some(2),
@@ -355,13 +449,47 @@ fn golden_json_no_real_change_output() {
             numeric_columns: Some(2),
             numeric_cells_checked: Some(4),
             numeric_cells_changed: Some(0),
+            format_only_changes: None,
+            missing_skipped: None,
+            missing_zeroed: None,
+            missing_both: None,
+            missing_row: None,
+            unparsable: None,
+            footer_rows_old: None,
+            footer_rows_new: None,
+            comment_lines_old: None,
+            comment_lines_new: None,
+            rows_filtered_old: None,
+            rows_filtered_new: None,
         },
         metrics: Metrics {
             total_change: Some(0.0),
             max_abs_delta: Some(7e-10),
             top_k_coverage: None,
+            coverage_curve: None,
+            timings: None,
         },
         field_changes: None,
+        schema_change: None,
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        diffuse: None,
+        by_column: None,
+        by_row: None,
+        column_deltas: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        warnings: Vec::new(),
+        column_map: Vec::new(),
+        suggested_maps: Vec::new(),
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
+        limits: Limits::default(),
     };
 
     let output = JsonOutput::no_real_change(ctx);
@@ -380,6 +508,7 @@ fn golden_json_no_real_change_output() {
         },
         "threshold": 0.95,
         "tolerance": 1e-9,
+        "ranking": { "mode": "abs_delta" },
         "counts": {
             "rows_old": 2,
             "rows_new": 2,
@@ -405,6 +534,7 @@ fn golden_json_no_real_change_output() {
 #[test]
 fn golden_json_refusal_output() {
     let ctx = JsonContext {
+        version: OutputVersion::V0,
         files: Files {
             old: "old.csv".to_string(),
             new: "new.csv".to_string(),
@@ -423,9 +553,32 @@ fn golden_json_refusal_output() {
         audit: None,
         threshold: 0.95,
         tolerance: 1e-9,
+        ranking: Ranking::AbsDelta,
+        missing_policy: None,
+        config: None,
         counts: Counts::default(),
         metrics: Metrics::default(),
         field_changes: None,
+        schema_change: None,
+        drift: None,
+        rescaled_columns: Vec::new(),
+        tolerance_sweep: None,
+        diffuse: None,
+        by_column: None,
+        by_row: None,
+        column_deltas: None,
+        key_changes: None,
+        date_changes: None,
+        text_changes: None,
+        warnings: Vec::new(),
+        column_map: Vec::new(),
+        suggested_maps: Vec::new(),
+        column_types: None,
+        percent_columns: Vec::new(),
+        transforms: Vec::new(),
+        columns_excluded_mixed: Vec::new(),
+        column_normalizations: Vec::new(),
+        limits: Limits::default(),
     };
 
     let refusal = Refusal::new(
@@ -0,0 +1,258 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::agent_manifest::build_manifest;
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use rvl::output::json::{Counts, Metrics, PhaseTimings};
+use serde_json::{Value, json};
+
+const OLD: &str = "\
+# exported 2024-04-01
+id,amount,qty,note,when
+A,1000,5,x,2024-01-01
+B,200,,y,2024-02-01
+C,30,3,z,2024-03-01
+D,40,4,w,2024-03-02
+total,1270,12,,
+";
+
+const NEW: &str = "\
+# exported 2024-04-02
+id,amount,qty,note,when
+A,\"1,000.00\",5,x,2024-01-01
+B,260,7,y2,2024-02-03
+C,30.0000000001,,z,2024-03-01
+E,50,5,v,2024-03-05
+total,1340,17,,
+";
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_output_schema_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Run one comparison of `old`/`new` with `flags` and return its JSON report.
+fn report(old: &str, new: &str, flags: &[&str]) -> Value {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut argv = vec![
+        "rvl".to_string(),
+        old_path.to_string_lossy().to_string(),
+        new_path.to_string_lossy().to_string(),
+        "--json".to_string(),
+        "--no-witness".to_string(),
+        "--no-config".to_string(),
+    ];
+    argv.extend(flags.iter().map(|flag| flag.to_string()));
+    let args = Args::parse_from(argv).expect("flags should parse");
+    let result = orchestrator::run(&args).expect("pipeline should run");
+    serde_json::from_str(&result.output).expect("report should be JSON")
+}
+
+/// Collect every emitted key the schema does not declare, and every required
+/// key that is missing. Objects whose schema lists no properties (free-form
+/// blocks such as refusal `detail`) are not descended into.
+fn check(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+                return;
+            };
+            for (key, child) in map {
+                match properties.get(key) {
+                    Some(child_schema) => {
+                        check(child, child_schema, &format!("{path}.{key}"), errors)
+                    }
+                    None => errors.push(format!("{path}.{key} is not in the schema")),
+                }
+            }
+            for required in schema["required"].as_array().into_iter().flatten() {
+                let required = required.as_str().unwrap();
+                if !map.contains_key(required) {
+                    errors.push(format!("{path}.{required} is required but missing"));
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check(item, item_schema, &format!("{path}[{index}]"), errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn assert_matches_schema(report: &Value) {
+    let manifest = build_manifest();
+    let mut errors = Vec::new();
+    check(report, &manifest["output_schema"], "$", &mut errors);
+    assert!(errors.is_empty(), "{errors:#?}\n{report:#}");
+}
+
+#[test]
+fn key_mode_report_with_every_detail_matches_schema() {
+    let report = report(
+        OLD,
+        NEW,
+        &[
+            "--key",
+            "id",
+            "--comment",
+            "#",
+            "--skip-footer",
+            "1",
+            "--include-entity-changes",
+            "--on-missing",
+            "zero",
+            "--include-text",
+            "--show-subtolerance",
+            "5",
+            "--tolerance",
+            "0.001",
+            "--tolerance-sweep",
+            "--drift",
+            "psi",
+            "--max-memory",
+            "1G",
+            "--timings",
+            "--explicit",
+        ],
+    );
+    assert_eq!(report["outcome"], "REAL_CHANGE");
+    for block in [
+        "key_changes",
+        "date_changes",
+        "text_changes",
+        "drift",
+        "memory",
+    ] {
+        assert!(report[block].is_object(), "{block} missing");
+    }
+    assert!(report["counts"]["comment_lines_old"].is_u64());
+    assert!(report["metrics"]["timings"].is_object());
+    assert_matches_schema(&report);
+}
+
+/// Every field set, written without `..Default::default()`, so a new counter
+/// or metric fails to compile here until it is added to the schema check.
+#[test]
+fn every_counts_and_metrics_field_is_in_the_schema() {
+    let counts = Counts {
+        rows_old: Some(1),
+        rows_new: Some(1),
+        rows_aligned: Some(1),
+        columns_old: Some(1),
+        columns_new: Some(1),
+        columns_common: Some(1),
+        columns_old_only: Some(1),
+        columns_new_only: Some(1),
+        numeric_columns: Some(1),
+        numeric_cells_checked: Some(1),
+        numeric_cells_changed: Some(1),
+        format_only_changes: Some(1),
+        missing_skipped: Some(1),
+        missing_zeroed: Some(1),
+        missing_both: Some(1),
+        missing_row: Some(1),
+        unparsable: Some(1),
+        footer_rows_old: Some(1),
+        footer_rows_new: Some(1),
+        comment_lines_old: Some(1),
+        comment_lines_new: Some(1),
+        rows_filtered_old: Some(1),
+        rows_filtered_new: Some(1),
+    };
+    let metrics = Metrics {
+        total_change: Some(1.0),
+        max_abs_delta: Some(1.0),
+        top_k_coverage: Some(1.0),
+        coverage_curve: Some(vec![1.0]),
+        timings: Some(PhaseTimings::default()),
+    };
+    let schema = &build_manifest()["output_schema"]["properties"];
+    let mut errors = Vec::new();
+    check(&json!(counts), &schema["counts"], "$.counts", &mut errors);
+    check(
+        &json!(metrics),
+        &schema["metrics"],
+        "$.metrics",
+        &mut errors,
+    );
+    assert!(errors.is_empty(), "{errors:#?}");
+}
+
+#[test]
+fn row_order_report_with_filters_matches_schema() {
+    let report = report(
+        OLD,
+        NEW,
+        &[
+            "--comment",
+            "#",
+            "--skip-footer",
+            "1",
+            "--where",
+            "note!=w",
+            "--on-missing",
+            "skip",
+            "--rank",
+            "relative",
+        ],
+    );
+    assert_matches_schema(&report);
+}
+
+#[test]
+fn grouped_and_sampled_reports_match_schema() {
+    let grouped = report(
+        "id,region,amount\nA,east,1\nB,east,2\nC,west,3\n",
+        "id,region,amount\nA,east,1\nB,east,9\nC,west,3\n",
+        &["--group-by", "region"],
+    );
+    assert_matches_schema(&grouped);
+    let sampled = report(
+        "id,amount\nA,1\nB,2\nC,3\n",
+        "id,amount\nA,1\nB,9\nC,3\n",
+        &[
+            "--key", "id", "--sample", "2", "--seed", "7", "--dedup", "sum",
+        ],
+    );
+    assert_matches_schema(&sampled);
+}
+
+#[test]
+fn no_change_schema_only_and_identical_reports_match_schema() {
+    let same = "id,amount\nA,1\nB,2\n";
+    let identical = report(same, same, &["--key", "id"]);
+    assert_eq!(identical["identical_files"], true);
+    assert_matches_schema(&identical);
+    let schema_only = report(same, "id,amount,extra\nA,1,x\nB,2,y\n", &["--schema-only"]);
+    assert_matches_schema(&schema_only);
+}
+
+#[test]
+fn refusal_reports_match_schema() {
+    let single = report("id,v\n1,1\n", "id,v\n1,1\n2,2\n", &[]);
+    assert_eq!(single["outcome"], "REFUSAL");
+    assert_matches_schema(&single);
+    let collected = report(
+        "id,v\n1,1\n1,2\n",
+        "id,w\n1,1\n",
+        &["--key", "id", "--collect-refusals"],
+    );
+    assert_eq!(collected["outcome"], "REFUSAL");
+    assert_matches_schema(&collected);
+}
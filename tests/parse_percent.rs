@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_parse_percent_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Occupancy is written as a percent in both files; cap_rate switches from a
+// fraction to a percent with the same value.
+fn write_occupancy(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,occupancy,cap_rate,units\nA,91%,0.0577,142\nB,88%,0.0603,224\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "id,occupancy,cap_rate,units\nA,92%,5.77%,142\nB,88%,6.03%,224\n",
+    )
+    .unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn percent_tokens_are_text_by_default() {
+    let dir = temp_dir();
+    let args = write_occupancy(&dir, true);
+
+    // Only `units` is numeric, so the occupancy change goes unseen.
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_columns"], 1);
+    assert!(json.get("percent_columns").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn parse_percent_compares_fractions_and_records_columns() {
+    let dir = temp_dir();
+    let mut args = write_occupancy(&dir, true);
+    args.parse_percent = true;
+    args.explicit = true;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_columns"], 3);
+    assert_eq!(
+        json["percent_columns"],
+        serde_json::json!(["u8:occupancy", "u8:cap_rate"])
+    );
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors.len(), 1);
+    assert_eq!(contributors[0]["column"], "u8:occupancy");
+    assert_eq!(contributors[0]["old"], 0.91);
+    assert_eq!(contributors[0]["new"], 0.92);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_header_lists_percent_columns() {
+    let dir = temp_dir();
+    let mut args = write_occupancy(&dir, false);
+    args.parse_percent = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Percent parsed: occupancy, cap_rate (N% read as N/100)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::diff::rank::RankMode;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_rank_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// A moves by 500 on a 1,000,000 line (0.05%); B moves by 10 on a 100 line
+// (10 / 110), so relative ranking puts B first.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,value\nA,1000000\nB,100\n").unwrap();
+    std::fs::write(&new_path, "id,value\nA,1000500\nB,110\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.5,
+        1e-9,
+        None,
+        json,
+    );
+    args.rank = RankMode::Relative;
+    args
+}
+
+#[test]
+fn relative_rank_scores_delta_against_the_larger_side() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["ranking"]["mode"], "relative");
+    assert_eq!(json["contributors"][0]["row_id"], "u8:B");
+    let total = json["metrics"]["total_change"].as_f64().unwrap();
+    assert!((total - (10.0 / 110.0 + 500.0 / 1_000_500.0)).abs() < 1e-12);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn relative_rank_is_declared_in_human_ranking_line() {
+    let dir = temp_dir();
+    let args = make_args(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Ranking: abs(delta) / max(abs(old), abs(new)) (relative)"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
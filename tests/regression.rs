@@ -12,24 +12,87 @@ fn run_case(old: &str, new: &str, key: Option<&str>, json: bool) -> String {
     let args = Args {
         old: Some(PathBuf::from(old)),
         new: Some(PathBuf::from(new)),
+        base: None,
         key: key.map(|value| value.to_string()),
         threshold: 0.95,
         tolerance: 1e-9,
         delimiter: None,
+        skip_rows: rvl::csv::input::SkipRows::default(),
+        skip_footer: rvl::csv::footer::FooterSkip::default(),
+        encoding: rvl::csv::encoding::EncodingMode::default(),
+        comment: None,
+        strict_dialect: false,
         exhaustive: false,
         audit_fields: false,
         max_audit_changes: 10_000,
+        max_memory: None,
         profile: None,
         profile_id: None,
         capsule_out: None,
         json,
+        format: None,
+        output_version: rvl::output::json::OutputVersion::V0,
         no_witness: true,
         describe: false,
         explicit: true,
         schema: false,
         version: false,
+        show_subtolerance: None,
+        explore: None,
+        report_xlsx: None,
+        schema_only: false,
+        drift: None,
+        cross_tab: false,
+        output_stream: rvl::cli::exit::StreamPolicy::Auto,
+        expected: None,
+        expect: None,
+        expect_json: None,
+        dedupe_rows: false,
+        key_map: None,
+        key_normalize: Vec::new(),
+        smart_tolerance: false,
+        min_header_overlap: 0.5,
+        top: 25,
+        rank: rvl::diff::rank::RankMode::Abs,
+        weight_col: None,
+        report_csv: None,
+        events: None,
+        timings: false,
+        allow_missing_keys: false,
+        include_entity_changes: false,
+        allow_trailing_rows: 0,
+        where_filters: Vec::new(),
+        sample: None,
+        seed: 0,
+        dedup: rvl::alignment::key_dedup::DedupMode::Refuse,
+        group_by: None,
+        column_map: Vec::new(),
+        header_match: rvl::normalize::headers::HeaderMatch::Exact,
+        parse_percent: false,
+        decimal: rvl::numeric::parse::DecimalSeparator::Dot,
+        currency_symbols: Vec::new(),
+        lenient_numbers: false,
+        include_text: false,
+        na_values: Vec::new(),
+        on_missing: rvl::numeric::missing::MissingPolicy::Refuse,
+        on_mixed_types: rvl::numeric::columns::MixedTypesPolicy::Refuse,
+        collect_refusals: false,
+        on_diffuse: rvl::diff::coverage::DiffusePolicy::Refuse,
+        coverage: rvl::diff::coverage::CoverageMode::Total,
+        columns: Vec::new(),
+        column_tolerances: Vec::new(),
+        transforms: Vec::new(),
+        tolerance_sweep: None,
+        config: None,
+        no_config: false,
+        config_receipt: None,
+        inline: Default::default(),
+        threads: 0,
         robot_triage: false,
         command: None,
+        watch: false,
+        assert: false,
+        param_sources: Default::default(),
     };
     orchestrator::run(&args)
         .expect("pipeline run should succeed")
@@ -176,3 +239,20 @@ fn key_mode_row_ref_refusals_prefer_key_over_record() {
         "key mode mixed-types detail should not include record"
     );
 }
+
+#[test]
+fn concurrent_parse_reports_old_file_refusal_first() {
+    let old_path = unique_temp_csv("both-missing-old");
+    let new_path = unique_temp_csv("both-missing-new");
+
+    let json = run_case(
+        old_path.to_string_lossy().as_ref(),
+        new_path.to_string_lossy().as_ref(),
+        None,
+        true,
+    );
+    let value: Value = serde_json::from_str(&json).expect("io refusal JSON");
+    assert_eq!(value["outcome"], "REFUSAL");
+    assert_eq!(value["refusal"]["code"], "E_IO");
+    assert_eq!(value["refusal"]["detail"]["file"], "old");
+}
@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_report_csv_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, key: Option<&str>) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.report_csv = Some(dir.join("out").join("changes.csv"));
+    args
+}
+
+#[test]
+fn lists_every_changed_cell_beyond_the_contributor_limit() {
+    let dir = temp_dir();
+    let mut old = String::from("id,a,b\n");
+    let mut new = String::from("id,a,b\n");
+    for i in 0..30 {
+        old.push_str(&format!("r{i},10,5\n"));
+        new.push_str(&format!("r{i},11,5\n"));
+    }
+    let mut args = make_args(&dir, &old, &new, Some("id"));
+    args.explicit = true;
+    let result = orchestrator::run(&args).unwrap();
+    assert_eq!(result.outcome, rvl::cli::exit::Outcome::Refusal);
+
+    // E_DIFFUSE still gets the full list: the report is not bounded by --top.
+    let text = std::fs::read_to_string(args.report_csv.as_ref().unwrap()).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "row,column,old,new,delta");
+    assert_eq!(lines[1], "r0,a,10,11,1");
+    assert_eq!(lines.len(), 31);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn row_order_values_are_redacted_without_explicit() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "a,b\n1,2\n3,4\n", "a,b\n1,2\n3,9\n", None);
+    orchestrator::run(&args).unwrap();
+
+    let text = std::fs::read_to_string(args.report_csv.as_ref().unwrap()).unwrap();
+    assert_eq!(text, "row,column\n2,b\n");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn refusals_before_a_verdict_leave_no_report() {
+    let dir = temp_dir();
+    // Reordered rows without a key refuse with E_NEED_KEY after the diff loop.
+    let args = make_args(&dir, "id,v\nA,1\nB,2\nC,3\n", "id,v\nC,3\nA,1\nB,2\n", None);
+    let json: serde_json::Value =
+        serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_NEED_KEY");
+
+    let report = args.report_csv.as_ref().unwrap();
+    assert!(!report.exists());
+    assert!(!report.with_file_name("changes.csv.partial").exists());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
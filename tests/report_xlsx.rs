@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_report_xlsx_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, explicit: bool) -> Args {
+    let old = dir.join("old.csv");
+    let new = dir.join("new.csv");
+    std::fs::write(&old, "id,amount\nA,100\nB,200\nC,300\n").unwrap();
+    std::fs::write(&new, "id,amount\nA,150\nB,200\nC,301\n").unwrap();
+    let mut args = Args::new(old, new, Some("id".to_string()), 0.95, 1e-9, None, false);
+    args.explicit = explicit;
+    args.report_xlsx = Some(dir.join("out").join("report.xlsx"));
+    args
+}
+
+fn contains(haystack: &[u8], needle: &str) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle.as_bytes())
+}
+
+#[test]
+fn report_xlsx_writes_summary_and_full_ledger() {
+    let dir = temp_dir();
+    let args = make_args(&dir, false);
+    orchestrator::run(&args).unwrap();
+
+    let bytes = std::fs::read(args.report_xlsx.as_ref().unwrap()).unwrap();
+    assert_eq!(&bytes[..2], b"PK");
+    assert!(contains(&bytes, r#"<sheet name="Summary""#));
+    assert!(contains(&bytes, r#"<sheet name="Changes""#));
+    assert!(contains(&bytes, "REAL CHANGE"));
+    // Both changed cells are listed, not only the explanation prefix.
+    assert!(contains(
+        &bytes,
+        r#"<c r="B2" t="inlineStr"><is><t xml:space="preserve">A</t>"#
+    ));
+    assert!(contains(
+        &bytes,
+        r#"<c r="B3" t="inlineStr"><is><t xml:space="preserve">C</t>"#
+    ));
+    assert!(!contains(&bytes, ">Delta<"), "values redacted by default");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn report_xlsx_includes_values_with_explicit() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+    orchestrator::run(&args).unwrap();
+
+    let bytes = std::fs::read(args.report_xlsx.as_ref().unwrap()).unwrap();
+    assert!(contains(&bytes, ">Delta<"));
+    assert!(contains(&bytes, r#"<c r="F2"><v>50</v></c>"#));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
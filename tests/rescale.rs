@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::transform::ColumnTransform;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_rescale_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// `value` moved from dollars to thousands; `units` is unchanged.
+fn write_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,value,units\nA,125000,4\nB,40000,2\nC,9500,1\n",
+    )
+    .unwrap();
+    std::fs::write(&new_path, "id,value,units\nA,125,4\nB,40,2\nC,9.5,1\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn rescaled_column_is_reported() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    let rescaled = json["rescaled_columns"].as_array().unwrap();
+    assert_eq!(rescaled.len(), 1);
+    assert_eq!(rescaled[0]["column"], "u8:value");
+    assert_eq!(rescaled[0]["factor"], 0.001);
+    assert_eq!(rescaled[0]["cells"], 3);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_note_suggests_the_transform() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains(
+            "Note: column value appears rescaled by ~0.001x (3 cells); --transform old:value=scale:0.001 compares it in the new units."
+        ),
+        "{output}"
+    );
+
+    args.transforms = vec![ColumnTransform::parse("old:value=scale:0.001").unwrap()];
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("NO REAL CHANGE"), "{output}");
+    assert!(!output.contains("appears rescaled"), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_rollup_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn rows_roll_up_change_across_columns() {
+    let dir = temp_dir();
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    // Unit 2 moves by 6 over two columns; unit 3's single cell (5) is the
+    // largest cell but the smaller row.
+    std::fs::write(&old_path, "unit,rent,fees\n1,100,10\n2,200,20\n3,300,30\n").unwrap();
+    std::fs::write(&new_path, "unit,rent,fees\n1,100,10\n2,203,23\n3,305,30\n").unwrap();
+    let args = Args::new(
+        old_path,
+        new_path,
+        Some("unit".to_string()),
+        0.5,
+        1e-9,
+        None,
+        true,
+    );
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["contributors"][0]["row_id"], "u8:3");
+
+    let by_row = json["by_row"].as_array().unwrap();
+    assert_eq!(by_row.len(), 2);
+    assert_eq!(by_row[0]["row_id"], "u8:2");
+    assert_eq!(by_row[0]["contribution"], 6.0);
+    assert_eq!(by_row[0]["cells_changed"], 2);
+    assert_eq!(by_row[1]["row_id"], "u8:3");
+
+    let by_column = json["by_column"].as_array().unwrap();
+    assert_eq!(by_column[0]["column"], "u8:rent");
+    assert_eq!(by_column[0]["contribution"], 8.0);
+    assert_eq!(by_column[1]["column"], "u8:fees");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_sample_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// 50 keys; every new value is 1 higher, so each sampled row contributes.
+fn write_pair(dir: &Path, reverse_new: bool, key: Option<&str>) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    let mut old = String::from("id,amount\n");
+    let mut new_rows = Vec::new();
+    for idx in 0..50 {
+        old.push_str(&format!("K{idx},{}\n", idx * 10));
+        new_rows.push(format!("K{idx},{}\n", idx * 10 + 1));
+    }
+    if reverse_new {
+        new_rows.reverse();
+    }
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, format!("id,amount\n{}", new_rows.concat())).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        1.0,
+        1e-9,
+        None,
+        true,
+    );
+    args.no_witness = true;
+    args.sample = Some(10);
+    args.seed = 42;
+    args
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+fn contributor_rows(json: &Value) -> Vec<String> {
+    let mut rows: Vec<String> = json["contributors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|contributor| contributor["row_id"].as_str().unwrap().to_string())
+        .collect();
+    rows.sort();
+    rows
+}
+
+#[test]
+fn sampled_verdict_records_the_sampling_parameters() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, false, Some("id"));
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["sampled"], true);
+    assert_eq!(json["alignment"]["sample"]["method"], "key_hash");
+    assert_eq!(json["alignment"]["sample"]["size"], 10);
+    assert_eq!(json["alignment"]["sample"]["seed"], 42);
+    assert_eq!(json["alignment"]["sample"]["rows_eligible"], 50);
+    assert_eq!(json["alignment"]["sample"]["rows_sampled"], 10);
+    assert_eq!(json["counts"]["rows_old"], 50);
+    assert_eq!(json["counts"]["rows_aligned"], 10);
+    assert_eq!(json["contributors"].as_array().unwrap().len(), 10);
+
+    let mut human_args = args.clone();
+    human_args.json = false;
+    let output = orchestrator::run(&human_args).unwrap().output;
+    assert!(output.contains("\nREAL CHANGE (SAMPLED)\n"), "{output}");
+    assert!(
+        output.contains("Sampled rows: 10 of 50 aligned rows by key hash (--sample, seed 42)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn sampled_refusals_are_marked_sampled() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, false, Some("id"));
+    // Ten equal contributors; the top 3 cover 30%, short of the threshold.
+    args.top = 3;
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert_eq!(json["sampled"], true);
+
+    let mut human_args = args.clone();
+    human_args.json = false;
+    let output = orchestrator::run(&human_args).unwrap().output;
+    assert!(
+        output.starts_with("RVL ERROR (E_DIFFUSE) (SAMPLED)\n"),
+        "{output}"
+    );
+
+    let mut unsampled = args.clone();
+    unsampled.sample = None;
+    let json = run_json(&unsampled);
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert!(json.get("sampled").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn sample_depends_on_keys_and_seed_not_row_order() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, false, Some("id"));
+    let forward = contributor_rows(&run_json(&args));
+
+    let reversed_dir = temp_dir();
+    let reversed_args = write_pair(&reversed_dir, true, Some("id"));
+    assert_eq!(contributor_rows(&run_json(&reversed_args)), forward);
+
+    let mut reseeded = args.clone();
+    reseeded.seed = 7;
+    assert_ne!(contributor_rows(&run_json(&reseeded)), forward);
+
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::remove_dir_all(&reversed_dir).ok();
+}
+
+#[test]
+fn sample_needs_key_mode() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, false, None);
+
+    assert!(orchestrator::run(&args).is_err());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,106 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::orchestrator;
+use rvl::output::ReportFormat;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_sarif_report_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        false,
+    );
+    args.format = Some(ReportFormat::Sarif);
+    args
+}
+
+#[test]
+fn sarif_maps_contributors_to_warnings() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, "id,amount\nA,100\nB,50\n", "id,amount\nA,100\nB,20\n");
+    args.explicit = true;
+
+    let result = orchestrator::run(&args).unwrap();
+    let sarif: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(exit_code(result.outcome), 1);
+    assert_eq!(sarif["version"], "2.1.0");
+    let run = &sarif["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "rvl");
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "REAL_CHANGE");
+    let finding = &run["results"][0];
+    assert_eq!(finding["level"], "warning");
+    assert_eq!(
+        finding["message"]["text"],
+        "amount changed in row B: 50 -> 20 (-30), 100.0% of total numeric change."
+    );
+    let location = &finding["locations"][0];
+    assert!(
+        location["physicalLocation"]["artifactLocation"]["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("new.csv")
+    );
+    assert_eq!(
+        location["logicalLocations"][0]["fullyQualifiedName"],
+        "B/amount"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn sarif_maps_refusal_to_error() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,amount\nA,1\nA,2\n", "id,amount\nA,3\n");
+
+    let result = orchestrator::run(&args).unwrap();
+    let sarif: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(exit_code(result.outcome), 2);
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "E_KEY_DUP");
+    assert_eq!(results[0]["level"], "error");
+    assert!(
+        results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("old.csv")
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn sarif_has_no_results_without_real_change() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,amount\nA,1\n", "id,amount\nA,1\n");
+
+    let sarif: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(sarif["runs"][0]["properties"]["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(sarif["runs"][0]["results"], serde_json::json!([]));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
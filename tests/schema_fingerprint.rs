@@ -0,0 +1,132 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::Outcome;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_schema_fingerprint_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, old: &str, new: &str, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+#[test]
+fn schema_only_reports_unchanged_schema_without_diffing() {
+    let dir = temp_dir();
+    // Values differ, but --schema-only never reaches cell diffing.
+    let mut args = make_args(&dir, "id,v\nA,1\nB,2\n", "id,v\nA,5\nB,9\n", true);
+    args.schema_only = true;
+
+    let result = orchestrator::run(&args).unwrap();
+    assert!(matches!(result.outcome, Outcome::NoRealChange));
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["mode"], "schema_only");
+    assert!(json.get("schema_change").is_none());
+    assert_eq!(json["counts"]["columns_old"], 2);
+    assert!(json["metrics"]["total_change"].is_null());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn schema_only_reports_structural_change_as_real_change() {
+    let dir = temp_dir();
+    let mut args = make_args(
+        &dir,
+        "id,a,gone\nA,1,x\n",
+        "id,a,fresh\nA,n/a-text,1\n",
+        true,
+    );
+    args.schema_only = true;
+
+    let result = orchestrator::run(&args).unwrap();
+    assert!(matches!(result.outcome, Outcome::RealChange));
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    let change = &json["schema_change"];
+    assert!(
+        change["old_fingerprint"]
+            .as_str()
+            .unwrap()
+            .starts_with("blake3:")
+    );
+    assert_ne!(change["old_fingerprint"], change["new_fingerprint"]);
+    assert_eq!(change["added"], serde_json::json!(["u8:fresh"]));
+    assert_eq!(change["removed"], serde_json::json!(["u8:gone"]));
+    assert_eq!(change["retyped"][0]["column"], "u8:a");
+    assert_eq!(change["retyped"][0]["old"], "numeric");
+    assert_eq!(change["retyped"][0]["new"], "text");
+    assert_eq!(change["reordered"], false);
+    assert!(json["contributors"].as_array().unwrap().is_empty());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn schema_change_section_precedes_the_verdict_body() {
+    let dir = temp_dir();
+    let args = make_args(
+        &dir,
+        "id,v\nA,1\nB,2\n",
+        "id,v,extra\nA,5,x\nB,2,y\n",
+        false,
+    );
+
+    let result = orchestrator::run(&args).unwrap();
+    assert!(matches!(result.outcome, Outcome::RealChange));
+    let schema_at = result
+        .output
+        .find("Schema changed:")
+        .expect("schema section");
+    assert!(result.output.contains("  Added: extra"));
+    let body_at = result.output.find("1 cell explain").expect("verdict body");
+    assert!(schema_at < body_at, "{}", result.output);
+
+    let mut json_args = args.clone();
+    json_args.json = true;
+    let json: Value = serde_json::from_str(&orchestrator::run(&json_args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(
+        json["schema_change"]["added"],
+        serde_json::json!(["u8:extra"])
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn matching_schema_adds_no_section() {
+    let dir = temp_dir();
+    let args = make_args(&dir, "id,v\nA,1\n", "id,v\nA,2\n", true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert!(json.get("schema_change").is_none());
+    assert!(json.get("mode").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
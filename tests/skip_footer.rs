@@ -0,0 +1,83 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::csv::footer::FooterSkip;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_skip_footer_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Both exports end in a total row whose amount follows the real change.
+fn write_totalled_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,50\nGrand Total,150\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,100\nB,20\nGrand Total,120\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn total_row_is_compared_without_skip_footer() {
+    let dir = temp_dir();
+    let args = write_totalled_pair(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["counts"]["rows_old"], 3);
+    assert!(json["counts"].get("footer_rows_old").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn auto_excludes_the_total_row_and_counts_it() {
+    let dir = temp_dir();
+    let mut args = write_totalled_pair(&dir, true);
+    args.skip_footer = FooterSkip::parse("auto").unwrap();
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["rows_old"], 2);
+    assert_eq!(json["counts"]["footer_rows_old"], 1);
+    assert_eq!(json["counts"]["footer_rows_new"], 1);
+    assert_eq!(json["metrics"]["total_change"], 30.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn fixed_count_shows_in_human_output() {
+    let dir = temp_dir();
+    let mut args = write_totalled_pair(&dir, false);
+    args.skip_footer = FooterSkip::parse("1").unwrap();
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Footer rows: 1 old, 1 new excluded (--skip-footer)."),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
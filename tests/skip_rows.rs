@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::csv::input::SkipRows;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_skip_rows_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The old export carries a three-line banner; the new one does not.
+fn write_bannered_pair(dir: &Path) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "Report generated 2024-05-01\n\nPositions; all desks\nid,amount\nA,100\nB,50\n",
+    )
+    .unwrap();
+    std::fs::write(&new_path, "id,amount\nA,100\nB,20\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn banner_lines_refuse_without_skip_rows() {
+    let dir = temp_dir();
+    let args = write_bannered_pair(&dir);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REFUSAL");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn skip_rows_starts_each_file_at_its_header() {
+    let dir = temp_dir();
+    let mut args = write_bannered_pair(&dir);
+    args.skip_rows = SkipRows::parse("3:0").unwrap();
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["dialect"]["old"]["delimiter"], ",");
+    assert_eq!(json["counts"]["rows_old"], 2);
+    assert_eq!(json["contributors"][0]["row_id"], "u8:B");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_smart_tolerance_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, smart_tolerance: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,balance,interest_rate,units\nA,100.00,5.25,3\nB,250.00,4.10,7\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "id,balance,interest_rate,units\nA,100.004,5.25,3\nB,250.00,4.20,7\n",
+    )
+    .unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.smart_tolerance = smart_tolerance;
+    args
+}
+
+#[test]
+fn sub_cent_balance_drift_is_noise_with_smart_tolerance() {
+    let dir = temp_dir();
+
+    let json: Value =
+        serde_json::from_str(&orchestrator::run(&make_args(&dir, false)).unwrap().output).unwrap();
+    assert_eq!(json["counts"]["numeric_cells_changed"], 2);
+    assert!(json.get("column_types").is_none());
+
+    let json: Value =
+        serde_json::from_str(&orchestrator::run(&make_args(&dir, true)).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["counts"]["numeric_cells_changed"], 1);
+    let contributors = json["contributors"].as_array().unwrap();
+    assert_eq!(contributors.len(), 1);
+    assert_eq!(contributors[0]["column"], "u8:interest_rate");
+
+    let types = json["column_types"].as_array().unwrap();
+    let summary: Vec<(&str, &str)> = types
+        .iter()
+        .map(|column| {
+            (
+                column["column"].as_str().unwrap(),
+                column["type"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("u8:balance", "currency"),
+            ("u8:interest_rate", "percent"),
+            ("u8:units", "count"),
+        ]
+    );
+    assert_eq!(types[0]["tolerance"], 0.005);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_lists_column_types() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.json = false;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Column types (--smart-tolerance):"),
+        "{output}"
+    );
+    assert!(
+        output.contains("  balance  currency  tolerance=5e-3"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
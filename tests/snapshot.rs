@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_snapshot_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn rvl(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn verify_checks_a_delivery_against_its_snapshot() {
+    let dir = temp_dir();
+    let baseline = dir.join("baseline.csv");
+    let snap = dir.join("baseline.rvlsnap");
+    std::fs::write(&baseline, "id,amount,desk\nA,100,rates\nB,50,fx\n").unwrap();
+
+    let (code, stdout) = rvl(&[
+        "snapshot",
+        baseline.to_str().unwrap(),
+        "--out",
+        snap.to_str().unwrap(),
+    ]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let written: Value = serde_json::from_str(&std::fs::read_to_string(&snap).unwrap()).unwrap();
+    assert_eq!(written["version"], "rvl.snapshot.v0");
+    assert_eq!(written["rows"], 2);
+    // The snapshot holds stats and digests, not the values themselves.
+    assert!(!written.to_string().contains("rates"));
+
+    // Only the snapshot is needed to verify the delivery.
+    std::fs::remove_file(&baseline).unwrap();
+    let same = dir.join("same.csv");
+    std::fs::write(&same, "id,amount,desk\nB,50.00,fx\nA,100,rates\n").unwrap();
+    let (code, stdout) = rvl(&["verify", same.to_str().unwrap(), snap.to_str().unwrap()]);
+    assert_eq!(code, Some(0), "{stdout}");
+    assert!(stdout.contains("VERIFIED"), "{stdout}");
+
+    let changed = dir.join("changed.csv");
+    std::fs::write(&changed, "id,amount,desk\nA,100,rates\nB,65,fx\n").unwrap();
+    let (code, stdout) = rvl(&[
+        "verify",
+        changed.to_str().unwrap(),
+        snap.to_str().unwrap(),
+        "--json",
+    ]);
+    assert_eq!(code, Some(1), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rvl.verify.v0");
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["columns_changed"][0]["name"], "u8:amount");
+    assert_eq!(json["columns_changed"][0]["delta"], 15.0);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn verify_rejects_a_file_that_is_not_a_snapshot() {
+    let dir = temp_dir();
+    let csv = dir.join("data.csv");
+    std::fs::write(&csv, "id,amount\nA,1\n").unwrap();
+
+    let (code, _) = rvl(&["verify", csv.to_str().unwrap(), csv.to_str().unwrap()]);
+    assert_eq!(code, Some(2));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn write_new(name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rvl_test_stdin_{name}_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("new.csv");
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn run_rvl_with_stdin(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(args)
+        .env_remove("RVL_KEY")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn dash_reads_the_old_input_from_stdin() {
+    let new = write_new("dash", "id,v\nA,1\nB,5\n");
+    let output = run_rvl_with_stdin(
+        &["-", new.to_str().unwrap(), "--key", "id", "--no-witness"],
+        "id,v\nA,1\nB,2\n",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("REAL CHANGE"), "{stdout}");
+    assert!(stdout.contains("stdin"), "{stdout}");
+}
+
+#[test]
+fn dash_for_both_inputs_is_refused() {
+    let output = run_rvl_with_stdin(&["-", "-", "--json", "--no-witness"], "id,v\nA,1\n");
+    assert_eq!(output.status.code(), Some(2));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["refusal"]["code"], "E_IO");
+}
+
+#[test]
+fn rerun_suggestions_use_a_placeholder_for_stdin() {
+    let old: String = std::iter::once("id,v\n".to_string())
+        .chain((0..40).map(|idx| format!("R{idx},10\n")))
+        .collect();
+    let new: String = std::iter::once("id,v\n".to_string())
+        .chain((0..40).map(|idx| format!("R{idx},11\n")))
+        .collect();
+    let new = write_new("rerun", &new);
+    let output = run_rvl_with_stdin(
+        &["-", new.to_str().unwrap(), "--key", "id", "--no-witness"],
+        &old,
+    );
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("E_DIFFUSE"), "{stderr}");
+    assert!(stderr.contains("rvl OLD_CSV "), "{stderr}");
+}
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_subtolerance_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pair(dir: &Path) -> (PathBuf, PathBuf) {
+    let old = dir.join("old.csv");
+    let new = dir.join("new.csv");
+    std::fs::write(&old, "id,value\nA,1.0\nB,2.0\nC,3.0\n").unwrap();
+    std::fs::write(&new, "id,value\nA,1.0005\nB,2.002\nC,3.0\n").unwrap();
+    (old, new)
+}
+
+fn make_args(old: &Path, new: &Path, json: bool) -> Args {
+    let mut args = Args::new(
+        old.to_path_buf(),
+        new.to_path_buf(),
+        Some("id".to_string()),
+        0.95,
+        0.01,
+        None,
+        json,
+    );
+    args.show_subtolerance = Some(5);
+    args
+}
+
+#[test]
+fn json_lists_subtolerance_deltas_largest_first_redacted() {
+    let dir = temp_dir();
+    let (old, new) = write_pair(&dir);
+
+    let result = orchestrator::run(&make_args(&old, &new, true)).unwrap();
+    let value: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(value["outcome"], "NO_REAL_CHANGE");
+    let entries = value["subtolerance"].as_array().expect("subtolerance");
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["row_id"], "u8:B");
+    assert_eq!(entries[1]["row_id"], "u8:A");
+    assert!(entries[0].get("delta").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn explicit_json_includes_values_and_human_lists_labels() {
+    let dir = temp_dir();
+    let (old, new) = write_pair(&dir);
+
+    let mut args = make_args(&old, &new, true);
+    args.explicit = true;
+    let result = orchestrator::run(&args).unwrap();
+    let value: Value = serde_json::from_str(&result.output).unwrap();
+    let delta = value["subtolerance"][0]["delta"].as_f64().expect("delta");
+    assert!((delta - 0.002).abs() < 1e-9);
+
+    let human = orchestrator::run(&make_args(&old, &new, false)).unwrap();
+    assert!(human.output.contains("Largest sub-tolerance deltas:"));
+    assert!(human.output.contains("1. B.value  within tolerance"));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn subtolerance_is_omitted_unless_requested() {
+    let dir = temp_dir();
+    let (old, new) = write_pair(&dir);
+
+    let mut args = make_args(&old, &new, true);
+    args.show_subtolerance = None;
+    let result = orchestrator::run(&args).unwrap();
+    let value: Value = serde_json::from_str(&result.output).unwrap();
+    assert!(value.get("subtolerance").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
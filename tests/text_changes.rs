@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_text_changes_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// rent is unchanged; B and D flip status, and lease_start is a date column.
+fn write_statuses(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,rent,status,lease_start\nA,100,Active,2024-01-01\nB,200,Active,2024-01-01\nC,300,Vacant,2024-01-01\nD,400,Active,2024-01-01\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "id,rent,status,lease_start\nA,100, Active ,2024-01-01\nB,200,Sold,2024-01-01\nC,300,Vacant,2024-02-01\nD,400,Pending,2024-01-01\n",
+    )
+    .unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    );
+    args.include_text = true;
+    args
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn text_changes_are_off_by_default() {
+    let dir = temp_dir();
+    let mut args = write_statuses(&dir, true);
+    args.include_text = false;
+
+    let json = run_json(&args);
+    assert!(json.get("text_changes").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn text_changes_count_per_column_without_values() {
+    let dir = temp_dir();
+    let args = write_statuses(&dir, true);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    let report = &json["text_changes"];
+    assert_eq!(report["cells_changed"], 2);
+    let columns = report["columns"].as_array().unwrap();
+    assert_eq!(columns.len(), 1, "date columns are not text: {report}");
+    assert_eq!(columns[0]["column"], "u8:status");
+    assert_eq!(columns[0]["changed"], 2);
+    assert_eq!(columns[0]["samples"][0]["row_id"], "u8:B");
+    assert!(columns[0]["samples"][0].get("old").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn explicit_human_output_shows_samples() {
+    let dir = temp_dir();
+    let mut args = write_statuses(&dir, false);
+    args.explicit = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Text changes: 2 cells in 1 column"),
+        "{output}"
+    );
+    assert!(
+        output.contains("  status  2 cells  (B: Active -> Sold, D: Active -> Pending)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
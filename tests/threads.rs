@@ -0,0 +1,84 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::diff::chunks::CHUNK_ROWS;
+use rvl::orchestrator;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_threads_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Several chunks of rows where many cells tie on the same delta, so top-K
+// order depends on tie-breaks across chunk boundaries.
+fn write_large(dir: &Path, key: Option<&str>) -> Args {
+    let rows = CHUNK_ROWS * 3 + 123;
+    let mut old = String::from("id,a,b,c\n");
+    let mut new = String::from("id,a,b,c\n");
+    for row in 0..rows {
+        writeln!(old, "R{row},{row},{},1.5", row % 7).unwrap();
+        let b = if row % 5 == 0 { row % 7 + 2 } else { row % 7 };
+        let c = if row % 97 == 0 { "1.5000000001" } else { "1.5" };
+        writeln!(new, "R{row},{},{b},{c}", row + row % 3).unwrap();
+    }
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-6,
+        None,
+        true,
+    );
+    args.exhaustive = true;
+    args.max_audit_changes = 20_000;
+    args.show_subtolerance = Some(5);
+    args.report_csv = Some(dir.join("changes.csv"));
+    args
+}
+
+fn run_with_threads(args: &mut Args, threads: usize) -> (String, String) {
+    args.threads = threads;
+    let output = orchestrator::run(args).unwrap().output;
+    let csv = std::fs::read_to_string(args.report_csv.as_ref().unwrap()).unwrap();
+    (output, csv)
+}
+
+#[test]
+fn key_mode_output_is_independent_of_thread_count() {
+    let dir = temp_dir();
+    let mut args = write_large(&dir, Some("id"));
+
+    let serial = run_with_threads(&mut args, 1);
+    assert!(serial.0.contains("REAL_CHANGE"));
+    assert!(serial.1.lines().count() > 10_000);
+    assert_eq!(run_with_threads(&mut args, 2), serial);
+    assert_eq!(run_with_threads(&mut args, 4), serial);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn row_order_output_is_independent_of_thread_count() {
+    let dir = temp_dir();
+    let mut args = write_large(&dir, None);
+
+    let serial = run_with_threads(&mut args, 1);
+    assert_eq!(run_with_threads(&mut args, 3), serial);
+    assert_eq!(run_with_threads(&mut args, 0), serial);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
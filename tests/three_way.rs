@@ -0,0 +1,97 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::three_way;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_three_way_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn make_args(dir: &Path, base: &str, ours: &str, theirs: &str, explicit: bool) -> Args {
+    let base_path = dir.join("base.csv");
+    let ours_path = dir.join("ours.csv");
+    let theirs_path = dir.join("theirs.csv");
+    std::fs::write(&base_path, base).unwrap();
+    std::fs::write(&ours_path, ours).unwrap();
+    std::fs::write(&theirs_path, theirs).unwrap();
+    let mut args = Args::new(
+        ours_path,
+        theirs_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    );
+    args.base = Some(base_path);
+    args.explicit = explicit;
+    args
+}
+
+const BASE: &str = "id,amount,rate\nA,10,1\nB,20,2\nC,30,3\n";
+const OURS: &str = "id,amount,rate\nA,11,1\nB,25,2\nC,30,3\n";
+const THEIRS: &str = "id,amount,rate\nA,10,1\nB,26,2\nC,30,4\n";
+
+#[test]
+fn classifies_edits_and_flags_conflicts() {
+    let dir = temp_dir();
+    let report = three_way::compare(&make_args(&dir, BASE, OURS, THEIRS, true)).unwrap();
+
+    assert_eq!(report.outcome, "REAL_CHANGE");
+    assert_eq!(exit_code(report.verdict), 1);
+    assert_eq!(report.counts.ours_only, 1);
+    assert_eq!(report.counts.theirs_only, 1);
+    assert_eq!(report.counts.conflicts, 1);
+    assert_eq!(report.counts.both, 0);
+
+    let json = serde_json::to_value(&report).unwrap();
+    let conflict = json["cells"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|cell| cell["change"] == "conflict")
+        .unwrap();
+    assert_eq!(conflict["base"], 20.0);
+    assert_eq!(conflict["ours"], 25.0);
+    assert_eq!(conflict["theirs"], 26.0);
+    assert_eq!(json["version"], "rvl.three_way.v0");
+    assert_eq!(json["sides"]["ours"]["outcome"], "REAL_CHANGE");
+}
+
+#[test]
+fn values_are_redacted_without_explicit() {
+    let dir = temp_dir();
+    let report = three_way::compare(&make_args(&dir, BASE, OURS, THEIRS, false)).unwrap();
+
+    assert_eq!(report.counts.conflicts, 1);
+    let json = serde_json::to_value(&report).unwrap();
+    for cell in json["cells"].as_array().unwrap() {
+        assert!(cell.get("base").is_none(), "{cell}");
+        assert!(cell.get("ours").is_none(), "{cell}");
+    }
+}
+
+#[test]
+fn a_refusing_side_refuses_the_three_way_run() {
+    let dir = temp_dir();
+    let theirs = "id,amount,rate\nA,10,1\nB,oops,2\nC,30,3\n";
+    let report = three_way::compare(&make_args(&dir, BASE, OURS, theirs, false)).unwrap();
+
+    assert_eq!(report.outcome, "REFUSAL");
+    assert!(report.cells.is_empty());
+    assert!(report.sides.ours.refusal.is_none());
+    assert_eq!(
+        report.sides.theirs.refusal.as_ref().unwrap().code,
+        "E_MIXED_TYPES"
+    );
+}
@@ -0,0 +1,90 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_timings_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,50\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,100\nB,20\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+const PHASES: [&str; 7] = [
+    "setup_ms",
+    "read_ms",
+    "parse_ms",
+    "align_ms",
+    "type_ms",
+    "diff_ms",
+    "render_ms",
+];
+
+#[test]
+fn timings_are_absent_by_default() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert!(json["metrics"].get("timings").is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn json_metrics_carry_phase_timings_that_sum_to_the_total() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, true);
+    args.timings = true;
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    let timings = &json["metrics"]["timings"];
+    let mut sum = 0.0;
+    for phase in PHASES {
+        let ms = timings[phase].as_f64().unwrap_or(-1.0);
+        assert!(ms >= 0.0, "{phase}: {timings}");
+        sum += ms;
+    }
+    let total = timings["total_ms"].as_f64().unwrap();
+    assert!((sum - total).abs() <= 1e-6 * total.max(1.0), "{timings}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_ends_with_a_timings_line() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, false);
+    args.timings = true;
+
+    let output = orchestrator::run(&args).unwrap().output;
+    let last = output.trim_end().lines().last().unwrap_or_default();
+    assert!(last.starts_with("Timings: setup "), "{output}");
+    assert!(last.ends_with(" (--timings)."), "{output}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::diff::tolerance::SweepLevels;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_tolerance_sweep_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Deltas of 0.0005, 0.02, and 5: one per order of magnitude.
+fn write_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,amount\nA,100\nB,200\nC,300\nD,400\n").unwrap();
+    std::fs::write(&new_path, "id,amount\nA,100.0005\nB,200.02\nC,305\nD,400\n").unwrap();
+    let mut args = Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    );
+    args.tolerance_sweep = Some(SweepLevels::parse("1e-9,1e-3,0.01,1,10").unwrap());
+    args
+}
+
+#[test]
+fn sweep_reports_cells_above_each_level() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    let sweep = json["tolerance_sweep"].as_array().unwrap();
+    let cells: Vec<u64> = sweep
+        .iter()
+        .map(|level| level["cells_above"].as_u64().unwrap())
+        .collect();
+    assert_eq!(cells, vec![3, 2, 2, 1, 0]);
+    assert_eq!(sweep[0]["tolerance"], 1e-9);
+    assert_eq!(sweep[3]["verdict"], "REAL_CHANGE");
+    assert_eq!(sweep[4]["verdict"], "NO_REAL_CHANGE");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_output_lists_the_sweep() {
+    let dir = temp_dir();
+    let args = write_pair(&dir, false);
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("Tolerance sweep:"), "{output}");
+    assert!(
+        output.contains("  10  0 cells above  NO_REAL_CHANGE"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_top_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// 40 equal deltas: the default 25 contributors cover 62.5%, below 95%.
+fn write_broad_change(dir: &Path) -> Args {
+    let mut old = String::from("id,amount\n");
+    let mut new = String::from("id,amount\n");
+    for i in 0..40 {
+        old.push_str(&format!("r{i},100\n"));
+        new.push_str(&format!("r{i},101\n"));
+    }
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, old).unwrap();
+    std::fs::write(&new_path, new).unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        true,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn default_top_refuses_broad_change() {
+    let dir = temp_dir();
+    let args = write_broad_change(&dir);
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REFUSAL");
+    assert_eq!(json["refusal"]["code"], "E_DIFFUSE");
+    assert_eq!(json["limits"]["max_contributors"], 25);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn raising_top_explains_broad_change() {
+    let dir = temp_dir();
+    let mut args = write_broad_change(&dir);
+    args.top = 40;
+
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["limits"]["max_contributors"], 40);
+    assert_eq!(json["contributors"].as_array().unwrap().len(), 38);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn top_out_of_range_is_a_usage_error() {
+    for value in ["0", "1001"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+            .args(["old.csv", "new.csv", "--top", value])
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2), "--top {value}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("1 <= N <= 1000"), "{stderr}");
+    }
+}
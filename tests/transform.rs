@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::numeric::transform::ColumnTransform;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_transform_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// The old export is in dollars, the new one in thousands.
+fn write_pair(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,value\nA,125000\nB,40000\n").unwrap();
+    std::fs::write(&new_path, "id,value\nA,125\nB,40\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn scaling_one_side_reconciles_units() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, true);
+
+    assert_eq!(run_json(&args)["outcome"], "REAL_CHANGE");
+
+    args.transforms = vec![ColumnTransform::parse("new:value=scale:1000").unwrap()];
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["transforms"][0]["column"], "u8:value");
+    assert_eq!(json["transforms"][0]["side"], "new");
+    assert_eq!(json["transforms"][0]["transform"], "scale:1000");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn human_header_lists_transforms() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, false);
+    args.transforms = vec![ColumnTransform::parse("old:value=scale:0.001").unwrap()];
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("NO REAL CHANGE"), "{output}");
+    assert!(
+        output.contains("Transforms: value scale:0.001 (old)"),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unknown_transform_column_refuses() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, true);
+    args.transforms = vec![ColumnTransform::parse("rent=abs").unwrap()];
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_HEADERS");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
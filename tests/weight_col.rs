@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::cli::args::Args;
+use rvl::cli::exit::exit_code;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_weight_col_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// A moves by 500 on a small line (weight 10); B moves by 10 on a large line
+// (weight 1000), so weighting flips the ranking.
+fn make_args(dir: &Path, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(&old_path, "id,value,mv\nA,100,10\nB,100,1000\n").unwrap();
+    std::fs::write(&new_path, "id,value,mv\nA,600,10\nB,110,1000\n").unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        Some("id".to_string()),
+        0.5,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+#[test]
+fn unweighted_ranking_is_declared_as_abs_delta() {
+    let dir = temp_dir();
+    let args = make_args(&dir, true);
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["ranking"]["mode"], "abs_delta");
+    assert!(json["ranking"].get("weight_column").is_none());
+    assert_eq!(json["contributors"][0]["row_id"], "u8:A");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn weight_col_scales_contributions_by_row_weight() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.weight_col = Some("mv".to_string());
+
+    let json: Value = serde_json::from_str(&orchestrator::run(&args).unwrap().output).unwrap();
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["ranking"]["mode"], "weighted");
+    assert_eq!(json["ranking"]["weight_column"], "u8:mv");
+    assert_eq!(json["metrics"]["total_change"], 15000.0);
+    assert_eq!(json["contributors"][0]["row_id"], "u8:B");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn weight_col_is_declared_in_human_ranking_line() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, false);
+    args.weight_col = Some("mv".to_string());
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(
+        output.contains("Ranking: abs(delta) x abs(weight) (weight=mv)"),
+        "output:\n{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn missing_weight_col_refuses_with_headers() {
+    let dir = temp_dir();
+    let mut args = make_args(&dir, true);
+    args.weight_col = Some("notional".to_string());
+
+    let result = orchestrator::run(&args).unwrap();
+    let json: Value = serde_json::from_str(&result.output).unwrap();
+    assert_eq!(exit_code(result.outcome), 2);
+    assert_eq!(json["refusal"]["code"], "E_HEADERS");
+    assert_eq!(json["refusal"]["detail"]["issue"], "missing_column");
+    assert_eq!(json["refusal"]["detail"]["file"], "old");
+    assert_eq!(json["refusal"]["detail"]["name"], "u8:notional");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
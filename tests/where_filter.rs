@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::alignment::row_filter::RowPredicate;
+use rvl::cli::args::Args;
+use rvl::orchestrator;
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_where_filter_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// B was sold between snapshots; its balance dropping to 0 is expected.
+fn write_pair(dir: &Path, key: Option<&str>, json: bool) -> Args {
+    let old_path = dir.join("old.csv");
+    let new_path = dir.join("new.csv");
+    std::fs::write(
+        &old_path,
+        "id,status,balance\nA,Active,100\nB,Active,500\nC,Active,20\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &new_path,
+        "id,status,balance\nA,Active,100\nB,Sold,0\nC,Active,20\n",
+    )
+    .unwrap();
+    Args::new(
+        old_path,
+        new_path,
+        key.map(str::to_string),
+        0.95,
+        1e-9,
+        None,
+        json,
+    )
+}
+
+fn run_json(args: &Args) -> Value {
+    serde_json::from_str(&orchestrator::run(args).unwrap().output).unwrap()
+}
+
+#[test]
+fn excluded_rows_do_not_reach_the_verdict() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, Some("id"), true);
+
+    assert_eq!(run_json(&args)["outcome"], "REAL_CHANGE");
+
+    args.where_filters = vec![RowPredicate::parse("status != 'Sold'").unwrap()];
+    let json = run_json(&args);
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["counts"]["rows_old"], 3);
+    assert_eq!(json["counts"]["rows_aligned"], 2);
+    assert_eq!(json["counts"]["rows_filtered_old"], 1);
+    assert_eq!(json["counts"]["rows_filtered_new"], 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn row_order_mode_filters_aligned_pairs() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, None, false);
+    args.where_filters = vec![RowPredicate::parse("status = Active").unwrap()];
+
+    let output = orchestrator::run(&args).unwrap().output;
+    assert!(output.contains("NO REAL CHANGE"), "{output}");
+    assert!(
+        output.contains("Filtered rows: 1 old, 1 new excluded (--where)."),
+        "{output}"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unknown_filter_column_refuses() {
+    let dir = temp_dir();
+    let mut args = write_pair(&dir, Some("id"), true);
+    args.where_filters = vec![RowPredicate::parse("desk = rates").unwrap()];
+
+    let json = run_json(&args);
+    assert_eq!(json["refusal"]["code"], "E_HEADERS");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
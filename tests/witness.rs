@@ -93,6 +93,41 @@ fn no_witness_flag_suppresses_recording() {
     cleanup(&dir);
 }
 
+#[test]
+fn env_defaults_apply_and_flags_win_with_recorded_sources() {
+    let dir = temp_dir();
+    let old = write_csv(&dir, "old.csv", "id,value\nA,1\nB,2\n");
+    let new = write_csv(&dir, "new.csv", "id,value\nB,2.5\nA,1\n");
+    let ledger = dir.join("witness.jsonl");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .arg(old.to_str().unwrap())
+        .arg(new.to_str().unwrap())
+        .arg("--tolerance")
+        .arg("1")
+        .env("RVL_KEY", "id")
+        .env("RVL_TOLERANCE", "0.1")
+        .env("RVL_JSON", "true")
+        .env("EPISTEMIC_WITNESS", ledger.to_str().unwrap())
+        .output()
+        .expect("failed to run rvl");
+
+    assert_eq!(output.status.code(), Some(0), "flag tolerance should win");
+    let stdout: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(stdout["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(stdout["tolerance"], 1.0);
+
+    let content = std::fs::read_to_string(&ledger).unwrap();
+    let record: serde_json::Value = serde_json::from_str(content.lines().next().unwrap()).unwrap();
+    let sources = &record["params"]["param_sources"];
+    assert_eq!(sources["key"], "env");
+    assert_eq!(sources["tolerance"], "cli");
+    assert_eq!(sources["json"], "env");
+    assert_eq!(sources["threshold"], "default");
+
+    cleanup(&dir);
+}
+
 #[test]
 fn empty_epistemic_witness_falls_back_to_home_default() {
     let dir = temp_dir();
@@ -0,0 +1,150 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rvl::output::xlsx::{Cell, Sheet, workbook_bytes};
+use serde_json::Value;
+
+fn temp_dir() -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let id: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("rvl_test_xlsx_workbook_{id}_{seq}"));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn sheet(name: &str, rows: &[(&str, f64)]) -> Sheet {
+    let mut cells = vec![vec![
+        Cell::Header("id".to_string()),
+        Cell::Header("amount".to_string()),
+    ]];
+    cells.extend(
+        rows.iter()
+            .map(|(id, amount)| vec![Cell::Text(id.to_string()), Cell::Number(*amount)]),
+    );
+    Sheet {
+        name: name.to_string(),
+        rows: cells,
+        column_widths: Vec::new(),
+        freeze_header: true,
+    }
+}
+
+fn write_workbook(path: &Path, sheets: &[Sheet]) {
+    std::fs::write(path, workbook_bytes(sheets)).unwrap();
+}
+
+fn rvl(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_rvl"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn all_sheets_compares_each_named_sheet() {
+    let dir = temp_dir();
+    let old = dir.join("old.xlsx");
+    let new = dir.join("new.xlsx");
+    write_workbook(
+        &old,
+        &[
+            sheet("Rent", &[("A", 100.0), ("B", 200.0)]),
+            sheet("Fees", &[("A", 5.0)]),
+            sheet("Legacy", &[("A", 1.0)]),
+        ],
+    );
+    write_workbook(
+        &new,
+        &[
+            sheet("Fees", &[("A", 5.0)]),
+            sheet("Rent", &[("A", 100.0), ("B", 260.0)]),
+        ],
+    );
+
+    let (code, stdout) = rvl(&[
+        "xlsx",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+        "--all-sheets",
+        "--key",
+        "id",
+        "--json",
+    ]);
+    assert_eq!(code, Some(1), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["version"], "rvl.workbook.v0");
+    assert_eq!(json["outcome"], "REAL_CHANGE");
+    assert_eq!(json["sheets"][0]["name"], "Rent");
+    assert_eq!(json["sheets"][0]["outcome"], "REAL_CHANGE");
+    assert_eq!(
+        json["sheets"][0]["result"]["contributors"][0]["row_id"],
+        "u8:B"
+    );
+    assert_eq!(json["sheets"][1]["name"], "Fees");
+    assert_eq!(json["sheets"][1]["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["old_only"], serde_json::json!(["Legacy"]));
+    assert_eq!(json["new_only"], serde_json::json!([]));
+
+    let (_, human) = rvl(&[
+        "xlsx",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+        "--all-sheets",
+        "--key",
+        "id",
+    ]);
+    assert!(human.starts_with("RVL WORKBOOK"), "{human}");
+    assert!(human.contains("Rent  REAL CHANGE"), "{human}");
+    assert!(
+        human.contains("Legacy  only in old workbook (not compared)"),
+        "{human}"
+    );
+    assert!(human.contains("== Sheet: Rent =="), "{human}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unchanged_first_sheet_exits_zero() {
+    let dir = temp_dir();
+    let old = dir.join("old.xlsx");
+    let new = dir.join("new.xlsx");
+    write_workbook(&old, &[sheet("Data", &[("A", 1.5)])]);
+    write_workbook(&new, &[sheet("Export", &[("A", 1.5)])]);
+
+    let (code, stdout) = rvl(&[
+        "xlsx",
+        old.to_str().unwrap(),
+        new.to_str().unwrap(),
+        "--json",
+    ]);
+    assert_eq!(code, Some(0), "{stdout}");
+    let json: Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(json["outcome"], "NO_REAL_CHANGE");
+    assert_eq!(json["sheets"].as_array().unwrap().len(), 1);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn unreadable_workbook_exits_two() {
+    let dir = temp_dir();
+    let old = dir.join("old.xlsx");
+    let new = dir.join("new.xlsx");
+    std::fs::write(&old, "id,amount\nA,1\n").unwrap();
+    write_workbook(&new, &[sheet("Data", &[("A", 1.0)])]);
+
+    let (code, _) = rvl(&["xlsx", old.to_str().unwrap(), new.to_str().unwrap()]);
+    assert_eq!(code, Some(2));
+
+    std::fs::remove_dir_all(&dir).ok();
+}